@@ -75,10 +75,43 @@ typedef struct wire_BlockchainConfig {
   union BlockchainConfigKind *kind;
 } wire_BlockchainConfig;
 
+typedef struct wire_StringList {
+  struct wire_uint_8_list **ptr;
+  int32_t len;
+} wire_StringList;
+
+typedef struct wire_OutPoint {
+  struct wire_uint_8_list *txid;
+  uint32_t vout;
+} wire_OutPoint;
+
 typedef struct wire_Script {
   struct wire_uint_8_list *internal;
 } wire_Script;
 
+typedef struct wire_PsbtMap_Global {
+
+} wire_PsbtMap_Global;
+
+typedef struct wire_PsbtMap_Input {
+  uint32_t index;
+} wire_PsbtMap_Input;
+
+typedef struct wire_PsbtMap_Output {
+  uint32_t index;
+} wire_PsbtMap_Output;
+
+typedef union PsbtMapKind {
+  struct wire_PsbtMap_Global *Global;
+  struct wire_PsbtMap_Input *Input;
+  struct wire_PsbtMap_Output *Output;
+} PsbtMapKind;
+
+typedef struct wire_PsbtMap {
+  int32_t tag;
+  union PsbtMapKind *kind;
+} wire_PsbtMap;
+
 typedef struct wire_ScriptAmount {
   struct wire_Script script;
   uint64_t amount;
@@ -89,11 +122,6 @@ typedef struct wire_list_script_amount {
   int32_t len;
 } wire_list_script_amount;
 
-typedef struct wire_OutPoint {
-  struct wire_uint_8_list *txid;
-  uint32_t vout;
-} wire_OutPoint;
-
 typedef struct wire_list_out_point {
   struct wire_OutPoint *ptr;
   int32_t len;
@@ -123,6 +151,57 @@ typedef struct wire_RbfValue {
   union RbfValueKind *kind;
 } wire_RbfValue;
 
+typedef struct wire_ShrinkOutput_Address {
+  struct wire_uint_8_list *address;
+} wire_ShrinkOutput_Address;
+
+typedef struct wire_ShrinkOutput_Script {
+  struct wire_Script *script;
+} wire_ShrinkOutput_Script;
+
+typedef struct wire_ShrinkOutput_Index {
+  uint32_t index;
+} wire_ShrinkOutput_Index;
+
+typedef union ShrinkOutputKind {
+  struct wire_ShrinkOutput_Address *Address;
+  struct wire_ShrinkOutput_Script *Script;
+  struct wire_ShrinkOutput_Index *Index;
+} ShrinkOutputKind;
+
+typedef struct wire_ShrinkOutput {
+  int32_t tag;
+  union ShrinkOutputKind *kind;
+} wire_ShrinkOutput;
+
+typedef struct wire_PayjoinParams {
+  uint32_t *additional_fee_output_index;
+  uint64_t *max_additional_fee_contribution;
+  float *min_fee_rate;
+  bool disable_output_substitution;
+} wire_PayjoinParams;
+
+typedef struct wire_SignOptions {
+  bool is_multi_sig;
+  bool trust_witness_utxo;
+  uint32_t *assume_height;
+  bool allow_all_sighashes;
+  bool remove_partial_sigs;
+  bool try_finalize;
+  bool sign_with_tap_internal_key;
+  bool allow_grinding;
+} wire_SignOptions;
+
+typedef struct wire_PolicyKey {
+  struct wire_uint_8_list *name;
+  struct wire_uint_8_list *key;
+} wire_PolicyKey;
+
+typedef struct wire_list_policy_key {
+  struct wire_PolicyKey *ptr;
+  int32_t len;
+} wire_list_policy_key;
+
 typedef struct wire_DatabaseConfig_Memory {
 
 } wire_DatabaseConfig_Memory;
@@ -183,16 +262,22 @@ typedef struct wire_AddressIndex {
   union AddressIndexKind *kind;
 } wire_AddressIndex;
 
-typedef struct wire_SignOptions {
-  bool is_multi_sig;
-  bool trust_witness_utxo;
-  uint32_t *assume_height;
-  bool allow_all_sighashes;
-  bool remove_partial_sigs;
-  bool try_finalize;
-  bool sign_with_tap_internal_key;
-  bool allow_grinding;
-} wire_SignOptions;
+typedef struct wire_PsbtSafetyPolicy {
+  uint64_t *max_fee;
+  float *max_fee_percent;
+  bool require_non_witness_utxo;
+  bool refuse_unsafe;
+} wire_PsbtSafetyPolicy;
+
+typedef struct wire_UtxoLabel {
+  struct wire_OutPoint outpoint;
+  struct wire_uint_8_list *label;
+} wire_UtxoLabel;
+
+typedef struct wire_list_utxo_label {
+  struct wire_UtxoLabel *ptr;
+  int32_t len;
+} wire_list_utxo_label;
 
 typedef struct wire_TxOut {
   uint64_t value;
@@ -267,6 +352,18 @@ void wire_output__static_method__Api(int64_t port_, struct wire_uint_8_list *tx)
 
 void wire_serialize_psbt__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
 
+void wire_psbt_from_bytes__static_method__Api(int64_t port_, struct wire_uint_8_list *bytes);
+
+void wire_psbt_to_bytes__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
+
+void wire_psbt_to_hex__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
+
+void wire_psbt_version__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
+
+void wire_serialize_psbt_version__static_method__Api(int64_t port_,
+                                                     struct wire_uint_8_list *psbt_str,
+                                                     uint32_t version);
+
 void wire_psbt_txid__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
 
 void wire_extract_tx__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
@@ -279,6 +376,64 @@ void wire_combine_psbt__static_method__Api(int64_t port_,
                                            struct wire_uint_8_list *psbt_str,
                                            struct wire_uint_8_list *other);
 
+void wire_combine_psbts__static_method__Api(int64_t port_, struct wire_StringList *psbt_strs);
+
+void wire_psbt_add_input__static_method__Api(int64_t port_,
+                                             struct wire_uint_8_list *psbt_str,
+                                             struct wire_OutPoint *outpoint,
+                                             struct wire_uint_8_list *psbt_input,
+                                             uint32_t *sequence);
+
+void wire_psbt_remove_input__static_method__Api(int64_t port_,
+                                                struct wire_uint_8_list *psbt_str,
+                                                uint32_t index);
+
+void wire_psbt_add_output__static_method__Api(int64_t port_,
+                                              struct wire_uint_8_list *psbt_str,
+                                              struct wire_Script *script,
+                                              uint64_t amount);
+
+void wire_psbt_remove_output__static_method__Api(int64_t port_,
+                                                 struct wire_uint_8_list *psbt_str,
+                                                 uint32_t index);
+
+void wire_psbt_set_sequence__static_method__Api(int64_t port_,
+                                                struct wire_uint_8_list *psbt_str,
+                                                uint32_t index,
+                                                uint32_t sequence);
+
+void wire_psbt_set_lock_time__static_method__Api(int64_t port_,
+                                                 struct wire_uint_8_list *psbt_str,
+                                                 uint32_t lock_time);
+
+void wire_psbt_set_unknown__static_method__Api(int64_t port_,
+                                               struct wire_uint_8_list *psbt_str,
+                                               struct wire_PsbtMap *map,
+                                               uint8_t key_type,
+                                               struct wire_uint_8_list *key,
+                                               struct wire_uint_8_list *value);
+
+void wire_psbt_set_proprietary__static_method__Api(int64_t port_,
+                                                   struct wire_uint_8_list *psbt_str,
+                                                   struct wire_PsbtMap *map,
+                                                   struct wire_uint_8_list *prefix,
+                                                   uint8_t subtype,
+                                                   struct wire_uint_8_list *key,
+                                                   struct wire_uint_8_list *value);
+
+void wire_finalize_psbt_with_descriptor__static_method__Api(int64_t port_,
+                                                            struct wire_uint_8_list *psbt_str,
+                                                            struct wire_uint_8_list *descriptor,
+                                                            struct wire_uint_8_list *change_descriptor,
+                                                            int32_t network,
+                                                            uint32_t lookahead);
+
+void wire_psbt_inputs__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
+
+void wire_psbt_outputs__static_method__Api(int64_t port_,
+                                           struct wire_uint_8_list *psbt_str,
+                                           int32_t network);
+
 void wire_json_serialize__static_method__Api(int64_t port_, struct wire_uint_8_list *psbt_str);
 
 void wire_tx_builder_finish__static_method__Api(int64_t port_,
@@ -298,12 +453,121 @@ void wire_tx_builder_finish__static_method__Api(int64_t port_,
 
 void wire_bump_fee_tx_builder_finish__static_method__Api(int64_t port_,
                                                          struct wire_uint_8_list *txid,
-                                                         float fee_rate,
-                                                         struct wire_uint_8_list *allow_shrinking,
+                                                         float *fee_rate,
+                                                         uint64_t *fee_absolute,
+                                                         struct wire_ShrinkOutput *allow_shrinking,
+                                                         struct wire_list_out_point *utxos,
+                                                         struct wire_list_out_point *unspendable,
+                                                         bool manually_selected_only,
                                                          struct wire_uint_8_list *wallet_id,
                                                          bool enable_rbf,
                                                          uint32_t *n_sequence);
 
+void wire_cancel_transaction__static_method__Api(int64_t port_,
+                                                 struct wire_uint_8_list *wallet_id,
+                                                 struct wire_uint_8_list *txid,
+                                                 float fee_rate);
+
+void wire_parse_payjoin_uri__static_method__Api(int64_t port_, struct wire_uint_8_list *uri);
+
+void wire_payjoin_sender_request__static_method__Api(int64_t port_,
+                                                     struct wire_uint_8_list *wallet_id,
+                                                     struct wire_uint_8_list *uri,
+                                                     struct wire_uint_8_list *psbt_str,
+                                                     struct wire_PayjoinParams *params,
+                                                     struct wire_SignOptions *sign_options);
+
+void wire_payjoin_send__static_method__Api(int64_t port_,
+                                           struct wire_uint_8_list *wallet_id,
+                                           struct wire_uint_8_list *uri,
+                                           struct wire_uint_8_list *psbt_str,
+                                           struct wire_PayjoinParams *params,
+                                           struct wire_SignOptions *sign_options);
+
+void wire_payjoin_sender_process_response__static_method__Api(int64_t port_,
+                                                              struct wire_uint_8_list *wallet_id,
+                                                              struct wire_uint_8_list *uri,
+                                                              struct wire_uint_8_list *original_psbt,
+                                                              struct wire_PayjoinParams *params,
+                                                              struct wire_uint_8_list *response,
+                                                              struct wire_SignOptions *sign_options);
+
+void wire_payjoin_receive__static_method__Api(int64_t port_,
+                                              struct wire_uint_8_list *wallet_id,
+                                              struct wire_uint_8_list *original_psbt,
+                                              struct wire_uint_8_list *query);
+
+void wire_psbt_to_ur__static_method__Api(int64_t port_,
+                                         struct wire_uint_8_list *psbt_str,
+                                         uint32_t max_fragment_length,
+                                         uint32_t start_seq_num,
+                                         uint32_t count);
+
+void wire_descriptor_to_ur__static_method__Api(int64_t port_,
+                                               struct wire_uint_8_list *descriptor,
+                                               int32_t network);
+
+void wire_descriptors_to_ur_account__static_method__Api(int64_t port_,
+                                                        struct wire_StringList *descriptors,
+                                                        int32_t network);
+
+void wire_create_ur_decoder__static_method__Api(int64_t port_, int32_t network);
+
+void wire_ur_decoder_receive__static_method__Api(int64_t port_,
+                                                 struct wire_uint_8_list *decoder_id,
+                                                 struct wire_uint_8_list *part);
+
+void wire_drop_ur_decoder__static_method__Api(int64_t port_, struct wire_uint_8_list *decoder_id);
+
+void wire_create_multisig_session__static_method__Api(int64_t port_,
+                                                      struct wire_uint_8_list *descriptor,
+                                                      struct wire_uint_8_list *change_descriptor,
+                                                      int32_t network,
+                                                      struct wire_uint_8_list *psbt_str);
+
+void wire_restore_multisig_session__static_method__Api(int64_t port_,
+                                                       struct wire_uint_8_list *data);
+
+void wire_export_multisig_session__static_method__Api(int64_t port_,
+                                                      struct wire_uint_8_list *session_id);
+
+void wire_multisig_session_add_psbt__static_method__Api(int64_t port_,
+                                                        struct wire_uint_8_list *session_id,
+                                                        struct wire_uint_8_list *psbt_str);
+
+void wire_multisig_session_status__static_method__Api(int64_t port_,
+                                                      struct wire_uint_8_list *session_id);
+
+void wire_finalize_multisig_session__static_method__Api(int64_t port_,
+                                                        struct wire_uint_8_list *session_id);
+
+void wire_drop_multisig_session__static_method__Api(int64_t port_,
+                                                    struct wire_uint_8_list *session_id);
+
+void wire_bsms_new_token__static_method__Api(int64_t port_, int32_t encryption);
+
+void wire_bsms_key_record__static_method__Api(int64_t port_,
+                                              struct wire_uint_8_list *secret_key,
+                                              struct wire_uint_8_list *token,
+                                              struct wire_uint_8_list *description);
+
+void wire_bsms_verify_key_record__static_method__Api(int64_t port_,
+                                                     struct wire_uint_8_list *record,
+                                                     struct wire_uint_8_list *token);
+
+void wire_bsms_descriptor_record__static_method__Api(int64_t port_,
+                                                     uint32_t threshold,
+                                                     struct wire_StringList *keys,
+                                                     int32_t script_type,
+                                                     struct wire_uint_8_list *token,
+                                                     int32_t network);
+
+void wire_bsms_verify_descriptor_record__static_method__Api(int64_t port_,
+                                                            struct wire_uint_8_list *record,
+                                                            struct wire_uint_8_list *token,
+                                                            struct wire_uint_8_list *secret_key,
+                                                            int32_t network);
+
 void wire_create_descriptor__static_method__Api(int64_t port_,
                                                 struct wire_uint_8_list *descriptor,
                                                 int32_t network);
@@ -341,6 +605,16 @@ void wire_new_bip84_public__static_method__Api(int64_t port_,
                                                int32_t network,
                                                struct wire_uint_8_list *fingerprint);
 
+void wire_new_single_key_descriptor__static_method__Api(int64_t port_,
+                                                        int32_t script_type,
+                                                        struct wire_uint_8_list *secret_key,
+                                                        int32_t network);
+
+void wire_new_single_key_public__static_method__Api(int64_t port_,
+                                                    int32_t script_type,
+                                                    struct wire_uint_8_list *public_key,
+                                                    int32_t network);
+
 void wire_as_string_private__static_method__Api(int64_t port_,
                                                 struct wire_uint_8_list *descriptor,
                                                 int32_t network);
@@ -353,6 +627,32 @@ void wire_max_satisfaction_weight__static_method__Api(int64_t port_,
                                                       struct wire_uint_8_list *descriptor,
                                                       int32_t network);
 
+void wire_new_multisig_descriptor__static_method__Api(int64_t port_,
+                                                      uint32_t threshold,
+                                                      struct wire_StringList *public_keys,
+                                                      struct wire_uint_8_list *secret_key,
+                                                      int32_t script_type,
+                                                      bool sorted,
+                                                      int32_t network);
+
+void wire_compile_policy__static_method__Api(int64_t port_,
+                                             struct wire_uint_8_list *policy,
+                                             struct wire_list_policy_key *keys,
+                                             int32_t context,
+                                             int32_t network);
+
+void wire_derive_addresses__static_method__Api(int64_t port_,
+                                               struct wire_uint_8_list *descriptor,
+                                               int32_t network,
+                                               uint32_t start,
+                                               uint32_t count);
+
+void wire_descriptor_info__static_method__Api(int64_t port_, struct wire_uint_8_list *descriptor);
+
+void wire_import_wallet__static_method__Api(int64_t port_,
+                                            struct wire_uint_8_list *data,
+                                            int32_t network);
+
 void wire_create_descriptor_secret__static_method__Api(int64_t port_,
                                                        int32_t network,
                                                        struct wire_uint_8_list *mnemonic,
@@ -361,6 +661,10 @@ void wire_create_descriptor_secret__static_method__Api(int64_t port_,
 void wire_descriptor_secret_from_string__static_method__Api(int64_t port_,
                                                             struct wire_uint_8_list *secret);
 
+void wire_descriptor_secret_from_single__static_method__Api(int64_t port_,
+                                                            struct wire_uint_8_list *secret,
+                                                            int32_t network);
+
 void wire_extend_descriptor_secret__static_method__Api(int64_t port_,
                                                        struct wire_uint_8_list *secret,
                                                        struct wire_uint_8_list *path);
@@ -375,6 +679,12 @@ void wire_as_public__static_method__Api(int64_t port_, struct wire_uint_8_list *
 
 void wire_create_derivation_path__static_method__Api(int64_t port_, struct wire_uint_8_list *path);
 
+void wire_descriptor_secret_metadata__static_method__Api(int64_t port_,
+                                                         struct wire_uint_8_list *secret);
+
+void wire_descriptor_public_metadata__static_method__Api(int64_t port_,
+                                                         struct wire_uint_8_list *public_key);
+
 void wire_descriptor_public_from_string__static_method__Api(int64_t port_,
                                                             struct wire_uint_8_list *public_key);
 
@@ -383,6 +693,14 @@ void wire_create_descriptor_public__static_method__Api(int64_t port_,
                                                        struct wire_uint_8_list *path,
                                                        bool derive);
 
+void wire_slip132_to_standard__static_method__Api(int64_t port_, struct wire_uint_8_list *key);
+
+void wire_standard_to_slip132__static_method__Api(int64_t port_,
+                                                  struct wire_uint_8_list *key,
+                                                  int32_t version);
+
+void wire_slip132_template__static_method__Api(int64_t port_, struct wire_uint_8_list *key);
+
 void wire_create_script__static_method__Api(int64_t port_,
                                             struct wire_uint_8_list *raw_output_script);
 
@@ -433,7 +751,42 @@ void wire_get_transactions__static_method__Api(int64_t port_,
 void wire_sign__static_method__Api(int64_t port_,
                                    struct wire_uint_8_list *wallet_id,
                                    struct wire_uint_8_list *psbt_str,
-                                   struct wire_SignOptions *sign_options);
+                                   struct wire_SignOptions *sign_options,
+                                   struct wire_PsbtSafetyPolicy *safety_policy);
+
+void wire_check_psbt__static_method__Api(int64_t port_,
+                                         struct wire_uint_8_list *wallet_id,
+                                         struct wire_uint_8_list *psbt_str,
+                                         struct wire_PsbtSafetyPolicy *policy);
+
+void wire_max_sendable__static_method__Api(int64_t port_,
+                                           struct wire_uint_8_list *wallet_id,
+                                           struct wire_Script *destination,
+                                           float fee_rate,
+                                           struct wire_list_out_point *utxos);
+
+void wire_sweep__static_method__Api(int64_t port_,
+                                    struct wire_uint_8_list *wallet_id,
+                                    struct wire_Script *destination,
+                                    float fee_rate,
+                                    struct wire_list_out_point *utxos,
+                                    bool enable_rbf);
+
+void wire_plan_consolidation__static_method__Api(int64_t port_,
+                                                 struct wire_uint_8_list *wallet_id,
+                                                 float fee_rate,
+                                                 float future_fee_rate,
+                                                 struct wire_list_utxo_label *labels,
+                                                 uint32_t *max_inputs);
+
+void wire_finalize_psbt__static_method__Api(int64_t port_,
+                                            struct wire_uint_8_list *wallet_id,
+                                            struct wire_uint_8_list *psbt_str,
+                                            struct wire_SignOptions *sign_options);
+
+void wire_analyze_psbt__static_method__Api(int64_t port_,
+                                           struct wire_uint_8_list *wallet_id,
+                                           struct wire_uint_8_list *psbt_str);
 
 void wire_wallet_network__static_method__Api(int64_t port_, struct wire_uint_8_list *wallet_id);
 
@@ -449,6 +802,9 @@ void wire_get_descriptor_for_keychain__static_method__Api(int64_t port_,
                                                           struct wire_uint_8_list *wallet_id,
                                                           int32_t keychain);
 
+void wire_get_multipath_descriptor__static_method__Api(int64_t port_,
+                                                       struct wire_uint_8_list *wallet_id);
+
 void wire_generate_seed_from_word_count__static_method__Api(int64_t port_, int32_t word_count);
 
 void wire_generate_seed_from_string__static_method__Api(int64_t port_,
@@ -457,6 +813,8 @@ void wire_generate_seed_from_string__static_method__Api(int64_t port_,
 void wire_generate_seed_from_entropy__static_method__Api(int64_t port_,
                                                          struct wire_uint_8_list *entropy);
 
+struct wire_StringList *new_StringList_0(int32_t len);
+
 struct wire___record__out_point_String_usize *new_box_autoadd___record__out_point_String_usize_0(void);
 
 struct wire_AddressIndex *new_box_autoadd_address_index_0(void);
@@ -473,6 +831,14 @@ float *new_box_autoadd_f32_0(float value);
 
 struct wire_LocalUtxo *new_box_autoadd_local_utxo_0(void);
 
+struct wire_OutPoint *new_box_autoadd_out_point_0(void);
+
+struct wire_PayjoinParams *new_box_autoadd_payjoin_params_0(void);
+
+struct wire_PsbtMap *new_box_autoadd_psbt_map_0(void);
+
+struct wire_PsbtSafetyPolicy *new_box_autoadd_psbt_safety_policy_0(void);
+
 struct wire_PsbtSigHashType *new_box_autoadd_psbt_sig_hash_type_0(void);
 
 struct wire_RbfValue *new_box_autoadd_rbf_value_0(void);
@@ -483,6 +849,8 @@ struct wire_RpcSyncParams *new_box_autoadd_rpc_sync_params_0(void);
 
 struct wire_Script *new_box_autoadd_script_0(void);
 
+struct wire_ShrinkOutput *new_box_autoadd_shrink_output_0(void);
+
 struct wire_SignOptions *new_box_autoadd_sign_options_0(void);
 
 struct wire_SledDbConfiguration *new_box_autoadd_sled_db_configuration_0(void);
@@ -499,8 +867,12 @@ struct wire_UserPass *new_box_autoadd_user_pass_0(void);
 
 struct wire_list_out_point *new_list_out_point_0(int32_t len);
 
+struct wire_list_policy_key *new_list_policy_key_0(int32_t len);
+
 struct wire_list_script_amount *new_list_script_amount_0(int32_t len);
 
+struct wire_list_utxo_label *new_list_utxo_label_0(int32_t len);
+
 struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
 
 union AddressIndexKind *inflate_AddressIndex_Peek(void);
@@ -517,8 +889,18 @@ union DatabaseConfigKind *inflate_DatabaseConfig_Sqlite(void);
 
 union DatabaseConfigKind *inflate_DatabaseConfig_Sled(void);
 
+union PsbtMapKind *inflate_PsbtMap_Input(void);
+
+union PsbtMapKind *inflate_PsbtMap_Output(void);
+
 union RbfValueKind *inflate_RbfValue_Value(void);
 
+union ShrinkOutputKind *inflate_ShrinkOutput_Address(void);
+
+union ShrinkOutputKind *inflate_ShrinkOutput_Script(void);
+
+union ShrinkOutputKind *inflate_ShrinkOutput_Index(void);
+
 void free_WireSyncReturn(WireSyncReturn ptr);
 
 static int64_t dummy_method_to_enforce_bundling(void) {
@@ -542,14 +924,55 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_input__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_output__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_serialize_psbt__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_from_bytes__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_to_bytes__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_to_hex__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_version__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_serialize_psbt_version__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_psbt_txid__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_extract_tx__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_psbt_fee_rate__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_psbt_fee_amount__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_combine_psbt__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_combine_psbts__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_add_input__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_remove_input__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_add_output__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_remove_output__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_set_sequence__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_set_lock_time__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_set_unknown__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_set_proprietary__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_finalize_psbt_with_descriptor__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_inputs__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_outputs__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_json_serialize__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_tx_builder_finish__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_bump_fee_tx_builder_finish__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_cancel_transaction__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_parse_payjoin_uri__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_payjoin_sender_request__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_payjoin_send__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_payjoin_sender_process_response__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_payjoin_receive__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_psbt_to_ur__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_descriptor_to_ur__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_descriptors_to_ur_account__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_create_ur_decoder__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_ur_decoder_receive__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_drop_ur_decoder__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_create_multisig_session__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_restore_multisig_session__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_export_multisig_session__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_multisig_session_add_psbt__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_multisig_session_status__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_finalize_multisig_session__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_drop_multisig_session__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_bsms_new_token__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_bsms_key_record__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_bsms_verify_key_record__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_bsms_descriptor_record__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_bsms_verify_descriptor_record__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_create_descriptor__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_new_bip44_descriptor__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_new_bip44_public__static_method__Api);
@@ -557,18 +980,31 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_new_bip49_public__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_new_bip84_descriptor__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_new_bip84_public__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_new_single_key_descriptor__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_new_single_key_public__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_as_string_private__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_as_string__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_max_satisfaction_weight__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_new_multisig_descriptor__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_compile_policy__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_derive_addresses__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_descriptor_info__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_import_wallet__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_create_descriptor_secret__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_descriptor_secret_from_string__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_descriptor_secret_from_single__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_extend_descriptor_secret__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_derive_descriptor_secret__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_as_secret_bytes__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_as_public__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_create_derivation_path__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_descriptor_secret_metadata__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_descriptor_public_metadata__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_descriptor_public_from_string__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_create_descriptor_public__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_slip132_to_standard__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_standard_to_slip132__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_slip132_template__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_create_script__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_create_address__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_address_from_script__static_method__Api);
@@ -584,13 +1020,21 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_list_unspent_outputs__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_get_transactions__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_sign__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_check_psbt__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_max_sendable__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_sweep__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_plan_consolidation__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_finalize_psbt__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_analyze_psbt__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_wallet_network__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_list_unspent__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_get_psbt_input__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_get_descriptor_for_keychain__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) wire_get_multipath_descriptor__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_generate_seed_from_word_count__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_generate_seed_from_string__static_method__Api);
     dummy_var ^= ((int64_t) (void*) wire_generate_seed_from_entropy__static_method__Api);
+    dummy_var ^= ((int64_t) (void*) new_StringList_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd___record__out_point_String_usize_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_address_index_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_blockchain_config_0);
@@ -599,11 +1043,16 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_esplora_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_f32_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_local_utxo_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_out_point_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_payjoin_params_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_psbt_map_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_psbt_safety_policy_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_psbt_sig_hash_type_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_rbf_value_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_rpc_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_rpc_sync_params_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_script_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_shrink_output_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_sign_options_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_sled_db_configuration_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_sqlite_db_configuration_0);
@@ -612,7 +1061,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u8_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_user_pass_0);
     dummy_var ^= ((int64_t) (void*) new_list_out_point_0);
+    dummy_var ^= ((int64_t) (void*) new_list_policy_key_0);
     dummy_var ^= ((int64_t) (void*) new_list_script_amount_0);
+    dummy_var ^= ((int64_t) (void*) new_list_utxo_label_0);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list_0);
     dummy_var ^= ((int64_t) (void*) inflate_AddressIndex_Peek);
     dummy_var ^= ((int64_t) (void*) inflate_AddressIndex_Reset);
@@ -621,7 +1072,12 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) inflate_BlockchainConfig_Rpc);
     dummy_var ^= ((int64_t) (void*) inflate_DatabaseConfig_Sqlite);
     dummy_var ^= ((int64_t) (void*) inflate_DatabaseConfig_Sled);
+    dummy_var ^= ((int64_t) (void*) inflate_PsbtMap_Input);
+    dummy_var ^= ((int64_t) (void*) inflate_PsbtMap_Output);
     dummy_var ^= ((int64_t) (void*) inflate_RbfValue_Value);
+    dummy_var ^= ((int64_t) (void*) inflate_ShrinkOutput_Address);
+    dummy_var ^= ((int64_t) (void*) inflate_ShrinkOutput_Script);
+    dummy_var ^= ((int64_t) (void*) inflate_ShrinkOutput_Index);
     dummy_var ^= ((int64_t) (void*) free_WireSyncReturn);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     dummy_var ^= ((int64_t) (void*) get_dart_object);
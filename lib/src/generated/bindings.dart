@@ -388,6 +388,103 @@ class RustBdkFfiImpl implements RustBdkFfi {
         argNames: ["psbtStr"],
       );
 
+  Future<String> psbtFromBytesStaticMethodApi(
+      {required Uint8List bytes, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(bytes);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_psbt_from_bytes__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtFromBytesStaticMethodApiConstMeta,
+      argValues: [bytes],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtFromBytesStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_from_bytes__static_method__Api",
+        argNames: ["bytes"],
+      );
+
+  Future<Uint8List> psbtToBytesStaticMethodApi(
+      {required String psbtStr, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_psbt_to_bytes__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_uint_8_list,
+      constMeta: kPsbtToBytesStaticMethodApiConstMeta,
+      argValues: [psbtStr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtToBytesStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_to_bytes__static_method__Api",
+        argNames: ["psbtStr"],
+      );
+
+  Future<String> psbtToHexStaticMethodApi(
+      {required String psbtStr, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_psbt_to_hex__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtToHexStaticMethodApiConstMeta,
+      argValues: [psbtStr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtToHexStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_to_hex__static_method__Api",
+        argNames: ["psbtStr"],
+      );
+
+  Future<int> psbtVersionStaticMethodApi(
+      {required String psbtStr, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_psbt_version__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kPsbtVersionStaticMethodApiConstMeta,
+      argValues: [psbtStr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtVersionStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_version__static_method__Api",
+        argNames: ["psbtStr"],
+      );
+
+  Future<String> serializePsbtVersionStaticMethodApi(
+      {required String psbtStr, required int version, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_u32(version);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_serialize_psbt_version__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kSerializePsbtVersionStaticMethodApiConstMeta,
+      argValues: [psbtStr, version],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kSerializePsbtVersionStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "serialize_psbt_version__static_method__Api",
+            argNames: ["psbtStr", "version"],
+          );
+
   Future<String> psbtTxidStaticMethodApi(
       {required String psbtStr, dynamic hint}) {
     var arg0 = _platform.api2wire_String(psbtStr);
@@ -484,6 +581,297 @@ class RustBdkFfiImpl implements RustBdkFfi {
         argNames: ["psbtStr", "other"],
       );
 
+  Future<CombinedPsbt> combinePsbtsStaticMethodApi(
+      {required List<String> psbtStrs, dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(psbtStrs);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_combine_psbts__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_combined_psbt,
+      constMeta: kCombinePsbtsStaticMethodApiConstMeta,
+      argValues: [psbtStrs],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCombinePsbtsStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "combine_psbts__static_method__Api",
+        argNames: ["psbtStrs"],
+      );
+
+  Future<String> psbtAddInputStaticMethodApi(
+      {required String psbtStr,
+      required OutPoint outpoint,
+      required String psbtInput,
+      int? sequence,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = _platform.api2wire_box_autoadd_out_point(outpoint);
+    var arg2 = _platform.api2wire_String(psbtInput);
+    var arg3 = _platform.api2wire_opt_box_autoadd_u32(sequence);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_add_input__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtAddInputStaticMethodApiConstMeta,
+      argValues: [psbtStr, outpoint, psbtInput, sequence],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtAddInputStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_add_input__static_method__Api",
+        argNames: ["psbtStr", "outpoint", "psbtInput", "sequence"],
+      );
+
+  Future<String> psbtRemoveInputStaticMethodApi(
+      {required String psbtStr, required int index, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_u32(index);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_remove_input__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtRemoveInputStaticMethodApiConstMeta,
+      argValues: [psbtStr, index],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtRemoveInputStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_remove_input__static_method__Api",
+        argNames: ["psbtStr", "index"],
+      );
+
+  Future<String> psbtAddOutputStaticMethodApi(
+      {required String psbtStr,
+      required Script script,
+      required int amount,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = _platform.api2wire_box_autoadd_script(script);
+    var arg2 = _platform.api2wire_u64(amount);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_add_output__static_method__Api(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtAddOutputStaticMethodApiConstMeta,
+      argValues: [psbtStr, script, amount],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtAddOutputStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_add_output__static_method__Api",
+        argNames: ["psbtStr", "script", "amount"],
+      );
+
+  Future<String> psbtRemoveOutputStaticMethodApi(
+      {required String psbtStr, required int index, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_u32(index);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_remove_output__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtRemoveOutputStaticMethodApiConstMeta,
+      argValues: [psbtStr, index],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kPsbtRemoveOutputStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "psbt_remove_output__static_method__Api",
+            argNames: ["psbtStr", "index"],
+          );
+
+  Future<String> psbtSetSequenceStaticMethodApi(
+      {required String psbtStr,
+      required int index,
+      required int sequence,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_u32(index);
+    var arg2 = api2wire_u32(sequence);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_set_sequence__static_method__Api(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtSetSequenceStaticMethodApiConstMeta,
+      argValues: [psbtStr, index, sequence],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtSetSequenceStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_set_sequence__static_method__Api",
+        argNames: ["psbtStr", "index", "sequence"],
+      );
+
+  Future<String> psbtSetLockTimeStaticMethodApi(
+      {required String psbtStr, required int lockTime, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_u32(lockTime);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_set_lock_time__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtSetLockTimeStaticMethodApiConstMeta,
+      argValues: [psbtStr, lockTime],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtSetLockTimeStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_set_lock_time__static_method__Api",
+        argNames: ["psbtStr", "lockTime"],
+      );
+
+  Future<String> psbtSetUnknownStaticMethodApi(
+      {required String psbtStr,
+      required PsbtMap map,
+      required int keyType,
+      required Uint8List key,
+      Uint8List? value,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = _platform.api2wire_box_autoadd_psbt_map(map);
+    var arg2 = api2wire_u8(keyType);
+    var arg3 = _platform.api2wire_uint_8_list(key);
+    var arg4 = _platform.api2wire_opt_uint_8_list(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_set_unknown__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtSetUnknownStaticMethodApiConstMeta,
+      argValues: [psbtStr, map, keyType, key, value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtSetUnknownStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_set_unknown__static_method__Api",
+        argNames: ["psbtStr", "map", "keyType", "key", "value"],
+      );
+
+  Future<String> psbtSetProprietaryStaticMethodApi(
+      {required String psbtStr,
+      required PsbtMap map,
+      required Uint8List prefix,
+      required int subtype,
+      required Uint8List key,
+      Uint8List? value,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = _platform.api2wire_box_autoadd_psbt_map(map);
+    var arg2 = _platform.api2wire_uint_8_list(prefix);
+    var arg3 = api2wire_u8(subtype);
+    var arg4 = _platform.api2wire_uint_8_list(key);
+    var arg5 = _platform.api2wire_opt_uint_8_list(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_set_proprietary__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4, arg5),
+      parseSuccessData: _wire2api_String,
+      constMeta: kPsbtSetProprietaryStaticMethodApiConstMeta,
+      argValues: [psbtStr, map, prefix, subtype, key, value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kPsbtSetProprietaryStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "psbt_set_proprietary__static_method__Api",
+            argNames: ["psbtStr", "map", "prefix", "subtype", "key", "value"],
+          );
+
+  Future<FinalizedPsbt> finalizePsbtWithDescriptorStaticMethodApi(
+      {required String psbtStr,
+      required String descriptor,
+      String? changeDescriptor,
+      required Network network,
+      required int lookahead,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = _platform.api2wire_String(descriptor);
+    var arg2 = _platform.api2wire_opt_String(changeDescriptor);
+    var arg3 = api2wire_network(network);
+    var arg4 = api2wire_u32(lookahead);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_finalize_psbt_with_descriptor__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_finalized_psbt,
+      constMeta: kFinalizePsbtWithDescriptorStaticMethodApiConstMeta,
+      argValues: [psbtStr, descriptor, changeDescriptor, network, lookahead],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kFinalizePsbtWithDescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "finalize_psbt_with_descriptor__static_method__Api",
+            argNames: [
+              "psbtStr",
+              "descriptor",
+              "changeDescriptor",
+              "network",
+              "lookahead"
+            ],
+          );
+
+  Future<List<PsbtInputInfo>> psbtInputsStaticMethodApi(
+      {required String psbtStr, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_psbt_inputs__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_list_psbt_input_info,
+      constMeta: kPsbtInputsStaticMethodApiConstMeta,
+      argValues: [psbtStr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtInputsStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_inputs__static_method__Api",
+        argNames: ["psbtStr"],
+      );
+
+  Future<List<PsbtOutputInfo>> psbtOutputsStaticMethodApi(
+      {required String psbtStr, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_network(network);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_psbt_outputs__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_psbt_output_info,
+      constMeta: kPsbtOutputsStaticMethodApiConstMeta,
+      argValues: [psbtStr, network],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPsbtOutputsStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "psbt_outputs__static_method__Api",
+        argNames: ["psbtStr", "network"],
+      );
+
   Future<String> jsonSerializeStaticMethodApi(
       {required String psbtStr, dynamic hint}) {
     var arg0 = _platform.api2wire_String(psbtStr);
@@ -577,30 +965,42 @@ class RustBdkFfiImpl implements RustBdkFfi {
         ],
       );
 
-  Future<BdkTxBuilderResult> bumpFeeTxBuilderFinishStaticMethodApi(
+  Future<BdkBumpFeeTxBuilderResult> bumpFeeTxBuilderFinishStaticMethodApi(
       {required String txid,
-      required double feeRate,
-      String? allowShrinking,
+      double? feeRate,
+      int? feeAbsolute,
+      ShrinkOutput? allowShrinking,
+      required List<OutPoint> utxos,
+      required List<OutPoint> unspendable,
+      required bool manuallySelectedOnly,
       required String walletId,
       required bool enableRbf,
       int? nSequence,
       dynamic hint}) {
     var arg0 = _platform.api2wire_String(txid);
-    var arg1 = api2wire_f32(feeRate);
-    var arg2 = _platform.api2wire_opt_String(allowShrinking);
-    var arg3 = _platform.api2wire_String(walletId);
-    var arg4 = enableRbf;
-    var arg5 = _platform.api2wire_opt_box_autoadd_u32(nSequence);
+    var arg1 = _platform.api2wire_opt_box_autoadd_f32(feeRate);
+    var arg2 = _platform.api2wire_opt_box_autoadd_u64(feeAbsolute);
+    var arg3 = _platform.api2wire_opt_box_autoadd_shrink_output(allowShrinking);
+    var arg4 = _platform.api2wire_list_out_point(utxos);
+    var arg5 = _platform.api2wire_list_out_point(unspendable);
+    var arg6 = manuallySelectedOnly;
+    var arg7 = _platform.api2wire_String(walletId);
+    var arg8 = enableRbf;
+    var arg9 = _platform.api2wire_opt_box_autoadd_u32(nSequence);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_bump_fee_tx_builder_finish__static_method__Api(
-              port_, arg0, arg1, arg2, arg3, arg4, arg5),
-      parseSuccessData: _wire2api_bdk_tx_builder_result,
+          .wire_bump_fee_tx_builder_finish__static_method__Api(port_, arg0,
+              arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9),
+      parseSuccessData: _wire2api_bdk_bump_fee_tx_builder_result,
       constMeta: kBumpFeeTxBuilderFinishStaticMethodApiConstMeta,
       argValues: [
         txid,
         feeRate,
+        feeAbsolute,
         allowShrinking,
+        utxos,
+        unspendable,
+        manuallySelectedOnly,
         walletId,
         enableRbf,
         nSequence
@@ -616,2467 +1016,5477 @@ class RustBdkFfiImpl implements RustBdkFfi {
             argNames: [
               "txid",
               "feeRate",
+              "feeAbsolute",
               "allowShrinking",
+              "utxos",
+              "unspendable",
+              "manuallySelectedOnly",
               "walletId",
               "enableRbf",
               "nSequence"
             ],
           );
 
-  Future<String> createDescriptorStaticMethodApi(
-      {required String descriptor, required Network network, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(descriptor);
-    var arg1 = api2wire_network(network);
+  Future<BdkTxBuilderResult> cancelTransactionStaticMethodApi(
+      {required String walletId,
+      required String txid,
+      required double feeRate,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(txid);
+    var arg2 = api2wire_f32(feeRate);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_create_descriptor__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_String,
-      constMeta: kCreateDescriptorStaticMethodApiConstMeta,
-      argValues: [descriptor, network],
+          .wire_cancel_transaction__static_method__Api(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_bdk_tx_builder_result,
+      constMeta: kCancelTransactionStaticMethodApiConstMeta,
+      argValues: [walletId, txid, feeRate],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kCreateDescriptorStaticMethodApiConstMeta =>
+      get kCancelTransactionStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "create_descriptor__static_method__Api",
-            argNames: ["descriptor", "network"],
+            debugName: "cancel_transaction__static_method__Api",
+            argNames: ["walletId", "txid", "feeRate"],
           );
 
-  Future<String> newBip44DescriptorStaticMethodApi(
-      {required KeychainKind keyChainKind,
-      required String secretKey,
-      required Network network,
-      dynamic hint}) {
-    var arg0 = api2wire_keychain_kind(keyChainKind);
-    var arg1 = _platform.api2wire_String(secretKey);
-    var arg2 = api2wire_network(network);
+  Future<PayjoinUri> parsePayjoinUriStaticMethodApi(
+      {required String uri, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(uri);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_new_bip44_descriptor__static_method__Api(
-              port_, arg0, arg1, arg2),
-      parseSuccessData: _wire2api_String,
-      constMeta: kNewBip44DescriptorStaticMethodApiConstMeta,
-      argValues: [keyChainKind, secretKey, network],
+          .wire_parse_payjoin_uri__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_payjoin_uri,
+      constMeta: kParsePayjoinUriStaticMethodApiConstMeta,
+      argValues: [uri],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kNewBip44DescriptorStaticMethodApiConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "new_bip44_descriptor__static_method__Api",
-            argNames: ["keyChainKind", "secretKey", "network"],
-          );
-
-  Future<String> newBip44PublicStaticMethodApi(
-      {required KeychainKind keyChainKind,
-      required String publicKey,
-      required Network network,
-      required String fingerprint,
-      dynamic hint}) {
-    var arg0 = api2wire_keychain_kind(keyChainKind);
-    var arg1 = _platform.api2wire_String(publicKey);
-    var arg2 = api2wire_network(network);
-    var arg3 = _platform.api2wire_String(fingerprint);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_new_bip44_public__static_method__Api(
-              port_, arg0, arg1, arg2, arg3),
-      parseSuccessData: _wire2api_String,
-      constMeta: kNewBip44PublicStaticMethodApiConstMeta,
-      argValues: [keyChainKind, publicKey, network, fingerprint],
-      hint: hint,
-    ));
-  }
-
-  FlutterRustBridgeTaskConstMeta get kNewBip44PublicStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kParsePayjoinUriStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "new_bip44_public__static_method__Api",
-        argNames: ["keyChainKind", "publicKey", "network", "fingerprint"],
+        debugName: "parse_payjoin_uri__static_method__Api",
+        argNames: ["uri"],
       );
 
-  Future<String> newBip49DescriptorStaticMethodApi(
-      {required KeychainKind keyChainKind,
-      required String secretKey,
-      required Network network,
+  Future<PayjoinRequest> payjoinSenderRequestStaticMethodApi(
+      {required String walletId,
+      required String uri,
+      required String psbtStr,
+      required PayjoinParams params,
+      SignOptions? signOptions,
       dynamic hint}) {
-    var arg0 = api2wire_keychain_kind(keyChainKind);
-    var arg1 = _platform.api2wire_String(secretKey);
-    var arg2 = api2wire_network(network);
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(uri);
+    var arg2 = _platform.api2wire_String(psbtStr);
+    var arg3 = _platform.api2wire_box_autoadd_payjoin_params(params);
+    var arg4 = _platform.api2wire_opt_box_autoadd_sign_options(signOptions);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_new_bip49_descriptor__static_method__Api(
-              port_, arg0, arg1, arg2),
-      parseSuccessData: _wire2api_String,
-      constMeta: kNewBip49DescriptorStaticMethodApiConstMeta,
-      argValues: [keyChainKind, secretKey, network],
+          .wire_payjoin_sender_request__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_payjoin_request,
+      constMeta: kPayjoinSenderRequestStaticMethodApiConstMeta,
+      argValues: [walletId, uri, psbtStr, params, signOptions],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kNewBip49DescriptorStaticMethodApiConstMeta =>
+      get kPayjoinSenderRequestStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "new_bip49_descriptor__static_method__Api",
-            argNames: ["keyChainKind", "secretKey", "network"],
+            debugName: "payjoin_sender_request__static_method__Api",
+            argNames: ["walletId", "uri", "psbtStr", "params", "signOptions"],
           );
 
-  Future<String> newBip49PublicStaticMethodApi(
-      {required KeychainKind keyChainKind,
-      required String publicKey,
-      required Network network,
-      required String fingerprint,
+  Future<String> payjoinSendStaticMethodApi(
+      {required String walletId,
+      required String uri,
+      required String psbtStr,
+      required PayjoinParams params,
+      SignOptions? signOptions,
       dynamic hint}) {
-    var arg0 = api2wire_keychain_kind(keyChainKind);
-    var arg1 = _platform.api2wire_String(publicKey);
-    var arg2 = api2wire_network(network);
-    var arg3 = _platform.api2wire_String(fingerprint);
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(uri);
+    var arg2 = _platform.api2wire_String(psbtStr);
+    var arg3 = _platform.api2wire_box_autoadd_payjoin_params(params);
+    var arg4 = _platform.api2wire_opt_box_autoadd_sign_options(signOptions);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_new_bip49_public__static_method__Api(
-              port_, arg0, arg1, arg2, arg3),
+      callFfi: (port_) => _platform.inner.wire_payjoin_send__static_method__Api(
+          port_, arg0, arg1, arg2, arg3, arg4),
       parseSuccessData: _wire2api_String,
-      constMeta: kNewBip49PublicStaticMethodApiConstMeta,
-      argValues: [keyChainKind, publicKey, network, fingerprint],
+      constMeta: kPayjoinSendStaticMethodApiConstMeta,
+      argValues: [walletId, uri, psbtStr, params, signOptions],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kNewBip49PublicStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kPayjoinSendStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "new_bip49_public__static_method__Api",
-        argNames: ["keyChainKind", "publicKey", "network", "fingerprint"],
+        debugName: "payjoin_send__static_method__Api",
+        argNames: ["walletId", "uri", "psbtStr", "params", "signOptions"],
       );
 
-  Future<String> newBip84DescriptorStaticMethodApi(
-      {required KeychainKind keyChainKind,
-      required String secretKey,
-      required Network network,
+  Future<String> payjoinSenderProcessResponseStaticMethodApi(
+      {required String walletId,
+      required String uri,
+      required String originalPsbt,
+      required PayjoinParams params,
+      required String response,
+      SignOptions? signOptions,
       dynamic hint}) {
-    var arg0 = api2wire_keychain_kind(keyChainKind);
-    var arg1 = _platform.api2wire_String(secretKey);
-    var arg2 = api2wire_network(network);
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(uri);
+    var arg2 = _platform.api2wire_String(originalPsbt);
+    var arg3 = _platform.api2wire_box_autoadd_payjoin_params(params);
+    var arg4 = _platform.api2wire_String(response);
+    var arg5 = _platform.api2wire_opt_box_autoadd_sign_options(signOptions);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_new_bip84_descriptor__static_method__Api(
-              port_, arg0, arg1, arg2),
+          .wire_payjoin_sender_process_response__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4, arg5),
       parseSuccessData: _wire2api_String,
-      constMeta: kNewBip84DescriptorStaticMethodApiConstMeta,
-      argValues: [keyChainKind, secretKey, network],
+      constMeta: kPayjoinSenderProcessResponseStaticMethodApiConstMeta,
+      argValues: [walletId, uri, originalPsbt, params, response, signOptions],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kNewBip84DescriptorStaticMethodApiConstMeta =>
+      get kPayjoinSenderProcessResponseStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "new_bip84_descriptor__static_method__Api",
-            argNames: ["keyChainKind", "secretKey", "network"],
+            debugName: "payjoin_sender_process_response__static_method__Api",
+            argNames: [
+              "walletId",
+              "uri",
+              "originalPsbt",
+              "params",
+              "response",
+              "signOptions"
+            ],
           );
 
-  Future<String> newBip84PublicStaticMethodApi(
-      {required KeychainKind keyChainKind,
-      required String publicKey,
-      required Network network,
-      required String fingerprint,
+  Future<String> payjoinReceiveStaticMethodApi(
+      {required String walletId,
+      required String originalPsbt,
+      required String query,
       dynamic hint}) {
-    var arg0 = api2wire_keychain_kind(keyChainKind);
-    var arg1 = _platform.api2wire_String(publicKey);
-    var arg2 = api2wire_network(network);
-    var arg3 = _platform.api2wire_String(fingerprint);
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(originalPsbt);
+    var arg2 = _platform.api2wire_String(query);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_new_bip84_public__static_method__Api(
-              port_, arg0, arg1, arg2, arg3),
+          .wire_payjoin_receive__static_method__Api(port_, arg0, arg1, arg2),
       parseSuccessData: _wire2api_String,
-      constMeta: kNewBip84PublicStaticMethodApiConstMeta,
-      argValues: [keyChainKind, publicKey, network, fingerprint],
+      constMeta: kPayjoinReceiveStaticMethodApiConstMeta,
+      argValues: [walletId, originalPsbt, query],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kNewBip84PublicStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kPayjoinReceiveStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "new_bip84_public__static_method__Api",
-        argNames: ["keyChainKind", "publicKey", "network", "fingerprint"],
+        debugName: "payjoin_receive__static_method__Api",
+        argNames: ["walletId", "originalPsbt", "query"],
       );
 
-  Future<String> asStringPrivateStaticMethodApi(
-      {required String descriptor, required Network network, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(descriptor);
-    var arg1 = api2wire_network(network);
+  Future<List<String>> psbtToUrStaticMethodApi(
+      {required String psbtStr,
+      required int maxFragmentLength,
+      required int startSeqNum,
+      required int count,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(psbtStr);
+    var arg1 = api2wire_u32(maxFragmentLength);
+    var arg2 = api2wire_u32(startSeqNum);
+    var arg3 = api2wire_u32(count);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_as_string_private__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_String,
-      constMeta: kAsStringPrivateStaticMethodApiConstMeta,
-      argValues: [descriptor, network],
+          .wire_psbt_to_ur__static_method__Api(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_StringList,
+      constMeta: kPsbtToUrStaticMethodApiConstMeta,
+      argValues: [psbtStr, maxFragmentLength, startSeqNum, count],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAsStringPrivateStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kPsbtToUrStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "as_string_private__static_method__Api",
-        argNames: ["descriptor", "network"],
+        debugName: "psbt_to_ur__static_method__Api",
+        argNames: ["psbtStr", "maxFragmentLength", "startSeqNum", "count"],
       );
 
-  Future<String> asStringStaticMethodApi(
+  Future<String> descriptorToUrStaticMethodApi(
       {required String descriptor, required Network network, dynamic hint}) {
     var arg0 = _platform.api2wire_String(descriptor);
     var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_as_string__static_method__Api(port_, arg0, arg1),
+      callFfi: (port_) => _platform.inner
+          .wire_descriptor_to_ur__static_method__Api(port_, arg0, arg1),
       parseSuccessData: _wire2api_String,
-      constMeta: kAsStringStaticMethodApiConstMeta,
+      constMeta: kDescriptorToUrStaticMethodApiConstMeta,
       argValues: [descriptor, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAsStringStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kDescriptorToUrStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "as_string__static_method__Api",
+        debugName: "descriptor_to_ur__static_method__Api",
         argNames: ["descriptor", "network"],
       );
 
-  Future<int> maxSatisfactionWeightStaticMethodApi(
-      {required String descriptor, required Network network, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(descriptor);
+  Future<String> descriptorsToUrAccountStaticMethodApi(
+      {required List<String> descriptors,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(descriptors);
     var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_max_satisfaction_weight__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_usize,
-      constMeta: kMaxSatisfactionWeightStaticMethodApiConstMeta,
-      argValues: [descriptor, network],
+          .wire_descriptors_to_ur_account__static_method__Api(
+              port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescriptorsToUrAccountStaticMethodApiConstMeta,
+      argValues: [descriptors, network],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kMaxSatisfactionWeightStaticMethodApiConstMeta =>
+      get kDescriptorsToUrAccountStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "max_satisfaction_weight__static_method__Api",
-            argNames: ["descriptor", "network"],
+            debugName: "descriptors_to_ur_account__static_method__Api",
+            argNames: ["descriptors", "network"],
           );
 
-  Future<String> createDescriptorSecretStaticMethodApi(
-      {required Network network,
-      required String mnemonic,
-      String? password,
-      dynamic hint}) {
+  Future<String> createUrDecoderStaticMethodApi(
+      {required Network network, dynamic hint}) {
     var arg0 = api2wire_network(network);
-    var arg1 = _platform.api2wire_String(mnemonic);
-    var arg2 = _platform.api2wire_opt_String(password);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_create_descriptor_secret__static_method__Api(
-              port_, arg0, arg1, arg2),
+          .wire_create_ur_decoder__static_method__Api(port_, arg0),
       parseSuccessData: _wire2api_String,
-      constMeta: kCreateDescriptorSecretStaticMethodApiConstMeta,
-      argValues: [network, mnemonic, password],
+      constMeta: kCreateUrDecoderStaticMethodApiConstMeta,
+      argValues: [network],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateUrDecoderStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_ur_decoder__static_method__Api",
+        argNames: ["network"],
+      );
+
+  Future<UrDecodeProgress> urDecoderReceiveStaticMethodApi(
+      {required String decoderId, required String part, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(decoderId);
+    var arg1 = _platform.api2wire_String(part);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_ur_decoder_receive__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_ur_decode_progress,
+      constMeta: kUrDecoderReceiveStaticMethodApiConstMeta,
+      argValues: [decoderId, part],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kCreateDescriptorSecretStaticMethodApiConstMeta =>
+      get kUrDecoderReceiveStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "create_descriptor_secret__static_method__Api",
-            argNames: ["network", "mnemonic", "password"],
+            debugName: "ur_decoder_receive__static_method__Api",
+            argNames: ["decoderId", "part"],
           );
 
-  Future<String> descriptorSecretFromStringStaticMethodApi(
-      {required String secret, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(secret);
+  Future<void> dropUrDecoderStaticMethodApi(
+      {required String decoderId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(decoderId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_drop_ur_decoder__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kDropUrDecoderStaticMethodApiConstMeta,
+      argValues: [decoderId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDropUrDecoderStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "drop_ur_decoder__static_method__Api",
+        argNames: ["decoderId"],
+      );
+
+  Future<String> createMultisigSessionStaticMethodApi(
+      {required String descriptor,
+      String? changeDescriptor,
+      required Network network,
+      required String psbtStr,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = _platform.api2wire_opt_String(changeDescriptor);
+    var arg2 = api2wire_network(network);
+    var arg3 = _platform.api2wire_String(psbtStr);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_descriptor_secret_from_string__static_method__Api(port_, arg0),
+          .wire_create_multisig_session__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_String,
-      constMeta: kDescriptorSecretFromStringStaticMethodApiConstMeta,
-      argValues: [secret],
+      constMeta: kCreateMultisigSessionStaticMethodApiConstMeta,
+      argValues: [descriptor, changeDescriptor, network, psbtStr],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kDescriptorSecretFromStringStaticMethodApiConstMeta =>
+      get kCreateMultisigSessionStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "descriptor_secret_from_string__static_method__Api",
-            argNames: ["secret"],
+            debugName: "create_multisig_session__static_method__Api",
+            argNames: ["descriptor", "changeDescriptor", "network", "psbtStr"],
           );
 
-  Future<String> extendDescriptorSecretStaticMethodApi(
-      {required String secret, required String path, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(secret);
-    var arg1 = _platform.api2wire_String(path);
+  Future<String> restoreMultisigSessionStaticMethodApi(
+      {required String data, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(data);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_extend_descriptor_secret__static_method__Api(port_, arg0, arg1),
+          .wire_restore_multisig_session__static_method__Api(port_, arg0),
       parseSuccessData: _wire2api_String,
-      constMeta: kExtendDescriptorSecretStaticMethodApiConstMeta,
-      argValues: [secret, path],
+      constMeta: kRestoreMultisigSessionStaticMethodApiConstMeta,
+      argValues: [data],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kExtendDescriptorSecretStaticMethodApiConstMeta =>
+      get kRestoreMultisigSessionStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "extend_descriptor_secret__static_method__Api",
-            argNames: ["secret", "path"],
+            debugName: "restore_multisig_session__static_method__Api",
+            argNames: ["data"],
           );
 
-  Future<String> deriveDescriptorSecretStaticMethodApi(
-      {required String secret, required String path, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(secret);
-    var arg1 = _platform.api2wire_String(path);
+  Future<String> exportMultisigSessionStaticMethodApi(
+      {required String sessionId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(sessionId);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_derive_descriptor_secret__static_method__Api(port_, arg0, arg1),
+          .wire_export_multisig_session__static_method__Api(port_, arg0),
       parseSuccessData: _wire2api_String,
-      constMeta: kDeriveDescriptorSecretStaticMethodApiConstMeta,
-      argValues: [secret, path],
+      constMeta: kExportMultisigSessionStaticMethodApiConstMeta,
+      argValues: [sessionId],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kDeriveDescriptorSecretStaticMethodApiConstMeta =>
+      get kExportMultisigSessionStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "derive_descriptor_secret__static_method__Api",
-            argNames: ["secret", "path"],
+            debugName: "export_multisig_session__static_method__Api",
+            argNames: ["sessionId"],
           );
 
-  Future<Uint8List> asSecretBytesStaticMethodApi(
-      {required String secret, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(secret);
+  Future<MultisigStatus> multisigSessionAddPsbtStaticMethodApi(
+      {required String sessionId, required String psbtStr, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(sessionId);
+    var arg1 = _platform.api2wire_String(psbtStr);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_as_secret_bytes__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_uint_8_list,
-      constMeta: kAsSecretBytesStaticMethodApiConstMeta,
-      argValues: [secret],
+      callFfi: (port_) => _platform.inner
+          .wire_multisig_session_add_psbt__static_method__Api(
+              port_, arg0, arg1),
+      parseSuccessData: _wire2api_multisig_status,
+      constMeta: kMultisigSessionAddPsbtStaticMethodApiConstMeta,
+      argValues: [sessionId, psbtStr],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAsSecretBytesStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "as_secret_bytes__static_method__Api",
-        argNames: ["secret"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kMultisigSessionAddPsbtStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "multisig_session_add_psbt__static_method__Api",
+            argNames: ["sessionId", "psbtStr"],
+          );
 
-  Future<String> asPublicStaticMethodApi(
-      {required String secret, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(secret);
+  Future<MultisigStatus> multisigSessionStatusStaticMethodApi(
+      {required String sessionId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(sessionId);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_as_public__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kAsPublicStaticMethodApiConstMeta,
-      argValues: [secret],
+      callFfi: (port_) => _platform.inner
+          .wire_multisig_session_status__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_multisig_status,
+      constMeta: kMultisigSessionStatusStaticMethodApiConstMeta,
+      argValues: [sessionId],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAsPublicStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "as_public__static_method__Api",
-        argNames: ["secret"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kMultisigSessionStatusStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "multisig_session_status__static_method__Api",
+            argNames: ["sessionId"],
+          );
 
-  Future<String> createDerivationPathStaticMethodApi(
-      {required String path, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(path);
+  Future<FinalizedPsbt> finalizeMultisigSessionStaticMethodApi(
+      {required String sessionId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(sessionId);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_create_derivation_path__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kCreateDerivationPathStaticMethodApiConstMeta,
-      argValues: [path],
+          .wire_finalize_multisig_session__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_finalized_psbt,
+      constMeta: kFinalizeMultisigSessionStaticMethodApiConstMeta,
+      argValues: [sessionId],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kCreateDerivationPathStaticMethodApiConstMeta =>
+      get kFinalizeMultisigSessionStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "create_derivation_path__static_method__Api",
-            argNames: ["path"],
+            debugName: "finalize_multisig_session__static_method__Api",
+            argNames: ["sessionId"],
           );
 
-  Future<String> descriptorPublicFromStringStaticMethodApi(
-      {required String publicKey, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(publicKey);
+  Future<void> dropMultisigSessionStaticMethodApi(
+      {required String sessionId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(sessionId);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_descriptor_public_from_string__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kDescriptorPublicFromStringStaticMethodApiConstMeta,
-      argValues: [publicKey],
+          .wire_drop_multisig_session__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kDropMultisigSessionStaticMethodApiConstMeta,
+      argValues: [sessionId],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kDescriptorPublicFromStringStaticMethodApiConstMeta =>
+      get kDropMultisigSessionStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "descriptor_public_from_string__static_method__Api",
-            argNames: ["publicKey"],
+            debugName: "drop_multisig_session__static_method__Api",
+            argNames: ["sessionId"],
           );
 
-  Future<String> createDescriptorPublicStaticMethodApi(
-      {String? xpub,
-      required String path,
-      required bool derive,
-      dynamic hint}) {
-    var arg0 = _platform.api2wire_opt_String(xpub);
-    var arg1 = _platform.api2wire_String(path);
-    var arg2 = derive;
+  Future<String> bsmsNewTokenStaticMethodApi(
+      {required BsmsEncryption encryption, dynamic hint}) {
+    var arg0 = api2wire_bsms_encryption(encryption);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_create_descriptor_public__static_method__Api(
-              port_, arg0, arg1, arg2),
+      callFfi: (port_) =>
+          _platform.inner.wire_bsms_new_token__static_method__Api(port_, arg0),
       parseSuccessData: _wire2api_String,
-      constMeta: kCreateDescriptorPublicStaticMethodApiConstMeta,
-      argValues: [xpub, path, derive],
+      constMeta: kBsmsNewTokenStaticMethodApiConstMeta,
+      argValues: [encryption],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kCreateDescriptorPublicStaticMethodApiConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "create_descriptor_public__static_method__Api",
-            argNames: ["xpub", "path", "derive"],
-          );
+  FlutterRustBridgeTaskConstMeta get kBsmsNewTokenStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "bsms_new_token__static_method__Api",
+        argNames: ["encryption"],
+      );
 
-  Future<Script> createScriptStaticMethodApi(
-      {required Uint8List rawOutputScript, dynamic hint}) {
-    var arg0 = _platform.api2wire_uint_8_list(rawOutputScript);
+  Future<String> bsmsKeyRecordStaticMethodApi(
+      {required String secretKey,
+      required String token,
+      required String description,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secretKey);
+    var arg1 = _platform.api2wire_String(token);
+    var arg2 = _platform.api2wire_String(description);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_create_script__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_script,
-      constMeta: kCreateScriptStaticMethodApiConstMeta,
-      argValues: [rawOutputScript],
+      callFfi: (port_) => _platform.inner
+          .wire_bsms_key_record__static_method__Api(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kBsmsKeyRecordStaticMethodApiConstMeta,
+      argValues: [secretKey, token, description],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kCreateScriptStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kBsmsKeyRecordStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "create_script__static_method__Api",
-        argNames: ["rawOutputScript"],
+        debugName: "bsms_key_record__static_method__Api",
+        argNames: ["secretKey", "token", "description"],
       );
 
-  Future<String> createAddressStaticMethodApi(
-      {required String address, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(address);
+  Future<BsmsKeyRecord> bsmsVerifyKeyRecordStaticMethodApi(
+      {required String record, required String token, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(record);
+    var arg1 = _platform.api2wire_String(token);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_create_address__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kCreateAddressStaticMethodApiConstMeta,
-      argValues: [address],
+      callFfi: (port_) => _platform.inner
+          .wire_bsms_verify_key_record__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bsms_key_record,
+      constMeta: kBsmsVerifyKeyRecordStaticMethodApiConstMeta,
+      argValues: [record, token],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kCreateAddressStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "create_address__static_method__Api",
-        argNames: ["address"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kBsmsVerifyKeyRecordStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "bsms_verify_key_record__static_method__Api",
+            argNames: ["record", "token"],
+          );
 
-  Future<String> addressFromScriptStaticMethodApi(
-      {required Script script, required Network network, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_script(script);
-    var arg1 = api2wire_network(network);
+  Future<String> bsmsDescriptorRecordStaticMethodApi(
+      {required int threshold,
+      required List<String> keys,
+      required MultisigScriptType scriptType,
+      required String token,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(threshold);
+    var arg1 = _platform.api2wire_StringList(keys);
+    var arg2 = api2wire_multisig_script_type(scriptType);
+    var arg3 = _platform.api2wire_String(token);
+    var arg4 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_address_from_script__static_method__Api(port_, arg0, arg1),
+          .wire_bsms_descriptor_record__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4),
       parseSuccessData: _wire2api_String,
-      constMeta: kAddressFromScriptStaticMethodApiConstMeta,
-      argValues: [script, network],
+      constMeta: kBsmsDescriptorRecordStaticMethodApiConstMeta,
+      argValues: [threshold, keys, scriptType, token, network],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kAddressFromScriptStaticMethodApiConstMeta =>
+      get kBsmsDescriptorRecordStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "address_from_script__static_method__Api",
-            argNames: ["script", "network"],
+            debugName: "bsms_descriptor_record__static_method__Api",
+            argNames: ["threshold", "keys", "scriptType", "token", "network"],
           );
 
-  Future<Script> addressToScriptPubkeyStaticMethodApi(
-      {required String address, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(address);
+  Future<BsmsDescriptorRecord> bsmsVerifyDescriptorRecordStaticMethodApi(
+      {required String record,
+      required String token,
+      required String secretKey,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(record);
+    var arg1 = _platform.api2wire_String(token);
+    var arg2 = _platform.api2wire_String(secretKey);
+    var arg3 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_address_to_script_pubkey__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_script,
-      constMeta: kAddressToScriptPubkeyStaticMethodApiConstMeta,
-      argValues: [address],
+          .wire_bsms_verify_descriptor_record__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_bsms_descriptor_record,
+      constMeta: kBsmsVerifyDescriptorRecordStaticMethodApiConstMeta,
+      argValues: [record, token, secretKey, network],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kAddressToScriptPubkeyStaticMethodApiConstMeta =>
+      get kBsmsVerifyDescriptorRecordStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "address_to_script_pubkey__static_method__Api",
-            argNames: ["address"],
+            debugName: "bsms_verify_descriptor_record__static_method__Api",
+            argNames: ["record", "token", "secretKey", "network"],
           );
 
-  Future<Payload> payloadStaticMethodApi(
-      {required String address, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(address);
+  Future<String> createDescriptorStaticMethodApi(
+      {required String descriptor, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_payload__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_payload,
-      constMeta: kPayloadStaticMethodApiConstMeta,
-      argValues: [address],
+      callFfi: (port_) => _platform.inner
+          .wire_create_descriptor__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kCreateDescriptorStaticMethodApiConstMeta,
+      argValues: [descriptor, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kPayloadStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "payload__static_method__Api",
-        argNames: ["address"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kCreateDescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "create_descriptor__static_method__Api",
+            argNames: ["descriptor", "network"],
+          );
 
-  Future<Network> addressNetworkStaticMethodApi(
-      {required String address, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(address);
+  Future<String> newBip44DescriptorStaticMethodApi(
+      {required KeychainKind keyChainKind,
+      required String secretKey,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = api2wire_keychain_kind(keyChainKind);
+    var arg1 = _platform.api2wire_String(secretKey);
+    var arg2 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_address_network__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_network,
-      constMeta: kAddressNetworkStaticMethodApiConstMeta,
-      argValues: [address],
+      callFfi: (port_) => _platform.inner
+          .wire_new_bip44_descriptor__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewBip44DescriptorStaticMethodApiConstMeta,
+      argValues: [keyChainKind, secretKey, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAddressNetworkStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "address_network__static_method__Api",
-        argNames: ["address"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kNewBip44DescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "new_bip44_descriptor__static_method__Api",
+            argNames: ["keyChainKind", "secretKey", "network"],
+          );
 
-  Future<String> createWalletStaticMethodApi(
-      {required String descriptor,
-      String? changeDescriptor,
+  Future<String> newBip44PublicStaticMethodApi(
+      {required KeychainKind keyChainKind,
+      required String publicKey,
       required Network network,
-      required DatabaseConfig databaseConfig,
+      required String fingerprint,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_String(descriptor);
-    var arg1 = _platform.api2wire_opt_String(changeDescriptor);
+    var arg0 = api2wire_keychain_kind(keyChainKind);
+    var arg1 = _platform.api2wire_String(publicKey);
     var arg2 = api2wire_network(network);
-    var arg3 = _platform.api2wire_box_autoadd_database_config(databaseConfig);
+    var arg3 = _platform.api2wire_String(fingerprint);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_create_wallet__static_method__Api(
+          .wire_new_bip44_public__static_method__Api(
               port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_String,
-      constMeta: kCreateWalletStaticMethodApiConstMeta,
-      argValues: [descriptor, changeDescriptor, network, databaseConfig],
+      constMeta: kNewBip44PublicStaticMethodApiConstMeta,
+      argValues: [keyChainKind, publicKey, network, fingerprint],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kCreateWalletStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kNewBip44PublicStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "create_wallet__static_method__Api",
-        argNames: [
-          "descriptor",
-          "changeDescriptor",
-          "network",
-          "databaseConfig"
-        ],
+        debugName: "new_bip44_public__static_method__Api",
+        argNames: ["keyChainKind", "publicKey", "network", "fingerprint"],
       );
 
-  Future<AddressInfo> getAddressStaticMethodApi(
-      {required String walletId,
-      required AddressIndex addressIndex,
+  Future<String> newBip49DescriptorStaticMethodApi(
+      {required KeychainKind keyChainKind,
+      required String secretKey,
+      required Network network,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = _platform.api2wire_box_autoadd_address_index(addressIndex);
+    var arg0 = api2wire_keychain_kind(keyChainKind);
+    var arg1 = _platform.api2wire_String(secretKey);
+    var arg2 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_get_address__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_address_info,
-      constMeta: kGetAddressStaticMethodApiConstMeta,
-      argValues: [walletId, addressIndex],
+          .wire_new_bip49_descriptor__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewBip49DescriptorStaticMethodApiConstMeta,
+      argValues: [keyChainKind, secretKey, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGetAddressStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "get_address__static_method__Api",
-        argNames: ["walletId", "addressIndex"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kNewBip49DescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "new_bip49_descriptor__static_method__Api",
+            argNames: ["keyChainKind", "secretKey", "network"],
+          );
 
-  Future<bool> isMineStaticMethodApi(
-      {required Script script, required String walletId, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_script(script);
-    var arg1 = _platform.api2wire_String(walletId);
+  Future<String> newBip49PublicStaticMethodApi(
+      {required KeychainKind keyChainKind,
+      required String publicKey,
+      required Network network,
+      required String fingerprint,
+      dynamic hint}) {
+    var arg0 = api2wire_keychain_kind(keyChainKind);
+    var arg1 = _platform.api2wire_String(publicKey);
+    var arg2 = api2wire_network(network);
+    var arg3 = _platform.api2wire_String(fingerprint);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_is_mine__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_bool,
-      constMeta: kIsMineStaticMethodApiConstMeta,
-      argValues: [script, walletId],
+      callFfi: (port_) => _platform.inner
+          .wire_new_bip49_public__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewBip49PublicStaticMethodApiConstMeta,
+      argValues: [keyChainKind, publicKey, network, fingerprint],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kIsMineStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kNewBip49PublicStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "is_mine__static_method__Api",
-        argNames: ["script", "walletId"],
+        debugName: "new_bip49_public__static_method__Api",
+        argNames: ["keyChainKind", "publicKey", "network", "fingerprint"],
       );
 
-  Future<AddressInfo> getInternalAddressStaticMethodApi(
-      {required String walletId,
-      required AddressIndex addressIndex,
+  Future<String> newBip84DescriptorStaticMethodApi(
+      {required KeychainKind keyChainKind,
+      required String secretKey,
+      required Network network,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = _platform.api2wire_box_autoadd_address_index(addressIndex);
+    var arg0 = api2wire_keychain_kind(keyChainKind);
+    var arg1 = _platform.api2wire_String(secretKey);
+    var arg2 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_get_internal_address__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_address_info,
-      constMeta: kGetInternalAddressStaticMethodApiConstMeta,
-      argValues: [walletId, addressIndex],
+          .wire_new_bip84_descriptor__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewBip84DescriptorStaticMethodApiConstMeta,
+      argValues: [keyChainKind, secretKey, network],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kGetInternalAddressStaticMethodApiConstMeta =>
+      get kNewBip84DescriptorStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "get_internal_address__static_method__Api",
-            argNames: ["walletId", "addressIndex"],
+            debugName: "new_bip84_descriptor__static_method__Api",
+            argNames: ["keyChainKind", "secretKey", "network"],
           );
 
-  Future<void> syncWalletStaticMethodApi(
-      {required String walletId, required String blockchainId, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = _platform.api2wire_String(blockchainId);
+  Future<String> newBip84PublicStaticMethodApi(
+      {required KeychainKind keyChainKind,
+      required String publicKey,
+      required Network network,
+      required String fingerprint,
+      dynamic hint}) {
+    var arg0 = api2wire_keychain_kind(keyChainKind);
+    var arg1 = _platform.api2wire_String(publicKey);
+    var arg2 = api2wire_network(network);
+    var arg3 = _platform.api2wire_String(fingerprint);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_sync_wallet__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      constMeta: kSyncWalletStaticMethodApiConstMeta,
-      argValues: [walletId, blockchainId],
+          .wire_new_bip84_public__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewBip84PublicStaticMethodApiConstMeta,
+      argValues: [keyChainKind, publicKey, network, fingerprint],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kSyncWalletStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kNewBip84PublicStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "sync_wallet__static_method__Api",
-        argNames: ["walletId", "blockchainId"],
+        debugName: "new_bip84_public__static_method__Api",
+        argNames: ["keyChainKind", "publicKey", "network", "fingerprint"],
       );
 
-  Future<Balance> getBalanceStaticMethodApi(
-      {required String walletId, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
+  Future<String> newSingleKeyDescriptorStaticMethodApi(
+      {required DescriptorType scriptType,
+      required String secretKey,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = api2wire_descriptor_type(scriptType);
+    var arg1 = _platform.api2wire_String(secretKey);
+    var arg2 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_get_balance__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_balance,
-      constMeta: kGetBalanceStaticMethodApiConstMeta,
-      argValues: [walletId],
+      callFfi: (port_) => _platform.inner
+          .wire_new_single_key_descriptor__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewSingleKeyDescriptorStaticMethodApiConstMeta,
+      argValues: [scriptType, secretKey, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGetBalanceStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "get_balance__static_method__Api",
-        argNames: ["walletId"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kNewSingleKeyDescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "new_single_key_descriptor__static_method__Api",
+            argNames: ["scriptType", "secretKey", "network"],
+          );
 
-  Future<List<LocalUtxo>> listUnspentOutputsStaticMethodApi(
-      {required String walletId, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
+  Future<String> newSingleKeyPublicStaticMethodApi(
+      {required DescriptorType scriptType,
+      required String publicKey,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = api2wire_descriptor_type(scriptType);
+    var arg1 = _platform.api2wire_String(publicKey);
+    var arg2 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_list_unspent_outputs__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_list_local_utxo,
-      constMeta: kListUnspentOutputsStaticMethodApiConstMeta,
-      argValues: [walletId],
+          .wire_new_single_key_public__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kNewSingleKeyPublicStaticMethodApiConstMeta,
+      argValues: [scriptType, publicKey, network],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kListUnspentOutputsStaticMethodApiConstMeta =>
+      get kNewSingleKeyPublicStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "list_unspent_outputs__static_method__Api",
-            argNames: ["walletId"],
+            debugName: "new_single_key_public__static_method__Api",
+            argNames: ["scriptType", "publicKey", "network"],
           );
 
-  Future<List<TransactionDetails>> getTransactionsStaticMethodApi(
-      {required String walletId, required bool includeRaw, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = includeRaw;
+  Future<String> asStringPrivateStaticMethodApi(
+      {required String descriptor, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_get_transactions__static_method__Api(port_, arg0, arg1),
-      parseSuccessData: _wire2api_list_transaction_details,
-      constMeta: kGetTransactionsStaticMethodApiConstMeta,
-      argValues: [walletId, includeRaw],
+          .wire_as_string_private__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kAsStringPrivateStaticMethodApiConstMeta,
+      argValues: [descriptor, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGetTransactionsStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kAsStringPrivateStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "get_transactions__static_method__Api",
-        argNames: ["walletId", "includeRaw"],
+        debugName: "as_string_private__static_method__Api",
+        argNames: ["descriptor", "network"],
       );
 
-  Future<String?> signStaticMethodApi(
-      {required String walletId,
-      required String psbtStr,
-      SignOptions? signOptions,
-      dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = _platform.api2wire_String(psbtStr);
-    var arg2 = _platform.api2wire_opt_box_autoadd_sign_options(signOptions);
+  Future<String> asStringStaticMethodApi(
+      {required String descriptor, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_sign__static_method__Api(port_, arg0, arg1, arg2),
-      parseSuccessData: _wire2api_opt_String,
-      constMeta: kSignStaticMethodApiConstMeta,
-      argValues: [walletId, psbtStr, signOptions],
+      callFfi: (port_) =>
+          _platform.inner.wire_as_string__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kAsStringStaticMethodApiConstMeta,
+      argValues: [descriptor, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kSignStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kAsStringStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "sign__static_method__Api",
-        argNames: ["walletId", "psbtStr", "signOptions"],
+        debugName: "as_string__static_method__Api",
+        argNames: ["descriptor", "network"],
       );
 
-  Future<Network> walletNetworkStaticMethodApi(
-      {required String walletId, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
+  Future<int> maxSatisfactionWeightStaticMethodApi(
+      {required String descriptor, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_wallet_network__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_network,
-      constMeta: kWalletNetworkStaticMethodApiConstMeta,
-      argValues: [walletId],
+      callFfi: (port_) => _platform.inner
+          .wire_max_satisfaction_weight__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_usize,
+      constMeta: kMaxSatisfactionWeightStaticMethodApiConstMeta,
+      argValues: [descriptor, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kWalletNetworkStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "wallet_network__static_method__Api",
-        argNames: ["walletId"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kMaxSatisfactionWeightStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "max_satisfaction_weight__static_method__Api",
+            argNames: ["descriptor", "network"],
+          );
 
-  Future<List<LocalUtxo>> listUnspentStaticMethodApi(
-      {required String walletId, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
+  Future<MultisigDescriptors> newMultisigDescriptorStaticMethodApi(
+      {required int threshold,
+      required List<String> publicKeys,
+      String? secretKey,
+      required MultisigScriptType scriptType,
+      required bool sorted,
+      required Network network,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(threshold);
+    var arg1 = _platform.api2wire_StringList(publicKeys);
+    var arg2 = _platform.api2wire_opt_String(secretKey);
+    var arg3 = api2wire_multisig_script_type(scriptType);
+    var arg4 = sorted;
+    var arg5 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_list_unspent__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_list_local_utxo,
-      constMeta: kListUnspentStaticMethodApiConstMeta,
-      argValues: [walletId],
+      callFfi: (port_) => _platform.inner
+          .wire_new_multisig_descriptor__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4, arg5),
+      parseSuccessData: _wire2api_multisig_descriptors,
+      constMeta: kNewMultisigDescriptorStaticMethodApiConstMeta,
+      argValues: [
+        threshold,
+        publicKeys,
+        secretKey,
+        scriptType,
+        sorted,
+        network
+      ],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kListUnspentStaticMethodApiConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "list_unspent__static_method__Api",
-        argNames: ["walletId"],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kNewMultisigDescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "new_multisig_descriptor__static_method__Api",
+            argNames: [
+              "threshold",
+              "publicKeys",
+              "secretKey",
+              "scriptType",
+              "sorted",
+              "network"
+            ],
+          );
 
-  Future<String> getPsbtInputStaticMethodApi(
-      {required String walletId,
-      required LocalUtxo utxo,
-      required bool onlyWitnessUtxo,
-      PsbtSigHashType? psbtSighashType,
+  Future<CompiledPolicy> compilePolicyStaticMethodApi(
+      {required String policy,
+      required List<PolicyKey> keys,
+      required PolicyContext context,
+      required Network network,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = _platform.api2wire_box_autoadd_local_utxo(utxo);
-    var arg2 = onlyWitnessUtxo;
-    var arg3 =
-        _platform.api2wire_opt_box_autoadd_psbt_sig_hash_type(psbtSighashType);
+    var arg0 = _platform.api2wire_String(policy);
+    var arg1 = _platform.api2wire_list_policy_key(keys);
+    var arg2 = api2wire_policy_context(context);
+    var arg3 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_get_psbt_input__static_method__Api(
+          .wire_compile_policy__static_method__Api(
               port_, arg0, arg1, arg2, arg3),
-      parseSuccessData: _wire2api_String,
-      constMeta: kGetPsbtInputStaticMethodApiConstMeta,
-      argValues: [walletId, utxo, onlyWitnessUtxo, psbtSighashType],
+      parseSuccessData: _wire2api_compiled_policy,
+      constMeta: kCompilePolicyStaticMethodApiConstMeta,
+      argValues: [policy, keys, context, network],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGetPsbtInputStaticMethodApiConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kCompilePolicyStaticMethodApiConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "get_psbt_input__static_method__Api",
-        argNames: ["walletId", "utxo", "onlyWitnessUtxo", "psbtSighashType"],
+        debugName: "compile_policy__static_method__Api",
+        argNames: ["policy", "keys", "context", "network"],
       );
 
-  Future<DescNetwork> getDescriptorForKeychainStaticMethodApi(
-      {required String walletId,
-      required KeychainKind keychain,
+  Future<List<DerivedAddress>> deriveAddressesStaticMethodApi(
+      {required String descriptor,
+      required Network network,
+      required int start,
+      required int count,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_String(walletId);
-    var arg1 = api2wire_keychain_kind(keychain);
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = api2wire_network(network);
+    var arg2 = api2wire_u32(start);
+    var arg3 = api2wire_u32(count);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_get_descriptor_for_keychain__static_method__Api(
-              port_, arg0, arg1),
-      parseSuccessData: _wire2api_desc_network,
-      constMeta: kGetDescriptorForKeychainStaticMethodApiConstMeta,
-      argValues: [walletId, keychain],
+          .wire_derive_addresses__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_list_derived_address,
+      constMeta: kDeriveAddressesStaticMethodApiConstMeta,
+      argValues: [descriptor, network, start, count],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kGetDescriptorForKeychainStaticMethodApiConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "get_descriptor_for_keychain__static_method__Api",
-            argNames: ["walletId", "keychain"],
-          );
+  FlutterRustBridgeTaskConstMeta get kDeriveAddressesStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "derive_addresses__static_method__Api",
+        argNames: ["descriptor", "network", "start", "count"],
+      );
 
-  Future<String> generateSeedFromWordCountStaticMethodApi(
-      {required WordCount wordCount, dynamic hint}) {
-    var arg0 = api2wire_word_count(wordCount);
+  Future<DescriptorInfo> descriptorInfoStaticMethodApi(
+      {required String descriptor, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_generate_seed_from_word_count__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kGenerateSeedFromWordCountStaticMethodApiConstMeta,
-      argValues: [wordCount],
+      callFfi: (port_) =>
+          _platform.inner.wire_descriptor_info__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_descriptor_info,
+      constMeta: kDescriptorInfoStaticMethodApiConstMeta,
+      argValues: [descriptor],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kGenerateSeedFromWordCountStaticMethodApiConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "generate_seed_from_word_count__static_method__Api",
-            argNames: ["wordCount"],
-          );
+  FlutterRustBridgeTaskConstMeta get kDescriptorInfoStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "descriptor_info__static_method__Api",
+        argNames: ["descriptor"],
+      );
 
-  Future<String> generateSeedFromStringStaticMethodApi(
-      {required String mnemonic, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(mnemonic);
+  Future<List<ImportedWallet>> importWalletStaticMethodApi(
+      {required String data, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(data);
+    var arg1 = api2wire_network(network);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_generate_seed_from_string__static_method__Api(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kGenerateSeedFromStringStaticMethodApiConstMeta,
-      argValues: [mnemonic],
+          .wire_import_wallet__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_imported_wallet,
+      constMeta: kImportWalletStaticMethodApiConstMeta,
+      argValues: [data, network],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kImportWalletStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "import_wallet__static_method__Api",
+        argNames: ["data", "network"],
+      );
+
+  Future<String> createDescriptorSecretStaticMethodApi(
+      {required Network network,
+      required String mnemonic,
+      String? password,
+      dynamic hint}) {
+    var arg0 = api2wire_network(network);
+    var arg1 = _platform.api2wire_String(mnemonic);
+    var arg2 = _platform.api2wire_opt_String(password);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_create_descriptor_secret__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kCreateDescriptorSecretStaticMethodApiConstMeta,
+      argValues: [network, mnemonic, password],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kGenerateSeedFromStringStaticMethodApiConstMeta =>
+      get kCreateDescriptorSecretStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "generate_seed_from_string__static_method__Api",
-            argNames: ["mnemonic"],
+            debugName: "create_descriptor_secret__static_method__Api",
+            argNames: ["network", "mnemonic", "password"],
           );
 
-  Future<String> generateSeedFromEntropyStaticMethodApi(
-      {required Uint8List entropy, dynamic hint}) {
-    var arg0 = _platform.api2wire_uint_8_list(entropy);
+  Future<String> descriptorSecretFromStringStaticMethodApi(
+      {required String secret, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_generate_seed_from_entropy__static_method__Api(port_, arg0),
+          .wire_descriptor_secret_from_string__static_method__Api(port_, arg0),
       parseSuccessData: _wire2api_String,
-      constMeta: kGenerateSeedFromEntropyStaticMethodApiConstMeta,
-      argValues: [entropy],
+      constMeta: kDescriptorSecretFromStringStaticMethodApiConstMeta,
+      argValues: [secret],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kGenerateSeedFromEntropyStaticMethodApiConstMeta =>
+      get kDescriptorSecretFromStringStaticMethodApiConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "generate_seed_from_entropy__static_method__Api",
-            argNames: ["entropy"],
+            debugName: "descriptor_secret_from_string__static_method__Api",
+            argNames: ["secret"],
           );
 
-  void dispose() {
-    _platform.dispose();
+  Future<String> descriptorSecretFromSingleStaticMethodApi(
+      {required String secret, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
+    var arg1 = api2wire_network(network);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_descriptor_secret_from_single__static_method__Api(
+              port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescriptorSecretFromSingleStaticMethodApiConstMeta,
+      argValues: [secret, network],
+      hint: hint,
+    ));
   }
-// Section: wire2api
 
-  String _wire2api_String(dynamic raw) {
-    return raw as String;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorSecretFromSingleStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "descriptor_secret_from_single__static_method__Api",
+            argNames: ["secret", "network"],
+          );
 
-  List<String> _wire2api_StringList(dynamic raw) {
-    return (raw as List<dynamic>).cast<String>();
+  Future<String> extendDescriptorSecretStaticMethodApi(
+      {required String secret, required String path, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
+    var arg1 = _platform.api2wire_String(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_extend_descriptor_secret__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kExtendDescriptorSecretStaticMethodApiConstMeta,
+      argValues: [secret, path],
+      hint: hint,
+    ));
   }
 
-  AddressInfo _wire2api_address_info(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return AddressInfo(
-      index: _wire2api_u32(arr[0]),
-      address: _wire2api_String(arr[1]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kExtendDescriptorSecretStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "extend_descriptor_secret__static_method__Api",
+            argNames: ["secret", "path"],
+          );
 
-  Balance _wire2api_balance(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return Balance(
-      immature: _wire2api_u64(arr[0]),
-      trustedPending: _wire2api_u64(arr[1]),
-      untrustedPending: _wire2api_u64(arr[2]),
-      confirmed: _wire2api_u64(arr[3]),
-      spendable: _wire2api_u64(arr[4]),
-      total: _wire2api_u64(arr[5]),
-    );
+  Future<String> deriveDescriptorSecretStaticMethodApi(
+      {required String secret, required String path, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
+    var arg1 = _platform.api2wire_String(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_derive_descriptor_secret__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDeriveDescriptorSecretStaticMethodApiConstMeta,
+      argValues: [secret, path],
+      hint: hint,
+    ));
   }
 
-  BdkTxBuilderResult _wire2api_bdk_tx_builder_result(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return BdkTxBuilderResult(
-      field0: _wire2api_String(arr[0]),
-      field1: _wire2api_transaction_details(arr[1]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kDeriveDescriptorSecretStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "derive_descriptor_secret__static_method__Api",
+            argNames: ["secret", "path"],
+          );
 
-  BlockTime _wire2api_block_time(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return BlockTime(
-      height: _wire2api_u32(arr[0]),
-      timestamp: _wire2api_u64(arr[1]),
-    );
+  Future<Uint8List> asSecretBytesStaticMethodApi(
+      {required String secret, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_as_secret_bytes__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_uint_8_list,
+      constMeta: kAsSecretBytesStaticMethodApiConstMeta,
+      argValues: [secret],
+      hint: hint,
+    ));
   }
 
-  bool _wire2api_bool(dynamic raw) {
-    return raw as bool;
-  }
+  FlutterRustBridgeTaskConstMeta get kAsSecretBytesStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "as_secret_bytes__static_method__Api",
+        argNames: ["secret"],
+      );
 
-  BlockTime _wire2api_box_autoadd_block_time(dynamic raw) {
-    return _wire2api_block_time(raw);
+  Future<String> asPublicStaticMethodApi(
+      {required String secret, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_as_public__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kAsPublicStaticMethodApiConstMeta,
+      argValues: [secret],
+      hint: hint,
+    ));
   }
 
-  double _wire2api_box_autoadd_f32(dynamic raw) {
-    return raw as double;
-  }
+  FlutterRustBridgeTaskConstMeta get kAsPublicStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "as_public__static_method__Api",
+        argNames: ["secret"],
+      );
 
-  int _wire2api_box_autoadd_u64(dynamic raw) {
-    return _wire2api_u64(raw);
+  Future<String> createDerivationPathStaticMethodApi(
+      {required String path, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_create_derivation_path__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kCreateDerivationPathStaticMethodApiConstMeta,
+      argValues: [path],
+      hint: hint,
+    ));
   }
 
-  DescNetwork _wire2api_desc_network(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DescNetwork(
-      field0: _wire2api_String(arr[0]),
-      field1: _wire2api_network(arr[1]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kCreateDerivationPathStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "create_derivation_path__static_method__Api",
+            argNames: ["path"],
+          );
 
-  double _wire2api_f32(dynamic raw) {
-    return raw as double;
+  Future<KeyMetadata> descriptorSecretMetadataStaticMethodApi(
+      {required String secret, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(secret);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_descriptor_secret_metadata__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_key_metadata,
+      constMeta: kDescriptorSecretMetadataStaticMethodApiConstMeta,
+      argValues: [secret],
+      hint: hint,
+    ));
   }
 
-  int _wire2api_i32(dynamic raw) {
-    return raw as int;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorSecretMetadataStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "descriptor_secret_metadata__static_method__Api",
+            argNames: ["secret"],
+          );
 
-  KeychainKind _wire2api_keychain_kind(dynamic raw) {
-    return KeychainKind.values[raw as int];
+  Future<KeyMetadata> descriptorPublicMetadataStaticMethodApi(
+      {required String publicKey, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(publicKey);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_descriptor_public_metadata__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_key_metadata,
+      constMeta: kDescriptorPublicMetadataStaticMethodApiConstMeta,
+      argValues: [publicKey],
+      hint: hint,
+    ));
   }
 
-  List<LocalUtxo> _wire2api_list_local_utxo(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_local_utxo).toList();
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorPublicMetadataStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "descriptor_public_metadata__static_method__Api",
+            argNames: ["publicKey"],
+          );
 
-  List<TransactionDetails> _wire2api_list_transaction_details(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_transaction_details).toList();
+  Future<String> descriptorPublicFromStringStaticMethodApi(
+      {required String publicKey, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(publicKey);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_descriptor_public_from_string__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescriptorPublicFromStringStaticMethodApiConstMeta,
+      argValues: [publicKey],
+      hint: hint,
+    ));
   }
 
-  List<TxIn> _wire2api_list_tx_in(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_tx_in).toList();
-  }
-
-  List<TxOut> _wire2api_list_tx_out(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_tx_out).toList();
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorPublicFromStringStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "descriptor_public_from_string__static_method__Api",
+            argNames: ["publicKey"],
+          );
 
-  LocalUtxo _wire2api_local_utxo(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return LocalUtxo(
-      outpoint: _wire2api_out_point(arr[0]),
-      txout: _wire2api_tx_out(arr[1]),
-      isSpent: _wire2api_bool(arr[2]),
-      keychain: _wire2api_keychain_kind(arr[3]),
-    );
+  Future<String> createDescriptorPublicStaticMethodApi(
+      {String? xpub,
+      required String path,
+      required bool derive,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_opt_String(xpub);
+    var arg1 = _platform.api2wire_String(path);
+    var arg2 = derive;
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_create_descriptor_public__static_method__Api(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kCreateDescriptorPublicStaticMethodApiConstMeta,
+      argValues: [xpub, path, derive],
+      hint: hint,
+    ));
   }
 
-  Network _wire2api_network(dynamic raw) {
-    return Network.values[raw as int];
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kCreateDescriptorPublicStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "create_descriptor_public__static_method__Api",
+            argNames: ["xpub", "path", "derive"],
+          );
 
-  String? _wire2api_opt_String(dynamic raw) {
-    return raw == null ? null : _wire2api_String(raw);
+  Future<String> slip132ToStandardStaticMethodApi(
+      {required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_slip132_to_standard__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kSlip132ToStandardStaticMethodApiConstMeta,
+      argValues: [key],
+      hint: hint,
+    ));
   }
 
-  BlockTime? _wire2api_opt_box_autoadd_block_time(dynamic raw) {
-    return raw == null ? null : _wire2api_box_autoadd_block_time(raw);
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kSlip132ToStandardStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "slip132_to_standard__static_method__Api",
+            argNames: ["key"],
+          );
 
-  double? _wire2api_opt_box_autoadd_f32(dynamic raw) {
-    return raw == null ? null : _wire2api_box_autoadd_f32(raw);
+  Future<String> standardToSlip132StaticMethodApi(
+      {required String key, required Slip132Version version, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    var arg1 = api2wire_slip_132_version(version);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_standard_to_slip132__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kStandardToSlip132StaticMethodApiConstMeta,
+      argValues: [key, version],
+      hint: hint,
+    ));
   }
 
-  int? _wire2api_opt_box_autoadd_u64(dynamic raw) {
-    return raw == null ? null : _wire2api_box_autoadd_u64(raw);
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kStandardToSlip132StaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "standard_to_slip132__static_method__Api",
+            argNames: ["key", "version"],
+          );
 
-  OutPoint _wire2api_out_point(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return OutPoint(
-      txid: _wire2api_String(arr[0]),
-      vout: _wire2api_u32(arr[1]),
-    );
+  Future<Slip132Template> slip132TemplateStaticMethodApi(
+      {required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_slip132_template__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_slip_132_template,
+      constMeta: kSlip132TemplateStaticMethodApiConstMeta,
+      argValues: [key],
+      hint: hint,
+    ));
   }
 
-  Payload _wire2api_payload(dynamic raw) {
-    switch (raw[0]) {
-      case 0:
-        return Payload_PubkeyHash(
-          pubkeyHash: _wire2api_uint_8_list(raw[1]),
-        );
-      case 1:
-        return Payload_ScriptHash(
-          scriptHash: _wire2api_uint_8_list(raw[1]),
-        );
-      case 2:
-        return Payload_WitnessProgram(
-          version: _wire2api_witness_version(raw[1]),
-          program: _wire2api_uint_8_list(raw[2]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
-  }
+  FlutterRustBridgeTaskConstMeta get kSlip132TemplateStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "slip132_template__static_method__Api",
+        argNames: ["key"],
+      );
 
-  Script _wire2api_script(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return Script(
-      internal: _wire2api_uint_8_list(arr[0]),
-    );
+  Future<Script> createScriptStaticMethodApi(
+      {required Uint8List rawOutputScript, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(rawOutputScript);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_create_script__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_script,
+      constMeta: kCreateScriptStaticMethodApiConstMeta,
+      argValues: [rawOutputScript],
+      hint: hint,
+    ));
   }
 
-  TransactionDetails _wire2api_transaction_details(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return TransactionDetails(
-      serializedTx: _wire2api_opt_String(arr[0]),
-      txid: _wire2api_String(arr[1]),
-      received: _wire2api_u64(arr[2]),
-      sent: _wire2api_u64(arr[3]),
-      fee: _wire2api_opt_box_autoadd_u64(arr[4]),
-      confirmationTime: _wire2api_opt_box_autoadd_block_time(arr[5]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kCreateScriptStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_script__static_method__Api",
+        argNames: ["rawOutputScript"],
+      );
 
-  TxIn _wire2api_tx_in(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return TxIn(
-      previousOutput: _wire2api_out_point(arr[0]),
-      scriptSig: _wire2api_script(arr[1]),
-      sequence: _wire2api_u32(arr[2]),
-      witness: _wire2api_StringList(arr[3]),
-    );
+  Future<String> createAddressStaticMethodApi(
+      {required String address, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(address);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_create_address__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kCreateAddressStaticMethodApiConstMeta,
+      argValues: [address],
+      hint: hint,
+    ));
   }
 
-  TxOut _wire2api_tx_out(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return TxOut(
-      value: _wire2api_u64(arr[0]),
-      scriptPubkey: _wire2api_script(arr[1]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kCreateAddressStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_address__static_method__Api",
+        argNames: ["address"],
+      );
 
-  int _wire2api_u32(dynamic raw) {
-    return raw as int;
+  Future<String> addressFromScriptStaticMethodApi(
+      {required Script script, required Network network, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_script(script);
+    var arg1 = api2wire_network(network);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_address_from_script__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kAddressFromScriptStaticMethodApiConstMeta,
+      argValues: [script, network],
+      hint: hint,
+    ));
   }
 
-  int _wire2api_u64(dynamic raw) {
-    return castInt(raw);
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kAddressFromScriptStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "address_from_script__static_method__Api",
+            argNames: ["script", "network"],
+          );
 
-  int _wire2api_u8(dynamic raw) {
-    return raw as int;
+  Future<Script> addressToScriptPubkeyStaticMethodApi(
+      {required String address, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(address);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_address_to_script_pubkey__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_script,
+      constMeta: kAddressToScriptPubkeyStaticMethodApiConstMeta,
+      argValues: [address],
+      hint: hint,
+    ));
   }
 
-  Uint8List _wire2api_uint_8_list(dynamic raw) {
-    return raw as Uint8List;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kAddressToScriptPubkeyStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "address_to_script_pubkey__static_method__Api",
+            argNames: ["address"],
+          );
 
-  void _wire2api_unit(dynamic raw) {
-    return;
+  Future<Payload> payloadStaticMethodApi(
+      {required String address, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(address);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_payload__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_payload,
+      constMeta: kPayloadStaticMethodApiConstMeta,
+      argValues: [address],
+      hint: hint,
+    ));
   }
 
-  int _wire2api_usize(dynamic raw) {
-    return castInt(raw);
-  }
+  FlutterRustBridgeTaskConstMeta get kPayloadStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "payload__static_method__Api",
+        argNames: ["address"],
+      );
 
-  WitnessVersion _wire2api_witness_version(dynamic raw) {
-    return WitnessVersion.values[raw as int];
+  Future<Network> addressNetworkStaticMethodApi(
+      {required String address, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(address);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_address_network__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_network,
+      constMeta: kAddressNetworkStaticMethodApiConstMeta,
+      argValues: [address],
+      hint: hint,
+    ));
   }
-}
+
+  FlutterRustBridgeTaskConstMeta get kAddressNetworkStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "address_network__static_method__Api",
+        argNames: ["address"],
+      );
+
+  Future<String> createWalletStaticMethodApi(
+      {required String descriptor,
+      String? changeDescriptor,
+      required Network network,
+      required DatabaseConfig databaseConfig,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(descriptor);
+    var arg1 = _platform.api2wire_opt_String(changeDescriptor);
+    var arg2 = api2wire_network(network);
+    var arg3 = _platform.api2wire_box_autoadd_database_config(databaseConfig);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_create_wallet__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kCreateWalletStaticMethodApiConstMeta,
+      argValues: [descriptor, changeDescriptor, network, databaseConfig],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateWalletStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_wallet__static_method__Api",
+        argNames: [
+          "descriptor",
+          "changeDescriptor",
+          "network",
+          "databaseConfig"
+        ],
+      );
+
+  Future<AddressInfo> getAddressStaticMethodApi(
+      {required String walletId,
+      required AddressIndex addressIndex,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_box_autoadd_address_index(addressIndex);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_get_address__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_address_info,
+      constMeta: kGetAddressStaticMethodApiConstMeta,
+      argValues: [walletId, addressIndex],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetAddressStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_address__static_method__Api",
+        argNames: ["walletId", "addressIndex"],
+      );
+
+  Future<bool> isMineStaticMethodApi(
+      {required Script script, required String walletId, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_script(script);
+    var arg1 = _platform.api2wire_String(walletId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_is_mine__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bool,
+      constMeta: kIsMineStaticMethodApiConstMeta,
+      argValues: [script, walletId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIsMineStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "is_mine__static_method__Api",
+        argNames: ["script", "walletId"],
+      );
+
+  Future<AddressInfo> getInternalAddressStaticMethodApi(
+      {required String walletId,
+      required AddressIndex addressIndex,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_box_autoadd_address_index(addressIndex);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_get_internal_address__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_address_info,
+      constMeta: kGetInternalAddressStaticMethodApiConstMeta,
+      argValues: [walletId, addressIndex],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGetInternalAddressStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "get_internal_address__static_method__Api",
+            argNames: ["walletId", "addressIndex"],
+          );
+
+  Future<void> syncWalletStaticMethodApi(
+      {required String walletId, required String blockchainId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(blockchainId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_sync_wallet__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSyncWalletStaticMethodApiConstMeta,
+      argValues: [walletId, blockchainId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSyncWalletStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "sync_wallet__static_method__Api",
+        argNames: ["walletId", "blockchainId"],
+      );
+
+  Future<Balance> getBalanceStaticMethodApi(
+      {required String walletId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_get_balance__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_balance,
+      constMeta: kGetBalanceStaticMethodApiConstMeta,
+      argValues: [walletId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetBalanceStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_balance__static_method__Api",
+        argNames: ["walletId"],
+      );
+
+  Future<List<LocalUtxo>> listUnspentOutputsStaticMethodApi(
+      {required String walletId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_list_unspent_outputs__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_list_local_utxo,
+      constMeta: kListUnspentOutputsStaticMethodApiConstMeta,
+      argValues: [walletId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kListUnspentOutputsStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "list_unspent_outputs__static_method__Api",
+            argNames: ["walletId"],
+          );
+
+  Future<List<TransactionDetails>> getTransactionsStaticMethodApi(
+      {required String walletId, required bool includeRaw, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = includeRaw;
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_get_transactions__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_transaction_details,
+      constMeta: kGetTransactionsStaticMethodApiConstMeta,
+      argValues: [walletId, includeRaw],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetTransactionsStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_transactions__static_method__Api",
+        argNames: ["walletId", "includeRaw"],
+      );
+
+  Future<String?> signStaticMethodApi(
+      {required String walletId,
+      required String psbtStr,
+      SignOptions? signOptions,
+      PsbtSafetyPolicy? safetyPolicy,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(psbtStr);
+    var arg2 = _platform.api2wire_opt_box_autoadd_sign_options(signOptions);
+    var arg3 =
+        _platform.api2wire_opt_box_autoadd_psbt_safety_policy(safetyPolicy);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_sign__static_method__Api(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kSignStaticMethodApiConstMeta,
+      argValues: [walletId, psbtStr, signOptions, safetyPolicy],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSignStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "sign__static_method__Api",
+        argNames: ["walletId", "psbtStr", "signOptions", "safetyPolicy"],
+      );
+
+  Future<PsbtSafetyReport> checkPsbtStaticMethodApi(
+      {required String walletId,
+      required String psbtStr,
+      required PsbtSafetyPolicy policy,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(psbtStr);
+    var arg2 = _platform.api2wire_box_autoadd_psbt_safety_policy(policy);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_check_psbt__static_method__Api(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_psbt_safety_report,
+      constMeta: kCheckPsbtStaticMethodApiConstMeta,
+      argValues: [walletId, psbtStr, policy],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCheckPsbtStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "check_psbt__static_method__Api",
+        argNames: ["walletId", "psbtStr", "policy"],
+      );
+
+  Future<MaxSendable> maxSendableStaticMethodApi(
+      {required String walletId,
+      required Script destination,
+      required double feeRate,
+      required List<OutPoint> utxos,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_box_autoadd_script(destination);
+    var arg2 = api2wire_f32(feeRate);
+    var arg3 = _platform.api2wire_list_out_point(utxos);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_max_sendable__static_method__Api(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_max_sendable,
+      constMeta: kMaxSendableStaticMethodApiConstMeta,
+      argValues: [walletId, destination, feeRate, utxos],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kMaxSendableStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "max_sendable__static_method__Api",
+        argNames: ["walletId", "destination", "feeRate", "utxos"],
+      );
+
+  Future<BdkSweepResult> sweepStaticMethodApi(
+      {required String walletId,
+      required Script destination,
+      required double feeRate,
+      required List<OutPoint> utxos,
+      required bool enableRbf,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_box_autoadd_script(destination);
+    var arg2 = api2wire_f32(feeRate);
+    var arg3 = _platform.api2wire_list_out_point(utxos);
+    var arg4 = enableRbf;
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_sweep__static_method__Api(port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_bdk_sweep_result,
+      constMeta: kSweepStaticMethodApiConstMeta,
+      argValues: [walletId, destination, feeRate, utxos, enableRbf],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSweepStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "sweep__static_method__Api",
+        argNames: ["walletId", "destination", "feeRate", "utxos", "enableRbf"],
+      );
+
+  Future<List<ConsolidationPsbt>> planConsolidationStaticMethodApi(
+      {required String walletId,
+      required double feeRate,
+      required double futureFeeRate,
+      required List<UtxoLabel> labels,
+      int? maxInputs,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = api2wire_f32(feeRate);
+    var arg2 = api2wire_f32(futureFeeRate);
+    var arg3 = _platform.api2wire_list_utxo_label(labels);
+    var arg4 = _platform.api2wire_opt_box_autoadd_u32(maxInputs);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_plan_consolidation__static_method__Api(
+              port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_list_consolidation_psbt,
+      constMeta: kPlanConsolidationStaticMethodApiConstMeta,
+      argValues: [walletId, feeRate, futureFeeRate, labels, maxInputs],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kPlanConsolidationStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "plan_consolidation__static_method__Api",
+            argNames: [
+              "walletId",
+              "feeRate",
+              "futureFeeRate",
+              "labels",
+              "maxInputs"
+            ],
+          );
+
+  Future<FinalizedPsbt> finalizePsbtStaticMethodApi(
+      {required String walletId,
+      required String psbtStr,
+      SignOptions? signOptions,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(psbtStr);
+    var arg2 = _platform.api2wire_opt_box_autoadd_sign_options(signOptions);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_finalize_psbt__static_method__Api(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_finalized_psbt,
+      constMeta: kFinalizePsbtStaticMethodApiConstMeta,
+      argValues: [walletId, psbtStr, signOptions],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFinalizePsbtStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "finalize_psbt__static_method__Api",
+        argNames: ["walletId", "psbtStr", "signOptions"],
+      );
+
+  Future<PsbtAnalysis> analyzePsbtStaticMethodApi(
+      {required String walletId, required String psbtStr, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_String(psbtStr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_analyze_psbt__static_method__Api(port_, arg0, arg1),
+      parseSuccessData: _wire2api_psbt_analysis,
+      constMeta: kAnalyzePsbtStaticMethodApiConstMeta,
+      argValues: [walletId, psbtStr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAnalyzePsbtStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "analyze_psbt__static_method__Api",
+        argNames: ["walletId", "psbtStr"],
+      );
+
+  Future<Network> walletNetworkStaticMethodApi(
+      {required String walletId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_wallet_network__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_network,
+      constMeta: kWalletNetworkStaticMethodApiConstMeta,
+      argValues: [walletId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kWalletNetworkStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "wallet_network__static_method__Api",
+        argNames: ["walletId"],
+      );
+
+  Future<List<LocalUtxo>> listUnspentStaticMethodApi(
+      {required String walletId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_list_unspent__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_list_local_utxo,
+      constMeta: kListUnspentStaticMethodApiConstMeta,
+      argValues: [walletId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListUnspentStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_unspent__static_method__Api",
+        argNames: ["walletId"],
+      );
+
+  Future<String> getPsbtInputStaticMethodApi(
+      {required String walletId,
+      required LocalUtxo utxo,
+      required bool onlyWitnessUtxo,
+      PsbtSigHashType? psbtSighashType,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = _platform.api2wire_box_autoadd_local_utxo(utxo);
+    var arg2 = onlyWitnessUtxo;
+    var arg3 =
+        _platform.api2wire_opt_box_autoadd_psbt_sig_hash_type(psbtSighashType);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_get_psbt_input__static_method__Api(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kGetPsbtInputStaticMethodApiConstMeta,
+      argValues: [walletId, utxo, onlyWitnessUtxo, psbtSighashType],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetPsbtInputStaticMethodApiConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_psbt_input__static_method__Api",
+        argNames: ["walletId", "utxo", "onlyWitnessUtxo", "psbtSighashType"],
+      );
+
+  Future<DescNetwork> getDescriptorForKeychainStaticMethodApi(
+      {required String walletId,
+      required KeychainKind keychain,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    var arg1 = api2wire_keychain_kind(keychain);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_get_descriptor_for_keychain__static_method__Api(
+              port_, arg0, arg1),
+      parseSuccessData: _wire2api_desc_network,
+      constMeta: kGetDescriptorForKeychainStaticMethodApiConstMeta,
+      argValues: [walletId, keychain],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGetDescriptorForKeychainStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "get_descriptor_for_keychain__static_method__Api",
+            argNames: ["walletId", "keychain"],
+          );
+
+  Future<DescNetwork> getMultipathDescriptorStaticMethodApi(
+      {required String walletId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(walletId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_get_multipath_descriptor__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_desc_network,
+      constMeta: kGetMultipathDescriptorStaticMethodApiConstMeta,
+      argValues: [walletId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGetMultipathDescriptorStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "get_multipath_descriptor__static_method__Api",
+            argNames: ["walletId"],
+          );
+
+  Future<String> generateSeedFromWordCountStaticMethodApi(
+      {required WordCount wordCount, dynamic hint}) {
+    var arg0 = api2wire_word_count(wordCount);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_generate_seed_from_word_count__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kGenerateSeedFromWordCountStaticMethodApiConstMeta,
+      argValues: [wordCount],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGenerateSeedFromWordCountStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "generate_seed_from_word_count__static_method__Api",
+            argNames: ["wordCount"],
+          );
+
+  Future<String> generateSeedFromStringStaticMethodApi(
+      {required String mnemonic, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(mnemonic);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_generate_seed_from_string__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kGenerateSeedFromStringStaticMethodApiConstMeta,
+      argValues: [mnemonic],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGenerateSeedFromStringStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "generate_seed_from_string__static_method__Api",
+            argNames: ["mnemonic"],
+          );
+
+  Future<String> generateSeedFromEntropyStaticMethodApi(
+      {required Uint8List entropy, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(entropy);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_generate_seed_from_entropy__static_method__Api(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kGenerateSeedFromEntropyStaticMethodApiConstMeta,
+      argValues: [entropy],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGenerateSeedFromEntropyStaticMethodApiConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "generate_seed_from_entropy__static_method__Api",
+            argNames: ["entropy"],
+          );
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
+
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
+
+  AddedSignature _wire2api_added_signature(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AddedSignature(
+      publicKey: _wire2api_String(arr[0]),
+      leafHash: _wire2api_opt_String(arr[1]),
+      isTapKeySig: _wire2api_bool(arr[2]),
+      source: _wire2api_u32(arr[3]),
+    );
+  }
+
+  AddressInfo _wire2api_address_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AddressInfo(
+      index: _wire2api_u32(arr[0]),
+      address: _wire2api_String(arr[1]),
+    );
+  }
+
+  Balance _wire2api_balance(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Balance(
+      immature: _wire2api_u64(arr[0]),
+      trustedPending: _wire2api_u64(arr[1]),
+      untrustedPending: _wire2api_u64(arr[2]),
+      confirmed: _wire2api_u64(arr[3]),
+      spendable: _wire2api_u64(arr[4]),
+      total: _wire2api_u64(arr[5]),
+    );
+  }
+
+  BdkBumpFeeTxBuilderResult _wire2api_bdk_bump_fee_tx_builder_result(
+      dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return BdkBumpFeeTxBuilderResult(
+      psbt: _wire2api_String(arr[0]),
+      transactionDetails: _wire2api_transaction_details(arr[1]),
+      originalFee: _wire2api_u64(arr[2]),
+      originalFeeRate: _wire2api_f32(arr[3]),
+      fee: _wire2api_u64(arr[4]),
+      feeRate: _wire2api_f32(arr[5]),
+    );
+  }
+
+  BdkSweepResult _wire2api_bdk_sweep_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BdkSweepResult(
+      psbt: _wire2api_String(arr[0]),
+      transactionDetails: _wire2api_transaction_details(arr[1]),
+      maxSendable: _wire2api_max_sendable(arr[2]),
+    );
+  }
+
+  BdkTxBuilderResult _wire2api_bdk_tx_builder_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BdkTxBuilderResult(
+      field0: _wire2api_String(arr[0]),
+      field1: _wire2api_transaction_details(arr[1]),
+    );
+  }
+
+  BlockTime _wire2api_block_time(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BlockTime(
+      height: _wire2api_u32(arr[0]),
+      timestamp: _wire2api_u64(arr[1]),
+    );
+  }
+
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
+
+  BlockTime _wire2api_box_autoadd_block_time(dynamic raw) {
+    return _wire2api_block_time(raw);
+  }
+
+  double _wire2api_box_autoadd_f32(dynamic raw) {
+    return raw as double;
+  }
+
+  KeychainKind _wire2api_box_autoadd_keychain_kind(dynamic raw) {
+    return _wire2api_keychain_kind(raw);
+  }
+
+  MultisigScriptType _wire2api_box_autoadd_multisig_script_type(dynamic raw) {
+    return _wire2api_multisig_script_type(raw);
+  }
+
+  Network _wire2api_box_autoadd_network(dynamic raw) {
+    return _wire2api_network(raw);
+  }
+
+  PsbtSigHashType _wire2api_box_autoadd_psbt_sig_hash_type(dynamic raw) {
+    return _wire2api_psbt_sig_hash_type(raw);
+  }
+
+  Script _wire2api_box_autoadd_script(dynamic raw) {
+    return _wire2api_script(raw);
+  }
+
+  int _wire2api_box_autoadd_u32(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_box_autoadd_u64(dynamic raw) {
+    return _wire2api_u64(raw);
+  }
+
+  UrPayload _wire2api_box_autoadd_ur_payload(dynamic raw) {
+    return _wire2api_ur_payload(raw);
+  }
+
+  BsmsDescriptorRecord _wire2api_bsms_descriptor_record(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BsmsDescriptorRecord(
+      template: _wire2api_String(arr[0]),
+      descriptor: _wire2api_String(arr[1]),
+      changeDescriptor: _wire2api_String(arr[2]),
+      firstAddress: _wire2api_String(arr[3]),
+    );
+  }
+
+  BsmsKeyRecord _wire2api_bsms_key_record(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BsmsKeyRecord(
+      token: _wire2api_String(arr[0]),
+      key: _wire2api_String(arr[1]),
+      description: _wire2api_String(arr[2]),
+      signature: _wire2api_String(arr[3]),
+    );
+  }
+
+  CombinedInput _wire2api_combined_input(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CombinedInput(
+      index: _wire2api_u32(arr[0]),
+      addedSignatures: _wire2api_list_added_signature(arr[1]),
+    );
+  }
+
+  CombinedPsbt _wire2api_combined_psbt(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CombinedPsbt(
+      psbt: _wire2api_String(arr[0]),
+      inputs: _wire2api_list_combined_input(arr[1]),
+    );
+  }
+
+  CompiledPolicy _wire2api_compiled_policy(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CompiledPolicy(
+      descriptor: _wire2api_String(arr[0]),
+      maxSatisfactionWeight: _wire2api_u64(arr[1]),
+      isSane: _wire2api_bool(arr[2]),
+      sanityError: _wire2api_opt_String(arr[3]),
+      isNonMalleable: _wire2api_bool(arr[4]),
+    );
+  }
+
+  ConsolidationPsbt _wire2api_consolidation_psbt(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ConsolidationPsbt(
+      psbt: _wire2api_String(arr[0]),
+      transactionDetails: _wire2api_transaction_details(arr[1]),
+      keychain: _wire2api_keychain_kind(arr[2]),
+      label: _wire2api_opt_String(arr[3]),
+      utxos: _wire2api_list_out_point(arr[4]),
+      estimatedSavings: _wire2api_i64(arr[5]),
+    );
+  }
+
+  CosignerStatus _wire2api_cosigner_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CosignerStatus(
+      fingerprint: _wire2api_String(arr[0]),
+      signedInputs: _wire2api_uint_32_list(arr[1]),
+      hasSigned: _wire2api_bool(arr[2]),
+    );
+  }
+
+  DerivedAddress _wire2api_derived_address(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DerivedAddress(
+      index: _wire2api_u32(arr[0]),
+      address: _wire2api_String(arr[1]),
+      scriptPubkey: _wire2api_script(arr[2]),
+      keys: _wire2api_list_key_origin(arr[3]),
+    );
+  }
+
+  DescNetwork _wire2api_desc_network(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DescNetwork(
+      field0: _wire2api_String(arr[0]),
+      field1: _wire2api_network(arr[1]),
+    );
+  }
+
+  DescriptorInfo _wire2api_descriptor_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return DescriptorInfo(
+      descriptorType: _wire2api_descriptor_type(arr[0]),
+      threshold: _wire2api_opt_box_autoadd_u32(arr[1]),
+      isSortedMulti: _wire2api_bool(arr[2]),
+      hasWildcard: _wire2api_bool(arr[3]),
+      isMultipath: _wire2api_bool(arr[4]),
+      keys: _wire2api_list_descriptor_key_info(arr[5]),
+      checksum: _wire2api_String(arr[6]),
+      hasPrivateKeys: _wire2api_bool(arr[7]),
+      network: _wire2api_opt_box_autoadd_network(arr[8]),
+    );
+  }
+
+  DescriptorKeyInfo _wire2api_descriptor_key_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DescriptorKeyInfo(
+      key: _wire2api_String(arr[0]),
+      originFingerprint: _wire2api_opt_String(arr[1]),
+      originPath: _wire2api_opt_String(arr[2]),
+      masterFingerprint: _wire2api_String(arr[3]),
+      fullDerivationPath: _wire2api_String(arr[4]),
+      hasWildcard: _wire2api_bool(arr[5]),
+      isPrivate: _wire2api_bool(arr[6]),
+    );
+  }
+
+  DescriptorType _wire2api_descriptor_type(dynamic raw) {
+    return DescriptorType.values[raw as int];
+  }
+
+  ExcludedUtxo _wire2api_excluded_utxo(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ExcludedUtxo(
+      outpoint: _wire2api_out_point(arr[0]),
+      value: _wire2api_u64(arr[1]),
+      spendCost: _wire2api_u64(arr[2]),
+    );
+  }
+
+  double _wire2api_f32(dynamic raw) {
+    return raw as double;
+  }
+
+  FinalizeInputResult _wire2api_finalize_input_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FinalizeInputResult(
+      index: _wire2api_u32(arr[0]),
+      isFinalized: _wire2api_bool(arr[1]),
+      error: _wire2api_opt_String(arr[2]),
+    );
+  }
+
+  FinalizedPsbt _wire2api_finalized_psbt(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FinalizedPsbt(
+      psbt: _wire2api_String(arr[0]),
+      isFinalized: _wire2api_bool(arr[1]),
+      inputs: _wire2api_list_finalize_input_result(arr[2]),
+    );
+  }
+
+  int _wire2api_i32(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_i64(dynamic raw) {
+    return castInt(raw);
+  }
+
+  ImportedCosigner _wire2api_imported_cosigner(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ImportedCosigner(
+      fingerprint: _wire2api_String(arr[0]),
+      derivationPath: _wire2api_String(arr[1]),
+      key: _wire2api_String(arr[2]),
+    );
+  }
+
+  ImportedWallet _wire2api_imported_wallet(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ImportedWallet(
+      source: _wire2api_wallet_source(arr[0]),
+      name: _wire2api_opt_String(arr[1]),
+      descriptor: _wire2api_String(arr[2]),
+      changeDescriptor: _wire2api_String(arr[3]),
+      threshold: _wire2api_opt_box_autoadd_u32(arr[4]),
+      cosigners: _wire2api_list_imported_cosigner(arr[5]),
+    );
+  }
+
+  KeyMetadata _wire2api_key_metadata(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return KeyMetadata(
+      masterFingerprint: _wire2api_String(arr[0]),
+      fingerprint: _wire2api_String(arr[1]),
+      originPath: _wire2api_opt_String(arr[2]),
+      wildcard: _wire2api_wildcard_type(arr[3]),
+      parentFingerprint: _wire2api_opt_String(arr[4]),
+      depth: _wire2api_opt_box_autoadd_u32(arr[5]),
+      childNumber: _wire2api_opt_box_autoadd_u32(arr[6]),
+      chainCode: _wire2api_opt_uint_8_list(arr[7]),
+      xpub: _wire2api_opt_String(arr[8]),
+      xprv: _wire2api_opt_String(arr[9]),
+      publicKey: _wire2api_opt_uint_8_list(arr[10]),
+      xOnlyPublicKey: _wire2api_uint_8_list(arr[11]),
+    );
+  }
+
+  KeyOrigin _wire2api_key_origin(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return KeyOrigin(
+      publicKey: _wire2api_String(arr[0]),
+      fingerprint: _wire2api_String(arr[1]),
+      path: _wire2api_String(arr[2]),
+    );
+  }
+
+  KeychainKind _wire2api_keychain_kind(dynamic raw) {
+    return KeychainKind.values[raw as int];
+  }
+
+  List<AddedSignature> _wire2api_list_added_signature(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_added_signature).toList();
+  }
+
+  List<CombinedInput> _wire2api_list_combined_input(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_combined_input).toList();
+  }
+
+  List<ConsolidationPsbt> _wire2api_list_consolidation_psbt(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_consolidation_psbt).toList();
+  }
+
+  List<CosignerStatus> _wire2api_list_cosigner_status(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_cosigner_status).toList();
+  }
+
+  List<DerivedAddress> _wire2api_list_derived_address(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_derived_address).toList();
+  }
+
+  List<DescriptorKeyInfo> _wire2api_list_descriptor_key_info(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_descriptor_key_info).toList();
+  }
+
+  List<ExcludedUtxo> _wire2api_list_excluded_utxo(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_excluded_utxo).toList();
+  }
+
+  List<FinalizeInputResult> _wire2api_list_finalize_input_result(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_finalize_input_result).toList();
+  }
+
+  List<ImportedCosigner> _wire2api_list_imported_cosigner(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_imported_cosigner).toList();
+  }
+
+  List<ImportedWallet> _wire2api_list_imported_wallet(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_imported_wallet).toList();
+  }
+
+  List<KeyOrigin> _wire2api_list_key_origin(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_key_origin).toList();
+  }
+
+  List<LocalUtxo> _wire2api_list_local_utxo(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_local_utxo).toList();
+  }
+
+  List<OutPoint> _wire2api_list_out_point(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_out_point).toList();
+  }
+
+  List<PsbtInputAnalysis> _wire2api_list_psbt_input_analysis(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_psbt_input_analysis).toList();
+  }
+
+  List<PsbtInputInfo> _wire2api_list_psbt_input_info(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_psbt_input_info).toList();
+  }
+
+  List<PsbtIssue> _wire2api_list_psbt_issue(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_psbt_issue).toList();
+  }
+
+  List<PsbtOutputAnalysis> _wire2api_list_psbt_output_analysis(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_psbt_output_analysis).toList();
+  }
+
+  List<PsbtOutputInfo> _wire2api_list_psbt_output_info(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_psbt_output_info).toList();
+  }
+
+  List<PsbtWarning> _wire2api_list_psbt_warning(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_psbt_warning).toList();
+  }
+
+  List<TapScriptSig> _wire2api_list_tap_script_sig(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_tap_script_sig).toList();
+  }
+
+  List<TransactionDetails> _wire2api_list_transaction_details(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_transaction_details).toList();
+  }
+
+  List<TxIn> _wire2api_list_tx_in(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_tx_in).toList();
+  }
+
+  List<TxOut> _wire2api_list_tx_out(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_tx_out).toList();
+  }
+
+  LocalUtxo _wire2api_local_utxo(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LocalUtxo(
+      outpoint: _wire2api_out_point(arr[0]),
+      txout: _wire2api_tx_out(arr[1]),
+      isSpent: _wire2api_bool(arr[2]),
+      keychain: _wire2api_keychain_kind(arr[3]),
+    );
+  }
+
+  MaxSendable _wire2api_max_sendable(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MaxSendable(
+      amount: _wire2api_u64(arr[0]),
+      fee: _wire2api_u64(arr[1]),
+      utxos: _wire2api_list_out_point(arr[2]),
+      excluded: _wire2api_list_excluded_utxo(arr[3]),
+    );
+  }
+
+  MultisigDescriptors _wire2api_multisig_descriptors(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MultisigDescriptors(
+      descriptor: _wire2api_String(arr[0]),
+      changeDescriptor: _wire2api_String(arr[1]),
+    );
+  }
+
+  MultisigScriptType _wire2api_multisig_script_type(dynamic raw) {
+    return MultisigScriptType.values[raw as int];
+  }
+
+  MultisigStatus _wire2api_multisig_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MultisigStatus(
+      threshold: _wire2api_u32(arr[0]),
+      cosigners: _wire2api_list_cosigner_status(arr[1]),
+      signatures: _wire2api_uint_32_list(arr[2]),
+      isReady: _wire2api_bool(arr[3]),
+      psbt: _wire2api_String(arr[4]),
+    );
+  }
+
+  Network _wire2api_network(dynamic raw) {
+    return Network.values[raw as int];
+  }
+
+  String? _wire2api_opt_String(dynamic raw) {
+    return raw == null ? null : _wire2api_String(raw);
+  }
+
+  BlockTime? _wire2api_opt_box_autoadd_block_time(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_block_time(raw);
+  }
+
+  double? _wire2api_opt_box_autoadd_f32(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_f32(raw);
+  }
+
+  KeychainKind? _wire2api_opt_box_autoadd_keychain_kind(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_keychain_kind(raw);
+  }
+
+  MultisigScriptType? _wire2api_opt_box_autoadd_multisig_script_type(
+      dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_multisig_script_type(raw);
+  }
+
+  Network? _wire2api_opt_box_autoadd_network(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_network(raw);
+  }
+
+  PsbtSigHashType? _wire2api_opt_box_autoadd_psbt_sig_hash_type(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_psbt_sig_hash_type(raw);
+  }
+
+  Script? _wire2api_opt_box_autoadd_script(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_script(raw);
+  }
+
+  int? _wire2api_opt_box_autoadd_u32(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_u32(raw);
+  }
+
+  int? _wire2api_opt_box_autoadd_u64(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_u64(raw);
+  }
+
+  UrPayload? _wire2api_opt_box_autoadd_ur_payload(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_ur_payload(raw);
+  }
+
+  Uint8List? _wire2api_opt_uint_8_list(dynamic raw) {
+    return raw == null ? null : _wire2api_uint_8_list(raw);
+  }
+
+  OutPoint _wire2api_out_point(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OutPoint(
+      txid: _wire2api_String(arr[0]),
+      vout: _wire2api_u32(arr[1]),
+    );
+  }
+
+  PayjoinRequest _wire2api_payjoin_request(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PayjoinRequest(
+      url: _wire2api_String(arr[0]),
+      body: _wire2api_String(arr[1]),
+    );
+  }
+
+  PayjoinUri _wire2api_payjoin_uri(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PayjoinUri(
+      address: _wire2api_String(arr[0]),
+      amount: _wire2api_opt_box_autoadd_u64(arr[1]),
+      endpoint: _wire2api_String(arr[2]),
+      disableOutputSubstitution: _wire2api_bool(arr[3]),
+    );
+  }
+
+  Payload _wire2api_payload(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return Payload_PubkeyHash(
+          pubkeyHash: _wire2api_uint_8_list(raw[1]),
+        );
+      case 1:
+        return Payload_ScriptHash(
+          scriptHash: _wire2api_uint_8_list(raw[1]),
+        );
+      case 2:
+        return Payload_WitnessProgram(
+          version: _wire2api_witness_version(raw[1]),
+          program: _wire2api_uint_8_list(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  PsbtAnalysis _wire2api_psbt_analysis(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PsbtAnalysis(
+      inputs: _wire2api_list_psbt_input_analysis(arr[0]),
+      outputs: _wire2api_list_psbt_output_analysis(arr[1]),
+      sent: _wire2api_u64(arr[2]),
+      received: _wire2api_u64(arr[3]),
+      net: _wire2api_i64(arr[4]),
+      fee: _wire2api_opt_box_autoadd_u64(arr[5]),
+      feeRate: _wire2api_opt_box_autoadd_f32(arr[6]),
+      warnings: _wire2api_list_psbt_warning(arr[7]),
+    );
+  }
+
+  PsbtInputAnalysis _wire2api_psbt_input_analysis(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PsbtInputAnalysis(
+      previousOutput: _wire2api_out_point(arr[0]),
+      value: _wire2api_opt_box_autoadd_u64(arr[1]),
+      isMine: _wire2api_bool(arr[2]),
+      keychain: _wire2api_opt_box_autoadd_keychain_kind(arr[3]),
+      derivationIndex: _wire2api_opt_box_autoadd_u32(arr[4]),
+      canSign: _wire2api_bool(arr[5]),
+    );
+  }
+
+  PsbtInputInfo _wire2api_psbt_input_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return PsbtInputInfo(
+      previousOutput: _wire2api_out_point(arr[0]),
+      sequence: _wire2api_u32(arr[1]),
+      amount: _wire2api_opt_box_autoadd_u64(arr[2]),
+      scriptPubkey: _wire2api_opt_box_autoadd_script(arr[3]),
+      sighashType: _wire2api_opt_box_autoadd_psbt_sig_hash_type(arr[4]),
+      bip32Derivations: _wire2api_list_key_origin(arr[5]),
+      partialSigs: _wire2api_StringList(arr[6]),
+      hasTapKeySig: _wire2api_bool(arr[7]),
+      tapScriptSigs: _wire2api_list_tap_script_sig(arr[8]),
+      isFinalized: _wire2api_bool(arr[9]),
+    );
+  }
+
+  PsbtIssue _wire2api_psbt_issue(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return PsbtIssue_FeeTooHigh(
+          fee: _wire2api_u64(raw[1]),
+          maxFee: _wire2api_u64(raw[2]),
+        );
+      case 1:
+        return PsbtIssue_FeeTooHighRelative(
+          fee: _wire2api_u64(raw[1]),
+          feePercent: _wire2api_f32(raw[2]),
+          maxFeePercent: _wire2api_f32(raw[3]),
+        );
+      case 2:
+        return PsbtIssue_MissingUtxo(
+          index: _wire2api_u32(raw[1]),
+        );
+      case 3:
+        return PsbtIssue_MissingNonWitnessUtxo(
+          index: _wire2api_u32(raw[1]),
+        );
+      case 4:
+        return PsbtIssue_NonWitnessUtxoMismatch(
+          index: _wire2api_u32(raw[1]),
+        );
+      case 5:
+        return PsbtIssue_UtxoValueMismatch(
+          index: _wire2api_u32(raw[1]),
+          witnessValue: _wire2api_u64(raw[2]),
+          nonWitnessValue: _wire2api_u64(raw[3]),
+        );
+      case 6:
+        return PsbtIssue_NonStandardSighash(
+          index: _wire2api_u32(raw[1]),
+          sighash: _wire2api_u32(raw[2]),
+        );
+      case 7:
+        return PsbtIssue_DustOutput(
+          index: _wire2api_u32(raw[1]),
+          value: _wire2api_u64(raw[2]),
+          dustLimit: _wire2api_u64(raw[3]),
+        );
+      case 8:
+        return PsbtIssue_NetworkMismatch(
+          network: _wire2api_network(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  PsbtOutputAnalysis _wire2api_psbt_output_analysis(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PsbtOutputAnalysis(
+      scriptPubkey: _wire2api_script(arr[0]),
+      value: _wire2api_u64(arr[1]),
+      isMine: _wire2api_bool(arr[2]),
+      isChange: _wire2api_bool(arr[3]),
+      keychain: _wire2api_opt_box_autoadd_keychain_kind(arr[4]),
+      derivationIndex: _wire2api_opt_box_autoadd_u32(arr[5]),
+    );
+  }
+
+  PsbtOutputInfo _wire2api_psbt_output_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PsbtOutputInfo(
+      scriptPubkey: _wire2api_script(arr[0]),
+      address: _wire2api_opt_String(arr[1]),
+      amount: _wire2api_u64(arr[2]),
+      bip32Derivations: _wire2api_list_key_origin(arr[3]),
+    );
+  }
+
+  PsbtSafetyReport _wire2api_psbt_safety_report(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PsbtSafetyReport(
+      isSafe: _wire2api_bool(arr[0]),
+      fee: _wire2api_opt_box_autoadd_u64(arr[1]),
+      issues: _wire2api_list_psbt_issue(arr[2]),
+    );
+  }
+
+  PsbtSigHashType _wire2api_psbt_sig_hash_type(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return PsbtSigHashType(
+      inner: _wire2api_u32(arr[0]),
+    );
+  }
+
+  PsbtWarning _wire2api_psbt_warning(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return PsbtWarning_NetworkMismatch(
+          network: _wire2api_network(raw[1]),
+        );
+      case 1:
+        return PsbtWarning_MissingUtxo(
+          index: _wire2api_u32(raw[1]),
+        );
+      case 2:
+        return PsbtWarning_ChangeDerivationMismatch(
+          index: _wire2api_u32(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  Script _wire2api_script(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return Script(
+      internal: _wire2api_uint_8_list(arr[0]),
+    );
+  }
+
+  Slip132Template _wire2api_slip_132_template(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Slip132Template(
+      key: _wire2api_String(arr[0]),
+      version: _wire2api_slip_132_version(arr[1]),
+      descriptor: _wire2api_opt_String(arr[2]),
+      multisigScriptType:
+          _wire2api_opt_box_autoadd_multisig_script_type(arr[3]),
+    );
+  }
+
+  Slip132Version _wire2api_slip_132_version(dynamic raw) {
+    return Slip132Version.values[raw as int];
+  }
+
+  TapScriptSig _wire2api_tap_script_sig(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TapScriptSig(
+      publicKey: _wire2api_String(arr[0]),
+      leafHash: _wire2api_String(arr[1]),
+    );
+  }
+
+  TransactionDetails _wire2api_transaction_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TransactionDetails(
+      serializedTx: _wire2api_opt_String(arr[0]),
+      txid: _wire2api_String(arr[1]),
+      received: _wire2api_u64(arr[2]),
+      sent: _wire2api_u64(arr[3]),
+      fee: _wire2api_opt_box_autoadd_u64(arr[4]),
+      confirmationTime: _wire2api_opt_box_autoadd_block_time(arr[5]),
+    );
+  }
+
+  TxIn _wire2api_tx_in(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TxIn(
+      previousOutput: _wire2api_out_point(arr[0]),
+      scriptSig: _wire2api_script(arr[1]),
+      sequence: _wire2api_u32(arr[2]),
+      witness: _wire2api_StringList(arr[3]),
+    );
+  }
+
+  TxOut _wire2api_tx_out(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TxOut(
+      value: _wire2api_u64(arr[0]),
+      scriptPubkey: _wire2api_script(arr[1]),
+    );
+  }
+
+  int _wire2api_u32(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_u64(dynamic raw) {
+    return castInt(raw);
+  }
+
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
+
+  Uint32List _wire2api_uint_32_list(dynamic raw) {
+    return raw as Uint32List;
+  }
+
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
+
+  UrDecodeProgress _wire2api_ur_decode_progress(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UrDecodeProgress(
+      urType: _wire2api_opt_String(arr[0]),
+      progress: _wire2api_f32(arr[1]),
+      result: _wire2api_opt_box_autoadd_ur_payload(arr[2]),
+    );
+  }
+
+  UrPayload _wire2api_ur_payload(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return UrPayload_Psbt(
+          psbt: _wire2api_String(raw[1]),
+        );
+      case 1:
+        return UrPayload_Output(
+          descriptor: _wire2api_String(raw[1]),
+        );
+      case 2:
+        return UrPayload_Account(
+          fingerprint: _wire2api_String(raw[1]),
+          descriptors: _wire2api_StringList(raw[2]),
+        );
+      case 3:
+        return UrPayload_Bytes(
+          urType: _wire2api_String(raw[1]),
+          cbor: _wire2api_uint_8_list(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  int _wire2api_usize(dynamic raw) {
+    return castInt(raw);
+  }
+
+  WalletSource _wire2api_wallet_source(dynamic raw) {
+    return WalletSource.values[raw as int];
+  }
+
+  WildcardType _wire2api_wildcard_type(dynamic raw) {
+    return WildcardType.values[raw as int];
+  }
+
+  WitnessVersion _wire2api_witness_version(dynamic raw) {
+    return WitnessVersion.values[raw as int];
+  }
+}
+
+// Section: api2wire
+
+@protected
+bool api2wire_bool(bool raw) {
+  return raw;
+}
+
+@protected
+int api2wire_bsms_encryption(BsmsEncryption raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_change_spend_policy(ChangeSpendPolicy raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_descriptor_type(DescriptorType raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+double api2wire_f32(double raw) {
+  return raw;
+}
+
+@protected
+int api2wire_i32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_keychain_kind(KeychainKind raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_multisig_script_type(MultisigScriptType raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_network(Network raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_policy_context(PolicyContext raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_slip_132_version(Slip132Version raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_u32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_u8(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_usize(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_word_count(WordCount raw) {
+  return api2wire_i32(raw.index);
+}
+// Section: finalizer
+
+class RustBdkFfiPlatform extends FlutterRustBridgeBase<RustBdkFfiWire> {
+  RustBdkFfiPlatform(ffi.DynamicLibrary dylib) : super(RustBdkFfiWire(dylib));
 
 // Section: api2wire
 
-@protected
-bool api2wire_bool(bool raw) {
-  return raw;
-}
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
+    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList_0(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire___record__out_point_String_usize>
+      api2wire_box_autoadd___record__out_point_String_usize(
+          (OutPoint, String, int) raw) {
+    final ptr = inner.new_box_autoadd___record__out_point_String_usize_0();
+    _api_fill_to_wire___record__out_point_String_usize(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_AddressIndex> api2wire_box_autoadd_address_index(
+      AddressIndex raw) {
+    final ptr = inner.new_box_autoadd_address_index_0();
+    _api_fill_to_wire_address_index(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_BlockchainConfig> api2wire_box_autoadd_blockchain_config(
+      BlockchainConfig raw) {
+    final ptr = inner.new_box_autoadd_blockchain_config_0();
+    _api_fill_to_wire_blockchain_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_DatabaseConfig> api2wire_box_autoadd_database_config(
+      DatabaseConfig raw) {
+    final ptr = inner.new_box_autoadd_database_config_0();
+    _api_fill_to_wire_database_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ElectrumConfig> api2wire_box_autoadd_electrum_config(
+      ElectrumConfig raw) {
+    final ptr = inner.new_box_autoadd_electrum_config_0();
+    _api_fill_to_wire_electrum_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_EsploraConfig> api2wire_box_autoadd_esplora_config(
+      EsploraConfig raw) {
+    final ptr = inner.new_box_autoadd_esplora_config_0();
+    _api_fill_to_wire_esplora_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Float> api2wire_box_autoadd_f32(double raw) {
+    return inner.new_box_autoadd_f32_0(api2wire_f32(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_LocalUtxo> api2wire_box_autoadd_local_utxo(LocalUtxo raw) {
+    final ptr = inner.new_box_autoadd_local_utxo_0();
+    _api_fill_to_wire_local_utxo(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OutPoint> api2wire_box_autoadd_out_point(OutPoint raw) {
+    final ptr = inner.new_box_autoadd_out_point_0();
+    _api_fill_to_wire_out_point(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_PayjoinParams> api2wire_box_autoadd_payjoin_params(
+      PayjoinParams raw) {
+    final ptr = inner.new_box_autoadd_payjoin_params_0();
+    _api_fill_to_wire_payjoin_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_PsbtMap> api2wire_box_autoadd_psbt_map(PsbtMap raw) {
+    final ptr = inner.new_box_autoadd_psbt_map_0();
+    _api_fill_to_wire_psbt_map(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_PsbtSafetyPolicy> api2wire_box_autoadd_psbt_safety_policy(
+      PsbtSafetyPolicy raw) {
+    final ptr = inner.new_box_autoadd_psbt_safety_policy_0();
+    _api_fill_to_wire_psbt_safety_policy(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_PsbtSigHashType> api2wire_box_autoadd_psbt_sig_hash_type(
+      PsbtSigHashType raw) {
+    final ptr = inner.new_box_autoadd_psbt_sig_hash_type_0();
+    _api_fill_to_wire_psbt_sig_hash_type(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_RbfValue> api2wire_box_autoadd_rbf_value(RbfValue raw) {
+    final ptr = inner.new_box_autoadd_rbf_value_0();
+    _api_fill_to_wire_rbf_value(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_RpcConfig> api2wire_box_autoadd_rpc_config(RpcConfig raw) {
+    final ptr = inner.new_box_autoadd_rpc_config_0();
+    _api_fill_to_wire_rpc_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_RpcSyncParams> api2wire_box_autoadd_rpc_sync_params(
+      RpcSyncParams raw) {
+    final ptr = inner.new_box_autoadd_rpc_sync_params_0();
+    _api_fill_to_wire_rpc_sync_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_Script> api2wire_box_autoadd_script(Script raw) {
+    final ptr = inner.new_box_autoadd_script_0();
+    _api_fill_to_wire_script(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ShrinkOutput> api2wire_box_autoadd_shrink_output(
+      ShrinkOutput raw) {
+    final ptr = inner.new_box_autoadd_shrink_output_0();
+    _api_fill_to_wire_shrink_output(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_SignOptions> api2wire_box_autoadd_sign_options(
+      SignOptions raw) {
+    final ptr = inner.new_box_autoadd_sign_options_0();
+    _api_fill_to_wire_sign_options(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_SledDbConfiguration>
+      api2wire_box_autoadd_sled_db_configuration(SledDbConfiguration raw) {
+    final ptr = inner.new_box_autoadd_sled_db_configuration_0();
+    _api_fill_to_wire_sled_db_configuration(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_SqliteDbConfiguration>
+      api2wire_box_autoadd_sqlite_db_configuration(SqliteDbConfiguration raw) {
+    final ptr = inner.new_box_autoadd_sqlite_db_configuration_0();
+    _api_fill_to_wire_sqlite_db_configuration(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
+    return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> api2wire_box_autoadd_u64(int raw) {
+    return inner.new_box_autoadd_u64_0(api2wire_u64(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint8> api2wire_box_autoadd_u8(int raw) {
+    return inner.new_box_autoadd_u8_0(api2wire_u8(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_UserPass> api2wire_box_autoadd_user_pass(UserPass raw) {
+    final ptr = inner.new_box_autoadd_user_pass_0();
+    _api_fill_to_wire_user_pass(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_out_point> api2wire_list_out_point(List<OutPoint> raw) {
+    final ans = inner.new_list_out_point_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_out_point(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_policy_key> api2wire_list_policy_key(
+      List<PolicyKey> raw) {
+    final ans = inner.new_list_policy_key_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_policy_key(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_script_amount> api2wire_list_script_amount(
+      List<ScriptAmount> raw) {
+    final ans = inner.new_list_script_amount_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_script_amount(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_utxo_label> api2wire_list_utxo_label(
+      List<UtxoLabel> raw) {
+    final ans = inner.new_list_utxo_label_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_utxo_label(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_opt_String(String? raw) {
+    return raw == null ? ffi.nullptr : api2wire_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire___record__out_point_String_usize>
+      api2wire_opt_box_autoadd___record__out_point_String_usize(
+          (OutPoint, String, int)? raw) {
+    return raw == null
+        ? ffi.nullptr
+        : api2wire_box_autoadd___record__out_point_String_usize(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Float> api2wire_opt_box_autoadd_f32(double? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_f32(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_PsbtSafetyPolicy>
+      api2wire_opt_box_autoadd_psbt_safety_policy(PsbtSafetyPolicy? raw) {
+    return raw == null
+        ? ffi.nullptr
+        : api2wire_box_autoadd_psbt_safety_policy(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_PsbtSigHashType> api2wire_opt_box_autoadd_psbt_sig_hash_type(
+      PsbtSigHashType? raw) {
+    return raw == null
+        ? ffi.nullptr
+        : api2wire_box_autoadd_psbt_sig_hash_type(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_RbfValue> api2wire_opt_box_autoadd_rbf_value(RbfValue? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_rbf_value(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_RpcSyncParams> api2wire_opt_box_autoadd_rpc_sync_params(
+      RpcSyncParams? raw) {
+    return raw == null
+        ? ffi.nullptr
+        : api2wire_box_autoadd_rpc_sync_params(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_Script> api2wire_opt_box_autoadd_script(Script? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_script(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_ShrinkOutput> api2wire_opt_box_autoadd_shrink_output(
+      ShrinkOutput? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_shrink_output(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_SignOptions> api2wire_opt_box_autoadd_sign_options(
+      SignOptions? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_sign_options(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> api2wire_opt_box_autoadd_u64(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u64(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint8> api2wire_opt_box_autoadd_u8(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u8(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_UserPass> api2wire_opt_box_autoadd_user_pass(UserPass? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_user_pass(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_opt_uint_8_list(Uint8List? raw) {
+    return raw == null ? ffi.nullptr : api2wire_uint_8_list(raw);
+  }
+
+  @protected
+  int api2wire_u64(int raw) {
+    return raw;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
+    final ans = inner.new_uint_8_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+// Section: finalizer
+
+// Section: api_fill_to_wire
+
+  void _api_fill_to_wire___record__out_point_String_usize(
+      (OutPoint, String, int) apiObj,
+      wire___record__out_point_String_usize wireObj) {
+    _api_fill_to_wire_out_point(apiObj.$1, wireObj.field0);
+    wireObj.field1 = api2wire_String(apiObj.$2);
+    wireObj.field2 = api2wire_usize(apiObj.$3);
+  }
+
+  void _api_fill_to_wire_address_index(
+      AddressIndex apiObj, wire_AddressIndex wireObj) {
+    if (apiObj is AddressIndex_New) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is AddressIndex_LastUnused) {
+      wireObj.tag = 1;
+      return;
+    }
+    if (apiObj is AddressIndex_Peek) {
+      var pre_index = api2wire_u32(apiObj.index);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_AddressIndex_Peek();
+      wireObj.kind.ref.Peek.ref.index = pre_index;
+      return;
+    }
+    if (apiObj is AddressIndex_Reset) {
+      var pre_index = api2wire_u32(apiObj.index);
+      wireObj.tag = 3;
+      wireObj.kind = inner.inflate_AddressIndex_Reset();
+      wireObj.kind.ref.Reset.ref.index = pre_index;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_blockchain_config(
+      BlockchainConfig apiObj, wire_BlockchainConfig wireObj) {
+    if (apiObj is BlockchainConfig_Electrum) {
+      var pre_config = api2wire_box_autoadd_electrum_config(apiObj.config);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_BlockchainConfig_Electrum();
+      wireObj.kind.ref.Electrum.ref.config = pre_config;
+      return;
+    }
+    if (apiObj is BlockchainConfig_Esplora) {
+      var pre_config = api2wire_box_autoadd_esplora_config(apiObj.config);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_BlockchainConfig_Esplora();
+      wireObj.kind.ref.Esplora.ref.config = pre_config;
+      return;
+    }
+    if (apiObj is BlockchainConfig_Rpc) {
+      var pre_config = api2wire_box_autoadd_rpc_config(apiObj.config);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_BlockchainConfig_Rpc();
+      wireObj.kind.ref.Rpc.ref.config = pre_config;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_box_autoadd___record__out_point_String_usize(
+      (OutPoint, String, int) apiObj,
+      ffi.Pointer<wire___record__out_point_String_usize> wireObj) {
+    _api_fill_to_wire___record__out_point_String_usize(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_address_index(
+      AddressIndex apiObj, ffi.Pointer<wire_AddressIndex> wireObj) {
+    _api_fill_to_wire_address_index(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_blockchain_config(
+      BlockchainConfig apiObj, ffi.Pointer<wire_BlockchainConfig> wireObj) {
+    _api_fill_to_wire_blockchain_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_database_config(
+      DatabaseConfig apiObj, ffi.Pointer<wire_DatabaseConfig> wireObj) {
+    _api_fill_to_wire_database_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_electrum_config(
+      ElectrumConfig apiObj, ffi.Pointer<wire_ElectrumConfig> wireObj) {
+    _api_fill_to_wire_electrum_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_esplora_config(
+      EsploraConfig apiObj, ffi.Pointer<wire_EsploraConfig> wireObj) {
+    _api_fill_to_wire_esplora_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_local_utxo(
+      LocalUtxo apiObj, ffi.Pointer<wire_LocalUtxo> wireObj) {
+    _api_fill_to_wire_local_utxo(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_out_point(
+      OutPoint apiObj, ffi.Pointer<wire_OutPoint> wireObj) {
+    _api_fill_to_wire_out_point(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_payjoin_params(
+      PayjoinParams apiObj, ffi.Pointer<wire_PayjoinParams> wireObj) {
+    _api_fill_to_wire_payjoin_params(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_psbt_map(
+      PsbtMap apiObj, ffi.Pointer<wire_PsbtMap> wireObj) {
+    _api_fill_to_wire_psbt_map(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_psbt_safety_policy(
+      PsbtSafetyPolicy apiObj, ffi.Pointer<wire_PsbtSafetyPolicy> wireObj) {
+    _api_fill_to_wire_psbt_safety_policy(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_psbt_sig_hash_type(
+      PsbtSigHashType apiObj, ffi.Pointer<wire_PsbtSigHashType> wireObj) {
+    _api_fill_to_wire_psbt_sig_hash_type(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_rbf_value(
+      RbfValue apiObj, ffi.Pointer<wire_RbfValue> wireObj) {
+    _api_fill_to_wire_rbf_value(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_rpc_config(
+      RpcConfig apiObj, ffi.Pointer<wire_RpcConfig> wireObj) {
+    _api_fill_to_wire_rpc_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_rpc_sync_params(
+      RpcSyncParams apiObj, ffi.Pointer<wire_RpcSyncParams> wireObj) {
+    _api_fill_to_wire_rpc_sync_params(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_script(
+      Script apiObj, ffi.Pointer<wire_Script> wireObj) {
+    _api_fill_to_wire_script(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_shrink_output(
+      ShrinkOutput apiObj, ffi.Pointer<wire_ShrinkOutput> wireObj) {
+    _api_fill_to_wire_shrink_output(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_sign_options(
+      SignOptions apiObj, ffi.Pointer<wire_SignOptions> wireObj) {
+    _api_fill_to_wire_sign_options(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_sled_db_configuration(
+      SledDbConfiguration apiObj,
+      ffi.Pointer<wire_SledDbConfiguration> wireObj) {
+    _api_fill_to_wire_sled_db_configuration(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_sqlite_db_configuration(
+      SqliteDbConfiguration apiObj,
+      ffi.Pointer<wire_SqliteDbConfiguration> wireObj) {
+    _api_fill_to_wire_sqlite_db_configuration(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_user_pass(
+      UserPass apiObj, ffi.Pointer<wire_UserPass> wireObj) {
+    _api_fill_to_wire_user_pass(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_database_config(
+      DatabaseConfig apiObj, wire_DatabaseConfig wireObj) {
+    if (apiObj is DatabaseConfig_Memory) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is DatabaseConfig_Sqlite) {
+      var pre_config =
+          api2wire_box_autoadd_sqlite_db_configuration(apiObj.config);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_DatabaseConfig_Sqlite();
+      wireObj.kind.ref.Sqlite.ref.config = pre_config;
+      return;
+    }
+    if (apiObj is DatabaseConfig_Sled) {
+      var pre_config =
+          api2wire_box_autoadd_sled_db_configuration(apiObj.config);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_DatabaseConfig_Sled();
+      wireObj.kind.ref.Sled.ref.config = pre_config;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_electrum_config(
+      ElectrumConfig apiObj, wire_ElectrumConfig wireObj) {
+    wireObj.url = api2wire_String(apiObj.url);
+    wireObj.socks5 = api2wire_opt_String(apiObj.socks5);
+    wireObj.retry = api2wire_u8(apiObj.retry);
+    wireObj.timeout = api2wire_opt_box_autoadd_u8(apiObj.timeout);
+    wireObj.stop_gap = api2wire_u64(apiObj.stopGap);
+    wireObj.validate_domain = api2wire_bool(apiObj.validateDomain);
+  }
+
+  void _api_fill_to_wire_esplora_config(
+      EsploraConfig apiObj, wire_EsploraConfig wireObj) {
+    wireObj.base_url = api2wire_String(apiObj.baseUrl);
+    wireObj.proxy = api2wire_opt_String(apiObj.proxy);
+    wireObj.concurrency = api2wire_opt_box_autoadd_u8(apiObj.concurrency);
+    wireObj.stop_gap = api2wire_u64(apiObj.stopGap);
+    wireObj.timeout = api2wire_opt_box_autoadd_u64(apiObj.timeout);
+  }
+
+  void _api_fill_to_wire_local_utxo(LocalUtxo apiObj, wire_LocalUtxo wireObj) {
+    _api_fill_to_wire_out_point(apiObj.outpoint, wireObj.outpoint);
+    _api_fill_to_wire_tx_out(apiObj.txout, wireObj.txout);
+    wireObj.is_spent = api2wire_bool(apiObj.isSpent);
+    wireObj.keychain = api2wire_keychain_kind(apiObj.keychain);
+  }
+
+  void _api_fill_to_wire_opt_box_autoadd___record__out_point_String_usize(
+      (OutPoint, String, int)? apiObj,
+      ffi.Pointer<wire___record__out_point_String_usize> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd___record__out_point_String_usize(
+          apiObj, wireObj);
+  }
+
+  void _api_fill_to_wire_opt_box_autoadd_psbt_safety_policy(
+      PsbtSafetyPolicy? apiObj, ffi.Pointer<wire_PsbtSafetyPolicy> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_psbt_safety_policy(apiObj, wireObj);
+  }
+
+  void _api_fill_to_wire_opt_box_autoadd_psbt_sig_hash_type(
+      PsbtSigHashType? apiObj, ffi.Pointer<wire_PsbtSigHashType> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_psbt_sig_hash_type(apiObj, wireObj);
+  }
+
+  void _api_fill_to_wire_opt_box_autoadd_rbf_value(
+      RbfValue? apiObj, ffi.Pointer<wire_RbfValue> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_rbf_value(apiObj, wireObj);
+  }
+
+  void _api_fill_to_wire_opt_box_autoadd_rpc_sync_params(
+      RpcSyncParams? apiObj, ffi.Pointer<wire_RpcSyncParams> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_rpc_sync_params(apiObj, wireObj);
+  }
+
+  void _api_fill_to_wire_opt_box_autoadd_script(
+      Script? apiObj, ffi.Pointer<wire_Script> wireObj) {
+    if (apiObj != null) _api_fill_to_wire_box_autoadd_script(apiObj, wireObj);
+  }
 
-@protected
-int api2wire_change_spend_policy(ChangeSpendPolicy raw) {
-  return api2wire_i32(raw.index);
-}
+  void _api_fill_to_wire_opt_box_autoadd_shrink_output(
+      ShrinkOutput? apiObj, ffi.Pointer<wire_ShrinkOutput> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_shrink_output(apiObj, wireObj);
+  }
 
-@protected
-double api2wire_f32(double raw) {
-  return raw;
-}
+  void _api_fill_to_wire_opt_box_autoadd_sign_options(
+      SignOptions? apiObj, ffi.Pointer<wire_SignOptions> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_sign_options(apiObj, wireObj);
+  }
 
-@protected
-int api2wire_i32(int raw) {
-  return raw;
-}
+  void _api_fill_to_wire_opt_box_autoadd_user_pass(
+      UserPass? apiObj, ffi.Pointer<wire_UserPass> wireObj) {
+    if (apiObj != null)
+      _api_fill_to_wire_box_autoadd_user_pass(apiObj, wireObj);
+  }
 
-@protected
-int api2wire_keychain_kind(KeychainKind raw) {
-  return api2wire_i32(raw.index);
-}
+  void _api_fill_to_wire_out_point(OutPoint apiObj, wire_OutPoint wireObj) {
+    wireObj.txid = api2wire_String(apiObj.txid);
+    wireObj.vout = api2wire_u32(apiObj.vout);
+  }
 
-@protected
-int api2wire_network(Network raw) {
-  return api2wire_i32(raw.index);
-}
+  void _api_fill_to_wire_payjoin_params(
+      PayjoinParams apiObj, wire_PayjoinParams wireObj) {
+    wireObj.additional_fee_output_index =
+        api2wire_opt_box_autoadd_u32(apiObj.additionalFeeOutputIndex);
+    wireObj.max_additional_fee_contribution =
+        api2wire_opt_box_autoadd_u64(apiObj.maxAdditionalFeeContribution);
+    wireObj.min_fee_rate = api2wire_opt_box_autoadd_f32(apiObj.minFeeRate);
+    wireObj.disable_output_substitution =
+        api2wire_bool(apiObj.disableOutputSubstitution);
+  }
 
-@protected
-int api2wire_u32(int raw) {
-  return raw;
-}
+  void _api_fill_to_wire_policy_key(PolicyKey apiObj, wire_PolicyKey wireObj) {
+    wireObj.name = api2wire_String(apiObj.name);
+    wireObj.key = api2wire_String(apiObj.key);
+  }
 
-@protected
-int api2wire_u8(int raw) {
-  return raw;
-}
+  void _api_fill_to_wire_psbt_map(PsbtMap apiObj, wire_PsbtMap wireObj) {
+    if (apiObj is PsbtMap_Global) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is PsbtMap_Input) {
+      var pre_index = api2wire_u32(apiObj.index);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_PsbtMap_Input();
+      wireObj.kind.ref.Input.ref.index = pre_index;
+      return;
+    }
+    if (apiObj is PsbtMap_Output) {
+      var pre_index = api2wire_u32(apiObj.index);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_PsbtMap_Output();
+      wireObj.kind.ref.Output.ref.index = pre_index;
+      return;
+    }
+  }
 
-@protected
-int api2wire_usize(int raw) {
-  return raw;
-}
+  void _api_fill_to_wire_psbt_safety_policy(
+      PsbtSafetyPolicy apiObj, wire_PsbtSafetyPolicy wireObj) {
+    wireObj.max_fee = api2wire_opt_box_autoadd_u64(apiObj.maxFee);
+    wireObj.max_fee_percent =
+        api2wire_opt_box_autoadd_f32(apiObj.maxFeePercent);
+    wireObj.require_non_witness_utxo =
+        api2wire_bool(apiObj.requireNonWitnessUtxo);
+    wireObj.refuse_unsafe = api2wire_bool(apiObj.refuseUnsafe);
+  }
 
-@protected
-int api2wire_word_count(WordCount raw) {
-  return api2wire_i32(raw.index);
-}
-// Section: finalizer
+  void _api_fill_to_wire_psbt_sig_hash_type(
+      PsbtSigHashType apiObj, wire_PsbtSigHashType wireObj) {
+    wireObj.inner = api2wire_u32(apiObj.inner);
+  }
 
-class RustBdkFfiPlatform extends FlutterRustBridgeBase<RustBdkFfiWire> {
-  RustBdkFfiPlatform(ffi.DynamicLibrary dylib) : super(RustBdkFfiWire(dylib));
+  void _api_fill_to_wire_rbf_value(RbfValue apiObj, wire_RbfValue wireObj) {
+    if (apiObj is RbfValue_RbfDefault) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is RbfValue_Value) {
+      var pre_field0 = api2wire_u32(apiObj.field0);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_RbfValue_Value();
+      wireObj.kind.ref.Value.ref.field0 = pre_field0;
+      return;
+    }
+  }
 
-// Section: api2wire
+  void _api_fill_to_wire_rpc_config(RpcConfig apiObj, wire_RpcConfig wireObj) {
+    wireObj.url = api2wire_String(apiObj.url);
+    wireObj.auth_cookie = api2wire_opt_String(apiObj.authCookie);
+    wireObj.auth_user_pass =
+        api2wire_opt_box_autoadd_user_pass(apiObj.authUserPass);
+    wireObj.network = api2wire_network(apiObj.network);
+    wireObj.wallet_name = api2wire_String(apiObj.walletName);
+    wireObj.sync_params =
+        api2wire_opt_box_autoadd_rpc_sync_params(apiObj.syncParams);
+  }
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
-    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  void _api_fill_to_wire_rpc_sync_params(
+      RpcSyncParams apiObj, wire_RpcSyncParams wireObj) {
+    wireObj.start_script_count = api2wire_u64(apiObj.startScriptCount);
+    wireObj.start_time = api2wire_u64(apiObj.startTime);
+    wireObj.force_start_time = api2wire_bool(apiObj.forceStartTime);
+    wireObj.poll_rate_sec = api2wire_u64(apiObj.pollRateSec);
   }
 
-  @protected
-  ffi.Pointer<wire___record__out_point_String_usize>
-      api2wire_box_autoadd___record__out_point_String_usize(
-          (OutPoint, String, int) raw) {
-    final ptr = inner.new_box_autoadd___record__out_point_String_usize_0();
-    _api_fill_to_wire___record__out_point_String_usize(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_script(Script apiObj, wire_Script wireObj) {
+    wireObj.internal = api2wire_uint_8_list(apiObj.internal);
   }
 
-  @protected
-  ffi.Pointer<wire_AddressIndex> api2wire_box_autoadd_address_index(
-      AddressIndex raw) {
-    final ptr = inner.new_box_autoadd_address_index_0();
-    _api_fill_to_wire_address_index(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_script_amount(
+      ScriptAmount apiObj, wire_ScriptAmount wireObj) {
+    _api_fill_to_wire_script(apiObj.script, wireObj.script);
+    wireObj.amount = api2wire_u64(apiObj.amount);
   }
 
-  @protected
-  ffi.Pointer<wire_BlockchainConfig> api2wire_box_autoadd_blockchain_config(
-      BlockchainConfig raw) {
-    final ptr = inner.new_box_autoadd_blockchain_config_0();
-    _api_fill_to_wire_blockchain_config(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_shrink_output(
+      ShrinkOutput apiObj, wire_ShrinkOutput wireObj) {
+    if (apiObj is ShrinkOutput_Address) {
+      var pre_address = api2wire_String(apiObj.address);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_ShrinkOutput_Address();
+      wireObj.kind.ref.Address.ref.address = pre_address;
+      return;
+    }
+    if (apiObj is ShrinkOutput_Script) {
+      var pre_script = api2wire_box_autoadd_script(apiObj.script);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_ShrinkOutput_Script();
+      wireObj.kind.ref.Script.ref.script = pre_script;
+      return;
+    }
+    if (apiObj is ShrinkOutput_Index) {
+      var pre_index = api2wire_u32(apiObj.index);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_ShrinkOutput_Index();
+      wireObj.kind.ref.Index.ref.index = pre_index;
+      return;
+    }
   }
 
-  @protected
-  ffi.Pointer<wire_DatabaseConfig> api2wire_box_autoadd_database_config(
-      DatabaseConfig raw) {
-    final ptr = inner.new_box_autoadd_database_config_0();
-    _api_fill_to_wire_database_config(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_sign_options(
+      SignOptions apiObj, wire_SignOptions wireObj) {
+    wireObj.is_multi_sig = api2wire_bool(apiObj.isMultiSig);
+    wireObj.trust_witness_utxo = api2wire_bool(apiObj.trustWitnessUtxo);
+    wireObj.assume_height = api2wire_opt_box_autoadd_u32(apiObj.assumeHeight);
+    wireObj.allow_all_sighashes = api2wire_bool(apiObj.allowAllSighashes);
+    wireObj.remove_partial_sigs = api2wire_bool(apiObj.removePartialSigs);
+    wireObj.try_finalize = api2wire_bool(apiObj.tryFinalize);
+    wireObj.sign_with_tap_internal_key =
+        api2wire_bool(apiObj.signWithTapInternalKey);
+    wireObj.allow_grinding = api2wire_bool(apiObj.allowGrinding);
   }
 
-  @protected
-  ffi.Pointer<wire_ElectrumConfig> api2wire_box_autoadd_electrum_config(
-      ElectrumConfig raw) {
-    final ptr = inner.new_box_autoadd_electrum_config_0();
-    _api_fill_to_wire_electrum_config(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_sled_db_configuration(
+      SledDbConfiguration apiObj, wire_SledDbConfiguration wireObj) {
+    wireObj.path = api2wire_String(apiObj.path);
+    wireObj.tree_name = api2wire_String(apiObj.treeName);
   }
 
-  @protected
-  ffi.Pointer<wire_EsploraConfig> api2wire_box_autoadd_esplora_config(
-      EsploraConfig raw) {
-    final ptr = inner.new_box_autoadd_esplora_config_0();
-    _api_fill_to_wire_esplora_config(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_sqlite_db_configuration(
+      SqliteDbConfiguration apiObj, wire_SqliteDbConfiguration wireObj) {
+    wireObj.path = api2wire_String(apiObj.path);
   }
 
-  @protected
-  ffi.Pointer<ffi.Float> api2wire_box_autoadd_f32(double raw) {
-    return inner.new_box_autoadd_f32_0(api2wire_f32(raw));
+  void _api_fill_to_wire_tx_out(TxOut apiObj, wire_TxOut wireObj) {
+    wireObj.value = api2wire_u64(apiObj.value);
+    _api_fill_to_wire_script(apiObj.scriptPubkey, wireObj.script_pubkey);
   }
 
-  @protected
-  ffi.Pointer<wire_LocalUtxo> api2wire_box_autoadd_local_utxo(LocalUtxo raw) {
-    final ptr = inner.new_box_autoadd_local_utxo_0();
-    _api_fill_to_wire_local_utxo(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_user_pass(UserPass apiObj, wire_UserPass wireObj) {
+    wireObj.username = api2wire_String(apiObj.username);
+    wireObj.password = api2wire_String(apiObj.password);
   }
 
-  @protected
-  ffi.Pointer<wire_PsbtSigHashType> api2wire_box_autoadd_psbt_sig_hash_type(
-      PsbtSigHashType raw) {
-    final ptr = inner.new_box_autoadd_psbt_sig_hash_type_0();
-    _api_fill_to_wire_psbt_sig_hash_type(raw, ptr.ref);
-    return ptr;
+  void _api_fill_to_wire_utxo_label(UtxoLabel apiObj, wire_UtxoLabel wireObj) {
+    _api_fill_to_wire_out_point(apiObj.outpoint, wireObj.outpoint);
+    wireObj.label = api2wire_String(apiObj.label);
+  }
+}
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint
+
+/// generated by flutter_rust_bridge
+class RustBdkFfiWire implements FlutterRustBridgeWireBase {
+  @internal
+  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  RustBdkFfiWire(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  RustBdkFfiWire.fromLookup(
+      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+          lookup)
+      : _lookup = lookup;
+
+  void store_dart_post_cobject(
+    DartPostCObjectFnType ptr,
+  ) {
+    return _store_dart_post_cobject(
+      ptr,
+    );
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+          'store_dart_post_cobject');
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
+      .asFunction<void Function(DartPostCObjectFnType)>();
+
+  Object get_dart_object(
+    int ptr,
+  ) {
+    return _get_dart_object(
+      ptr,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_RbfValue> api2wire_box_autoadd_rbf_value(RbfValue raw) {
-    final ptr = inner.new_box_autoadd_rbf_value_0();
-    _api_fill_to_wire_rbf_value(raw, ptr.ref);
-    return ptr;
-  }
+  late final _get_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
+          'get_dart_object');
+  late final _get_dart_object =
+      _get_dart_objectPtr.asFunction<Object Function(int)>();
 
-  @protected
-  ffi.Pointer<wire_RpcConfig> api2wire_box_autoadd_rpc_config(RpcConfig raw) {
-    final ptr = inner.new_box_autoadd_rpc_config_0();
-    _api_fill_to_wire_rpc_config(raw, ptr.ref);
-    return ptr;
+  void drop_dart_object(
+    int ptr,
+  ) {
+    return _drop_dart_object(
+      ptr,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_RpcSyncParams> api2wire_box_autoadd_rpc_sync_params(
-      RpcSyncParams raw) {
-    final ptr = inner.new_box_autoadd_rpc_sync_params_0();
-    _api_fill_to_wire_rpc_sync_params(raw, ptr.ref);
-    return ptr;
-  }
+  late final _drop_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
+          'drop_dart_object');
+  late final _drop_dart_object =
+      _drop_dart_objectPtr.asFunction<void Function(int)>();
 
-  @protected
-  ffi.Pointer<wire_Script> api2wire_box_autoadd_script(Script raw) {
-    final ptr = inner.new_box_autoadd_script_0();
-    _api_fill_to_wire_script(raw, ptr.ref);
-    return ptr;
+  int new_dart_opaque(
+    Object handle,
+  ) {
+    return _new_dart_opaque(
+      handle,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_SignOptions> api2wire_box_autoadd_sign_options(
-      SignOptions raw) {
-    final ptr = inner.new_box_autoadd_sign_options_0();
-    _api_fill_to_wire_sign_options(raw, ptr.ref);
-    return ptr;
-  }
+  late final _new_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
+          'new_dart_opaque');
+  late final _new_dart_opaque =
+      _new_dart_opaquePtr.asFunction<int Function(Object)>();
 
-  @protected
-  ffi.Pointer<wire_SledDbConfiguration>
-      api2wire_box_autoadd_sled_db_configuration(SledDbConfiguration raw) {
-    final ptr = inner.new_box_autoadd_sled_db_configuration_0();
-    _api_fill_to_wire_sled_db_configuration(raw, ptr.ref);
-    return ptr;
+  int init_frb_dart_api_dl(
+    ffi.Pointer<ffi.Void> obj,
+  ) {
+    return _init_frb_dart_api_dl(
+      obj,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_SqliteDbConfiguration>
-      api2wire_box_autoadd_sqlite_db_configuration(SqliteDbConfiguration raw) {
-    final ptr = inner.new_box_autoadd_sqlite_db_configuration_0();
-    _api_fill_to_wire_sqlite_db_configuration(raw, ptr.ref);
-    return ptr;
-  }
+  late final _init_frb_dart_api_dlPtr =
+      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
+          'init_frb_dart_api_dl');
+  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
+      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
 
-  @protected
-  ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
-    return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
+  void wire_create_blockchain__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_BlockchainConfig> config,
+  ) {
+    return _wire_create_blockchain__static_method__Api(
+      port_,
+      config,
+    );
   }
 
-  @protected
-  ffi.Pointer<ffi.Uint64> api2wire_box_autoadd_u64(int raw) {
-    return inner.new_box_autoadd_u64_0(api2wire_u64(raw));
-  }
+  late final _wire_create_blockchain__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_BlockchainConfig>)>>(
+      'wire_create_blockchain__static_method__Api');
+  late final _wire_create_blockchain__static_method__Api =
+      _wire_create_blockchain__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_BlockchainConfig>)>();
 
-  @protected
-  ffi.Pointer<ffi.Uint8> api2wire_box_autoadd_u8(int raw) {
-    return inner.new_box_autoadd_u8_0(api2wire_u8(raw));
+  void wire_get_height__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> blockchain_id,
+  ) {
+    return _wire_get_height__static_method__Api(
+      port_,
+      blockchain_id,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_UserPass> api2wire_box_autoadd_user_pass(UserPass raw) {
-    final ptr = inner.new_box_autoadd_user_pass_0();
-    _api_fill_to_wire_user_pass(raw, ptr.ref);
-    return ptr;
-  }
+  late final _wire_get_height__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_get_height__static_method__Api');
+  late final _wire_get_height__static_method__Api =
+      _wire_get_height__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<wire_list_out_point> api2wire_list_out_point(List<OutPoint> raw) {
-    final ans = inner.new_list_out_point_0(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      _api_fill_to_wire_out_point(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
+  void wire_get_blockchain_hash__static_method__Api(
+    int port_,
+    int blockchain_height,
+    ffi.Pointer<wire_uint_8_list> blockchain_id,
+  ) {
+    return _wire_get_blockchain_hash__static_method__Api(
+      port_,
+      blockchain_height,
+      blockchain_id,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_list_script_amount> api2wire_list_script_amount(
-      List<ScriptAmount> raw) {
-    final ans = inner.new_list_script_amount_0(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      _api_fill_to_wire_script_amount(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  late final _wire_get_blockchain_hash__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_get_blockchain_hash__static_method__Api');
+  late final _wire_get_blockchain_hash__static_method__Api =
+      _wire_get_blockchain_hash__static_method__ApiPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_opt_String(String? raw) {
-    return raw == null ? ffi.nullptr : api2wire_String(raw);
+  void wire_estimate_fee__static_method__Api(
+    int port_,
+    int target,
+    ffi.Pointer<wire_uint_8_list> blockchain_id,
+  ) {
+    return _wire_estimate_fee__static_method__Api(
+      port_,
+      target,
+      blockchain_id,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire___record__out_point_String_usize>
-      api2wire_opt_box_autoadd___record__out_point_String_usize(
-          (OutPoint, String, int)? raw) {
-    return raw == null
-        ? ffi.nullptr
-        : api2wire_box_autoadd___record__out_point_String_usize(raw);
-  }
+  late final _wire_estimate_fee__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Uint64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_estimate_fee__static_method__Api');
+  late final _wire_estimate_fee__static_method__Api =
+      _wire_estimate_fee__static_method__ApiPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<ffi.Float> api2wire_opt_box_autoadd_f32(double? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_f32(raw);
+  void wire_broadcast__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> blockchain_id,
+  ) {
+    return _wire_broadcast__static_method__Api(
+      port_,
+      tx,
+      blockchain_id,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_PsbtSigHashType> api2wire_opt_box_autoadd_psbt_sig_hash_type(
-      PsbtSigHashType? raw) {
-    return raw == null
-        ? ffi.nullptr
-        : api2wire_box_autoadd_psbt_sig_hash_type(raw);
-  }
+  late final _wire_broadcast__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_broadcast__static_method__Api');
+  late final _wire_broadcast__static_method__Api =
+      _wire_broadcast__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<wire_RbfValue> api2wire_opt_box_autoadd_rbf_value(RbfValue? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_rbf_value(raw);
+  void wire_create_transaction__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_create_transaction__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_RpcSyncParams> api2wire_opt_box_autoadd_rpc_sync_params(
-      RpcSyncParams? raw) {
-    return raw == null
-        ? ffi.nullptr
-        : api2wire_box_autoadd_rpc_sync_params(raw);
-  }
+  late final _wire_create_transaction__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_create_transaction__static_method__Api');
+  late final _wire_create_transaction__static_method__Api =
+      _wire_create_transaction__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<wire_Script> api2wire_opt_box_autoadd_script(Script? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_script(raw);
+  void wire_tx_txid__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_tx_txid__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_SignOptions> api2wire_opt_box_autoadd_sign_options(
-      SignOptions? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_sign_options(raw);
-  }
+  late final _wire_tx_txid__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_tx_txid__static_method__Api');
+  late final _wire_tx_txid__static_method__Api =
+      _wire_tx_txid__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
+  void wire_weight__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_weight__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  @protected
-  ffi.Pointer<ffi.Uint64> api2wire_opt_box_autoadd_u64(int? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u64(raw);
-  }
+  late final _wire_weight__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_weight__static_method__Api');
+  late final _wire_weight__static_method__Api =
+      _wire_weight__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  ffi.Pointer<ffi.Uint8> api2wire_opt_box_autoadd_u8(int? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u8(raw);
+  void wire_size__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_size__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_UserPass> api2wire_opt_box_autoadd_user_pass(UserPass? raw) {
-    return raw == null ? ffi.nullptr : api2wire_box_autoadd_user_pass(raw);
-  }
+  late final _wire_size__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_size__static_method__Api');
+  late final _wire_size__static_method__Api = _wire_size__static_method__ApiPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  @protected
-  int api2wire_u64(int raw) {
-    return raw;
+  void wire_vsize__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_vsize__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
-    final ans = inner.new_uint_8_list_0(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+  late final _wire_vsize__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_vsize__static_method__Api');
+  late final _wire_vsize__static_method__Api =
+      _wire_vsize__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-// Section: finalizer
+  void wire_serialize_tx__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_serialize_tx__static_method__Api(
+      port_,
+      tx,
+    );
+  }
 
-// Section: api_fill_to_wire
+  late final _wire_serialize_tx__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_serialize_tx__static_method__Api');
+  late final _wire_serialize_tx__static_method__Api =
+      _wire_serialize_tx__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire___record__out_point_String_usize(
-      (OutPoint, String, int) apiObj,
-      wire___record__out_point_String_usize wireObj) {
-    _api_fill_to_wire_out_point(apiObj.$1, wireObj.field0);
-    wireObj.field1 = api2wire_String(apiObj.$2);
-    wireObj.field2 = api2wire_usize(apiObj.$3);
+  void wire_is_coin_base__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_is_coin_base__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_address_index(
-      AddressIndex apiObj, wire_AddressIndex wireObj) {
-    if (apiObj is AddressIndex_New) {
-      wireObj.tag = 0;
-      return;
-    }
-    if (apiObj is AddressIndex_LastUnused) {
-      wireObj.tag = 1;
-      return;
-    }
-    if (apiObj is AddressIndex_Peek) {
-      var pre_index = api2wire_u32(apiObj.index);
-      wireObj.tag = 2;
-      wireObj.kind = inner.inflate_AddressIndex_Peek();
-      wireObj.kind.ref.Peek.ref.index = pre_index;
-      return;
-    }
-    if (apiObj is AddressIndex_Reset) {
-      var pre_index = api2wire_u32(apiObj.index);
-      wireObj.tag = 3;
-      wireObj.kind = inner.inflate_AddressIndex_Reset();
-      wireObj.kind.ref.Reset.ref.index = pre_index;
-      return;
-    }
-  }
+  late final _wire_is_coin_base__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_is_coin_base__static_method__Api');
+  late final _wire_is_coin_base__static_method__Api =
+      _wire_is_coin_base__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_blockchain_config(
-      BlockchainConfig apiObj, wire_BlockchainConfig wireObj) {
-    if (apiObj is BlockchainConfig_Electrum) {
-      var pre_config = api2wire_box_autoadd_electrum_config(apiObj.config);
-      wireObj.tag = 0;
-      wireObj.kind = inner.inflate_BlockchainConfig_Electrum();
-      wireObj.kind.ref.Electrum.ref.config = pre_config;
-      return;
-    }
-    if (apiObj is BlockchainConfig_Esplora) {
-      var pre_config = api2wire_box_autoadd_esplora_config(apiObj.config);
-      wireObj.tag = 1;
-      wireObj.kind = inner.inflate_BlockchainConfig_Esplora();
-      wireObj.kind.ref.Esplora.ref.config = pre_config;
-      return;
-    }
-    if (apiObj is BlockchainConfig_Rpc) {
-      var pre_config = api2wire_box_autoadd_rpc_config(apiObj.config);
-      wireObj.tag = 2;
-      wireObj.kind = inner.inflate_BlockchainConfig_Rpc();
-      wireObj.kind.ref.Rpc.ref.config = pre_config;
-      return;
-    }
+  void wire_is_explicitly_rbf__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_is_explicitly_rbf__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd___record__out_point_String_usize(
-      (OutPoint, String, int) apiObj,
-      ffi.Pointer<wire___record__out_point_String_usize> wireObj) {
-    _api_fill_to_wire___record__out_point_String_usize(apiObj, wireObj.ref);
-  }
+  late final _wire_is_explicitly_rbf__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_is_explicitly_rbf__static_method__Api');
+  late final _wire_is_explicitly_rbf__static_method__Api =
+      _wire_is_explicitly_rbf__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_address_index(
-      AddressIndex apiObj, ffi.Pointer<wire_AddressIndex> wireObj) {
-    _api_fill_to_wire_address_index(apiObj, wireObj.ref);
+  void wire_is_lock_time_enabled__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_is_lock_time_enabled__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_blockchain_config(
-      BlockchainConfig apiObj, ffi.Pointer<wire_BlockchainConfig> wireObj) {
-    _api_fill_to_wire_blockchain_config(apiObj, wireObj.ref);
-  }
+  late final _wire_is_lock_time_enabled__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_is_lock_time_enabled__static_method__Api');
+  late final _wire_is_lock_time_enabled__static_method__Api =
+      _wire_is_lock_time_enabled__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_database_config(
-      DatabaseConfig apiObj, ffi.Pointer<wire_DatabaseConfig> wireObj) {
-    _api_fill_to_wire_database_config(apiObj, wireObj.ref);
+  void wire_version__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_version__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_electrum_config(
-      ElectrumConfig apiObj, ffi.Pointer<wire_ElectrumConfig> wireObj) {
-    _api_fill_to_wire_electrum_config(apiObj, wireObj.ref);
-  }
+  late final _wire_version__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_version__static_method__Api');
+  late final _wire_version__static_method__Api =
+      _wire_version__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_esplora_config(
-      EsploraConfig apiObj, ffi.Pointer<wire_EsploraConfig> wireObj) {
-    _api_fill_to_wire_esplora_config(apiObj, wireObj.ref);
+  void wire_lock_time__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_lock_time__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_local_utxo(
-      LocalUtxo apiObj, ffi.Pointer<wire_LocalUtxo> wireObj) {
-    _api_fill_to_wire_local_utxo(apiObj, wireObj.ref);
+  late final _wire_lock_time__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_lock_time__static_method__Api');
+  late final _wire_lock_time__static_method__Api =
+      _wire_lock_time__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_input__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_input__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_psbt_sig_hash_type(
-      PsbtSigHashType apiObj, ffi.Pointer<wire_PsbtSigHashType> wireObj) {
-    _api_fill_to_wire_psbt_sig_hash_type(apiObj, wireObj.ref);
-  }
+  late final _wire_input__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_input__static_method__Api');
+  late final _wire_input__static_method__Api =
+      _wire_input__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_rbf_value(
-      RbfValue apiObj, ffi.Pointer<wire_RbfValue> wireObj) {
-    _api_fill_to_wire_rbf_value(apiObj, wireObj.ref);
+  void wire_output__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> tx,
+  ) {
+    return _wire_output__static_method__Api(
+      port_,
+      tx,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_rpc_config(
-      RpcConfig apiObj, ffi.Pointer<wire_RpcConfig> wireObj) {
-    _api_fill_to_wire_rpc_config(apiObj, wireObj.ref);
-  }
+  late final _wire_output__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_output__static_method__Api');
+  late final _wire_output__static_method__Api =
+      _wire_output__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_rpc_sync_params(
-      RpcSyncParams apiObj, ffi.Pointer<wire_RpcSyncParams> wireObj) {
-    _api_fill_to_wire_rpc_sync_params(apiObj, wireObj.ref);
+  void wire_serialize_psbt__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_serialize_psbt__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_script(
-      Script apiObj, ffi.Pointer<wire_Script> wireObj) {
-    _api_fill_to_wire_script(apiObj, wireObj.ref);
-  }
+  late final _wire_serialize_psbt__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_serialize_psbt__static_method__Api');
+  late final _wire_serialize_psbt__static_method__Api =
+      _wire_serialize_psbt__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_sign_options(
-      SignOptions apiObj, ffi.Pointer<wire_SignOptions> wireObj) {
-    _api_fill_to_wire_sign_options(apiObj, wireObj.ref);
+  void wire_psbt_from_bytes__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> bytes,
+  ) {
+    return _wire_psbt_from_bytes__static_method__Api(
+      port_,
+      bytes,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_sled_db_configuration(
-      SledDbConfiguration apiObj,
-      ffi.Pointer<wire_SledDbConfiguration> wireObj) {
-    _api_fill_to_wire_sled_db_configuration(apiObj, wireObj.ref);
-  }
+  late final _wire_psbt_from_bytes__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_from_bytes__static_method__Api');
+  late final _wire_psbt_from_bytes__static_method__Api =
+      _wire_psbt_from_bytes__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_box_autoadd_sqlite_db_configuration(
-      SqliteDbConfiguration apiObj,
-      ffi.Pointer<wire_SqliteDbConfiguration> wireObj) {
-    _api_fill_to_wire_sqlite_db_configuration(apiObj, wireObj.ref);
+  void wire_psbt_to_bytes__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_psbt_to_bytes__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_user_pass(
-      UserPass apiObj, ffi.Pointer<wire_UserPass> wireObj) {
-    _api_fill_to_wire_user_pass(apiObj, wireObj.ref);
-  }
+  late final _wire_psbt_to_bytes__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_to_bytes__static_method__Api');
+  late final _wire_psbt_to_bytes__static_method__Api =
+      _wire_psbt_to_bytes__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_database_config(
-      DatabaseConfig apiObj, wire_DatabaseConfig wireObj) {
-    if (apiObj is DatabaseConfig_Memory) {
-      wireObj.tag = 0;
-      return;
-    }
-    if (apiObj is DatabaseConfig_Sqlite) {
-      var pre_config =
-          api2wire_box_autoadd_sqlite_db_configuration(apiObj.config);
-      wireObj.tag = 1;
-      wireObj.kind = inner.inflate_DatabaseConfig_Sqlite();
-      wireObj.kind.ref.Sqlite.ref.config = pre_config;
-      return;
-    }
-    if (apiObj is DatabaseConfig_Sled) {
-      var pre_config =
-          api2wire_box_autoadd_sled_db_configuration(apiObj.config);
-      wireObj.tag = 2;
-      wireObj.kind = inner.inflate_DatabaseConfig_Sled();
-      wireObj.kind.ref.Sled.ref.config = pre_config;
-      return;
-    }
+  void wire_psbt_to_hex__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_psbt_to_hex__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_electrum_config(
-      ElectrumConfig apiObj, wire_ElectrumConfig wireObj) {
-    wireObj.url = api2wire_String(apiObj.url);
-    wireObj.socks5 = api2wire_opt_String(apiObj.socks5);
-    wireObj.retry = api2wire_u8(apiObj.retry);
-    wireObj.timeout = api2wire_opt_box_autoadd_u8(apiObj.timeout);
-    wireObj.stop_gap = api2wire_u64(apiObj.stopGap);
-    wireObj.validate_domain = api2wire_bool(apiObj.validateDomain);
-  }
+  late final _wire_psbt_to_hex__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_to_hex__static_method__Api');
+  late final _wire_psbt_to_hex__static_method__Api =
+      _wire_psbt_to_hex__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_esplora_config(
-      EsploraConfig apiObj, wire_EsploraConfig wireObj) {
-    wireObj.base_url = api2wire_String(apiObj.baseUrl);
-    wireObj.proxy = api2wire_opt_String(apiObj.proxy);
-    wireObj.concurrency = api2wire_opt_box_autoadd_u8(apiObj.concurrency);
-    wireObj.stop_gap = api2wire_u64(apiObj.stopGap);
-    wireObj.timeout = api2wire_opt_box_autoadd_u64(apiObj.timeout);
+  void wire_psbt_version__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_psbt_version__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_local_utxo(LocalUtxo apiObj, wire_LocalUtxo wireObj) {
-    _api_fill_to_wire_out_point(apiObj.outpoint, wireObj.outpoint);
-    _api_fill_to_wire_tx_out(apiObj.txout, wireObj.txout);
-    wireObj.is_spent = api2wire_bool(apiObj.isSpent);
-    wireObj.keychain = api2wire_keychain_kind(apiObj.keychain);
-  }
+  late final _wire_psbt_version__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_version__static_method__Api');
+  late final _wire_psbt_version__static_method__Api =
+      _wire_psbt_version__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_opt_box_autoadd___record__out_point_String_usize(
-      (OutPoint, String, int)? apiObj,
-      ffi.Pointer<wire___record__out_point_String_usize> wireObj) {
-    if (apiObj != null)
-      _api_fill_to_wire_box_autoadd___record__out_point_String_usize(
-          apiObj, wireObj);
+  void wire_serialize_psbt_version__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int version,
+  ) {
+    return _wire_serialize_psbt_version__static_method__Api(
+      port_,
+      psbt_str,
+      version,
+    );
   }
 
-  void _api_fill_to_wire_opt_box_autoadd_psbt_sig_hash_type(
-      PsbtSigHashType? apiObj, ffi.Pointer<wire_PsbtSigHashType> wireObj) {
-    if (apiObj != null)
-      _api_fill_to_wire_box_autoadd_psbt_sig_hash_type(apiObj, wireObj);
-  }
+  late final _wire_serialize_psbt_version__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32)>>('wire_serialize_psbt_version__static_method__Api');
+  late final _wire_serialize_psbt_version__static_method__Api =
+      _wire_serialize_psbt_version__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void _api_fill_to_wire_opt_box_autoadd_rbf_value(
-      RbfValue? apiObj, ffi.Pointer<wire_RbfValue> wireObj) {
-    if (apiObj != null)
-      _api_fill_to_wire_box_autoadd_rbf_value(apiObj, wireObj);
+  void wire_psbt_txid__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_psbt_txid__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_opt_box_autoadd_rpc_sync_params(
-      RpcSyncParams? apiObj, ffi.Pointer<wire_RpcSyncParams> wireObj) {
-    if (apiObj != null)
-      _api_fill_to_wire_box_autoadd_rpc_sync_params(apiObj, wireObj);
-  }
+  late final _wire_psbt_txid__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_txid__static_method__Api');
+  late final _wire_psbt_txid__static_method__Api =
+      _wire_psbt_txid__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_opt_box_autoadd_script(
-      Script? apiObj, ffi.Pointer<wire_Script> wireObj) {
-    if (apiObj != null) _api_fill_to_wire_box_autoadd_script(apiObj, wireObj);
+  void wire_extract_tx__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_extract_tx__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_opt_box_autoadd_sign_options(
-      SignOptions? apiObj, ffi.Pointer<wire_SignOptions> wireObj) {
-    if (apiObj != null)
-      _api_fill_to_wire_box_autoadd_sign_options(apiObj, wireObj);
-  }
+  late final _wire_extract_tx__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_extract_tx__static_method__Api');
+  late final _wire_extract_tx__static_method__Api =
+      _wire_extract_tx__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_opt_box_autoadd_user_pass(
-      UserPass? apiObj, ffi.Pointer<wire_UserPass> wireObj) {
-    if (apiObj != null)
-      _api_fill_to_wire_box_autoadd_user_pass(apiObj, wireObj);
+  void wire_psbt_fee_rate__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_psbt_fee_rate__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_out_point(OutPoint apiObj, wire_OutPoint wireObj) {
-    wireObj.txid = api2wire_String(apiObj.txid);
-    wireObj.vout = api2wire_u32(apiObj.vout);
-  }
+  late final _wire_psbt_fee_rate__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_fee_rate__static_method__Api');
+  late final _wire_psbt_fee_rate__static_method__Api =
+      _wire_psbt_fee_rate__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_psbt_sig_hash_type(
-      PsbtSigHashType apiObj, wire_PsbtSigHashType wireObj) {
-    wireObj.inner = api2wire_u32(apiObj.inner);
+  void wire_psbt_fee_amount__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+  ) {
+    return _wire_psbt_fee_amount__static_method__Api(
+      port_,
+      psbt_str,
+    );
   }
 
-  void _api_fill_to_wire_rbf_value(RbfValue apiObj, wire_RbfValue wireObj) {
-    if (apiObj is RbfValue_RbfDefault) {
-      wireObj.tag = 0;
-      return;
-    }
-    if (apiObj is RbfValue_Value) {
-      var pre_field0 = api2wire_u32(apiObj.field0);
-      wireObj.tag = 1;
-      wireObj.kind = inner.inflate_RbfValue_Value();
-      wireObj.kind.ref.Value.ref.field0 = pre_field0;
-      return;
-    }
-  }
+  late final _wire_psbt_fee_amount__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_fee_amount__static_method__Api');
+  late final _wire_psbt_fee_amount__static_method__Api =
+      _wire_psbt_fee_amount__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_rpc_config(RpcConfig apiObj, wire_RpcConfig wireObj) {
-    wireObj.url = api2wire_String(apiObj.url);
-    wireObj.auth_cookie = api2wire_opt_String(apiObj.authCookie);
-    wireObj.auth_user_pass =
-        api2wire_opt_box_autoadd_user_pass(apiObj.authUserPass);
-    wireObj.network = api2wire_network(apiObj.network);
-    wireObj.wallet_name = api2wire_String(apiObj.walletName);
-    wireObj.sync_params =
-        api2wire_opt_box_autoadd_rpc_sync_params(apiObj.syncParams);
+  void wire_combine_psbt__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_uint_8_list> other,
+  ) {
+    return _wire_combine_psbt__static_method__Api(
+      port_,
+      psbt_str,
+      other,
+    );
   }
 
-  void _api_fill_to_wire_rpc_sync_params(
-      RpcSyncParams apiObj, wire_RpcSyncParams wireObj) {
-    wireObj.start_script_count = api2wire_u64(apiObj.startScriptCount);
-    wireObj.start_time = api2wire_u64(apiObj.startTime);
-    wireObj.force_start_time = api2wire_bool(apiObj.forceStartTime);
-    wireObj.poll_rate_sec = api2wire_u64(apiObj.pollRateSec);
-  }
+  late final _wire_combine_psbt__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_combine_psbt__static_method__Api');
+  late final _wire_combine_psbt__static_method__Api =
+      _wire_combine_psbt__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_script(Script apiObj, wire_Script wireObj) {
-    wireObj.internal = api2wire_uint_8_list(apiObj.internal);
+  void wire_combine_psbts__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_StringList> psbt_strs,
+  ) {
+    return _wire_combine_psbts__static_method__Api(
+      port_,
+      psbt_strs,
+    );
   }
 
-  void _api_fill_to_wire_script_amount(
-      ScriptAmount apiObj, wire_ScriptAmount wireObj) {
-    _api_fill_to_wire_script(apiObj.script, wireObj.script);
-    wireObj.amount = api2wire_u64(apiObj.amount);
-  }
+  late final _wire_combine_psbts__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
+      'wire_combine_psbts__static_method__Api');
+  late final _wire_combine_psbts__static_method__Api =
+      _wire_combine_psbts__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
 
-  void _api_fill_to_wire_sign_options(
-      SignOptions apiObj, wire_SignOptions wireObj) {
-    wireObj.is_multi_sig = api2wire_bool(apiObj.isMultiSig);
-    wireObj.trust_witness_utxo = api2wire_bool(apiObj.trustWitnessUtxo);
-    wireObj.assume_height = api2wire_opt_box_autoadd_u32(apiObj.assumeHeight);
-    wireObj.allow_all_sighashes = api2wire_bool(apiObj.allowAllSighashes);
-    wireObj.remove_partial_sigs = api2wire_bool(apiObj.removePartialSigs);
-    wireObj.try_finalize = api2wire_bool(apiObj.tryFinalize);
-    wireObj.sign_with_tap_internal_key =
-        api2wire_bool(apiObj.signWithTapInternalKey);
-    wireObj.allow_grinding = api2wire_bool(apiObj.allowGrinding);
+  void wire_psbt_add_input__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_OutPoint> outpoint,
+    ffi.Pointer<wire_uint_8_list> psbt_input,
+    ffi.Pointer<ffi.Uint32> sequence,
+  ) {
+    return _wire_psbt_add_input__static_method__Api(
+      port_,
+      psbt_str,
+      outpoint,
+      psbt_input,
+      sequence,
+    );
   }
 
-  void _api_fill_to_wire_sled_db_configuration(
-      SledDbConfiguration apiObj, wire_SledDbConfiguration wireObj) {
-    wireObj.path = api2wire_String(apiObj.path);
-    wireObj.tree_name = api2wire_String(apiObj.treeName);
-  }
+  late final _wire_psbt_add_input__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_OutPoint>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<ffi.Uint32>)>>(
+      'wire_psbt_add_input__static_method__Api');
+  late final _wire_psbt_add_input__static_method__Api =
+      _wire_psbt_add_input__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_OutPoint>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<ffi.Uint32>)>();
 
-  void _api_fill_to_wire_sqlite_db_configuration(
-      SqliteDbConfiguration apiObj, wire_SqliteDbConfiguration wireObj) {
-    wireObj.path = api2wire_String(apiObj.path);
+  void wire_psbt_remove_input__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int index,
+  ) {
+    return _wire_psbt_remove_input__static_method__Api(
+      port_,
+      psbt_str,
+      index,
+    );
   }
 
-  void _api_fill_to_wire_tx_out(TxOut apiObj, wire_TxOut wireObj) {
-    wireObj.value = api2wire_u64(apiObj.value);
-    _api_fill_to_wire_script(apiObj.scriptPubkey, wireObj.script_pubkey);
-  }
+  late final _wire_psbt_remove_input__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32)>>('wire_psbt_remove_input__static_method__Api');
+  late final _wire_psbt_remove_input__static_method__Api =
+      _wire_psbt_remove_input__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void _api_fill_to_wire_user_pass(UserPass apiObj, wire_UserPass wireObj) {
-    wireObj.username = api2wire_String(apiObj.username);
-    wireObj.password = api2wire_String(apiObj.password);
+  void wire_psbt_add_output__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_Script> script,
+    int amount,
+  ) {
+    return _wire_psbt_add_output__static_method__Api(
+      port_,
+      psbt_str,
+      script,
+      amount,
+    );
   }
-}
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
-
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-// ignore_for_file: type=lint
+  late final _wire_psbt_add_output__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_Script>,
+              ffi.Uint64)>>('wire_psbt_add_output__static_method__Api');
+  late final _wire_psbt_add_output__static_method__Api =
+      _wire_psbt_add_output__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_Script>, int)>();
 
-/// generated by flutter_rust_bridge
-class RustBdkFfiWire implements FlutterRustBridgeWireBase {
-  @internal
-  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+  void wire_psbt_remove_output__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int index,
+  ) {
+    return _wire_psbt_remove_output__static_method__Api(
+      port_,
+      psbt_str,
+      index,
+    );
+  }
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+  late final _wire_psbt_remove_output__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32)>>('wire_psbt_remove_output__static_method__Api');
+  late final _wire_psbt_remove_output__static_method__Api =
+      _wire_psbt_remove_output__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustBdkFfiWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
+  void wire_psbt_set_sequence__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int index,
+    int sequence,
+  ) {
+    return _wire_psbt_set_sequence__static_method__Api(
+      port_,
+      psbt_str,
+      index,
+      sequence,
+    );
+  }
 
-  /// The symbols are looked up with [lookup].
-  RustBdkFfiWire.fromLookup(
-      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-          lookup)
-      : _lookup = lookup;
+  late final _wire_psbt_set_sequence__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32,
+              ffi.Uint32)>>('wire_psbt_set_sequence__static_method__Api');
+  late final _wire_psbt_set_sequence__static_method__Api =
+      _wire_psbt_set_sequence__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>, int, int)>();
 
-  void store_dart_post_cobject(
-    DartPostCObjectFnType ptr,
+  void wire_psbt_set_lock_time__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int lock_time,
   ) {
-    return _store_dart_post_cobject(
-      ptr,
+    return _wire_psbt_set_lock_time__static_method__Api(
+      port_,
+      psbt_str,
+      lock_time,
     );
   }
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-          'store_dart_post_cobject');
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+  late final _wire_psbt_set_lock_time__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32)>>('wire_psbt_set_lock_time__static_method__Api');
+  late final _wire_psbt_set_lock_time__static_method__Api =
+      _wire_psbt_set_lock_time__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  Object get_dart_object(
-    int ptr,
+  void wire_psbt_set_unknown__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_PsbtMap> map,
+    int key_type,
+    ffi.Pointer<wire_uint_8_list> key,
+    ffi.Pointer<wire_uint_8_list> value,
   ) {
-    return _get_dart_object(
-      ptr,
+    return _wire_psbt_set_unknown__static_method__Api(
+      port_,
+      psbt_str,
+      map,
+      key_type,
+      key,
+      value,
     );
   }
 
-  late final _get_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
-          'get_dart_object');
-  late final _get_dart_object =
-      _get_dart_objectPtr.asFunction<Object Function(int)>();
+  late final _wire_psbt_set_unknown__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_PsbtMap>,
+                  ffi.Uint8,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_set_unknown__static_method__Api');
+  late final _wire_psbt_set_unknown__static_method__Api =
+      _wire_psbt_set_unknown__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_PsbtMap>,
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void drop_dart_object(
-    int ptr,
+  void wire_psbt_set_proprietary__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_PsbtMap> map,
+    ffi.Pointer<wire_uint_8_list> prefix,
+    int subtype,
+    ffi.Pointer<wire_uint_8_list> key,
+    ffi.Pointer<wire_uint_8_list> value,
   ) {
-    return _drop_dart_object(
-      ptr,
+    return _wire_psbt_set_proprietary__static_method__Api(
+      port_,
+      psbt_str,
+      map,
+      prefix,
+      subtype,
+      key,
+      value,
     );
   }
 
-  late final _drop_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
-          'drop_dart_object');
-  late final _drop_dart_object =
-      _drop_dart_objectPtr.asFunction<void Function(int)>();
+  late final _wire_psbt_set_proprietary__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_PsbtMap>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Uint8,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_set_proprietary__static_method__Api');
+  late final _wire_psbt_set_proprietary__static_method__Api =
+      _wire_psbt_set_proprietary__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_PsbtMap>,
+              ffi.Pointer<wire_uint_8_list>,
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  int new_dart_opaque(
-    Object handle,
+  void wire_finalize_psbt_with_descriptor__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_uint_8_list> descriptor,
+    ffi.Pointer<wire_uint_8_list> change_descriptor,
+    int network,
+    int lookahead,
   ) {
-    return _new_dart_opaque(
-      handle,
+    return _wire_finalize_psbt_with_descriptor__static_method__Api(
+      port_,
+      psbt_str,
+      descriptor,
+      change_descriptor,
+      network,
+      lookahead,
     );
   }
 
-  late final _new_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
-          'new_dart_opaque');
-  late final _new_dart_opaque =
-      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+  late final _wire_finalize_psbt_with_descriptor__static_method__ApiPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Int32,
+                      ffi.Uint32)>>(
+          'wire_finalize_psbt_with_descriptor__static_method__Api');
+  late final _wire_finalize_psbt_with_descriptor__static_method__Api =
+      _wire_finalize_psbt_with_descriptor__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              int,
+              int)>();
 
-  int init_frb_dart_api_dl(
-    ffi.Pointer<ffi.Void> obj,
+  void wire_psbt_inputs__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
   ) {
-    return _init_frb_dart_api_dl(
-      obj,
+    return _wire_psbt_inputs__static_method__Api(
+      port_,
+      psbt_str,
     );
   }
 
-  late final _init_frb_dart_api_dlPtr =
-      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
-          'init_frb_dart_api_dl');
-  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
-      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+  late final _wire_psbt_inputs__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_psbt_inputs__static_method__Api');
+  late final _wire_psbt_inputs__static_method__Api =
+      _wire_psbt_inputs__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_create_blockchain__static_method__Api(
+  void wire_psbt_outputs__static_method__Api(
     int port_,
-    ffi.Pointer<wire_BlockchainConfig> config,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int network,
   ) {
-    return _wire_create_blockchain__static_method__Api(
+    return _wire_psbt_outputs__static_method__Api(
       port_,
-      config,
+      psbt_str,
+      network,
     );
   }
 
-  late final _wire_create_blockchain__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(
-                  ffi.Int64, ffi.Pointer<wire_BlockchainConfig>)>>(
-      'wire_create_blockchain__static_method__Api');
-  late final _wire_create_blockchain__static_method__Api =
-      _wire_create_blockchain__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_BlockchainConfig>)>();
+  late final _wire_psbt_outputs__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_psbt_outputs__static_method__Api');
+  late final _wire_psbt_outputs__static_method__Api =
+      _wire_psbt_outputs__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void wire_get_height__static_method__Api(
+  void wire_json_serialize__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> blockchain_id,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
   ) {
-    return _wire_get_height__static_method__Api(
+    return _wire_json_serialize__static_method__Api(
       port_,
-      blockchain_id,
+      psbt_str,
     );
   }
 
-  late final _wire_get_height__static_method__ApiPtr = _lookup<
+  late final _wire_json_serialize__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_get_height__static_method__Api');
-  late final _wire_get_height__static_method__Api =
-      _wire_get_height__static_method__ApiPtr
+      'wire_json_serialize__static_method__Api');
+  late final _wire_json_serialize__static_method__Api =
+      _wire_json_serialize__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_get_blockchain_hash__static_method__Api(
+  void wire_tx_builder_finish__static_method__Api(
     int port_,
-    int blockchain_height,
-    ffi.Pointer<wire_uint_8_list> blockchain_id,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_list_script_amount> recipients,
+    ffi.Pointer<wire_list_out_point> utxos,
+    ffi.Pointer<wire___record__out_point_String_usize> foreign_utxo,
+    ffi.Pointer<wire_list_out_point> unspendable,
+    int change_policy,
+    bool manually_selected_only,
+    ffi.Pointer<ffi.Float> fee_rate,
+    ffi.Pointer<ffi.Uint64> fee_absolute,
+    bool drain_wallet,
+    ffi.Pointer<wire_Script> drain_to,
+    ffi.Pointer<wire_RbfValue> rbf,
+    ffi.Pointer<wire_uint_8_list> data,
   ) {
-    return _wire_get_blockchain_hash__static_method__Api(
+    return _wire_tx_builder_finish__static_method__Api(
       port_,
-      blockchain_height,
-      blockchain_id,
+      wallet_id,
+      recipients,
+      utxos,
+      foreign_utxo,
+      unspendable,
+      change_policy,
+      manually_selected_only,
+      fee_rate,
+      fee_absolute,
+      drain_wallet,
+      drain_to,
+      rbf,
+      data,
     );
   }
 
-  late final _wire_get_blockchain_hash__static_method__ApiPtr = _lookup<
+  late final _wire_tx_builder_finish__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(
-                  ffi.Int64, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_get_blockchain_hash__static_method__Api');
-  late final _wire_get_blockchain_hash__static_method__Api =
-      _wire_get_blockchain_hash__static_method__ApiPtr
-          .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_list_script_amount>,
+                  ffi.Pointer<wire_list_out_point>,
+                  ffi.Pointer<wire___record__out_point_String_usize>,
+                  ffi.Pointer<wire_list_out_point>,
+                  ffi.Int32,
+                  ffi.Bool,
+                  ffi.Pointer<ffi.Float>,
+                  ffi.Pointer<ffi.Uint64>,
+                  ffi.Bool,
+                  ffi.Pointer<wire_Script>,
+                  ffi.Pointer<wire_RbfValue>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_tx_builder_finish__static_method__Api');
+  late final _wire_tx_builder_finish__static_method__Api =
+      _wire_tx_builder_finish__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_list_script_amount>,
+              ffi.Pointer<wire_list_out_point>,
+              ffi.Pointer<wire___record__out_point_String_usize>,
+              ffi.Pointer<wire_list_out_point>,
+              int,
+              bool,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<ffi.Uint64>,
+              bool,
+              ffi.Pointer<wire_Script>,
+              ffi.Pointer<wire_RbfValue>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_estimate_fee__static_method__Api(
+  void wire_bump_fee_tx_builder_finish__static_method__Api(
     int port_,
-    int target,
-    ffi.Pointer<wire_uint_8_list> blockchain_id,
+    ffi.Pointer<wire_uint_8_list> txid,
+    ffi.Pointer<ffi.Float> fee_rate,
+    ffi.Pointer<ffi.Uint64> fee_absolute,
+    ffi.Pointer<wire_ShrinkOutput> allow_shrinking,
+    ffi.Pointer<wire_list_out_point> utxos,
+    ffi.Pointer<wire_list_out_point> unspendable,
+    bool manually_selected_only,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    bool enable_rbf,
+    ffi.Pointer<ffi.Uint32> n_sequence,
   ) {
-    return _wire_estimate_fee__static_method__Api(
+    return _wire_bump_fee_tx_builder_finish__static_method__Api(
       port_,
-      target,
-      blockchain_id,
+      txid,
+      fee_rate,
+      fee_absolute,
+      allow_shrinking,
+      utxos,
+      unspendable,
+      manually_selected_only,
+      wallet_id,
+      enable_rbf,
+      n_sequence,
     );
   }
 
-  late final _wire_estimate_fee__static_method__ApiPtr = _lookup<
+  late final _wire_bump_fee_tx_builder_finish__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(
-                  ffi.Int64, ffi.Uint64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_estimate_fee__static_method__Api');
-  late final _wire_estimate_fee__static_method__Api =
-      _wire_estimate_fee__static_method__ApiPtr
-          .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<ffi.Float>,
+                  ffi.Pointer<ffi.Uint64>,
+                  ffi.Pointer<wire_ShrinkOutput>,
+                  ffi.Pointer<wire_list_out_point>,
+                  ffi.Pointer<wire_list_out_point>,
+                  ffi.Bool,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Bool,
+                  ffi.Pointer<ffi.Uint32>)>>(
+      'wire_bump_fee_tx_builder_finish__static_method__Api');
+  late final _wire_bump_fee_tx_builder_finish__static_method__Api =
+      _wire_bump_fee_tx_builder_finish__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<ffi.Uint64>,
+              ffi.Pointer<wire_ShrinkOutput>,
+              ffi.Pointer<wire_list_out_point>,
+              ffi.Pointer<wire_list_out_point>,
+              bool,
+              ffi.Pointer<wire_uint_8_list>,
+              bool,
+              ffi.Pointer<ffi.Uint32>)>();
 
-  void wire_broadcast__static_method__Api(
+  void wire_cancel_transaction__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
-    ffi.Pointer<wire_uint_8_list> blockchain_id,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> txid,
+    double fee_rate,
   ) {
-    return _wire_broadcast__static_method__Api(
+    return _wire_cancel_transaction__static_method__Api(
       port_,
-      tx,
-      blockchain_id,
+      wallet_id,
+      txid,
+      fee_rate,
     );
   }
 
-  late final _wire_broadcast__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_broadcast__static_method__Api');
-  late final _wire_broadcast__static_method__Api =
-      _wire_broadcast__static_method__ApiPtr.asFunction<
+  late final _wire_cancel_transaction__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Float)>>('wire_cancel_transaction__static_method__Api');
+  late final _wire_cancel_transaction__static_method__Api =
+      _wire_cancel_transaction__static_method__ApiPtr.asFunction<
           void Function(int, ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Pointer<wire_uint_8_list>, double)>();
 
-  void wire_create_transaction__static_method__Api(
+  void wire_parse_payjoin_uri__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> uri,
   ) {
-    return _wire_create_transaction__static_method__Api(
+    return _wire_parse_payjoin_uri__static_method__Api(
       port_,
-      tx,
+      uri,
     );
   }
 
-  late final _wire_create_transaction__static_method__ApiPtr = _lookup<
+  late final _wire_parse_payjoin_uri__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_create_transaction__static_method__Api');
-  late final _wire_create_transaction__static_method__Api =
-      _wire_create_transaction__static_method__ApiPtr
+      'wire_parse_payjoin_uri__static_method__Api');
+  late final _wire_parse_payjoin_uri__static_method__Api =
+      _wire_parse_payjoin_uri__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_tx_txid__static_method__Api(
+  void wire_payjoin_sender_request__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> uri,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_PayjoinParams> params,
+    ffi.Pointer<wire_SignOptions> sign_options,
+  ) {
+    return _wire_payjoin_sender_request__static_method__Api(
+      port_,
+      wallet_id,
+      uri,
+      psbt_str,
+      params,
+      sign_options,
+    );
+  }
+
+  late final _wire_payjoin_sender_request__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_PayjoinParams>,
+                  ffi.Pointer<wire_SignOptions>)>>(
+      'wire_payjoin_sender_request__static_method__Api');
+  late final _wire_payjoin_sender_request__static_method__Api =
+      _wire_payjoin_sender_request__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_PayjoinParams>,
+              ffi.Pointer<wire_SignOptions>)>();
+
+  void wire_payjoin_send__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> uri,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_PayjoinParams> params,
+    ffi.Pointer<wire_SignOptions> sign_options,
   ) {
-    return _wire_tx_txid__static_method__Api(
+    return _wire_payjoin_send__static_method__Api(
       port_,
-      tx,
+      wallet_id,
+      uri,
+      psbt_str,
+      params,
+      sign_options,
     );
   }
 
-  late final _wire_tx_txid__static_method__ApiPtr = _lookup<
+  late final _wire_payjoin_send__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_tx_txid__static_method__Api');
-  late final _wire_tx_txid__static_method__Api =
-      _wire_tx_txid__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_PayjoinParams>,
+                  ffi.Pointer<wire_SignOptions>)>>(
+      'wire_payjoin_send__static_method__Api');
+  late final _wire_payjoin_send__static_method__Api =
+      _wire_payjoin_send__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_PayjoinParams>,
+              ffi.Pointer<wire_SignOptions>)>();
 
-  void wire_weight__static_method__Api(
+  void wire_payjoin_sender_process_response__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> uri,
+    ffi.Pointer<wire_uint_8_list> original_psbt,
+    ffi.Pointer<wire_PayjoinParams> params,
+    ffi.Pointer<wire_uint_8_list> response,
+    ffi.Pointer<wire_SignOptions> sign_options,
   ) {
-    return _wire_weight__static_method__Api(
+    return _wire_payjoin_sender_process_response__static_method__Api(
       port_,
-      tx,
+      wallet_id,
+      uri,
+      original_psbt,
+      params,
+      response,
+      sign_options,
     );
   }
 
-  late final _wire_weight__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_weight__static_method__Api');
-  late final _wire_weight__static_method__Api =
-      _wire_weight__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_payjoin_sender_process_response__static_method__ApiPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_PayjoinParams>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_SignOptions>)>>(
+          'wire_payjoin_sender_process_response__static_method__Api');
+  late final _wire_payjoin_sender_process_response__static_method__Api =
+      _wire_payjoin_sender_process_response__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_PayjoinParams>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_SignOptions>)>();
 
-  void wire_size__static_method__Api(
+  void wire_payjoin_receive__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> original_psbt,
+    ffi.Pointer<wire_uint_8_list> query,
   ) {
-    return _wire_size__static_method__Api(
+    return _wire_payjoin_receive__static_method__Api(
       port_,
-      tx,
+      wallet_id,
+      original_psbt,
+      query,
     );
   }
 
-  late final _wire_size__static_method__ApiPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_size__static_method__Api');
-  late final _wire_size__static_method__Api = _wire_size__static_method__ApiPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_payjoin_receive__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_payjoin_receive__static_method__Api');
+  late final _wire_payjoin_receive__static_method__Api =
+      _wire_payjoin_receive__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_vsize__static_method__Api(
+  void wire_psbt_to_ur__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int max_fragment_length,
+    int start_seq_num,
+    int count,
   ) {
-    return _wire_vsize__static_method__Api(
+    return _wire_psbt_to_ur__static_method__Api(
       port_,
-      tx,
+      psbt_str,
+      max_fragment_length,
+      start_seq_num,
+      count,
     );
   }
 
-  late final _wire_vsize__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_vsize__static_method__Api');
-  late final _wire_vsize__static_method__Api =
-      _wire_vsize__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
-
-  void wire_serialize_tx__static_method__Api(
+  late final _wire_psbt_to_ur__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32,
+              ffi.Uint32,
+              ffi.Uint32)>>('wire_psbt_to_ur__static_method__Api');
+  late final _wire_psbt_to_ur__static_method__Api =
+      _wire_psbt_to_ur__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>, int, int, int)>();
+
+  void wire_descriptor_to_ur__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> descriptor,
+    int network,
   ) {
-    return _wire_serialize_tx__static_method__Api(
+    return _wire_descriptor_to_ur__static_method__Api(
       port_,
-      tx,
+      descriptor,
+      network,
     );
   }
 
-  late final _wire_serialize_tx__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_serialize_tx__static_method__Api');
-  late final _wire_serialize_tx__static_method__Api =
-      _wire_serialize_tx__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_descriptor_to_ur__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_descriptor_to_ur__static_method__Api');
+  late final _wire_descriptor_to_ur__static_method__Api =
+      _wire_descriptor_to_ur__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void wire_is_coin_base__static_method__Api(
+  void wire_descriptors_to_ur_account__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_StringList> descriptors,
+    int network,
   ) {
-    return _wire_is_coin_base__static_method__Api(
+    return _wire_descriptors_to_ur_account__static_method__Api(
       port_,
-      tx,
+      descriptors,
+      network,
     );
   }
 
-  late final _wire_is_coin_base__static_method__ApiPtr = _lookup<
+  late final _wire_descriptors_to_ur_account__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_is_coin_base__static_method__Api');
-  late final _wire_is_coin_base__static_method__Api =
-      _wire_is_coin_base__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_StringList>, ffi.Int32)>>(
+      'wire_descriptors_to_ur_account__static_method__Api');
+  late final _wire_descriptors_to_ur_account__static_method__Api =
+      _wire_descriptors_to_ur_account__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_StringList>, int)>();
 
-  void wire_is_explicitly_rbf__static_method__Api(
+  void wire_create_ur_decoder__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    int network,
   ) {
-    return _wire_is_explicitly_rbf__static_method__Api(
+    return _wire_create_ur_decoder__static_method__Api(
       port_,
-      tx,
+      network,
     );
   }
 
-  late final _wire_is_explicitly_rbf__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_is_explicitly_rbf__static_method__Api');
-  late final _wire_is_explicitly_rbf__static_method__Api =
-      _wire_is_explicitly_rbf__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_create_ur_decoder__static_method__ApiPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
+          'wire_create_ur_decoder__static_method__Api');
+  late final _wire_create_ur_decoder__static_method__Api =
+      _wire_create_ur_decoder__static_method__ApiPtr
+          .asFunction<void Function(int, int)>();
 
-  void wire_is_lock_time_enabled__static_method__Api(
+  void wire_ur_decoder_receive__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> decoder_id,
+    ffi.Pointer<wire_uint_8_list> part,
   ) {
-    return _wire_is_lock_time_enabled__static_method__Api(
+    return _wire_ur_decoder_receive__static_method__Api(
       port_,
-      tx,
+      decoder_id,
+      part,
     );
   }
 
-  late final _wire_is_lock_time_enabled__static_method__ApiPtr = _lookup<
+  late final _wire_ur_decoder_receive__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_is_lock_time_enabled__static_method__Api');
-  late final _wire_is_lock_time_enabled__static_method__Api =
-      _wire_is_lock_time_enabled__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_ur_decoder_receive__static_method__Api');
+  late final _wire_ur_decoder_receive__static_method__Api =
+      _wire_ur_decoder_receive__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_version__static_method__Api(
+  void wire_drop_ur_decoder__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> decoder_id,
   ) {
-    return _wire_version__static_method__Api(
+    return _wire_drop_ur_decoder__static_method__Api(
       port_,
-      tx,
+      decoder_id,
     );
   }
 
-  late final _wire_version__static_method__ApiPtr = _lookup<
+  late final _wire_drop_ur_decoder__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_version__static_method__Api');
-  late final _wire_version__static_method__Api =
-      _wire_version__static_method__ApiPtr
+      'wire_drop_ur_decoder__static_method__Api');
+  late final _wire_drop_ur_decoder__static_method__Api =
+      _wire_drop_ur_decoder__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_lock_time__static_method__Api(
+  void wire_create_multisig_session__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> descriptor,
+    ffi.Pointer<wire_uint_8_list> change_descriptor,
+    int network,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
   ) {
-    return _wire_lock_time__static_method__Api(
+    return _wire_create_multisig_session__static_method__Api(
       port_,
-      tx,
+      descriptor,
+      change_descriptor,
+      network,
+      psbt_str,
     );
   }
 
-  late final _wire_lock_time__static_method__ApiPtr = _lookup<
+  late final _wire_create_multisig_session__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_lock_time__static_method__Api');
-  late final _wire_lock_time__static_method__Api =
-      _wire_lock_time__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Int32,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_create_multisig_session__static_method__Api');
+  late final _wire_create_multisig_session__static_method__Api =
+      _wire_create_multisig_session__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              int,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_input__static_method__Api(
+  void wire_restore_multisig_session__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> data,
   ) {
-    return _wire_input__static_method__Api(
+    return _wire_restore_multisig_session__static_method__Api(
       port_,
-      tx,
+      data,
     );
   }
 
-  late final _wire_input__static_method__ApiPtr = _lookup<
+  late final _wire_restore_multisig_session__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_input__static_method__Api');
-  late final _wire_input__static_method__Api =
-      _wire_input__static_method__ApiPtr
+      'wire_restore_multisig_session__static_method__Api');
+  late final _wire_restore_multisig_session__static_method__Api =
+      _wire_restore_multisig_session__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_output__static_method__Api(
+  void wire_export_multisig_session__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> tx,
+    ffi.Pointer<wire_uint_8_list> session_id,
   ) {
-    return _wire_output__static_method__Api(
+    return _wire_export_multisig_session__static_method__Api(
       port_,
-      tx,
+      session_id,
     );
   }
 
-  late final _wire_output__static_method__ApiPtr = _lookup<
+  late final _wire_export_multisig_session__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_output__static_method__Api');
-  late final _wire_output__static_method__Api =
-      _wire_output__static_method__ApiPtr
+      'wire_export_multisig_session__static_method__Api');
+  late final _wire_export_multisig_session__static_method__Api =
+      _wire_export_multisig_session__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_serialize_psbt__static_method__Api(
+  void wire_multisig_session_add_psbt__static_method__Api(
     int port_,
+    ffi.Pointer<wire_uint_8_list> session_id,
     ffi.Pointer<wire_uint_8_list> psbt_str,
   ) {
-    return _wire_serialize_psbt__static_method__Api(
+    return _wire_multisig_session_add_psbt__static_method__Api(
       port_,
+      session_id,
       psbt_str,
     );
   }
 
-  late final _wire_serialize_psbt__static_method__ApiPtr = _lookup<
+  late final _wire_multisig_session_add_psbt__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_serialize_psbt__static_method__Api');
-  late final _wire_serialize_psbt__static_method__Api =
-      _wire_serialize_psbt__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_multisig_session_add_psbt__static_method__Api');
+  late final _wire_multisig_session_add_psbt__static_method__Api =
+      _wire_multisig_session_add_psbt__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_psbt_txid__static_method__Api(
+  void wire_multisig_session_status__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_uint_8_list> session_id,
   ) {
-    return _wire_psbt_txid__static_method__Api(
+    return _wire_multisig_session_status__static_method__Api(
       port_,
-      psbt_str,
+      session_id,
     );
   }
 
-  late final _wire_psbt_txid__static_method__ApiPtr = _lookup<
+  late final _wire_multisig_session_status__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_psbt_txid__static_method__Api');
-  late final _wire_psbt_txid__static_method__Api =
-      _wire_psbt_txid__static_method__ApiPtr
+      'wire_multisig_session_status__static_method__Api');
+  late final _wire_multisig_session_status__static_method__Api =
+      _wire_multisig_session_status__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_extract_tx__static_method__Api(
+  void wire_finalize_multisig_session__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_uint_8_list> session_id,
   ) {
-    return _wire_extract_tx__static_method__Api(
+    return _wire_finalize_multisig_session__static_method__Api(
       port_,
-      psbt_str,
+      session_id,
     );
   }
 
-  late final _wire_extract_tx__static_method__ApiPtr = _lookup<
+  late final _wire_finalize_multisig_session__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_extract_tx__static_method__Api');
-  late final _wire_extract_tx__static_method__Api =
-      _wire_extract_tx__static_method__ApiPtr
+      'wire_finalize_multisig_session__static_method__Api');
+  late final _wire_finalize_multisig_session__static_method__Api =
+      _wire_finalize_multisig_session__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_psbt_fee_rate__static_method__Api(
+  void wire_drop_multisig_session__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_uint_8_list> session_id,
   ) {
-    return _wire_psbt_fee_rate__static_method__Api(
+    return _wire_drop_multisig_session__static_method__Api(
       port_,
-      psbt_str,
+      session_id,
     );
   }
 
-  late final _wire_psbt_fee_rate__static_method__ApiPtr = _lookup<
+  late final _wire_drop_multisig_session__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_psbt_fee_rate__static_method__Api');
-  late final _wire_psbt_fee_rate__static_method__Api =
-      _wire_psbt_fee_rate__static_method__ApiPtr
+      'wire_drop_multisig_session__static_method__Api');
+  late final _wire_drop_multisig_session__static_method__Api =
+      _wire_drop_multisig_session__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_psbt_fee_amount__static_method__Api(
+  void wire_bsms_new_token__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> psbt_str,
+    int encryption,
   ) {
-    return _wire_psbt_fee_amount__static_method__Api(
+    return _wire_bsms_new_token__static_method__Api(
       port_,
-      psbt_str,
+      encryption,
     );
   }
 
-  late final _wire_psbt_fee_amount__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_psbt_fee_amount__static_method__Api');
-  late final _wire_psbt_fee_amount__static_method__Api =
-      _wire_psbt_fee_amount__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_bsms_new_token__static_method__ApiPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
+          'wire_bsms_new_token__static_method__Api');
+  late final _wire_bsms_new_token__static_method__Api =
+      _wire_bsms_new_token__static_method__ApiPtr
+          .asFunction<void Function(int, int)>();
 
-  void wire_combine_psbt__static_method__Api(
+  void wire_bsms_key_record__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> psbt_str,
-    ffi.Pointer<wire_uint_8_list> other,
+    ffi.Pointer<wire_uint_8_list> secret_key,
+    ffi.Pointer<wire_uint_8_list> token,
+    ffi.Pointer<wire_uint_8_list> description,
   ) {
-    return _wire_combine_psbt__static_method__Api(
+    return _wire_bsms_key_record__static_method__Api(
       port_,
-      psbt_str,
-      other,
+      secret_key,
+      token,
+      description,
     );
   }
 
-  late final _wire_combine_psbt__static_method__ApiPtr = _lookup<
+  late final _wire_bsms_key_record__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
                   ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_combine_psbt__static_method__Api');
-  late final _wire_combine_psbt__static_method__Api =
-      _wire_combine_psbt__static_method__ApiPtr.asFunction<
+      'wire_bsms_key_record__static_method__Api');
+  late final _wire_bsms_key_record__static_method__Api =
+      _wire_bsms_key_record__static_method__ApiPtr.asFunction<
           void Function(int, ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_json_serialize__static_method__Api(
+  void wire_bsms_verify_key_record__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_uint_8_list> record,
+    ffi.Pointer<wire_uint_8_list> token,
   ) {
-    return _wire_json_serialize__static_method__Api(
+    return _wire_bsms_verify_key_record__static_method__Api(
       port_,
-      psbt_str,
+      record,
+      token,
     );
   }
 
-  late final _wire_json_serialize__static_method__ApiPtr = _lookup<
+  late final _wire_bsms_verify_key_record__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_json_serialize__static_method__Api');
-  late final _wire_json_serialize__static_method__Api =
-      _wire_json_serialize__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_bsms_verify_key_record__static_method__Api');
+  late final _wire_bsms_verify_key_record__static_method__Api =
+      _wire_bsms_verify_key_record__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_tx_builder_finish__static_method__Api(
+  void wire_bsms_descriptor_record__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> wallet_id,
-    ffi.Pointer<wire_list_script_amount> recipients,
-    ffi.Pointer<wire_list_out_point> utxos,
-    ffi.Pointer<wire___record__out_point_String_usize> foreign_utxo,
-    ffi.Pointer<wire_list_out_point> unspendable,
-    int change_policy,
-    bool manually_selected_only,
-    ffi.Pointer<ffi.Float> fee_rate,
-    ffi.Pointer<ffi.Uint64> fee_absolute,
-    bool drain_wallet,
-    ffi.Pointer<wire_Script> drain_to,
-    ffi.Pointer<wire_RbfValue> rbf,
-    ffi.Pointer<wire_uint_8_list> data,
+    int threshold,
+    ffi.Pointer<wire_StringList> keys,
+    int script_type,
+    ffi.Pointer<wire_uint_8_list> token,
+    int network,
   ) {
-    return _wire_tx_builder_finish__static_method__Api(
+    return _wire_bsms_descriptor_record__static_method__Api(
       port_,
-      wallet_id,
-      recipients,
-      utxos,
-      foreign_utxo,
-      unspendable,
-      change_policy,
-      manually_selected_only,
-      fee_rate,
-      fee_absolute,
-      drain_wallet,
-      drain_to,
-      rbf,
-      data,
+      threshold,
+      keys,
+      script_type,
+      token,
+      network,
     );
   }
 
-  late final _wire_tx_builder_finish__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(
-                  ffi.Int64,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Pointer<wire_list_script_amount>,
-                  ffi.Pointer<wire_list_out_point>,
-                  ffi.Pointer<wire___record__out_point_String_usize>,
-                  ffi.Pointer<wire_list_out_point>,
-                  ffi.Int32,
-                  ffi.Bool,
-                  ffi.Pointer<ffi.Float>,
-                  ffi.Pointer<ffi.Uint64>,
-                  ffi.Bool,
-                  ffi.Pointer<wire_Script>,
-                  ffi.Pointer<wire_RbfValue>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_tx_builder_finish__static_method__Api');
-  late final _wire_tx_builder_finish__static_method__Api =
-      _wire_tx_builder_finish__static_method__ApiPtr.asFunction<
-          void Function(
-              int,
+  late final _wire_bsms_descriptor_record__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Uint32,
+              ffi.Pointer<wire_StringList>,
+              ffi.Int32,
               ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_list_script_amount>,
-              ffi.Pointer<wire_list_out_point>,
-              ffi.Pointer<wire___record__out_point_String_usize>,
-              ffi.Pointer<wire_list_out_point>,
-              int,
-              bool,
-              ffi.Pointer<ffi.Float>,
-              ffi.Pointer<ffi.Uint64>,
-              bool,
-              ffi.Pointer<wire_Script>,
-              ffi.Pointer<wire_RbfValue>,
-              ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Int32)>>('wire_bsms_descriptor_record__static_method__Api');
+  late final _wire_bsms_descriptor_record__static_method__Api =
+      _wire_bsms_descriptor_record__static_method__ApiPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_StringList>, int,
+              ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void wire_bump_fee_tx_builder_finish__static_method__Api(
+  void wire_bsms_verify_descriptor_record__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> txid,
-    double fee_rate,
-    ffi.Pointer<wire_uint_8_list> allow_shrinking,
-    ffi.Pointer<wire_uint_8_list> wallet_id,
-    bool enable_rbf,
-    ffi.Pointer<ffi.Uint32> n_sequence,
+    ffi.Pointer<wire_uint_8_list> record,
+    ffi.Pointer<wire_uint_8_list> token,
+    ffi.Pointer<wire_uint_8_list> secret_key,
+    int network,
   ) {
-    return _wire_bump_fee_tx_builder_finish__static_method__Api(
+    return _wire_bsms_verify_descriptor_record__static_method__Api(
       port_,
-      txid,
-      fee_rate,
-      allow_shrinking,
-      wallet_id,
-      enable_rbf,
-      n_sequence,
+      record,
+      token,
+      secret_key,
+      network,
     );
   }
 
-  late final _wire_bump_fee_tx_builder_finish__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(
-                  ffi.Int64,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Float,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Bool,
-                  ffi.Pointer<ffi.Uint32>)>>(
-      'wire_bump_fee_tx_builder_finish__static_method__Api');
-  late final _wire_bump_fee_tx_builder_finish__static_method__Api =
-      _wire_bump_fee_tx_builder_finish__static_method__ApiPtr.asFunction<
+  late final _wire_bsms_verify_descriptor_record__static_method__ApiPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Pointer<wire_uint_8_list>,
+                      ffi.Int32)>>(
+          'wire_bsms_verify_descriptor_record__static_method__Api');
+  late final _wire_bsms_verify_descriptor_record__static_method__Api =
+      _wire_bsms_verify_descriptor_record__static_method__ApiPtr.asFunction<
           void Function(
               int,
               ffi.Pointer<wire_uint_8_list>,
-              double,
               ffi.Pointer<wire_uint_8_list>,
               ffi.Pointer<wire_uint_8_list>,
-              bool,
-              ffi.Pointer<ffi.Uint32>)>();
+              int)>();
 
   void wire_create_descriptor__static_method__Api(
     int port_,
@@ -3229,89 +6639,264 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
     int key_chain_kind,
     ffi.Pointer<wire_uint_8_list> public_key,
     int network,
-    ffi.Pointer<wire_uint_8_list> fingerprint,
+    ffi.Pointer<wire_uint_8_list> fingerprint,
+  ) {
+    return _wire_new_bip84_public__static_method__Api(
+      port_,
+      key_chain_kind,
+      public_key,
+      network,
+      fingerprint,
+    );
+  }
+
+  late final _wire_new_bip84_public__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Int32,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Int32,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_new_bip84_public__static_method__Api');
+  late final _wire_new_bip84_public__static_method__Api =
+      _wire_new_bip84_public__static_method__ApiPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_uint_8_list>, int,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_new_single_key_descriptor__static_method__Api(
+    int port_,
+    int script_type,
+    ffi.Pointer<wire_uint_8_list> secret_key,
+    int network,
+  ) {
+    return _wire_new_single_key_descriptor__static_method__Api(
+      port_,
+      script_type,
+      secret_key,
+      network,
+    );
+  }
+
+  late final _wire_new_single_key_descriptor__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Int32,
+                  ffi.Pointer<wire_uint_8_list>, ffi.Int32)>>(
+      'wire_new_single_key_descriptor__static_method__Api');
+  late final _wire_new_single_key_descriptor__static_method__Api =
+      _wire_new_single_key_descriptor__static_method__ApiPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_new_single_key_public__static_method__Api(
+    int port_,
+    int script_type,
+    ffi.Pointer<wire_uint_8_list> public_key,
+    int network,
+  ) {
+    return _wire_new_single_key_public__static_method__Api(
+      port_,
+      script_type,
+      public_key,
+      network,
+    );
+  }
+
+  late final _wire_new_single_key_public__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_new_single_key_public__static_method__Api');
+  late final _wire_new_single_key_public__static_method__Api =
+      _wire_new_single_key_public__static_method__ApiPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_as_string_private__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> descriptor,
+    int network,
+  ) {
+    return _wire_as_string_private__static_method__Api(
+      port_,
+      descriptor,
+      network,
+    );
+  }
+
+  late final _wire_as_string_private__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_as_string_private__static_method__Api');
+  late final _wire_as_string_private__static_method__Api =
+      _wire_as_string_private__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_as_string__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> descriptor,
+    int network,
+  ) {
+    return _wire_as_string__static_method__Api(
+      port_,
+      descriptor,
+      network,
+    );
+  }
+
+  late final _wire_as_string__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_as_string__static_method__Api');
+  late final _wire_as_string__static_method__Api =
+      _wire_as_string__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_max_satisfaction_weight__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> descriptor,
+    int network,
+  ) {
+    return _wire_max_satisfaction_weight__static_method__Api(
+      port_,
+      descriptor,
+      network,
+    );
+  }
+
+  late final _wire_max_satisfaction_weight__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_max_satisfaction_weight__static_method__Api');
+  late final _wire_max_satisfaction_weight__static_method__Api =
+      _wire_max_satisfaction_weight__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_new_multisig_descriptor__static_method__Api(
+    int port_,
+    int threshold,
+    ffi.Pointer<wire_StringList> public_keys,
+    ffi.Pointer<wire_uint_8_list> secret_key,
+    int script_type,
+    bool sorted,
+    int network,
+  ) {
+    return _wire_new_multisig_descriptor__static_method__Api(
+      port_,
+      threshold,
+      public_keys,
+      secret_key,
+      script_type,
+      sorted,
+      network,
+    );
+  }
+
+  late final _wire_new_multisig_descriptor__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Uint32,
+              ffi.Pointer<wire_StringList>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32,
+              ffi.Bool,
+              ffi.Int32)>>('wire_new_multisig_descriptor__static_method__Api');
+  late final _wire_new_multisig_descriptor__static_method__Api =
+      _wire_new_multisig_descriptor__static_method__ApiPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_StringList>,
+              ffi.Pointer<wire_uint_8_list>, int, bool, int)>();
+
+  void wire_compile_policy__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> policy,
+    ffi.Pointer<wire_list_policy_key> keys,
+    int context,
+    int network,
   ) {
-    return _wire_new_bip84_public__static_method__Api(
+    return _wire_compile_policy__static_method__Api(
       port_,
-      key_chain_kind,
-      public_key,
+      policy,
+      keys,
+      context,
       network,
-      fingerprint,
     );
   }
 
-  late final _wire_new_bip84_public__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(
-                  ffi.Int64,
-                  ffi.Int32,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Int32,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_new_bip84_public__static_method__Api');
-  late final _wire_new_bip84_public__static_method__Api =
-      _wire_new_bip84_public__static_method__ApiPtr.asFunction<
-          void Function(int, int, ffi.Pointer<wire_uint_8_list>, int,
-              ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_compile_policy__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_list_policy_key>,
+              ffi.Int32,
+              ffi.Int32)>>('wire_compile_policy__static_method__Api');
+  late final _wire_compile_policy__static_method__Api =
+      _wire_compile_policy__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_list_policy_key>, int, int)>();
 
-  void wire_as_string_private__static_method__Api(
+  void wire_derive_addresses__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> descriptor,
     int network,
+    int start,
+    int count,
   ) {
-    return _wire_as_string_private__static_method__Api(
+    return _wire_derive_addresses__static_method__Api(
       port_,
       descriptor,
       network,
+      start,
+      count,
     );
   }
 
-  late final _wire_as_string_private__static_method__ApiPtr = _lookup<
+  late final _wire_derive_addresses__static_method__ApiPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
-              ffi.Int32)>>('wire_as_string_private__static_method__Api');
-  late final _wire_as_string_private__static_method__Api =
-      _wire_as_string_private__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
-
-  void wire_as_string__static_method__Api(
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32,
+              ffi.Uint32,
+              ffi.Uint32)>>('wire_derive_addresses__static_method__Api');
+  late final _wire_derive_addresses__static_method__Api =
+      _wire_derive_addresses__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>, int, int, int)>();
+
+  void wire_descriptor_info__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> descriptor,
-    int network,
   ) {
-    return _wire_as_string__static_method__Api(
+    return _wire_descriptor_info__static_method__Api(
       port_,
       descriptor,
-      network,
     );
   }
 
-  late final _wire_as_string__static_method__ApiPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
-              ffi.Int32)>>('wire_as_string__static_method__Api');
-  late final _wire_as_string__static_method__Api =
-      _wire_as_string__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+  late final _wire_descriptor_info__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_descriptor_info__static_method__Api');
+  late final _wire_descriptor_info__static_method__Api =
+      _wire_descriptor_info__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_max_satisfaction_weight__static_method__Api(
+  void wire_import_wallet__static_method__Api(
     int port_,
-    ffi.Pointer<wire_uint_8_list> descriptor,
+    ffi.Pointer<wire_uint_8_list> data,
     int network,
   ) {
-    return _wire_max_satisfaction_weight__static_method__Api(
+    return _wire_import_wallet__static_method__Api(
       port_,
-      descriptor,
+      data,
       network,
     );
   }
 
-  late final _wire_max_satisfaction_weight__static_method__ApiPtr = _lookup<
+  late final _wire_import_wallet__static_method__ApiPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
-              ffi.Int32)>>('wire_max_satisfaction_weight__static_method__Api');
-  late final _wire_max_satisfaction_weight__static_method__Api =
-      _wire_max_satisfaction_weight__static_method__ApiPtr
+              ffi.Int32)>>('wire_import_wallet__static_method__Api');
+  late final _wire_import_wallet__static_method__Api =
+      _wire_import_wallet__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
   void wire_create_descriptor_secret__static_method__Api(
@@ -3360,6 +6945,28 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
       _wire_descriptor_secret_from_string__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_descriptor_secret_from_single__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> secret,
+    int network,
+  ) {
+    return _wire_descriptor_secret_from_single__static_method__Api(
+      port_,
+      secret,
+      network,
+    );
+  }
+
+  late final _wire_descriptor_secret_from_single__static_method__ApiPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Int32)>>(
+          'wire_descriptor_secret_from_single__static_method__Api');
+  late final _wire_descriptor_secret_from_single__static_method__Api =
+      _wire_descriptor_secret_from_single__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
   void wire_extend_descriptor_secret__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> secret,
@@ -3458,6 +7065,42 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
       _wire_create_derivation_path__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_descriptor_secret_metadata__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> secret,
+  ) {
+    return _wire_descriptor_secret_metadata__static_method__Api(
+      port_,
+      secret,
+    );
+  }
+
+  late final _wire_descriptor_secret_metadata__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_descriptor_secret_metadata__static_method__Api');
+  late final _wire_descriptor_secret_metadata__static_method__Api =
+      _wire_descriptor_secret_metadata__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_descriptor_public_metadata__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> public_key,
+  ) {
+    return _wire_descriptor_public_metadata__static_method__Api(
+      port_,
+      public_key,
+    );
+  }
+
+  late final _wire_descriptor_public_metadata__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_descriptor_public_metadata__static_method__Api');
+  late final _wire_descriptor_public_metadata__static_method__Api =
+      _wire_descriptor_public_metadata__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_descriptor_public_from_string__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> public_key,
@@ -3503,6 +7146,62 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
           void Function(int, ffi.Pointer<wire_uint_8_list>,
               ffi.Pointer<wire_uint_8_list>, bool)>();
 
+  void wire_slip132_to_standard__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> key,
+  ) {
+    return _wire_slip132_to_standard__static_method__Api(
+      port_,
+      key,
+    );
+  }
+
+  late final _wire_slip132_to_standard__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_slip132_to_standard__static_method__Api');
+  late final _wire_slip132_to_standard__static_method__Api =
+      _wire_slip132_to_standard__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_standard_to_slip132__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> key,
+    int version,
+  ) {
+    return _wire_standard_to_slip132__static_method__Api(
+      port_,
+      key,
+      version,
+    );
+  }
+
+  late final _wire_standard_to_slip132__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int32)>>('wire_standard_to_slip132__static_method__Api');
+  late final _wire_standard_to_slip132__static_method__Api =
+      _wire_standard_to_slip132__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_slip132_template__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> key,
+  ) {
+    return _wire_slip132_template__static_method__Api(
+      port_,
+      key,
+    );
+  }
+
+  late final _wire_slip132_template__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_slip132_template__static_method__Api');
+  late final _wire_slip132_template__static_method__Api =
+      _wire_slip132_template__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_create_script__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> raw_output_script,
@@ -3745,77 +7444,268 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
     );
   }
 
-  late final _wire_get_balance__static_method__ApiPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_get_balance__static_method__Api');
-  late final _wire_get_balance__static_method__Api =
-      _wire_get_balance__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_get_balance__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_get_balance__static_method__Api');
+  late final _wire_get_balance__static_method__Api =
+      _wire_get_balance__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_list_unspent_outputs__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+  ) {
+    return _wire_list_unspent_outputs__static_method__Api(
+      port_,
+      wallet_id,
+    );
+  }
+
+  late final _wire_list_unspent_outputs__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_list_unspent_outputs__static_method__Api');
+  late final _wire_list_unspent_outputs__static_method__Api =
+      _wire_list_unspent_outputs__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_get_transactions__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    bool include_raw,
+  ) {
+    return _wire_get_transactions__static_method__Api(
+      port_,
+      wallet_id,
+      include_raw,
+    );
+  }
+
+  late final _wire_get_transactions__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Bool)>>('wire_get_transactions__static_method__Api');
+  late final _wire_get_transactions__static_method__Api =
+      _wire_get_transactions__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>, bool)>();
+
+  void wire_sign__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_SignOptions> sign_options,
+    ffi.Pointer<wire_PsbtSafetyPolicy> safety_policy,
+  ) {
+    return _wire_sign__static_method__Api(
+      port_,
+      wallet_id,
+      psbt_str,
+      sign_options,
+      safety_policy,
+    );
+  }
+
+  late final _wire_sign__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_SignOptions>,
+                  ffi.Pointer<wire_PsbtSafetyPolicy>)>>(
+      'wire_sign__static_method__Api');
+  late final _wire_sign__static_method__Api =
+      _wire_sign__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_SignOptions>,
+              ffi.Pointer<wire_PsbtSafetyPolicy>)>();
+
+  void wire_check_psbt__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_PsbtSafetyPolicy> policy,
+  ) {
+    return _wire_check_psbt__static_method__Api(
+      port_,
+      wallet_id,
+      psbt_str,
+      policy,
+    );
+  }
+
+  late final _wire_check_psbt__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_PsbtSafetyPolicy>)>>(
+      'wire_check_psbt__static_method__Api');
+  late final _wire_check_psbt__static_method__Api =
+      _wire_check_psbt__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_PsbtSafetyPolicy>)>();
+
+  void wire_max_sendable__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_Script> destination,
+    double fee_rate,
+    ffi.Pointer<wire_list_out_point> utxos,
+  ) {
+    return _wire_max_sendable__static_method__Api(
+      port_,
+      wallet_id,
+      destination,
+      fee_rate,
+      utxos,
+    );
+  }
+
+  late final _wire_max_sendable__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_Script>,
+                  ffi.Float,
+                  ffi.Pointer<wire_list_out_point>)>>(
+      'wire_max_sendable__static_method__Api');
+  late final _wire_max_sendable__static_method__Api =
+      _wire_max_sendable__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_Script>,
+              double,
+              ffi.Pointer<wire_list_out_point>)>();
+
+  void wire_sweep__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+    ffi.Pointer<wire_Script> destination,
+    double fee_rate,
+    ffi.Pointer<wire_list_out_point> utxos,
+    bool enable_rbf,
+  ) {
+    return _wire_sweep__static_method__Api(
+      port_,
+      wallet_id,
+      destination,
+      fee_rate,
+      utxos,
+      enable_rbf,
+    );
+  }
+
+  late final _wire_sweep__static_method__ApiPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_Script>,
+              ffi.Float,
+              ffi.Pointer<wire_list_out_point>,
+              ffi.Bool)>>('wire_sweep__static_method__Api');
+  late final _wire_sweep__static_method__Api =
+      _wire_sweep__static_method__ApiPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_Script>,
+              double,
+              ffi.Pointer<wire_list_out_point>,
+              bool)>();
 
-  void wire_list_unspent_outputs__static_method__Api(
+  void wire_plan_consolidation__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> wallet_id,
+    double fee_rate,
+    double future_fee_rate,
+    ffi.Pointer<wire_list_utxo_label> labels,
+    ffi.Pointer<ffi.Uint32> max_inputs,
   ) {
-    return _wire_list_unspent_outputs__static_method__Api(
+    return _wire_plan_consolidation__static_method__Api(
       port_,
       wallet_id,
+      fee_rate,
+      future_fee_rate,
+      labels,
+      max_inputs,
     );
   }
 
-  late final _wire_list_unspent_outputs__static_method__ApiPtr = _lookup<
+  late final _wire_plan_consolidation__static_method__ApiPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_list_unspent_outputs__static_method__Api');
-  late final _wire_list_unspent_outputs__static_method__Api =
-      _wire_list_unspent_outputs__static_method__ApiPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Float,
+                  ffi.Float,
+                  ffi.Pointer<wire_list_utxo_label>,
+                  ffi.Pointer<ffi.Uint32>)>>(
+      'wire_plan_consolidation__static_method__Api');
+  late final _wire_plan_consolidation__static_method__Api =
+      _wire_plan_consolidation__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>, double, double,
+              ffi.Pointer<wire_list_utxo_label>, ffi.Pointer<ffi.Uint32>)>();
 
-  void wire_get_transactions__static_method__Api(
+  void wire_finalize_psbt__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> wallet_id,
-    bool include_raw,
+    ffi.Pointer<wire_uint_8_list> psbt_str,
+    ffi.Pointer<wire_SignOptions> sign_options,
   ) {
-    return _wire_get_transactions__static_method__Api(
+    return _wire_finalize_psbt__static_method__Api(
       port_,
       wallet_id,
-      include_raw,
+      psbt_str,
+      sign_options,
     );
   }
 
-  late final _wire_get_transactions__static_method__ApiPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
-              ffi.Bool)>>('wire_get_transactions__static_method__Api');
-  late final _wire_get_transactions__static_method__Api =
-      _wire_get_transactions__static_method__ApiPtr.asFunction<
-          void Function(int, ffi.Pointer<wire_uint_8_list>, bool)>();
+  late final _wire_finalize_psbt__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_SignOptions>)>>(
+      'wire_finalize_psbt__static_method__Api');
+  late final _wire_finalize_psbt__static_method__Api =
+      _wire_finalize_psbt__static_method__ApiPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_SignOptions>)>();
 
-  void wire_sign__static_method__Api(
+  void wire_analyze_psbt__static_method__Api(
     int port_,
     ffi.Pointer<wire_uint_8_list> wallet_id,
     ffi.Pointer<wire_uint_8_list> psbt_str,
-    ffi.Pointer<wire_SignOptions> sign_options,
   ) {
-    return _wire_sign__static_method__Api(
+    return _wire_analyze_psbt__static_method__Api(
       port_,
       wallet_id,
       psbt_str,
-      sign_options,
     );
   }
 
-  late final _wire_sign__static_method__ApiPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_SignOptions>)>>('wire_sign__static_method__Api');
-  late final _wire_sign__static_method__Api =
-      _wire_sign__static_method__ApiPtr.asFunction<
+  late final _wire_analyze_psbt__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_analyze_psbt__static_method__Api');
+  late final _wire_analyze_psbt__static_method__Api =
+      _wire_analyze_psbt__static_method__ApiPtr.asFunction<
           void Function(int, ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_SignOptions>)>();
+              ffi.Pointer<wire_uint_8_list>)>();
 
   void wire_wallet_network__static_method__Api(
     int port_,
@@ -3908,6 +7798,24 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
       _wire_get_descriptor_for_keychain__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
+  void wire_get_multipath_descriptor__static_method__Api(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> wallet_id,
+  ) {
+    return _wire_get_multipath_descriptor__static_method__Api(
+      port_,
+      wallet_id,
+    );
+  }
+
+  late final _wire_get_multipath_descriptor__static_method__ApiPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_get_multipath_descriptor__static_method__Api');
+  late final _wire_get_multipath_descriptor__static_method__Api =
+      _wire_get_multipath_descriptor__static_method__ApiPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_generate_seed_from_word_count__static_method__Api(
     int port_,
     int word_count,
@@ -3961,6 +7869,20 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
       _wire_generate_seed_from_entropy__static_method__ApiPtr
           .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  ffi.Pointer<wire_StringList> new_StringList_0(
+    int len,
+  ) {
+    return _new_StringList_0(
+      len,
+    );
+  }
+
+  late final _new_StringList_0Ptr = _lookup<
+          ffi.NativeFunction<ffi.Pointer<wire_StringList> Function(ffi.Int32)>>(
+      'new_StringList_0');
+  late final _new_StringList_0 = _new_StringList_0Ptr
+      .asFunction<ffi.Pointer<wire_StringList> Function(int)>();
+
   ffi.Pointer<wire___record__out_point_String_usize>
       new_box_autoadd___record__out_point_String_usize_0() {
     return _new_box_autoadd___record__out_point_String_usize_0();
@@ -4053,6 +7975,48 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_local_utxo_0 = _new_box_autoadd_local_utxo_0Ptr
       .asFunction<ffi.Pointer<wire_LocalUtxo> Function()>();
 
+  ffi.Pointer<wire_OutPoint> new_box_autoadd_out_point_0() {
+    return _new_box_autoadd_out_point_0();
+  }
+
+  late final _new_box_autoadd_out_point_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_OutPoint> Function()>>(
+          'new_box_autoadd_out_point_0');
+  late final _new_box_autoadd_out_point_0 = _new_box_autoadd_out_point_0Ptr
+      .asFunction<ffi.Pointer<wire_OutPoint> Function()>();
+
+  ffi.Pointer<wire_PayjoinParams> new_box_autoadd_payjoin_params_0() {
+    return _new_box_autoadd_payjoin_params_0();
+  }
+
+  late final _new_box_autoadd_payjoin_params_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PayjoinParams> Function()>>(
+          'new_box_autoadd_payjoin_params_0');
+  late final _new_box_autoadd_payjoin_params_0 =
+      _new_box_autoadd_payjoin_params_0Ptr
+          .asFunction<ffi.Pointer<wire_PayjoinParams> Function()>();
+
+  ffi.Pointer<wire_PsbtMap> new_box_autoadd_psbt_map_0() {
+    return _new_box_autoadd_psbt_map_0();
+  }
+
+  late final _new_box_autoadd_psbt_map_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PsbtMap> Function()>>(
+          'new_box_autoadd_psbt_map_0');
+  late final _new_box_autoadd_psbt_map_0 = _new_box_autoadd_psbt_map_0Ptr
+      .asFunction<ffi.Pointer<wire_PsbtMap> Function()>();
+
+  ffi.Pointer<wire_PsbtSafetyPolicy> new_box_autoadd_psbt_safety_policy_0() {
+    return _new_box_autoadd_psbt_safety_policy_0();
+  }
+
+  late final _new_box_autoadd_psbt_safety_policy_0Ptr = _lookup<
+          ffi.NativeFunction<ffi.Pointer<wire_PsbtSafetyPolicy> Function()>>(
+      'new_box_autoadd_psbt_safety_policy_0');
+  late final _new_box_autoadd_psbt_safety_policy_0 =
+      _new_box_autoadd_psbt_safety_policy_0Ptr
+          .asFunction<ffi.Pointer<wire_PsbtSafetyPolicy> Function()>();
+
   ffi.Pointer<wire_PsbtSigHashType> new_box_autoadd_psbt_sig_hash_type_0() {
     return _new_box_autoadd_psbt_sig_hash_type_0();
   }
@@ -4105,6 +8069,17 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_script_0 = _new_box_autoadd_script_0Ptr
       .asFunction<ffi.Pointer<wire_Script> Function()>();
 
+  ffi.Pointer<wire_ShrinkOutput> new_box_autoadd_shrink_output_0() {
+    return _new_box_autoadd_shrink_output_0();
+  }
+
+  late final _new_box_autoadd_shrink_output_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ShrinkOutput> Function()>>(
+          'new_box_autoadd_shrink_output_0');
+  late final _new_box_autoadd_shrink_output_0 =
+      _new_box_autoadd_shrink_output_0Ptr
+          .asFunction<ffi.Pointer<wire_ShrinkOutput> Function()>();
+
   ffi.Pointer<wire_SignOptions> new_box_autoadd_sign_options_0() {
     return _new_box_autoadd_sign_options_0();
   }
@@ -4208,6 +8183,21 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
   late final _new_list_out_point_0 = _new_list_out_point_0Ptr
       .asFunction<ffi.Pointer<wire_list_out_point> Function(int)>();
 
+  ffi.Pointer<wire_list_policy_key> new_list_policy_key_0(
+    int len,
+  ) {
+    return _new_list_policy_key_0(
+      len,
+    );
+  }
+
+  late final _new_list_policy_key_0Ptr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_list_policy_key> Function(
+              ffi.Int32)>>('new_list_policy_key_0');
+  late final _new_list_policy_key_0 = _new_list_policy_key_0Ptr
+      .asFunction<ffi.Pointer<wire_list_policy_key> Function(int)>();
+
   ffi.Pointer<wire_list_script_amount> new_list_script_amount_0(
     int len,
   ) {
@@ -4223,6 +8213,21 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
   late final _new_list_script_amount_0 = _new_list_script_amount_0Ptr
       .asFunction<ffi.Pointer<wire_list_script_amount> Function(int)>();
 
+  ffi.Pointer<wire_list_utxo_label> new_list_utxo_label_0(
+    int len,
+  ) {
+    return _new_list_utxo_label_0(
+      len,
+    );
+  }
+
+  late final _new_list_utxo_label_0Ptr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_list_utxo_label> Function(
+              ffi.Int32)>>('new_list_utxo_label_0');
+  late final _new_list_utxo_label_0 = _new_list_utxo_label_0Ptr
+      .asFunction<ffi.Pointer<wire_list_utxo_label> Function(int)>();
+
   ffi.Pointer<wire_uint_8_list> new_uint_8_list_0(
     int len,
   ) {
@@ -4310,6 +8315,26 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
   late final _inflate_DatabaseConfig_Sled = _inflate_DatabaseConfig_SledPtr
       .asFunction<ffi.Pointer<DatabaseConfigKind> Function()>();
 
+  ffi.Pointer<PsbtMapKind> inflate_PsbtMap_Input() {
+    return _inflate_PsbtMap_Input();
+  }
+
+  late final _inflate_PsbtMap_InputPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<PsbtMapKind> Function()>>(
+          'inflate_PsbtMap_Input');
+  late final _inflate_PsbtMap_Input = _inflate_PsbtMap_InputPtr
+      .asFunction<ffi.Pointer<PsbtMapKind> Function()>();
+
+  ffi.Pointer<PsbtMapKind> inflate_PsbtMap_Output() {
+    return _inflate_PsbtMap_Output();
+  }
+
+  late final _inflate_PsbtMap_OutputPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<PsbtMapKind> Function()>>(
+          'inflate_PsbtMap_Output');
+  late final _inflate_PsbtMap_Output = _inflate_PsbtMap_OutputPtr
+      .asFunction<ffi.Pointer<PsbtMapKind> Function()>();
+
   ffi.Pointer<RbfValueKind> inflate_RbfValue_Value() {
     return _inflate_RbfValue_Value();
   }
@@ -4320,6 +8345,36 @@ class RustBdkFfiWire implements FlutterRustBridgeWireBase {
   late final _inflate_RbfValue_Value = _inflate_RbfValue_ValuePtr
       .asFunction<ffi.Pointer<RbfValueKind> Function()>();
 
+  ffi.Pointer<ShrinkOutputKind> inflate_ShrinkOutput_Address() {
+    return _inflate_ShrinkOutput_Address();
+  }
+
+  late final _inflate_ShrinkOutput_AddressPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ShrinkOutputKind> Function()>>(
+          'inflate_ShrinkOutput_Address');
+  late final _inflate_ShrinkOutput_Address = _inflate_ShrinkOutput_AddressPtr
+      .asFunction<ffi.Pointer<ShrinkOutputKind> Function()>();
+
+  ffi.Pointer<ShrinkOutputKind> inflate_ShrinkOutput_Script() {
+    return _inflate_ShrinkOutput_Script();
+  }
+
+  late final _inflate_ShrinkOutput_ScriptPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ShrinkOutputKind> Function()>>(
+          'inflate_ShrinkOutput_Script');
+  late final _inflate_ShrinkOutput_Script = _inflate_ShrinkOutput_ScriptPtr
+      .asFunction<ffi.Pointer<ShrinkOutputKind> Function()>();
+
+  ffi.Pointer<ShrinkOutputKind> inflate_ShrinkOutput_Index() {
+    return _inflate_ShrinkOutput_Index();
+  }
+
+  late final _inflate_ShrinkOutput_IndexPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ShrinkOutputKind> Function()>>(
+          'inflate_ShrinkOutput_Index');
+  late final _inflate_ShrinkOutput_Index = _inflate_ShrinkOutput_IndexPtr
+      .asFunction<ffi.Pointer<ShrinkOutputKind> Function()>();
+
   void free_WireSyncReturn(
     WireSyncReturn ptr,
   ) {
@@ -4436,10 +8491,51 @@ final class wire_BlockchainConfig extends ffi.Struct {
   external ffi.Pointer<BlockchainConfigKind> kind;
 }
 
+final class wire_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_OutPoint extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> txid;
+
+  @ffi.Uint32()
+  external int vout;
+}
+
 final class wire_Script extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> internal;
 }
 
+final class wire_PsbtMap_Global extends ffi.Opaque {}
+
+final class wire_PsbtMap_Input extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
+}
+
+final class wire_PsbtMap_Output extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
+}
+
+final class PsbtMapKind extends ffi.Union {
+  external ffi.Pointer<wire_PsbtMap_Global> Global;
+
+  external ffi.Pointer<wire_PsbtMap_Input> Input;
+
+  external ffi.Pointer<wire_PsbtMap_Output> Output;
+}
+
+final class wire_PsbtMap extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<PsbtMapKind> kind;
+}
+
 final class wire_ScriptAmount extends ffi.Struct {
   external wire_Script script;
 
@@ -4454,13 +8550,6 @@ final class wire_list_script_amount extends ffi.Struct {
   external int len;
 }
 
-final class wire_OutPoint extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> txid;
-
-  @ffi.Uint32()
-  external int vout;
-}
-
 final class wire_list_out_point extends ffi.Struct {
   external ffi.Pointer<wire_OutPoint> ptr;
 
@@ -4497,6 +8586,83 @@ final class wire_RbfValue extends ffi.Struct {
   external ffi.Pointer<RbfValueKind> kind;
 }
 
+final class wire_ShrinkOutput_Address extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> address;
+}
+
+final class wire_ShrinkOutput_Script extends ffi.Struct {
+  external ffi.Pointer<wire_Script> script;
+}
+
+final class wire_ShrinkOutput_Index extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
+}
+
+final class ShrinkOutputKind extends ffi.Union {
+  external ffi.Pointer<wire_ShrinkOutput_Address> Address;
+
+  external ffi.Pointer<wire_ShrinkOutput_Script> Script;
+
+  external ffi.Pointer<wire_ShrinkOutput_Index> Index;
+}
+
+final class wire_ShrinkOutput extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<ShrinkOutputKind> kind;
+}
+
+final class wire_PayjoinParams extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> additional_fee_output_index;
+
+  external ffi.Pointer<ffi.Uint64> max_additional_fee_contribution;
+
+  external ffi.Pointer<ffi.Float> min_fee_rate;
+
+  @ffi.Bool()
+  external bool disable_output_substitution;
+}
+
+final class wire_SignOptions extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_multi_sig;
+
+  @ffi.Bool()
+  external bool trust_witness_utxo;
+
+  external ffi.Pointer<ffi.Uint32> assume_height;
+
+  @ffi.Bool()
+  external bool allow_all_sighashes;
+
+  @ffi.Bool()
+  external bool remove_partial_sigs;
+
+  @ffi.Bool()
+  external bool try_finalize;
+
+  @ffi.Bool()
+  external bool sign_with_tap_internal_key;
+
+  @ffi.Bool()
+  external bool allow_grinding;
+}
+
+final class wire_PolicyKey extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> name;
+
+  external ffi.Pointer<wire_uint_8_list> key;
+}
+
+final class wire_list_policy_key extends ffi.Struct {
+  external ffi.Pointer<wire_PolicyKey> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_DatabaseConfig_Memory extends ffi.Opaque {}
 
 final class wire_SqliteDbConfiguration extends ffi.Struct {
@@ -4563,29 +8729,29 @@ final class wire_AddressIndex extends ffi.Struct {
   external ffi.Pointer<AddressIndexKind> kind;
 }
 
-final class wire_SignOptions extends ffi.Struct {
-  @ffi.Bool()
-  external bool is_multi_sig;
-
-  @ffi.Bool()
-  external bool trust_witness_utxo;
+final class wire_PsbtSafetyPolicy extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> max_fee;
 
-  external ffi.Pointer<ffi.Uint32> assume_height;
+  external ffi.Pointer<ffi.Float> max_fee_percent;
 
   @ffi.Bool()
-  external bool allow_all_sighashes;
+  external bool require_non_witness_utxo;
 
   @ffi.Bool()
-  external bool remove_partial_sigs;
+  external bool refuse_unsafe;
+}
 
-  @ffi.Bool()
-  external bool try_finalize;
+final class wire_UtxoLabel extends ffi.Struct {
+  external wire_OutPoint outpoint;
 
-  @ffi.Bool()
-  external bool sign_with_tap_internal_key;
+  external ffi.Pointer<wire_uint_8_list> label;
+}
 
-  @ffi.Bool()
-  external bool allow_grinding;
+final class wire_list_utxo_label extends ffi.Struct {
+  external ffi.Pointer<wire_UtxoLabel> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_TxOut extends ffi.Struct {
@@ -100,6 +100,38 @@ abstract class RustBdkFfi {
 
   FlutterRustBridgeTaskConstMeta get kSerializePsbtStaticMethodApiConstMeta;
 
+  /// Parse a PSBT given as binary, hex or base64 bytes, as read from a `.psbt` file, and
+  /// return it as base64.
+  Future<String> psbtFromBytesStaticMethodApi(
+      {required Uint8List bytes, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtFromBytesStaticMethodApiConstMeta;
+
+  /// Serialize a PSBT as binary, the format used for `.psbt` files.
+  Future<Uint8List> psbtToBytesStaticMethodApi(
+      {required String psbtStr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtToBytesStaticMethodApiConstMeta;
+
+  /// Serialize a PSBT as hex.
+  Future<String> psbtToHexStaticMethodApi(
+      {required String psbtStr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtToHexStaticMethodApiConstMeta;
+
+  /// The version of a PSBT, 0 or 2 (BIP370).
+  Future<int> psbtVersionStaticMethodApi(
+      {required String psbtStr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtVersionStaticMethodApiConstMeta;
+
+  /// Serialize a PSBT of either version as version 0 or version 2 (BIP370).
+  Future<String> serializePsbtVersionStaticMethodApi(
+      {required String psbtStr, required int version, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kSerializePsbtVersionStaticMethodApiConstMeta;
+
   Future<String> psbtTxidStaticMethodApi(
       {required String psbtStr, dynamic hint});
 
@@ -125,6 +157,106 @@ abstract class RustBdkFfi {
 
   FlutterRustBridgeTaskConstMeta get kCombinePsbtStaticMethodApiConstMeta;
 
+  /// Combine PSBTs describing the same unsigned transaction, e.g. the partially signed copies
+  /// returned by each cosigner, and report which inputs gained which signatures.
+  Future<CombinedPsbt> combinePsbtsStaticMethodApi(
+      {required List<String> psbtStrs, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCombinePsbtsStaticMethodApiConstMeta;
+
+  /// Add an input spending `outpoint` to a PSBT. `psbt_input` is the JSON encoded PSBT input
+  /// and must carry its UTXO. Edits that would invalidate existing signatures are refused, as
+  /// for all the PSBT editing functions below.
+  Future<String> psbtAddInputStaticMethodApi(
+      {required String psbtStr,
+      required OutPoint outpoint,
+      required String psbtInput,
+      int? sequence,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtAddInputStaticMethodApiConstMeta;
+
+  /// Remove an unsigned input from a PSBT.
+  Future<String> psbtRemoveInputStaticMethodApi(
+      {required String psbtStr, required int index, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtRemoveInputStaticMethodApiConstMeta;
+
+  Future<String> psbtAddOutputStaticMethodApi(
+      {required String psbtStr,
+      required Script script,
+      required int amount,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtAddOutputStaticMethodApiConstMeta;
+
+  /// Remove an output that no signature of the PSBT commits to.
+  Future<String> psbtRemoveOutputStaticMethodApi(
+      {required String psbtStr, required int index, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtRemoveOutputStaticMethodApiConstMeta;
+
+  Future<String> psbtSetSequenceStaticMethodApi(
+      {required String psbtStr,
+      required int index,
+      required int sequence,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtSetSequenceStaticMethodApiConstMeta;
+
+  Future<String> psbtSetLockTimeStaticMethodApi(
+      {required String psbtStr, required int lockTime, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtSetLockTimeStaticMethodApiConstMeta;
+
+  /// Set an unknown field of a PSBT map, or remove it when `value` is null.
+  Future<String> psbtSetUnknownStaticMethodApi(
+      {required String psbtStr,
+      required PsbtMap map,
+      required int keyType,
+      required Uint8List key,
+      Uint8List? value,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtSetUnknownStaticMethodApiConstMeta;
+
+  /// Set a proprietary field of a PSBT map, or remove it when `value` is null.
+  Future<String> psbtSetProprietaryStaticMethodApi(
+      {required String psbtStr,
+      required PsbtMap map,
+      required Uint8List prefix,
+      required int subtype,
+      required Uint8List key,
+      Uint8List? value,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kPsbtSetProprietaryStaticMethodApiConstMeta;
+
+  /// Finalize the inputs of a PSBT spending from `descriptor` or `change_descriptor`, without
+  /// creating a wallet. The first `lookahead` derivation indexes, at most 10,000, are searched for
+  /// inputs that carry no BIP32 derivations.
+  Future<FinalizedPsbt> finalizePsbtWithDescriptorStaticMethodApi(
+      {required String psbtStr,
+      required String descriptor,
+      String? changeDescriptor,
+      required Network network,
+      required int lookahead,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kFinalizePsbtWithDescriptorStaticMethodApiConstMeta;
+
+  Future<List<PsbtInputInfo>> psbtInputsStaticMethodApi(
+      {required String psbtStr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtInputsStaticMethodApiConstMeta;
+
+  Future<List<PsbtOutputInfo>> psbtOutputsStaticMethodApi(
+      {required String psbtStr, required Network network, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtOutputsStaticMethodApiConstMeta;
+
   Future<String> jsonSerializeStaticMethodApi(
       {required String psbtStr, dynamic hint});
 
@@ -148,10 +280,14 @@ abstract class RustBdkFfi {
 
   FlutterRustBridgeTaskConstMeta get kTxBuilderFinishStaticMethodApiConstMeta;
 
-  Future<BdkTxBuilderResult> bumpFeeTxBuilderFinishStaticMethodApi(
+  Future<BdkBumpFeeTxBuilderResult> bumpFeeTxBuilderFinishStaticMethodApi(
       {required String txid,
-      required double feeRate,
-      String? allowShrinking,
+      double? feeRate,
+      int? feeAbsolute,
+      ShrinkOutput? allowShrinking,
+      required List<OutPoint> utxos,
+      required List<OutPoint> unspendable,
+      required bool manuallySelectedOnly,
       required String walletId,
       required bool enableRbf,
       int? nSequence,
@@ -160,6 +296,208 @@ abstract class RustBdkFfi {
   FlutterRustBridgeTaskConstMeta
       get kBumpFeeTxBuilderFinishStaticMethodApiConstMeta;
 
+  Future<BdkTxBuilderResult> cancelTransactionStaticMethodApi(
+      {required String walletId,
+      required String txid,
+      required double feeRate,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCancelTransactionStaticMethodApiConstMeta;
+
+  Future<PayjoinUri> parsePayjoinUriStaticMethodApi(
+      {required String uri, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kParsePayjoinUriStaticMethodApiConstMeta;
+
+  /// Sign the PSBT returned by `tx_builder_finish` and build the BIP78 request to post to the
+  /// receiver's endpoint. The request body doubles as the fallback transaction.
+  Future<PayjoinRequest> payjoinSenderRequestStaticMethodApi(
+      {required String walletId,
+      required String uri,
+      required String psbtStr,
+      required PayjoinParams params,
+      SignOptions? signOptions,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kPayjoinSenderRequestStaticMethodApiConstMeta;
+
+  /// Sign the PSBT returned by `tx_builder_finish`, post it to the receiver's endpoint over
+  /// HTTPS (or to an onion service) and return the checked and signed PayJoin transaction.
+  Future<String> payjoinSendStaticMethodApi(
+      {required String walletId,
+      required String uri,
+      required String psbtStr,
+      required PayjoinParams params,
+      SignOptions? signOptions,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPayjoinSendStaticMethodApiConstMeta;
+
+  /// Check the receiver's proposal against the original PSBT and sign it.
+  Future<String> payjoinSenderProcessResponseStaticMethodApi(
+      {required String walletId,
+      required String uri,
+      required String originalPsbt,
+      required PayjoinParams params,
+      required String response,
+      SignOptions? signOptions,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kPayjoinSenderProcessResponseStaticMethodApiConstMeta;
+
+  /// Contribute one of the wallet's inputs to the sender's original PSBT and return the
+  /// PayJoin proposal. `query` is the query string the sender's request was posted with.
+  Future<String> payjoinReceiveStaticMethodApi(
+      {required String walletId,
+      required String originalPsbt,
+      required String query,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPayjoinReceiveStaticMethodApiConstMeta;
+
+  /// Encode a PSBT as animated QR parts (`ur:crypto-psbt`), `count` parts (at most 10 000) from
+  /// `start_seq_num` (1 based). A PSBT fitting in one fragment is returned as a single part.
+  Future<List<String>> psbtToUrStaticMethodApi(
+      {required String psbtStr,
+      required int maxFragmentLength,
+      required int startSeqNum,
+      required int count,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPsbtToUrStaticMethodApiConstMeta;
+
+  /// Encode a descriptor as a `ur:crypto-output`.
+  Future<String> descriptorToUrStaticMethodApi(
+      {required String descriptor, required Network network, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDescriptorToUrStaticMethodApiConstMeta;
+
+  /// Encode the descriptors of an account as a `ur:crypto-account`.
+  Future<String> descriptorsToUrAccountStaticMethodApi(
+      {required List<String> descriptors,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorsToUrAccountStaticMethodApiConstMeta;
+
+  /// Create a decoder for scanned UR parts and return its id.
+  Future<String> createUrDecoderStaticMethodApi(
+      {required Network network, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateUrDecoderStaticMethodApiConstMeta;
+
+  Future<UrDecodeProgress> urDecoderReceiveStaticMethodApi(
+      {required String decoderId, required String part, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUrDecoderReceiveStaticMethodApiConstMeta;
+
+  Future<void> dropUrDecoderStaticMethodApi(
+      {required String decoderId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDropUrDecoderStaticMethodApiConstMeta;
+
+  /// Start collecting cosigner signatures on a PSBT spending from a multisig `descriptor` and
+  /// return the session id.
+  Future<String> createMultisigSessionStaticMethodApi(
+      {required String descriptor,
+      String? changeDescriptor,
+      required Network network,
+      required String psbtStr,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kCreateMultisigSessionStaticMethodApiConstMeta;
+
+  /// Restore a session saved with `export_multisig_session` and return its id.
+  Future<String> restoreMultisigSessionStaticMethodApi(
+      {required String data, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kRestoreMultisigSessionStaticMethodApiConstMeta;
+
+  Future<String> exportMultisigSessionStaticMethodApi(
+      {required String sessionId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kExportMultisigSessionStaticMethodApiConstMeta;
+
+  /// Merge a copy of the session PSBT signed by a cosigner.
+  Future<MultisigStatus> multisigSessionAddPsbtStaticMethodApi(
+      {required String sessionId, required String psbtStr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kMultisigSessionAddPsbtStaticMethodApiConstMeta;
+
+  Future<MultisigStatus> multisigSessionStatusStaticMethodApi(
+      {required String sessionId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kMultisigSessionStatusStaticMethodApiConstMeta;
+
+  /// Finalize the session PSBT, failing if an input doesn't have enough signatures yet.
+  Future<FinalizedPsbt> finalizeMultisigSessionStaticMethodApi(
+      {required String sessionId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kFinalizeMultisigSessionStaticMethodApiConstMeta;
+
+  Future<void> dropMultisigSessionStaticMethodApi(
+      {required String sessionId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kDropMultisigSessionStaticMethodApiConstMeta;
+
+  /// Generate the token a BSMS coordinator shares with the signers, `00` without encryption.
+  Future<String> bsmsNewTokenStaticMethodApi(
+      {required BsmsEncryption encryption, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBsmsNewTokenStaticMethodApiConstMeta;
+
+  /// Create the key record of a BSMS signer for its account secret key, e.g.
+  /// `[fingerprint/48'/0'/0'/2']xprv...`.
+  Future<String> bsmsKeyRecordStaticMethodApi(
+      {required String secretKey,
+      required String token,
+      required String description,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBsmsKeyRecordStaticMethodApiConstMeta;
+
+  /// Decrypt and check the key record of a signer as the BSMS coordinator.
+  Future<BsmsKeyRecord> bsmsVerifyKeyRecordStaticMethodApi(
+      {required String record, required String token, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kBsmsVerifyKeyRecordStaticMethodApiConstMeta;
+
+  /// Create the descriptor record of a `sortedmulti` wallet from the keys of the verified key
+  /// records.
+  Future<String> bsmsDescriptorRecordStaticMethodApi(
+      {required int threshold,
+      required List<String> keys,
+      required MultisigScriptType scriptType,
+      required String token,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kBsmsDescriptorRecordStaticMethodApiConstMeta;
+
+  /// Decrypt and check the descriptor record as a BSMS signer: it must have the signer key and
+  /// the first address must match. Returns the descriptors to create the wallet with.
+  Future<BsmsDescriptorRecord> bsmsVerifyDescriptorRecordStaticMethodApi(
+      {required String record,
+      required String token,
+      required String secretKey,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kBsmsVerifyDescriptorRecordStaticMethodApiConstMeta;
+
   Future<String> createDescriptorStaticMethodApi(
       {required String descriptor, required Network network, dynamic hint});
 
@@ -219,6 +557,28 @@ abstract class RustBdkFfi {
 
   FlutterRustBridgeTaskConstMeta get kNewBip84PublicStaticMethodApiConstMeta;
 
+  /// Build a `pkh()`, `sh(wpkh())`, `wpkh()` or `tr()` descriptor for a single secret key, given
+  /// as WIF or as 32 hex encoded bytes, such as an imported paper wallet key.
+  Future<String> newSingleKeyDescriptorStaticMethodApi(
+      {required DescriptorType scriptType,
+      required String secretKey,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kNewSingleKeyDescriptorStaticMethodApiConstMeta;
+
+  /// Build a watch-only `pkh()`, `sh(wpkh())`, `wpkh()` or `tr()` descriptor for a single public
+  /// key, given as hex.
+  Future<String> newSingleKeyPublicStaticMethodApi(
+      {required DescriptorType scriptType,
+      required String publicKey,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kNewSingleKeyPublicStaticMethodApiConstMeta;
+
   Future<String> asStringPrivateStaticMethodApi(
       {required String descriptor, required Network network, dynamic hint});
 
@@ -235,6 +595,57 @@ abstract class RustBdkFfi {
   FlutterRustBridgeTaskConstMeta
       get kMaxSatisfactionWeightStaticMethodApiConstMeta;
 
+  /// Build the external and change descriptors of a `threshold`-of-n multisig wallet from the
+  /// account-level keys of the cosigners, and optionally our own secret key.
+  Future<MultisigDescriptors> newMultisigDescriptorStaticMethodApi(
+      {required int threshold,
+      required List<String> publicKeys,
+      String? secretKey,
+      required MultisigScriptType scriptType,
+      required bool sorted,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kNewMultisigDescriptorStaticMethodApiConstMeta;
+
+  /// Compile a spending policy with named keys, e.g. `or(pk(A),and(pk(B),older(4320)))`, into a
+  /// `wsh` or `tr` descriptor.
+  Future<CompiledPolicy> compilePolicyStaticMethodApi(
+      {required String policy,
+      required List<PolicyKey> keys,
+      required PolicyContext context,
+      required Network network,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCompilePolicyStaticMethodApiConstMeta;
+
+  /// Derive `count` addresses of a descriptor starting at index `start`, without creating a
+  /// wallet. At most 10,000 addresses can be derived at once.
+  Future<List<DerivedAddress>> deriveAddressesStaticMethodApi(
+      {required String descriptor,
+      required Network network,
+      required int start,
+      required int count,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDeriveAddressesStaticMethodApiConstMeta;
+
+  /// Describe the type, keys and threshold of a descriptor, which may be a BIP389 multipath
+  /// descriptor. The network is inferred from the keys.
+  Future<DescriptorInfo> descriptorInfoStaticMethodApi(
+      {required String descriptor, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDescriptorInfoStaticMethodApiConstMeta;
+
+  /// Read the wallets of a Coldcard, Specter, Sparrow, Electrum or BlueWallet export, with the
+  /// descriptors to pass to `create_wallet`. Coldcard generic JSON exports hold one wallet per
+  /// script type.
+  Future<List<ImportedWallet>> importWalletStaticMethodApi(
+      {required String data, required Network network, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kImportWalletStaticMethodApiConstMeta;
+
   Future<String> createDescriptorSecretStaticMethodApi(
       {required Network network,
       required String mnemonic,
@@ -250,6 +661,13 @@ abstract class RustBdkFfi {
   FlutterRustBridgeTaskConstMeta
       get kDescriptorSecretFromStringStaticMethodApiConstMeta;
 
+  /// Parse a single secret key, given as WIF or as 32 hex encoded bytes.
+  Future<String> descriptorSecretFromSingleStaticMethodApi(
+      {required String secret, required Network network, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorSecretFromSingleStaticMethodApiConstMeta;
+
   Future<String> extendDescriptorSecretStaticMethodApi(
       {required String secret, required String path, dynamic hint});
 
@@ -278,6 +696,20 @@ abstract class RustBdkFfi {
   FlutterRustBridgeTaskConstMeta
       get kCreateDerivationPathStaticMethodApiConstMeta;
 
+  /// Get the fingerprints, origin, extended keys and public key bytes of a descriptor secret key.
+  Future<KeyMetadata> descriptorSecretMetadataStaticMethodApi(
+      {required String secret, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorSecretMetadataStaticMethodApiConstMeta;
+
+  /// Get the fingerprints, origin, extended key and public key bytes of a descriptor public key.
+  Future<KeyMetadata> descriptorPublicMetadataStaticMethodApi(
+      {required String publicKey, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kDescriptorPublicMetadataStaticMethodApiConstMeta;
+
   Future<String> descriptorPublicFromStringStaticMethodApi(
       {required String publicKey, dynamic hint});
 
@@ -290,6 +722,27 @@ abstract class RustBdkFfi {
   FlutterRustBridgeTaskConstMeta
       get kCreateDescriptorPublicStaticMethodApiConstMeta;
 
+  /// Convert a `ypub`, `zpub`, `Ypub`, `Zpub`, `upub`, `vpub`, `Upub` or `Vpub` key to a standard
+  /// xpub or tpub.
+  Future<String> slip132ToStandardStaticMethodApi(
+      {required String key, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSlip132ToStandardStaticMethodApiConstMeta;
+
+  /// Convert an extended public key of any SLIP-132 version to `version`, keeping its network.
+  Future<String> standardToSlip132StaticMethodApi(
+      {required String key, required Slip132Version version, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStandardToSlip132StaticMethodApiConstMeta;
+
+  /// Convert a SLIP-132 key, optionally with an origin such as `[fingerprint/84'/0'/0']zpub...`,
+  /// and give the `sh(wpkh())` or `wpkh()` descriptor, or the `sh(wsh())` or `wsh()` multisig
+  /// script type its version stands for.
+  Future<Slip132Template> slip132TemplateStaticMethodApi(
+      {required String key, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSlip132TemplateStaticMethodApiConstMeta;
+
   Future<Script> createScriptStaticMethodApi(
       {required Uint8List rawOutputScript, dynamic hint});
 
@@ -321,6 +774,8 @@ abstract class RustBdkFfi {
 
   FlutterRustBridgeTaskConstMeta get kAddressNetworkStaticMethodApiConstMeta;
 
+  /// Create a wallet from its descriptor and change descriptor, or from a single BIP389
+  /// multipath descriptor such as `wpkh(xpub.../<0;1>/*)` with no change descriptor.
   Future<String> createWalletStaticMethodApi(
       {required String descriptor,
       String? changeDescriptor,
@@ -371,14 +826,73 @@ abstract class RustBdkFfi {
 
   FlutterRustBridgeTaskConstMeta get kGetTransactionsStaticMethodApiConstMeta;
 
+  /// Sign the inputs of a PSBT the wallet has keys for. With a `safety_policy`, the PSBT is
+  /// first checked with `check_psbt`, and refused when issues are found and the policy's
+  /// `refuse_unsafe` is set.
   Future<String?> signStaticMethodApi(
       {required String walletId,
       required String psbtStr,
       SignOptions? signOptions,
+      PsbtSafetyPolicy? safetyPolicy,
       dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSignStaticMethodApiConstMeta;
 
+  /// Check a PSBT for absurd fees, missing UTXO data, unusual sighash types, dust outputs
+  /// and network mismatches before the wallet signs it.
+  Future<PsbtSafetyReport> checkPsbtStaticMethodApi(
+      {required String walletId,
+      required String psbtStr,
+      required PsbtSafetyPolicy policy,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCheckPsbtStaticMethodApiConstMeta;
+
+  Future<MaxSendable> maxSendableStaticMethodApi(
+      {required String walletId,
+      required Script destination,
+      required double feeRate,
+      required List<OutPoint> utxos,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kMaxSendableStaticMethodApiConstMeta;
+
+  Future<BdkSweepResult> sweepStaticMethodApi(
+      {required String walletId,
+      required Script destination,
+      required double feeRate,
+      required List<OutPoint> utxos,
+      required bool enableRbf,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSweepStaticMethodApiConstMeta;
+
+  Future<List<ConsolidationPsbt>> planConsolidationStaticMethodApi(
+      {required String walletId,
+      required double feeRate,
+      required double futureFeeRate,
+      required List<UtxoLabel> labels,
+      int? maxInputs,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPlanConsolidationStaticMethodApiConstMeta;
+
+  /// Finalize the inputs of a PSBT the wallet can satisfy, e.g. once all cosigners' partial
+  /// signatures have been combined.
+  Future<FinalizedPsbt> finalizePsbtStaticMethodApi(
+      {required String walletId,
+      required String psbtStr,
+      SignOptions? signOptions,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFinalizePsbtStaticMethodApiConstMeta;
+
+  /// Describe what a PSBT does to the wallet before signing it.
+  Future<PsbtAnalysis> analyzePsbtStaticMethodApi(
+      {required String walletId, required String psbtStr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAnalyzePsbtStaticMethodApiConstMeta;
+
   Future<Network> walletNetworkStaticMethodApi(
       {required String walletId, dynamic hint});
 
@@ -405,6 +919,14 @@ abstract class RustBdkFfi {
   FlutterRustBridgeTaskConstMeta
       get kGetDescriptorForKeychainStaticMethodApiConstMeta;
 
+  /// Get the descriptors of both keychains as a single BIP389 multipath descriptor, e.g.
+  /// `wpkh([fingerprint/84'/0'/0']xpub.../<0;1>/*)`, for exporting the wallet.
+  Future<DescNetwork> getMultipathDescriptorStaticMethodApi(
+      {required String walletId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kGetMultipathDescriptorStaticMethodApiConstMeta;
+
   Future<String> generateSeedFromWordCountStaticMethodApi(
       {required WordCount wordCount, dynamic hint});
 
@@ -424,6 +946,29 @@ abstract class RustBdkFfi {
       get kGenerateSeedFromEntropyStaticMethodApiConstMeta;
 }
 
+/// A signature added to an input while combining PSBTs.
+class AddedSignature {
+  /// The public key that signed, hex encoded (x-only for taproot keys). For a taproot key-path
+  /// signature this is the internal key, or empty if the PSBT doesn't carry it.
+  final String publicKey;
+
+  /// The hash of the leaf for taproot script-path signatures.
+  final String? leafHash;
+
+  /// Whether this is a taproot key-path signature.
+  final bool isTapKeySig;
+
+  /// Position in the combined list of the PSBT the signature came from.
+  final int source;
+
+  const AddedSignature({
+    required this.publicKey,
+    this.leafHash,
+    required this.isTapKeySig,
+    required this.source,
+  });
+}
+
 @freezed
 class AddressIndex with _$AddressIndex {
   ///Return a new address after incrementing the current descriptor index.
@@ -496,6 +1041,48 @@ class Balance {
   });
 }
 
+/// The result after finishing a fee bump. Contains the unsigned replacement PSBT, its transaction
+/// details and the fee paid before and after the bump.
+class BdkBumpFeeTxBuilderResult {
+  final String psbt;
+  final TransactionDetails transactionDetails;
+
+  /// Absolute fee (sats) paid by the original transaction.
+  final int originalFee;
+
+  /// Fee rate (sat/vB) paid by the original transaction.
+  final double originalFeeRate;
+
+  /// Absolute fee (sats) paid by the replacement transaction.
+  final int fee;
+
+  /// Estimated fee rate (sat/vB) of the replacement transaction once it is signed.
+  final double feeRate;
+
+  const BdkBumpFeeTxBuilderResult({
+    required this.psbt,
+    required this.transactionDetails,
+    required this.originalFee,
+    required this.originalFeeRate,
+    required this.fee,
+    required this.feeRate,
+  });
+}
+
+/// The result of a sweep. Contains the unsigned PSBT, its transaction details and a breakdown of
+/// the amount sent and the coins left behind.
+class BdkSweepResult {
+  final String psbt;
+  final TransactionDetails transactionDetails;
+  final MaxSendable maxSendable;
+
+  const BdkSweepResult({
+    required this.psbt,
+    required this.transactionDetails,
+    required this.maxSendable,
+  });
+}
+
 /// The result after calling the TxBuilder finish() function. Contains unsigned PSBT and
 /// transaction details.
 class BdkTxBuilderResult {
@@ -540,13 +1127,154 @@ class BlockchainConfig with _$BlockchainConfig {
   }) = BlockchainConfig_Rpc;
 }
 
+/// A descriptor record, checked by a signer.
+class BsmsDescriptorRecord {
+  /// The descriptor template, with `/**` for the external and internal keychains.
+  final String template;
+  final String descriptor;
+  final String changeDescriptor;
+
+  /// The first external address, which the signer should also show to the user.
+  final String firstAddress;
+
+  const BsmsDescriptorRecord({
+    required this.template,
+    required this.descriptor,
+    required this.changeDescriptor,
+    required this.firstAddress,
+  });
+}
+
+/// How the records of a BSMS setup are protected.
+enum BsmsEncryption {
+  /// Plain text records, the token is `00`.
+  NoEncryption,
+
+  /// Records encrypted with a 64-bit token.
+  Standard,
+
+  /// Records encrypted with a 128-bit token.
+  Extended,
+}
+
+/// A signer key record, checked by the coordinator.
+class BsmsKeyRecord {
+  final String token;
+
+  /// The signer key with its origin, e.g. `[fingerprint/48'/0'/0'/2']xpub...`.
+  final String key;
+  final String description;
+
+  /// The signature of the record by the key, in the Bitcoin signed message format.
+  final String signature;
+
+  const BsmsKeyRecord({
+    required this.token,
+    required this.key,
+    required this.description,
+    required this.signature,
+  });
+}
+
 enum ChangeSpendPolicy {
   ChangeAllowed,
   OnlyChange,
   ChangeForbidden,
 }
 
-@freezed
+/// The signatures an input gained while combining PSBTs.
+class CombinedInput {
+  final int index;
+  final List<AddedSignature> addedSignatures;
+
+  const CombinedInput({
+    required this.index,
+    required this.addedSignatures,
+  });
+}
+
+/// The result of combining several PSBTs.
+class CombinedPsbt {
+  /// The combined PSBT, base64 encoded.
+  final String psbt;
+
+  /// One entry per input, in order.
+  final List<CombinedInput> inputs;
+
+  const CombinedPsbt({
+    required this.psbt,
+    required this.inputs,
+  });
+}
+
+/// A descriptor compiled from a policy, with its analysis.
+class CompiledPolicy {
+  /// The descriptor, including the secret keys given for the policy.
+  final String descriptor;
+  final int maxSatisfactionWeight;
+
+  /// Whether the descriptor passes the miniscript sanity checks: no mixed timelocks, every
+  /// spending path needs a signature and can't be malleated, and no repeated keys.
+  final bool isSane;
+
+  /// Why the sanity checks failed.
+  final String? sanityError;
+
+  /// Whether third parties can't malleate the satisfactions of the descriptor.
+  final bool isNonMalleable;
+
+  const CompiledPolicy({
+    required this.descriptor,
+    required this.maxSatisfactionWeight,
+    required this.isSane,
+    this.sanityError,
+    required this.isNonMalleable,
+  });
+}
+
+/// A PSBT planned by the UTXO consolidation planner, merging coins of a single keychain and label
+/// into one output paying a fresh internal address.
+class ConsolidationPsbt {
+  final String psbt;
+  final TransactionDetails transactionDetails;
+  final KeychainKind keychain;
+  final String? label;
+
+  /// The UTXOs merged by this PSBT.
+  final List<OutPoint> utxos;
+
+  /// Sats saved compared to spending the same UTXOs individually at the future fee rate.
+  final int estimatedSavings;
+
+  const ConsolidationPsbt({
+    required this.psbt,
+    required this.transactionDetails,
+    required this.keychain,
+    this.label,
+    required this.utxos,
+    required this.estimatedSavings,
+  });
+}
+
+/// The signing status of one cosigner of a multisig session.
+class CosignerStatus {
+  /// The master key fingerprint of the cosigner.
+  final String fingerprint;
+
+  /// The inputs carrying a signature from this cosigner.
+  final Uint32List signedInputs;
+
+  /// Whether the cosigner signed every input that is not finalized yet.
+  final bool hasSigned;
+
+  const CosignerStatus({
+    required this.fingerprint,
+    required this.signedInputs,
+    required this.hasSigned,
+  });
+}
+
+@freezed
 class DatabaseConfig with _$DatabaseConfig {
   const factory DatabaseConfig.memory() = DatabaseConfig_Memory;
 
@@ -561,6 +1289,23 @@ class DatabaseConfig with _$DatabaseConfig {
   }) = DatabaseConfig_Sled;
 }
 
+/// An address derived from a descriptor.
+class DerivedAddress {
+  final int index;
+  final String address;
+  final Script scriptPubkey;
+
+  /// The keys of the address, with the full path they were derived at from their master key.
+  final List<KeyOrigin> keys;
+
+  const DerivedAddress({
+    required this.index,
+    required this.address,
+    required this.scriptPubkey,
+    required this.keys,
+  });
+}
+
 class DescNetwork {
   final String field0;
   final Network field1;
@@ -571,6 +1316,84 @@ class DescNetwork {
   });
 }
 
+/// The structure and keys of a descriptor.
+class DescriptorInfo {
+  final DescriptorType descriptorType;
+
+  /// The threshold of a multisig descriptor (`multi`, `sortedmulti` or a single `multi_a` leaf).
+  final int? threshold;
+
+  /// Whether the descriptor uses `sortedmulti`.
+  final bool isSortedMulti;
+  final bool hasWildcard;
+
+  /// Whether the descriptor has BIP389 multipath keys, e.g. `/<0;1>/*`.
+  final bool isMultipath;
+  final List<DescriptorKeyInfo> keys;
+  final String checksum;
+  final bool hasPrivateKeys;
+
+  /// The network of the keys: `Bitcoin`, `Testnet` for any test network, or `None` when no key
+  /// tells or the keys disagree.
+  final Network? network;
+
+  const DescriptorInfo({
+    required this.descriptorType,
+    this.threshold,
+    required this.isSortedMulti,
+    required this.hasWildcard,
+    required this.isMultipath,
+    required this.keys,
+    required this.checksum,
+    required this.hasPrivateKeys,
+    this.network,
+  });
+}
+
+/// A key of a descriptor.
+class DescriptorKeyInfo {
+  /// The public key as written in the descriptor, with its origin and derivation steps.
+  final String key;
+
+  /// The master key fingerprint from the key origin, if the key has one.
+  final String? originFingerprint;
+
+  /// The derivation path from the key origin, if the key has one.
+  final String? originPath;
+
+  /// The origin fingerprint, or the fingerprint of the key itself without an origin.
+  final String masterFingerprint;
+
+  /// The path from the master key, up to but excluding a wildcard.
+  final String fullDerivationPath;
+  final bool hasWildcard;
+
+  /// Whether the descriptor carries the private key.
+  final bool isPrivate;
+
+  const DescriptorKeyInfo({
+    required this.key,
+    this.originFingerprint,
+    this.originPath,
+    required this.masterFingerprint,
+    required this.fullDerivationPath,
+    required this.hasWildcard,
+    required this.isPrivate,
+  });
+}
+
+/// The script type of a descriptor.
+enum DescriptorType {
+  Bare,
+  Pkh,
+  Wpkh,
+  ShWpkh,
+  Sh,
+  Wsh,
+  ShWsh,
+  Tr,
+}
+
 /// Configuration for an ElectrumBlockchain
 class ElectrumConfig {
   ///URL of the Electrum server (such as ElectrumX, Esplora, BWT) may start with ssl:// or tcp:// and include a port
@@ -633,6 +1456,158 @@ class EsploraConfig {
   });
 }
 
+/// A UTXO left out of a sweep because spending it would cost more than it is worth.
+class ExcludedUtxo {
+  final OutPoint outpoint;
+
+  /// Value of the UTXO (sats).
+  final int value;
+
+  /// Fee (sats) needed to spend the UTXO at the requested fee rate.
+  final int spendCost;
+
+  const ExcludedUtxo({
+    required this.outpoint,
+    required this.value,
+    required this.spendCost,
+  });
+}
+
+/// The outcome of finalizing one PSBT input.
+class FinalizeInputResult {
+  final int index;
+  final bool isFinalized;
+
+  /// Why the input could not be finalized yet, e.g. missing signatures.
+  final String? error;
+
+  const FinalizeInputResult({
+    required this.index,
+    required this.isFinalized,
+    this.error,
+  });
+}
+
+/// A PSBT after finalization, along with the outcome for each of its inputs.
+class FinalizedPsbt {
+  final String psbt;
+
+  /// Whether every input is finalized, i.e. the transaction can be extracted.
+  final bool isFinalized;
+  final List<FinalizeInputResult> inputs;
+
+  const FinalizedPsbt({
+    required this.psbt,
+    required this.isFinalized,
+    required this.inputs,
+  });
+}
+
+/// A key of an imported wallet.
+class ImportedCosigner {
+  /// The master key fingerprint.
+  final String fingerprint;
+
+  /// The derivation path of the key from its master key.
+  final String derivationPath;
+
+  /// The account extended public key as an xpub or tpub, or the key of a single key
+  /// descriptor.
+  final String key;
+
+  const ImportedCosigner({
+    required this.fingerprint,
+    required this.derivationPath,
+    required this.key,
+  });
+}
+
+/// A wallet read from an export, with the descriptors to pass to `Api::create_wallet`.
+class ImportedWallet {
+  final WalletSource source;
+
+  /// The wallet name or label, if the export has one.
+  final String? name;
+  final String descriptor;
+  final String changeDescriptor;
+
+  /// The signatures needed to spend from a multisig wallet.
+  final int? threshold;
+  final List<ImportedCosigner> cosigners;
+
+  const ImportedWallet({
+    required this.source,
+    this.name,
+    required this.descriptor,
+    required this.changeDescriptor,
+    this.threshold,
+    required this.cosigners,
+  });
+}
+
+/// The metadata of a descriptor key. The extended key fields are `None` for single keys.
+class KeyMetadata {
+  /// The fingerprint from the key origin, or of the key itself without an origin.
+  final String masterFingerprint;
+
+  /// The fingerprint of the key itself.
+  final String fingerprint;
+
+  /// The derivation path from the master key, e.g. `m/84'/0'/0'`, if the key has an origin.
+  final String? originPath;
+  final WildcardType wildcard;
+
+  /// The fingerprint of the parent key, `00000000` for a master key.
+  final String? parentFingerprint;
+  final int? depth;
+
+  /// The child number, with the high bit set for hardened children.
+  final int? childNumber;
+  final Uint8List? chainCode;
+
+  /// The extended public key, without the origin nor the derivation steps after it.
+  final String? xpub;
+
+  /// The extended private key, without the origin nor the derivation steps after it. Only for
+  /// secret keys.
+  final String? xprv;
+
+  /// The 33 bytes compressed public key, before any derivation steps. `None` for x-only keys.
+  final Uint8List? publicKey;
+
+  /// The 32 bytes x-only public key, as used by taproot.
+  final Uint8List xOnlyPublicKey;
+
+  const KeyMetadata({
+    required this.masterFingerprint,
+    required this.fingerprint,
+    this.originPath,
+    required this.wildcard,
+    this.parentFingerprint,
+    this.depth,
+    this.childNumber,
+    this.chainCode,
+    this.xpub,
+    this.xprv,
+    this.publicKey,
+    required this.xOnlyPublicKey,
+  });
+}
+
+/// The origin of a key: the fingerprint of its master key and the path it was derived at.
+class KeyOrigin {
+  /// The public key, hex encoded (x-only for taproot keys).
+  final String publicKey;
+  final String fingerprint;
+  final String path;
+
+  const KeyOrigin({
+    required this.publicKey,
+    required this.fingerprint,
+    required this.path,
+  });
+}
+
 ///Types of keychains
 enum KeychainKind {
   External,
@@ -661,6 +1636,78 @@ class LocalUtxo {
   });
 }
 
+/// The maximum amount a wallet can send to a destination in a single transaction.
+class MaxSendable {
+  /// Amount (sats) received by the destination after fees.
+  final int amount;
+
+  /// Fee (sats) paid by the transaction.
+  final int fee;
+
+  /// The UTXOs spent.
+  final List<OutPoint> utxos;
+
+  /// The UTXOs excluded because they are uneconomical at the requested fee rate.
+  final List<ExcludedUtxo> excluded;
+
+  const MaxSendable({
+    required this.amount,
+    required this.fee,
+    required this.utxos,
+    required this.excluded,
+  });
+}
+
+/// The external and change descriptors of a multisig wallet.
+class MultisigDescriptors {
+  final String descriptor;
+  final String changeDescriptor;
+
+  const MultisigDescriptors({
+    required this.descriptor,
+    required this.changeDescriptor,
+  });
+}
+
+/// The script type of a multisig descriptor.
+enum MultisigScriptType {
+  /// Legacy `sh(multi(...))`, at most 15 keys.
+  P2sh,
+
+  /// Nested segwit `sh(wsh(multi(...)))`.
+  P2shP2wsh,
+
+  /// Native segwit `wsh(multi(...))`.
+  P2wsh,
+
+  /// Taproot `tr(<unspendable key>,multi_a(...))`.
+  P2tr,
+}
+
+/// The state of a multisig session.
+class MultisigStatus {
+  /// Signatures needed for each input.
+  final int threshold;
+  final List<CosignerStatus> cosigners;
+
+  /// The number of cosigner signatures on each input, `threshold` for finalized inputs.
+  final Uint32List signatures;
+
+  /// Whether every input has enough signatures to be finalized.
+  final bool isReady;
+
+  /// The session PSBT, base64 encoded.
+  final String psbt;
+
+  const MultisigStatus({
+    required this.threshold,
+    required this.cosigners,
+    required this.signatures,
+    required this.isReady,
+    required this.psbt,
+  });
+}
+
 ///The cryptocurrency to act on
 enum Network {
   ///Bitcoin’s testnet
@@ -690,6 +1737,65 @@ class OutPoint {
   });
 }
 
+/// Optional parameters a PayJoin sender passes to the receiver, as described by BIP78.
+class PayjoinParams {
+  /// Index of the sender's output that may be reduced to pay for the receiver's inputs.
+  final int? additionalFeeOutputIndex;
+
+  /// Maximum sats the sender accepts to pay for the receiver's inputs.
+  final int? maxAdditionalFeeContribution;
+
+  /// Minimum fee rate (sat/vB) the payjoin transaction must pay.
+  final double? minFeeRate;
+
+  /// Whether the receiver may not substitute the payee output.
+  final bool disableOutputSubstitution;
+
+  const PayjoinParams({
+    this.additionalFeeOutputIndex,
+    this.maxAdditionalFeeContribution,
+    this.minFeeRate,
+    required this.disableOutputSubstitution,
+  });
+}
+
+/// The HTTP request a PayJoin sender posts to the receiver's endpoint with `Content-Type: text/plain`.
+class PayjoinRequest {
+  /// The endpoint with the BIP78 parameters appended to its query string.
+  final String url;
+
+  /// The signed original PSBT, base64 encoded. It doubles as the fallback transaction to
+  /// broadcast if the PayJoin fails.
+  final String body;
+
+  const PayjoinRequest({
+    required this.url,
+    required this.body,
+  });
+}
+
+/// A BIP21 payment request carrying a BIP78 PayJoin endpoint.
+class PayjoinUri {
+  /// The address to pay.
+  final String address;
+
+  /// The requested amount in sats, if any.
+  final int? amount;
+
+  /// The receiver's PayJoin endpoint (`pj=`).
+  final String endpoint;
+
+  /// Whether the receiver asked the sender to refuse output substitution (`pjos=0`).
+  final bool disableOutputSubstitution;
+
+  const PayjoinUri({
+    required this.address,
+    this.amount,
+    required this.endpoint,
+    required this.disableOutputSubstitution,
+  });
+}
+
 @freezed
 class Payload with _$Payload {
   /// P2PKH address.
@@ -712,6 +1818,275 @@ class Payload with _$Payload {
   }) = Payload_WitnessProgram;
 }
 
+/// The kind of descriptor a policy is compiled to.
+enum PolicyContext {
+  /// A segwit v0 `wsh()` descriptor.
+  Wsh,
+
+  /// A taproot `tr()` descriptor. The internal key is the most likely key of the policy, or an
+  /// unspendable key if no key can spend alone.
+  Tr,
+}
+
+/// A key of a policy: its name in the policy and the descriptor public or secret key it stands
+/// for.
+class PolicyKey {
+  final String name;
+  final String key;
+
+  const PolicyKey({
+    required this.name,
+    required this.key,
+  });
+}
+
+/// What a PSBT does to a wallet.
+class PsbtAnalysis {
+  final List<PsbtInputAnalysis> inputs;
+  final List<PsbtOutputAnalysis> outputs;
+
+  /// Sum of the wallet's inputs (sats).
+  final int sent;
+
+  /// Sum of the outputs paying the wallet (sats).
+  final int received;
+
+  /// Change in the wallet's balance once the transaction confirms (sats).
+  final int net;
+
+  /// Fee (sats), if every input has UTXO data.
+  final int? fee;
+
+  /// Estimated fee rate (sat/vB). Unfinalized inputs are counted at their largest satisfaction
+  /// for the wallet's own inputs, and at the usual size of a single signature spend for foreign
+  /// P2PKH, P2WPKH and P2TR inputs. `None` if the fee is unknown or a foreign input's
+  /// satisfaction can't be estimated.
+  final double? feeRate;
+  final List<PsbtWarning> warnings;
+
+  const PsbtAnalysis({
+    required this.inputs,
+    required this.outputs,
+    required this.sent,
+    required this.received,
+    required this.net,
+    this.fee,
+    this.feeRate,
+    required this.warnings,
+  });
+}
+
+/// An input of a PSBT, as seen by the wallet analyzing it.
+class PsbtInputAnalysis {
+  final OutPoint previousOutput;
+
+  /// Value of the spent output (sats), if the PSBT or the wallet knows it.
+  final int? value;
+  final bool isMine;
+
+  /// Keychain and derivation index of the spent output, if it belongs to the wallet.
+  final KeychainKind? keychain;
+  final int? derivationIndex;
+
+  /// Whether the wallet holds a key able to sign this input.
+  final bool canSign;
+
+  const PsbtInputAnalysis({
+    required this.previousOutput,
+    this.value,
+    required this.isMine,
+    this.keychain,
+    this.derivationIndex,
+    required this.canSign,
+  });
+}
+
+/// A PSBT input as seen by a signer.
+class PsbtInputInfo {
+  final OutPoint previousOutput;
+  final int sequence;
+
+  /// Value of the spent output, from the witness or non-witness UTXO. `None` if the PSBT has
+  /// neither.
+  final int? amount;
+
+  /// Script of the spent output, `None` if the PSBT has no UTXO for this input.
+  final Script? scriptPubkey;
+  final PsbtSigHashType? sighashType;
+
+  /// The BIP32 derivations of the keys involved, including taproot keys.
+  final List<KeyOrigin> bip32Derivations;
+
+  /// The public keys, hex encoded, that have provided a partial ECDSA signature.
+  final List<String> partialSigs;
+
+  /// Whether a taproot key-path signature is present.
+  final bool hasTapKeySig;
+  final List<TapScriptSig> tapScriptSigs;
+
+  /// Whether the input has a final script_sig or witness.
+  final bool isFinalized;
+
+  const PsbtInputInfo({
+    required this.previousOutput,
+    required this.sequence,
+    this.amount,
+    this.scriptPubkey,
+    this.sighashType,
+    required this.bip32Derivations,
+    required this.partialSigs,
+    required this.hasTapKeySig,
+    required this.tapScriptSigs,
+    required this.isFinalized,
+  });
+}
+
+@freezed
+class PsbtIssue with _$PsbtIssue {
+  /// The fee is above the policy's absolute limit.
+  const factory PsbtIssue.feeTooHigh({
+    required int fee,
+    required int maxFee,
+  }) = PsbtIssue_FeeTooHigh;
+
+  /// The fee is above the policy's limit relative to the amount sent.
+  const factory PsbtIssue.feeTooHighRelative({
+    required int fee,
+    required double feePercent,
+    required double maxFeePercent,
+  }) = PsbtIssue_FeeTooHighRelative;
+
+  /// The input has no UTXO data at all, so its value and the fee are unknown.
+  const factory PsbtIssue.missingUtxo({
+    required int index,
+  }) = PsbtIssue_MissingUtxo;
+
+  /// The input only has a `witness_utxo`, whose amount can't be verified.
+  const factory PsbtIssue.missingNonWitnessUtxo({
+    required int index,
+  }) = PsbtIssue_MissingNonWitnessUtxo;
+
+  /// The `non_witness_utxo` of the input is not the transaction it spends from.
+  const factory PsbtIssue.nonWitnessUtxoMismatch({
+    required int index,
+  }) = PsbtIssue_NonWitnessUtxoMismatch;
+
+  /// The `witness_utxo` of the input doesn't have the value of the output its `non_witness_utxo`
+  /// says it spends. The value of the `non_witness_utxo` is the one used for the fee.
+  const factory PsbtIssue.utxoValueMismatch({
+    required int index,
+    required int witnessValue,
+    required int nonWitnessValue,
+  }) = PsbtIssue_UtxoValueMismatch;
+
+  /// The input asks for a sighash type other than ALL.
+  const factory PsbtIssue.nonStandardSighash({
+    required int index,
+    required int sighash,
+  }) = PsbtIssue_NonStandardSighash;
+
+  /// The output is below the dust limit of its script.
+  const factory PsbtIssue.dustOutput({
+    required int index,
+    required int value,
+    required int dustLimit,
+  }) = PsbtIssue_DustOutput;
+
+  /// The PSBT carries keys or derivation paths of another network.
+  const factory PsbtIssue.networkMismatch({
+    required Network network,
+  }) = PsbtIssue_NetworkMismatch;
+}
+
+@freezed
+class PsbtMap with _$PsbtMap {
+  const factory PsbtMap.global() = PsbtMap_Global;
+  const factory PsbtMap.input({
+    required int index,
+  }) = PsbtMap_Input;
+  const factory PsbtMap.output({
+    required int index,
+  }) = PsbtMap_Output;
+}
+
+/// An output of a PSBT, as seen by the wallet analyzing it.
+class PsbtOutputAnalysis {
+  final Script scriptPubkey;
+  final int value;
+  final bool isMine;
+
+  /// Whether the output pays to the internal (change) keychain of the wallet.
+  final bool isChange;
+  final KeychainKind? keychain;
+  final int? derivationIndex;
+
+  const PsbtOutputAnalysis({
+    required this.scriptPubkey,
+    required this.value,
+    required this.isMine,
+    required this.isChange,
+    this.keychain,
+    this.derivationIndex,
+  });
+}
+
+/// A PSBT output as seen by a signer.
+class PsbtOutputInfo {
+  final Script scriptPubkey;
+
+  /// The address of the output, `None` if the script has no address form.
+  final String? address;
+  final int amount;
+
+  /// The BIP32 derivations of the output's keys, present when the output is change.
+  final List<KeyOrigin> bip32Derivations;
+
+  const PsbtOutputInfo({
+    required this.scriptPubkey,
+    this.address,
+    required this.amount,
+    required this.bip32Derivations,
+  });
+}
+
+/// Limits applied by the PSBT safety checks.
+class PsbtSafetyPolicy {
+  /// Highest acceptable fee (sats).
+  final int? maxFee;
+
+  /// Highest acceptable fee, as a percentage of the value sent to outputs the wallet doesn't own.
+  final double? maxFeePercent;
+
+  /// Whether non-taproot inputs must carry their full previous transaction, which protects
+  /// against the SegWit v0 fee bug.
+  final bool requireNonWitnessUtxo;
+
+  /// Whether signing is refused when any issue is found.
+  final bool refuseUnsafe;
+
+  const PsbtSafetyPolicy({
+    this.maxFee,
+    this.maxFeePercent,
+    required this.requireNonWitnessUtxo,
+    required this.refuseUnsafe,
+  });
+}
+
+/// The outcome of the PSBT safety checks.
+class PsbtSafetyReport {
+  final bool isSafe;
+
+  /// Fee (sats), if every input has UTXO data.
+  final int? fee;
+  final List<PsbtIssue> issues;
+
+  const PsbtSafetyReport({
+    required this.isSafe,
+    this.fee,
+    required this.issues,
+  });
+}
+
 class PsbtSigHashType {
   final int inner;
 
@@ -720,6 +2095,25 @@ class PsbtSigHashType {
   });
 }
 
+@freezed
+class PsbtWarning with _$PsbtWarning {
+  /// The PSBT carries extended keys for another network than the wallet's.
+  const factory PsbtWarning.networkMismatch({
+    required Network network,
+  }) = PsbtWarning_NetworkMismatch;
+
+  /// The input at `index` has no UTXO data, so its value can't be verified.
+  const factory PsbtWarning.missingUtxo({
+    required int index,
+  }) = PsbtWarning_MissingUtxo;
+
+  /// The output at `index` claims a derivation from the wallet's keys, but its script doesn't
+  /// match what the wallet's descriptors derive.
+  const factory PsbtWarning.changeDerivationMismatch({
+    required int index,
+  }) = PsbtWarning_ChangeDerivationMismatch;
+}
+
 @freezed
 class RbfValue with _$RbfValue {
   const factory RbfValue.rbfDefault() = RbfValue_RbfDefault;
@@ -802,6 +2196,24 @@ class ScriptAmount {
   });
 }
 
+@freezed
+class ShrinkOutput with _$ShrinkOutput {
+  /// The output paying to this address.
+  const factory ShrinkOutput.address({
+    required String address,
+  }) = ShrinkOutput_Address;
+
+  /// The output locked by this script.
+  const factory ShrinkOutput.script({
+    required Script script,
+  }) = ShrinkOutput_Script;
+
+  /// The output at this position in the original transaction.
+  const factory ShrinkOutput.index({
+    required int index,
+  }) = ShrinkOutput_Index;
+}
+
 /// Options for a software signer
 ///
 /// Adjust the behavior of our software signers and the way a transaction is finalized
@@ -884,6 +2296,48 @@ class SledDbConfiguration {
   });
 }
 
+/// A SLIP-132 extended public key converted to a standard one, with the descriptor template of
+/// its version.
+class Slip132Template {
+  /// The key as a standard xpub or tpub, with its origin if it had one.
+  final String key;
+  final Slip132Version version;
+
+  /// The BIP389 multipath wallet descriptor of the single signature versions, e.g.
+  /// `wpkh([fingerprint/84'/0'/0']xpub.../<0;1>/*)`. `None` for `xpub`/`tpub`, which may be
+  /// `pkh()` or `sh()` multisig.
+  final String? descriptor;
+
+  /// The script type of the multisig versions, to build the descriptor with once every cosigner
+  /// key is known.
+  final MultisigScriptType? multisigScriptType;
+
+  const Slip132Template({
+    required this.key,
+    required this.version,
+    this.descriptor,
+    this.multisigScriptType,
+  });
+}
+
+/// The script types of extended public key versions, see SLIP-132.
+enum Slip132Version {
+  /// `xpub`, or `tpub` on the test networks: `pkh()`, or `sh()` multisig.
+  Standard,
+
+  /// `ypub`/`upub`: `sh(wpkh())`.
+  NestedSegwit,
+
+  /// `zpub`/`vpub`: `wpkh()`.
+  Segwit,
+
+  /// `Ypub`/`Upub`: `sh(wsh())` multisig.
+  NestedSegwitMultisig,
+
+  /// `Zpub`/`Vpub`: `wsh()` multisig.
+  SegwitMultisig,
+}
+
 ///Configuration type for a SqliteDatabase database
 class SqliteDbConfiguration {
   ///Main directory of the db
@@ -894,6 +2348,20 @@ class SqliteDbConfiguration {
   });
 }
 
+/// A taproot script-path signature present in a PSBT input.
+class TapScriptSig {
+  /// The x-only public key that signed, hex encoded.
+  final String publicKey;
+
+  /// The hash of the leaf the signature is for.
+  final String leafHash;
+
+  const TapScriptSig({
+    required this.publicKey,
+    required this.leafHash,
+  });
+}
+
 ///A wallet transaction
 class TransactionDetails {
   final String? serializedTx;
@@ -957,6 +2425,49 @@ class TxOut {
   });
 }
 
+/// The state of a UR decoder after receiving a part.
+class UrDecodeProgress {
+  /// The type of the UR being decoded, known after the first valid part.
+  final String? urType;
+
+  /// Estimated fraction of the message received, from 0 to 1.
+  final double progress;
+
+  /// The decoded payload, once the message is complete.
+  final UrPayload? result;
+
+  const UrDecodeProgress({
+    this.urType,
+    required this.progress,
+    this.result,
+  });
+}
+
+@freezed
+class UrPayload with _$UrPayload {
+  /// A `crypto-psbt`, as base64.
+  const factory UrPayload.psbt({
+    required String psbt,
+  }) = UrPayload_Psbt;
+
+  /// A `crypto-output` descriptor.
+  const factory UrPayload.output({
+    required String descriptor,
+  }) = UrPayload_Output;
+
+  /// A `crypto-account`: the master key fingerprint and the descriptors of the account.
+  const factory UrPayload.account({
+    required String fingerprint,
+    required List<String> descriptors,
+  }) = UrPayload_Account;
+
+  /// A UR of any other type, as its raw CBOR message.
+  const factory UrPayload.bytes({
+    required String urType,
+    required Uint8List cbor,
+  }) = UrPayload_Bytes;
+}
+
 /// RpcBlockchain configuration options
 ///
 class UserPass {
@@ -972,6 +2483,47 @@ class UserPass {
   });
 }
 
+/// A user-assigned label for one of the wallet's UTXOs.
+class UtxoLabel {
+  final OutPoint outpoint;
+  final String label;
+
+  const UtxoLabel({
+    required this.outpoint,
+    required this.label,
+  });
+}
+
+/// Where a wallet export comes from.
+enum WalletSource {
+  /// A Coldcard generic JSON export or multisig `wallet.txt` setup file.
+  Coldcard,
+
+  /// A Specter Desktop wallet backup.
+  Specter,
+
+  /// An Electrum wallet file.
+  Electrum,
+
+  /// A BlueWallet multisig setup file or watch-only export.
+  BlueWallet,
+
+  /// Plain descriptors, as exported by Sparrow or Bitcoin Core.
+  Descriptor,
+}
+
+/// The wildcard at the end of a descriptor key.
+enum WildcardType {
+  /// No wildcard, e.g. a single key or `xpub.../0`.
+  None,
+
+  /// `xpub.../*`.
+  Unhardened,
+
+  /// `xprv.../*'`, which can't be derived from the public key.
+  Hardened,
+}
+
 enum WitnessVersion {
   /// Initial version of witness program. Used for P2WPKH and P2WPK outputs
   V0,
@@ -2078,57 +2078,114 @@ abstract class Payload_WitnessProgram implements Payload {
 }
 
 /// @nodoc
-mixin _$RbfValue {
+mixin _$PsbtIssue {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() rbfDefault,
-    required TResult Function(int field0) value,
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? rbfDefault,
-    TResult? Function(int field0)? value,
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? rbfDefault,
-    TResult Function(int field0)? value,
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(RbfValue_RbfDefault value) rbfDefault,
-    required TResult Function(RbfValue_Value value) value,
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(RbfValue_RbfDefault value)? rbfDefault,
-    TResult? Function(RbfValue_Value value)? value,
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(RbfValue_RbfDefault value)? rbfDefault,
-    TResult Function(RbfValue_Value value)? value,
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $RbfValueCopyWith<$Res> {
-  factory $RbfValueCopyWith(RbfValue value, $Res Function(RbfValue) then) =
-      _$RbfValueCopyWithImpl<$Res, RbfValue>;
+abstract class $PsbtIssueCopyWith<$Res> {
+  factory $PsbtIssueCopyWith(PsbtIssue value, $Res Function(PsbtIssue) then) =
+      _$PsbtIssueCopyWithImpl<$Res, PsbtIssue>;
 }
 
 /// @nodoc
-class _$RbfValueCopyWithImpl<$Res, $Val extends RbfValue>
-    implements $RbfValueCopyWith<$Res> {
-  _$RbfValueCopyWithImpl(this._value, this._then);
+class _$PsbtIssueCopyWithImpl<$Res, $Val extends PsbtIssue>
+    implements $PsbtIssueCopyWith<$Res> {
+  _$PsbtIssueCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -2137,67 +2194,129 @@ class _$RbfValueCopyWithImpl<$Res, $Val extends RbfValue>
 }
 
 /// @nodoc
-abstract class _$$RbfValue_RbfDefaultCopyWith<$Res> {
-  factory _$$RbfValue_RbfDefaultCopyWith(_$RbfValue_RbfDefault value,
-          $Res Function(_$RbfValue_RbfDefault) then) =
-      __$$RbfValue_RbfDefaultCopyWithImpl<$Res>;
+abstract class _$$PsbtIssue_FeeTooHighCopyWith<$Res> {
+  factory _$$PsbtIssue_FeeTooHighCopyWith(_$PsbtIssue_FeeTooHigh value,
+          $Res Function(_$PsbtIssue_FeeTooHigh) then) =
+      __$$PsbtIssue_FeeTooHighCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int fee, int maxFee});
 }
 
 /// @nodoc
-class __$$RbfValue_RbfDefaultCopyWithImpl<$Res>
-    extends _$RbfValueCopyWithImpl<$Res, _$RbfValue_RbfDefault>
-    implements _$$RbfValue_RbfDefaultCopyWith<$Res> {
-  __$$RbfValue_RbfDefaultCopyWithImpl(
-      _$RbfValue_RbfDefault _value, $Res Function(_$RbfValue_RbfDefault) _then)
+class __$$PsbtIssue_FeeTooHighCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_FeeTooHigh>
+    implements _$$PsbtIssue_FeeTooHighCopyWith<$Res> {
+  __$$PsbtIssue_FeeTooHighCopyWithImpl(_$PsbtIssue_FeeTooHigh _value,
+      $Res Function(_$PsbtIssue_FeeTooHigh) _then)
       : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? fee = null,
+    Object? maxFee = null,
+  }) {
+    return _then(_$PsbtIssue_FeeTooHigh(
+      fee: null == fee
+          ? _value.fee
+          : fee // ignore: cast_nullable_to_non_nullable
+              as int,
+      maxFee: null == maxFee
+          ? _value.maxFee
+          : maxFee // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$RbfValue_RbfDefault implements RbfValue_RbfDefault {
-  const _$RbfValue_RbfDefault();
+class _$PsbtIssue_FeeTooHigh implements PsbtIssue_FeeTooHigh {
+  const _$PsbtIssue_FeeTooHigh({required this.fee, required this.maxFee});
+
+  @override
+  final int fee;
+  @override
+  final int maxFee;
 
   @override
   String toString() {
-    return 'RbfValue.rbfDefault()';
+    return 'PsbtIssue.feeTooHigh(fee: $fee, maxFee: $maxFee)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$RbfValue_RbfDefault);
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_FeeTooHigh &&
+            (identical(other.fee, fee) || other.fee == fee) &&
+            (identical(other.maxFee, maxFee) || other.maxFee == maxFee));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, fee, maxFee);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_FeeTooHighCopyWith<_$PsbtIssue_FeeTooHigh> get copyWith =>
+      __$$PsbtIssue_FeeTooHighCopyWithImpl<_$PsbtIssue_FeeTooHigh>(
+          this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() rbfDefault,
-    required TResult Function(int field0) value,
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
   }) {
-    return rbfDefault();
+    return feeTooHigh(fee, maxFee);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? rbfDefault,
-    TResult? Function(int field0)? value,
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
   }) {
-    return rbfDefault?.call();
+    return feeTooHigh?.call(fee, maxFee);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? rbfDefault,
-    TResult Function(int field0)? value,
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
     required TResult orElse(),
   }) {
-    if (rbfDefault != null) {
-      return rbfDefault();
+    if (feeTooHigh != null) {
+      return feeTooHigh(fee, maxFee);
     }
     return orElse();
   }
@@ -2205,127 +2324,216 @@ class _$RbfValue_RbfDefault implements RbfValue_RbfDefault {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(RbfValue_RbfDefault value) rbfDefault,
-    required TResult Function(RbfValue_Value value) value,
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
   }) {
-    return rbfDefault(this);
+    return feeTooHigh(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(RbfValue_RbfDefault value)? rbfDefault,
-    TResult? Function(RbfValue_Value value)? value,
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
   }) {
-    return rbfDefault?.call(this);
+    return feeTooHigh?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(RbfValue_RbfDefault value)? rbfDefault,
-    TResult Function(RbfValue_Value value)? value,
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
     required TResult orElse(),
   }) {
-    if (rbfDefault != null) {
-      return rbfDefault(this);
+    if (feeTooHigh != null) {
+      return feeTooHigh(this);
     }
     return orElse();
   }
 }
 
-abstract class RbfValue_RbfDefault implements RbfValue {
-  const factory RbfValue_RbfDefault() = _$RbfValue_RbfDefault;
+abstract class PsbtIssue_FeeTooHigh implements PsbtIssue {
+  const factory PsbtIssue_FeeTooHigh(
+      {required final int fee,
+      required final int maxFee}) = _$PsbtIssue_FeeTooHigh;
+
+  int get fee;
+  int get maxFee;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_FeeTooHighCopyWith<_$PsbtIssue_FeeTooHigh> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$RbfValue_ValueCopyWith<$Res> {
-  factory _$$RbfValue_ValueCopyWith(
-          _$RbfValue_Value value, $Res Function(_$RbfValue_Value) then) =
-      __$$RbfValue_ValueCopyWithImpl<$Res>;
+abstract class _$$PsbtIssue_FeeTooHighRelativeCopyWith<$Res> {
+  factory _$$PsbtIssue_FeeTooHighRelativeCopyWith(
+          _$PsbtIssue_FeeTooHighRelative value,
+          $Res Function(_$PsbtIssue_FeeTooHighRelative) then) =
+      __$$PsbtIssue_FeeTooHighRelativeCopyWithImpl<$Res>;
   @useResult
-  $Res call({int field0});
+  $Res call({int fee, double feePercent, double maxFeePercent});
 }
 
 /// @nodoc
-class __$$RbfValue_ValueCopyWithImpl<$Res>
-    extends _$RbfValueCopyWithImpl<$Res, _$RbfValue_Value>
-    implements _$$RbfValue_ValueCopyWith<$Res> {
-  __$$RbfValue_ValueCopyWithImpl(
-      _$RbfValue_Value _value, $Res Function(_$RbfValue_Value) _then)
+class __$$PsbtIssue_FeeTooHighRelativeCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_FeeTooHighRelative>
+    implements _$$PsbtIssue_FeeTooHighRelativeCopyWith<$Res> {
+  __$$PsbtIssue_FeeTooHighRelativeCopyWithImpl(
+      _$PsbtIssue_FeeTooHighRelative _value,
+      $Res Function(_$PsbtIssue_FeeTooHighRelative) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? field0 = null,
+    Object? fee = null,
+    Object? feePercent = null,
+    Object? maxFeePercent = null,
   }) {
-    return _then(_$RbfValue_Value(
-      null == field0
-          ? _value.field0
-          : field0 // ignore: cast_nullable_to_non_nullable
+    return _then(_$PsbtIssue_FeeTooHighRelative(
+      fee: null == fee
+          ? _value.fee
+          : fee // ignore: cast_nullable_to_non_nullable
               as int,
+      feePercent: null == feePercent
+          ? _value.feePercent
+          : feePercent // ignore: cast_nullable_to_non_nullable
+              as double,
+      maxFeePercent: null == maxFeePercent
+          ? _value.maxFeePercent
+          : maxFeePercent // ignore: cast_nullable_to_non_nullable
+              as double,
     ));
   }
 }
 
 /// @nodoc
 
-class _$RbfValue_Value implements RbfValue_Value {
-  const _$RbfValue_Value(this.field0);
+class _$PsbtIssue_FeeTooHighRelative implements PsbtIssue_FeeTooHighRelative {
+  const _$PsbtIssue_FeeTooHighRelative(
+      {required this.fee,
+      required this.feePercent,
+      required this.maxFeePercent});
 
   @override
-  final int field0;
+  final int fee;
+  @override
+  final double feePercent;
+  @override
+  final double maxFeePercent;
 
   @override
   String toString() {
-    return 'RbfValue.value(field0: $field0)';
+    return 'PsbtIssue.feeTooHighRelative(fee: $fee, feePercent: $feePercent, maxFeePercent: $maxFeePercent)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$RbfValue_Value &&
-            (identical(other.field0, field0) || other.field0 == field0));
+            other is _$PsbtIssue_FeeTooHighRelative &&
+            (identical(other.fee, fee) || other.fee == fee) &&
+            (identical(other.feePercent, feePercent) ||
+                other.feePercent == feePercent) &&
+            (identical(other.maxFeePercent, maxFeePercent) ||
+                other.maxFeePercent == maxFeePercent));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, field0);
+  int get hashCode => Object.hash(runtimeType, fee, feePercent, maxFeePercent);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$RbfValue_ValueCopyWith<_$RbfValue_Value> get copyWith =>
-      __$$RbfValue_ValueCopyWithImpl<_$RbfValue_Value>(this, _$identity);
+  _$$PsbtIssue_FeeTooHighRelativeCopyWith<_$PsbtIssue_FeeTooHighRelative>
+      get copyWith => __$$PsbtIssue_FeeTooHighRelativeCopyWithImpl<
+          _$PsbtIssue_FeeTooHighRelative>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() rbfDefault,
-    required TResult Function(int field0) value,
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
   }) {
-    return value(field0);
+    return feeTooHighRelative(fee, feePercent, maxFeePercent);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? rbfDefault,
-    TResult? Function(int field0)? value,
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
   }) {
-    return value?.call(field0);
+    return feeTooHighRelative?.call(fee, feePercent, maxFeePercent);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? rbfDefault,
-    TResult Function(int field0)? value,
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
     required TResult orElse(),
   }) {
-    if (value != null) {
-      return value(field0);
+    if (feeTooHighRelative != null) {
+      return feeTooHighRelative(fee, feePercent, maxFeePercent);
     }
     return orElse();
   }
@@ -2333,40 +2541,3929 @@ class _$RbfValue_Value implements RbfValue_Value {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(RbfValue_RbfDefault value) rbfDefault,
-    required TResult Function(RbfValue_Value value) value,
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
   }) {
-    return value(this);
+    return feeTooHighRelative(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(RbfValue_RbfDefault value)? rbfDefault,
-    TResult? Function(RbfValue_Value value)? value,
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
   }) {
-    return value?.call(this);
+    return feeTooHighRelative?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(RbfValue_RbfDefault value)? rbfDefault,
-    TResult Function(RbfValue_Value value)? value,
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
     required TResult orElse(),
   }) {
-    if (value != null) {
-      return value(this);
+    if (feeTooHighRelative != null) {
+      return feeTooHighRelative(this);
     }
     return orElse();
   }
 }
 
-abstract class RbfValue_Value implements RbfValue {
-  const factory RbfValue_Value(final int field0) = _$RbfValue_Value;
+abstract class PsbtIssue_FeeTooHighRelative implements PsbtIssue {
+  const factory PsbtIssue_FeeTooHighRelative(
+      {required final int fee,
+      required final double feePercent,
+      required final double maxFeePercent}) = _$PsbtIssue_FeeTooHighRelative;
 
-  int get field0;
+  int get fee;
+  double get feePercent;
+  double get maxFeePercent;
   @JsonKey(ignore: true)
-  _$$RbfValue_ValueCopyWith<_$RbfValue_Value> get copyWith =>
+  _$$PsbtIssue_FeeTooHighRelativeCopyWith<_$PsbtIssue_FeeTooHighRelative>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_MissingUtxoCopyWith<$Res> {
+  factory _$$PsbtIssue_MissingUtxoCopyWith(_$PsbtIssue_MissingUtxo value,
+          $Res Function(_$PsbtIssue_MissingUtxo) then) =
+      __$$PsbtIssue_MissingUtxoCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtIssue_MissingUtxoCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_MissingUtxo>
+    implements _$$PsbtIssue_MissingUtxoCopyWith<$Res> {
+  __$$PsbtIssue_MissingUtxoCopyWithImpl(_$PsbtIssue_MissingUtxo _value,
+      $Res Function(_$PsbtIssue_MissingUtxo) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtIssue_MissingUtxo(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_MissingUtxo implements PsbtIssue_MissingUtxo {
+  const _$PsbtIssue_MissingUtxo({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.missingUtxo(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_MissingUtxo &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_MissingUtxoCopyWith<_$PsbtIssue_MissingUtxo> get copyWith =>
+      __$$PsbtIssue_MissingUtxoCopyWithImpl<_$PsbtIssue_MissingUtxo>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return missingUtxo(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return missingUtxo?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (missingUtxo != null) {
+      return missingUtxo(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return missingUtxo(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return missingUtxo?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (missingUtxo != null) {
+      return missingUtxo(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_MissingUtxo implements PsbtIssue {
+  const factory PsbtIssue_MissingUtxo({required final int index}) =
+      _$PsbtIssue_MissingUtxo;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_MissingUtxoCopyWith<_$PsbtIssue_MissingUtxo> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_MissingNonWitnessUtxoCopyWith<$Res> {
+  factory _$$PsbtIssue_MissingNonWitnessUtxoCopyWith(
+          _$PsbtIssue_MissingNonWitnessUtxo value,
+          $Res Function(_$PsbtIssue_MissingNonWitnessUtxo) then) =
+      __$$PsbtIssue_MissingNonWitnessUtxoCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtIssue_MissingNonWitnessUtxoCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_MissingNonWitnessUtxo>
+    implements _$$PsbtIssue_MissingNonWitnessUtxoCopyWith<$Res> {
+  __$$PsbtIssue_MissingNonWitnessUtxoCopyWithImpl(
+      _$PsbtIssue_MissingNonWitnessUtxo _value,
+      $Res Function(_$PsbtIssue_MissingNonWitnessUtxo) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtIssue_MissingNonWitnessUtxo(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_MissingNonWitnessUtxo
+    implements PsbtIssue_MissingNonWitnessUtxo {
+  const _$PsbtIssue_MissingNonWitnessUtxo({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.missingNonWitnessUtxo(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_MissingNonWitnessUtxo &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_MissingNonWitnessUtxoCopyWith<_$PsbtIssue_MissingNonWitnessUtxo>
+      get copyWith => __$$PsbtIssue_MissingNonWitnessUtxoCopyWithImpl<
+          _$PsbtIssue_MissingNonWitnessUtxo>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return missingNonWitnessUtxo(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return missingNonWitnessUtxo?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (missingNonWitnessUtxo != null) {
+      return missingNonWitnessUtxo(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return missingNonWitnessUtxo(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return missingNonWitnessUtxo?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (missingNonWitnessUtxo != null) {
+      return missingNonWitnessUtxo(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_MissingNonWitnessUtxo implements PsbtIssue {
+  const factory PsbtIssue_MissingNonWitnessUtxo({required final int index}) =
+      _$PsbtIssue_MissingNonWitnessUtxo;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_MissingNonWitnessUtxoCopyWith<_$PsbtIssue_MissingNonWitnessUtxo>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_NonWitnessUtxoMismatchCopyWith<$Res> {
+  factory _$$PsbtIssue_NonWitnessUtxoMismatchCopyWith(
+          _$PsbtIssue_NonWitnessUtxoMismatch value,
+          $Res Function(_$PsbtIssue_NonWitnessUtxoMismatch) then) =
+      __$$PsbtIssue_NonWitnessUtxoMismatchCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtIssue_NonWitnessUtxoMismatchCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_NonWitnessUtxoMismatch>
+    implements _$$PsbtIssue_NonWitnessUtxoMismatchCopyWith<$Res> {
+  __$$PsbtIssue_NonWitnessUtxoMismatchCopyWithImpl(
+      _$PsbtIssue_NonWitnessUtxoMismatch _value,
+      $Res Function(_$PsbtIssue_NonWitnessUtxoMismatch) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtIssue_NonWitnessUtxoMismatch(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_NonWitnessUtxoMismatch
+    implements PsbtIssue_NonWitnessUtxoMismatch {
+  const _$PsbtIssue_NonWitnessUtxoMismatch({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.nonWitnessUtxoMismatch(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_NonWitnessUtxoMismatch &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_NonWitnessUtxoMismatchCopyWith<
+      _$PsbtIssue_NonWitnessUtxoMismatch> get copyWith =>
+      __$$PsbtIssue_NonWitnessUtxoMismatchCopyWithImpl<
+          _$PsbtIssue_NonWitnessUtxoMismatch>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return nonWitnessUtxoMismatch(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return nonWitnessUtxoMismatch?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (nonWitnessUtxoMismatch != null) {
+      return nonWitnessUtxoMismatch(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return nonWitnessUtxoMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return nonWitnessUtxoMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (nonWitnessUtxoMismatch != null) {
+      return nonWitnessUtxoMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_NonWitnessUtxoMismatch implements PsbtIssue {
+  const factory PsbtIssue_NonWitnessUtxoMismatch({required final int index}) =
+      _$PsbtIssue_NonWitnessUtxoMismatch;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_NonWitnessUtxoMismatchCopyWith<
+      _$PsbtIssue_NonWitnessUtxoMismatch> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_UtxoValueMismatchCopyWith<$Res> {
+  factory _$$PsbtIssue_UtxoValueMismatchCopyWith(
+          _$PsbtIssue_UtxoValueMismatch value,
+          $Res Function(_$PsbtIssue_UtxoValueMismatch) then) =
+      __$$PsbtIssue_UtxoValueMismatchCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index, int witnessValue, int nonWitnessValue});
+}
+
+/// @nodoc
+class __$$PsbtIssue_UtxoValueMismatchCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_UtxoValueMismatch>
+    implements _$$PsbtIssue_UtxoValueMismatchCopyWith<$Res> {
+  __$$PsbtIssue_UtxoValueMismatchCopyWithImpl(
+      _$PsbtIssue_UtxoValueMismatch _value,
+      $Res Function(_$PsbtIssue_UtxoValueMismatch) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+    Object? witnessValue = null,
+    Object? nonWitnessValue = null,
+  }) {
+    return _then(_$PsbtIssue_UtxoValueMismatch(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+      witnessValue: null == witnessValue
+          ? _value.witnessValue
+          : witnessValue // ignore: cast_nullable_to_non_nullable
+              as int,
+      nonWitnessValue: null == nonWitnessValue
+          ? _value.nonWitnessValue
+          : nonWitnessValue // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_UtxoValueMismatch implements PsbtIssue_UtxoValueMismatch {
+  const _$PsbtIssue_UtxoValueMismatch(
+      {required this.index,
+      required this.witnessValue,
+      required this.nonWitnessValue});
+
+  @override
+  final int index;
+  @override
+  final int witnessValue;
+  @override
+  final int nonWitnessValue;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.utxoValueMismatch(index: $index, witnessValue: $witnessValue, nonWitnessValue: $nonWitnessValue)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_UtxoValueMismatch &&
+            (identical(other.index, index) || other.index == index) &&
+            (identical(other.witnessValue, witnessValue) ||
+                other.witnessValue == witnessValue) &&
+            (identical(other.nonWitnessValue, nonWitnessValue) ||
+                other.nonWitnessValue == nonWitnessValue));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, index, witnessValue, nonWitnessValue);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_UtxoValueMismatchCopyWith<_$PsbtIssue_UtxoValueMismatch>
+      get copyWith => __$$PsbtIssue_UtxoValueMismatchCopyWithImpl<
+          _$PsbtIssue_UtxoValueMismatch>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return utxoValueMismatch(index, witnessValue, nonWitnessValue);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return utxoValueMismatch?.call(index, witnessValue, nonWitnessValue);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (utxoValueMismatch != null) {
+      return utxoValueMismatch(index, witnessValue, nonWitnessValue);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return utxoValueMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return utxoValueMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (utxoValueMismatch != null) {
+      return utxoValueMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_UtxoValueMismatch implements PsbtIssue {
+  const factory PsbtIssue_UtxoValueMismatch(
+      {required final int index,
+      required final int witnessValue,
+      required final int nonWitnessValue}) = _$PsbtIssue_UtxoValueMismatch;
+
+  int get index;
+  int get witnessValue;
+  int get nonWitnessValue;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_UtxoValueMismatchCopyWith<_$PsbtIssue_UtxoValueMismatch>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_NonStandardSighashCopyWith<$Res> {
+  factory _$$PsbtIssue_NonStandardSighashCopyWith(
+          _$PsbtIssue_NonStandardSighash value,
+          $Res Function(_$PsbtIssue_NonStandardSighash) then) =
+      __$$PsbtIssue_NonStandardSighashCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index, int sighash});
+}
+
+/// @nodoc
+class __$$PsbtIssue_NonStandardSighashCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_NonStandardSighash>
+    implements _$$PsbtIssue_NonStandardSighashCopyWith<$Res> {
+  __$$PsbtIssue_NonStandardSighashCopyWithImpl(
+      _$PsbtIssue_NonStandardSighash _value,
+      $Res Function(_$PsbtIssue_NonStandardSighash) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+    Object? sighash = null,
+  }) {
+    return _then(_$PsbtIssue_NonStandardSighash(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+      sighash: null == sighash
+          ? _value.sighash
+          : sighash // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_NonStandardSighash implements PsbtIssue_NonStandardSighash {
+  const _$PsbtIssue_NonStandardSighash(
+      {required this.index, required this.sighash});
+
+  @override
+  final int index;
+  @override
+  final int sighash;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.nonStandardSighash(index: $index, sighash: $sighash)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_NonStandardSighash &&
+            (identical(other.index, index) || other.index == index) &&
+            (identical(other.sighash, sighash) || other.sighash == sighash));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index, sighash);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_NonStandardSighashCopyWith<_$PsbtIssue_NonStandardSighash>
+      get copyWith => __$$PsbtIssue_NonStandardSighashCopyWithImpl<
+          _$PsbtIssue_NonStandardSighash>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return nonStandardSighash(index, sighash);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return nonStandardSighash?.call(index, sighash);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (nonStandardSighash != null) {
+      return nonStandardSighash(index, sighash);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return nonStandardSighash(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return nonStandardSighash?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (nonStandardSighash != null) {
+      return nonStandardSighash(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_NonStandardSighash implements PsbtIssue {
+  const factory PsbtIssue_NonStandardSighash(
+      {required final int index,
+      required final int sighash}) = _$PsbtIssue_NonStandardSighash;
+
+  int get index;
+  int get sighash;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_NonStandardSighashCopyWith<_$PsbtIssue_NonStandardSighash>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_DustOutputCopyWith<$Res> {
+  factory _$$PsbtIssue_DustOutputCopyWith(_$PsbtIssue_DustOutput value,
+          $Res Function(_$PsbtIssue_DustOutput) then) =
+      __$$PsbtIssue_DustOutputCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index, int value, int dustLimit});
+}
+
+/// @nodoc
+class __$$PsbtIssue_DustOutputCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_DustOutput>
+    implements _$$PsbtIssue_DustOutputCopyWith<$Res> {
+  __$$PsbtIssue_DustOutputCopyWithImpl(_$PsbtIssue_DustOutput _value,
+      $Res Function(_$PsbtIssue_DustOutput) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+    Object? value = null,
+    Object? dustLimit = null,
+  }) {
+    return _then(_$PsbtIssue_DustOutput(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as int,
+      dustLimit: null == dustLimit
+          ? _value.dustLimit
+          : dustLimit // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_DustOutput implements PsbtIssue_DustOutput {
+  const _$PsbtIssue_DustOutput(
+      {required this.index, required this.value, required this.dustLimit});
+
+  @override
+  final int index;
+  @override
+  final int value;
+  @override
+  final int dustLimit;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.dustOutput(index: $index, value: $value, dustLimit: $dustLimit)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_DustOutput &&
+            (identical(other.index, index) || other.index == index) &&
+            (identical(other.value, value) || other.value == value) &&
+            (identical(other.dustLimit, dustLimit) ||
+                other.dustLimit == dustLimit));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index, value, dustLimit);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_DustOutputCopyWith<_$PsbtIssue_DustOutput> get copyWith =>
+      __$$PsbtIssue_DustOutputCopyWithImpl<_$PsbtIssue_DustOutput>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return dustOutput(index, value, dustLimit);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return dustOutput?.call(index, value, dustLimit);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (dustOutput != null) {
+      return dustOutput(index, value, dustLimit);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return dustOutput(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return dustOutput?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (dustOutput != null) {
+      return dustOutput(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_DustOutput implements PsbtIssue {
+  const factory PsbtIssue_DustOutput(
+      {required final int index,
+      required final int value,
+      required final int dustLimit}) = _$PsbtIssue_DustOutput;
+
+  int get index;
+  int get value;
+  int get dustLimit;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_DustOutputCopyWith<_$PsbtIssue_DustOutput> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtIssue_NetworkMismatchCopyWith<$Res> {
+  factory _$$PsbtIssue_NetworkMismatchCopyWith(
+          _$PsbtIssue_NetworkMismatch value,
+          $Res Function(_$PsbtIssue_NetworkMismatch) then) =
+      __$$PsbtIssue_NetworkMismatchCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Network network});
+}
+
+/// @nodoc
+class __$$PsbtIssue_NetworkMismatchCopyWithImpl<$Res>
+    extends _$PsbtIssueCopyWithImpl<$Res, _$PsbtIssue_NetworkMismatch>
+    implements _$$PsbtIssue_NetworkMismatchCopyWith<$Res> {
+  __$$PsbtIssue_NetworkMismatchCopyWithImpl(_$PsbtIssue_NetworkMismatch _value,
+      $Res Function(_$PsbtIssue_NetworkMismatch) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? network = null,
+  }) {
+    return _then(_$PsbtIssue_NetworkMismatch(
+      network: null == network
+          ? _value.network
+          : network // ignore: cast_nullable_to_non_nullable
+              as Network,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtIssue_NetworkMismatch implements PsbtIssue_NetworkMismatch {
+  const _$PsbtIssue_NetworkMismatch({required this.network});
+
+  @override
+  final Network network;
+
+  @override
+  String toString() {
+    return 'PsbtIssue.networkMismatch(network: $network)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtIssue_NetworkMismatch &&
+            (identical(other.network, network) || other.network == network));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, network);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtIssue_NetworkMismatchCopyWith<_$PsbtIssue_NetworkMismatch>
+      get copyWith => __$$PsbtIssue_NetworkMismatchCopyWithImpl<
+          _$PsbtIssue_NetworkMismatch>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int fee, int maxFee) feeTooHigh,
+    required TResult Function(int fee, double feePercent, double maxFeePercent)
+        feeTooHighRelative,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) missingNonWitnessUtxo,
+    required TResult Function(int index) nonWitnessUtxoMismatch,
+    required TResult Function(int index, int witnessValue, int nonWitnessValue)
+        utxoValueMismatch,
+    required TResult Function(int index, int sighash) nonStandardSighash,
+    required TResult Function(int index, int value, int dustLimit) dustOutput,
+    required TResult Function(Network network) networkMismatch,
+  }) {
+    return networkMismatch(network);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int fee, int maxFee)? feeTooHigh,
+    TResult? Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? missingNonWitnessUtxo,
+    TResult? Function(int index)? nonWitnessUtxoMismatch,
+    TResult? Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult? Function(int index, int sighash)? nonStandardSighash,
+    TResult? Function(int index, int value, int dustLimit)? dustOutput,
+    TResult? Function(Network network)? networkMismatch,
+  }) {
+    return networkMismatch?.call(network);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int fee, int maxFee)? feeTooHigh,
+    TResult Function(int fee, double feePercent, double maxFeePercent)?
+        feeTooHighRelative,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? missingNonWitnessUtxo,
+    TResult Function(int index)? nonWitnessUtxoMismatch,
+    TResult Function(int index, int witnessValue, int nonWitnessValue)?
+        utxoValueMismatch,
+    TResult Function(int index, int sighash)? nonStandardSighash,
+    TResult Function(int index, int value, int dustLimit)? dustOutput,
+    TResult Function(Network network)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (networkMismatch != null) {
+      return networkMismatch(network);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtIssue_FeeTooHigh value) feeTooHigh,
+    required TResult Function(PsbtIssue_FeeTooHighRelative value)
+        feeTooHighRelative,
+    required TResult Function(PsbtIssue_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtIssue_MissingNonWitnessUtxo value)
+        missingNonWitnessUtxo,
+    required TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)
+        nonWitnessUtxoMismatch,
+    required TResult Function(PsbtIssue_UtxoValueMismatch value)
+        utxoValueMismatch,
+    required TResult Function(PsbtIssue_NonStandardSighash value)
+        nonStandardSighash,
+    required TResult Function(PsbtIssue_DustOutput value) dustOutput,
+    required TResult Function(PsbtIssue_NetworkMismatch value) networkMismatch,
+  }) {
+    return networkMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult? Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult? Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult? Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult? Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult? Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult? Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult? Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+  }) {
+    return networkMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtIssue_FeeTooHigh value)? feeTooHigh,
+    TResult Function(PsbtIssue_FeeTooHighRelative value)? feeTooHighRelative,
+    TResult Function(PsbtIssue_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtIssue_MissingNonWitnessUtxo value)?
+        missingNonWitnessUtxo,
+    TResult Function(PsbtIssue_NonWitnessUtxoMismatch value)?
+        nonWitnessUtxoMismatch,
+    TResult Function(PsbtIssue_UtxoValueMismatch value)? utxoValueMismatch,
+    TResult Function(PsbtIssue_NonStandardSighash value)? nonStandardSighash,
+    TResult Function(PsbtIssue_DustOutput value)? dustOutput,
+    TResult Function(PsbtIssue_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (networkMismatch != null) {
+      return networkMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtIssue_NetworkMismatch implements PsbtIssue {
+  const factory PsbtIssue_NetworkMismatch({required final Network network}) =
+      _$PsbtIssue_NetworkMismatch;
+
+  Network get network;
+  @JsonKey(ignore: true)
+  _$$PsbtIssue_NetworkMismatchCopyWith<_$PsbtIssue_NetworkMismatch>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PsbtMap {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() global,
+    required TResult Function(int index) input,
+    required TResult Function(int index) output,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? global,
+    TResult? Function(int index)? input,
+    TResult? Function(int index)? output,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? global,
+    TResult Function(int index)? input,
+    TResult Function(int index)? output,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtMap_Global value) global,
+    required TResult Function(PsbtMap_Input value) input,
+    required TResult Function(PsbtMap_Output value) output,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtMap_Global value)? global,
+    TResult? Function(PsbtMap_Input value)? input,
+    TResult? Function(PsbtMap_Output value)? output,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtMap_Global value)? global,
+    TResult Function(PsbtMap_Input value)? input,
+    TResult Function(PsbtMap_Output value)? output,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PsbtMapCopyWith<$Res> {
+  factory $PsbtMapCopyWith(PsbtMap value, $Res Function(PsbtMap) then) =
+      _$PsbtMapCopyWithImpl<$Res, PsbtMap>;
+}
+
+/// @nodoc
+class _$PsbtMapCopyWithImpl<$Res, $Val extends PsbtMap>
+    implements $PsbtMapCopyWith<$Res> {
+  _$PsbtMapCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$PsbtMap_GlobalCopyWith<$Res> {
+  factory _$$PsbtMap_GlobalCopyWith(
+          _$PsbtMap_Global value, $Res Function(_$PsbtMap_Global) then) =
+      __$$PsbtMap_GlobalCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PsbtMap_GlobalCopyWithImpl<$Res>
+    extends _$PsbtMapCopyWithImpl<$Res, _$PsbtMap_Global>
+    implements _$$PsbtMap_GlobalCopyWith<$Res> {
+  __$$PsbtMap_GlobalCopyWithImpl(
+      _$PsbtMap_Global _value, $Res Function(_$PsbtMap_Global) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$PsbtMap_Global implements PsbtMap_Global {
+  const _$PsbtMap_Global();
+
+  @override
+  String toString() {
+    return 'PsbtMap.global()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$PsbtMap_Global);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() global,
+    required TResult Function(int index) input,
+    required TResult Function(int index) output,
+  }) {
+    return global();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? global,
+    TResult? Function(int index)? input,
+    TResult? Function(int index)? output,
+  }) {
+    return global?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? global,
+    TResult Function(int index)? input,
+    TResult Function(int index)? output,
+    required TResult orElse(),
+  }) {
+    if (global != null) {
+      return global();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtMap_Global value) global,
+    required TResult Function(PsbtMap_Input value) input,
+    required TResult Function(PsbtMap_Output value) output,
+  }) {
+    return global(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtMap_Global value)? global,
+    TResult? Function(PsbtMap_Input value)? input,
+    TResult? Function(PsbtMap_Output value)? output,
+  }) {
+    return global?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtMap_Global value)? global,
+    TResult Function(PsbtMap_Input value)? input,
+    TResult Function(PsbtMap_Output value)? output,
+    required TResult orElse(),
+  }) {
+    if (global != null) {
+      return global(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtMap_Global implements PsbtMap {
+  const factory PsbtMap_Global() = _$PsbtMap_Global;
+}
+
+/// @nodoc
+abstract class _$$PsbtMap_InputCopyWith<$Res> {
+  factory _$$PsbtMap_InputCopyWith(
+          _$PsbtMap_Input value, $Res Function(_$PsbtMap_Input) then) =
+      __$$PsbtMap_InputCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtMap_InputCopyWithImpl<$Res>
+    extends _$PsbtMapCopyWithImpl<$Res, _$PsbtMap_Input>
+    implements _$$PsbtMap_InputCopyWith<$Res> {
+  __$$PsbtMap_InputCopyWithImpl(
+      _$PsbtMap_Input _value, $Res Function(_$PsbtMap_Input) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtMap_Input(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtMap_Input implements PsbtMap_Input {
+  const _$PsbtMap_Input({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtMap.input(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtMap_Input &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtMap_InputCopyWith<_$PsbtMap_Input> get copyWith =>
+      __$$PsbtMap_InputCopyWithImpl<_$PsbtMap_Input>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() global,
+    required TResult Function(int index) input,
+    required TResult Function(int index) output,
+  }) {
+    return input(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? global,
+    TResult? Function(int index)? input,
+    TResult? Function(int index)? output,
+  }) {
+    return input?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? global,
+    TResult Function(int index)? input,
+    TResult Function(int index)? output,
+    required TResult orElse(),
+  }) {
+    if (input != null) {
+      return input(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtMap_Global value) global,
+    required TResult Function(PsbtMap_Input value) input,
+    required TResult Function(PsbtMap_Output value) output,
+  }) {
+    return input(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtMap_Global value)? global,
+    TResult? Function(PsbtMap_Input value)? input,
+    TResult? Function(PsbtMap_Output value)? output,
+  }) {
+    return input?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtMap_Global value)? global,
+    TResult Function(PsbtMap_Input value)? input,
+    TResult Function(PsbtMap_Output value)? output,
+    required TResult orElse(),
+  }) {
+    if (input != null) {
+      return input(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtMap_Input implements PsbtMap {
+  const factory PsbtMap_Input({required final int index}) = _$PsbtMap_Input;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtMap_InputCopyWith<_$PsbtMap_Input> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtMap_OutputCopyWith<$Res> {
+  factory _$$PsbtMap_OutputCopyWith(
+          _$PsbtMap_Output value, $Res Function(_$PsbtMap_Output) then) =
+      __$$PsbtMap_OutputCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtMap_OutputCopyWithImpl<$Res>
+    extends _$PsbtMapCopyWithImpl<$Res, _$PsbtMap_Output>
+    implements _$$PsbtMap_OutputCopyWith<$Res> {
+  __$$PsbtMap_OutputCopyWithImpl(
+      _$PsbtMap_Output _value, $Res Function(_$PsbtMap_Output) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtMap_Output(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtMap_Output implements PsbtMap_Output {
+  const _$PsbtMap_Output({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtMap.output(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtMap_Output &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtMap_OutputCopyWith<_$PsbtMap_Output> get copyWith =>
+      __$$PsbtMap_OutputCopyWithImpl<_$PsbtMap_Output>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() global,
+    required TResult Function(int index) input,
+    required TResult Function(int index) output,
+  }) {
+    return output(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? global,
+    TResult? Function(int index)? input,
+    TResult? Function(int index)? output,
+  }) {
+    return output?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? global,
+    TResult Function(int index)? input,
+    TResult Function(int index)? output,
+    required TResult orElse(),
+  }) {
+    if (output != null) {
+      return output(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtMap_Global value) global,
+    required TResult Function(PsbtMap_Input value) input,
+    required TResult Function(PsbtMap_Output value) output,
+  }) {
+    return output(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtMap_Global value)? global,
+    TResult? Function(PsbtMap_Input value)? input,
+    TResult? Function(PsbtMap_Output value)? output,
+  }) {
+    return output?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtMap_Global value)? global,
+    TResult Function(PsbtMap_Input value)? input,
+    TResult Function(PsbtMap_Output value)? output,
+    required TResult orElse(),
+  }) {
+    if (output != null) {
+      return output(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtMap_Output implements PsbtMap {
+  const factory PsbtMap_Output({required final int index}) = _$PsbtMap_Output;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtMap_OutputCopyWith<_$PsbtMap_Output> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PsbtWarning {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Network network) networkMismatch,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) changeDerivationMismatch,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Network network)? networkMismatch,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? changeDerivationMismatch,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Network network)? networkMismatch,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? changeDerivationMismatch,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtWarning_NetworkMismatch value)
+        networkMismatch,
+    required TResult Function(PsbtWarning_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtWarning_ChangeDerivationMismatch value)
+        changeDerivationMismatch,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult? Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PsbtWarningCopyWith<$Res> {
+  factory $PsbtWarningCopyWith(
+          PsbtWarning value, $Res Function(PsbtWarning) then) =
+      _$PsbtWarningCopyWithImpl<$Res, PsbtWarning>;
+}
+
+/// @nodoc
+class _$PsbtWarningCopyWithImpl<$Res, $Val extends PsbtWarning>
+    implements $PsbtWarningCopyWith<$Res> {
+  _$PsbtWarningCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$PsbtWarning_NetworkMismatchCopyWith<$Res> {
+  factory _$$PsbtWarning_NetworkMismatchCopyWith(
+          _$PsbtWarning_NetworkMismatch value,
+          $Res Function(_$PsbtWarning_NetworkMismatch) then) =
+      __$$PsbtWarning_NetworkMismatchCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Network network});
+}
+
+/// @nodoc
+class __$$PsbtWarning_NetworkMismatchCopyWithImpl<$Res>
+    extends _$PsbtWarningCopyWithImpl<$Res, _$PsbtWarning_NetworkMismatch>
+    implements _$$PsbtWarning_NetworkMismatchCopyWith<$Res> {
+  __$$PsbtWarning_NetworkMismatchCopyWithImpl(
+      _$PsbtWarning_NetworkMismatch _value,
+      $Res Function(_$PsbtWarning_NetworkMismatch) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? network = null,
+  }) {
+    return _then(_$PsbtWarning_NetworkMismatch(
+      network: null == network
+          ? _value.network
+          : network // ignore: cast_nullable_to_non_nullable
+              as Network,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtWarning_NetworkMismatch implements PsbtWarning_NetworkMismatch {
+  const _$PsbtWarning_NetworkMismatch({required this.network});
+
+  @override
+  final Network network;
+
+  @override
+  String toString() {
+    return 'PsbtWarning.networkMismatch(network: $network)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtWarning_NetworkMismatch &&
+            (identical(other.network, network) || other.network == network));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, network);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtWarning_NetworkMismatchCopyWith<_$PsbtWarning_NetworkMismatch>
+      get copyWith => __$$PsbtWarning_NetworkMismatchCopyWithImpl<
+          _$PsbtWarning_NetworkMismatch>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Network network) networkMismatch,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) changeDerivationMismatch,
+  }) {
+    return networkMismatch(network);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Network network)? networkMismatch,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? changeDerivationMismatch,
+  }) {
+    return networkMismatch?.call(network);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Network network)? networkMismatch,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? changeDerivationMismatch,
+    required TResult orElse(),
+  }) {
+    if (networkMismatch != null) {
+      return networkMismatch(network);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtWarning_NetworkMismatch value)
+        networkMismatch,
+    required TResult Function(PsbtWarning_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtWarning_ChangeDerivationMismatch value)
+        changeDerivationMismatch,
+  }) {
+    return networkMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult? Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+  }) {
+    return networkMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+    required TResult orElse(),
+  }) {
+    if (networkMismatch != null) {
+      return networkMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtWarning_NetworkMismatch implements PsbtWarning {
+  const factory PsbtWarning_NetworkMismatch({required final Network network}) =
+      _$PsbtWarning_NetworkMismatch;
+
+  Network get network;
+  @JsonKey(ignore: true)
+  _$$PsbtWarning_NetworkMismatchCopyWith<_$PsbtWarning_NetworkMismatch>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtWarning_MissingUtxoCopyWith<$Res> {
+  factory _$$PsbtWarning_MissingUtxoCopyWith(_$PsbtWarning_MissingUtxo value,
+          $Res Function(_$PsbtWarning_MissingUtxo) then) =
+      __$$PsbtWarning_MissingUtxoCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtWarning_MissingUtxoCopyWithImpl<$Res>
+    extends _$PsbtWarningCopyWithImpl<$Res, _$PsbtWarning_MissingUtxo>
+    implements _$$PsbtWarning_MissingUtxoCopyWith<$Res> {
+  __$$PsbtWarning_MissingUtxoCopyWithImpl(_$PsbtWarning_MissingUtxo _value,
+      $Res Function(_$PsbtWarning_MissingUtxo) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtWarning_MissingUtxo(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtWarning_MissingUtxo implements PsbtWarning_MissingUtxo {
+  const _$PsbtWarning_MissingUtxo({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtWarning.missingUtxo(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtWarning_MissingUtxo &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtWarning_MissingUtxoCopyWith<_$PsbtWarning_MissingUtxo> get copyWith =>
+      __$$PsbtWarning_MissingUtxoCopyWithImpl<_$PsbtWarning_MissingUtxo>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Network network) networkMismatch,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) changeDerivationMismatch,
+  }) {
+    return missingUtxo(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Network network)? networkMismatch,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? changeDerivationMismatch,
+  }) {
+    return missingUtxo?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Network network)? networkMismatch,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? changeDerivationMismatch,
+    required TResult orElse(),
+  }) {
+    if (missingUtxo != null) {
+      return missingUtxo(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtWarning_NetworkMismatch value)
+        networkMismatch,
+    required TResult Function(PsbtWarning_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtWarning_ChangeDerivationMismatch value)
+        changeDerivationMismatch,
+  }) {
+    return missingUtxo(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult? Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+  }) {
+    return missingUtxo?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+    required TResult orElse(),
+  }) {
+    if (missingUtxo != null) {
+      return missingUtxo(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtWarning_MissingUtxo implements PsbtWarning {
+  const factory PsbtWarning_MissingUtxo({required final int index}) =
+      _$PsbtWarning_MissingUtxo;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtWarning_MissingUtxoCopyWith<_$PsbtWarning_MissingUtxo> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PsbtWarning_ChangeDerivationMismatchCopyWith<$Res> {
+  factory _$$PsbtWarning_ChangeDerivationMismatchCopyWith(
+          _$PsbtWarning_ChangeDerivationMismatch value,
+          $Res Function(_$PsbtWarning_ChangeDerivationMismatch) then) =
+      __$$PsbtWarning_ChangeDerivationMismatchCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$PsbtWarning_ChangeDerivationMismatchCopyWithImpl<$Res>
+    extends _$PsbtWarningCopyWithImpl<$Res,
+        _$PsbtWarning_ChangeDerivationMismatch>
+    implements _$$PsbtWarning_ChangeDerivationMismatchCopyWith<$Res> {
+  __$$PsbtWarning_ChangeDerivationMismatchCopyWithImpl(
+      _$PsbtWarning_ChangeDerivationMismatch _value,
+      $Res Function(_$PsbtWarning_ChangeDerivationMismatch) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$PsbtWarning_ChangeDerivationMismatch(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PsbtWarning_ChangeDerivationMismatch
+    implements PsbtWarning_ChangeDerivationMismatch {
+  const _$PsbtWarning_ChangeDerivationMismatch({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'PsbtWarning.changeDerivationMismatch(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PsbtWarning_ChangeDerivationMismatch &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PsbtWarning_ChangeDerivationMismatchCopyWith<
+      _$PsbtWarning_ChangeDerivationMismatch> get copyWith =>
+      __$$PsbtWarning_ChangeDerivationMismatchCopyWithImpl<
+          _$PsbtWarning_ChangeDerivationMismatch>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Network network) networkMismatch,
+    required TResult Function(int index) missingUtxo,
+    required TResult Function(int index) changeDerivationMismatch,
+  }) {
+    return changeDerivationMismatch(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Network network)? networkMismatch,
+    TResult? Function(int index)? missingUtxo,
+    TResult? Function(int index)? changeDerivationMismatch,
+  }) {
+    return changeDerivationMismatch?.call(index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Network network)? networkMismatch,
+    TResult Function(int index)? missingUtxo,
+    TResult Function(int index)? changeDerivationMismatch,
+    required TResult orElse(),
+  }) {
+    if (changeDerivationMismatch != null) {
+      return changeDerivationMismatch(index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PsbtWarning_NetworkMismatch value)
+        networkMismatch,
+    required TResult Function(PsbtWarning_MissingUtxo value) missingUtxo,
+    required TResult Function(PsbtWarning_ChangeDerivationMismatch value)
+        changeDerivationMismatch,
+  }) {
+    return changeDerivationMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult? Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult? Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+  }) {
+    return changeDerivationMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PsbtWarning_NetworkMismatch value)? networkMismatch,
+    TResult Function(PsbtWarning_MissingUtxo value)? missingUtxo,
+    TResult Function(PsbtWarning_ChangeDerivationMismatch value)?
+        changeDerivationMismatch,
+    required TResult orElse(),
+  }) {
+    if (changeDerivationMismatch != null) {
+      return changeDerivationMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PsbtWarning_ChangeDerivationMismatch implements PsbtWarning {
+  const factory PsbtWarning_ChangeDerivationMismatch(
+      {required final int index}) = _$PsbtWarning_ChangeDerivationMismatch;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$PsbtWarning_ChangeDerivationMismatchCopyWith<
+      _$PsbtWarning_ChangeDerivationMismatch> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$RbfValue {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() rbfDefault,
+    required TResult Function(int field0) value,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? rbfDefault,
+    TResult? Function(int field0)? value,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? rbfDefault,
+    TResult Function(int field0)? value,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RbfValue_RbfDefault value) rbfDefault,
+    required TResult Function(RbfValue_Value value) value,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RbfValue_RbfDefault value)? rbfDefault,
+    TResult? Function(RbfValue_Value value)? value,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RbfValue_RbfDefault value)? rbfDefault,
+    TResult Function(RbfValue_Value value)? value,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RbfValueCopyWith<$Res> {
+  factory $RbfValueCopyWith(RbfValue value, $Res Function(RbfValue) then) =
+      _$RbfValueCopyWithImpl<$Res, RbfValue>;
+}
+
+/// @nodoc
+class _$RbfValueCopyWithImpl<$Res, $Val extends RbfValue>
+    implements $RbfValueCopyWith<$Res> {
+  _$RbfValueCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$RbfValue_RbfDefaultCopyWith<$Res> {
+  factory _$$RbfValue_RbfDefaultCopyWith(_$RbfValue_RbfDefault value,
+          $Res Function(_$RbfValue_RbfDefault) then) =
+      __$$RbfValue_RbfDefaultCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$RbfValue_RbfDefaultCopyWithImpl<$Res>
+    extends _$RbfValueCopyWithImpl<$Res, _$RbfValue_RbfDefault>
+    implements _$$RbfValue_RbfDefaultCopyWith<$Res> {
+  __$$RbfValue_RbfDefaultCopyWithImpl(
+      _$RbfValue_RbfDefault _value, $Res Function(_$RbfValue_RbfDefault) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$RbfValue_RbfDefault implements RbfValue_RbfDefault {
+  const _$RbfValue_RbfDefault();
+
+  @override
+  String toString() {
+    return 'RbfValue.rbfDefault()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$RbfValue_RbfDefault);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() rbfDefault,
+    required TResult Function(int field0) value,
+  }) {
+    return rbfDefault();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? rbfDefault,
+    TResult? Function(int field0)? value,
+  }) {
+    return rbfDefault?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? rbfDefault,
+    TResult Function(int field0)? value,
+    required TResult orElse(),
+  }) {
+    if (rbfDefault != null) {
+      return rbfDefault();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RbfValue_RbfDefault value) rbfDefault,
+    required TResult Function(RbfValue_Value value) value,
+  }) {
+    return rbfDefault(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RbfValue_RbfDefault value)? rbfDefault,
+    TResult? Function(RbfValue_Value value)? value,
+  }) {
+    return rbfDefault?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RbfValue_RbfDefault value)? rbfDefault,
+    TResult Function(RbfValue_Value value)? value,
+    required TResult orElse(),
+  }) {
+    if (rbfDefault != null) {
+      return rbfDefault(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RbfValue_RbfDefault implements RbfValue {
+  const factory RbfValue_RbfDefault() = _$RbfValue_RbfDefault;
+}
+
+/// @nodoc
+abstract class _$$RbfValue_ValueCopyWith<$Res> {
+  factory _$$RbfValue_ValueCopyWith(
+          _$RbfValue_Value value, $Res Function(_$RbfValue_Value) then) =
+      __$$RbfValue_ValueCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$RbfValue_ValueCopyWithImpl<$Res>
+    extends _$RbfValueCopyWithImpl<$Res, _$RbfValue_Value>
+    implements _$$RbfValue_ValueCopyWith<$Res> {
+  __$$RbfValue_ValueCopyWithImpl(
+      _$RbfValue_Value _value, $Res Function(_$RbfValue_Value) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$RbfValue_Value(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RbfValue_Value implements RbfValue_Value {
+  const _$RbfValue_Value(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'RbfValue.value(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RbfValue_Value &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RbfValue_ValueCopyWith<_$RbfValue_Value> get copyWith =>
+      __$$RbfValue_ValueCopyWithImpl<_$RbfValue_Value>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() rbfDefault,
+    required TResult Function(int field0) value,
+  }) {
+    return value(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? rbfDefault,
+    TResult? Function(int field0)? value,
+  }) {
+    return value?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? rbfDefault,
+    TResult Function(int field0)? value,
+    required TResult orElse(),
+  }) {
+    if (value != null) {
+      return value(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RbfValue_RbfDefault value) rbfDefault,
+    required TResult Function(RbfValue_Value value) value,
+  }) {
+    return value(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RbfValue_RbfDefault value)? rbfDefault,
+    TResult? Function(RbfValue_Value value)? value,
+  }) {
+    return value?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RbfValue_RbfDefault value)? rbfDefault,
+    TResult Function(RbfValue_Value value)? value,
+    required TResult orElse(),
+  }) {
+    if (value != null) {
+      return value(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RbfValue_Value implements RbfValue {
+  const factory RbfValue_Value(final int field0) = _$RbfValue_Value;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$RbfValue_ValueCopyWith<_$RbfValue_Value> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$ShrinkOutput {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String address) address,
+    required TResult Function(Script script) script,
+    required TResult Function(int index) index,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String address)? address,
+    TResult? Function(Script script)? script,
+    TResult? Function(int index)? index,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String address)? address,
+    TResult Function(Script script)? script,
+    TResult Function(int index)? index,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ShrinkOutput_Address value) address,
+    required TResult Function(ShrinkOutput_Script value) script,
+    required TResult Function(ShrinkOutput_Index value) index,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ShrinkOutput_Address value)? address,
+    TResult? Function(ShrinkOutput_Script value)? script,
+    TResult? Function(ShrinkOutput_Index value)? index,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ShrinkOutput_Address value)? address,
+    TResult Function(ShrinkOutput_Script value)? script,
+    TResult Function(ShrinkOutput_Index value)? index,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ShrinkOutputCopyWith<$Res> {
+  factory $ShrinkOutputCopyWith(
+          ShrinkOutput value, $Res Function(ShrinkOutput) then) =
+      _$ShrinkOutputCopyWithImpl<$Res, ShrinkOutput>;
+}
+
+/// @nodoc
+class _$ShrinkOutputCopyWithImpl<$Res, $Val extends ShrinkOutput>
+    implements $ShrinkOutputCopyWith<$Res> {
+  _$ShrinkOutputCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$ShrinkOutput_AddressCopyWith<$Res> {
+  factory _$$ShrinkOutput_AddressCopyWith(_$ShrinkOutput_Address value,
+          $Res Function(_$ShrinkOutput_Address) then) =
+      __$$ShrinkOutput_AddressCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String address});
+}
+
+/// @nodoc
+class __$$ShrinkOutput_AddressCopyWithImpl<$Res>
+    extends _$ShrinkOutputCopyWithImpl<$Res, _$ShrinkOutput_Address>
+    implements _$$ShrinkOutput_AddressCopyWith<$Res> {
+  __$$ShrinkOutput_AddressCopyWithImpl(_$ShrinkOutput_Address _value,
+      $Res Function(_$ShrinkOutput_Address) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? address = null,
+  }) {
+    return _then(_$ShrinkOutput_Address(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ShrinkOutput_Address implements ShrinkOutput_Address {
+  const _$ShrinkOutput_Address({required this.address});
+
+  @override
+  final String address;
+
+  @override
+  String toString() {
+    return 'ShrinkOutput.address(address: $address)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ShrinkOutput_Address &&
+            (identical(other.address, address) || other.address == address));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, address);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ShrinkOutput_AddressCopyWith<_$ShrinkOutput_Address> get copyWith =>
+      __$$ShrinkOutput_AddressCopyWithImpl<_$ShrinkOutput_Address>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String address) address,
+    required TResult Function(Script script) script,
+    required TResult Function(int index) index,
+  }) {
+    return address(this.address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String address)? address,
+    TResult? Function(Script script)? script,
+    TResult? Function(int index)? index,
+  }) {
+    return address?.call(this.address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String address)? address,
+    TResult Function(Script script)? script,
+    TResult Function(int index)? index,
+    required TResult orElse(),
+  }) {
+    if (address != null) {
+      return address(this.address);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ShrinkOutput_Address value) address,
+    required TResult Function(ShrinkOutput_Script value) script,
+    required TResult Function(ShrinkOutput_Index value) index,
+  }) {
+    return address(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ShrinkOutput_Address value)? address,
+    TResult? Function(ShrinkOutput_Script value)? script,
+    TResult? Function(ShrinkOutput_Index value)? index,
+  }) {
+    return address?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ShrinkOutput_Address value)? address,
+    TResult Function(ShrinkOutput_Script value)? script,
+    TResult Function(ShrinkOutput_Index value)? index,
+    required TResult orElse(),
+  }) {
+    if (address != null) {
+      return address(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ShrinkOutput_Address implements ShrinkOutput {
+  const factory ShrinkOutput_Address({required final String address}) =
+      _$ShrinkOutput_Address;
+
+  String get address;
+  @JsonKey(ignore: true)
+  _$$ShrinkOutput_AddressCopyWith<_$ShrinkOutput_Address> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ShrinkOutput_ScriptCopyWith<$Res> {
+  factory _$$ShrinkOutput_ScriptCopyWith(_$ShrinkOutput_Script value,
+          $Res Function(_$ShrinkOutput_Script) then) =
+      __$$ShrinkOutput_ScriptCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Script script});
+}
+
+/// @nodoc
+class __$$ShrinkOutput_ScriptCopyWithImpl<$Res>
+    extends _$ShrinkOutputCopyWithImpl<$Res, _$ShrinkOutput_Script>
+    implements _$$ShrinkOutput_ScriptCopyWith<$Res> {
+  __$$ShrinkOutput_ScriptCopyWithImpl(
+      _$ShrinkOutput_Script _value, $Res Function(_$ShrinkOutput_Script) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? script = null,
+  }) {
+    return _then(_$ShrinkOutput_Script(
+      script: null == script
+          ? _value.script
+          : script // ignore: cast_nullable_to_non_nullable
+              as Script,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ShrinkOutput_Script implements ShrinkOutput_Script {
+  const _$ShrinkOutput_Script({required this.script});
+
+  @override
+  final Script script;
+
+  @override
+  String toString() {
+    return 'ShrinkOutput.script(script: $script)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ShrinkOutput_Script &&
+            (identical(other.script, script) || other.script == script));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, script);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ShrinkOutput_ScriptCopyWith<_$ShrinkOutput_Script> get copyWith =>
+      __$$ShrinkOutput_ScriptCopyWithImpl<_$ShrinkOutput_Script>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String address) address,
+    required TResult Function(Script script) script,
+    required TResult Function(int index) index,
+  }) {
+    return script(this.script);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String address)? address,
+    TResult? Function(Script script)? script,
+    TResult? Function(int index)? index,
+  }) {
+    return script?.call(this.script);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String address)? address,
+    TResult Function(Script script)? script,
+    TResult Function(int index)? index,
+    required TResult orElse(),
+  }) {
+    if (script != null) {
+      return script(this.script);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ShrinkOutput_Address value) address,
+    required TResult Function(ShrinkOutput_Script value) script,
+    required TResult Function(ShrinkOutput_Index value) index,
+  }) {
+    return script(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ShrinkOutput_Address value)? address,
+    TResult? Function(ShrinkOutput_Script value)? script,
+    TResult? Function(ShrinkOutput_Index value)? index,
+  }) {
+    return script?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ShrinkOutput_Address value)? address,
+    TResult Function(ShrinkOutput_Script value)? script,
+    TResult Function(ShrinkOutput_Index value)? index,
+    required TResult orElse(),
+  }) {
+    if (script != null) {
+      return script(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ShrinkOutput_Script implements ShrinkOutput {
+  const factory ShrinkOutput_Script({required final Script script}) =
+      _$ShrinkOutput_Script;
+
+  Script get script;
+  @JsonKey(ignore: true)
+  _$$ShrinkOutput_ScriptCopyWith<_$ShrinkOutput_Script> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ShrinkOutput_IndexCopyWith<$Res> {
+  factory _$$ShrinkOutput_IndexCopyWith(_$ShrinkOutput_Index value,
+          $Res Function(_$ShrinkOutput_Index) then) =
+      __$$ShrinkOutput_IndexCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int index});
+}
+
+/// @nodoc
+class __$$ShrinkOutput_IndexCopyWithImpl<$Res>
+    extends _$ShrinkOutputCopyWithImpl<$Res, _$ShrinkOutput_Index>
+    implements _$$ShrinkOutput_IndexCopyWith<$Res> {
+  __$$ShrinkOutput_IndexCopyWithImpl(
+      _$ShrinkOutput_Index _value, $Res Function(_$ShrinkOutput_Index) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? index = null,
+  }) {
+    return _then(_$ShrinkOutput_Index(
+      index: null == index
+          ? _value.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ShrinkOutput_Index implements ShrinkOutput_Index {
+  const _$ShrinkOutput_Index({required this.index});
+
+  @override
+  final int index;
+
+  @override
+  String toString() {
+    return 'ShrinkOutput.index(index: $index)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ShrinkOutput_Index &&
+            (identical(other.index, index) || other.index == index));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, index);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ShrinkOutput_IndexCopyWith<_$ShrinkOutput_Index> get copyWith =>
+      __$$ShrinkOutput_IndexCopyWithImpl<_$ShrinkOutput_Index>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String address) address,
+    required TResult Function(Script script) script,
+    required TResult Function(int index) index,
+  }) {
+    return index(this.index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String address)? address,
+    TResult? Function(Script script)? script,
+    TResult? Function(int index)? index,
+  }) {
+    return index?.call(this.index);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String address)? address,
+    TResult Function(Script script)? script,
+    TResult Function(int index)? index,
+    required TResult orElse(),
+  }) {
+    if (index != null) {
+      return index(this.index);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ShrinkOutput_Address value) address,
+    required TResult Function(ShrinkOutput_Script value) script,
+    required TResult Function(ShrinkOutput_Index value) index,
+  }) {
+    return index(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ShrinkOutput_Address value)? address,
+    TResult? Function(ShrinkOutput_Script value)? script,
+    TResult? Function(ShrinkOutput_Index value)? index,
+  }) {
+    return index?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ShrinkOutput_Address value)? address,
+    TResult Function(ShrinkOutput_Script value)? script,
+    TResult Function(ShrinkOutput_Index value)? index,
+    required TResult orElse(),
+  }) {
+    if (index != null) {
+      return index(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ShrinkOutput_Index implements ShrinkOutput {
+  const factory ShrinkOutput_Index({required final int index}) =
+      _$ShrinkOutput_Index;
+
+  int get index;
+  @JsonKey(ignore: true)
+  _$$ShrinkOutput_IndexCopyWith<_$ShrinkOutput_Index> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$UrPayload {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String psbt) psbt,
+    required TResult Function(String descriptor) output,
+    required TResult Function(String fingerprint, List<String> descriptors)
+        account,
+    required TResult Function(String urType, Uint8List cbor) bytes,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String psbt)? psbt,
+    TResult? Function(String descriptor)? output,
+    TResult? Function(String fingerprint, List<String> descriptors)? account,
+    TResult? Function(String urType, Uint8List cbor)? bytes,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String psbt)? psbt,
+    TResult Function(String descriptor)? output,
+    TResult Function(String fingerprint, List<String> descriptors)? account,
+    TResult Function(String urType, Uint8List cbor)? bytes,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UrPayload_Psbt value) psbt,
+    required TResult Function(UrPayload_Output value) output,
+    required TResult Function(UrPayload_Account value) account,
+    required TResult Function(UrPayload_Bytes value) bytes,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UrPayload_Psbt value)? psbt,
+    TResult? Function(UrPayload_Output value)? output,
+    TResult? Function(UrPayload_Account value)? account,
+    TResult? Function(UrPayload_Bytes value)? bytes,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UrPayload_Psbt value)? psbt,
+    TResult Function(UrPayload_Output value)? output,
+    TResult Function(UrPayload_Account value)? account,
+    TResult Function(UrPayload_Bytes value)? bytes,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $UrPayloadCopyWith<$Res> {
+  factory $UrPayloadCopyWith(UrPayload value, $Res Function(UrPayload) then) =
+      _$UrPayloadCopyWithImpl<$Res, UrPayload>;
+}
+
+/// @nodoc
+class _$UrPayloadCopyWithImpl<$Res, $Val extends UrPayload>
+    implements $UrPayloadCopyWith<$Res> {
+  _$UrPayloadCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$UrPayload_PsbtCopyWith<$Res> {
+  factory _$$UrPayload_PsbtCopyWith(
+          _$UrPayload_Psbt value, $Res Function(_$UrPayload_Psbt) then) =
+      __$$UrPayload_PsbtCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String psbt});
+}
+
+/// @nodoc
+class __$$UrPayload_PsbtCopyWithImpl<$Res>
+    extends _$UrPayloadCopyWithImpl<$Res, _$UrPayload_Psbt>
+    implements _$$UrPayload_PsbtCopyWith<$Res> {
+  __$$UrPayload_PsbtCopyWithImpl(
+      _$UrPayload_Psbt _value, $Res Function(_$UrPayload_Psbt) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? psbt = null,
+  }) {
+    return _then(_$UrPayload_Psbt(
+      psbt: null == psbt
+          ? _value.psbt
+          : psbt // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UrPayload_Psbt implements UrPayload_Psbt {
+  const _$UrPayload_Psbt({required this.psbt});
+
+  @override
+  final String psbt;
+
+  @override
+  String toString() {
+    return 'UrPayload.psbt(psbt: $psbt)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UrPayload_Psbt &&
+            (identical(other.psbt, psbt) || other.psbt == psbt));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, psbt);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UrPayload_PsbtCopyWith<_$UrPayload_Psbt> get copyWith =>
+      __$$UrPayload_PsbtCopyWithImpl<_$UrPayload_Psbt>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String psbt) psbt,
+    required TResult Function(String descriptor) output,
+    required TResult Function(String fingerprint, List<String> descriptors)
+        account,
+    required TResult Function(String urType, Uint8List cbor) bytes,
+  }) {
+    return psbt(this.psbt);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String psbt)? psbt,
+    TResult? Function(String descriptor)? output,
+    TResult? Function(String fingerprint, List<String> descriptors)? account,
+    TResult? Function(String urType, Uint8List cbor)? bytes,
+  }) {
+    return psbt?.call(this.psbt);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String psbt)? psbt,
+    TResult Function(String descriptor)? output,
+    TResult Function(String fingerprint, List<String> descriptors)? account,
+    TResult Function(String urType, Uint8List cbor)? bytes,
+    required TResult orElse(),
+  }) {
+    if (psbt != null) {
+      return psbt(this.psbt);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UrPayload_Psbt value) psbt,
+    required TResult Function(UrPayload_Output value) output,
+    required TResult Function(UrPayload_Account value) account,
+    required TResult Function(UrPayload_Bytes value) bytes,
+  }) {
+    return psbt(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UrPayload_Psbt value)? psbt,
+    TResult? Function(UrPayload_Output value)? output,
+    TResult? Function(UrPayload_Account value)? account,
+    TResult? Function(UrPayload_Bytes value)? bytes,
+  }) {
+    return psbt?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UrPayload_Psbt value)? psbt,
+    TResult Function(UrPayload_Output value)? output,
+    TResult Function(UrPayload_Account value)? account,
+    TResult Function(UrPayload_Bytes value)? bytes,
+    required TResult orElse(),
+  }) {
+    if (psbt != null) {
+      return psbt(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UrPayload_Psbt implements UrPayload {
+  const factory UrPayload_Psbt({required final String psbt}) = _$UrPayload_Psbt;
+
+  String get psbt;
+  @JsonKey(ignore: true)
+  _$$UrPayload_PsbtCopyWith<_$UrPayload_Psbt> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$UrPayload_OutputCopyWith<$Res> {
+  factory _$$UrPayload_OutputCopyWith(
+          _$UrPayload_Output value, $Res Function(_$UrPayload_Output) then) =
+      __$$UrPayload_OutputCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String descriptor});
+}
+
+/// @nodoc
+class __$$UrPayload_OutputCopyWithImpl<$Res>
+    extends _$UrPayloadCopyWithImpl<$Res, _$UrPayload_Output>
+    implements _$$UrPayload_OutputCopyWith<$Res> {
+  __$$UrPayload_OutputCopyWithImpl(
+      _$UrPayload_Output _value, $Res Function(_$UrPayload_Output) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? descriptor = null,
+  }) {
+    return _then(_$UrPayload_Output(
+      descriptor: null == descriptor
+          ? _value.descriptor
+          : descriptor // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UrPayload_Output implements UrPayload_Output {
+  const _$UrPayload_Output({required this.descriptor});
+
+  @override
+  final String descriptor;
+
+  @override
+  String toString() {
+    return 'UrPayload.output(descriptor: $descriptor)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UrPayload_Output &&
+            (identical(other.descriptor, descriptor) ||
+                other.descriptor == descriptor));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, descriptor);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UrPayload_OutputCopyWith<_$UrPayload_Output> get copyWith =>
+      __$$UrPayload_OutputCopyWithImpl<_$UrPayload_Output>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String psbt) psbt,
+    required TResult Function(String descriptor) output,
+    required TResult Function(String fingerprint, List<String> descriptors)
+        account,
+    required TResult Function(String urType, Uint8List cbor) bytes,
+  }) {
+    return output(descriptor);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String psbt)? psbt,
+    TResult? Function(String descriptor)? output,
+    TResult? Function(String fingerprint, List<String> descriptors)? account,
+    TResult? Function(String urType, Uint8List cbor)? bytes,
+  }) {
+    return output?.call(descriptor);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String psbt)? psbt,
+    TResult Function(String descriptor)? output,
+    TResult Function(String fingerprint, List<String> descriptors)? account,
+    TResult Function(String urType, Uint8List cbor)? bytes,
+    required TResult orElse(),
+  }) {
+    if (output != null) {
+      return output(descriptor);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UrPayload_Psbt value) psbt,
+    required TResult Function(UrPayload_Output value) output,
+    required TResult Function(UrPayload_Account value) account,
+    required TResult Function(UrPayload_Bytes value) bytes,
+  }) {
+    return output(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UrPayload_Psbt value)? psbt,
+    TResult? Function(UrPayload_Output value)? output,
+    TResult? Function(UrPayload_Account value)? account,
+    TResult? Function(UrPayload_Bytes value)? bytes,
+  }) {
+    return output?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UrPayload_Psbt value)? psbt,
+    TResult Function(UrPayload_Output value)? output,
+    TResult Function(UrPayload_Account value)? account,
+    TResult Function(UrPayload_Bytes value)? bytes,
+    required TResult orElse(),
+  }) {
+    if (output != null) {
+      return output(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UrPayload_Output implements UrPayload {
+  const factory UrPayload_Output({required final String descriptor}) =
+      _$UrPayload_Output;
+
+  String get descriptor;
+  @JsonKey(ignore: true)
+  _$$UrPayload_OutputCopyWith<_$UrPayload_Output> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$UrPayload_AccountCopyWith<$Res> {
+  factory _$$UrPayload_AccountCopyWith(
+          _$UrPayload_Account value, $Res Function(_$UrPayload_Account) then) =
+      __$$UrPayload_AccountCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String fingerprint, List<String> descriptors});
+}
+
+/// @nodoc
+class __$$UrPayload_AccountCopyWithImpl<$Res>
+    extends _$UrPayloadCopyWithImpl<$Res, _$UrPayload_Account>
+    implements _$$UrPayload_AccountCopyWith<$Res> {
+  __$$UrPayload_AccountCopyWithImpl(
+      _$UrPayload_Account _value, $Res Function(_$UrPayload_Account) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? fingerprint = null,
+    Object? descriptors = null,
+  }) {
+    return _then(_$UrPayload_Account(
+      fingerprint: null == fingerprint
+          ? _value.fingerprint
+          : fingerprint // ignore: cast_nullable_to_non_nullable
+              as String,
+      descriptors: null == descriptors
+          ? _value._descriptors
+          : descriptors // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UrPayload_Account implements UrPayload_Account {
+  const _$UrPayload_Account(
+      {required this.fingerprint, required final List<String> descriptors})
+      : _descriptors = descriptors;
+
+  @override
+  final String fingerprint;
+  final List<String> _descriptors;
+  @override
+  List<String> get descriptors {
+    if (_descriptors is EqualUnmodifiableListView) return _descriptors;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_descriptors);
+  }
+
+  @override
+  String toString() {
+    return 'UrPayload.account(fingerprint: $fingerprint, descriptors: $descriptors)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UrPayload_Account &&
+            (identical(other.fingerprint, fingerprint) ||
+                other.fingerprint == fingerprint) &&
+            const DeepCollectionEquality()
+                .equals(other._descriptors, _descriptors));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, fingerprint,
+      const DeepCollectionEquality().hash(_descriptors));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UrPayload_AccountCopyWith<_$UrPayload_Account> get copyWith =>
+      __$$UrPayload_AccountCopyWithImpl<_$UrPayload_Account>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String psbt) psbt,
+    required TResult Function(String descriptor) output,
+    required TResult Function(String fingerprint, List<String> descriptors)
+        account,
+    required TResult Function(String urType, Uint8List cbor) bytes,
+  }) {
+    return account(fingerprint, descriptors);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String psbt)? psbt,
+    TResult? Function(String descriptor)? output,
+    TResult? Function(String fingerprint, List<String> descriptors)? account,
+    TResult? Function(String urType, Uint8List cbor)? bytes,
+  }) {
+    return account?.call(fingerprint, descriptors);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String psbt)? psbt,
+    TResult Function(String descriptor)? output,
+    TResult Function(String fingerprint, List<String> descriptors)? account,
+    TResult Function(String urType, Uint8List cbor)? bytes,
+    required TResult orElse(),
+  }) {
+    if (account != null) {
+      return account(fingerprint, descriptors);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UrPayload_Psbt value) psbt,
+    required TResult Function(UrPayload_Output value) output,
+    required TResult Function(UrPayload_Account value) account,
+    required TResult Function(UrPayload_Bytes value) bytes,
+  }) {
+    return account(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UrPayload_Psbt value)? psbt,
+    TResult? Function(UrPayload_Output value)? output,
+    TResult? Function(UrPayload_Account value)? account,
+    TResult? Function(UrPayload_Bytes value)? bytes,
+  }) {
+    return account?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UrPayload_Psbt value)? psbt,
+    TResult Function(UrPayload_Output value)? output,
+    TResult Function(UrPayload_Account value)? account,
+    TResult Function(UrPayload_Bytes value)? bytes,
+    required TResult orElse(),
+  }) {
+    if (account != null) {
+      return account(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UrPayload_Account implements UrPayload {
+  const factory UrPayload_Account(
+      {required final String fingerprint,
+      required final List<String> descriptors}) = _$UrPayload_Account;
+
+  String get fingerprint;
+  List<String> get descriptors;
+  @JsonKey(ignore: true)
+  _$$UrPayload_AccountCopyWith<_$UrPayload_Account> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$UrPayload_BytesCopyWith<$Res> {
+  factory _$$UrPayload_BytesCopyWith(
+          _$UrPayload_Bytes value, $Res Function(_$UrPayload_Bytes) then) =
+      __$$UrPayload_BytesCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String urType, Uint8List cbor});
+}
+
+/// @nodoc
+class __$$UrPayload_BytesCopyWithImpl<$Res>
+    extends _$UrPayloadCopyWithImpl<$Res, _$UrPayload_Bytes>
+    implements _$$UrPayload_BytesCopyWith<$Res> {
+  __$$UrPayload_BytesCopyWithImpl(
+      _$UrPayload_Bytes _value, $Res Function(_$UrPayload_Bytes) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? urType = null,
+    Object? cbor = null,
+  }) {
+    return _then(_$UrPayload_Bytes(
+      urType: null == urType
+          ? _value.urType
+          : urType // ignore: cast_nullable_to_non_nullable
+              as String,
+      cbor: null == cbor
+          ? _value.cbor
+          : cbor // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UrPayload_Bytes implements UrPayload_Bytes {
+  const _$UrPayload_Bytes({required this.urType, required this.cbor});
+
+  @override
+  final String urType;
+  @override
+  final Uint8List cbor;
+
+  @override
+  String toString() {
+    return 'UrPayload.bytes(urType: $urType, cbor: $cbor)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UrPayload_Bytes &&
+            (identical(other.urType, urType) || other.urType == urType) &&
+            const DeepCollectionEquality().equals(other.cbor, cbor));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, urType, const DeepCollectionEquality().hash(cbor));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UrPayload_BytesCopyWith<_$UrPayload_Bytes> get copyWith =>
+      __$$UrPayload_BytesCopyWithImpl<_$UrPayload_Bytes>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String psbt) psbt,
+    required TResult Function(String descriptor) output,
+    required TResult Function(String fingerprint, List<String> descriptors)
+        account,
+    required TResult Function(String urType, Uint8List cbor) bytes,
+  }) {
+    return bytes(urType, cbor);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String psbt)? psbt,
+    TResult? Function(String descriptor)? output,
+    TResult? Function(String fingerprint, List<String> descriptors)? account,
+    TResult? Function(String urType, Uint8List cbor)? bytes,
+  }) {
+    return bytes?.call(urType, cbor);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String psbt)? psbt,
+    TResult Function(String descriptor)? output,
+    TResult Function(String fingerprint, List<String> descriptors)? account,
+    TResult Function(String urType, Uint8List cbor)? bytes,
+    required TResult orElse(),
+  }) {
+    if (bytes != null) {
+      return bytes(urType, cbor);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UrPayload_Psbt value) psbt,
+    required TResult Function(UrPayload_Output value) output,
+    required TResult Function(UrPayload_Account value) account,
+    required TResult Function(UrPayload_Bytes value) bytes,
+  }) {
+    return bytes(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UrPayload_Psbt value)? psbt,
+    TResult? Function(UrPayload_Output value)? output,
+    TResult? Function(UrPayload_Account value)? account,
+    TResult? Function(UrPayload_Bytes value)? bytes,
+  }) {
+    return bytes?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UrPayload_Psbt value)? psbt,
+    TResult Function(UrPayload_Output value)? output,
+    TResult Function(UrPayload_Account value)? account,
+    TResult Function(UrPayload_Bytes value)? bytes,
+    required TResult orElse(),
+  }) {
+    if (bytes != null) {
+      return bytes(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UrPayload_Bytes implements UrPayload {
+  const factory UrPayload_Bytes(
+      {required final String urType,
+      required final Uint8List cbor}) = _$UrPayload_Bytes;
+
+  String get urType;
+  Uint8List get cbor;
+  @JsonKey(ignore: true)
+  _$$UrPayload_BytesCopyWith<_$UrPayload_Bytes> get copyWith =>
       throw _privateConstructorUsedError;
 }
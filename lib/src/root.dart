@@ -140,15 +140,102 @@ class Blockchain {
   }
 }
 
+///Bitcoin Secure Multisig Setup (BIP129) of a `sortedmulti` wallet between a coordinator and its signers.
+class Bsms {
+  ///Generate the token the coordinator shares with the signers, `00` without encryption.
+  static Future<String> newToken(bridge.BsmsEncryption encryption) async {
+    try {
+      final res =
+          await loaderApi.bsmsNewTokenStaticMethodApi(encryption: encryption);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Create the key record of a signer for its account secret key, e.g. `[fingerprint/48'/0'/0'/2']xprv...`.
+  static Future<String> keyRecord(
+      {required DescriptorSecretKey secretKey,
+      required String token,
+      required String description}) async {
+    try {
+      final res = await loaderApi.bsmsKeyRecordStaticMethodApi(
+          secretKey: secretKey.asString(),
+          token: token,
+          description: description);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Decrypt and check the key record of a signer as the coordinator.
+  static Future<bridge.BsmsKeyRecord> verifyKeyRecord(
+      {required String record, required String token}) async {
+    try {
+      final res = await loaderApi.bsmsVerifyKeyRecordStaticMethodApi(
+          record: record, token: token);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Create the descriptor record of the wallet from the keys of the verified key records.
+  static Future<String> descriptorRecord(
+      {required int threshold,
+      required List<String> keys,
+      required bridge.MultisigScriptType scriptType,
+      required String token,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.bsmsDescriptorRecordStaticMethodApi(
+          threshold: threshold,
+          keys: keys,
+          scriptType: scriptType,
+          token: token,
+          network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Decrypt and check the descriptor record as a signer.
+  ///
+  /// The record must have the signer key and its first address must match. Returns the descriptors to create the [Wallet] with.
+  static Future<bridge.BsmsDescriptorRecord> verifyDescriptorRecord(
+      {required String record,
+      required String token,
+      required DescriptorSecretKey secretKey,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.bsmsVerifyDescriptorRecordStaticMethodApi(
+          record: record,
+          token: token,
+          secretKey: secretKey.asString(),
+          network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+}
+
 /// The BumpFeeTxBuilder is used to bump the fee on a transaction that has been broadcast and has its RBF flag set to true.
 class BumpFeeTxBuilder {
   int? _nSequence;
-  String? _allowShrinking;
+  bridge.ShrinkOutput? _allowShrinking;
   bool _enableRbf = false;
+  int? _feeAbsolute;
+  final List<bridge.OutPoint> _utxos = [];
+  final List<bridge.OutPoint> _unSpendable = [];
+  bool _manuallySelectedOnly = false;
   final String txid;
-  final double feeRate;
+  final double? feeRate;
 
-  BumpFeeTxBuilder({required this.txid, required this.feeRate});
+  /// Either a [feeRate] or an absolute fee, set with [feeAbsolute], is required.
+  BumpFeeTxBuilder({required this.txid, this.feeRate});
 
   ///Explicitly tells the wallet that it is allowed to reduce the amount of the output matching this `address` in order to bump the transaction fee. Without specifying this the wallet will attempt to find a change output to shrink instead.
   ///
@@ -156,7 +243,57 @@ class BumpFeeTxBuilder {
   ///
   /// Throws and exception if address can’t be found among the recipients of the transaction we are bumping.
   BumpFeeTxBuilder allowShrinking(String address) {
-    _allowShrinking = address;
+    _allowShrinking = bridge.ShrinkOutput.address(address: address);
+    return this;
+  }
+
+  ///Same as [allowShrinking], for the output locked by this `script`.
+  BumpFeeTxBuilder allowShrinkingScript(bridge.Script script) {
+    _allowShrinking = bridge.ShrinkOutput.script(script: script);
+    return this;
+  }
+
+  ///Same as [allowShrinking], for the output at this `index` of the transaction we are bumping.
+  BumpFeeTxBuilder allowShrinkingIndex(int index) {
+    _allowShrinking = bridge.ShrinkOutput.index(index: index);
+    return this;
+  }
+
+  ///Set an absolute fee for the replacement transaction, instead of a fee rate.
+  ///
+  /// The fee must be higher than the fee paid by the transaction we are bumping.
+  BumpFeeTxBuilder feeAbsolute(int feeAmount) {
+    _feeAbsolute = feeAmount;
+    return this;
+  }
+
+  ///Add a utxo to the internal list of utxos that must be spent, on top of the inputs of the transaction we are bumping.
+  BumpFeeTxBuilder addUtxo(bridge.OutPoint outpoint) {
+    _utxos.add(outpoint);
+    return this;
+  }
+
+  ///Add the list of outpoints to the internal list of UTXOs that must be spent.
+  BumpFeeTxBuilder addUtxos(List<bridge.OutPoint> outpoints) {
+    _utxos.addAll(outpoints);
+    return this;
+  }
+
+  ///Add utxos to the internal list of unspendable utxos, which will not be added to pay for the new fee.
+  BumpFeeTxBuilder unSpendable(List<bridge.OutPoint> outpoints) {
+    _unSpendable.addAll(outpoints);
+    return this;
+  }
+
+  ///Add a utxo to the internal list of unspendable utxos.
+  BumpFeeTxBuilder addUnSpendable(bridge.OutPoint unSpendable) {
+    _unSpendable.add(unSpendable);
+    return this;
+  }
+
+  ///Only spend the inputs of the transaction we are bumping and the utxos added by [addUtxo].
+  BumpFeeTxBuilder manuallySelectedOnly() {
+    _manuallySelectedOnly = true;
     return this;
   }
 
@@ -179,19 +316,31 @@ class BumpFeeTxBuilder {
     return this;
   }
 
-  /// Finish building the transaction. Returns the  [TxBuilderResult].
-  Future<TxBuilderResult> finish(Wallet wallet) async {
+  /// Finish building the transaction. Returns the  [BumpFeeTxBuilderResult].
+  Future<BumpFeeTxBuilderResult> finish(Wallet wallet) async {
+    if (feeRate == null && _feeAbsolute == null) {
+      throw const BdkException.unExpected(
+          "Either a fee rate or an absolute fee is required");
+    }
     try {
       final res = await loaderApi.bumpFeeTxBuilderFinishStaticMethodApi(
           txid: txid.toString(),
           enableRbf: _enableRbf,
           feeRate: feeRate,
+          feeAbsolute: _feeAbsolute,
           walletId: wallet._wallet,
           nSequence: _nSequence,
-          allowShrinking: _allowShrinking);
-      return TxBuilderResult(
-          psbt: PartiallySignedTransaction(psbtBase64: res.field0),
-          txDetails: res.field1);
+          allowShrinking: _allowShrinking,
+          utxos: _utxos,
+          unspendable: _unSpendable,
+          manuallySelectedOnly: _manuallySelectedOnly);
+      return BumpFeeTxBuilderResult(
+          psbt: PartiallySignedTransaction(psbtBase64: res.psbt),
+          txDetails: res.transactionDetails,
+          originalFee: res.originalFee,
+          originalFeeRate: res.originalFeeRate,
+          fee: res.fee,
+          feeRate: res.feeRate);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
@@ -389,6 +538,148 @@ class Descriptor {
       throw configException(e.message);
     }
   }
+
+  ///Describe the type, keys and threshold of the descriptor.
+  Future<bridge.DescriptorInfo> info() async {
+    try {
+      final res = await loaderApi.descriptorInfoStaticMethodApi(
+          descriptor: _descriptorInstance);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Derive [count] addresses starting at index [start], without creating a wallet.
+  ///
+  /// At most 10,000 addresses can be derived at once.
+  Future<List<bridge.DerivedAddress>> deriveAddresses(
+      {required int start, required int count}) async {
+    try {
+      final res = await loaderApi.deriveAddressesStaticMethodApi(
+          descriptor: _descriptorInstance,
+          network: _network,
+          start: start,
+          count: count);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Encode the descriptor as a `ur:crypto-output`.
+  Future<String> toUr() async {
+    try {
+      final res = await loaderApi.descriptorToUrStaticMethodApi(
+          descriptor: _descriptorInstance, network: _network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Encode the descriptors of an account as a `ur:crypto-account`.
+  static Future<String> accountToUr(
+      {required List<Descriptor> descriptors,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.descriptorsToUrAccountStaticMethodApi(
+          descriptors: descriptors.map((e) => e._descriptorInstance).toList(),
+          network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Finalize the inputs of a [PartiallySignedTransaction] spending from this descriptor or [changeDescriptor], without creating a wallet.
+  ///
+  /// The first [lookahead] derivation indexes, at most 10,000, are searched for inputs that carry no BIP32 derivations.
+  Future<bridge.FinalizedPsbt> finalizePsbt(PartiallySignedTransaction psbt,
+      {Descriptor? changeDescriptor, int lookahead = 1000}) async {
+    try {
+      final res = await loaderApi.finalizePsbtWithDescriptorStaticMethodApi(
+          psbtStr: psbt.psbtBase64,
+          descriptor: _descriptorInstance,
+          changeDescriptor: changeDescriptor?._descriptorInstance,
+          network: _network,
+          lookahead: lookahead);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Single key template. Expands to pkh(key), sh(wpkh(key)), wpkh(key) or tr(key).
+  ///
+  /// The secret key is given as WIF or as 32 hex encoded bytes, such as an imported paper wallet key.
+  static Future<Descriptor> newSingleKey(
+      {required String secretKey,
+      required bridge.DescriptorType scriptType,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.newSingleKeyDescriptorStaticMethodApi(
+          scriptType: scriptType, secretKey: secretKey, network: network);
+      return Descriptor._(res, network);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Watch-only single key template. Expands to pkh(key), sh(wpkh(key)), wpkh(key) or tr(key).
+  ///
+  /// The public key is given as hex.
+  static Future<Descriptor> newSingleKeyPublic(
+      {required String publicKey,
+      required bridge.DescriptorType scriptType,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.newSingleKeyPublicStaticMethodApi(
+          scriptType: scriptType, publicKey: publicKey, network: network);
+      return Descriptor._(res, network);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Build the external and change descriptors of a [threshold]-of-n multisig wallet.
+  ///
+  /// [publicKeys] are the account-level keys of the cosigners. Pass [secretKey] to include our own key.
+  static Future<bridge.MultisigDescriptors> newMultisig(
+      {required int threshold,
+      required List<DescriptorPublicKey> publicKeys,
+      DescriptorSecretKey? secretKey,
+      required bridge.MultisigScriptType scriptType,
+      bool sorted = true,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.newMultisigDescriptorStaticMethodApi(
+          threshold: threshold,
+          publicKeys: publicKeys.map((e) => e.asString()).toList(),
+          secretKey: secretKey?.asString(),
+          scriptType: scriptType,
+          sorted: sorted,
+          network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Compile a spending policy with named keys, e.g. `or(pk(A),and(pk(B),older(4320)))`, into a `wsh` or `tr` descriptor.
+  static Future<bridge.CompiledPolicy> compilePolicy(
+      {required String policy,
+      required List<bridge.PolicyKey> keys,
+      required bridge.PolicyContext context,
+      required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.compilePolicyStaticMethodApi(
+          policy: policy, keys: keys, context: context, network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
 }
 
 ///An extended public key.
@@ -439,6 +730,17 @@ class DescriptorPublicKey {
     }
   }
 
+  ///Get the fingerprints, origin, extended key and public key bytes of the key.
+  Future<bridge.KeyMetadata> metadata() async {
+    try {
+      final res = await loaderApi.descriptorPublicMetadataStaticMethodApi(
+          publicKey: _descriptorPublicKey.toString());
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
   @override
   String toString() {
     return asString();
@@ -525,6 +827,29 @@ class DescriptorSecretKey {
     }
   }
 
+  ///Get the fingerprints, origin, extended keys and public key bytes of the key.
+  Future<bridge.KeyMetadata> metadata() async {
+    try {
+      final res = await loaderApi.descriptorSecretMetadataStaticMethodApi(
+          secret: _descriptorSecretKey);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// [DescriptorSecretKey] constructor from a single key, given as WIF or as 32 hex encoded bytes.
+  static Future<DescriptorSecretKey> fromSingle(
+      {required String secret, required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.descriptorSecretFromSingleStaticMethodApi(
+          secret: secret, network: network);
+      return DescriptorSecretKey._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
   @override
   String toString() {
     return asString();
@@ -574,127 +899,452 @@ class Mnemonic {
     }
   }
 
-  /// Returns [Mnemonic] as string
-  String asString() {
-    return _mnemonic.toString();
+  /// Returns [Mnemonic] as string
+  String asString() {
+    return _mnemonic.toString();
+  }
+
+  /// Create a new [Mnemonic] in the specified language from the given entropy.
+  /// Entropy must be a multiple of 32 bits (4 bytes) and 128-256 bits in length.
+  ///
+  /// [Mnemonic] constructor
+  static Future<Mnemonic> fromEntropy(typed_data.Uint8List entropy) async {
+    try {
+      final res = await loaderApi.generateSeedFromEntropyStaticMethodApi(
+          entropy: entropy);
+      return Mnemonic._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Parse a [Mnemonic] with given string
+  ///
+  /// [Mnemonic] constructor
+  static Future<Mnemonic> fromString(String mnemonic) async {
+    try {
+      final res = await loaderApi.generateSeedFromStringStaticMethodApi(
+          mnemonic: mnemonic);
+      return Mnemonic._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  @override
+  String toString() {
+    return asString();
+  }
+}
+
+///Collects the cosigners' signatures on a [PartiallySignedTransaction] spending from a multisig wallet.
+class MultisigSession {
+  final String _session;
+  MultisigSession._(this._session);
+
+  /// [MultisigSession] constructor
+  static Future<MultisigSession> create(
+      {required Descriptor descriptor,
+      Descriptor? changeDescriptor,
+      required PartiallySignedTransaction psbt}) async {
+    try {
+      final res = await loaderApi.createMultisigSessionStaticMethodApi(
+          descriptor: descriptor._descriptorInstance,
+          changeDescriptor: changeDescriptor?._descriptorInstance,
+          network: descriptor._network,
+          psbtStr: psbt.psbtBase64);
+      return MultisigSession._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Restore a session saved with [export].
+  static Future<MultisigSession> restore(String data) async {
+    try {
+      final res =
+          await loaderApi.restoreMultisigSessionStaticMethodApi(data: data);
+      return MultisigSession._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Save the session, to be restored with [restore].
+  Future<String> export() async {
+    try {
+      final res = await loaderApi.exportMultisigSessionStaticMethodApi(
+          sessionId: _session);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Merge a copy of the session PSBT signed by a cosigner.
+  Future<bridge.MultisigStatus> addPsbt(PartiallySignedTransaction psbt) async {
+    try {
+      final res = await loaderApi.multisigSessionAddPsbtStaticMethodApi(
+          sessionId: _session, psbtStr: psbt.psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Returns which cosigners have signed which inputs.
+  Future<bridge.MultisigStatus> status() async {
+    try {
+      final res = await loaderApi.multisigSessionStatusStaticMethodApi(
+          sessionId: _session);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Finalize the session PSBT, failing if an input doesn't have enough signatures yet.
+  Future<bridge.FinalizedPsbt> finalize() async {
+    try {
+      final res = await loaderApi.finalizeMultisigSessionStaticMethodApi(
+          sessionId: _session);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Release the session.
+  Future<void> dispose() async {
+    try {
+      await loaderApi.dropMultisigSessionStaticMethodApi(sessionId: _session);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+}
+
+///A Partially Signed Transaction
+class PartiallySignedTransaction {
+  final String psbtBase64;
+
+  PartiallySignedTransaction({required this.psbtBase64});
+
+  /// Combines this [PartiallySignedTransaction] with other PSBT as described by BIP 174.
+  ///
+  /// In accordance with BIP 174 this function is commutative i.e., `A.combine(B) == B.combine(A)`
+  Future<PartiallySignedTransaction> combine(
+      PartiallySignedTransaction other) async {
+    try {
+      final res = await loaderApi.combinePsbtStaticMethodApi(
+          psbtStr: psbtBase64, other: other.psbtBase64);
+      return PartiallySignedTransaction(psbtBase64: res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Return the transaction as bytes.
+  Future<Transaction> extractTx() async {
+    try {
+      final res = await loaderApi.extractTxStaticMethodApi(psbtStr: psbtBase64);
+      return Transaction._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Return feeAmount
+  Future<int?> feeAmount() async {
+    try {
+      final res =
+          await loaderApi.psbtFeeAmountStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Return Fee Rate
+  Future<FeeRate?> feeRate() async {
+    try {
+      final res =
+          await loaderApi.psbtFeeRateStaticMethodApi(psbtStr: psbtBase64);
+      if (res == null) return null;
+      return FeeRate._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Return txid as string
+  Future<String> serialize() async {
+    try {
+      final res =
+          await loaderApi.serializePsbtStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  Future<String> jsonSerialize() async {
+    try {
+      final res =
+          await loaderApi.jsonSerializeStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  @override
+  String toString() {
+    return psbtBase64;
+  }
+
+  /// Returns the [PartiallySignedTransaction] transaction id
+  Future<String> txId() async {
+    try {
+      final res = await loaderApi.psbtTxidStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// [PartiallySignedTransaction] constructor from binary, hex or base64 bytes, as read from a `.psbt` file.
+  static Future<PartiallySignedTransaction> fromBytes(
+      typed_data.Uint8List bytes) async {
+    try {
+      final res = await loaderApi.psbtFromBytesStaticMethodApi(bytes: bytes);
+      return PartiallySignedTransaction(psbtBase64: res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Serialize the PSBT as binary, the format used for `.psbt` files.
+  Future<typed_data.Uint8List> toBytes() async {
+    try {
+      final res =
+          await loaderApi.psbtToBytesStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Serialize the PSBT as hex.
+  Future<String> toHex() async {
+    try {
+      final res = await loaderApi.psbtToHexStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Encode the PSBT as animated QR parts (`ur:crypto-psbt`).
+  ///
+  /// Returns [count] parts, at most 10,000, from [startSeqNum] (1 based). A PSBT fitting in one fragment is returned as a single part.
+  Future<List<String>> toUr(
+      {required int maxFragmentLength,
+      int startSeqNum = 1,
+      required int count}) async {
+    try {
+      final res = await loaderApi.psbtToUrStaticMethodApi(
+          psbtStr: psbtBase64,
+          maxFragmentLength: maxFragmentLength,
+          startSeqNum: startSeqNum,
+          count: count);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// The version of the PSBT, 0 or 2 (BIP370).
+  Future<int> version() async {
+    try {
+      final res =
+          await loaderApi.psbtVersionStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Serialize the PSBT as version 0 or version 2 (BIP370).
+  Future<String> serializeVersion(int version) async {
+    try {
+      final res = await loaderApi.serializePsbtVersionStaticMethodApi(
+          psbtStr: psbtBase64, version: version);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Returns the inputs of the [PartiallySignedTransaction].
+  Future<List<bridge.PsbtInputInfo>> inputs() async {
+    try {
+      final res =
+          await loaderApi.psbtInputsStaticMethodApi(psbtStr: psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Returns the outputs of the [PartiallySignedTransaction], with their addresses on [network].
+  Future<List<bridge.PsbtOutputInfo>> outputs(bridge.Network network) async {
+    try {
+      final res = await loaderApi.psbtOutputsStaticMethodApi(
+          psbtStr: psbtBase64, network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
   }
 
-  /// Create a new [Mnemonic] in the specified language from the given entropy.
-  /// Entropy must be a multiple of 32 bits (4 bytes) and 128-256 bits in length.
+  /// Add an input spending [outpoint]. The [input] must carry its UTXO.
   ///
-  /// [Mnemonic] constructor
-  static Future<Mnemonic> fromEntropy(typed_data.Uint8List entropy) async {
-    try {
-      final res = await loaderApi.generateSeedFromEntropyStaticMethodApi(
-          entropy: entropy);
-      return Mnemonic._(res);
+  /// Edits that would invalidate existing signatures are refused, as for all the editing methods below.
+  Future<PartiallySignedTransaction> addInput(
+      {required bridge.OutPoint outpoint,
+      required Input input,
+      int? sequence}) async {
+    try {
+      final res = await loaderApi.psbtAddInputStaticMethodApi(
+          psbtStr: psbtBase64,
+          outpoint: outpoint,
+          psbtInput: input._input,
+          sequence: sequence);
+      return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  /// Parse a [Mnemonic] with given string
-  ///
-  /// [Mnemonic] constructor
-  static Future<Mnemonic> fromString(String mnemonic) async {
+  /// Add an output paying [amount] sats to [script].
+  Future<PartiallySignedTransaction> addOutput(
+      bridge.Script script, int amount) async {
     try {
-      final res = await loaderApi.generateSeedFromStringStaticMethodApi(
-          mnemonic: mnemonic);
-      return Mnemonic._(res);
+      final res = await loaderApi.psbtAddOutputStaticMethodApi(
+          psbtStr: psbtBase64, script: script, amount: amount);
+      return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  @override
-  String toString() {
-    return asString();
+  /// Remove an unsigned input.
+  Future<PartiallySignedTransaction> removeInput(int index) async {
+    try {
+      final res = await loaderApi.psbtRemoveInputStaticMethodApi(
+          psbtStr: psbtBase64, index: index);
+      return PartiallySignedTransaction(psbtBase64: res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
   }
-}
-
-///A Partially Signed Transaction
-class PartiallySignedTransaction {
-  final String psbtBase64;
-
-  PartiallySignedTransaction({required this.psbtBase64});
 
-  /// Combines this [PartiallySignedTransaction] with other PSBT as described by BIP 174.
-  ///
-  /// In accordance with BIP 174 this function is commutative i.e., `A.combine(B) == B.combine(A)`
-  Future<PartiallySignedTransaction> combine(
-      PartiallySignedTransaction other) async {
+  /// Remove an output that no signature commits to.
+  Future<PartiallySignedTransaction> removeOutput(int index) async {
     try {
-      final res = await loaderApi.combinePsbtStaticMethodApi(
-          psbtStr: psbtBase64, other: other.psbtBase64);
+      final res = await loaderApi.psbtRemoveOutputStaticMethodApi(
+          psbtStr: psbtBase64, index: index);
       return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  /// Return the transaction as bytes.
-  Future<Transaction> extractTx() async {
+  /// Set the lock time of the unsigned transaction.
+  Future<PartiallySignedTransaction> setLockTime(int lockTime) async {
     try {
-      final res = await loaderApi.extractTxStaticMethodApi(psbtStr: psbtBase64);
-      return Transaction._(res);
+      final res = await loaderApi.psbtSetLockTimeStaticMethodApi(
+          psbtStr: psbtBase64, lockTime: lockTime);
+      return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  /// Return feeAmount
-  Future<int?> feeAmount() async {
+  /// Set the sequence number of the input at [index].
+  Future<PartiallySignedTransaction> setSequence(
+      int index, int sequence) async {
     try {
-      final res =
-          await loaderApi.psbtFeeAmountStaticMethodApi(psbtStr: psbtBase64);
-      return res;
+      final res = await loaderApi.psbtSetSequenceStaticMethodApi(
+          psbtStr: psbtBase64, index: index, sequence: sequence);
+      return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  /// Return Fee Rate
-  Future<FeeRate?> feeRate() async {
+  /// Set a proprietary field of a PSBT map, or remove it when [value] is null.
+  Future<PartiallySignedTransaction> setProprietary(
+      {required bridge.PsbtMap map,
+      required typed_data.Uint8List prefix,
+      required int subtype,
+      required typed_data.Uint8List key,
+      typed_data.Uint8List? value}) async {
     try {
-      final res =
-          await loaderApi.psbtFeeRateStaticMethodApi(psbtStr: psbtBase64);
-      if (res == null) return null;
-      return FeeRate._(res);
+      final res = await loaderApi.psbtSetProprietaryStaticMethodApi(
+          psbtStr: psbtBase64,
+          map: map,
+          prefix: prefix,
+          subtype: subtype,
+          key: key,
+          value: value);
+      return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  /// Return txid as string
-  Future<String> serialize() async {
+  /// Set an unknown field of a PSBT map, or remove it when [value] is null.
+  Future<PartiallySignedTransaction> setUnknown(
+      {required bridge.PsbtMap map,
+      required int keyType,
+      required typed_data.Uint8List key,
+      typed_data.Uint8List? value}) async {
     try {
-      final res =
-          await loaderApi.serializePsbtStaticMethodApi(psbtStr: psbtBase64);
-      return res;
+      final res = await loaderApi.psbtSetUnknownStaticMethodApi(
+          psbtStr: psbtBase64,
+          map: map,
+          keyType: keyType,
+          key: key,
+          value: value);
+      return PartiallySignedTransaction(psbtBase64: res);
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
 
-  Future<String> jsonSerialize() async {
+  /// Combines PSBTs describing the same unsigned transaction, e.g. the partially signed copies returned by each cosigner.
+  ///
+  /// Reports which inputs gained which signatures.
+  static Future<bridge.CombinedPsbt> combineAll(
+      List<PartiallySignedTransaction> psbts) async {
     try {
-      final res =
-          await loaderApi.jsonSerializeStaticMethodApi(psbtStr: psbtBase64);
+      final res = await loaderApi.combinePsbtsStaticMethodApi(
+          psbtStrs: psbts.map((e) => e.psbtBase64).toList());
       return res;
     } on FfiException catch (e) {
       throw configException(e.message);
     }
   }
+}
 
-  @override
-  String toString() {
-    return psbtBase64;
-  }
-
-  /// Returns the [PartiallySignedTransaction] transaction id
-  Future<String> txId() async {
+///PayJoin (BIP78) helpers. The sender and receiver sides are methods of [Wallet].
+class Payjoin {
+  ///Parse a BIP21 URI with a `pj` endpoint.
+  static Future<bridge.PayjoinUri> parseUri(String uri) async {
     try {
-      final res = await loaderApi.psbtTxidStaticMethodApi(psbtStr: psbtBase64);
+      final res = await loaderApi.parsePayjoinUriStaticMethodApi(uri: uri);
       return res;
     } on FfiException catch (e) {
       throw configException(e.message);
@@ -727,6 +1377,41 @@ class Script extends bridge.Script {
   }
 }
 
+///Conversions between SLIP-132 extended public keys (`ypub`, `zpub`, `Ypub`, `Zpub` and their test network versions) and standard ones.
+class Slip132 {
+  ///Convert a `ypub`, `zpub`, `Ypub`, `Zpub`, `upub`, `vpub`, `Upub` or `Vpub` key to a standard xpub or tpub.
+  static Future<String> toStandard(String key) async {
+    try {
+      final res = await loaderApi.slip132ToStandardStaticMethodApi(key: key);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Convert an extended public key of any SLIP-132 version to [version], keeping its network.
+  static Future<String> fromStandard(
+      String key, bridge.Slip132Version version) async {
+    try {
+      final res = await loaderApi.standardToSlip132StaticMethodApi(
+          key: key, version: version);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Convert a SLIP-132 key, optionally with an origin such as `[fingerprint/84'/0'/0']zpub...`, and give the descriptor or multisig script type its version stands for.
+  static Future<bridge.Slip132Template> template(String key) async {
+    try {
+      final res = await loaderApi.slip132TemplateStaticMethodApi(key: key);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+}
+
 ///A bitcoin transaction.
 class Transaction {
   final String? _tx;
@@ -1079,6 +1764,66 @@ class TxBuilderResult {
   TxBuilderResult({required this.psbt, required this.txDetails});
 }
 
+///The value returned from calling the .finish() method on the [BumpFeeTxBuilder].
+class BumpFeeTxBuilderResult extends TxBuilderResult {
+  ///Absolute fee (sats) paid by the original transaction.
+  final int originalFee;
+
+  ///Fee rate (sat/vB) paid by the original transaction.
+  final double originalFeeRate;
+
+  ///Absolute fee (sats) paid by the replacement transaction.
+  final int fee;
+
+  ///Estimated fee rate (sat/vB) of the replacement transaction once it is signed.
+  final double feeRate;
+
+  BumpFeeTxBuilderResult(
+      {required super.psbt,
+      required super.txDetails,
+      required this.originalFee,
+      required this.originalFeeRate,
+      required this.fee,
+      required this.feeRate});
+}
+
+///Decodes the parts of a Uniform Resource (UR) scanned from animated QR codes.
+class UrDecoder {
+  final String _decoder;
+  UrDecoder._(this._decoder);
+
+  /// [UrDecoder] constructor
+  static Future<UrDecoder> create(bridge.Network network) async {
+    try {
+      final res =
+          await loaderApi.createUrDecoderStaticMethodApi(network: network);
+      return UrDecoder._(res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Receive a scanned part and return the decoding progress.
+  Future<bridge.UrDecodeProgress> receive(String part) async {
+    try {
+      final res = await loaderApi.urDecoderReceiveStaticMethodApi(
+          decoderId: _decoder, part: part);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  ///Release the decoder.
+  Future<void> dispose() async {
+    try {
+      await loaderApi.dropUrDecoderStaticMethodApi(decoderId: _decoder);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+}
+
 /// A Bitcoin wallet.
 ///
 /// The Wallet acts as a way of coherently interfacing with output descriptors and related transactions. Its main components are:
@@ -1216,12 +1961,16 @@ class Wallet {
   ///Sign a transaction with all the wallet’s signers, in the order specified by every signer’s SignerOrdering
   ///
   /// Note that it can’t be guaranteed that every signers will follow the options, but the “software signers” (WIF keys and xprv) defined in this library will.
+  ///
+  /// The PSBT is first checked for absurd fees, inconsistent UTXO data, unusual sighash types, dust outputs and network mismatches, against [safetyPolicy] or a default policy without fee limits, and signing is refused when an issue is found. Pass a policy with `refuseUnsafe` set to false to sign anyway.
   Future<PartiallySignedTransaction> sign(
       {required PartiallySignedTransaction psbt,
-      bridge.SignOptions? signOptions}) async {
+      bridge.SignOptions? signOptions,
+      bridge.PsbtSafetyPolicy? safetyPolicy}) async {
     try {
       final sbt = await loaderApi.signStaticMethodApi(
           signOptions: signOptions,
+          safetyPolicy: safetyPolicy,
           psbtStr: psbt.psbtBase64,
           walletId: _wallet);
       if (sbt == null) {
@@ -1261,6 +2010,222 @@ class Wallet {
       throw configException(e.message);
     }
   }
+
+  /// Returns the descriptors of both keychains as a single BIP389 multipath descriptor, e.g. `wpkh([fingerprint/84'/0'/0']xpub.../<0;1>/*)`, for exporting the wallet.
+  Future<Descriptor> getMultipathDescriptor() async {
+    try {
+      final res = await loaderApi.getMultipathDescriptorStaticMethodApi(
+          walletId: _wallet);
+      return Descriptor._(res.field0, res.field1);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Describe what a [PartiallySignedTransaction] does to the wallet before signing it.
+  Future<bridge.PsbtAnalysis> analyzePsbt(
+      PartiallySignedTransaction psbt) async {
+    try {
+      final res = await loaderApi.analyzePsbtStaticMethodApi(
+          walletId: _wallet, psbtStr: psbt.psbtBase64);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Check a [PartiallySignedTransaction] for absurd fees, missing UTXO data, unusual sighash types, dust outputs and network mismatches against [policy].
+  Future<bridge.PsbtSafetyReport> checkPsbt(PartiallySignedTransaction psbt,
+      {required bridge.PsbtSafetyPolicy policy}) async {
+    try {
+      final res = await loaderApi.checkPsbtStaticMethodApi(
+          walletId: _wallet, psbtStr: psbt.psbtBase64, policy: policy);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Finalize the inputs of a [PartiallySignedTransaction] the wallet can satisfy, e.g. once all cosigners' partial signatures have been combined.
+  Future<bridge.FinalizedPsbt> finalizePsbt(PartiallySignedTransaction psbt,
+      {bridge.SignOptions? signOptions}) async {
+    try {
+      final res = await loaderApi.finalizePsbtStaticMethodApi(
+          walletId: _wallet,
+          psbtStr: psbt.psbtBase64,
+          signOptions: signOptions);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Replace the unconfirmed transaction [txid] with one that spends the same inputs back to a fresh internal address at [feeRate] sat/vB, cancelling the original payment.
+  ///
+  /// The original transaction must signal RBF.
+  Future<TxBuilderResult> cancelTransaction(
+      {required String txid, required double feeRate}) async {
+    try {
+      final res = await loaderApi.cancelTransactionStaticMethodApi(
+          walletId: _wallet, txid: txid, feeRate: feeRate);
+      return TxBuilderResult(
+          psbt: PartiallySignedTransaction(psbtBase64: res.field0),
+          txDetails: res.field1);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Returns the amount that can be sent to [destination] at [feeRate] sat/vB from [utxos], or from all the confirmed UTXOs of the wallet when empty.
+  Future<bridge.MaxSendable> maxSendable(
+      {required bridge.Script destination,
+      required double feeRate,
+      List<bridge.OutPoint> utxos = const []}) async {
+    try {
+      final res = await loaderApi.maxSendableStaticMethodApi(
+          walletId: _wallet,
+          destination: destination,
+          feeRate: feeRate,
+          utxos: utxos);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Build a transaction sending as much as possible from [utxos], or from all the confirmed UTXOs of the wallet when empty, to [destination] at [feeRate] sat/vB.
+  ///
+  /// UTXOs worth less than the fee needed to spend them are left out and reported in [bridge.MaxSendable.excluded].
+  Future<bridge.BdkSweepResult> sweep(
+      {required bridge.Script destination,
+      required double feeRate,
+      List<bridge.OutPoint> utxos = const [],
+      bool enableRbf = false}) async {
+    try {
+      final res = await loaderApi.sweepStaticMethodApi(
+          walletId: _wallet,
+          destination: destination,
+          feeRate: feeRate,
+          utxos: utxos,
+          enableRbf: enableRbf);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Plan the consolidation of the wallet's UTXOs at [feeRate] sat/vB while fees are low.
+  ///
+  /// UTXOs are grouped by keychain and by their label in [labels], so that consolidating never links coins kept apart. Every group, split into chunks of at most [maxInputs], that saves fees compared to spending its UTXOs at [futureFeeRate] is merged into a PSBT paying a fresh internal address.
+  Future<List<bridge.ConsolidationPsbt>> planConsolidation(
+      {required double feeRate,
+      required double futureFeeRate,
+      List<bridge.UtxoLabel> labels = const [],
+      int? maxInputs}) async {
+    try {
+      final res = await loaderApi.planConsolidationStaticMethodApi(
+          walletId: _wallet,
+          feeRate: feeRate,
+          futureFeeRate: futureFeeRate,
+          labels: labels,
+          maxInputs: maxInputs);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Sign [psbt] and build the BIP78 request to post to the PayJoin receiver's endpoint.
+  ///
+  /// The request body doubles as the fallback transaction.
+  Future<bridge.PayjoinRequest> payjoinSenderRequest(
+      {required String uri,
+      required PartiallySignedTransaction psbt,
+      required bridge.PayjoinParams params,
+      bridge.SignOptions? signOptions}) async {
+    try {
+      final res = await loaderApi.payjoinSenderRequestStaticMethodApi(
+          walletId: _wallet,
+          uri: uri,
+          psbtStr: psbt.psbtBase64,
+          params: params,
+          signOptions: signOptions);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Check the receiver's PayJoin proposal [response] against [originalPsbt] and sign it.
+  Future<PartiallySignedTransaction> payjoinSenderProcessResponse(
+      {required String uri,
+      required PartiallySignedTransaction originalPsbt,
+      required bridge.PayjoinParams params,
+      required String response,
+      bridge.SignOptions? signOptions}) async {
+    try {
+      final res = await loaderApi.payjoinSenderProcessResponseStaticMethodApi(
+          walletId: _wallet,
+          uri: uri,
+          originalPsbt: originalPsbt.psbtBase64,
+          params: params,
+          response: response,
+          signOptions: signOptions);
+      return PartiallySignedTransaction(psbtBase64: res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Sign [psbt], post it to the PayJoin receiver's endpoint over HTTPS (or to an onion service) and return the checked and signed PayJoin transaction.
+  Future<PartiallySignedTransaction> payjoinSend(
+      {required String uri,
+      required PartiallySignedTransaction psbt,
+      required bridge.PayjoinParams params,
+      bridge.SignOptions? signOptions}) async {
+    try {
+      final res = await loaderApi.payjoinSendStaticMethodApi(
+          walletId: _wallet,
+          uri: uri,
+          psbtStr: psbt.psbtBase64,
+          params: params,
+          signOptions: signOptions);
+      return PartiallySignedTransaction(psbtBase64: res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Contribute one of the wallet's inputs to the sender's [originalPsbt] and return the PayJoin proposal.
+  ///
+  /// [query] is the query string the sender's request was posted with.
+  Future<PartiallySignedTransaction> payjoinReceive(
+      {required PartiallySignedTransaction originalPsbt,
+      required String query}) async {
+    try {
+      final res = await loaderApi.payjoinReceiveStaticMethodApi(
+          walletId: _wallet,
+          originalPsbt: originalPsbt.psbtBase64,
+          query: query);
+      return PartiallySignedTransaction(psbtBase64: res);
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
+
+  /// Read the wallets of a Coldcard, Specter, Sparrow, Electrum or BlueWallet export, with the descriptors to create each [Wallet] with.
+  ///
+  /// Coldcard generic JSON exports hold one wallet per script type.
+  static Future<List<bridge.ImportedWallet>> importWallets(
+      {required String data, required bridge.Network network}) async {
+    try {
+      final res = await loaderApi.importWalletStaticMethodApi(
+          data: data, network: network);
+      return res;
+    } on FfiException catch (e) {
+      throw configException(e.message);
+    }
+  }
 }
 
 extension Tx on bridge.TransactionDetails {
@@ -25,26 +25,76 @@ use crate::blockchain::EsploraConfig;
 use crate::blockchain::RpcConfig;
 use crate::blockchain::RpcSyncParams;
 use crate::blockchain::UserPass;
+use crate::bsms::BsmsDescriptorRecord;
+use crate::bsms::BsmsEncryption;
+use crate::bsms::BsmsKeyRecord;
+use crate::descriptor::CompiledPolicy;
+use crate::descriptor::DerivedAddress;
+use crate::descriptor::DescriptorInfo;
+use crate::descriptor::DescriptorKeyInfo;
+use crate::descriptor::DescriptorType;
+use crate::descriptor::MultisigDescriptors;
+use crate::descriptor::MultisigScriptType;
+use crate::descriptor::PolicyContext;
+use crate::descriptor::PolicyKey;
+use crate::descriptor::Slip132Template;
+use crate::import::ImportedCosigner;
+use crate::import::ImportedWallet;
+use crate::import::WalletSource;
+use crate::key::KeyMetadata;
+use crate::key::Slip132Version;
+use crate::key::WildcardType;
+use crate::multisig::CosignerStatus;
+use crate::multisig::MultisigStatus;
+use crate::payjoin::PayjoinParams;
+use crate::payjoin::PayjoinRequest;
+use crate::payjoin::PayjoinUri;
+use crate::psbt::AddedSignature;
+use crate::psbt::CombinedInput;
+use crate::psbt::CombinedPsbt;
+use crate::psbt::FinalizeInputResult;
+use crate::psbt::FinalizedPsbt;
+use crate::psbt::KeyOrigin;
+use crate::psbt::PsbtInputInfo;
+use crate::psbt::PsbtIssue;
+use crate::psbt::PsbtMap;
+use crate::psbt::PsbtOutputInfo;
+use crate::psbt::PsbtSafetyPolicy;
+use crate::psbt::PsbtSafetyReport;
+use crate::psbt::TapScriptSig;
 use crate::types::AddressIndex;
 use crate::types::AddressInfo;
 use crate::types::Balance;
+use crate::types::BdkBumpFeeTxBuilderResult;
+use crate::types::BdkSweepResult;
 use crate::types::BdkTxBuilderResult;
 use crate::types::BlockTime;
 use crate::types::ChangeSpendPolicy;
+use crate::types::ConsolidationPsbt;
 use crate::types::DescNetwork;
+use crate::types::ExcludedUtxo;
 use crate::types::KeychainKind;
+use crate::types::MaxSendable;
 use crate::types::Network;
 use crate::types::OutPoint;
 use crate::types::Payload;
+use crate::types::PsbtAnalysis;
+use crate::types::PsbtInputAnalysis;
+use crate::types::PsbtOutputAnalysis;
 use crate::types::PsbtSigHashType;
+use crate::types::PsbtWarning;
 use crate::types::RbfValue;
 use crate::types::Script;
 use crate::types::ScriptAmount;
+use crate::types::ShrinkOutput;
 use crate::types::TransactionDetails;
 use crate::types::TxIn;
 use crate::types::TxOut;
+use crate::types::UtxoLabel;
 use crate::types::WitnessVersion;
 use crate::types::WordCount;
+use crate::ur::UrDecodeProgress;
+use crate::ur::UrPayload;
 use crate::wallet::DatabaseConfig;
 use crate::wallet::LocalUtxo;
 use crate::wallet::SignOptions;
@@ -354,1484 +404,3886 @@ fn wire_serialize_psbt__static_method__Api_impl(
         },
     )
 }
-fn wire_psbt_txid__static_method__Api_impl(
+fn wire_psbt_from_bytes__static_method__Api_impl(
     port_: MessagePort,
-    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    bytes: impl Wire2Api<Vec<u8>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "psbt_txid__static_method__Api",
+            debug_name: "psbt_from_bytes__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_psbt_str = psbt_str.wire2api();
-            move |task_callback| Api::psbt_txid(api_psbt_str)
+            let api_bytes = bytes.wire2api();
+            move |task_callback| Api::psbt_from_bytes(api_bytes)
         },
     )
 }
-fn wire_extract_tx__static_method__Api_impl(
+fn wire_psbt_to_bytes__static_method__Api_impl(
     port_: MessagePort,
     psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "extract_tx__static_method__Api",
+            debug_name: "psbt_to_bytes__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_psbt_str = psbt_str.wire2api();
-            move |task_callback| Api::extract_tx(api_psbt_str)
+            move |task_callback| Api::psbt_to_bytes(api_psbt_str)
         },
     )
 }
-fn wire_psbt_fee_rate__static_method__Api_impl(
+fn wire_psbt_to_hex__static_method__Api_impl(
     port_: MessagePort,
     psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "psbt_fee_rate__static_method__Api",
+            debug_name: "psbt_to_hex__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_psbt_str = psbt_str.wire2api();
-            move |task_callback| Ok(Api::psbt_fee_rate(api_psbt_str))
+            move |task_callback| Api::psbt_to_hex(api_psbt_str)
         },
     )
 }
-fn wire_psbt_fee_amount__static_method__Api_impl(
+fn wire_psbt_version__static_method__Api_impl(
     port_: MessagePort,
     psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "psbt_fee_amount__static_method__Api",
+            debug_name: "psbt_version__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_psbt_str = psbt_str.wire2api();
-            move |task_callback| Ok(Api::psbt_fee_amount(api_psbt_str))
+            move |task_callback| Api::psbt_version(api_psbt_str)
         },
     )
 }
-fn wire_combine_psbt__static_method__Api_impl(
+fn wire_serialize_psbt_version__static_method__Api_impl(
     port_: MessagePort,
     psbt_str: impl Wire2Api<String> + UnwindSafe,
-    other: impl Wire2Api<String> + UnwindSafe,
+    version: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "combine_psbt__static_method__Api",
+            debug_name: "serialize_psbt_version__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_psbt_str = psbt_str.wire2api();
-            let api_other = other.wire2api();
-            move |task_callback| Api::combine_psbt(api_psbt_str, api_other)
+            let api_version = version.wire2api();
+            move |task_callback| Api::serialize_psbt_version(api_psbt_str, api_version)
         },
     )
 }
-fn wire_json_serialize__static_method__Api_impl(
+fn wire_psbt_txid__static_method__Api_impl(
     port_: MessagePort,
     psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "json_serialize__static_method__Api",
+            debug_name: "psbt_txid__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_psbt_str = psbt_str.wire2api();
-            move |task_callback| Api::json_serialize(api_psbt_str)
+            move |task_callback| Api::psbt_txid(api_psbt_str)
         },
     )
 }
-fn wire_tx_builder_finish__static_method__Api_impl(
+fn wire_extract_tx__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    recipients: impl Wire2Api<Vec<ScriptAmount>> + UnwindSafe,
-    utxos: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
-    foreign_utxo: impl Wire2Api<Option<(OutPoint, String, usize)>> + UnwindSafe,
-    unspendable: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
-    change_policy: impl Wire2Api<ChangeSpendPolicy> + UnwindSafe,
-    manually_selected_only: impl Wire2Api<bool> + UnwindSafe,
-    fee_rate: impl Wire2Api<Option<f32>> + UnwindSafe,
-    fee_absolute: impl Wire2Api<Option<u64>> + UnwindSafe,
-    drain_wallet: impl Wire2Api<bool> + UnwindSafe,
-    drain_to: impl Wire2Api<Option<Script>> + UnwindSafe,
-    rbf: impl Wire2Api<Option<RbfValue>> + UnwindSafe,
-    data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "tx_builder_finish__static_method__Api",
+            debug_name: "extract_tx__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_recipients = recipients.wire2api();
-            let api_utxos = utxos.wire2api();
-            let api_foreign_utxo = foreign_utxo.wire2api();
-            let api_unspendable = unspendable.wire2api();
-            let api_change_policy = change_policy.wire2api();
-            let api_manually_selected_only = manually_selected_only.wire2api();
-            let api_fee_rate = fee_rate.wire2api();
-            let api_fee_absolute = fee_absolute.wire2api();
-            let api_drain_wallet = drain_wallet.wire2api();
-            let api_drain_to = drain_to.wire2api();
-            let api_rbf = rbf.wire2api();
-            let api_data = data.wire2api();
-            move |task_callback| {
-                Api::tx_builder_finish(
-                    api_wallet_id,
-                    api_recipients,
-                    api_utxos,
-                    api_foreign_utxo,
-                    api_unspendable,
-                    api_change_policy,
-                    api_manually_selected_only,
-                    api_fee_rate,
-                    api_fee_absolute,
-                    api_drain_wallet,
-                    api_drain_to,
-                    api_rbf,
-                    api_data,
-                )
-            }
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Api::extract_tx(api_psbt_str)
         },
     )
 }
-fn wire_bump_fee_tx_builder_finish__static_method__Api_impl(
+fn wire_psbt_fee_rate__static_method__Api_impl(
     port_: MessagePort,
-    txid: impl Wire2Api<String> + UnwindSafe,
-    fee_rate: impl Wire2Api<f32> + UnwindSafe,
-    allow_shrinking: impl Wire2Api<Option<String>> + UnwindSafe,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    enable_rbf: impl Wire2Api<bool> + UnwindSafe,
-    n_sequence: impl Wire2Api<Option<u32>> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "bump_fee_tx_builder_finish__static_method__Api",
+            debug_name: "psbt_fee_rate__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_txid = txid.wire2api();
-            let api_fee_rate = fee_rate.wire2api();
-            let api_allow_shrinking = allow_shrinking.wire2api();
-            let api_wallet_id = wallet_id.wire2api();
-            let api_enable_rbf = enable_rbf.wire2api();
-            let api_n_sequence = n_sequence.wire2api();
-            move |task_callback| {
-                Api::bump_fee_tx_builder_finish(
-                    api_txid,
-                    api_fee_rate,
-                    api_allow_shrinking,
-                    api_wallet_id,
-                    api_enable_rbf,
-                    api_n_sequence,
-                )
-            }
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Ok(Api::psbt_fee_rate(api_psbt_str))
         },
     )
 }
-fn wire_create_descriptor__static_method__Api_impl(
+fn wire_psbt_fee_amount__static_method__Api_impl(
     port_: MessagePort,
-    descriptor: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_descriptor__static_method__Api",
+            debug_name: "psbt_fee_amount__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_descriptor = descriptor.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| Api::create_descriptor(api_descriptor, api_network)
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Ok(Api::psbt_fee_amount(api_psbt_str))
         },
     )
 }
-fn wire_new_bip44_descriptor__static_method__Api_impl(
+fn wire_combine_psbt__static_method__Api_impl(
     port_: MessagePort,
-    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
-    secret_key: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    other: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_bip44_descriptor__static_method__Api",
+            debug_name: "combine_psbt__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_key_chain_kind = key_chain_kind.wire2api();
-            let api_secret_key = secret_key.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| {
-                Api::new_bip44_descriptor(api_key_chain_kind, api_secret_key, api_network)
-            }
+            let api_psbt_str = psbt_str.wire2api();
+            let api_other = other.wire2api();
+            move |task_callback| Api::combine_psbt(api_psbt_str, api_other)
         },
     )
 }
-fn wire_new_bip44_public__static_method__Api_impl(
+fn wire_combine_psbts__static_method__Api_impl(
     port_: MessagePort,
-    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
-    public_key: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
-    fingerprint: impl Wire2Api<String> + UnwindSafe,
+    psbt_strs: impl Wire2Api<Vec<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_bip44_public__static_method__Api",
+            debug_name: "combine_psbts__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_key_chain_kind = key_chain_kind.wire2api();
-            let api_public_key = public_key.wire2api();
-            let api_network = network.wire2api();
-            let api_fingerprint = fingerprint.wire2api();
-            move |task_callback| {
-                Api::new_bip44_public(
-                    api_key_chain_kind,
-                    api_public_key,
-                    api_network,
-                    api_fingerprint,
-                )
-            }
+            let api_psbt_strs = psbt_strs.wire2api();
+            move |task_callback| Api::combine_psbts(api_psbt_strs)
         },
     )
 }
-fn wire_new_bip49_descriptor__static_method__Api_impl(
+fn wire_psbt_add_input__static_method__Api_impl(
     port_: MessagePort,
-    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
-    secret_key: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    outpoint: impl Wire2Api<OutPoint> + UnwindSafe,
+    psbt_input: impl Wire2Api<String> + UnwindSafe,
+    sequence: impl Wire2Api<Option<u32>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_bip49_descriptor__static_method__Api",
+            debug_name: "psbt_add_input__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_key_chain_kind = key_chain_kind.wire2api();
-            let api_secret_key = secret_key.wire2api();
-            let api_network = network.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_outpoint = outpoint.wire2api();
+            let api_psbt_input = psbt_input.wire2api();
+            let api_sequence = sequence.wire2api();
             move |task_callback| {
-                Api::new_bip49_descriptor(api_key_chain_kind, api_secret_key, api_network)
+                Api::psbt_add_input(api_psbt_str, api_outpoint, api_psbt_input, api_sequence)
             }
         },
     )
 }
-fn wire_new_bip49_public__static_method__Api_impl(
+fn wire_psbt_remove_input__static_method__Api_impl(
     port_: MessagePort,
-    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
-    public_key: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
-    fingerprint: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_bip49_public__static_method__Api",
+            debug_name: "psbt_remove_input__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_key_chain_kind = key_chain_kind.wire2api();
-            let api_public_key = public_key.wire2api();
-            let api_network = network.wire2api();
-            let api_fingerprint = fingerprint.wire2api();
-            move |task_callback| {
-                Api::new_bip49_public(
-                    api_key_chain_kind,
-                    api_public_key,
-                    api_network,
-                    api_fingerprint,
-                )
-            }
+            let api_psbt_str = psbt_str.wire2api();
+            let api_index = index.wire2api();
+            move |task_callback| Api::psbt_remove_input(api_psbt_str, api_index)
         },
     )
 }
-fn wire_new_bip84_descriptor__static_method__Api_impl(
+fn wire_psbt_add_output__static_method__Api_impl(
     port_: MessagePort,
-    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
-    secret_key: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    script: impl Wire2Api<Script> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_bip84_descriptor__static_method__Api",
+            debug_name: "psbt_add_output__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_key_chain_kind = key_chain_kind.wire2api();
-            let api_secret_key = secret_key.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| {
-                Api::new_bip84_descriptor(api_key_chain_kind, api_secret_key, api_network)
-            }
+            let api_psbt_str = psbt_str.wire2api();
+            let api_script = script.wire2api();
+            let api_amount = amount.wire2api();
+            move |task_callback| Api::psbt_add_output(api_psbt_str, api_script, api_amount)
         },
     )
 }
-fn wire_new_bip84_public__static_method__Api_impl(
+fn wire_psbt_remove_output__static_method__Api_impl(
     port_: MessagePort,
-    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
-    public_key: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
-    fingerprint: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_bip84_public__static_method__Api",
+            debug_name: "psbt_remove_output__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_key_chain_kind = key_chain_kind.wire2api();
-            let api_public_key = public_key.wire2api();
-            let api_network = network.wire2api();
-            let api_fingerprint = fingerprint.wire2api();
-            move |task_callback| {
-                Api::new_bip84_public(
-                    api_key_chain_kind,
-                    api_public_key,
-                    api_network,
-                    api_fingerprint,
-                )
-            }
+            let api_psbt_str = psbt_str.wire2api();
+            let api_index = index.wire2api();
+            move |task_callback| Api::psbt_remove_output(api_psbt_str, api_index)
         },
     )
 }
-fn wire_as_string_private__static_method__Api_impl(
+fn wire_psbt_set_sequence__static_method__Api_impl(
     port_: MessagePort,
-    descriptor: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
+    sequence: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "as_string_private__static_method__Api",
+            debug_name: "psbt_set_sequence__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_descriptor = descriptor.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| Ok(Api::as_string_private(api_descriptor, api_network))
+            let api_psbt_str = psbt_str.wire2api();
+            let api_index = index.wire2api();
+            let api_sequence = sequence.wire2api();
+            move |task_callback| Api::psbt_set_sequence(api_psbt_str, api_index, api_sequence)
         },
     )
 }
-fn wire_as_string__static_method__Api_impl(
+fn wire_psbt_set_lock_time__static_method__Api_impl(
     port_: MessagePort,
-    descriptor: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    lock_time: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "as_string__static_method__Api",
+            debug_name: "psbt_set_lock_time__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_descriptor = descriptor.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| Ok(Api::as_string(api_descriptor, api_network))
+            let api_psbt_str = psbt_str.wire2api();
+            let api_lock_time = lock_time.wire2api();
+            move |task_callback| Api::psbt_set_lock_time(api_psbt_str, api_lock_time)
         },
     )
 }
-fn wire_max_satisfaction_weight__static_method__Api_impl(
+fn wire_psbt_set_unknown__static_method__Api_impl(
     port_: MessagePort,
-    descriptor: impl Wire2Api<String> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    map: impl Wire2Api<PsbtMap> + UnwindSafe,
+    key_type: impl Wire2Api<u8> + UnwindSafe,
+    key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    value: impl Wire2Api<Option<Vec<u8>>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "max_satisfaction_weight__static_method__Api",
+            debug_name: "psbt_set_unknown__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_descriptor = descriptor.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| Ok(Api::max_satisfaction_weight(api_descriptor, api_network))
+            let api_psbt_str = psbt_str.wire2api();
+            let api_map = map.wire2api();
+            let api_key_type = key_type.wire2api();
+            let api_key = key.wire2api();
+            let api_value = value.wire2api();
+            move |task_callback| {
+                Api::psbt_set_unknown(api_psbt_str, api_map, api_key_type, api_key, api_value)
+            }
         },
     )
 }
-fn wire_create_descriptor_secret__static_method__Api_impl(
+fn wire_psbt_set_proprietary__static_method__Api_impl(
     port_: MessagePort,
-    network: impl Wire2Api<Network> + UnwindSafe,
-    mnemonic: impl Wire2Api<String> + UnwindSafe,
-    password: impl Wire2Api<Option<String>> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    map: impl Wire2Api<PsbtMap> + UnwindSafe,
+    prefix: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    subtype: impl Wire2Api<u8> + UnwindSafe,
+    key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    value: impl Wire2Api<Option<Vec<u8>>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_descriptor_secret__static_method__Api",
+            debug_name: "psbt_set_proprietary__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_network = network.wire2api();
-            let api_mnemonic = mnemonic.wire2api();
-            let api_password = password.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_map = map.wire2api();
+            let api_prefix = prefix.wire2api();
+            let api_subtype = subtype.wire2api();
+            let api_key = key.wire2api();
+            let api_value = value.wire2api();
             move |task_callback| {
-                Api::create_descriptor_secret(api_network, api_mnemonic, api_password)
+                Api::psbt_set_proprietary(
+                    api_psbt_str,
+                    api_map,
+                    api_prefix,
+                    api_subtype,
+                    api_key,
+                    api_value,
+                )
             }
         },
     )
 }
-fn wire_descriptor_secret_from_string__static_method__Api_impl(
+fn wire_finalize_psbt_with_descriptor__static_method__Api_impl(
     port_: MessagePort,
-    secret: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    change_descriptor: impl Wire2Api<Option<String>> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    lookahead: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "descriptor_secret_from_string__static_method__Api",
+            debug_name: "finalize_psbt_with_descriptor__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_secret = secret.wire2api();
-            move |task_callback| Api::descriptor_secret_from_string(api_secret)
+            let api_psbt_str = psbt_str.wire2api();
+            let api_descriptor = descriptor.wire2api();
+            let api_change_descriptor = change_descriptor.wire2api();
+            let api_network = network.wire2api();
+            let api_lookahead = lookahead.wire2api();
+            move |task_callback| {
+                Api::finalize_psbt_with_descriptor(
+                    api_psbt_str,
+                    api_descriptor,
+                    api_change_descriptor,
+                    api_network,
+                    api_lookahead,
+                )
+            }
         },
     )
 }
-fn wire_extend_descriptor_secret__static_method__Api_impl(
+fn wire_psbt_inputs__static_method__Api_impl(
     port_: MessagePort,
-    secret: impl Wire2Api<String> + UnwindSafe,
-    path: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "extend_descriptor_secret__static_method__Api",
+            debug_name: "psbt_inputs__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_secret = secret.wire2api();
-            let api_path = path.wire2api();
-            move |task_callback| Ok(Api::extend_descriptor_secret(api_secret, api_path))
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Api::psbt_inputs(api_psbt_str)
         },
     )
 }
-fn wire_derive_descriptor_secret__static_method__Api_impl(
+fn wire_psbt_outputs__static_method__Api_impl(
     port_: MessagePort,
-    secret: impl Wire2Api<String> + UnwindSafe,
-    path: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "derive_descriptor_secret__static_method__Api",
+            debug_name: "psbt_outputs__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_secret = secret.wire2api();
-            let api_path = path.wire2api();
-            move |task_callback| Ok(Api::derive_descriptor_secret(api_secret, api_path))
+            let api_psbt_str = psbt_str.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::psbt_outputs(api_psbt_str, api_network)
         },
     )
 }
-fn wire_as_secret_bytes__static_method__Api_impl(
+fn wire_json_serialize__static_method__Api_impl(
     port_: MessagePort,
-    secret: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "as_secret_bytes__static_method__Api",
+            debug_name: "json_serialize__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_secret = secret.wire2api();
-            move |task_callback| Api::as_secret_bytes(api_secret)
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Api::json_serialize(api_psbt_str)
         },
     )
 }
-fn wire_as_public__static_method__Api_impl(
+fn wire_tx_builder_finish__static_method__Api_impl(
     port_: MessagePort,
-    secret: impl Wire2Api<String> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    recipients: impl Wire2Api<Vec<ScriptAmount>> + UnwindSafe,
+    utxos: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
+    foreign_utxo: impl Wire2Api<Option<(OutPoint, String, usize)>> + UnwindSafe,
+    unspendable: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
+    change_policy: impl Wire2Api<ChangeSpendPolicy> + UnwindSafe,
+    manually_selected_only: impl Wire2Api<bool> + UnwindSafe,
+    fee_rate: impl Wire2Api<Option<f32>> + UnwindSafe,
+    fee_absolute: impl Wire2Api<Option<u64>> + UnwindSafe,
+    drain_wallet: impl Wire2Api<bool> + UnwindSafe,
+    drain_to: impl Wire2Api<Option<Script>> + UnwindSafe,
+    rbf: impl Wire2Api<Option<RbfValue>> + UnwindSafe,
+    data: impl Wire2Api<Vec<u8>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "as_public__static_method__Api",
+            debug_name: "tx_builder_finish__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_secret = secret.wire2api();
-            move |task_callback| Api::as_public(api_secret)
+            let api_wallet_id = wallet_id.wire2api();
+            let api_recipients = recipients.wire2api();
+            let api_utxos = utxos.wire2api();
+            let api_foreign_utxo = foreign_utxo.wire2api();
+            let api_unspendable = unspendable.wire2api();
+            let api_change_policy = change_policy.wire2api();
+            let api_manually_selected_only = manually_selected_only.wire2api();
+            let api_fee_rate = fee_rate.wire2api();
+            let api_fee_absolute = fee_absolute.wire2api();
+            let api_drain_wallet = drain_wallet.wire2api();
+            let api_drain_to = drain_to.wire2api();
+            let api_rbf = rbf.wire2api();
+            let api_data = data.wire2api();
+            move |task_callback| {
+                Api::tx_builder_finish(
+                    api_wallet_id,
+                    api_recipients,
+                    api_utxos,
+                    api_foreign_utxo,
+                    api_unspendable,
+                    api_change_policy,
+                    api_manually_selected_only,
+                    api_fee_rate,
+                    api_fee_absolute,
+                    api_drain_wallet,
+                    api_drain_to,
+                    api_rbf,
+                    api_data,
+                )
+            }
         },
     )
 }
-fn wire_create_derivation_path__static_method__Api_impl(
+fn wire_bump_fee_tx_builder_finish__static_method__Api_impl(
     port_: MessagePort,
-    path: impl Wire2Api<String> + UnwindSafe,
+    txid: impl Wire2Api<String> + UnwindSafe,
+    fee_rate: impl Wire2Api<Option<f32>> + UnwindSafe,
+    fee_absolute: impl Wire2Api<Option<u64>> + UnwindSafe,
+    allow_shrinking: impl Wire2Api<Option<ShrinkOutput>> + UnwindSafe,
+    utxos: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
+    unspendable: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
+    manually_selected_only: impl Wire2Api<bool> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    enable_rbf: impl Wire2Api<bool> + UnwindSafe,
+    n_sequence: impl Wire2Api<Option<u32>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_derivation_path__static_method__Api",
+            debug_name: "bump_fee_tx_builder_finish__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_path = path.wire2api();
-            move |task_callback| Api::create_derivation_path(api_path)
+            let api_txid = txid.wire2api();
+            let api_fee_rate = fee_rate.wire2api();
+            let api_fee_absolute = fee_absolute.wire2api();
+            let api_allow_shrinking = allow_shrinking.wire2api();
+            let api_utxos = utxos.wire2api();
+            let api_unspendable = unspendable.wire2api();
+            let api_manually_selected_only = manually_selected_only.wire2api();
+            let api_wallet_id = wallet_id.wire2api();
+            let api_enable_rbf = enable_rbf.wire2api();
+            let api_n_sequence = n_sequence.wire2api();
+            move |task_callback| {
+                Api::bump_fee_tx_builder_finish(
+                    api_txid,
+                    api_fee_rate,
+                    api_fee_absolute,
+                    api_allow_shrinking,
+                    api_utxos,
+                    api_unspendable,
+                    api_manually_selected_only,
+                    api_wallet_id,
+                    api_enable_rbf,
+                    api_n_sequence,
+                )
+            }
         },
     )
 }
-fn wire_descriptor_public_from_string__static_method__Api_impl(
+fn wire_cancel_transaction__static_method__Api_impl(
     port_: MessagePort,
-    public_key: impl Wire2Api<String> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    txid: impl Wire2Api<String> + UnwindSafe,
+    fee_rate: impl Wire2Api<f32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "descriptor_public_from_string__static_method__Api",
+            debug_name: "cancel_transaction__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_public_key = public_key.wire2api();
-            move |task_callback| Api::descriptor_public_from_string(api_public_key)
+            let api_wallet_id = wallet_id.wire2api();
+            let api_txid = txid.wire2api();
+            let api_fee_rate = fee_rate.wire2api();
+            move |task_callback| Api::cancel_transaction(api_wallet_id, api_txid, api_fee_rate)
         },
     )
 }
-fn wire_create_descriptor_public__static_method__Api_impl(
+fn wire_parse_payjoin_uri__static_method__Api_impl(
     port_: MessagePort,
-    xpub: impl Wire2Api<Option<String>> + UnwindSafe,
-    path: impl Wire2Api<String> + UnwindSafe,
-    derive: impl Wire2Api<bool> + UnwindSafe,
+    uri: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_descriptor_public__static_method__Api",
+            debug_name: "parse_payjoin_uri__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_xpub = xpub.wire2api();
-            let api_path = path.wire2api();
-            let api_derive = derive.wire2api();
-            move |task_callback| Api::create_descriptor_public(api_xpub, api_path, api_derive)
+            let api_uri = uri.wire2api();
+            move |task_callback| Api::parse_payjoin_uri(api_uri)
         },
     )
 }
-fn wire_create_script__static_method__Api_impl(
+fn wire_payjoin_sender_request__static_method__Api_impl(
     port_: MessagePort,
-    raw_output_script: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    uri: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    params: impl Wire2Api<PayjoinParams> + UnwindSafe,
+    sign_options: impl Wire2Api<Option<SignOptions>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_script__static_method__Api",
+            debug_name: "payjoin_sender_request__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_raw_output_script = raw_output_script.wire2api();
-            move |task_callback| Api::create_script(api_raw_output_script)
+            let api_wallet_id = wallet_id.wire2api();
+            let api_uri = uri.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_params = params.wire2api();
+            let api_sign_options = sign_options.wire2api();
+            move |task_callback| {
+                Api::payjoin_sender_request(
+                    api_wallet_id,
+                    api_uri,
+                    api_psbt_str,
+                    api_params,
+                    api_sign_options,
+                )
+            }
         },
     )
 }
-fn wire_create_address__static_method__Api_impl(
+fn wire_payjoin_send__static_method__Api_impl(
     port_: MessagePort,
-    address: impl Wire2Api<String> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    uri: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    params: impl Wire2Api<PayjoinParams> + UnwindSafe,
+    sign_options: impl Wire2Api<Option<SignOptions>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_address__static_method__Api",
+            debug_name: "payjoin_send__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_address = address.wire2api();
-            move |task_callback| Api::create_address(api_address)
+            let api_wallet_id = wallet_id.wire2api();
+            let api_uri = uri.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_params = params.wire2api();
+            let api_sign_options = sign_options.wire2api();
+            move |task_callback| {
+                Api::payjoin_send(
+                    api_wallet_id,
+                    api_uri,
+                    api_psbt_str,
+                    api_params,
+                    api_sign_options,
+                )
+            }
         },
     )
 }
-fn wire_address_from_script__static_method__Api_impl(
+fn wire_payjoin_sender_process_response__static_method__Api_impl(
     port_: MessagePort,
-    script: impl Wire2Api<Script> + UnwindSafe,
-    network: impl Wire2Api<Network> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    uri: impl Wire2Api<String> + UnwindSafe,
+    original_psbt: impl Wire2Api<String> + UnwindSafe,
+    params: impl Wire2Api<PayjoinParams> + UnwindSafe,
+    response: impl Wire2Api<String> + UnwindSafe,
+    sign_options: impl Wire2Api<Option<SignOptions>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "address_from_script__static_method__Api",
+            debug_name: "payjoin_sender_process_response__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_script = script.wire2api();
-            let api_network = network.wire2api();
-            move |task_callback| Api::address_from_script(api_script, api_network)
+            let api_wallet_id = wallet_id.wire2api();
+            let api_uri = uri.wire2api();
+            let api_original_psbt = original_psbt.wire2api();
+            let api_params = params.wire2api();
+            let api_response = response.wire2api();
+            let api_sign_options = sign_options.wire2api();
+            move |task_callback| {
+                Api::payjoin_sender_process_response(
+                    api_wallet_id,
+                    api_uri,
+                    api_original_psbt,
+                    api_params,
+                    api_response,
+                    api_sign_options,
+                )
+            }
         },
     )
 }
-fn wire_address_to_script_pubkey__static_method__Api_impl(
+fn wire_payjoin_receive__static_method__Api_impl(
     port_: MessagePort,
-    address: impl Wire2Api<String> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    original_psbt: impl Wire2Api<String> + UnwindSafe,
+    query: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "address_to_script_pubkey__static_method__Api",
+            debug_name: "payjoin_receive__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_address = address.wire2api();
-            move |task_callback| Api::address_to_script_pubkey(api_address)
+            let api_wallet_id = wallet_id.wire2api();
+            let api_original_psbt = original_psbt.wire2api();
+            let api_query = query.wire2api();
+            move |task_callback| Api::payjoin_receive(api_wallet_id, api_original_psbt, api_query)
         },
     )
 }
-fn wire_payload__static_method__Api_impl(
+fn wire_psbt_to_ur__static_method__Api_impl(
     port_: MessagePort,
-    address: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    max_fragment_length: impl Wire2Api<u32> + UnwindSafe,
+    start_seq_num: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "payload__static_method__Api",
+            debug_name: "psbt_to_ur__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_address = address.wire2api();
-            move |task_callback| Api::payload(api_address)
+            let api_psbt_str = psbt_str.wire2api();
+            let api_max_fragment_length = max_fragment_length.wire2api();
+            let api_start_seq_num = start_seq_num.wire2api();
+            let api_count = count.wire2api();
+            move |task_callback| {
+                Api::psbt_to_ur(
+                    api_psbt_str,
+                    api_max_fragment_length,
+                    api_start_seq_num,
+                    api_count,
+                )
+            }
         },
     )
 }
-fn wire_address_network__static_method__Api_impl(
+fn wire_descriptor_to_ur__static_method__Api_impl(
     port_: MessagePort,
-    address: impl Wire2Api<String> + UnwindSafe,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "address_network__static_method__Api",
+            debug_name: "descriptor_to_ur__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_address = address.wire2api();
-            move |task_callback| Api::address_network(api_address)
+            let api_descriptor = descriptor.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::descriptor_to_ur(api_descriptor, api_network)
         },
     )
 }
-fn wire_create_wallet__static_method__Api_impl(
+fn wire_descriptors_to_ur_account__static_method__Api_impl(
     port_: MessagePort,
-    descriptor: impl Wire2Api<String> + UnwindSafe,
-    change_descriptor: impl Wire2Api<Option<String>> + UnwindSafe,
+    descriptors: impl Wire2Api<Vec<String>> + UnwindSafe,
     network: impl Wire2Api<Network> + UnwindSafe,
-    database_config: impl Wire2Api<DatabaseConfig> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_wallet__static_method__Api",
+            debug_name: "descriptors_to_ur_account__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_descriptor = descriptor.wire2api();
-            let api_change_descriptor = change_descriptor.wire2api();
+            let api_descriptors = descriptors.wire2api();
             let api_network = network.wire2api();
-            let api_database_config = database_config.wire2api();
-            move |task_callback| {
-                Api::create_wallet(
-                    api_descriptor,
-                    api_change_descriptor,
-                    api_network,
-                    api_database_config,
-                )
-            }
+            move |task_callback| Api::descriptors_to_ur_account(api_descriptors, api_network)
         },
     )
 }
-fn wire_get_address__static_method__Api_impl(
+fn wire_create_ur_decoder__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    address_index: impl Wire2Api<AddressIndex> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_address__static_method__Api",
+            debug_name: "create_ur_decoder__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_address_index = address_index.wire2api();
-            move |task_callback| Api::get_address(api_wallet_id, api_address_index)
+            let api_network = network.wire2api();
+            move |task_callback| Ok(Api::create_ur_decoder(api_network))
         },
     )
 }
-fn wire_is_mine__static_method__Api_impl(
+fn wire_ur_decoder_receive__static_method__Api_impl(
     port_: MessagePort,
-    script: impl Wire2Api<Script> + UnwindSafe,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    decoder_id: impl Wire2Api<String> + UnwindSafe,
+    part: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "is_mine__static_method__Api",
+            debug_name: "ur_decoder_receive__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_script = script.wire2api();
-            let api_wallet_id = wallet_id.wire2api();
-            move |task_callback| Api::is_mine(api_script, api_wallet_id)
+            let api_decoder_id = decoder_id.wire2api();
+            let api_part = part.wire2api();
+            move |task_callback| Api::ur_decoder_receive(api_decoder_id, api_part)
         },
     )
 }
-fn wire_get_internal_address__static_method__Api_impl(
+fn wire_drop_ur_decoder__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    address_index: impl Wire2Api<AddressIndex> + UnwindSafe,
+    decoder_id: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_internal_address__static_method__Api",
+            debug_name: "drop_ur_decoder__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_address_index = address_index.wire2api();
-            move |task_callback| Api::get_internal_address(api_wallet_id, api_address_index)
+            let api_decoder_id = decoder_id.wire2api();
+            move |task_callback| Ok(Api::drop_ur_decoder(api_decoder_id))
         },
     )
 }
-fn wire_sync_wallet__static_method__Api_impl(
+fn wire_create_multisig_session__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    blockchain_id: impl Wire2Api<String> + UnwindSafe,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    change_descriptor: impl Wire2Api<Option<String>> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_wallet__static_method__Api",
+            debug_name: "create_multisig_session__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_blockchain_id = blockchain_id.wire2api();
-            move |task_callback| Ok(Api::sync_wallet(api_wallet_id, api_blockchain_id))
+            let api_descriptor = descriptor.wire2api();
+            let api_change_descriptor = change_descriptor.wire2api();
+            let api_network = network.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| {
+                Api::create_multisig_session(
+                    api_descriptor,
+                    api_change_descriptor,
+                    api_network,
+                    api_psbt_str,
+                )
+            }
         },
     )
 }
-fn wire_get_balance__static_method__Api_impl(
+fn wire_restore_multisig_session__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    data: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_balance__static_method__Api",
+            debug_name: "restore_multisig_session__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            move |task_callback| Api::get_balance(api_wallet_id)
+            let api_data = data.wire2api();
+            move |task_callback| Api::restore_multisig_session(api_data)
         },
     )
 }
-fn wire_list_unspent_outputs__static_method__Api_impl(
+fn wire_export_multisig_session__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    session_id: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "list_unspent_outputs__static_method__Api",
+            debug_name: "export_multisig_session__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            move |task_callback| Api::list_unspent_outputs(api_wallet_id)
+            let api_session_id = session_id.wire2api();
+            move |task_callback| Api::export_multisig_session(api_session_id)
         },
     )
 }
-fn wire_get_transactions__static_method__Api_impl(
+fn wire_multisig_session_add_psbt__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    include_raw: impl Wire2Api<bool> + UnwindSafe,
+    session_id: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_transactions__static_method__Api",
+            debug_name: "multisig_session_add_psbt__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_include_raw = include_raw.wire2api();
-            move |task_callback| Api::get_transactions(api_wallet_id, api_include_raw)
+            let api_session_id = session_id.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Api::multisig_session_add_psbt(api_session_id, api_psbt_str)
         },
     )
 }
-fn wire_sign__static_method__Api_impl(
+fn wire_multisig_session_status__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    psbt_str: impl Wire2Api<String> + UnwindSafe,
-    sign_options: impl Wire2Api<Option<SignOptions>> + UnwindSafe,
+    session_id: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sign__static_method__Api",
+            debug_name: "multisig_session_status__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_psbt_str = psbt_str.wire2api();
-            let api_sign_options = sign_options.wire2api();
-            move |task_callback| Ok(Api::sign(api_wallet_id, api_psbt_str, api_sign_options))
+            let api_session_id = session_id.wire2api();
+            move |task_callback| Api::multisig_session_status(api_session_id)
         },
     )
 }
-fn wire_wallet_network__static_method__Api_impl(
+fn wire_finalize_multisig_session__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    session_id: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "wallet_network__static_method__Api",
+            debug_name: "finalize_multisig_session__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            move |task_callback| Ok(Api::wallet_network(api_wallet_id))
+            let api_session_id = session_id.wire2api();
+            move |task_callback| Api::finalize_multisig_session(api_session_id)
         },
     )
 }
-fn wire_list_unspent__static_method__Api_impl(
+fn wire_drop_multisig_session__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    session_id: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "list_unspent__static_method__Api",
+            debug_name: "drop_multisig_session__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            move |task_callback| Api::list_unspent(api_wallet_id)
+            let api_session_id = session_id.wire2api();
+            move |task_callback| Ok(Api::drop_multisig_session(api_session_id))
         },
     )
 }
-fn wire_get_psbt_input__static_method__Api_impl(
+fn wire_bsms_new_token__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    utxo: impl Wire2Api<LocalUtxo> + UnwindSafe,
-    only_witness_utxo: impl Wire2Api<bool> + UnwindSafe,
-    psbt_sighash_type: impl Wire2Api<Option<PsbtSigHashType>> + UnwindSafe,
+    encryption: impl Wire2Api<BsmsEncryption> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_psbt_input__static_method__Api",
+            debug_name: "bsms_new_token__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_utxo = utxo.wire2api();
-            let api_only_witness_utxo = only_witness_utxo.wire2api();
-            let api_psbt_sighash_type = psbt_sighash_type.wire2api();
-            move |task_callback| {
-                Ok(Api::get_psbt_input(
-                    api_wallet_id,
-                    api_utxo,
-                    api_only_witness_utxo,
-                    api_psbt_sighash_type,
-                ))
-            }
+            let api_encryption = encryption.wire2api();
+            move |task_callback| Ok(Api::bsms_new_token(api_encryption))
         },
     )
 }
-fn wire_get_descriptor_for_keychain__static_method__Api_impl(
+fn wire_bsms_key_record__static_method__Api_impl(
     port_: MessagePort,
-    wallet_id: impl Wire2Api<String> + UnwindSafe,
-    keychain: impl Wire2Api<KeychainKind> + UnwindSafe,
+    secret_key: impl Wire2Api<String> + UnwindSafe,
+    token: impl Wire2Api<String> + UnwindSafe,
+    description: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_descriptor_for_keychain__static_method__Api",
+            debug_name: "bsms_key_record__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_wallet_id = wallet_id.wire2api();
-            let api_keychain = keychain.wire2api();
-            move |task_callback| Api::get_descriptor_for_keychain(api_wallet_id, api_keychain)
+            let api_secret_key = secret_key.wire2api();
+            let api_token = token.wire2api();
+            let api_description = description.wire2api();
+            move |task_callback| Api::bsms_key_record(api_secret_key, api_token, api_description)
         },
     )
 }
-fn wire_generate_seed_from_word_count__static_method__Api_impl(
+fn wire_bsms_verify_key_record__static_method__Api_impl(
     port_: MessagePort,
-    word_count: impl Wire2Api<WordCount> + UnwindSafe,
+    record: impl Wire2Api<String> + UnwindSafe,
+    token: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "generate_seed_from_word_count__static_method__Api",
+            debug_name: "bsms_verify_key_record__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_word_count = word_count.wire2api();
-            move |task_callback| Ok(Api::generate_seed_from_word_count(api_word_count))
+            let api_record = record.wire2api();
+            let api_token = token.wire2api();
+            move |task_callback| Api::bsms_verify_key_record(api_record, api_token)
         },
     )
 }
-fn wire_generate_seed_from_string__static_method__Api_impl(
+fn wire_bsms_descriptor_record__static_method__Api_impl(
     port_: MessagePort,
-    mnemonic: impl Wire2Api<String> + UnwindSafe,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    script_type: impl Wire2Api<MultisigScriptType> + UnwindSafe,
+    token: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "generate_seed_from_string__static_method__Api",
+            debug_name: "bsms_descriptor_record__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_mnemonic = mnemonic.wire2api();
-            move |task_callback| Api::generate_seed_from_string(api_mnemonic)
+            let api_threshold = threshold.wire2api();
+            let api_keys = keys.wire2api();
+            let api_script_type = script_type.wire2api();
+            let api_token = token.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::bsms_descriptor_record(
+                    api_threshold,
+                    api_keys,
+                    api_script_type,
+                    api_token,
+                    api_network,
+                )
+            }
         },
     )
 }
-fn wire_generate_seed_from_entropy__static_method__Api_impl(
+fn wire_bsms_verify_descriptor_record__static_method__Api_impl(
     port_: MessagePort,
-    entropy: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    record: impl Wire2Api<String> + UnwindSafe,
+    token: impl Wire2Api<String> + UnwindSafe,
+    secret_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "generate_seed_from_entropy__static_method__Api",
+            debug_name: "bsms_verify_descriptor_record__static_method__Api",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_entropy = entropy.wire2api();
-            move |task_callback| Api::generate_seed_from_entropy(api_entropy)
+            let api_record = record.wire2api();
+            let api_token = token.wire2api();
+            let api_secret_key = secret_key.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::bsms_verify_descriptor_record(
+                    api_record,
+                    api_token,
+                    api_secret_key,
+                    api_network,
+                )
+            }
         },
     )
 }
-// Section: wrapper structs
-
-// Section: static checks
-
-// Section: allocate functions
-
-// Section: related functions
-
-// Section: impl Wire2Api
-
-pub trait Wire2Api<T> {
-    fn wire2api(self) -> T;
-}
-
-impl<T, S> Wire2Api<Option<T>> for *mut S
-where
-    *mut S: Wire2Api<T>,
-{
-    fn wire2api(self) -> Option<T> {
-        (!self.is_null()).then(|| self.wire2api())
-    }
+fn wire_create_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::create_descriptor(api_descriptor, api_network)
+        },
+    )
 }
-
-impl Wire2Api<bool> for bool {
-    fn wire2api(self) -> bool {
-        self
-    }
+fn wire_new_bip44_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
+    secret_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_bip44_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key_chain_kind = key_chain_kind.wire2api();
+            let api_secret_key = secret_key.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::new_bip44_descriptor(api_key_chain_kind, api_secret_key, api_network)
+            }
+        },
+    )
 }
-
-impl Wire2Api<ChangeSpendPolicy> for i32 {
-    fn wire2api(self) -> ChangeSpendPolicy {
-        match self {
-            0 => ChangeSpendPolicy::ChangeAllowed,
-            1 => ChangeSpendPolicy::OnlyChange,
-            2 => ChangeSpendPolicy::ChangeForbidden,
-            _ => unreachable!("Invalid variant for ChangeSpendPolicy: {}", self),
-        }
-    }
+fn wire_new_bip44_public__static_method__Api_impl(
+    port_: MessagePort,
+    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
+    public_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    fingerprint: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_bip44_public__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key_chain_kind = key_chain_kind.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_network = network.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            move |task_callback| {
+                Api::new_bip44_public(
+                    api_key_chain_kind,
+                    api_public_key,
+                    api_network,
+                    api_fingerprint,
+                )
+            }
+        },
+    )
 }
-
-impl Wire2Api<f32> for f32 {
-    fn wire2api(self) -> f32 {
-        self
-    }
+fn wire_new_bip49_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
+    secret_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_bip49_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key_chain_kind = key_chain_kind.wire2api();
+            let api_secret_key = secret_key.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::new_bip49_descriptor(api_key_chain_kind, api_secret_key, api_network)
+            }
+        },
+    )
+}
+fn wire_new_bip49_public__static_method__Api_impl(
+    port_: MessagePort,
+    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
+    public_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    fingerprint: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_bip49_public__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key_chain_kind = key_chain_kind.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_network = network.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            move |task_callback| {
+                Api::new_bip49_public(
+                    api_key_chain_kind,
+                    api_public_key,
+                    api_network,
+                    api_fingerprint,
+                )
+            }
+        },
+    )
+}
+fn wire_new_bip84_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
+    secret_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_bip84_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key_chain_kind = key_chain_kind.wire2api();
+            let api_secret_key = secret_key.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::new_bip84_descriptor(api_key_chain_kind, api_secret_key, api_network)
+            }
+        },
+    )
+}
+fn wire_new_bip84_public__static_method__Api_impl(
+    port_: MessagePort,
+    key_chain_kind: impl Wire2Api<KeychainKind> + UnwindSafe,
+    public_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    fingerprint: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_bip84_public__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key_chain_kind = key_chain_kind.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_network = network.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            move |task_callback| {
+                Api::new_bip84_public(
+                    api_key_chain_kind,
+                    api_public_key,
+                    api_network,
+                    api_fingerprint,
+                )
+            }
+        },
+    )
+}
+fn wire_new_single_key_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    script_type: impl Wire2Api<DescriptorType> + UnwindSafe,
+    secret_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_single_key_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_script_type = script_type.wire2api();
+            let api_secret_key = secret_key.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::new_single_key_descriptor(api_script_type, api_secret_key, api_network)
+            }
+        },
+    )
+}
+fn wire_new_single_key_public__static_method__Api_impl(
+    port_: MessagePort,
+    script_type: impl Wire2Api<DescriptorType> + UnwindSafe,
+    public_key: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_single_key_public__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_script_type = script_type.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::new_single_key_public(api_script_type, api_public_key, api_network)
+            }
+        },
+    )
+}
+fn wire_as_string_private__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "as_string_private__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Ok(Api::as_string_private(api_descriptor, api_network))
+        },
+    )
+}
+fn wire_as_string__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "as_string__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Ok(Api::as_string(api_descriptor, api_network))
+        },
+    )
+}
+fn wire_max_satisfaction_weight__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "max_satisfaction_weight__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Ok(Api::max_satisfaction_weight(api_descriptor, api_network))
+        },
+    )
+}
+fn wire_new_multisig_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    public_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    secret_key: impl Wire2Api<Option<String>> + UnwindSafe,
+    script_type: impl Wire2Api<MultisigScriptType> + UnwindSafe,
+    sorted: impl Wire2Api<bool> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_multisig_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_threshold = threshold.wire2api();
+            let api_public_keys = public_keys.wire2api();
+            let api_secret_key = secret_key.wire2api();
+            let api_script_type = script_type.wire2api();
+            let api_sorted = sorted.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                Api::new_multisig_descriptor(
+                    api_threshold,
+                    api_public_keys,
+                    api_secret_key,
+                    api_script_type,
+                    api_sorted,
+                    api_network,
+                )
+            }
+        },
+    )
+}
+fn wire_compile_policy__static_method__Api_impl(
+    port_: MessagePort,
+    policy: impl Wire2Api<String> + UnwindSafe,
+    keys: impl Wire2Api<Vec<PolicyKey>> + UnwindSafe,
+    context: impl Wire2Api<PolicyContext> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "compile_policy__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_policy = policy.wire2api();
+            let api_keys = keys.wire2api();
+            let api_context = context.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::compile_policy(api_policy, api_keys, api_context, api_network)
+        },
+    )
+}
+fn wire_derive_addresses__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    start: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "derive_addresses__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            let api_network = network.wire2api();
+            let api_start = start.wire2api();
+            let api_count = count.wire2api();
+            move |task_callback| {
+                Api::derive_addresses(api_descriptor, api_network, api_start, api_count)
+            }
+        },
+    )
+}
+fn wire_descriptor_info__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "descriptor_info__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            move |task_callback| Api::descriptor_info(api_descriptor)
+        },
+    )
+}
+fn wire_import_wallet__static_method__Api_impl(
+    port_: MessagePort,
+    data: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "import_wallet__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_data = data.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::import_wallet(api_data, api_network)
+        },
+    )
+}
+fn wire_create_descriptor_secret__static_method__Api_impl(
+    port_: MessagePort,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    mnemonic: impl Wire2Api<String> + UnwindSafe,
+    password: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_descriptor_secret__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_network = network.wire2api();
+            let api_mnemonic = mnemonic.wire2api();
+            let api_password = password.wire2api();
+            move |task_callback| {
+                Api::create_descriptor_secret(api_network, api_mnemonic, api_password)
+            }
+        },
+    )
+}
+fn wire_descriptor_secret_from_string__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "descriptor_secret_from_string__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            move |task_callback| Api::descriptor_secret_from_string(api_secret)
+        },
+    )
+}
+fn wire_descriptor_secret_from_single__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "descriptor_secret_from_single__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::descriptor_secret_from_single(api_secret, api_network)
+        },
+    )
+}
+fn wire_extend_descriptor_secret__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+    path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "extend_descriptor_secret__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            let api_path = path.wire2api();
+            move |task_callback| Api::extend_descriptor_secret(api_secret, api_path)
+        },
+    )
+}
+fn wire_derive_descriptor_secret__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+    path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "derive_descriptor_secret__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            let api_path = path.wire2api();
+            move |task_callback| Api::derive_descriptor_secret(api_secret, api_path)
+        },
+    )
+}
+fn wire_as_secret_bytes__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "as_secret_bytes__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            move |task_callback| Api::as_secret_bytes(api_secret)
+        },
+    )
+}
+fn wire_as_public__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "as_public__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            move |task_callback| Api::as_public(api_secret)
+        },
+    )
+}
+fn wire_create_derivation_path__static_method__Api_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_derivation_path__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            move |task_callback| Api::create_derivation_path(api_path)
+        },
+    )
+}
+fn wire_descriptor_secret_metadata__static_method__Api_impl(
+    port_: MessagePort,
+    secret: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "descriptor_secret_metadata__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_secret = secret.wire2api();
+            move |task_callback| Api::descriptor_secret_metadata(api_secret)
+        },
+    )
+}
+fn wire_descriptor_public_metadata__static_method__Api_impl(
+    port_: MessagePort,
+    public_key: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "descriptor_public_metadata__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_public_key = public_key.wire2api();
+            move |task_callback| Api::descriptor_public_metadata(api_public_key)
+        },
+    )
+}
+fn wire_descriptor_public_from_string__static_method__Api_impl(
+    port_: MessagePort,
+    public_key: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "descriptor_public_from_string__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_public_key = public_key.wire2api();
+            move |task_callback| Api::descriptor_public_from_string(api_public_key)
+        },
+    )
+}
+fn wire_create_descriptor_public__static_method__Api_impl(
+    port_: MessagePort,
+    xpub: impl Wire2Api<Option<String>> + UnwindSafe,
+    path: impl Wire2Api<String> + UnwindSafe,
+    derive: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_descriptor_public__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_xpub = xpub.wire2api();
+            let api_path = path.wire2api();
+            let api_derive = derive.wire2api();
+            move |task_callback| Api::create_descriptor_public(api_xpub, api_path, api_derive)
+        },
+    )
+}
+fn wire_slip132_to_standard__static_method__Api_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "slip132_to_standard__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key = key.wire2api();
+            move |task_callback| Api::slip132_to_standard(api_key)
+        },
+    )
+}
+fn wire_standard_to_slip132__static_method__Api_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<String> + UnwindSafe,
+    version: impl Wire2Api<Slip132Version> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "standard_to_slip132__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key = key.wire2api();
+            let api_version = version.wire2api();
+            move |task_callback| Api::standard_to_slip132(api_key, api_version)
+        },
+    )
+}
+fn wire_slip132_template__static_method__Api_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "slip132_template__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key = key.wire2api();
+            move |task_callback| Api::slip132_template(api_key)
+        },
+    )
+}
+fn wire_create_script__static_method__Api_impl(
+    port_: MessagePort,
+    raw_output_script: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_script__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_raw_output_script = raw_output_script.wire2api();
+            move |task_callback| Api::create_script(api_raw_output_script)
+        },
+    )
+}
+fn wire_create_address__static_method__Api_impl(
+    port_: MessagePort,
+    address: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_address__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_address = address.wire2api();
+            move |task_callback| Api::create_address(api_address)
+        },
+    )
+}
+fn wire_address_from_script__static_method__Api_impl(
+    port_: MessagePort,
+    script: impl Wire2Api<Script> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "address_from_script__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_script = script.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| Api::address_from_script(api_script, api_network)
+        },
+    )
+}
+fn wire_address_to_script_pubkey__static_method__Api_impl(
+    port_: MessagePort,
+    address: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "address_to_script_pubkey__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_address = address.wire2api();
+            move |task_callback| Api::address_to_script_pubkey(api_address)
+        },
+    )
+}
+fn wire_payload__static_method__Api_impl(
+    port_: MessagePort,
+    address: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "payload__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_address = address.wire2api();
+            move |task_callback| Api::payload(api_address)
+        },
+    )
+}
+fn wire_address_network__static_method__Api_impl(
+    port_: MessagePort,
+    address: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "address_network__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_address = address.wire2api();
+            move |task_callback| Api::address_network(api_address)
+        },
+    )
+}
+fn wire_create_wallet__static_method__Api_impl(
+    port_: MessagePort,
+    descriptor: impl Wire2Api<String> + UnwindSafe,
+    change_descriptor: impl Wire2Api<Option<String>> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
+    database_config: impl Wire2Api<DatabaseConfig> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_wallet__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_descriptor = descriptor.wire2api();
+            let api_change_descriptor = change_descriptor.wire2api();
+            let api_network = network.wire2api();
+            let api_database_config = database_config.wire2api();
+            move |task_callback| {
+                Api::create_wallet(
+                    api_descriptor,
+                    api_change_descriptor,
+                    api_network,
+                    api_database_config,
+                )
+            }
+        },
+    )
+}
+fn wire_get_address__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    address_index: impl Wire2Api<AddressIndex> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_address__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_address_index = address_index.wire2api();
+            move |task_callback| Api::get_address(api_wallet_id, api_address_index)
+        },
+    )
+}
+fn wire_is_mine__static_method__Api_impl(
+    port_: MessagePort,
+    script: impl Wire2Api<Script> + UnwindSafe,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "is_mine__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_script = script.wire2api();
+            let api_wallet_id = wallet_id.wire2api();
+            move |task_callback| Api::is_mine(api_script, api_wallet_id)
+        },
+    )
+}
+fn wire_get_internal_address__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    address_index: impl Wire2Api<AddressIndex> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_internal_address__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_address_index = address_index.wire2api();
+            move |task_callback| Api::get_internal_address(api_wallet_id, api_address_index)
+        },
+    )
+}
+fn wire_sync_wallet__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    blockchain_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sync_wallet__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_blockchain_id = blockchain_id.wire2api();
+            move |task_callback| Ok(Api::sync_wallet(api_wallet_id, api_blockchain_id))
+        },
+    )
+}
+fn wire_get_balance__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_balance__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            move |task_callback| Api::get_balance(api_wallet_id)
+        },
+    )
+}
+fn wire_list_unspent_outputs__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_unspent_outputs__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            move |task_callback| Api::list_unspent_outputs(api_wallet_id)
+        },
+    )
+}
+fn wire_get_transactions__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    include_raw: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_transactions__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_include_raw = include_raw.wire2api();
+            move |task_callback| Api::get_transactions(api_wallet_id, api_include_raw)
+        },
+    )
+}
+fn wire_sign__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    sign_options: impl Wire2Api<Option<SignOptions>> + UnwindSafe,
+    safety_policy: impl Wire2Api<Option<PsbtSafetyPolicy>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sign__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_sign_options = sign_options.wire2api();
+            let api_safety_policy = safety_policy.wire2api();
+            move |task_callback| {
+                Api::sign(
+                    api_wallet_id,
+                    api_psbt_str,
+                    api_sign_options,
+                    api_safety_policy,
+                )
+            }
+        },
+    )
+}
+fn wire_check_psbt__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    policy: impl Wire2Api<PsbtSafetyPolicy> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "check_psbt__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_policy = policy.wire2api();
+            move |task_callback| Api::check_psbt(api_wallet_id, api_psbt_str, api_policy)
+        },
+    )
+}
+fn wire_max_sendable__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    destination: impl Wire2Api<Script> + UnwindSafe,
+    fee_rate: impl Wire2Api<f32> + UnwindSafe,
+    utxos: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "max_sendable__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_destination = destination.wire2api();
+            let api_fee_rate = fee_rate.wire2api();
+            let api_utxos = utxos.wire2api();
+            move |task_callback| {
+                Api::max_sendable(api_wallet_id, api_destination, api_fee_rate, api_utxos)
+            }
+        },
+    )
+}
+fn wire_sweep__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    destination: impl Wire2Api<Script> + UnwindSafe,
+    fee_rate: impl Wire2Api<f32> + UnwindSafe,
+    utxos: impl Wire2Api<Vec<OutPoint>> + UnwindSafe,
+    enable_rbf: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sweep__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_destination = destination.wire2api();
+            let api_fee_rate = fee_rate.wire2api();
+            let api_utxos = utxos.wire2api();
+            let api_enable_rbf = enable_rbf.wire2api();
+            move |task_callback| {
+                Api::sweep(
+                    api_wallet_id,
+                    api_destination,
+                    api_fee_rate,
+                    api_utxos,
+                    api_enable_rbf,
+                )
+            }
+        },
+    )
+}
+fn wire_plan_consolidation__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    fee_rate: impl Wire2Api<f32> + UnwindSafe,
+    future_fee_rate: impl Wire2Api<f32> + UnwindSafe,
+    labels: impl Wire2Api<Vec<UtxoLabel>> + UnwindSafe,
+    max_inputs: impl Wire2Api<Option<u32>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "plan_consolidation__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_fee_rate = fee_rate.wire2api();
+            let api_future_fee_rate = future_fee_rate.wire2api();
+            let api_labels = labels.wire2api();
+            let api_max_inputs = max_inputs.wire2api();
+            move |task_callback| {
+                Api::plan_consolidation(
+                    api_wallet_id,
+                    api_fee_rate,
+                    api_future_fee_rate,
+                    api_labels,
+                    api_max_inputs,
+                )
+            }
+        },
+    )
+}
+fn wire_finalize_psbt__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+    sign_options: impl Wire2Api<Option<SignOptions>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "finalize_psbt__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            let api_sign_options = sign_options.wire2api();
+            move |task_callback| Api::finalize_psbt(api_wallet_id, api_psbt_str, api_sign_options)
+        },
+    )
+}
+fn wire_analyze_psbt__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    psbt_str: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "analyze_psbt__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_psbt_str = psbt_str.wire2api();
+            move |task_callback| Api::analyze_psbt(api_wallet_id, api_psbt_str)
+        },
+    )
+}
+fn wire_wallet_network__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "wallet_network__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            move |task_callback| Ok(Api::wallet_network(api_wallet_id))
+        },
+    )
+}
+fn wire_list_unspent__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_unspent__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            move |task_callback| Api::list_unspent(api_wallet_id)
+        },
+    )
+}
+fn wire_get_psbt_input__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    utxo: impl Wire2Api<LocalUtxo> + UnwindSafe,
+    only_witness_utxo: impl Wire2Api<bool> + UnwindSafe,
+    psbt_sighash_type: impl Wire2Api<Option<PsbtSigHashType>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_psbt_input__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_utxo = utxo.wire2api();
+            let api_only_witness_utxo = only_witness_utxo.wire2api();
+            let api_psbt_sighash_type = psbt_sighash_type.wire2api();
+            move |task_callback| {
+                Ok(Api::get_psbt_input(
+                    api_wallet_id,
+                    api_utxo,
+                    api_only_witness_utxo,
+                    api_psbt_sighash_type,
+                ))
+            }
+        },
+    )
+}
+fn wire_get_descriptor_for_keychain__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+    keychain: impl Wire2Api<KeychainKind> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_descriptor_for_keychain__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            let api_keychain = keychain.wire2api();
+            move |task_callback| Api::get_descriptor_for_keychain(api_wallet_id, api_keychain)
+        },
+    )
+}
+fn wire_get_multipath_descriptor__static_method__Api_impl(
+    port_: MessagePort,
+    wallet_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_multipath_descriptor__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_wallet_id = wallet_id.wire2api();
+            move |task_callback| Api::get_multipath_descriptor(api_wallet_id)
+        },
+    )
+}
+fn wire_generate_seed_from_word_count__static_method__Api_impl(
+    port_: MessagePort,
+    word_count: impl Wire2Api<WordCount> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "generate_seed_from_word_count__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_word_count = word_count.wire2api();
+            move |task_callback| Ok(Api::generate_seed_from_word_count(api_word_count))
+        },
+    )
+}
+fn wire_generate_seed_from_string__static_method__Api_impl(
+    port_: MessagePort,
+    mnemonic: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "generate_seed_from_string__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_mnemonic = mnemonic.wire2api();
+            move |task_callback| Api::generate_seed_from_string(api_mnemonic)
+        },
+    )
+}
+fn wire_generate_seed_from_entropy__static_method__Api_impl(
+    port_: MessagePort,
+    entropy: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "generate_seed_from_entropy__static_method__Api",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_entropy = entropy.wire2api();
+            move |task_callback| Api::generate_seed_from_entropy(api_entropy)
+        },
+    )
+}
+// Section: wrapper structs
+
+// Section: static checks
+
+// Section: allocate functions
+
+// Section: related functions
+
+// Section: impl Wire2Api
+
+pub trait Wire2Api<T> {
+    fn wire2api(self) -> T;
+}
+
+impl<T, S> Wire2Api<Option<T>> for *mut S
+where
+    *mut S: Wire2Api<T>,
+{
+    fn wire2api(self) -> Option<T> {
+        (!self.is_null()).then(|| self.wire2api())
+    }
+}
+
+impl Wire2Api<bool> for bool {
+    fn wire2api(self) -> bool {
+        self
+    }
+}
+
+impl Wire2Api<BsmsEncryption> for i32 {
+    fn wire2api(self) -> BsmsEncryption {
+        match self {
+            0 => BsmsEncryption::NoEncryption,
+            1 => BsmsEncryption::Standard,
+            2 => BsmsEncryption::Extended,
+            _ => unreachable!("Invalid variant for BsmsEncryption: {}", self),
+        }
+    }
+}
+impl Wire2Api<ChangeSpendPolicy> for i32 {
+    fn wire2api(self) -> ChangeSpendPolicy {
+        match self {
+            0 => ChangeSpendPolicy::ChangeAllowed,
+            1 => ChangeSpendPolicy::OnlyChange,
+            2 => ChangeSpendPolicy::ChangeForbidden,
+            _ => unreachable!("Invalid variant for ChangeSpendPolicy: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<DescriptorType> for i32 {
+    fn wire2api(self) -> DescriptorType {
+        match self {
+            0 => DescriptorType::Bare,
+            1 => DescriptorType::Pkh,
+            2 => DescriptorType::Wpkh,
+            3 => DescriptorType::ShWpkh,
+            4 => DescriptorType::Sh,
+            5 => DescriptorType::Wsh,
+            6 => DescriptorType::ShWsh,
+            7 => DescriptorType::Tr,
+            _ => unreachable!("Invalid variant for DescriptorType: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<f32> for f32 {
+    fn wire2api(self) -> f32 {
+        self
+    }
 }
 impl Wire2Api<i32> for i32 {
     fn wire2api(self) -> i32 {
         self
     }
-}
-impl Wire2Api<KeychainKind> for i32 {
-    fn wire2api(self) -> KeychainKind {
-        match self {
-            0 => KeychainKind::External,
-            1 => KeychainKind::Internal,
-            _ => unreachable!("Invalid variant for KeychainKind: {}", self),
-        }
+}
+impl Wire2Api<KeychainKind> for i32 {
+    fn wire2api(self) -> KeychainKind {
+        match self {
+            0 => KeychainKind::External,
+            1 => KeychainKind::Internal,
+            _ => unreachable!("Invalid variant for KeychainKind: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<MultisigScriptType> for i32 {
+    fn wire2api(self) -> MultisigScriptType {
+        match self {
+            0 => MultisigScriptType::P2sh,
+            1 => MultisigScriptType::P2shP2wsh,
+            2 => MultisigScriptType::P2wsh,
+            3 => MultisigScriptType::P2tr,
+            _ => unreachable!("Invalid variant for MultisigScriptType: {}", self),
+        }
+    }
+}
+impl Wire2Api<Network> for i32 {
+    fn wire2api(self) -> Network {
+        match self {
+            0 => Network::Testnet,
+            1 => Network::Regtest,
+            2 => Network::Bitcoin,
+            3 => Network::Signet,
+            _ => unreachable!("Invalid variant for Network: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<PolicyContext> for i32 {
+    fn wire2api(self) -> PolicyContext {
+        match self {
+            0 => PolicyContext::Wsh,
+            1 => PolicyContext::Tr,
+            _ => unreachable!("Invalid variant for PolicyContext: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<Slip132Version> for i32 {
+    fn wire2api(self) -> Slip132Version {
+        match self {
+            0 => Slip132Version::Standard,
+            1 => Slip132Version::NestedSegwit,
+            2 => Slip132Version::Segwit,
+            3 => Slip132Version::NestedSegwitMultisig,
+            4 => Slip132Version::SegwitMultisig,
+            _ => unreachable!("Invalid variant for Slip132Version: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<u32> for u32 {
+    fn wire2api(self) -> u32 {
+        self
+    }
+}
+impl Wire2Api<u64> for u64 {
+    fn wire2api(self) -> u64 {
+        self
+    }
+}
+impl Wire2Api<u8> for u8 {
+    fn wire2api(self) -> u8 {
+        self
+    }
+}
+
+impl Wire2Api<usize> for usize {
+    fn wire2api(self) -> usize {
+        self
+    }
+}
+
+impl Wire2Api<WordCount> for i32 {
+    fn wire2api(self) -> WordCount {
+        match self {
+            0 => WordCount::Words12,
+            1 => WordCount::Words18,
+            2 => WordCount::Words24,
+            _ => unreachable!("Invalid variant for WordCount: {}", self),
+        }
+    }
+}
+// Section: impl IntoDart
+
+impl support::IntoDart for AddedSignature {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.public_key.into_dart(),
+            self.leaf_hash.into_dart(),
+            self.is_tap_key_sig.into_dart(),
+            self.source.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for AddedSignature {}
+
+impl support::IntoDart for AddressInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.index.into_dart(), self.address.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for AddressInfo {}
+
+impl support::IntoDart for Balance {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.immature.into_dart(),
+            self.trusted_pending.into_dart(),
+            self.untrusted_pending.into_dart(),
+            self.confirmed.into_dart(),
+            self.spendable.into_dart(),
+            self.total.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Balance {}
+
+impl support::IntoDart for BdkBumpFeeTxBuilderResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.psbt.into_dart(),
+            self.transaction_details.into_dart(),
+            self.original_fee.into_dart(),
+            self.original_fee_rate.into_dart(),
+            self.fee.into_dart(),
+            self.fee_rate.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BdkBumpFeeTxBuilderResult {}
+
+impl support::IntoDart for BdkSweepResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.psbt.into_dart(),
+            self.transaction_details.into_dart(),
+            self.max_sendable.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BdkSweepResult {}
+
+impl support::IntoDart for BdkTxBuilderResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart(), self.1.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BdkTxBuilderResult {}
+
+impl support::IntoDart for BlockTime {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.height.into_dart(), self.timestamp.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BlockTime {}
+
+impl support::IntoDart for BsmsDescriptorRecord {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.template.into_dart(),
+            self.descriptor.into_dart(),
+            self.change_descriptor.into_dart(),
+            self.first_address.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BsmsDescriptorRecord {}
+
+impl support::IntoDart for BsmsKeyRecord {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.token.into_dart(),
+            self.key.into_dart(),
+            self.description.into_dart(),
+            self.signature.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BsmsKeyRecord {}
+
+impl support::IntoDart for CombinedInput {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.index.into_dart(), self.added_signatures.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CombinedInput {}
+
+impl support::IntoDart for CombinedPsbt {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.psbt.into_dart(), self.inputs.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CombinedPsbt {}
+
+impl support::IntoDart for CompiledPolicy {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.descriptor.into_dart(),
+            self.max_satisfaction_weight.into_dart(),
+            self.is_sane.into_dart(),
+            self.sanity_error.into_dart(),
+            self.is_non_malleable.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CompiledPolicy {}
+
+impl support::IntoDart for ConsolidationPsbt {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.psbt.into_dart(),
+            self.transaction_details.into_dart(),
+            self.keychain.into_dart(),
+            self.label.into_dart(),
+            self.utxos.into_dart(),
+            self.estimated_savings.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ConsolidationPsbt {}
+
+impl support::IntoDart for CosignerStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.fingerprint.into_dart(),
+            self.signed_inputs.into_dart(),
+            self.has_signed.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CosignerStatus {}
+
+impl support::IntoDart for DerivedAddress {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_dart(),
+            self.address.into_dart(),
+            self.script_pubkey.into_dart(),
+            self.keys.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DerivedAddress {}
+
+impl support::IntoDart for DescNetwork {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart(), self.1.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DescNetwork {}
+
+impl support::IntoDart for DescriptorInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.descriptor_type.into_dart(),
+            self.threshold.into_dart(),
+            self.is_sorted_multi.into_dart(),
+            self.has_wildcard.into_dart(),
+            self.is_multipath.into_dart(),
+            self.keys.into_dart(),
+            self.checksum.into_dart(),
+            self.has_private_keys.into_dart(),
+            self.network.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DescriptorInfo {}
+
+impl support::IntoDart for DescriptorKeyInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.key.into_dart(),
+            self.origin_fingerprint.into_dart(),
+            self.origin_path.into_dart(),
+            self.master_fingerprint.into_dart(),
+            self.full_derivation_path.into_dart(),
+            self.has_wildcard.into_dart(),
+            self.is_private.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DescriptorKeyInfo {}
+
+impl support::IntoDart for DescriptorType {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Bare => 0,
+            Self::Pkh => 1,
+            Self::Wpkh => 2,
+            Self::ShWpkh => 3,
+            Self::Sh => 4,
+            Self::Wsh => 5,
+            Self::ShWsh => 6,
+            Self::Tr => 7,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DescriptorType {}
+impl support::IntoDart for ExcludedUtxo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.outpoint.into_dart(),
+            self.value.into_dart(),
+            self.spend_cost.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ExcludedUtxo {}
+
+impl support::IntoDart for FinalizeInputResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_dart(),
+            self.is_finalized.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FinalizeInputResult {}
+
+impl support::IntoDart for FinalizedPsbt {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.psbt.into_dart(),
+            self.is_finalized.into_dart(),
+            self.inputs.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FinalizedPsbt {}
+
+impl support::IntoDart for ImportedCosigner {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.fingerprint.into_dart(),
+            self.derivation_path.into_dart(),
+            self.key.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ImportedCosigner {}
+
+impl support::IntoDart for ImportedWallet {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.source.into_dart(),
+            self.name.into_dart(),
+            self.descriptor.into_dart(),
+            self.change_descriptor.into_dart(),
+            self.threshold.into_dart(),
+            self.cosigners.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ImportedWallet {}
+
+impl support::IntoDart for KeyMetadata {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.master_fingerprint.into_dart(),
+            self.fingerprint.into_dart(),
+            self.origin_path.into_dart(),
+            self.wildcard.into_dart(),
+            self.parent_fingerprint.into_dart(),
+            self.depth.into_dart(),
+            self.child_number.into_dart(),
+            self.chain_code.into_dart(),
+            self.xpub.into_dart(),
+            self.xprv.into_dart(),
+            self.public_key.into_dart(),
+            self.x_only_public_key.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for KeyMetadata {}
+
+impl support::IntoDart for KeyOrigin {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.public_key.into_dart(),
+            self.fingerprint.into_dart(),
+            self.path.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for KeyOrigin {}
+
+impl support::IntoDart for KeychainKind {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::External => 0,
+            Self::Internal => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for KeychainKind {}
+
+impl support::IntoDart for LocalUtxo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.outpoint.into_dart(),
+            self.txout.into_dart(),
+            self.is_spent.into_dart(),
+            self.keychain.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LocalUtxo {}
+
+impl support::IntoDart for MaxSendable {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.amount.into_dart(),
+            self.fee.into_dart(),
+            self.utxos.into_dart(),
+            self.excluded.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MaxSendable {}
+
+impl support::IntoDart for MultisigDescriptors {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.descriptor.into_dart(),
+            self.change_descriptor.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MultisigDescriptors {}
+
+impl support::IntoDart for MultisigScriptType {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::P2sh => 0,
+            Self::P2shP2wsh => 1,
+            Self::P2wsh => 2,
+            Self::P2tr => 3,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MultisigScriptType {}
+impl support::IntoDart for MultisigStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.threshold.into_dart(),
+            self.cosigners.into_dart(),
+            self.signatures.into_dart(),
+            self.is_ready.into_dart(),
+            self.psbt.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MultisigStatus {}
+
+impl support::IntoDart for Network {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Testnet => 0,
+            Self::Regtest => 1,
+            Self::Bitcoin => 2,
+            Self::Signet => 3,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Network {}
+
+impl support::IntoDart for OutPoint {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.txid.into_dart(), self.vout.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OutPoint {}
+
+impl support::IntoDart for PayjoinRequest {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.url.into_dart(), self.body.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PayjoinRequest {}
+
+impl support::IntoDart for PayjoinUri {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.address.into_dart(),
+            self.amount.into_dart(),
+            self.endpoint.into_dart(),
+            self.disable_output_substitution.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PayjoinUri {}
+
+impl support::IntoDart for Payload {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::PubkeyHash { pubkey_hash } => vec![0.into_dart(), pubkey_hash.into_dart()],
+            Self::ScriptHash { script_hash } => vec![1.into_dart(), script_hash.into_dart()],
+            Self::WitnessProgram { version, program } => {
+                vec![2.into_dart(), version.into_dart(), program.into_dart()]
+            }
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Payload {}
+impl support::IntoDart for PsbtAnalysis {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.inputs.into_dart(),
+            self.outputs.into_dart(),
+            self.sent.into_dart(),
+            self.received.into_dart(),
+            self.net.into_dart(),
+            self.fee.into_dart(),
+            self.fee_rate.into_dart(),
+            self.warnings.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtAnalysis {}
+
+impl support::IntoDart for PsbtInputAnalysis {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.previous_output.into_dart(),
+            self.value.into_dart(),
+            self.is_mine.into_dart(),
+            self.keychain.into_dart(),
+            self.derivation_index.into_dart(),
+            self.can_sign.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtInputAnalysis {}
+
+impl support::IntoDart for PsbtInputInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.previous_output.into_dart(),
+            self.sequence.into_dart(),
+            self.amount.into_dart(),
+            self.script_pubkey.into_dart(),
+            self.sighash_type.into_dart(),
+            self.bip32_derivations.into_dart(),
+            self.partial_sigs.into_dart(),
+            self.has_tap_key_sig.into_dart(),
+            self.tap_script_sigs.into_dart(),
+            self.is_finalized.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtInputInfo {}
+
+impl support::IntoDart for PsbtIssue {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::FeeTooHigh { fee, max_fee } => {
+                vec![0.into_dart(), fee.into_dart(), max_fee.into_dart()]
+            }
+            Self::FeeTooHighRelative {
+                fee,
+                fee_percent,
+                max_fee_percent,
+            } => vec![
+                1.into_dart(),
+                fee.into_dart(),
+                fee_percent.into_dart(),
+                max_fee_percent.into_dart(),
+            ],
+            Self::MissingUtxo { index } => vec![2.into_dart(), index.into_dart()],
+            Self::MissingNonWitnessUtxo { index } => vec![3.into_dart(), index.into_dart()],
+            Self::NonWitnessUtxoMismatch { index } => vec![4.into_dart(), index.into_dart()],
+            Self::UtxoValueMismatch {
+                index,
+                witness_value,
+                non_witness_value,
+            } => vec![
+                5.into_dart(),
+                index.into_dart(),
+                witness_value.into_dart(),
+                non_witness_value.into_dart(),
+            ],
+            Self::NonStandardSighash { index, sighash } => {
+                vec![6.into_dart(), index.into_dart(), sighash.into_dart()]
+            }
+            Self::DustOutput {
+                index,
+                value,
+                dust_limit,
+            } => vec![
+                7.into_dart(),
+                index.into_dart(),
+                value.into_dart(),
+                dust_limit.into_dart(),
+            ],
+            Self::NetworkMismatch { network } => vec![8.into_dart(), network.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtIssue {}
+impl support::IntoDart for PsbtOutputAnalysis {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.script_pubkey.into_dart(),
+            self.value.into_dart(),
+            self.is_mine.into_dart(),
+            self.is_change.into_dart(),
+            self.keychain.into_dart(),
+            self.derivation_index.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtOutputAnalysis {}
+
+impl support::IntoDart for PsbtOutputInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.script_pubkey.into_dart(),
+            self.address.into_dart(),
+            self.amount.into_dart(),
+            self.bip32_derivations.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtOutputInfo {}
+
+impl support::IntoDart for PsbtSafetyReport {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.is_safe.into_dart(),
+            self.fee.into_dart(),
+            self.issues.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtSafetyReport {}
+
+impl support::IntoDart for PsbtSigHashType {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.inner.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtSigHashType {}
+
+impl support::IntoDart for PsbtWarning {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::NetworkMismatch { network } => vec![0.into_dart(), network.into_dart()],
+            Self::MissingUtxo { index } => vec![1.into_dart(), index.into_dart()],
+            Self::ChangeDerivationMismatch { index } => vec![2.into_dart(), index.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PsbtWarning {}
+impl support::IntoDart for Script {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.internal.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Script {}
+
+impl support::IntoDart for Slip132Template {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.key.into_dart(),
+            self.version.into_dart(),
+            self.descriptor.into_dart(),
+            self.multisig_script_type.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Slip132Template {}
+
+impl support::IntoDart for Slip132Version {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Standard => 0,
+            Self::NestedSegwit => 1,
+            Self::Segwit => 2,
+            Self::NestedSegwitMultisig => 3,
+            Self::SegwitMultisig => 4,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Slip132Version {}
+impl support::IntoDart for TapScriptSig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.public_key.into_dart(), self.leaf_hash.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TapScriptSig {}
+
+impl support::IntoDart for TransactionDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.serialized_tx.into_dart(),
+            self.txid.into_dart(),
+            self.received.into_dart(),
+            self.sent.into_dart(),
+            self.fee.into_dart(),
+            self.confirmation_time.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TransactionDetails {}
+
+impl support::IntoDart for TxIn {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.previous_output.into_dart(),
+            self.script_sig.into_dart(),
+            self.sequence.into_dart(),
+            self.witness.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TxIn {}
+
+impl support::IntoDart for TxOut {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.value.into_dart(), self.script_pubkey.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TxOut {}
+
+impl support::IntoDart for UrDecodeProgress {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.ur_type.into_dart(),
+            self.progress.into_dart(),
+            self.result.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UrDecodeProgress {}
+
+impl support::IntoDart for UrPayload {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Psbt { psbt } => vec![0.into_dart(), psbt.into_dart()],
+            Self::Output { descriptor } => vec![1.into_dart(), descriptor.into_dart()],
+            Self::Account {
+                fingerprint,
+                descriptors,
+            } => vec![
+                2.into_dart(),
+                fingerprint.into_dart(),
+                descriptors.into_dart(),
+            ],
+            Self::Bytes { ur_type, cbor } => {
+                vec![3.into_dart(), ur_type.into_dart(), cbor.into_dart()]
+            }
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UrPayload {}
+
+impl support::IntoDart for WalletSource {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Coldcard => 0,
+            Self::Specter => 1,
+            Self::Electrum => 2,
+            Self::BlueWallet => 3,
+            Self::Descriptor => 4,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WalletSource {}
+impl support::IntoDart for WildcardType {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::None => 0,
+            Self::Unhardened => 1,
+            Self::Hardened => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WildcardType {}
+impl support::IntoDart for WitnessVersion {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::V0 => 0,
+            Self::V1 => 1,
+            Self::V2 => 2,
+            Self::V3 => 3,
+            Self::V4 => 4,
+            Self::V5 => 5,
+            Self::V6 => 6,
+            Self::V7 => 7,
+            Self::V8 => 8,
+            Self::V9 => 9,
+            Self::V10 => 10,
+            Self::V11 => 11,
+            Self::V12 => 12,
+            Self::V13 => 13,
+            Self::V14 => 14,
+            Self::V15 => 15,
+            Self::V16 => 16,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WitnessVersion {}
+// Section: executor
+
+support::lazy_static! {
+    pub static ref FLUTTER_RUST_BRIDGE_HANDLER: support::DefaultHandler = Default::default();
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use super::*;
+    // Section: wire functions
+
+    #[no_mangle]
+    pub extern "C" fn wire_create_blockchain__static_method__Api(
+        port_: i64,
+        config: *mut wire_BlockchainConfig,
+    ) {
+        wire_create_blockchain__static_method__Api_impl(port_, config)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_get_height__static_method__Api(
+        port_: i64,
+        blockchain_id: *mut wire_uint_8_list,
+    ) {
+        wire_get_height__static_method__Api_impl(port_, blockchain_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_get_blockchain_hash__static_method__Api(
+        port_: i64,
+        blockchain_height: u32,
+        blockchain_id: *mut wire_uint_8_list,
+    ) {
+        wire_get_blockchain_hash__static_method__Api_impl(port_, blockchain_height, blockchain_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_estimate_fee__static_method__Api(
+        port_: i64,
+        target: u64,
+        blockchain_id: *mut wire_uint_8_list,
+    ) {
+        wire_estimate_fee__static_method__Api_impl(port_, target, blockchain_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_broadcast__static_method__Api(
+        port_: i64,
+        tx: *mut wire_uint_8_list,
+        blockchain_id: *mut wire_uint_8_list,
+    ) {
+        wire_broadcast__static_method__Api_impl(port_, tx, blockchain_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_create_transaction__static_method__Api(
+        port_: i64,
+        tx: *mut wire_uint_8_list,
+    ) {
+        wire_create_transaction__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_tx_txid__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_tx_txid__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_weight__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_weight__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_size__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_size__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_vsize__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_vsize__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_serialize_tx__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_serialize_tx__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_is_coin_base__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_is_coin_base__static_method__Api_impl(port_, tx)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_is_explicitly_rbf__static_method__Api(
+        port_: i64,
+        tx: *mut wire_uint_8_list,
+    ) {
+        wire_is_explicitly_rbf__static_method__Api_impl(port_, tx)
     }
-}
 
-impl Wire2Api<Network> for i32 {
-    fn wire2api(self) -> Network {
-        match self {
-            0 => Network::Testnet,
-            1 => Network::Regtest,
-            2 => Network::Bitcoin,
-            3 => Network::Signet,
-            _ => unreachable!("Invalid variant for Network: {}", self),
-        }
+    #[no_mangle]
+    pub extern "C" fn wire_is_lock_time_enabled__static_method__Api(
+        port_: i64,
+        tx: *mut wire_uint_8_list,
+    ) {
+        wire_is_lock_time_enabled__static_method__Api_impl(port_, tx)
     }
-}
 
-impl Wire2Api<u32> for u32 {
-    fn wire2api(self) -> u32 {
-        self
+    #[no_mangle]
+    pub extern "C" fn wire_version__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_version__static_method__Api_impl(port_, tx)
     }
-}
-impl Wire2Api<u64> for u64 {
-    fn wire2api(self) -> u64 {
-        self
+
+    #[no_mangle]
+    pub extern "C" fn wire_lock_time__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_lock_time__static_method__Api_impl(port_, tx)
     }
-}
-impl Wire2Api<u8> for u8 {
-    fn wire2api(self) -> u8 {
-        self
+
+    #[no_mangle]
+    pub extern "C" fn wire_input__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_input__static_method__Api_impl(port_, tx)
     }
-}
 
-impl Wire2Api<usize> for usize {
-    fn wire2api(self) -> usize {
-        self
+    #[no_mangle]
+    pub extern "C" fn wire_output__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
+        wire_output__static_method__Api_impl(port_, tx)
     }
-}
-impl Wire2Api<WordCount> for i32 {
-    fn wire2api(self) -> WordCount {
-        match self {
-            0 => WordCount::Words12,
-            1 => WordCount::Words18,
-            2 => WordCount::Words24,
-            _ => unreachable!("Invalid variant for WordCount: {}", self),
-        }
+
+    #[no_mangle]
+    pub extern "C" fn wire_serialize_psbt__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_serialize_psbt__static_method__Api_impl(port_, psbt_str)
     }
-}
-// Section: impl IntoDart
 
-impl support::IntoDart for AddressInfo {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.index.into_dart(), self.address.into_dart()].into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_from_bytes__static_method__Api(
+        port_: i64,
+        bytes: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_from_bytes__static_method__Api_impl(port_, bytes)
     }
-}
-impl support::IntoDartExceptPrimitive for AddressInfo {}
 
-impl support::IntoDart for Balance {
-    fn into_dart(self) -> support::DartAbi {
-        vec![
-            self.immature.into_dart(),
-            self.trusted_pending.into_dart(),
-            self.untrusted_pending.into_dart(),
-            self.confirmed.into_dart(),
-            self.spendable.into_dart(),
-            self.total.into_dart(),
-        ]
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_to_bytes__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_to_bytes__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for Balance {}
 
-impl support::IntoDart for BdkTxBuilderResult {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.0.into_dart(), self.1.into_dart()].into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_to_hex__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_to_hex__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for BdkTxBuilderResult {}
 
-impl support::IntoDart for BlockTime {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.height.into_dart(), self.timestamp.into_dart()].into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_version__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_version__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for BlockTime {}
 
-impl support::IntoDart for DescNetwork {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.0.into_dart(), self.1.into_dart()].into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_serialize_psbt_version__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        version: u32,
+    ) {
+        wire_serialize_psbt_version__static_method__Api_impl(port_, psbt_str, version)
     }
-}
-impl support::IntoDartExceptPrimitive for DescNetwork {}
 
-impl support::IntoDart for KeychainKind {
-    fn into_dart(self) -> support::DartAbi {
-        match self {
-            Self::External => 0,
-            Self::Internal => 1,
-        }
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_txid__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_txid__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for KeychainKind {}
 
-impl support::IntoDart for LocalUtxo {
-    fn into_dart(self) -> support::DartAbi {
-        vec![
-            self.outpoint.into_dart(),
-            self.txout.into_dart(),
-            self.is_spent.into_dart(),
-            self.keychain.into_dart(),
-        ]
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_extract_tx__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_extract_tx__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for LocalUtxo {}
 
-impl support::IntoDart for Network {
-    fn into_dart(self) -> support::DartAbi {
-        match self {
-            Self::Testnet => 0,
-            Self::Regtest => 1,
-            Self::Bitcoin => 2,
-            Self::Signet => 3,
-        }
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_fee_rate__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_fee_rate__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for Network {}
 
-impl support::IntoDart for OutPoint {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.txid.into_dart(), self.vout.into_dart()].into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_fee_amount__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_fee_amount__static_method__Api_impl(port_, psbt_str)
     }
-}
-impl support::IntoDartExceptPrimitive for OutPoint {}
 
-impl support::IntoDart for Payload {
-    fn into_dart(self) -> support::DartAbi {
-        match self {
-            Self::PubkeyHash { pubkey_hash } => vec![0.into_dart(), pubkey_hash.into_dart()],
-            Self::ScriptHash { script_hash } => vec![1.into_dart(), script_hash.into_dart()],
-            Self::WitnessProgram { version, program } => {
-                vec![2.into_dart(), version.into_dart(), program.into_dart()]
-            }
-        }
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_combine_psbt__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        other: *mut wire_uint_8_list,
+    ) {
+        wire_combine_psbt__static_method__Api_impl(port_, psbt_str, other)
     }
-}
-impl support::IntoDartExceptPrimitive for Payload {}
-impl support::IntoDart for Script {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.internal.into_dart()].into_dart()
+
+    #[no_mangle]
+    pub extern "C" fn wire_combine_psbts__static_method__Api(
+        port_: i64,
+        psbt_strs: *mut wire_StringList,
+    ) {
+        wire_combine_psbts__static_method__Api_impl(port_, psbt_strs)
     }
-}
-impl support::IntoDartExceptPrimitive for Script {}
 
-impl support::IntoDart for TransactionDetails {
-    fn into_dart(self) -> support::DartAbi {
-        vec![
-            self.serialized_tx.into_dart(),
-            self.txid.into_dart(),
-            self.received.into_dart(),
-            self.sent.into_dart(),
-            self.fee.into_dart(),
-            self.confirmation_time.into_dart(),
-        ]
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_add_input__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        outpoint: *mut wire_OutPoint,
+        psbt_input: *mut wire_uint_8_list,
+        sequence: *mut u32,
+    ) {
+        wire_psbt_add_input__static_method__Api_impl(
+            port_, psbt_str, outpoint, psbt_input, sequence,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_remove_input__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        index: u32,
+    ) {
+        wire_psbt_remove_input__static_method__Api_impl(port_, psbt_str, index)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_add_output__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        script: *mut wire_Script,
+        amount: u64,
+    ) {
+        wire_psbt_add_output__static_method__Api_impl(port_, psbt_str, script, amount)
     }
-}
-impl support::IntoDartExceptPrimitive for TransactionDetails {}
 
-impl support::IntoDart for TxIn {
-    fn into_dart(self) -> support::DartAbi {
-        vec![
-            self.previous_output.into_dart(),
-            self.script_sig.into_dart(),
-            self.sequence.into_dart(),
-            self.witness.into_dart(),
-        ]
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_remove_output__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        index: u32,
+    ) {
+        wire_psbt_remove_output__static_method__Api_impl(port_, psbt_str, index)
     }
-}
-impl support::IntoDartExceptPrimitive for TxIn {}
 
-impl support::IntoDart for TxOut {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.value.into_dart(), self.script_pubkey.into_dart()].into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_set_sequence__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        index: u32,
+        sequence: u32,
+    ) {
+        wire_psbt_set_sequence__static_method__Api_impl(port_, psbt_str, index, sequence)
     }
-}
-impl support::IntoDartExceptPrimitive for TxOut {}
 
-impl support::IntoDart for WitnessVersion {
-    fn into_dart(self) -> support::DartAbi {
-        match self {
-            Self::V0 => 0,
-            Self::V1 => 1,
-            Self::V2 => 2,
-            Self::V3 => 3,
-            Self::V4 => 4,
-            Self::V5 => 5,
-            Self::V6 => 6,
-            Self::V7 => 7,
-            Self::V8 => 8,
-            Self::V9 => 9,
-            Self::V10 => 10,
-            Self::V11 => 11,
-            Self::V12 => 12,
-            Self::V13 => 13,
-            Self::V14 => 14,
-            Self::V15 => 15,
-            Self::V16 => 16,
-        }
-        .into_dart()
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_set_lock_time__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        lock_time: u32,
+    ) {
+        wire_psbt_set_lock_time__static_method__Api_impl(port_, psbt_str, lock_time)
     }
-}
-impl support::IntoDartExceptPrimitive for WitnessVersion {}
-// Section: executor
 
-support::lazy_static! {
-    pub static ref FLUTTER_RUST_BRIDGE_HANDLER: support::DefaultHandler = Default::default();
-}
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_set_unknown__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        map: *mut wire_PsbtMap,
+        key_type: u8,
+        key: *mut wire_uint_8_list,
+        value: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_set_unknown__static_method__Api_impl(port_, psbt_str, map, key_type, key, value)
+    }
 
-#[cfg(not(target_family = "wasm"))]
-mod io {
-    use super::*;
-    // Section: wire functions
+    #[no_mangle]
+    pub extern "C" fn wire_psbt_set_proprietary__static_method__Api(
+        port_: i64,
+        psbt_str: *mut wire_uint_8_list,
+        map: *mut wire_PsbtMap,
+        prefix: *mut wire_uint_8_list,
+        subtype: u8,
+        key: *mut wire_uint_8_list,
+        value: *mut wire_uint_8_list,
+    ) {
+        wire_psbt_set_proprietary__static_method__Api_impl(
+            port_, psbt_str, map, prefix, subtype, key, value,
+        )
+    }
 
     #[no_mangle]
-    pub extern "C" fn wire_create_blockchain__static_method__Api(
+    pub extern "C" fn wire_finalize_psbt_with_descriptor__static_method__Api(
         port_: i64,
-        config: *mut wire_BlockchainConfig,
+        psbt_str: *mut wire_uint_8_list,
+        descriptor: *mut wire_uint_8_list,
+        change_descriptor: *mut wire_uint_8_list,
+        network: i32,
+        lookahead: u32,
     ) {
-        wire_create_blockchain__static_method__Api_impl(port_, config)
+        wire_finalize_psbt_with_descriptor__static_method__Api_impl(
+            port_,
+            psbt_str,
+            descriptor,
+            change_descriptor,
+            network,
+            lookahead,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_get_height__static_method__Api(
+    pub extern "C" fn wire_psbt_inputs__static_method__Api(
         port_: i64,
-        blockchain_id: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
     ) {
-        wire_get_height__static_method__Api_impl(port_, blockchain_id)
+        wire_psbt_inputs__static_method__Api_impl(port_, psbt_str)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_get_blockchain_hash__static_method__Api(
+    pub extern "C" fn wire_psbt_outputs__static_method__Api(
         port_: i64,
-        blockchain_height: u32,
-        blockchain_id: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
+        network: i32,
     ) {
-        wire_get_blockchain_hash__static_method__Api_impl(port_, blockchain_height, blockchain_id)
+        wire_psbt_outputs__static_method__Api_impl(port_, psbt_str, network)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_estimate_fee__static_method__Api(
+    pub extern "C" fn wire_json_serialize__static_method__Api(
         port_: i64,
-        target: u64,
-        blockchain_id: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
     ) {
-        wire_estimate_fee__static_method__Api_impl(port_, target, blockchain_id)
+        wire_json_serialize__static_method__Api_impl(port_, psbt_str)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_broadcast__static_method__Api(
+    pub extern "C" fn wire_tx_builder_finish__static_method__Api(
         port_: i64,
-        tx: *mut wire_uint_8_list,
-        blockchain_id: *mut wire_uint_8_list,
+        wallet_id: *mut wire_uint_8_list,
+        recipients: *mut wire_list_script_amount,
+        utxos: *mut wire_list_out_point,
+        foreign_utxo: *mut wire___record__out_point_String_usize,
+        unspendable: *mut wire_list_out_point,
+        change_policy: i32,
+        manually_selected_only: bool,
+        fee_rate: *mut f32,
+        fee_absolute: *mut u64,
+        drain_wallet: bool,
+        drain_to: *mut wire_Script,
+        rbf: *mut wire_RbfValue,
+        data: *mut wire_uint_8_list,
     ) {
-        wire_broadcast__static_method__Api_impl(port_, tx, blockchain_id)
+        wire_tx_builder_finish__static_method__Api_impl(
+            port_,
+            wallet_id,
+            recipients,
+            utxos,
+            foreign_utxo,
+            unspendable,
+            change_policy,
+            manually_selected_only,
+            fee_rate,
+            fee_absolute,
+            drain_wallet,
+            drain_to,
+            rbf,
+            data,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_create_transaction__static_method__Api(
+    pub extern "C" fn wire_bump_fee_tx_builder_finish__static_method__Api(
         port_: i64,
-        tx: *mut wire_uint_8_list,
+        txid: *mut wire_uint_8_list,
+        fee_rate: *mut f32,
+        fee_absolute: *mut u64,
+        allow_shrinking: *mut wire_ShrinkOutput,
+        utxos: *mut wire_list_out_point,
+        unspendable: *mut wire_list_out_point,
+        manually_selected_only: bool,
+        wallet_id: *mut wire_uint_8_list,
+        enable_rbf: bool,
+        n_sequence: *mut u32,
     ) {
-        wire_create_transaction__static_method__Api_impl(port_, tx)
+        wire_bump_fee_tx_builder_finish__static_method__Api_impl(
+            port_,
+            txid,
+            fee_rate,
+            fee_absolute,
+            allow_shrinking,
+            utxos,
+            unspendable,
+            manually_selected_only,
+            wallet_id,
+            enable_rbf,
+            n_sequence,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_tx_txid__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_tx_txid__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_cancel_transaction__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        txid: *mut wire_uint_8_list,
+        fee_rate: f32,
+    ) {
+        wire_cancel_transaction__static_method__Api_impl(port_, wallet_id, txid, fee_rate)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_weight__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_weight__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_parse_payjoin_uri__static_method__Api(
+        port_: i64,
+        uri: *mut wire_uint_8_list,
+    ) {
+        wire_parse_payjoin_uri__static_method__Api_impl(port_, uri)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_size__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_size__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_payjoin_sender_request__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        uri: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
+        params: *mut wire_PayjoinParams,
+        sign_options: *mut wire_SignOptions,
+    ) {
+        wire_payjoin_sender_request__static_method__Api_impl(
+            port_,
+            wallet_id,
+            uri,
+            psbt_str,
+            params,
+            sign_options,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_vsize__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_vsize__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_payjoin_send__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        uri: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
+        params: *mut wire_PayjoinParams,
+        sign_options: *mut wire_SignOptions,
+    ) {
+        wire_payjoin_send__static_method__Api_impl(
+            port_,
+            wallet_id,
+            uri,
+            psbt_str,
+            params,
+            sign_options,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_serialize_tx__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_serialize_tx__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_payjoin_sender_process_response__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        uri: *mut wire_uint_8_list,
+        original_psbt: *mut wire_uint_8_list,
+        params: *mut wire_PayjoinParams,
+        response: *mut wire_uint_8_list,
+        sign_options: *mut wire_SignOptions,
+    ) {
+        wire_payjoin_sender_process_response__static_method__Api_impl(
+            port_,
+            wallet_id,
+            uri,
+            original_psbt,
+            params,
+            response,
+            sign_options,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_is_coin_base__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_is_coin_base__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_payjoin_receive__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        original_psbt: *mut wire_uint_8_list,
+        query: *mut wire_uint_8_list,
+    ) {
+        wire_payjoin_receive__static_method__Api_impl(port_, wallet_id, original_psbt, query)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_is_explicitly_rbf__static_method__Api(
+    pub extern "C" fn wire_psbt_to_ur__static_method__Api(
         port_: i64,
-        tx: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
+        max_fragment_length: u32,
+        start_seq_num: u32,
+        count: u32,
     ) {
-        wire_is_explicitly_rbf__static_method__Api_impl(port_, tx)
+        wire_psbt_to_ur__static_method__Api_impl(
+            port_,
+            psbt_str,
+            max_fragment_length,
+            start_seq_num,
+            count,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_is_lock_time_enabled__static_method__Api(
+    pub extern "C" fn wire_descriptor_to_ur__static_method__Api(
         port_: i64,
-        tx: *mut wire_uint_8_list,
+        descriptor: *mut wire_uint_8_list,
+        network: i32,
     ) {
-        wire_is_lock_time_enabled__static_method__Api_impl(port_, tx)
+        wire_descriptor_to_ur__static_method__Api_impl(port_, descriptor, network)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_version__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_version__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_descriptors_to_ur_account__static_method__Api(
+        port_: i64,
+        descriptors: *mut wire_StringList,
+        network: i32,
+    ) {
+        wire_descriptors_to_ur_account__static_method__Api_impl(port_, descriptors, network)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_lock_time__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_lock_time__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_create_ur_decoder__static_method__Api(port_: i64, network: i32) {
+        wire_create_ur_decoder__static_method__Api_impl(port_, network)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_input__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_input__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_ur_decoder_receive__static_method__Api(
+        port_: i64,
+        decoder_id: *mut wire_uint_8_list,
+        part: *mut wire_uint_8_list,
+    ) {
+        wire_ur_decoder_receive__static_method__Api_impl(port_, decoder_id, part)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_output__static_method__Api(port_: i64, tx: *mut wire_uint_8_list) {
-        wire_output__static_method__Api_impl(port_, tx)
+    pub extern "C" fn wire_drop_ur_decoder__static_method__Api(
+        port_: i64,
+        decoder_id: *mut wire_uint_8_list,
+    ) {
+        wire_drop_ur_decoder__static_method__Api_impl(port_, decoder_id)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_serialize_psbt__static_method__Api(
+    pub extern "C" fn wire_create_multisig_session__static_method__Api(
         port_: i64,
+        descriptor: *mut wire_uint_8_list,
+        change_descriptor: *mut wire_uint_8_list,
+        network: i32,
         psbt_str: *mut wire_uint_8_list,
     ) {
-        wire_serialize_psbt__static_method__Api_impl(port_, psbt_str)
+        wire_create_multisig_session__static_method__Api_impl(
+            port_,
+            descriptor,
+            change_descriptor,
+            network,
+            psbt_str,
+        )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_psbt_txid__static_method__Api(
+    pub extern "C" fn wire_restore_multisig_session__static_method__Api(
+        port_: i64,
+        data: *mut wire_uint_8_list,
+    ) {
+        wire_restore_multisig_session__static_method__Api_impl(port_, data)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_export_multisig_session__static_method__Api(
+        port_: i64,
+        session_id: *mut wire_uint_8_list,
+    ) {
+        wire_export_multisig_session__static_method__Api_impl(port_, session_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_multisig_session_add_psbt__static_method__Api(
         port_: i64,
+        session_id: *mut wire_uint_8_list,
         psbt_str: *mut wire_uint_8_list,
     ) {
-        wire_psbt_txid__static_method__Api_impl(port_, psbt_str)
+        wire_multisig_session_add_psbt__static_method__Api_impl(port_, session_id, psbt_str)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_extract_tx__static_method__Api(
+    pub extern "C" fn wire_multisig_session_status__static_method__Api(
         port_: i64,
-        psbt_str: *mut wire_uint_8_list,
+        session_id: *mut wire_uint_8_list,
     ) {
-        wire_extract_tx__static_method__Api_impl(port_, psbt_str)
+        wire_multisig_session_status__static_method__Api_impl(port_, session_id)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_psbt_fee_rate__static_method__Api(
+    pub extern "C" fn wire_finalize_multisig_session__static_method__Api(
         port_: i64,
-        psbt_str: *mut wire_uint_8_list,
+        session_id: *mut wire_uint_8_list,
     ) {
-        wire_psbt_fee_rate__static_method__Api_impl(port_, psbt_str)
+        wire_finalize_multisig_session__static_method__Api_impl(port_, session_id)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_psbt_fee_amount__static_method__Api(
+    pub extern "C" fn wire_drop_multisig_session__static_method__Api(
         port_: i64,
-        psbt_str: *mut wire_uint_8_list,
+        session_id: *mut wire_uint_8_list,
     ) {
-        wire_psbt_fee_amount__static_method__Api_impl(port_, psbt_str)
+        wire_drop_multisig_session__static_method__Api_impl(port_, session_id)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_combine_psbt__static_method__Api(
+    pub extern "C" fn wire_bsms_new_token__static_method__Api(port_: i64, encryption: i32) {
+        wire_bsms_new_token__static_method__Api_impl(port_, encryption)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_bsms_key_record__static_method__Api(
         port_: i64,
-        psbt_str: *mut wire_uint_8_list,
-        other: *mut wire_uint_8_list,
+        secret_key: *mut wire_uint_8_list,
+        token: *mut wire_uint_8_list,
+        description: *mut wire_uint_8_list,
     ) {
-        wire_combine_psbt__static_method__Api_impl(port_, psbt_str, other)
+        wire_bsms_key_record__static_method__Api_impl(port_, secret_key, token, description)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_json_serialize__static_method__Api(
+    pub extern "C" fn wire_bsms_verify_key_record__static_method__Api(
         port_: i64,
-        psbt_str: *mut wire_uint_8_list,
+        record: *mut wire_uint_8_list,
+        token: *mut wire_uint_8_list,
     ) {
-        wire_json_serialize__static_method__Api_impl(port_, psbt_str)
+        wire_bsms_verify_key_record__static_method__Api_impl(port_, record, token)
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_tx_builder_finish__static_method__Api(
+    pub extern "C" fn wire_bsms_descriptor_record__static_method__Api(
         port_: i64,
-        wallet_id: *mut wire_uint_8_list,
-        recipients: *mut wire_list_script_amount,
-        utxos: *mut wire_list_out_point,
-        foreign_utxo: *mut wire___record__out_point_String_usize,
-        unspendable: *mut wire_list_out_point,
-        change_policy: i32,
-        manually_selected_only: bool,
-        fee_rate: *mut f32,
-        fee_absolute: *mut u64,
-        drain_wallet: bool,
-        drain_to: *mut wire_Script,
-        rbf: *mut wire_RbfValue,
-        data: *mut wire_uint_8_list,
+        threshold: u32,
+        keys: *mut wire_StringList,
+        script_type: i32,
+        token: *mut wire_uint_8_list,
+        network: i32,
     ) {
-        wire_tx_builder_finish__static_method__Api_impl(
+        wire_bsms_descriptor_record__static_method__Api_impl(
             port_,
-            wallet_id,
-            recipients,
-            utxos,
-            foreign_utxo,
-            unspendable,
-            change_policy,
-            manually_selected_only,
-            fee_rate,
-            fee_absolute,
-            drain_wallet,
-            drain_to,
-            rbf,
-            data,
+            threshold,
+            keys,
+            script_type,
+            token,
+            network,
         )
     }
 
     #[no_mangle]
-    pub extern "C" fn wire_bump_fee_tx_builder_finish__static_method__Api(
+    pub extern "C" fn wire_bsms_verify_descriptor_record__static_method__Api(
         port_: i64,
-        txid: *mut wire_uint_8_list,
-        fee_rate: f32,
-        allow_shrinking: *mut wire_uint_8_list,
-        wallet_id: *mut wire_uint_8_list,
-        enable_rbf: bool,
-        n_sequence: *mut u32,
+        record: *mut wire_uint_8_list,
+        token: *mut wire_uint_8_list,
+        secret_key: *mut wire_uint_8_list,
+        network: i32,
     ) {
-        wire_bump_fee_tx_builder_finish__static_method__Api_impl(
-            port_,
-            txid,
-            fee_rate,
-            allow_shrinking,
-            wallet_id,
-            enable_rbf,
-            n_sequence,
+        wire_bsms_verify_descriptor_record__static_method__Api_impl(
+            port_, record, token, secret_key, network,
         )
     }
 
@@ -1940,6 +4392,31 @@ mod io {
         )
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_new_single_key_descriptor__static_method__Api(
+        port_: i64,
+        script_type: i32,
+        secret_key: *mut wire_uint_8_list,
+        network: i32,
+    ) {
+        wire_new_single_key_descriptor__static_method__Api_impl(
+            port_,
+            script_type,
+            secret_key,
+            network,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_new_single_key_public__static_method__Api(
+        port_: i64,
+        script_type: i32,
+        public_key: *mut wire_uint_8_list,
+        network: i32,
+    ) {
+        wire_new_single_key_public__static_method__Api_impl(port_, script_type, public_key, network)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_as_string_private__static_method__Api(
         port_: i64,
@@ -1967,6 +4444,66 @@ mod io {
         wire_max_satisfaction_weight__static_method__Api_impl(port_, descriptor, network)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_new_multisig_descriptor__static_method__Api(
+        port_: i64,
+        threshold: u32,
+        public_keys: *mut wire_StringList,
+        secret_key: *mut wire_uint_8_list,
+        script_type: i32,
+        sorted: bool,
+        network: i32,
+    ) {
+        wire_new_multisig_descriptor__static_method__Api_impl(
+            port_,
+            threshold,
+            public_keys,
+            secret_key,
+            script_type,
+            sorted,
+            network,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_compile_policy__static_method__Api(
+        port_: i64,
+        policy: *mut wire_uint_8_list,
+        keys: *mut wire_list_policy_key,
+        context: i32,
+        network: i32,
+    ) {
+        wire_compile_policy__static_method__Api_impl(port_, policy, keys, context, network)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_derive_addresses__static_method__Api(
+        port_: i64,
+        descriptor: *mut wire_uint_8_list,
+        network: i32,
+        start: u32,
+        count: u32,
+    ) {
+        wire_derive_addresses__static_method__Api_impl(port_, descriptor, network, start, count)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_descriptor_info__static_method__Api(
+        port_: i64,
+        descriptor: *mut wire_uint_8_list,
+    ) {
+        wire_descriptor_info__static_method__Api_impl(port_, descriptor)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_import_wallet__static_method__Api(
+        port_: i64,
+        data: *mut wire_uint_8_list,
+        network: i32,
+    ) {
+        wire_import_wallet__static_method__Api_impl(port_, data, network)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_create_descriptor_secret__static_method__Api(
         port_: i64,
@@ -1985,6 +4522,15 @@ mod io {
         wire_descriptor_secret_from_string__static_method__Api_impl(port_, secret)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_descriptor_secret_from_single__static_method__Api(
+        port_: i64,
+        secret: *mut wire_uint_8_list,
+        network: i32,
+    ) {
+        wire_descriptor_secret_from_single__static_method__Api_impl(port_, secret, network)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_extend_descriptor_secret__static_method__Api(
         port_: i64,
@@ -2027,6 +4573,22 @@ mod io {
         wire_create_derivation_path__static_method__Api_impl(port_, path)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_descriptor_secret_metadata__static_method__Api(
+        port_: i64,
+        secret: *mut wire_uint_8_list,
+    ) {
+        wire_descriptor_secret_metadata__static_method__Api_impl(port_, secret)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_descriptor_public_metadata__static_method__Api(
+        port_: i64,
+        public_key: *mut wire_uint_8_list,
+    ) {
+        wire_descriptor_public_metadata__static_method__Api_impl(port_, public_key)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_descriptor_public_from_string__static_method__Api(
         port_: i64,
@@ -2045,6 +4607,31 @@ mod io {
         wire_create_descriptor_public__static_method__Api_impl(port_, xpub, path, derive)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_slip132_to_standard__static_method__Api(
+        port_: i64,
+        key: *mut wire_uint_8_list,
+    ) {
+        wire_slip132_to_standard__static_method__Api_impl(port_, key)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_standard_to_slip132__static_method__Api(
+        port_: i64,
+        key: *mut wire_uint_8_list,
+        version: i32,
+    ) {
+        wire_standard_to_slip132__static_method__Api_impl(port_, key, version)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_slip132_template__static_method__Api(
+        port_: i64,
+        key: *mut wire_uint_8_list,
+    ) {
+        wire_slip132_template__static_method__Api_impl(port_, key)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_create_script__static_method__Api(
         port_: i64,
@@ -2175,8 +4762,87 @@ mod io {
         wallet_id: *mut wire_uint_8_list,
         psbt_str: *mut wire_uint_8_list,
         sign_options: *mut wire_SignOptions,
+        safety_policy: *mut wire_PsbtSafetyPolicy,
+    ) {
+        wire_sign__static_method__Api_impl(port_, wallet_id, psbt_str, sign_options, safety_policy)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_check_psbt__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
+        policy: *mut wire_PsbtSafetyPolicy,
+    ) {
+        wire_check_psbt__static_method__Api_impl(port_, wallet_id, psbt_str, policy)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_max_sendable__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        destination: *mut wire_Script,
+        fee_rate: f32,
+        utxos: *mut wire_list_out_point,
+    ) {
+        wire_max_sendable__static_method__Api_impl(port_, wallet_id, destination, fee_rate, utxos)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_sweep__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        destination: *mut wire_Script,
+        fee_rate: f32,
+        utxos: *mut wire_list_out_point,
+        enable_rbf: bool,
+    ) {
+        wire_sweep__static_method__Api_impl(
+            port_,
+            wallet_id,
+            destination,
+            fee_rate,
+            utxos,
+            enable_rbf,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_plan_consolidation__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        fee_rate: f32,
+        future_fee_rate: f32,
+        labels: *mut wire_list_utxo_label,
+        max_inputs: *mut u32,
+    ) {
+        wire_plan_consolidation__static_method__Api_impl(
+            port_,
+            wallet_id,
+            fee_rate,
+            future_fee_rate,
+            labels,
+            max_inputs,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_finalize_psbt__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
+        sign_options: *mut wire_SignOptions,
+    ) {
+        wire_finalize_psbt__static_method__Api_impl(port_, wallet_id, psbt_str, sign_options)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_analyze_psbt__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+        psbt_str: *mut wire_uint_8_list,
     ) {
-        wire_sign__static_method__Api_impl(port_, wallet_id, psbt_str, sign_options)
+        wire_analyze_psbt__static_method__Api_impl(port_, wallet_id, psbt_str)
     }
 
     #[no_mangle]
@@ -2221,6 +4887,14 @@ mod io {
         wire_get_descriptor_for_keychain__static_method__Api_impl(port_, wallet_id, keychain)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_get_multipath_descriptor__static_method__Api(
+        port_: i64,
+        wallet_id: *mut wire_uint_8_list,
+    ) {
+        wire_get_multipath_descriptor__static_method__Api_impl(port_, wallet_id)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_generate_seed_from_word_count__static_method__Api(
         port_: i64,
@@ -2247,6 +4921,15 @@ mod io {
 
     // Section: allocate functions
 
+    #[no_mangle]
+    pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
+        let wrap = wire_StringList {
+            ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+            len,
+        };
+        support::new_leak_box_ptr(wrap)
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd___record__out_point_String_usize_0(
     ) -> *mut wire___record__out_point_String_usize {
@@ -2288,6 +4971,26 @@ mod io {
         support::new_leak_box_ptr(wire_LocalUtxo::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_out_point_0() -> *mut wire_OutPoint {
+        support::new_leak_box_ptr(wire_OutPoint::new_with_null_ptr())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_payjoin_params_0() -> *mut wire_PayjoinParams {
+        support::new_leak_box_ptr(wire_PayjoinParams::new_with_null_ptr())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_psbt_map_0() -> *mut wire_PsbtMap {
+        support::new_leak_box_ptr(wire_PsbtMap::new_with_null_ptr())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_psbt_safety_policy_0() -> *mut wire_PsbtSafetyPolicy {
+        support::new_leak_box_ptr(wire_PsbtSafetyPolicy::new_with_null_ptr())
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd_psbt_sig_hash_type_0() -> *mut wire_PsbtSigHashType {
         support::new_leak_box_ptr(wire_PsbtSigHashType::new_with_null_ptr())
@@ -2313,6 +5016,11 @@ mod io {
         support::new_leak_box_ptr(wire_Script::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_shrink_output_0() -> *mut wire_ShrinkOutput {
+        support::new_leak_box_ptr(wire_ShrinkOutput::new_with_null_ptr())
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd_sign_options_0() -> *mut wire_SignOptions {
         support::new_leak_box_ptr(wire_SignOptions::new_with_null_ptr())
@@ -2358,6 +5066,15 @@ mod io {
         support::new_leak_box_ptr(wrap)
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_list_policy_key_0(len: i32) -> *mut wire_list_policy_key {
+        let wrap = wire_list_policy_key {
+            ptr: support::new_leak_vec_ptr(<wire_PolicyKey>::new_with_null_ptr(), len),
+            len,
+        };
+        support::new_leak_box_ptr(wrap)
+    }
+
     #[no_mangle]
     pub extern "C" fn new_list_script_amount_0(len: i32) -> *mut wire_list_script_amount {
         let wrap = wire_list_script_amount {
@@ -2367,6 +5084,15 @@ mod io {
         support::new_leak_box_ptr(wrap)
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_list_utxo_label_0(len: i32) -> *mut wire_list_utxo_label {
+        let wrap = wire_list_utxo_label {
+            ptr: support::new_leak_vec_ptr(<wire_UtxoLabel>::new_with_null_ptr(), len),
+            len,
+        };
+        support::new_leak_box_ptr(wrap)
+    }
+
     #[no_mangle]
     pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
         let ans = wire_uint_8_list {
@@ -2386,6 +5112,15 @@ mod io {
             String::from_utf8_lossy(&vec).into_owned()
         }
     }
+    impl Wire2Api<Vec<String>> for *mut wire_StringList {
+        fn wire2api(self) -> Vec<String> {
+            let vec = unsafe {
+                let wrap = support::box_from_leak_ptr(self);
+                support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(Wire2Api::wire2api).collect()
+        }
+    }
     impl Wire2Api<(OutPoint, String, usize)> for wire___record__out_point_String_usize {
         fn wire2api(self) -> (OutPoint, String, usize) {
             (
@@ -2494,6 +5229,30 @@ mod io {
             Wire2Api::<LocalUtxo>::wire2api(*wrap).into()
         }
     }
+    impl Wire2Api<OutPoint> for *mut wire_OutPoint {
+        fn wire2api(self) -> OutPoint {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<OutPoint>::wire2api(*wrap).into()
+        }
+    }
+    impl Wire2Api<PayjoinParams> for *mut wire_PayjoinParams {
+        fn wire2api(self) -> PayjoinParams {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<PayjoinParams>::wire2api(*wrap).into()
+        }
+    }
+    impl Wire2Api<PsbtMap> for *mut wire_PsbtMap {
+        fn wire2api(self) -> PsbtMap {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<PsbtMap>::wire2api(*wrap).into()
+        }
+    }
+    impl Wire2Api<PsbtSafetyPolicy> for *mut wire_PsbtSafetyPolicy {
+        fn wire2api(self) -> PsbtSafetyPolicy {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<PsbtSafetyPolicy>::wire2api(*wrap).into()
+        }
+    }
     impl Wire2Api<PsbtSigHashType> for *mut wire_PsbtSigHashType {
         fn wire2api(self) -> PsbtSigHashType {
             let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -2524,6 +5283,12 @@ mod io {
             Wire2Api::<Script>::wire2api(*wrap).into()
         }
     }
+    impl Wire2Api<ShrinkOutput> for *mut wire_ShrinkOutput {
+        fn wire2api(self) -> ShrinkOutput {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<ShrinkOutput>::wire2api(*wrap).into()
+        }
+    }
     impl Wire2Api<SignOptions> for *mut wire_SignOptions {
         fn wire2api(self) -> SignOptions {
             let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -2586,6 +5351,7 @@ mod io {
             }
         }
     }
+
     impl Wire2Api<ElectrumConfig> for wire_ElectrumConfig {
         fn wire2api(self) -> ElectrumConfig {
             ElectrumConfig {
@@ -2619,6 +5385,15 @@ mod io {
             vec.into_iter().map(Wire2Api::wire2api).collect()
         }
     }
+    impl Wire2Api<Vec<PolicyKey>> for *mut wire_list_policy_key {
+        fn wire2api(self) -> Vec<PolicyKey> {
+            let vec = unsafe {
+                let wrap = support::box_from_leak_ptr(self);
+                support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(Wire2Api::wire2api).collect()
+        }
+    }
     impl Wire2Api<Vec<ScriptAmount>> for *mut wire_list_script_amount {
         fn wire2api(self) -> Vec<ScriptAmount> {
             let vec = unsafe {
@@ -2628,6 +5403,15 @@ mod io {
             vec.into_iter().map(Wire2Api::wire2api).collect()
         }
     }
+    impl Wire2Api<Vec<UtxoLabel>> for *mut wire_list_utxo_label {
+        fn wire2api(self) -> Vec<UtxoLabel> {
+            let vec = unsafe {
+                let wrap = support::box_from_leak_ptr(self);
+                support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(Wire2Api::wire2api).collect()
+        }
+    }
     impl Wire2Api<LocalUtxo> for wire_LocalUtxo {
         fn wire2api(self) -> LocalUtxo {
             LocalUtxo {
@@ -2647,6 +5431,57 @@ mod io {
             }
         }
     }
+    impl Wire2Api<PayjoinParams> for wire_PayjoinParams {
+        fn wire2api(self) -> PayjoinParams {
+            PayjoinParams {
+                additional_fee_output_index: self.additional_fee_output_index.wire2api(),
+                max_additional_fee_contribution: self.max_additional_fee_contribution.wire2api(),
+                min_fee_rate: self.min_fee_rate.wire2api(),
+                disable_output_substitution: self.disable_output_substitution.wire2api(),
+            }
+        }
+    }
+
+    impl Wire2Api<PolicyKey> for wire_PolicyKey {
+        fn wire2api(self) -> PolicyKey {
+            PolicyKey {
+                name: self.name.wire2api(),
+                key: self.key.wire2api(),
+            }
+        }
+    }
+    impl Wire2Api<PsbtMap> for wire_PsbtMap {
+        fn wire2api(self) -> PsbtMap {
+            match self.tag {
+                0 => PsbtMap::Global,
+                1 => unsafe {
+                    let ans = support::box_from_leak_ptr(self.kind);
+                    let ans = support::box_from_leak_ptr(ans.Input);
+                    PsbtMap::Input {
+                        index: ans.index.wire2api(),
+                    }
+                },
+                2 => unsafe {
+                    let ans = support::box_from_leak_ptr(self.kind);
+                    let ans = support::box_from_leak_ptr(ans.Output);
+                    PsbtMap::Output {
+                        index: ans.index.wire2api(),
+                    }
+                },
+                _ => unreachable!(),
+            }
+        }
+    }
+    impl Wire2Api<PsbtSafetyPolicy> for wire_PsbtSafetyPolicy {
+        fn wire2api(self) -> PsbtSafetyPolicy {
+            PsbtSafetyPolicy {
+                max_fee: self.max_fee.wire2api(),
+                max_fee_percent: self.max_fee_percent.wire2api(),
+                require_non_witness_utxo: self.require_non_witness_utxo.wire2api(),
+                refuse_unsafe: self.refuse_unsafe.wire2api(),
+            }
+        }
+    }
     impl Wire2Api<PsbtSigHashType> for wire_PsbtSigHashType {
         fn wire2api(self) -> PsbtSigHashType {
             PsbtSigHashType {
@@ -2704,6 +5539,34 @@ mod io {
             }
         }
     }
+    impl Wire2Api<ShrinkOutput> for wire_ShrinkOutput {
+        fn wire2api(self) -> ShrinkOutput {
+            match self.tag {
+                0 => unsafe {
+                    let ans = support::box_from_leak_ptr(self.kind);
+                    let ans = support::box_from_leak_ptr(ans.Address);
+                    ShrinkOutput::Address {
+                        address: ans.address.wire2api(),
+                    }
+                },
+                1 => unsafe {
+                    let ans = support::box_from_leak_ptr(self.kind);
+                    let ans = support::box_from_leak_ptr(ans.Script);
+                    ShrinkOutput::Script {
+                        script: ans.script.wire2api(),
+                    }
+                },
+                2 => unsafe {
+                    let ans = support::box_from_leak_ptr(self.kind);
+                    let ans = support::box_from_leak_ptr(ans.Index);
+                    ShrinkOutput::Index {
+                        index: ans.index.wire2api(),
+                    }
+                },
+                _ => unreachable!(),
+            }
+        }
+    }
     impl Wire2Api<SignOptions> for wire_SignOptions {
         fn wire2api(self) -> SignOptions {
             SignOptions {
@@ -2726,6 +5589,7 @@ mod io {
             }
         }
     }
+
     impl Wire2Api<SqliteDbConfiguration> for wire_SqliteDbConfiguration {
         fn wire2api(self) -> SqliteDbConfiguration {
             SqliteDbConfiguration {
@@ -2759,8 +5623,24 @@ mod io {
         }
     }
 
+    impl Wire2Api<UtxoLabel> for wire_UtxoLabel {
+        fn wire2api(self) -> UtxoLabel {
+            UtxoLabel {
+                outpoint: self.outpoint.wire2api(),
+                label: self.label.wire2api(),
+            }
+        }
+    }
+
     // Section: wire structs
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_StringList {
+        ptr: *mut *mut wire_uint_8_list,
+        len: i32,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire___record__out_point_String_usize {
@@ -2797,6 +5677,13 @@ mod io {
         len: i32,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_list_policy_key {
+        ptr: *mut wire_PolicyKey,
+        len: i32,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_list_script_amount {
@@ -2804,6 +5691,13 @@ mod io {
         len: i32,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_list_utxo_label {
+        ptr: *mut wire_UtxoLabel,
+        len: i32,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_LocalUtxo {
@@ -2820,6 +5714,31 @@ mod io {
         vout: u32,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PayjoinParams {
+        additional_fee_output_index: *mut u32,
+        max_additional_fee_contribution: *mut u64,
+        min_fee_rate: *mut f32,
+        disable_output_substitution: bool,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PolicyKey {
+        name: *mut wire_uint_8_list,
+        key: *mut wire_uint_8_list,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PsbtSafetyPolicy {
+        max_fee: *mut u64,
+        max_fee_percent: *mut f32,
+        require_non_witness_utxo: bool,
+        refuse_unsafe: bool,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_PsbtSigHashType {
@@ -2906,6 +5825,13 @@ mod io {
         password: *mut wire_uint_8_list,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_UtxoLabel {
+        outpoint: wire_OutPoint,
+        label: *mut wire_uint_8_list,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_AddressIndex {
@@ -3002,6 +5928,36 @@ mod io {
         config: *mut wire_SledDbConfiguration,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PsbtMap {
+        tag: i32,
+        kind: *mut PsbtMapKind,
+    }
+
+    #[repr(C)]
+    pub union PsbtMapKind {
+        Global: *mut wire_PsbtMap_Global,
+        Input: *mut wire_PsbtMap_Input,
+        Output: *mut wire_PsbtMap_Output,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PsbtMap_Global {}
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PsbtMap_Input {
+        index: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_PsbtMap_Output {
+        index: u32,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_RbfValue {
@@ -3025,6 +5981,38 @@ mod io {
         field0: u32,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ShrinkOutput {
+        tag: i32,
+        kind: *mut ShrinkOutputKind,
+    }
+
+    #[repr(C)]
+    pub union ShrinkOutputKind {
+        Address: *mut wire_ShrinkOutput_Address,
+        Script: *mut wire_ShrinkOutput_Script,
+        Index: *mut wire_ShrinkOutput_Index,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ShrinkOutput_Address {
+        address: *mut wire_uint_8_list,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ShrinkOutput_Script {
+        script: *mut wire_Script,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ShrinkOutput_Index {
+        index: u32,
+    }
+
     // Section: impl NewWithNullPtr
 
     pub trait NewWithNullPtr {
@@ -3230,6 +6218,88 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_PayjoinParams {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                additional_fee_output_index: core::ptr::null_mut(),
+                max_additional_fee_contribution: core::ptr::null_mut(),
+                min_fee_rate: core::ptr::null_mut(),
+                disable_output_substitution: Default::default(),
+            }
+        }
+    }
+
+    impl Default for wire_PayjoinParams {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    impl NewWithNullPtr for wire_PolicyKey {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                name: core::ptr::null_mut(),
+                key: core::ptr::null_mut(),
+            }
+        }
+    }
+
+    impl Default for wire_PolicyKey {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    impl Default for wire_PsbtMap {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    impl NewWithNullPtr for wire_PsbtMap {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: core::ptr::null_mut(),
+            }
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn inflate_PsbtMap_Input() -> *mut PsbtMapKind {
+        support::new_leak_box_ptr(PsbtMapKind {
+            Input: support::new_leak_box_ptr(wire_PsbtMap_Input {
+                index: Default::default(),
+            }),
+        })
+    }
+
+    #[no_mangle]
+    pub extern "C" fn inflate_PsbtMap_Output() -> *mut PsbtMapKind {
+        support::new_leak_box_ptr(PsbtMapKind {
+            Output: support::new_leak_box_ptr(wire_PsbtMap_Output {
+                index: Default::default(),
+            }),
+        })
+    }
+
+    impl NewWithNullPtr for wire_PsbtSafetyPolicy {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                max_fee: core::ptr::null_mut(),
+                max_fee_percent: core::ptr::null_mut(),
+                require_non_witness_utxo: Default::default(),
+                refuse_unsafe: Default::default(),
+            }
+        }
+    }
+
+    impl Default for wire_PsbtSafetyPolicy {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
     impl NewWithNullPtr for wire_PsbtSigHashType {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -3333,6 +6403,48 @@ mod io {
         }
     }
 
+    impl Default for wire_ShrinkOutput {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    impl NewWithNullPtr for wire_ShrinkOutput {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: core::ptr::null_mut(),
+            }
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn inflate_ShrinkOutput_Address() -> *mut ShrinkOutputKind {
+        support::new_leak_box_ptr(ShrinkOutputKind {
+            Address: support::new_leak_box_ptr(wire_ShrinkOutput_Address {
+                address: core::ptr::null_mut(),
+            }),
+        })
+    }
+
+    #[no_mangle]
+    pub extern "C" fn inflate_ShrinkOutput_Script() -> *mut ShrinkOutputKind {
+        support::new_leak_box_ptr(ShrinkOutputKind {
+            Script: support::new_leak_box_ptr(wire_ShrinkOutput_Script {
+                script: core::ptr::null_mut(),
+            }),
+        })
+    }
+
+    #[no_mangle]
+    pub extern "C" fn inflate_ShrinkOutput_Index() -> *mut ShrinkOutputKind {
+        support::new_leak_box_ptr(ShrinkOutputKind {
+            Index: support::new_leak_box_ptr(wire_ShrinkOutput_Index {
+                index: Default::default(),
+            }),
+        })
+    }
+
     impl NewWithNullPtr for wire_SignOptions {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -3413,6 +6525,21 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_UtxoLabel {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                outpoint: Default::default(),
+                label: core::ptr::null_mut(),
+            }
+        }
+    }
+
+    impl Default for wire_UtxoLabel {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
     // Section: sync execution mode utility
 
     #[no_mangle]
@@ -0,0 +1,550 @@
+use crate::descriptor::{strip_checksum, BdkDescriptor, MultisigScriptType};
+use crate::key::{DescriptorPublicKey, DescriptorSecretKey};
+use aes::cipher::{KeyIvInit, StreamCipher};
+use bdk::bitcoin::hashes::hex::{FromHex, ToHex};
+use bdk::bitcoin::hashes::hmac::{Hmac, HmacEngine};
+use bdk::bitcoin::hashes::{sha256, sha512, Hash, HashEngine};
+use bdk::bitcoin::secp256k1::{Message, Secp256k1};
+use bdk::bitcoin::util::bip32::{DerivationPath as BdkDerivationPath, ExtendedPubKey, Fingerprint};
+use bdk::bitcoin::util::misc::{signed_msg_hash, MessageSignature};
+use bdk::bitcoin::Network;
+use bdk::descriptor::checksum::calc_checksum;
+use bdk::keys::{
+    DescriptorPublicKey as BdkDescriptorPublicKey, DescriptorSecretKey as BdkDescriptorSecretKey,
+};
+use bdk::miniscript::descriptor::{DescriptorXKey, Wildcard};
+use bdk::Error as BdkError;
+use bdk::KeychainKind;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+type Aes256Ctr = ctr::Ctr128BE<aes::Aes256>;
+
+const BSMS_VERSION: &str = "BSMS 1.0";
+/// The token of a setup without encryption.
+const NO_TOKEN: &str = "00";
+/// The derivation paths the descriptor record restricts the wallet to.
+const PATH_RESTRICTIONS: &str = "/0/*,/1/*";
+const NO_PATH_RESTRICTIONS: &str = "No path restrictions";
+const PBKDF2_SALT: &[u8] = b"No SPOF";
+const PBKDF2_ROUNDS: usize = 2048;
+
+/// How the records of a BSMS setup are protected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BsmsEncryption {
+    /// Plain text records, the token is `00`.
+    NoEncryption,
+    /// Records encrypted with a 64-bit token.
+    Standard,
+    /// Records encrypted with a 128-bit token.
+    Extended,
+}
+
+/// A signer key record, checked by the coordinator.
+pub struct BsmsKeyRecord {
+    pub token: String,
+    /// The signer key with its origin, e.g. `[fingerprint/48'/0'/0'/2']xpub...`.
+    pub key: String,
+    pub description: String,
+    /// The signature of the record by the key, in the Bitcoin signed message format.
+    pub signature: String,
+}
+
+/// A descriptor record, checked by a signer.
+pub struct BsmsDescriptorRecord {
+    /// The descriptor template, with `/**` for the external and internal keychains.
+    pub template: String,
+    pub descriptor: String,
+    pub change_descriptor: String,
+    /// The first external address, which the signer should also show to the user.
+    pub first_address: String,
+}
+
+/// Generate the token the coordinator shares with each signer.
+pub(crate) fn new_token(encryption: BsmsEncryption) -> String {
+    match encryption {
+        BsmsEncryption::NoEncryption => NO_TOKEN.to_string(),
+        BsmsEncryption::Standard => rand::random::<[u8; 8]>().to_hex(),
+        BsmsEncryption::Extended => rand::random::<[u8; 16]>().to_hex(),
+    }
+}
+
+/// Create the key record of a signer, signed by its account key such as
+/// `[fingerprint/48'/0'/0'/2']tprv...`. The record is encrypted unless the token is `00`.
+pub(crate) fn key_record(
+    secret_key: &DescriptorSecretKey,
+    token: &str,
+    description: &str,
+) -> Result<String, BdkError> {
+    let token_bytes = parse_token(token)?;
+    if description.contains('\n') {
+        return Err(BdkError::Generic(
+            "The key description must be a single line".to_string(),
+        ));
+    }
+    let secp = Secp256k1::new();
+    let xprv = match secret_key
+        .descriptor_secret_key_mutex
+        .lock()
+        .unwrap()
+        .deref()
+    {
+        BdkDescriptorSecretKey::XPrv(xprv) => xprv.clone(),
+        BdkDescriptorSecretKey::Single(_) => {
+            return Err(BdkError::Generic(
+                "BSMS signer keys must be extended keys".to_string(),
+            ))
+        }
+    };
+    let xpub = ExtendedPubKey::from_priv(&secp, &xprv.xkey);
+    if !xprv.derivation_path.is_master() {
+        // The error names the public key, the secret one must not end up in logs.
+        return Err(BdkError::Generic(format!(
+            "BSMS signer key {} must not have derivation steps",
+            xpub
+        )));
+    }
+    let key = signer_key(xprv.origin, xpub);
+    let message = [BSMS_VERSION, &token.to_lowercase(), &key, description].join("\n");
+    let hash = Message::from_slice(&signed_msg_hash(&message)[..])
+        .map_err(|e| BdkError::Generic(e.to_string()))?;
+    let signature = secp.sign_ecdsa_recoverable(&hash, &xprv.xkey.private_key);
+    let record = format!(
+        "{}\n{}",
+        message,
+        MessageSignature::new(signature, true).to_base64()
+    );
+    Ok(encrypt(token_bytes.as_deref(), &record))
+}
+
+/// Decrypt and check a signer key record: its token, key and signature.
+pub(crate) fn verify_key_record(record: &str, token: &str) -> Result<BsmsKeyRecord, BdkError> {
+    let token_bytes = parse_token(token)?;
+    let record = decrypt(token_bytes.as_deref(), record)?;
+    let lines: Vec<&str> = record.trim().lines().collect();
+    let (version, record_token, key, description, signature) = match lines[..] {
+        [version, token, key, description, signature] => {
+            (version, token, key, description, signature)
+        }
+        _ => return Err(BdkError::Generic("Invalid BSMS key record".to_string())),
+    };
+    check_version(version)?;
+    if !record_token.eq_ignore_ascii_case(token) {
+        return Err(BdkError::Generic(format!(
+            "The key record token {} is not the setup token",
+            record_token
+        )));
+    }
+    let xpub = match parse_key(key)? {
+        BdkDescriptorPublicKey::XPub(xpub)
+            if xpub.derivation_path.is_master() && xpub.wildcard == Wildcard::None =>
+        {
+            xpub.xkey
+        }
+        _ => {
+            return Err(BdkError::Generic(format!(
+                "Invalid BSMS signer key {}, expected an account extended key",
+                key
+            )))
+        }
+    };
+    let secp = Secp256k1::verification_only();
+    let message = lines[..4].join("\n");
+    let signed_by_key = MessageSignature::from_base64(signature)
+        .and_then(|x| x.recover_pubkey(&secp, signed_msg_hash(&message)))
+        .map(|public_key| public_key.inner == xpub.public_key)
+        .unwrap_or(false);
+    if !signed_by_key {
+        return Err(BdkError::Generic(format!(
+            "The key record is not signed by {}",
+            key
+        )));
+    }
+    Ok(BsmsKeyRecord {
+        token: record_token.to_string(),
+        key: key.to_string(),
+        description: description.to_string(),
+        signature: signature.to_string(),
+    })
+}
+
+/// Create the descriptor record of a `threshold`-of-n `sortedmulti` wallet from the keys of the
+/// verified key records. The record is encrypted unless the token is `00`.
+pub(crate) fn descriptor_record(
+    threshold: u32,
+    keys: Vec<String>,
+    script_type: MultisigScriptType,
+    token: &str,
+    network: Network,
+) -> Result<String, BdkError> {
+    let token_bytes = parse_token(token)?;
+    let public_keys = keys
+        .iter()
+        .map(|key| {
+            Ok(Arc::new(DescriptorPublicKey {
+                descriptor_public_key_mutex: Mutex::new(parse_key(key)?),
+            }))
+        })
+        .collect::<Result<Vec<_>, BdkError>>()?;
+    let descriptor = BdkDescriptor::new_multisig(
+        threshold,
+        public_keys,
+        None,
+        script_type,
+        true,
+        KeychainKind::External,
+        network,
+    )?;
+    let external = descriptor.as_string();
+    let template = strip_checksum(&external)?.replace("/0/*", "/**");
+    let first_address = descriptor
+        .extended_descriptor
+        .at_derivation_index(0)
+        .address(network)?;
+    let record = [
+        BSMS_VERSION.to_string(),
+        format!("{}#{}", template, calc_checksum(&template)?),
+        PATH_RESTRICTIONS.to_string(),
+        first_address.to_string(),
+    ]
+    .join("\n");
+    Ok(encrypt(token_bytes.as_deref(), &record))
+}
+
+/// Decrypt and check a descriptor record as the signer with `secret_key`: the record must have
+/// our key, and its first address must be the one of the descriptor. Returns the descriptors to
+/// create the wallet with.
+pub(crate) fn verify_descriptor_record(
+    record: &str,
+    token: &str,
+    secret_key: &DescriptorSecretKey,
+    network: Network,
+) -> Result<BsmsDescriptorRecord, BdkError> {
+    let token_bytes = parse_token(token)?;
+    let record = decrypt(token_bytes.as_deref(), record)?;
+    let lines: Vec<&str> = record.trim().lines().collect();
+    let (version, template, path_restrictions, first_address) = match lines[..] {
+        [version, template, path_restrictions, first_address] => {
+            (version, template, path_restrictions, first_address)
+        }
+        _ => {
+            return Err(BdkError::Generic(
+                "Invalid BSMS descriptor record".to_string(),
+            ))
+        }
+    };
+    check_version(version)?;
+    if path_restrictions != PATH_RESTRICTIONS && path_restrictions != NO_PATH_RESTRICTIONS {
+        return Err(BdkError::Generic(format!(
+            "Unsupported path restrictions {}",
+            path_restrictions
+        )));
+    }
+    let template = strip_checksum(template)?;
+    if !template.contains("/**") {
+        return Err(BdkError::Generic(
+            "The descriptor template has no /** keys".to_string(),
+        ));
+    }
+    let descriptor = BdkDescriptor::new(template.replace("/**", "/0/*"), network)?;
+    let change_descriptor = BdkDescriptor::new(template.replace("/**", "/1/*"), network)?;
+
+    let secp = Secp256k1::new();
+    let xpub = match secret_key
+        .descriptor_secret_key_mutex
+        .lock()
+        .unwrap()
+        .deref()
+    {
+        BdkDescriptorSecretKey::XPrv(xprv) => ExtendedPubKey::from_priv(&secp, &xprv.xkey),
+        BdkDescriptorSecretKey::Single(_) => {
+            return Err(BdkError::Generic(
+                "BSMS signer keys must be extended keys".to_string(),
+            ))
+        }
+    };
+    let has_key = descriptor
+        .multisig()
+        .map(|(_, keys)| {
+            keys.iter().any(|key| match key {
+                BdkDescriptorPublicKey::XPub(key) => key.xkey == xpub,
+                BdkDescriptorPublicKey::Single(_) => false,
+            })
+        })
+        .unwrap_or(false);
+    if !has_key {
+        return Err(BdkError::Generic(format!(
+            "The descriptor record does not have our key {}",
+            xpub
+        )));
+    }
+    let address = descriptor
+        .extended_descriptor
+        .at_derivation_index(0)
+        .address(network)?
+        .to_string();
+    if address != first_address {
+        return Err(BdkError::Generic(format!(
+            "The first address of the descriptor is {}, not {}",
+            address, first_address
+        )));
+    }
+    Ok(BsmsDescriptorRecord {
+        template: format!("{}#{}", template, calc_checksum(template)?),
+        descriptor: descriptor.as_string(),
+        change_descriptor: change_descriptor.as_string(),
+        first_address: address,
+    })
+}
+
+/// The key of a key record: the account extended public key with its origin.
+fn signer_key(origin: Option<(Fingerprint, BdkDerivationPath)>, xpub: ExtendedPubKey) -> String {
+    BdkDescriptorPublicKey::XPub(DescriptorXKey {
+        origin,
+        xkey: xpub,
+        derivation_path: BdkDerivationPath::master(),
+        wildcard: Wildcard::None,
+    })
+    .to_string()
+}
+
+fn parse_key(key: &str) -> Result<BdkDescriptorPublicKey, BdkError> {
+    BdkDescriptorPublicKey::from_str(key)
+        .map_err(|e| BdkError::Generic(format!("Invalid BSMS signer key {}: {}", key, e)))
+}
+
+fn check_version(version: &str) -> Result<(), BdkError> {
+    if version == BSMS_VERSION {
+        Ok(())
+    } else {
+        Err(BdkError::Generic(format!(
+            "Unsupported BSMS version {}",
+            version
+        )))
+    }
+}
+
+/// The token bytes, `None` for `00` which disables encryption.
+fn parse_token(token: &str) -> Result<Option<Vec<u8>>, BdkError> {
+    if token == NO_TOKEN {
+        return Ok(None);
+    }
+    match Vec::<u8>::from_hex(token) {
+        Ok(bytes) if bytes.len() == 8 || bytes.len() == 16 => Ok(Some(bytes)),
+        _ => Err(BdkError::Generic(format!(
+            "Invalid BSMS token {}, expected 00 or 16 or 32 hex characters",
+            token
+        ))),
+    }
+}
+
+/// The AES-256 key of the records: the first 32 bytes of PBKDF2-HMAC-SHA512 with the token
+/// bytes, not their hex, as password and "No SPOF" as salt.
+fn encryption_key(token: &[u8]) -> [u8; 32] {
+    let hmac = |data: &[&[u8]]| {
+        let mut engine = HmacEngine::<sha512::Hash>::new(token);
+        for data in data {
+            engine.input(data);
+        }
+        Hmac::<sha512::Hash>::from_engine(engine).into_inner()
+    };
+    let mut round = hmac(&[PBKDF2_SALT, &1u32.to_be_bytes()[..]]);
+    let mut block = round;
+    for _ in 1..PBKDF2_ROUNDS {
+        round = hmac(&[&round[..]]);
+        block
+            .iter_mut()
+            .zip(round.iter())
+            .for_each(|(x, y)| *x ^= y);
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&block[..32]);
+    key
+}
+
+/// HMAC-SHA256 of the token and the plain text, keyed with the SHA256 of the encryption key.
+fn record_mac(key: &[u8; 32], token: &[u8], data: &[u8]) -> [u8; 32] {
+    let mac_key = sha256::Hash::hash(key);
+    let mut engine = HmacEngine::<sha256::Hash>::new(&mac_key[..]);
+    engine.input(token);
+    engine.input(data);
+    Hmac::<sha256::Hash>::from_engine(engine).into_inner()
+}
+
+/// Encrypt a record with AES-256-CTR, using the first 16 bytes of its MAC as the IV. The result
+/// is the hex of the MAC followed by the cipher text.
+fn encrypt(token: Option<&[u8]>, record: &str) -> String {
+    let token = match token {
+        Some(token) => token,
+        None => return record.to_string(),
+    };
+    let key = encryption_key(token);
+    let mac = record_mac(&key, token, record.as_bytes());
+    let mut iv = [0u8; 16];
+    iv.copy_from_slice(&mac[..16]);
+    let mut data = record.as_bytes().to_vec();
+    Aes256Ctr::new(&key.into(), &iv.into()).apply_keystream(&mut data);
+    [&mac[..], &data].concat().to_hex()
+}
+
+fn decrypt(token: Option<&[u8]>, record: &str) -> Result<String, BdkError> {
+    let token = match token {
+        Some(token) => token,
+        None => return Ok(record.to_string()),
+    };
+    let invalid_record = || BdkError::Generic("Invalid encrypted BSMS record".to_string());
+    let data = Vec::<u8>::from_hex(record.trim()).map_err(|_| invalid_record())?;
+    if data.len() <= 32 {
+        return Err(invalid_record());
+    }
+    let (mac, data) = data.split_at(32);
+    let key = encryption_key(token);
+    let mut iv = [0u8; 16];
+    iv.copy_from_slice(&mac[..16]);
+    let mut data = data.to_vec();
+    Aes256Ctr::new(&key.into(), &iv.into()).apply_keystream(&mut data);
+    if record_mac(&key, token, &data)[..] != mac[..] {
+        return Err(BdkError::Generic(
+            "The BSMS record MAC does not match, check the token".to_string(),
+        ));
+    }
+    String::from_utf8(data).map_err(|_| invalid_record())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bsms::{
+        decrypt, descriptor_record, encrypt, encryption_key, key_record, new_token, parse_token,
+        record_mac, verify_descriptor_record, verify_key_record, BsmsEncryption,
+    };
+    use crate::descriptor::MultisigScriptType;
+    use crate::key::{DerivationPath, DescriptorSecretKey, Mnemonic};
+    use bdk::bitcoin::hashes::hex::ToHex;
+    use bdk::bitcoin::Network;
+    use std::sync::Arc;
+
+    fn signer_key(account: u32) -> Arc<DescriptorSecretKey> {
+        let mnemonic = Mnemonic::from_str("chaos fabric time speed sponsor all flat solution wisdom trophy crack object robot pave observe combine where aware bench orient secret primary cable detect".to_string()).unwrap();
+        DescriptorSecretKey::new(Network::Testnet, mnemonic, None)
+            .unwrap()
+            .derive(Arc::new(
+                DerivationPath::new(format!("m/48h/1h/{}h/2h", account)).unwrap(),
+            ))
+            .unwrap()
+    }
+
+    fn setup(encryption: BsmsEncryption) {
+        let token = new_token(encryption);
+        let signers = [signer_key(0), signer_key(1)];
+        let keys: Vec<String> = signers
+            .iter()
+            .enumerate()
+            .map(|(i, signer)| {
+                let record = key_record(signer, &token, &format!("Signer {}", i)).unwrap();
+                let verified = verify_key_record(&record, &token).unwrap();
+                assert_eq!(verified.description, format!("Signer {}", i));
+                assert!(verified.key.starts_with("[d1d04177/48'/1'/"));
+                verified.key
+            })
+            .collect();
+        let record =
+            descriptor_record(2, keys, MultisigScriptType::P2wsh, &token, Network::Testnet)
+                .unwrap();
+        for signer in signers.iter() {
+            let verified =
+                verify_descriptor_record(&record, &token, signer, Network::Testnet).unwrap();
+            assert!(verified.template.starts_with("wsh(sortedmulti(2,"));
+            assert!(verified.template.contains("/**"));
+            assert!(verified.descriptor.contains("/0/*"));
+            assert!(verified.change_descriptor.contains("/1/*"));
+        }
+        assert!(
+            verify_descriptor_record(&record, &token, &signer_key(2), Network::Testnet).is_err()
+        );
+    }
+
+    #[test]
+    fn test_unencrypted_setup() {
+        assert_eq!(new_token(BsmsEncryption::NoEncryption), "00");
+        setup(BsmsEncryption::NoEncryption);
+
+        let record = key_record(&signer_key(0), "00", "Signer").unwrap();
+        assert!(record.starts_with("BSMS 1.0\n00\n[d1d04177/48'/1'/0'/2']tpub"));
+        let forged = record.replace("Signer", "Forged");
+        assert!(verify_key_record(&forged, "00").is_err());
+
+        let record = descriptor_record(
+            1,
+            vec![verify_key_record(&record, "00").unwrap().key],
+            MultisigScriptType::P2wsh,
+            "00",
+            Network::Testnet,
+        )
+        .unwrap();
+        let mut lines: Vec<&str> = record.lines().collect();
+        lines[3] = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+        assert!(verify_descriptor_record(
+            &lines.join("\n"),
+            "00",
+            &signer_key(0),
+            Network::Testnet
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_encrypted_setup() {
+        assert_eq!(new_token(BsmsEncryption::Standard).len(), 16);
+        assert_eq!(new_token(BsmsEncryption::Extended).len(), 32);
+        setup(BsmsEncryption::Standard);
+        setup(BsmsEncryption::Extended);
+
+        let token = new_token(BsmsEncryption::Standard);
+        let record = key_record(&signer_key(0), &token, "Signer").unwrap();
+        assert!(!record.contains("BSMS"));
+        let other_token = new_token(BsmsEncryption::Standard);
+        assert!(verify_key_record(&record, &other_token).is_err());
+        assert!(key_record(&signer_key(0), "abcd", "Signer").is_err());
+    }
+
+    #[test]
+    fn test_encryption_vectors() {
+        // Computed following BIP129 with Python's hashlib and OpenSSL's AES-256-CTR: the token
+        // enters PBKDF2 and the MAC as raw bytes.
+        let token = parse_token("1f5c9d9fa0458ae2").unwrap().unwrap();
+        let key = encryption_key(&token);
+        assert_eq!(
+            key.to_hex(),
+            "6a33e558f52f46c6af5b3c48983229ad5110e4d16aa20d92d6a0b0701dce33fb"
+        );
+        // The token is not used as its hex string.
+        assert_ne!(encryption_key(b"1f5c9d9fa0458ae2"), key);
+        let record = "BSMS 1.0\n1f5c9d9fa0458ae2\nSigner 1\n";
+        assert_eq!(
+            record_mac(&key, &token, record.as_bytes()).to_hex(),
+            "d98370662ca7833019654d0ad75511dc3cf852235282aea4f2afbf905c79ccd5"
+        );
+        let encrypted = encrypt(Some(&token), record);
+        assert_eq!(
+            encrypted,
+            "d98370662ca7833019654d0ad75511dc3cf852235282aea4f2afbf905c79ccd5\
+             dfa4407dad9135bb9ad3f65d03b03b1ac56ed09d38827826ae488d6acea74a45fb6508"
+        );
+        assert_eq!(decrypt(Some(&token), &encrypted).unwrap(), record);
+
+        let token = parse_token("a54044308ceac9b7d03b7e8f3c2a1b60")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            encryption_key(&token).to_hex(),
+            "0ff0f20d83a80e1b1ef8ff621b25a2fec7ad62c0432f2396cec05f87a855a411"
+        );
+        let encrypted = "bcf8f8124e5efc26cb0e8a1658f9ff3b833508b33b0f3b2c9cc297dd6bd8db31\
+                         eba1834dba32f9f123bf967b6892ff3f56f7e12377a18f6bd8221416ebb11aabeccbec\
+                         22d0496e925f5ca29b0e765e1248d32e";
+        let record = "BSMS 1.0\na54044308ceac9b7d03b7e8f3c2a1b60\nSigner 2\n";
+        assert_eq!(encrypt(Some(&token), record), encrypted);
+        assert_eq!(decrypt(Some(&token), encrypted).unwrap(), record);
+    }
+}
@@ -1,17 +1,29 @@
-use crate::key::{DescriptorPublicKey, DescriptorSecretKey};
+use crate::key::{slip132_to_standard, DescriptorPublicKey, DescriptorSecretKey, Slip132Version};
+use crate::psbt::KeyOrigin;
+use crate::types;
 use bdk::bitcoin::secp256k1::Secp256k1;
-use bdk::bitcoin::util::bip32::Fingerprint;
-use bdk::bitcoin::Network;
+use bdk::bitcoin::util::bip32::{
+    ChildNumber, DerivationPath as BdkDerivationPath, ExtendedPubKey, Fingerprint, KeySource,
+};
+use bdk::bitcoin::{Network, Script};
+use bdk::descriptor::checksum::calc_checksum;
 use bdk::descriptor::{ExtendedDescriptor, IntoWalletDescriptor, KeyMap};
 use bdk::keys::{
     DescriptorPublicKey as BdkDescriptorPublicKey, DescriptorSecretKey as BdkDescriptorSecretKey,
 };
-use bdk::miniscript::Error;
+use bdk::miniscript::descriptor::{
+    DescriptorType as MsDescriptorType, DescriptorXKey, ShInner, Wildcard, WshInner,
+};
+use bdk::miniscript::policy::Concrete;
+use bdk::miniscript::{
+    DefiniteDescriptorKey, Descriptor, Error, ForEachKey, Segwitv0, Terminal, Translator,
+};
 use bdk::template::{
     Bip44, Bip44Public, Bip49, Bip49Public, Bip84, Bip84Public, DescriptorTemplate,
 };
 use bdk::Error as BdkError;
 use bdk::KeychainKind;
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -36,22 +48,20 @@ impl BdkDescriptor {
         secret_key: Arc<DescriptorSecretKey>,
         keychain_kind: KeychainKind,
         network: Network,
-    ) -> Self {
+    ) -> Result<Self, BdkError> {
         let derivable_key = secret_key.descriptor_secret_key_mutex.lock().unwrap();
 
         match derivable_key.deref() {
             BdkDescriptorSecretKey::XPrv(descriptor_x_key) => {
                 let derivable_key = descriptor_x_key.xkey;
                 let (extended_descriptor, key_map, _) =
-                    Bip44(derivable_key, keychain_kind).build(network).unwrap();
-                Self {
+                    Bip44(derivable_key, keychain_kind).build(network)?;
+                Ok(Self {
                     extended_descriptor,
                     key_map,
-                }
-            }
-            BdkDescriptorSecretKey::Single(_) => {
-                unreachable!()
+                })
             }
+            BdkDescriptorSecretKey::Single(_) => Err(single_key_template_error("BIP44")),
         }
     }
 
@@ -60,26 +70,23 @@ impl BdkDescriptor {
         fingerprint: String,
         keychain_kind: KeychainKind,
         network: Network,
-    ) -> Self {
-        let fingerprint = Fingerprint::from_str(fingerprint.as_str()).unwrap();
+    ) -> Result<Self, BdkError> {
+        let fingerprint = Fingerprint::from_str(fingerprint.as_str())
+            .map_err(|_| BdkError::Generic(format!("Invalid fingerprint {}", fingerprint)))?;
         let derivable_key = public_key.descriptor_public_key_mutex.lock().unwrap();
 
         match derivable_key.deref() {
             BdkDescriptorPublicKey::XPub(descriptor_x_key) => {
                 let derivable_key = descriptor_x_key.xkey;
                 let (extended_descriptor, key_map, _) =
-                    Bip44Public(derivable_key, fingerprint, keychain_kind)
-                        .build(network)
-                        .unwrap();
+                    Bip44Public(derivable_key, fingerprint, keychain_kind).build(network)?;
 
-                Self {
+                Ok(Self {
                     extended_descriptor,
                     key_map,
-                }
-            }
-            BdkDescriptorPublicKey::Single(_) => {
-                unreachable!()
+                })
             }
+            BdkDescriptorPublicKey::Single(_) => Err(single_key_template_error("BIP44")),
         }
     }
 
@@ -87,22 +94,20 @@ impl BdkDescriptor {
         secret_key: Arc<DescriptorSecretKey>,
         keychain_kind: KeychainKind,
         network: Network,
-    ) -> Self {
+    ) -> Result<Self, BdkError> {
         let derivable_key = secret_key.descriptor_secret_key_mutex.lock().unwrap();
 
         match derivable_key.deref() {
             BdkDescriptorSecretKey::XPrv(descriptor_x_key) => {
                 let derivable_key = descriptor_x_key.xkey;
                 let (extended_descriptor, key_map, _) =
-                    Bip49(derivable_key, keychain_kind).build(network).unwrap();
-                Self {
+                    Bip49(derivable_key, keychain_kind).build(network)?;
+                Ok(Self {
                     extended_descriptor,
                     key_map,
-                }
-            }
-            BdkDescriptorSecretKey::Single(_) => {
-                unreachable!()
+                })
             }
+            BdkDescriptorSecretKey::Single(_) => Err(single_key_template_error("BIP49")),
         }
     }
 
@@ -111,26 +116,23 @@ impl BdkDescriptor {
         fingerprint: String,
         keychain_kind: KeychainKind,
         network: Network,
-    ) -> Self {
-        let fingerprint = Fingerprint::from_str(fingerprint.as_str()).unwrap();
+    ) -> Result<Self, BdkError> {
+        let fingerprint = Fingerprint::from_str(fingerprint.as_str())
+            .map_err(|_| BdkError::Generic(format!("Invalid fingerprint {}", fingerprint)))?;
         let derivable_key = public_key.descriptor_public_key_mutex.lock().unwrap();
 
         match derivable_key.deref() {
             BdkDescriptorPublicKey::XPub(descriptor_x_key) => {
                 let derivable_key = descriptor_x_key.xkey;
                 let (extended_descriptor, key_map, _) =
-                    Bip49Public(derivable_key, fingerprint, keychain_kind)
-                        .build(network)
-                        .unwrap();
+                    Bip49Public(derivable_key, fingerprint, keychain_kind).build(network)?;
 
-                Self {
+                Ok(Self {
                     extended_descriptor,
                     key_map,
-                }
-            }
-            BdkDescriptorPublicKey::Single(_) => {
-                unreachable!()
+                })
             }
+            BdkDescriptorPublicKey::Single(_) => Err(single_key_template_error("BIP49")),
         }
     }
 
@@ -138,22 +140,20 @@ impl BdkDescriptor {
         secret_key: Arc<DescriptorSecretKey>,
         keychain_kind: KeychainKind,
         network: Network,
-    ) -> Self {
+    ) -> Result<Self, BdkError> {
         let derivable_key = secret_key.descriptor_secret_key_mutex.lock().unwrap();
 
         match derivable_key.deref() {
             BdkDescriptorSecretKey::XPrv(descriptor_x_key) => {
                 let derivable_key = descriptor_x_key.xkey;
                 let (extended_descriptor, key_map, _) =
-                    Bip84(derivable_key, keychain_kind).build(network).unwrap();
-                Self {
+                    Bip84(derivable_key, keychain_kind).build(network)?;
+                Ok(Self {
                     extended_descriptor,
                     key_map,
-                }
-            }
-            BdkDescriptorSecretKey::Single(_) => {
-                unreachable!()
+                })
             }
+            BdkDescriptorSecretKey::Single(_) => Err(single_key_template_error("BIP84")),
         }
     }
 
@@ -162,29 +162,181 @@ impl BdkDescriptor {
         fingerprint: String,
         keychain_kind: KeychainKind,
         network: Network,
-    ) -> Self {
-        let fingerprint = Fingerprint::from_str(fingerprint.as_str()).unwrap();
+    ) -> Result<Self, BdkError> {
+        let fingerprint = Fingerprint::from_str(fingerprint.as_str())
+            .map_err(|_| BdkError::Generic(format!("Invalid fingerprint {}", fingerprint)))?;
         let derivable_key = public_key.descriptor_public_key_mutex.lock().unwrap();
 
         match derivable_key.deref() {
             BdkDescriptorPublicKey::XPub(descriptor_x_key) => {
                 let derivable_key = descriptor_x_key.xkey;
                 let (extended_descriptor, key_map, _) =
-                    Bip84Public(derivable_key, fingerprint, keychain_kind)
-                        .build(network)
-                        .unwrap();
+                    Bip84Public(derivable_key, fingerprint, keychain_kind).build(network)?;
 
-                Self {
+                Ok(Self {
                     extended_descriptor,
                     key_map,
-                }
+                })
+            }
+            BdkDescriptorPublicKey::Single(_) => Err(single_key_template_error("BIP84")),
+        }
+    }
+
+    /// Build a `pkh()`, `sh(wpkh())`, `wpkh()` or `tr()` descriptor for a single secret key, such
+    /// as an imported paper wallet key.
+    pub(crate) fn new_single_key(
+        secret_key: Arc<DescriptorSecretKey>,
+        script_type: DescriptorType,
+        network: Network,
+    ) -> Result<Self, BdkError> {
+        let key = secret_key.descriptor_secret_key_mutex.lock().unwrap();
+        match &*key {
+            BdkDescriptorSecretKey::Single(single) => {
+                // Errors name the public key, the secret one must not end up in logs.
+                let public_key = single.key.public_key(&Secp256k1::new());
+                check_key_network(single.key.network, network, &public_key.to_string())?;
+                single_key_descriptor(key.to_string(), script_type, network)
             }
+            BdkDescriptorSecretKey::XPrv(_) => Err(BdkError::Generic(
+                "Extended keys need a derivation path, use the BIP templates instead".to_string(),
+            )),
+        }
+    }
+
+    /// Build a watch-only `pkh()`, `sh(wpkh())`, `wpkh()` or `tr()` descriptor for a single
+    /// public key.
+    pub(crate) fn new_single_key_public(
+        public_key: Arc<DescriptorPublicKey>,
+        script_type: DescriptorType,
+        network: Network,
+    ) -> Result<Self, BdkError> {
+        let key = public_key
+            .descriptor_public_key_mutex
+            .lock()
+            .unwrap()
+            .clone();
+        match key {
             BdkDescriptorPublicKey::Single(_) => {
-                unreachable!()
+                single_key_descriptor(key.to_string(), script_type, network)
             }
+            BdkDescriptorPublicKey::XPub(_) => Err(BdkError::Generic(
+                "Extended keys need a derivation path, use the BIP templates instead".to_string(),
+            )),
         }
     }
 
+    /// Build a `threshold`-of-n multisig descriptor for `keychain_kind` from account-level
+    /// cosigner keys, such as `[fingerprint/48'/1'/0'/2']tpub...`. Each key is extended with
+    /// `/0/*` for the external keychain or `/1/*` for the internal one. `secret_key`, if any, is
+    /// our own cosigner key and comes first in the key list.
+    ///
+    /// Taproot multisigs can't be `sorted`: miniscript has no `sortedmulti_a` yet.
+    pub(crate) fn new_multisig(
+        threshold: u32,
+        public_keys: Vec<Arc<DescriptorPublicKey>>,
+        secret_key: Option<Arc<DescriptorSecretKey>>,
+        script_type: MultisigScriptType,
+        sorted: bool,
+        keychain_kind: KeychainKind,
+        network: Network,
+    ) -> Result<Self, BdkError> {
+        if let (MultisigScriptType::P2tr, true) = (script_type, sorted) {
+            return Err(BdkError::Generic(
+                "Sorted taproot multisigs (sortedmulti_a) are not supported".to_string(),
+            ));
+        }
+        let secp = Secp256k1::new();
+        let keychain_path = BdkDerivationPath::from(vec![ChildNumber::Normal {
+            index: match keychain_kind {
+                KeychainKind::External => 0,
+                KeychainKind::Internal => 1,
+            },
+        }]);
+        let mut keys: Vec<String> = Vec::new();
+        let mut cosigners: Vec<(Option<KeySource>, ExtendedPubKey)> = Vec::new();
+        if let Some(secret_key) = secret_key {
+            let secret_key = secret_key.descriptor_secret_key_mutex.lock().unwrap();
+            let xprv = match &*secret_key {
+                BdkDescriptorSecretKey::XPrv(xprv) => xprv.clone(),
+                BdkDescriptorSecretKey::Single(_) => {
+                    return Err(BdkError::Generic(
+                        "Multisig cosigner keys must be extended keys".to_string(),
+                    ))
+                }
+            };
+            // Errors name the public key, the secret one must not end up in logs.
+            let xpub = ExtendedPubKey::from_priv(&secp, &xprv.xkey);
+            check_account_key(&xprv.derivation_path, &xpub.to_string())?;
+            check_key_network(xprv.xkey.network, network, &xpub.to_string())?;
+            cosigners.push((xprv.origin.clone(), xpub));
+            keys.push(
+                BdkDescriptorSecretKey::XPrv(DescriptorXKey {
+                    derivation_path: keychain_path.clone(),
+                    wildcard: Wildcard::Unhardened,
+                    ..xprv
+                })
+                .to_string(),
+            );
+        }
+        for key in public_keys.iter() {
+            let key = key.descriptor_public_key_mutex.lock().unwrap().clone();
+            let xpub = match key {
+                BdkDescriptorPublicKey::XPub(xpub) => xpub,
+                BdkDescriptorPublicKey::Single(_) => {
+                    return Err(BdkError::Generic(
+                        "Multisig cosigner keys must be extended keys".to_string(),
+                    ))
+                }
+            };
+            check_account_key(&xpub.derivation_path, &xpub.xkey.to_string())?;
+            check_key_network(xpub.xkey.network, network, &xpub.xkey.to_string())?;
+            // The same key, or two keys claiming the same origin, can't be two cosigners.
+            if cosigners.iter().any(|(origin, x)| {
+                x.public_key == xpub.xkey.public_key || (origin.is_some() && *origin == xpub.origin)
+            }) {
+                return Err(BdkError::Generic(format!(
+                    "Duplicate cosigner key {}",
+                    xpub.xkey
+                )));
+            }
+            cosigners.push((xpub.origin.clone(), xpub.xkey));
+            keys.push(
+                BdkDescriptorPublicKey::XPub(DescriptorXKey {
+                    derivation_path: keychain_path.clone(),
+                    wildcard: Wildcard::Unhardened,
+                    ..xpub
+                })
+                .to_string(),
+            );
+        }
+        let max_keys = match script_type {
+            MultisigScriptType::P2sh => 15,
+            MultisigScriptType::P2shP2wsh | MultisigScriptType::P2wsh => 20,
+            MultisigScriptType::P2tr => 999,
+        };
+        if threshold == 0 || threshold as usize > keys.len() || keys.len() > max_keys {
+            return Err(BdkError::Generic(format!(
+                "Invalid {}-of-{} multisig, at most {} keys are allowed",
+                threshold,
+                keys.len(),
+                max_keys
+            )));
+        }
+        let multi = match (script_type, sorted) {
+            (MultisigScriptType::P2tr, _) => "multi_a",
+            (_, true) => "sortedmulti",
+            (_, false) => "multi",
+        };
+        let multi = format!("{}({},{})", multi, threshold, keys.join(","));
+        let descriptor = match script_type {
+            MultisigScriptType::P2sh => format!("sh({})", multi),
+            MultisigScriptType::P2shP2wsh => format!("sh(wsh({}))", multi),
+            MultisigScriptType::P2wsh => format!("wsh({})", multi),
+            MultisigScriptType::P2tr => format!("tr({},{})", UNSPENDABLE_KEY, multi),
+        };
+        BdkDescriptor::new(descriptor, network)
+    }
+
     pub(crate) fn as_string_private(&self) -> String {
         let descriptor = &self.extended_descriptor;
         let key_map = &self.key_map;
@@ -198,13 +350,714 @@ impl BdkDescriptor {
         let descriptor = &self.extended_descriptor;
         descriptor.max_satisfaction_weight()
     }
+
+    /// Find the derived descriptor producing `script_pubkey`.
+    ///
+    /// The indexes in `hints` (usually taken from the BIP32 derivations of a PSBT) are tried first,
+    /// then the first `lookahead` indexes, which must stay below the first hardened index.
+    pub(crate) fn find_derivation(
+        &self,
+        script_pubkey: &Script,
+        hints: impl IntoIterator<Item = ChildNumber>,
+        lookahead: u32,
+    ) -> Option<Descriptor<DefiniteDescriptorKey>> {
+        let descriptor = &self.extended_descriptor;
+        if !descriptor.has_wildcard() {
+            let definite = descriptor.at_derivation_index(0);
+            return (&definite.script_pubkey() == script_pubkey).then_some(definite);
+        }
+        hints
+            .into_iter()
+            .filter_map(|x| match x {
+                ChildNumber::Normal { index } => Some(index),
+                ChildNumber::Hardened { .. } => None,
+            })
+            .chain(0..lookahead)
+            .map(|index| descriptor.at_derivation_index(index))
+            .find(|x| &x.script_pubkey() == script_pubkey)
+    }
+
+    /// Derive the addresses at `count` indexes starting at `start`, with the keys each one was
+    /// derived from. A descriptor without wildcard only has the address at index 0.
+    ///
+    /// Fails if `count` is above `MAX_DERIVE_ADDRESSES`.
+    pub(crate) fn derive_addresses(
+        &self,
+        network: Network,
+        start: u32,
+        count: u32,
+    ) -> Result<Vec<DerivedAddress>, BdkError> {
+        if count > MAX_DERIVE_ADDRESSES {
+            return Err(BdkError::Generic(format!(
+                "At most {} addresses can be derived at once",
+                MAX_DERIVE_ADDRESSES
+            )));
+        }
+        let secp = Secp256k1::verification_only();
+        let descriptor = &self.extended_descriptor;
+        let indexes = if descriptor.has_wildcard() {
+            match start.checked_add(count) {
+                Some(end) if end <= 1 << 31 => start..end,
+                _ => {
+                    return Err(BdkError::Generic(
+                        "Derivation index out of range".to_string(),
+                    ))
+                }
+            }
+        } else {
+            0..1
+        };
+        let is_taproot = matches!(descriptor, Descriptor::Tr(_));
+        indexes
+            .map(|index| -> Result<DerivedAddress, BdkError> {
+                let derived = descriptor.at_derivation_index(index);
+                let address = derived.address(network)?;
+                let mut keys = Vec::new();
+                let mut error = None;
+                derived.for_each_key(|key| {
+                    match key.derive_public_key(&secp) {
+                        Ok(public_key) => keys.push(KeyOrigin {
+                            public_key: if is_taproot {
+                                public_key.inner.x_only_public_key().0.to_string()
+                            } else {
+                                public_key.to_string()
+                            },
+                            fingerprint: key.master_fingerprint().to_string(),
+                            path: key.full_derivation_path().to_string(),
+                        }),
+                        Err(e) => error = Some(e),
+                    }
+                    error.is_none()
+                });
+                if let Some(e) = error {
+                    return Err(BdkError::Generic(format!("Can't derive key: {}", e)));
+                }
+                Ok(DerivedAddress {
+                    index,
+                    address: address.to_string(),
+                    script_pubkey: address.script_pubkey().into(),
+                    keys,
+                })
+            })
+            .collect()
+    }
+
+    /// The threshold and keys of a `multi` or `sortedmulti` descriptor, or of a taproot
+    /// descriptor with a single `multi_a` leaf. `None` for other descriptors.
+    pub(crate) fn multisig(&self) -> Option<(usize, Vec<BdkDescriptorPublicKey>)> {
+        match &self.extended_descriptor {
+            Descriptor::Sh(sh) => match sh.as_inner() {
+                ShInner::SortedMulti(smv) => Some((smv.k, smv.pks.clone())),
+                ShInner::Ms(ms) => match &ms.node {
+                    Terminal::Multi(k, pks) => Some((*k, pks.clone())),
+                    _ => None,
+                },
+                ShInner::Wsh(wsh) => wsh_multisig(wsh.as_inner()),
+                ShInner::Wpkh(_) => None,
+            },
+            Descriptor::Wsh(wsh) => wsh_multisig(wsh.as_inner()),
+            Descriptor::Tr(tr) => {
+                let leaves: Vec<_> = tr.iter_scripts().collect();
+                match leaves.as_slice() {
+                    [(_, ms)] => match &ms.node {
+                        Terminal::MultiA(k, pks) => Some((*k, pks.clone())),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Describe the structure and keys of the descriptor.
+    pub(crate) fn info(&self) -> DescriptorInfo {
+        let descriptor = &self.extended_descriptor;
+        let (descriptor_type, is_sorted_multi) = match descriptor.desc_type() {
+            MsDescriptorType::Bare => (DescriptorType::Bare, false),
+            MsDescriptorType::Pkh => (DescriptorType::Pkh, false),
+            MsDescriptorType::Wpkh => (DescriptorType::Wpkh, false),
+            MsDescriptorType::ShWpkh => (DescriptorType::ShWpkh, false),
+            MsDescriptorType::Sh => (DescriptorType::Sh, false),
+            MsDescriptorType::Wsh => (DescriptorType::Wsh, false),
+            MsDescriptorType::ShWsh => (DescriptorType::ShWsh, false),
+            MsDescriptorType::Tr => (DescriptorType::Tr, false),
+            MsDescriptorType::ShSortedMulti => (DescriptorType::Sh, true),
+            MsDescriptorType::WshSortedMulti => (DescriptorType::Wsh, true),
+            MsDescriptorType::ShWshSortedMulti => (DescriptorType::ShWsh, true),
+        };
+        let mut keys = Vec::new();
+        let mut networks = Vec::new();
+        descriptor.for_each_key(|key| {
+            let origin = match key {
+                BdkDescriptorPublicKey::Single(single) => single.origin.as_ref(),
+                BdkDescriptorPublicKey::XPub(xpub) => {
+                    networks.push(xpub.xkey.network);
+                    xpub.origin.as_ref()
+                }
+            };
+            keys.push(DescriptorKeyInfo {
+                key: key.to_string(),
+                origin_fingerprint: origin.map(|(fingerprint, _)| fingerprint.to_string()),
+                origin_path: origin.map(|(_, path)| path.to_string()),
+                master_fingerprint: key.master_fingerprint().to_string(),
+                full_derivation_path: key.full_derivation_path().to_string(),
+                has_wildcard: key.has_wildcard(),
+                is_private: self.key_map.contains_key(key),
+            });
+            true
+        });
+        for secret_key in self.key_map.values() {
+            networks.push(match secret_key {
+                BdkDescriptorSecretKey::Single(single) => single.key.network,
+                BdkDescriptorSecretKey::XPrv(xprv) => xprv.xkey.network,
+            });
+        }
+        // Extended keys only tell mainnet from the test networks.
+        let network = match networks.first() {
+            Some(first)
+                if networks
+                    .iter()
+                    .all(|x| (*x == Network::Bitcoin) == (*first == Network::Bitcoin)) =>
+            {
+                Some(match first {
+                    Network::Bitcoin => Network::Bitcoin,
+                    _ => Network::Testnet,
+                })
+            }
+            _ => None,
+        };
+        let descriptor_string = descriptor.to_string();
+        DescriptorInfo {
+            descriptor_type,
+            threshold: self.multisig().map(|(k, _)| k as u32),
+            is_sorted_multi,
+            has_wildcard: descriptor.has_wildcard(),
+            is_multipath: false,
+            keys,
+            checksum: descriptor_string
+                .split('#')
+                .nth(1)
+                .unwrap_or_default()
+                .to_string(),
+            has_private_keys: !self.key_map.is_empty(),
+            network: network.map(|x| x.into()),
+        }
+    }
+}
+
+/// The most addresses `derive_addresses` derives in one call.
+pub(crate) const MAX_DERIVE_ADDRESSES: u32 = 10_000;
+
+/// The BIP341 point with no known discrete logarithm, used as the internal key of taproot
+/// multisig descriptors so they can only be spent through the script path.
+const UNSPENDABLE_KEY: &str = "50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0";
+
+fn single_key_template_error(template: &str) -> BdkError {
+    BdkError::Generic(format!(
+        "{} descriptors need an extended key, use a single key descriptor instead",
+        template
+    ))
+}
+
+fn single_key_descriptor(
+    key: String,
+    script_type: DescriptorType,
+    network: Network,
+) -> Result<BdkDescriptor, BdkError> {
+    let descriptor = match script_type {
+        DescriptorType::Pkh => format!("pkh({})", key),
+        DescriptorType::ShWpkh => format!("sh(wpkh({}))", key),
+        DescriptorType::Wpkh => format!("wpkh({})", key),
+        DescriptorType::Tr => format!("tr({})", key),
+        _ => {
+            return Err(BdkError::Generic(format!(
+                "Unsupported single key script type {:?}",
+                script_type
+            )))
+        }
+    };
+    BdkDescriptor::new(descriptor, network)
+}
+
+fn check_account_key(derivation_path: &BdkDerivationPath, key: &str) -> Result<(), BdkError> {
+    if derivation_path.is_master() {
+        Ok(())
+    } else {
+        Err(BdkError::Generic(format!(
+            "Multisig cosigner key {} must not have derivation steps",
+            key
+        )))
+    }
+}
+
+fn check_key_network(key_network: Network, network: Network, key: &str) -> Result<(), BdkError> {
+    if (key_network == Network::Bitcoin) == (network == Network::Bitcoin) {
+        Ok(())
+    } else {
+        Err(BdkError::Generic(format!(
+            "Key {} is not for network {}",
+            key, network
+        )))
+    }
+}
+
+fn wsh_multisig(
+    wsh: &WshInner<BdkDescriptorPublicKey>,
+) -> Option<(usize, Vec<BdkDescriptorPublicKey>)> {
+    match wsh {
+        WshInner::SortedMulti(smv) => Some((smv.k, smv.pks.clone())),
+        WshInner::Ms(ms) => match &ms.node {
+            Terminal::Multi(k, pks) => Some((*k, pks.clone())),
+            _ => None,
+        },
+    }
+}
+
+/// Describe a descriptor given as a string, which may be a BIP389 multipath descriptor. The
+/// network is not checked, it is inferred from the keys instead.
+pub(crate) fn describe(descriptor: &str) -> Result<DescriptorInfo, BdkError> {
+    let secp = Secp256k1::new();
+    let body = strip_checksum(descriptor)?;
+    let checksum = calc_checksum(body)?;
+    let mut descriptors = Vec::new();
+    for branch in expand_multipath(body)? {
+        let (extended_descriptor, key_map) = ExtendedDescriptor::parse_descriptor(&secp, &branch)?;
+        descriptors.push(BdkDescriptor {
+            extended_descriptor,
+            key_map,
+        });
+    }
+    let mut info = descriptors[0].info();
+    info.is_multipath = descriptors.len() > 1;
+    info.checksum = checksum;
+    Ok(info)
+}
+
+/// Compile a concrete spending policy such as `or(pk(A),and(pk(B),older(4320)))` into a `wsh`
+/// or `tr` descriptor. `keys` gives a descriptor public or secret key for each key name of the
+/// policy; secret keys end up in the descriptor's key map.
+pub(crate) fn compile_policy(
+    policy: &str,
+    keys: Vec<PolicyKey>,
+    context: PolicyContext,
+    network: Network,
+) -> Result<CompiledPolicy, BdkError> {
+    let secp = Secp256k1::new();
+    let mut names = HashMap::new();
+    let mut key_map = KeyMap::new();
+    for PolicyKey { name, key } in keys {
+        let public_key = match BdkDescriptorPublicKey::from_str(&key) {
+            Ok(e) => e,
+            Err(_) => {
+                let secret_key = BdkDescriptorSecretKey::from_str(&key)
+                    .map_err(|e| BdkError::Generic(format!("Invalid key for {}: {}", name, e)))?;
+                let public_key = secret_key
+                    .to_public(&secp)
+                    .map_err(|e| BdkError::Generic(format!("Invalid key for {}: {}", name, e)))?;
+                key_map.insert(public_key.clone(), secret_key);
+                public_key
+            }
+        };
+        names.insert(name, public_key.to_string());
+    }
+    // Keys are substituted in the policy text, then the policy is parsed again with actual keys.
+    let named_policy = Concrete::<String>::from_str(policy)?;
+    let policy = named_policy
+        .translate_pk(&mut KeyNames(&names))?
+        .to_string();
+    let policy = Concrete::<BdkDescriptorPublicKey>::from_str(&policy)?;
+    let descriptor = match context {
+        PolicyContext::Wsh => {
+            let miniscript = policy
+                .compile::<Segwitv0>()
+                .map_err(|e| BdkError::Generic(format!("Can't compile the policy: {}", e)))?;
+            Descriptor::new_wsh(miniscript)?
+        }
+        PolicyContext::Tr => {
+            let unspendable_key = BdkDescriptorPublicKey::from_str(UNSPENDABLE_KEY)
+                .expect("The unspendable key is valid");
+            policy.compile_tr(Some(unspendable_key))?
+        }
+    };
+    let descriptor = BdkDescriptor::new(descriptor.to_string_with_secret(&key_map), network)?;
+    let is_non_malleable = match &descriptor.extended_descriptor {
+        Descriptor::Wsh(wsh) => match wsh.as_inner() {
+            WshInner::Ms(ms) => ms.is_non_malleable(),
+            WshInner::SortedMulti(_) => true,
+        },
+        Descriptor::Tr(tr) => tr.iter_scripts().all(|(_, ms)| ms.is_non_malleable()),
+        _ => true,
+    };
+    let sanity_error = descriptor
+        .extended_descriptor
+        .sanity_check()
+        .err()
+        .map(|e| e.to_string());
+    Ok(CompiledPolicy {
+        descriptor: descriptor.as_string_private(),
+        max_satisfaction_weight: descriptor.max_satisfaction_weight()? as u64,
+        is_sane: sanity_error.is_none(),
+        sanity_error,
+        is_non_malleable,
+    })
+}
+
+/// Replaces the key names of a policy with the keys given for them.
+struct KeyNames<'a>(&'a HashMap<String, String>);
+impl Translator<String, String, BdkError> for KeyNames<'_> {
+    fn pk(&mut self, pk: &String) -> Result<String, BdkError> {
+        self.0
+            .get(pk)
+            .cloned()
+            .ok_or_else(|| BdkError::Generic(format!("No key given for {}", pk)))
+    }
+    fn sha256(&mut self, sha256: &String) -> Result<String, BdkError> {
+        Ok(sha256.clone())
+    }
+    fn hash256(&mut self, hash256: &String) -> Result<String, BdkError> {
+        Ok(hash256.clone())
+    }
+    fn ripemd160(&mut self, ripemd160: &String) -> Result<String, BdkError> {
+        Ok(ripemd160.clone())
+    }
+    fn hash160(&mut self, hash160: &String) -> Result<String, BdkError> {
+        Ok(hash160.clone())
+    }
+}
+
+/// Convert a SLIP-132 extended public key, optionally with an origin such as
+/// `[fingerprint/84'/0'/0']zpub...`, and give the descriptor template its version stands for.
+pub(crate) fn slip132_template(key: &str) -> Result<Slip132Template, BdkError> {
+    let key = key.trim();
+    let (origin, encoded_key) = match key.find(']') {
+        Some(end) => key.split_at(end + 1),
+        None => ("", key),
+    };
+    let (xpub, version) = slip132_to_standard(encoded_key)?;
+    let standard_key = format!("{}{}", origin, xpub);
+    BdkDescriptorPublicKey::from_str(&standard_key)
+        .map_err(|e| BdkError::Generic(format!("Invalid key origin in {}: {}", key, e)))?;
+    let multipath_key = format!("{}/<0;1>/*", standard_key);
+    let (descriptor, multisig_script_type) = match version {
+        Slip132Version::Standard => (None, None),
+        Slip132Version::NestedSegwit => (Some(format!("sh(wpkh({}))", multipath_key)), None),
+        Slip132Version::Segwit => (Some(format!("wpkh({})", multipath_key)), None),
+        Slip132Version::NestedSegwitMultisig => (None, Some(MultisigScriptType::P2shP2wsh)),
+        Slip132Version::SegwitMultisig => (None, Some(MultisigScriptType::P2wsh)),
+    };
+    let descriptor = match descriptor {
+        Some(descriptor) => {
+            let checksum = calc_checksum(&descriptor)?;
+            Some(format!("{}#{}", descriptor, checksum))
+        }
+        None => None,
+    };
+    Ok(Slip132Template {
+        key: standard_key,
+        version,
+        descriptor,
+        multisig_script_type,
+    })
+}
+
+/// Remove the checksum of a descriptor, after checking it if there is one.
+pub(crate) fn strip_checksum(descriptor: &str) -> Result<&str, BdkError> {
+    let mut parts = descriptor.trim().splitn(2, '#');
+    let body = parts.next().unwrap_or_default();
+    if let Some(expected) = parts.next() {
+        let checksum = calc_checksum(body)?;
+        if expected != checksum {
+            return Err(BdkError::Generic(format!(
+                "Invalid descriptor checksum {}, expected {}",
+                expected, checksum
+            )));
+        }
+    }
+    Ok(body)
+}
+
+/// Split a BIP389 multipath descriptor with two paths, e.g. `/<0;1>/*`, into its external and
+/// internal descriptors. `None` if the descriptor is not multipath.
+pub(crate) fn split_multipath(descriptor: &str) -> Result<Option<(String, String)>, BdkError> {
+    let mut branches = expand_multipath(strip_checksum(descriptor)?)?;
+    match branches.len() {
+        1 => Ok(None),
+        2 => {
+            let internal = branches.pop().unwrap();
+            let external = branches.pop().unwrap();
+            Ok(Some((external, internal)))
+        }
+        _ => Err(BdkError::Generic(
+            "A wallet multipath descriptor must have exactly two paths, for the external and \
+             internal keychains"
+                .to_string(),
+        )),
+    }
+}
+
+/// Merge the external and internal descriptors of a wallet into a BIP389 multipath descriptor,
+/// e.g. `/0/*` and `/1/*` into `/<0;1>/*`. The descriptors may only differ in one derivation step
+/// of each key, after its origin.
+pub(crate) fn merge_multipath(external: &str, internal: &str) -> Result<String, BdkError> {
+    let tokenize = |descriptor: &str| -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut current = String::new();
+        for c in descriptor.chars() {
+            if "/,()[]{}*".contains(c) {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            } else {
+                current.push(c);
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+        tokens
+    };
+    let is_step = |token: &str| {
+        let digits = token.trim_end_matches(['h', '\'']);
+        !digits.is_empty() && digits.len() + 1 >= token.len() && digits.parse::<u32>().is_ok()
+    };
+    let external = tokenize(strip_checksum(external)?);
+    let internal = tokenize(strip_checksum(internal)?);
+    let mismatch = || {
+        BdkError::Generic("The descriptors differ in more than their derivation steps".to_string())
+    };
+    if external.len() != internal.len() {
+        return Err(mismatch());
+    }
+    let mut merged = String::new();
+    let mut is_multipath = false;
+    // Key origins must match, and BIP389 allows one multipath step per key.
+    let mut in_origin = false;
+    let mut key_is_multipath = false;
+    for (i, (a, b)) in external.iter().zip(internal.iter()).enumerate() {
+        if a == b {
+            match a.as_str() {
+                "[" => in_origin = true,
+                "]" => in_origin = false,
+                "," | "(" | ")" | "{" | "}" => key_is_multipath = false,
+                _ => {}
+            }
+            merged.push_str(a);
+        } else if !in_origin
+            && !key_is_multipath
+            && i > 0
+            && external[i - 1] == "/"
+            && is_step(a)
+            && is_step(b)
+        {
+            merged.push_str(&format!("<{};{}>", a, b));
+            is_multipath = true;
+            key_is_multipath = true;
+        } else {
+            return Err(mismatch());
+        }
+    }
+    if !is_multipath {
+        return Err(BdkError::Generic(
+            "The external and internal descriptors are the same".to_string(),
+        ));
+    }
+    let checksum = calc_checksum(&merged)?;
+    Ok(format!("{}#{}", merged, checksum))
+}
+
+/// Expand a BIP389 multipath descriptor into one descriptor per path, e.g. `/<0;1>/*` into a
+/// descriptor ending in `/0/*` and one ending in `/1/*`. Other descriptors are returned as is.
+/// The descriptor must not carry a checksum.
+pub(crate) fn expand_multipath(descriptor: &str) -> Result<Vec<String>, BdkError> {
+    let mut pieces: Vec<&str> = Vec::new();
+    let mut paths: Vec<Vec<&str>> = Vec::new();
+    let mut rest = descriptor;
+    while let Some(start) = rest.find('<') {
+        let end = match rest[start..].find('>') {
+            Some(e) => start + e,
+            None => {
+                return Err(BdkError::Generic(
+                    "Unterminated multipath derivation step".to_string(),
+                ))
+            }
+        };
+        let steps: Vec<&str> = rest[start + 1..end].split(';').collect();
+        let is_duplicated = |i: usize| steps[..i].contains(&steps[i]);
+        if steps.len() < 2
+            || steps.iter().any(|x| x.is_empty())
+            || (1..steps.len()).any(is_duplicated)
+        {
+            return Err(BdkError::Generic(format!(
+                "Invalid multipath derivation step <{}>",
+                &rest[start + 1..end]
+            )));
+        }
+        if paths.first().is_some_and(|x| x.len() != steps.len()) {
+            return Err(BdkError::Generic(
+                "Every multipath derivation step must have the same number of paths".to_string(),
+            ));
+        }
+        pieces.push(&rest[..start]);
+        paths.push(steps);
+        rest = &rest[end + 1..];
+    }
+    if rest.contains('>') {
+        return Err(BdkError::Generic(
+            "Unexpected '>' in descriptor".to_string(),
+        ));
+    }
+    let count = paths.first().map_or(1, |x| x.len());
+    Ok((0..count)
+        .map(|i| {
+            let mut branch = String::new();
+            for (piece, steps) in pieces.iter().zip(paths.iter()) {
+                branch.push_str(piece);
+                branch.push_str(steps[i]);
+            }
+            branch.push_str(rest);
+            branch
+        })
+        .collect())
+}
+
+/// An address derived from a descriptor.
+pub struct DerivedAddress {
+    pub index: u32,
+    pub address: String,
+    pub script_pubkey: types::Script,
+    /// The keys of the address, with the full path they were derived at from their master key.
+    pub keys: Vec<KeyOrigin>,
+}
+
+/// The kind of descriptor a policy is compiled to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyContext {
+    /// A segwit v0 `wsh()` descriptor.
+    Wsh,
+    /// A taproot `tr()` descriptor. The internal key is the most likely key of the policy, or an
+    /// unspendable key if no key can spend alone.
+    Tr,
+}
+
+/// A key of a policy: its name in the policy and the descriptor public or secret key it stands
+/// for.
+pub struct PolicyKey {
+    pub name: String,
+    pub key: String,
+}
+
+/// A descriptor compiled from a policy, with its analysis.
+pub struct CompiledPolicy {
+    /// The descriptor, including the secret keys given for the policy.
+    pub descriptor: String,
+    pub max_satisfaction_weight: u64,
+    /// Whether the descriptor passes the miniscript sanity checks: no mixed timelocks, every
+    /// spending path needs a signature and can't be malleated, and no repeated keys.
+    pub is_sane: bool,
+    /// Why the sanity checks failed.
+    pub sanity_error: Option<String>,
+    /// Whether third parties can't malleate the satisfactions of the descriptor.
+    pub is_non_malleable: bool,
+}
+
+/// A SLIP-132 extended public key converted to a standard one, with the descriptor template of
+/// its version.
+pub struct Slip132Template {
+    /// The key as a standard xpub or tpub, with its origin if it had one.
+    pub key: String,
+    pub version: Slip132Version,
+    /// The BIP389 multipath wallet descriptor of the single signature versions, e.g.
+    /// `wpkh([fingerprint/84'/0'/0']xpub.../<0;1>/*)`. `None` for `xpub`/`tpub`, which may be
+    /// `pkh()` or `sh()` multisig.
+    pub descriptor: Option<String>,
+    /// The script type of the multisig versions, to build the descriptor with once every cosigner
+    /// key is known.
+    pub multisig_script_type: Option<MultisigScriptType>,
+}
+
+/// The external and change descriptors of a multisig wallet.
+pub struct MultisigDescriptors {
+    pub descriptor: String,
+    pub change_descriptor: String,
+}
+
+/// The script type of a multisig descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultisigScriptType {
+    /// Legacy `sh(multi(...))`, at most 15 keys.
+    P2sh,
+    /// Nested segwit `sh(wsh(multi(...)))`.
+    P2shP2wsh,
+    /// Native segwit `wsh(multi(...))`.
+    P2wsh,
+    /// Taproot `tr(<unspendable key>,multi_a(...))`.
+    P2tr,
+}
+
+/// The script type of a descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescriptorType {
+    Bare,
+    Pkh,
+    Wpkh,
+    ShWpkh,
+    Sh,
+    Wsh,
+    ShWsh,
+    Tr,
+}
+
+/// A key of a descriptor.
+#[derive(Debug, Clone)]
+pub struct DescriptorKeyInfo {
+    /// The public key as written in the descriptor, with its origin and derivation steps.
+    pub key: String,
+    /// The master key fingerprint from the key origin, if the key has one.
+    pub origin_fingerprint: Option<String>,
+    /// The derivation path from the key origin, if the key has one.
+    pub origin_path: Option<String>,
+    /// The origin fingerprint, or the fingerprint of the key itself without an origin.
+    pub master_fingerprint: String,
+    /// The path from the master key, up to but excluding a wildcard.
+    pub full_derivation_path: String,
+    pub has_wildcard: bool,
+    /// Whether the descriptor carries the private key.
+    pub is_private: bool,
+}
+
+/// The structure and keys of a descriptor.
+#[derive(Clone)]
+pub struct DescriptorInfo {
+    pub descriptor_type: DescriptorType,
+    /// The threshold of a multisig descriptor (`multi`, `sortedmulti` or a single `multi_a` leaf).
+    pub threshold: Option<u32>,
+    /// Whether the descriptor uses `sortedmulti`.
+    pub is_sorted_multi: bool,
+    pub has_wildcard: bool,
+    /// Whether the descriptor has BIP389 multipath keys, e.g. `/<0;1>/*`.
+    pub is_multipath: bool,
+    pub keys: Vec<DescriptorKeyInfo>,
+    pub checksum: String,
+    pub has_private_keys: bool,
+    /// The network of the keys: `Bitcoin`, `Testnet` for any test network, or `None` when no key
+    /// tells or the keys disagree.
+    pub network: Option<types::Network>,
 }
 #[cfg(test)]
 mod test {
-    use crate::descriptor::BdkDescriptor;
-    use crate::key::{DerivationPath, DescriptorSecretKey, Mnemonic};
+    use crate::descriptor::{
+        compile_policy, describe, expand_multipath, merge_multipath, slip132_template,
+        split_multipath, strip_checksum, BdkDescriptor, DescriptorType, MultisigScriptType,
+        PolicyContext, PolicyKey, MAX_DERIVE_ADDRESSES,
+    };
+    use crate::key::{
+        DerivationPath, DescriptorPublicKey, DescriptorSecretKey, Mnemonic, Slip132Version,
+    };
+    use crate::types;
     use assert_matches::assert_matches;
-    use bdk::bitcoin::Network;
+    use bdk::bitcoin::{Address as BdkAddress, Network};
     use bdk::descriptor::DescriptorError::Key;
     use bdk::keys::KeyError::InvalidNetwork;
     use bdk::KeychainKind;
@@ -248,11 +1101,13 @@ mod test {
         println!("Public 84: {}", handmade_public_84.as_string());
         // Public 84: [d1d04177/84'/1'/0']tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6/*
         let template_private_44 =
-            BdkDescriptor::new_bip44(master.clone(), KeychainKind::External, Network::Testnet);
+            BdkDescriptor::new_bip44(master.clone(), KeychainKind::External, Network::Testnet)
+                .unwrap();
         let template_private_49 =
-            BdkDescriptor::new_bip49(master.clone(), KeychainKind::External, Network::Testnet);
+            BdkDescriptor::new_bip49(master.clone(), KeychainKind::External, Network::Testnet)
+                .unwrap();
         let template_private_84 =
-            BdkDescriptor::new_bip84(master, KeychainKind::External, Network::Testnet);
+            BdkDescriptor::new_bip84(master, KeychainKind::External, Network::Testnet).unwrap();
         // the extended public keys are the same when creating them manually as they are with the templates
         println!("Template 49: {}", template_private_49.as_string());
         println!("Template 44: {}", template_private_44.as_string());
@@ -263,19 +1118,22 @@ mod test {
             "d1d04177".to_string(),
             KeychainKind::External,
             Network::Testnet,
-        );
+        )
+        .unwrap();
         let template_public_49 = BdkDescriptor::new_bip49_public(
             handmade_public_49,
             "d1d04177".to_string(),
             KeychainKind::External,
             Network::Testnet,
-        );
+        )
+        .unwrap();
         let template_public_84 = BdkDescriptor::new_bip84_public(
             handmade_public_84,
             "d1d04177".to_string(),
             KeychainKind::External,
             Network::Testnet,
-        );
+        )
+        .unwrap();
         println!("Template public 49: {}", template_public_49.as_string());
         println!("Template public 44: {}", template_public_44.as_string());
         println!("Template public 84: {}", template_public_84.as_string());
@@ -317,4 +1175,356 @@ mod test {
             bdk::Error::Descriptor(Key(InvalidNetwork))
         )
     }
+    #[test]
+    fn test_descriptor_info() {
+        let descriptor = "wsh(sortedmulti(2,[d1d04177/48'/1'/0'/2']tpubDCoPjomfTqh1e7o1WgGpQtARWtkueXQAepTeNpWiitS3Sdv8RKJ1yvTrGHcwjDXp2SKyMrTEca4LoN7gEUiGCWboyWe2rz99Kf4jK4m2Zmx/0/*,tprv8hwWMmPE4BVNxGdVt3HhEERZhondQvodUY7Ajyseyhudr4WabJqWKWLr4Wi2r26CDaNCQhhxEftEaNzz7dPGhWuKFU4VULesmhEfZYyBXdE/0/*))";
+        let info = describe(descriptor).unwrap();
+        assert_eq!(info.descriptor_type, DescriptorType::Wsh);
+        assert_eq!(info.threshold, Some(2));
+        assert!(info.is_sorted_multi);
+        assert!(info.has_wildcard);
+        assert!(!info.is_multipath);
+        assert!(info.has_private_keys);
+        assert!(matches!(info.network, Some(types::Network::Testnet)));
+        assert_eq!(info.keys.len(), 2);
+        assert_eq!(info.keys[0].origin_fingerprint.as_deref(), Some("d1d04177"));
+        assert_eq!(info.keys[0].origin_path.as_deref(), Some("m/48'/1'/0'/2'"));
+        assert_eq!(info.keys[0].full_derivation_path, "m/48'/1'/0'/2'/0");
+        assert!(!info.keys[0].is_private);
+        assert!(info.keys[1].is_private);
+        // The checksum is that of the descriptor as given, private keys included.
+        let with_checksum = format!("{}#{}", descriptor, info.checksum);
+        assert!(BdkDescriptor::new(with_checksum, Network::Testnet).is_ok());
+
+        let info =
+            describe("pkh(0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798)")
+                .unwrap();
+        assert_eq!(info.descriptor_type, DescriptorType::Pkh);
+        assert_eq!(info.threshold, None);
+        assert!(!info.has_wildcard);
+        assert!(info.network.is_none());
+    }
+    #[test]
+    fn test_expand_multipath() {
+        let branches = expand_multipath("wpkh([d1d04177/84'/1'/0']tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6/<0;1>/*)").unwrap();
+        assert_eq!(branches.len(), 2);
+        assert!(branches[0].ends_with("/0/*)"));
+        assert!(branches[1].ends_with("/1/*)"));
+        assert_eq!(
+            expand_multipath("wpkh(A/0/*)").unwrap(),
+            vec!["wpkh(A/0/*)"]
+        );
+        assert!(expand_multipath("wpkh(A/<0;1>/*,B/<0;1;2>/*)").is_err());
+        assert!(expand_multipath("wpkh(A/<0>/*)").is_err());
+        assert!(expand_multipath("wpkh(A/<0;1/*)").is_err());
+        let info = describe(&branches[0].replace("/0/*", "/<0;1>/*")).unwrap();
+        assert!(info.is_multipath);
+    }
+    #[test]
+    fn test_new_multisig() {
+        let secret_key = get_descriptor_secret_key()
+            .derive(Arc::new(
+                DerivationPath::new("m/48h/1h/0h/2h".to_string()).unwrap(),
+            ))
+            .unwrap();
+        let public_keys = vec![
+            DescriptorPublicKey::from_string("[d1d04177/44'/1'/0']tpubDCoPjomfTqh1e7o1WgGpQtARWtkueXQAepTeNpWiitS3Sdv8RKJ1yvTrGHcwjDXp2SKyMrTEca4LoN7gEUiGCWboyWe2rz99Kf4jK4m2Zmx/*".to_string()).unwrap(),
+            DescriptorPublicKey::from_string("[d1d04177/84'/1'/0']tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6/*".to_string()).unwrap(),
+        ];
+        let new_multisig = |public_keys: Vec<Arc<DescriptorPublicKey>>,
+                            threshold: u32,
+                            script_type: MultisigScriptType,
+                            sorted: bool,
+                            keychain_kind: KeychainKind| {
+            BdkDescriptor::new_multisig(
+                threshold,
+                public_keys,
+                Some(secret_key.clone()),
+                script_type,
+                sorted,
+                keychain_kind,
+                Network::Testnet,
+            )
+        };
+
+        let external = new_multisig(
+            public_keys.clone(),
+            2,
+            MultisigScriptType::P2wsh,
+            true,
+            KeychainKind::External,
+        )
+        .unwrap();
+        let internal = new_multisig(
+            public_keys.clone(),
+            2,
+            MultisigScriptType::P2wsh,
+            true,
+            KeychainKind::Internal,
+        )
+        .unwrap();
+        assert!(external
+            .as_string()
+            .starts_with("wsh(sortedmulti(2,[d1d04177/48'/1'/0'/2']tpub"));
+        assert_eq!(external.as_string().matches("/0/*").count(), 3);
+        assert_eq!(internal.as_string().matches("/1/*").count(), 3);
+        assert!(external.as_string_private().contains("tprv"));
+        let info = external.info();
+        assert_eq!(info.threshold, Some(2));
+        assert_eq!(info.keys.iter().filter(|x| x.is_private).count(), 1);
+
+        let taproot = new_multisig(
+            public_keys.clone(),
+            3,
+            MultisigScriptType::P2tr,
+            false,
+            KeychainKind::External,
+        )
+        .unwrap();
+        assert_eq!(taproot.info().descriptor_type, DescriptorType::Tr);
+        assert_eq!(taproot.info().threshold, Some(3));
+        let error = new_multisig(
+            public_keys.clone(),
+            2,
+            MultisigScriptType::P2tr,
+            true,
+            KeychainKind::External,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("sortedmulti_a"));
+        assert!(new_multisig(
+            public_keys.clone(),
+            4,
+            MultisigScriptType::P2sh,
+            true,
+            KeychainKind::External
+        )
+        .is_err());
+
+        let mut duplicated = public_keys.clone();
+        duplicated.push(public_keys[0].clone());
+        assert!(new_multisig(
+            duplicated,
+            2,
+            MultisigScriptType::P2wsh,
+            true,
+            KeychainKind::External
+        )
+        .is_err());
+
+        // A different key claiming the origin of another cosigner.
+        let same_origin = DescriptorPublicKey::from_string("[d1d04177/44'/1'/0']tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6/*".to_string()).unwrap();
+        assert!(new_multisig(
+            vec![public_keys[0].clone(), same_origin],
+            2,
+            MultisigScriptType::P2wsh,
+            true,
+            KeychainKind::External
+        )
+        .is_err());
+
+        let mainnet = DescriptorPublicKey::from_string("xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8/*".to_string()).unwrap();
+        assert!(new_multisig(
+            vec![public_keys[0].clone(), mainnet],
+            2,
+            MultisigScriptType::P2wsh,
+            true,
+            KeychainKind::External
+        )
+        .is_err());
+    }
+    #[test]
+    fn test_compile_policy() {
+        let keys = || {
+            vec![
+                PolicyKey {
+                    name: "A".to_string(),
+                    key: "tprv8hwWMmPE4BVNxGdVt3HhEERZhondQvodUY7Ajyseyhudr4WabJqWKWLr4Wi2r26CDaNCQhhxEftEaNzz7dPGhWuKFU4VULesmhEfZYyBXdE/0/*".to_string(),
+                },
+                PolicyKey {
+                    name: "B".to_string(),
+                    key: "[d1d04177/84'/1'/0']tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6/0/*".to_string(),
+                },
+            ]
+        };
+        let policy = "or(pk(A),and(pk(B),older(4320)))";
+
+        let wsh = compile_policy(policy, keys(), PolicyContext::Wsh, Network::Testnet).unwrap();
+        assert!(wsh.descriptor.starts_with("wsh("));
+        assert!(wsh.descriptor.contains("tprv"));
+        assert!(wsh.descriptor.contains("older(4320)"));
+        assert!(wsh.is_sane);
+        assert!(wsh.is_non_malleable);
+        assert!(wsh.max_satisfaction_weight > 0);
+
+        let tr = compile_policy(policy, keys(), PolicyContext::Tr, Network::Testnet).unwrap();
+        assert!(tr.descriptor.starts_with("tr(tprv"));
+        assert!(tr.is_sane);
+
+        assert!(compile_policy(
+            "or(pk(A),pk(C))",
+            keys(),
+            PolicyContext::Wsh,
+            Network::Testnet
+        )
+        .is_err());
+        assert!(compile_policy(policy, keys(), PolicyContext::Wsh, Network::Bitcoin).is_err());
+    }
+    #[test]
+    fn test_single_key_descriptors() {
+        let single_key = DescriptorSecretKey::from_single(
+            "cVQ1bagBzrypkpC8vEGC2HXpJuDkid4Wdf1WaLk88XdAjRMEjanm".to_string(),
+            Network::Testnet,
+        )
+        .unwrap();
+        // The master key of the test mnemonic, whose secret is the WIF key above.
+        let master = "tprv8ZgxMBicQKsPdWuqM1t1CDRvQtQuBPyfL6GbhQwtxDKgUAVPbxmj71pRA8raTqLrec5LyTs5TqCxdABcZr77bt2KyWA5bizJHnC4g4ysm4h";
+        let first_address = |descriptor: &BdkDescriptor| {
+            descriptor
+                .derive_addresses(Network::Testnet, 0, 1)
+                .unwrap()
+                .pop()
+                .unwrap()
+                .address
+        };
+        for (script_type, template) in [
+            (DescriptorType::Pkh, "pkh({})"),
+            (DescriptorType::ShWpkh, "sh(wpkh({}))"),
+            (DescriptorType::Wpkh, "wpkh({})"),
+            (DescriptorType::Tr, "tr({})"),
+        ] {
+            let expected =
+                BdkDescriptor::new(template.replace("{}", master), Network::Testnet).unwrap();
+            let descriptor =
+                BdkDescriptor::new_single_key(single_key.clone(), script_type, Network::Testnet)
+                    .unwrap();
+            assert!(descriptor
+                .as_string_private()
+                .contains("cVQ1bagBzrypkpC8vEGC2HXpJuDkid4Wdf1WaLk88XdAjRMEjanm"));
+            assert_eq!(first_address(&descriptor), first_address(&expected));
+            let public = BdkDescriptor::new_single_key_public(
+                single_key.as_public().unwrap(),
+                script_type,
+                Network::Testnet,
+            )
+            .unwrap();
+            assert_eq!(public.as_string(), descriptor.as_string());
+        }
+
+        assert!(BdkDescriptor::new_single_key(
+            single_key.clone(),
+            DescriptorType::Wsh,
+            Network::Testnet
+        )
+        .is_err());
+        assert!(BdkDescriptor::new_single_key(
+            single_key.clone(),
+            DescriptorType::Wpkh,
+            Network::Bitcoin
+        )
+        .is_err());
+        assert!(
+            BdkDescriptor::new_bip84(single_key, KeychainKind::External, Network::Testnet).is_err()
+        );
+        assert!(BdkDescriptor::new_single_key(
+            Arc::new(get_descriptor_secret_key()),
+            DescriptorType::Wpkh,
+            Network::Testnet
+        )
+        .is_err());
+    }
+    #[test]
+    fn test_slip132_template() {
+        let template = slip132_template("[d1d04177/84'/1'/0']vpub5VmR59DVLkizfQ4fzJdsR7X2nygGdzc8DTogjHxe95xektQ5AWD6ZBBE66kkSLCEiqPH9qi8NTJmzFbq9NX1MZPjXFDwn976QchSGairb9R").unwrap();
+        assert_eq!(template.key, "[d1d04177/84'/1'/0']tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m");
+        assert_eq!(template.version, Slip132Version::Segwit);
+        assert!(template.multisig_script_type.is_none());
+        let descriptor = template.descriptor.unwrap();
+        assert!(descriptor.starts_with(&format!("wpkh({}/<0;1>/*)#", template.key)));
+        let (external, _) = split_multipath(&descriptor).unwrap().unwrap();
+        assert!(BdkDescriptor::new(external, Network::Testnet).is_ok());
+
+        let template = slip132_template("Vpub5gfWCNwvuiHN5yE3vy6rFBrqWmiXrMHiXjTMeZEBWro4P4xyvubNeJ39toiDzmR8xJTG2RiFFfhH7RDbSbfxW3WQMiLMBYa6JLyJrX8bphK").unwrap();
+        assert_eq!(template.key, "tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m");
+        assert!(template.descriptor.is_none());
+        assert_eq!(
+            template.multisig_script_type,
+            Some(MultisigScriptType::P2wsh)
+        );
+
+        assert!(slip132_template("[d1d04177/84'/1'/0'vpub5VmR59DVLkizfQ4fzJdsR7X2nygGdzc8DTogjHxe95xektQ5AWD6ZBBE66kkSLCEiqPH9qi8NTJmzFbq9NX1MZPjXFDwn976QchSGairb9R").is_err());
+        assert!(slip132_template("[zz/84'/1'/0']vpub5VmR59DVLkizfQ4fzJdsR7X2nygGdzc8DTogjHxe95xektQ5AWD6ZBBE66kkSLCEiqPH9qi8NTJmzFbq9NX1MZPjXFDwn976QchSGairb9R").is_err());
+    }
+    #[test]
+    fn test_derive_addresses() {
+        let descriptor = BdkDescriptor::new("wpkh(tprv8hwWMmPE4BVNxGdVt3HhEERZhondQvodUY7Ajyseyhudr4WabJqWKWLr4Wi2r26CDaNCQhhxEftEaNzz7dPGhWuKFU4VULesmhEfZYyBXdE/0/*)".to_string(), Network::Regtest).unwrap();
+        let addresses = descriptor.derive_addresses(Network::Regtest, 1, 2).unwrap();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].index, 1);
+        assert_eq!(
+            addresses[0].address,
+            "bcrt1q0xs7dau8af22rspp4klya4f7lhggcnqfun2y3a"
+        );
+        assert_eq!(
+            addresses[1].address,
+            "bcrt1q5g0mq6dkmwzvxscqwgc932jhgcxuqqkjv09tkj"
+        );
+        assert_eq!(addresses[1].keys.len(), 1);
+        assert_eq!(addresses[1].keys[0].path, "m/0/2");
+        for address in addresses.iter() {
+            let script_pubkey = address
+                .address
+                .parse::<BdkAddress>()
+                .unwrap()
+                .script_pubkey();
+            assert_eq!(address.script_pubkey.internal, script_pubkey.to_bytes());
+        }
+        assert_eq!(
+            addresses[0].script_pubkey.internal,
+            descriptor
+                .extended_descriptor
+                .at_derivation_index(1)
+                .script_pubkey()
+                .to_bytes()
+        );
+        assert!(descriptor
+            .derive_addresses(Network::Regtest, u32::MAX, 2)
+            .is_err());
+        assert_eq!(
+            descriptor
+                .derive_addresses(Network::Regtest, 0, MAX_DERIVE_ADDRESSES)
+                .unwrap()
+                .len(),
+            MAX_DERIVE_ADDRESSES as usize
+        );
+        assert!(descriptor
+            .derive_addresses(Network::Regtest, 0, MAX_DERIVE_ADDRESSES + 1)
+            .is_err());
+    }
+    #[test]
+    fn test_split_merge_multipath() {
+        let multipath = "wsh(multi(2,[d1d04177/48'/1'/0'/2']tpubDCoPjomfTqh1e7o1WgGpQtARWtkueXQAepTeNpWiitS3Sdv8RKJ1yvTrGHcwjDXp2SKyMrTEca4LoN7gEUiGCWboyWe2rz99Kf4jK4m2Zmx/<0;1>/*,tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m/<0;1>/*))";
+        let (external, internal) = split_multipath(multipath).unwrap().unwrap();
+        assert!(!external.contains('<') && external.matches("/0/*").count() == 2);
+        assert!(!internal.contains('<') && internal.matches("/1/*").count() == 2);
+
+        let merged = merge_multipath(&external, &internal).unwrap();
+        assert!(merged.starts_with(multipath));
+        assert!(strip_checksum(&merged).is_ok());
+        assert_eq!(
+            split_multipath(&merged).unwrap(),
+            Some((external.clone(), internal))
+        );
+
+        assert_eq!(split_multipath(&external).unwrap(), None);
+        assert!(split_multipath(&multipath.replace("<0;1>", "<0;1;2>")).is_err());
+        assert!(split_multipath(&multipath.replace("<0;1>", "<0;0>")).is_err());
+        assert!(merge_multipath(&external, &external).is_err());
+        assert!(merge_multipath(&external, &external.replace("wsh(", "sh(")).is_err());
+        // Differences in key origins, or in two steps of the same key, can't be merged.
+        assert!(merge_multipath(&external, &external.replace("/48'/1'/0'", "/48'/1'/1'")).is_err());
+        assert!(merge_multipath("wpkh(tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m/0/0/*)", "wpkh(tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m/1/1/*)").is_err());
+        assert!(merge_multipath("wpkh(tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m/0/0/*)", "wpkh(tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m/0/1/*)").is_ok());
+        assert!(strip_checksum(&format!("{}#abcdefgh", external)).is_err());
+    }
 }
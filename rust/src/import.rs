@@ -0,0 +1,721 @@
+use crate::descriptor::{
+    split_multipath, strip_checksum, BdkDescriptor, DescriptorType, MultisigScriptType,
+};
+use crate::key::{slip132_to_standard, DescriptorPublicKey, Slip132Version};
+use bdk::bitcoin::util::bip32::{
+    ChildNumber, DerivationPath as BdkDerivationPath, ExtendedPubKey, Fingerprint,
+};
+use bdk::bitcoin::Network;
+use bdk::keys::DescriptorPublicKey as BdkDescriptorPublicKey;
+use bdk::miniscript::descriptor::{DescriptorXKey, Wildcard};
+use bdk::miniscript::ForEachKey;
+use bdk::Error as BdkError;
+use bdk::KeychainKind;
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// Where a wallet export comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletSource {
+    /// A Coldcard generic JSON export or multisig `wallet.txt` setup file.
+    Coldcard,
+    /// A Specter Desktop wallet backup.
+    Specter,
+    /// An Electrum wallet file.
+    Electrum,
+    /// A BlueWallet multisig setup file or watch-only export.
+    BlueWallet,
+    /// Plain descriptors, as exported by Sparrow or Bitcoin Core.
+    Descriptor,
+}
+
+/// A key of an imported wallet.
+pub struct ImportedCosigner {
+    /// The master key fingerprint.
+    pub fingerprint: String,
+    /// The derivation path of the key from its master key.
+    pub derivation_path: String,
+    /// The account extended public key as an xpub or tpub, or the key of a single key
+    /// descriptor.
+    pub key: String,
+}
+
+/// A wallet read from an export, with the descriptors to pass to `Api::create_wallet`.
+pub struct ImportedWallet {
+    pub source: WalletSource,
+    /// The wallet name or label, if the export has one.
+    pub name: Option<String>,
+    pub descriptor: String,
+    pub change_descriptor: String,
+    /// The signatures needed to spend from a multisig wallet.
+    pub threshold: Option<u32>,
+    pub cosigners: Vec<ImportedCosigner>,
+}
+
+/// An account extended public key read from an export.
+struct AccountKey {
+    xpub: ExtendedPubKey,
+    version: Slip132Version,
+    origin: Option<(Fingerprint, BdkDerivationPath)>,
+}
+
+/// Read the wallets of a wallet export. Coldcard generic JSON exports hold one wallet per script
+/// type, the other formats a single wallet.
+pub(crate) fn import_wallet(data: &str, network: Network) -> Result<Vec<ImportedWallet>, BdkError> {
+    let data = data.trim();
+    if data.starts_with('{') {
+        let json: Value = serde_json::from_str(data)
+            .map_err(|e| BdkError::Generic(format!("Invalid wallet export: {}", e)))?;
+        if json.get("wallet_type").is_some() {
+            import_electrum(&json, network).map(|x| vec![x])
+        } else if let Some(descriptor) = json.get("descriptor") {
+            let descriptor = descriptor
+                .as_str()
+                .ok_or_else(|| invalid_field("descriptor"))?;
+            let (descriptor, change_descriptor) = descriptor_pair(descriptor)?;
+            let name = json
+                .get("label")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            imported(
+                WalletSource::Specter,
+                name,
+                descriptor,
+                change_descriptor,
+                network,
+            )
+            .map(|x| vec![x])
+        } else if json.get("ExtPubKey").is_some() {
+            let key = parse_account_key(
+                field(&json, "ExtPubKey")?,
+                Some((
+                    parse_fingerprint(field(&json, "MasterFingerprint")?)?,
+                    parse_path(field(&json, "AccountKeyPath")?)?,
+                )),
+            )?;
+            let script_type = single_sig_type(key.version);
+            single_sig(WalletSource::BlueWallet, script_type, &key, network).map(|x| vec![x])
+        } else if json.get("xfp").is_some() {
+            import_coldcard_json(&json, network)
+        } else {
+            Err(BdkError::Generic("Unsupported wallet export".to_string()))
+        }
+    } else {
+        let lines: Vec<&str> = data
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        if lines
+            .iter()
+            .any(|line| line.to_lowercase().starts_with("policy:"))
+        {
+            import_multisig_setup(data, network).map(|x| vec![x])
+        } else if lines.iter().any(|line| line.contains('(')) {
+            let (descriptor, change_descriptor) = match lines.as_slice() {
+                [descriptor] => descriptor_pair(descriptor)?,
+                [descriptor, change_descriptor] => (
+                    strip_checksum(descriptor)?.to_string(),
+                    strip_checksum(change_descriptor)?.to_string(),
+                ),
+                _ => {
+                    return Err(BdkError::Generic(
+                        "Expected a descriptor and a change descriptor".to_string(),
+                    ))
+                }
+            };
+            imported(
+                WalletSource::Descriptor,
+                None,
+                descriptor,
+                change_descriptor,
+                network,
+            )
+            .map(|x| vec![x])
+        } else if let [key] = lines.as_slice() {
+            let key = parse_key_with_origin(key)?;
+            let script_type = single_sig_type(key.version);
+            single_sig(WalletSource::BlueWallet, script_type, &key, network).map(|x| vec![x])
+        } else {
+            Err(BdkError::Generic("Unsupported wallet export".to_string()))
+        }
+    }
+}
+
+/// A Coldcard generic JSON export, with the single signature accounts it has.
+fn import_coldcard_json(json: &Value, network: Network) -> Result<Vec<ImportedWallet>, BdkError> {
+    let master_fingerprint = field(json, "xfp")?;
+    let mut wallets = Vec::new();
+    for (section, script_type) in [
+        ("bip84", DescriptorType::Wpkh),
+        ("bip86", DescriptorType::Tr),
+        ("bip49", DescriptorType::ShWpkh),
+        ("bip44", DescriptorType::Pkh),
+    ] {
+        if let Some(account) = json.get(section) {
+            let fingerprint = match account.get("xfp") {
+                Some(fingerprint) => fingerprint.as_str().ok_or_else(|| invalid_field("xfp"))?,
+                None => master_fingerprint,
+            };
+            let key = parse_account_key(
+                field(account, "xpub")?,
+                Some((
+                    parse_fingerprint(fingerprint)?,
+                    parse_path(field(account, "deriv")?)?,
+                )),
+            )?;
+            wallets.push(single_sig(
+                WalletSource::Coldcard,
+                script_type,
+                &key,
+                network,
+            )?);
+        }
+    }
+    if wallets.is_empty() {
+        return Err(BdkError::Generic(
+            "The Coldcard export has no single signature account, import multisig wallets from \
+             their wallet.txt setup file"
+                .to_string(),
+        ));
+    }
+    Ok(wallets)
+}
+
+/// A Coldcard or BlueWallet multisig setup file.
+fn import_multisig_setup(data: &str, network: Network) -> Result<ImportedWallet, BdkError> {
+    let source = if data.to_lowercase().contains("bluewallet") {
+        WalletSource::BlueWallet
+    } else {
+        WalletSource::Coldcard
+    };
+    let mut name = None;
+    let mut policy = None;
+    // Coldcard defaults to legacy P2SH when there is no format.
+    let mut script_type = MultisigScriptType::P2sh;
+    // A derivation applies to the keys after it.
+    let mut derivation = None;
+    let mut keys = Vec::new();
+    for line in data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    {
+        let unexpected_line =
+            || BdkError::Generic(format!("Unexpected line in multisig setup file: {}", line));
+        let (label, value) = line.split_once(':').ok_or_else(unexpected_line)?;
+        let value = value.trim();
+        match label.trim().to_lowercase().as_str() {
+            "name" => name = Some(value.to_string()),
+            "policy" => {
+                policy = Some(parse_policy(value).ok_or_else(|| {
+                    BdkError::Generic(format!("Invalid multisig policy {}", value))
+                })?)
+            }
+            "derivation" => derivation = Some(parse_path(value)?),
+            "format" => {
+                script_type = match value.to_uppercase().as_str() {
+                    "P2SH" => MultisigScriptType::P2sh,
+                    "P2SH-P2WSH" | "P2WSH-P2SH" => MultisigScriptType::P2shP2wsh,
+                    "P2WSH" => MultisigScriptType::P2wsh,
+                    _ => {
+                        return Err(BdkError::Generic(format!(
+                            "Unsupported script type {}",
+                            value
+                        )))
+                    }
+                }
+            }
+            fingerprint => {
+                let fingerprint =
+                    Fingerprint::from_str(fingerprint).map_err(|_| unexpected_line())?;
+                let derivation = derivation.clone().ok_or_else(|| {
+                    BdkError::Generic(format!("Key {} has no derivation path", fingerprint))
+                })?;
+                keys.push(parse_account_key(value, Some((fingerprint, derivation)))?);
+            }
+        }
+    }
+    let (threshold, key_count) = policy
+        .ok_or_else(|| BdkError::Generic("The multisig setup file has no policy".to_string()))?;
+    check_policy(threshold, key_count)?;
+    if key_count != keys.len() {
+        return Err(BdkError::Generic(format!(
+            "The policy has {} keys, the setup file {}",
+            key_count,
+            keys.len()
+        )));
+    }
+    multisig(source, name, threshold, &keys, script_type, network)
+}
+
+/// An Electrum wallet file, a `standard` wallet or an `MofN` multisig.
+fn import_electrum(json: &Value, network: Network) -> Result<ImportedWallet, BdkError> {
+    let wallet_type = field(json, "wallet_type")?;
+    if wallet_type == "standard" {
+        let key = electrum_key(
+            json.get("keystore")
+                .ok_or_else(|| missing_field("keystore"))?,
+        )?;
+        let script_type = single_sig_type(key.version);
+        return single_sig(WalletSource::Electrum, script_type, &key, network);
+    }
+    let (threshold, key_count) = parse_policy(wallet_type).ok_or_else(|| {
+        BdkError::Generic(format!("Unsupported Electrum wallet type {}", wallet_type))
+    })?;
+    check_policy(threshold, key_count)?;
+    let mut keys = Vec::new();
+    for i in 1..=key_count {
+        let keystore = format!("x{}/", i);
+        keys.push(electrum_key(
+            json.get(&keystore)
+                .ok_or_else(|| missing_field(&keystore))?,
+        )?);
+    }
+    // Electrum tells the script type by the key versions, which must agree.
+    let script_type = multisig_type(keys[0].version);
+    if keys
+        .iter()
+        .any(|key| multisig_type(key.version) != script_type)
+    {
+        return Err(BdkError::Generic(
+            "The Electrum cosigner keys are for different script types".to_string(),
+        ));
+    }
+    multisig(
+        WalletSource::Electrum,
+        None,
+        threshold,
+        &keys,
+        script_type,
+        network,
+    )
+}
+
+fn electrum_key(keystore: &Value) -> Result<AccountKey, BdkError> {
+    let keystore_type = field(keystore, "type")?;
+    if keystore_type != "bip32" && keystore_type != "hardware" {
+        return Err(BdkError::Generic(format!(
+            "Unsupported Electrum keystore type {}",
+            keystore_type
+        )));
+    }
+    let fingerprint = keystore.get("root_fingerprint").and_then(Value::as_str);
+    let derivation = keystore.get("derivation").and_then(Value::as_str);
+    let origin = match (fingerprint, derivation) {
+        (Some(fingerprint), Some(derivation)) => {
+            Some((parse_fingerprint(fingerprint)?, parse_path(derivation)?))
+        }
+        _ => None,
+    };
+    parse_account_key(field(keystore, "xpub")?, origin)
+}
+
+/// The external and change descriptors of a single descriptor: a BIP389 multipath descriptor
+/// (or Specter's `/{0,1}/*`), or a descriptor with `/0/*` keys whose change keys are `/1/*`.
+fn descriptor_pair(descriptor: &str) -> Result<(String, String), BdkError> {
+    let descriptor = strip_checksum(descriptor)?.replace("/{0,1}/", "/<0;1>/");
+    if let Some(pair) = split_multipath(&descriptor)? {
+        return Ok(pair);
+    }
+    if !descriptor.contains("/0/*") {
+        return Err(BdkError::Generic(format!(
+            "Can't tell the change descriptor of {}",
+            descriptor
+        )));
+    }
+    let change_descriptor = descriptor.replace("/0/*", "/1/*");
+    Ok((descriptor, change_descriptor))
+}
+
+fn single_sig(
+    source: WalletSource,
+    script_type: DescriptorType,
+    key: &AccountKey,
+    network: Network,
+) -> Result<ImportedWallet, BdkError> {
+    let descriptor = |keychain: u32| {
+        let key = BdkDescriptorPublicKey::XPub(DescriptorXKey {
+            origin: key.origin.clone(),
+            xkey: key.xpub,
+            derivation_path: BdkDerivationPath::from(vec![ChildNumber::Normal { index: keychain }]),
+            wildcard: Wildcard::Unhardened,
+        });
+        match script_type {
+            DescriptorType::Pkh => Ok(format!("pkh({})", key)),
+            DescriptorType::ShWpkh => Ok(format!("sh(wpkh({}))", key)),
+            DescriptorType::Wpkh => Ok(format!("wpkh({})", key)),
+            DescriptorType::Tr => Ok(format!("tr({})", key)),
+            _ => Err(BdkError::Generic(format!(
+                "Unsupported script type {:?}",
+                script_type
+            ))),
+        }
+    };
+    imported(source, None, descriptor(0)?, descriptor(1)?, network)
+}
+
+fn multisig(
+    source: WalletSource,
+    name: Option<String>,
+    threshold: u32,
+    keys: &[AccountKey],
+    script_type: MultisigScriptType,
+    network: Network,
+) -> Result<ImportedWallet, BdkError> {
+    let public_keys: Vec<Arc<DescriptorPublicKey>> = keys
+        .iter()
+        .map(|key| {
+            Arc::new(DescriptorPublicKey {
+                descriptor_public_key_mutex: Mutex::new(BdkDescriptorPublicKey::XPub(
+                    DescriptorXKey {
+                        origin: key.origin.clone(),
+                        xkey: key.xpub,
+                        derivation_path: BdkDerivationPath::master(),
+                        wildcard: Wildcard::None,
+                    },
+                )),
+            })
+        })
+        .collect();
+    // Coordinators and hardware wallets all use `sortedmulti`.
+    let descriptors = [KeychainKind::External, KeychainKind::Internal].map(|keychain_kind| {
+        BdkDescriptor::new_multisig(
+            threshold,
+            public_keys.clone(),
+            None,
+            script_type,
+            true,
+            keychain_kind,
+            network,
+        )
+    });
+    let [descriptor, change_descriptor] = descriptors;
+    imported(
+        source,
+        name,
+        descriptor?.as_string(),
+        change_descriptor?.as_string(),
+        network,
+    )
+}
+
+/// Check the descriptors of an imported wallet and describe its keys.
+fn imported(
+    source: WalletSource,
+    name: Option<String>,
+    descriptor: String,
+    change_descriptor: String,
+    network: Network,
+) -> Result<ImportedWallet, BdkError> {
+    let descriptor = BdkDescriptor::new(descriptor, network)?;
+    let change_descriptor = BdkDescriptor::new(change_descriptor, network)?;
+    let mut cosigners = Vec::new();
+    descriptor.extended_descriptor.for_each_key(|key| {
+        let (origin, key_string) = match key {
+            BdkDescriptorPublicKey::Single(single) => (single.origin.as_ref(), key.to_string()),
+            BdkDescriptorPublicKey::XPub(xpub) => (xpub.origin.as_ref(), xpub.xkey.to_string()),
+        };
+        cosigners.push(ImportedCosigner {
+            fingerprint: key.master_fingerprint().to_string(),
+            derivation_path: origin
+                .map(|(_, path)| path.clone())
+                .unwrap_or_else(BdkDerivationPath::master)
+                .to_string(),
+            key: key_string,
+        });
+        true
+    });
+    Ok(ImportedWallet {
+        source,
+        name,
+        threshold: descriptor.multisig().map(|(k, _)| k as u32),
+        descriptor: descriptor.as_string_private(),
+        change_descriptor: change_descriptor.as_string_private(),
+        cosigners,
+    })
+}
+
+fn single_sig_type(version: Slip132Version) -> DescriptorType {
+    match version {
+        Slip132Version::Standard => DescriptorType::Pkh,
+        Slip132Version::NestedSegwit | Slip132Version::NestedSegwitMultisig => {
+            DescriptorType::ShWpkh
+        }
+        Slip132Version::Segwit | Slip132Version::SegwitMultisig => DescriptorType::Wpkh,
+    }
+}
+
+fn multisig_type(version: Slip132Version) -> MultisigScriptType {
+    match version {
+        Slip132Version::Standard => MultisigScriptType::P2sh,
+        Slip132Version::NestedSegwit | Slip132Version::NestedSegwitMultisig => {
+            MultisigScriptType::P2shP2wsh
+        }
+        Slip132Version::Segwit | Slip132Version::SegwitMultisig => MultisigScriptType::P2wsh,
+    }
+}
+
+/// Parse an extended public key with any SLIP-132 version.
+fn parse_account_key(
+    key: &str,
+    origin: Option<(Fingerprint, BdkDerivationPath)>,
+) -> Result<AccountKey, BdkError> {
+    let (xpub, version) = slip132_to_standard(key)?;
+    Ok(AccountKey {
+        xpub,
+        version,
+        origin,
+    })
+}
+
+/// Parse a key such as `[fingerprint/84'/0'/0']zpub...`.
+fn parse_key_with_origin(key: &str) -> Result<AccountKey, BdkError> {
+    match key.strip_prefix('[') {
+        Some(key) => {
+            let (origin, key) = key
+                .split_once(']')
+                .ok_or_else(|| BdkError::Generic(format!("Invalid key origin in {}", key)))?;
+            let (fingerprint, path) = origin.split_once('/').unwrap_or((origin, ""));
+            parse_account_key(
+                key,
+                Some((parse_fingerprint(fingerprint)?, parse_path(path)?)),
+            )
+        }
+        None => parse_account_key(key, None),
+    }
+}
+
+fn parse_fingerprint(fingerprint: &str) -> Result<Fingerprint, BdkError> {
+    Fingerprint::from_str(fingerprint.trim())
+        .map_err(|_| BdkError::Generic(format!("Invalid fingerprint {}", fingerprint)))
+}
+
+/// Parse a derivation path, with or without the leading `m/`.
+fn parse_path(path: &str) -> Result<BdkDerivationPath, BdkError> {
+    let steps = path.trim();
+    let steps = steps
+        .strip_prefix('m')
+        .unwrap_or(steps)
+        .trim_start_matches('/');
+    let full_path = if steps.is_empty() {
+        "m".to_string()
+    } else {
+        format!("m/{}", steps)
+    };
+    BdkDerivationPath::from_str(&full_path)
+        .map_err(|_| BdkError::Generic(format!("Invalid derivation path {}", path)))
+}
+
+/// Parse an `M of N` policy, or Electrum's `MofN`.
+fn parse_policy(policy: &str) -> Option<(u32, usize)> {
+    let (threshold, key_count) = policy.split_once("of")?;
+    Some((
+        threshold.trim().parse().ok()?,
+        key_count.trim().parse().ok()?,
+    ))
+}
+
+/// Fails unless the policy has keys and a threshold between 1 and the number of keys.
+fn check_policy(threshold: u32, key_count: usize) -> Result<(), BdkError> {
+    if key_count == 0 || threshold == 0 || threshold as usize > key_count {
+        return Err(BdkError::Generic(format!(
+            "Invalid {} of {} multisig policy",
+            threshold, key_count
+        )));
+    }
+    Ok(())
+}
+
+fn field<'a>(json: &'a Value, name: &str) -> Result<&'a str, BdkError> {
+    json.get(name)
+        .ok_or_else(|| missing_field(name))?
+        .as_str()
+        .ok_or_else(|| invalid_field(name))
+}
+
+fn missing_field(name: &str) -> BdkError {
+    BdkError::Generic(format!("Missing field {} in wallet export", name))
+}
+
+fn invalid_field(name: &str) -> BdkError {
+    BdkError::Generic(format!("Invalid field {} in wallet export", name))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::BdkDescriptor;
+    use crate::import::{import_wallet, WalletSource};
+    use bdk::bitcoin::Network;
+
+    const KEY_1: &str = "tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m";
+    const KEY_2: &str = "tpubDCoPjomfTqh1e7o1WgGpQtARWtkueXQAepTeNpWiitS3Sdv8RKJ1yvTrGHcwjDXp2SKyMrTEca4LoN7gEUiGCWboyWe2rz99Kf4jK4m2Zmx";
+    /// `KEY_1` and `KEY_2` with the `Vpub` version.
+    const MULTISIG_KEY_1: &str = "Vpub5gfWCNwvuiHN5yE3vy6rFBrqWmiXrMHiXjTMeZEBWro4P4xyvubNeJ39toiDzmR8xJTG2RiFFfhH7RDbSbfxW3WQMiLMBYa6JLyJrX8bphK";
+    const MULTISIG_KEY_2: &str = "Vpub5jfKjULb2ACy6JPJLSBTcryrZdQczFbhbEUaNjwLzjkoDLBscBW5gULfnYaTmLVdeidS7ukN3MuhTn1hhTBws5tz1hcHR3Jw5ycuMpz2UJx";
+    /// `KEY_1` with the `vpub` version.
+    const SEGWIT_KEY: &str = "vpub5VmR59DVLkizfQ4fzJdsR7X2nygGdzc8DTogjHxe95xektQ5AWD6ZBBE66kkSLCEiqPH9qi8NTJmzFbq9NX1MZPjXFDwn976QchSGairb9R";
+
+    #[test]
+    fn test_coldcard_multisig() {
+        let setup = format!(
+            "# Coldcard Multisig setup file (created on 0F056943)\n\
+             #\n\
+             Name: Vault\n\
+             Policy: 2 of 2\n\
+             Derivation: m/48'/1'/0'/2'\n\
+             Format: P2WSH\n\
+             \n\
+             0F056943: {}\n\
+             6BA6CFD0: {}\n",
+            KEY_1, KEY_2
+        );
+        let wallets = import_wallet(&setup, Network::Testnet).unwrap();
+        assert_eq!(wallets.len(), 1);
+        let wallet = &wallets[0];
+        assert_eq!(wallet.source, WalletSource::Coldcard);
+        assert_eq!(wallet.name.as_deref(), Some("Vault"));
+        assert_eq!(wallet.threshold, Some(2));
+        assert!(wallet
+            .descriptor
+            .starts_with("wsh(sortedmulti(2,[0f056943/48'/1'/0'/2']"));
+        assert!(wallet.descriptor.contains(&format!("{}/0/*", KEY_2)));
+        assert!(wallet.change_descriptor.contains(&format!("{}/1/*", KEY_1)));
+        assert_eq!(wallet.cosigners.len(), 2);
+        assert_eq!(wallet.cosigners[1].fingerprint, "6ba6cfd0");
+        assert_eq!(wallet.cosigners[1].derivation_path, "m/48'/1'/0'/2'");
+        assert_eq!(wallet.cosigners[1].key, KEY_2);
+
+        let taproot = setup.replace("P2WSH", "P2TR");
+        assert!(import_wallet(&taproot, Network::Testnet)
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("Unsupported script type P2TR"));
+        let missing_key = setup.replace("2 of 2", "2 of 3");
+        assert!(import_wallet(&missing_key, Network::Testnet).is_err());
+        for policy in ["0 of 2", "3 of 2", "0 of 0"] {
+            assert!(
+                import_wallet(&setup.replace("2 of 2", policy), Network::Testnet)
+                    .err()
+                    .unwrap()
+                    .to_string()
+                    .contains(&format!("Invalid {} multisig policy", policy))
+            );
+        }
+        assert!(import_wallet(&setup, Network::Bitcoin).is_err());
+    }
+
+    #[test]
+    fn test_electrum() {
+        let multisig = format!(
+            r#"{{
+                "wallet_type": "2of2",
+                "x1/": {{"type": "bip32", "xpub": "{}", "root_fingerprint": "0f056943", "derivation": "m/48'/1'/0'/2'"}},
+                "x2/": {{"type": "hardware", "xpub": "{}", "root_fingerprint": "6ba6cfd0", "derivation": "m/48'/1'/0'/2'"}}
+            }}"#,
+            MULTISIG_KEY_1, MULTISIG_KEY_2
+        );
+        let wallet = import_wallet(&multisig, Network::Testnet)
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(wallet.source, WalletSource::Electrum);
+        assert_eq!(wallet.threshold, Some(2));
+        assert!(wallet.descriptor.starts_with("wsh(sortedmulti(2,"));
+        assert!(wallet.descriptor.contains(KEY_1));
+        assert!(wallet.descriptor.contains(KEY_2));
+
+        let standard = format!(
+            r#"{{"wallet_type": "standard", "keystore": {{"type": "bip32", "xpub": "{}", "root_fingerprint": "0f056943", "derivation": "m/84'/1'/0'"}}}}"#,
+            SEGWIT_KEY
+        );
+        let wallet = import_wallet(&standard, Network::Testnet)
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(wallet.threshold, None);
+        assert_eq!(
+            wallet.descriptor,
+            BdkDescriptor::new(
+                format!("wpkh([0f056943/84'/1'/0']{}/0/*)", KEY_1),
+                Network::Testnet
+            )
+            .unwrap()
+            .as_string()
+        );
+
+        for policy in ["0of2", "3of2", "0of0"] {
+            let invalid = multisig.replace("2of2", policy);
+            assert!(import_wallet(&invalid, Network::Testnet)
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("multisig policy"));
+        }
+
+        let imported = standard.replace("\"bip32\"", "\"imported\"");
+        assert!(import_wallet(&imported, Network::Testnet).is_err());
+    }
+
+    #[test]
+    fn test_coldcard_generic_json() {
+        let export = format!(
+            r#"{{
+                "chain": "XTN",
+                "xfp": "0F056943",
+                "bip84": {{"xpub": "{}", "deriv": "m/84'/1'/0'", "name": "p2wpkh"}},
+                "bip48_2": {{"xpub": "{}", "deriv": "m/48'/1'/0'/2'", "name": "p2wsh"}},
+                "bip44": {{"xpub": "{}", "deriv": "m/44'/1'/0'", "name": "p2pkh"}}
+            }}"#,
+            SEGWIT_KEY, KEY_1, KEY_2
+        );
+        let wallets = import_wallet(&export, Network::Testnet).unwrap();
+        assert_eq!(wallets.len(), 2);
+        assert!(wallets[0]
+            .descriptor
+            .starts_with(&format!("wpkh([0f056943/84'/1'/0']{}/0/*)", KEY_1)));
+        assert!(wallets[1]
+            .change_descriptor
+            .starts_with(&format!("pkh([0f056943/44'/1'/0']{}/1/*)", KEY_2)));
+    }
+
+    #[test]
+    fn test_descriptor_exports() {
+        let specter = format!(
+            r#"{{"label": "Hot", "blockheight": 0, "descriptor": "wpkh([0f056943/84'/1'/0']{}/{{0,1}}/*)"}}"#,
+            KEY_1
+        );
+        let wallet = import_wallet(&specter, Network::Testnet)
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(wallet.source, WalletSource::Specter);
+        assert_eq!(wallet.name.as_deref(), Some("Hot"));
+        assert!(wallet.change_descriptor.contains("/1/*"));
+
+        let sparrow = format!(
+            "# Receive and change descriptor (BIP389):\nwpkh([0f056943/84'/1'/0']{}/<0;1>/*)\n",
+            KEY_1
+        );
+        let descriptor = import_wallet(&sparrow, Network::Testnet)
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(descriptor.source, WalletSource::Descriptor);
+        assert_eq!(descriptor.descriptor, wallet.descriptor);
+        assert_eq!(descriptor.change_descriptor, wallet.change_descriptor);
+
+        let blue_wallet = format!("[0f056943/84'/1'/0']{}", SEGWIT_KEY);
+        let key = import_wallet(&blue_wallet, Network::Testnet)
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(key.source, WalletSource::BlueWallet);
+        assert_eq!(key.descriptor, wallet.descriptor);
+
+        assert!(import_wallet("wpkh(tpub/2/*)", Network::Testnet).is_err());
+        assert!(import_wallet("{}", Network::Testnet).is_err());
+    }
+}
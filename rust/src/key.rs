@@ -1,11 +1,15 @@
+use bdk::bitcoin::hashes::hex::FromHex;
 use bdk::bitcoin::secp256k1::Secp256k1;
-use bdk::bitcoin::util::bip32::DerivationPath as BdkDerivationPath;
+use bdk::bitcoin::util::base58;
+use bdk::bitcoin::util::bip32::{DerivationPath as BdkDerivationPath, ExtendedPubKey};
+use bdk::bitcoin::{Network, PrivateKey};
 use bdk::descriptor::DescriptorXKey;
 use bdk::keys::bip39::{Language, Mnemonic as BdkMnemonic, WordCount};
 use bdk::keys::{DerivableKey, ExtendedKey, GeneratableKey, GeneratedKey};
 use bdk::keys::{
     DescriptorPublicKey as BdkDescriptorPublicKey, DescriptorSecretKey as BdkDescriptorSecretKey,
 };
+use bdk::miniscript::descriptor::{SinglePriv, SinglePub, SinglePubKey, Wildcard};
 use bdk::miniscript::BareCtx;
 use bdk::Error as BdkError;
 use std::ops::Deref;
@@ -104,9 +108,9 @@ impl DescriptorSecretKey {
                     descriptor_secret_key_mutex: Mutex::new(derived_descriptor_secret_key),
                 }))
             }
-            BdkDescriptorSecretKey::Single(_) => {
-                unreachable!()
-            }
+            BdkDescriptorSecretKey::Single(_) => Err(BdkError::Generic(
+                "Can't derive a single key, only extended keys have child keys".to_string(),
+            )),
         }
     }
     pub fn extend(&self, path: Arc<DerivationPath>) -> Result<Arc<Self>, BdkError> {
@@ -125,9 +129,9 @@ impl DescriptorSecretKey {
                     descriptor_secret_key_mutex: Mutex::new(extended_descriptor_secret_key),
                 }))
             }
-            BdkDescriptorSecretKey::Single(_) => {
-                unreachable!()
-            }
+            BdkDescriptorSecretKey::Single(_) => Err(BdkError::Generic(
+                "Can't extend a single key with a derivation path".to_string(),
+            )),
         }
     }
     pub fn as_public(&self) -> Result<Arc<DescriptorPublicKey>, BdkError> {
@@ -137,7 +141,7 @@ impl DescriptorSecretKey {
             .lock()
             .unwrap()
             .to_public(&secp)
-            .unwrap();
+            .map_err(|e| BdkError::Generic(e.to_string()))?;
         Ok(Arc::new(DescriptorPublicKey {
             descriptor_public_key_mutex: Mutex::new(descriptor_public_key),
         }))
@@ -149,8 +153,8 @@ impl DescriptorSecretKey {
             BdkDescriptorSecretKey::XPrv(descriptor_x_key) => {
                 descriptor_x_key.xkey.private_key.secret_bytes().to_vec()
             }
-            BdkDescriptorSecretKey::Single(_) => {
-                unreachable!()
+            BdkDescriptorSecretKey::Single(single_priv) => {
+                single_priv.key.inner.secret_bytes().to_vec()
             }
         };
 
@@ -158,11 +162,67 @@ impl DescriptorSecretKey {
     }
 
     pub fn from_string(key_str: String) -> Result<Arc<Self>, BdkError> {
-        let key = BdkDescriptorSecretKey::from_str(&*key_str).unwrap();
+        let key = BdkDescriptorSecretKey::from_str(&key_str)
+            .map_err(|e| BdkError::Generic(e.to_string()))?;
         Ok(Arc::new(Self {
             descriptor_secret_key_mutex: Mutex::new(key),
         }))
     }
+    /// Parse a single private key, such as a paper wallet key, given as WIF or as 32 hex encoded
+    /// bytes.
+    pub fn from_single(key_str: String, network: Network) -> Result<Arc<Self>, BdkError> {
+        let key_str = key_str.trim();
+        let private_key = match PrivateKey::from_wif(key_str) {
+            Ok(private_key) => {
+                if (private_key.network == Network::Bitcoin) != (network == Network::Bitcoin) {
+                    return Err(BdkError::Generic(format!(
+                        "The WIF key is not for network {}",
+                        network
+                    )));
+                }
+                private_key
+            }
+            Err(_) => Vec::<u8>::from_hex(key_str)
+                .ok()
+                .filter(|bytes| bytes.len() == 32)
+                .and_then(|bytes| PrivateKey::from_slice(&bytes, network).ok())
+                .ok_or_else(|| {
+                    BdkError::Generic(
+                        "Invalid single key, expected WIF or 32 hex encoded bytes".to_string(),
+                    )
+                })?,
+        };
+        Ok(Arc::new(Self {
+            descriptor_secret_key_mutex: Mutex::new(BdkDescriptorSecretKey::Single(SinglePriv {
+                origin: None,
+                key: private_key,
+            })),
+        }))
+    }
+    /// Get the fingerprints, origin, extended key fields and public key bytes of the key, with the
+    /// extended private key.
+    ///
+    /// Like the public fields, `xprv` describes the key after the hardened steps of its derivation
+    /// path, which are the steps a public key can't derive.
+    pub fn metadata(&self) -> Result<KeyMetadata, BdkError> {
+        let mut metadata = self.as_public()?.metadata();
+        if let BdkDescriptorSecretKey::XPrv(descriptor_x_key) =
+            self.descriptor_secret_key_mutex.lock().unwrap().deref()
+        {
+            let hardened_len = descriptor_x_key
+                .derivation_path
+                .into_iter()
+                .rposition(|child| child.is_hardened())
+                .map_or(0, |index| index + 1);
+            let hardened_path: BdkDerivationPath =
+                descriptor_x_key.derivation_path[..hardened_len].into();
+            let xprv = descriptor_x_key
+                .xkey
+                .derive_priv(&Secp256k1::new(), &hardened_path)?;
+            metadata.xprv = Some(xprv.to_string());
+        }
+        Ok(metadata)
+    }
     pub fn as_string(&self) -> String {
         self.descriptor_secret_key_mutex.lock().unwrap().to_string()
     }
@@ -174,7 +234,8 @@ pub struct DescriptorPublicKey {
 
 impl DescriptorPublicKey {
     pub fn from_string(key: String) -> Result<Arc<Self>, BdkError> {
-        let key = BdkDescriptorPublicKey::from_str(&*key).unwrap();
+        let key =
+            BdkDescriptorPublicKey::from_str(&key).map_err(|e| BdkError::Generic(e.to_string()))?;
         Ok(Arc::new(Self {
             descriptor_public_key_mutex: Mutex::new(key),
         }))
@@ -201,9 +262,9 @@ impl DescriptorPublicKey {
                     descriptor_public_key_mutex: Mutex::new(derived_descriptor_public_key),
                 }))
             }
-            BdkDescriptorPublicKey::Single(_) => {
-                unreachable!()
-            }
+            BdkDescriptorPublicKey::Single(_) => Err(BdkError::Generic(
+                "Can't derive a single key, only extended keys have child keys".to_string(),
+            )),
         }
     }
     pub fn extend(&self, path: Arc<DerivationPath>) -> Result<Arc<Self>, BdkError> {
@@ -222,8 +283,65 @@ impl DescriptorPublicKey {
                     descriptor_public_key_mutex: Mutex::new(extended_descriptor_public_key),
                 }))
             }
-            BdkDescriptorPublicKey::Single(_) => {
-                unreachable!()
+            BdkDescriptorPublicKey::Single(_) => Err(BdkError::Generic(
+                "Can't extend a single key with a derivation path".to_string(),
+            )),
+        }
+    }
+    /// Get the fingerprints, origin, extended key fields and public key bytes of the key.
+    pub fn metadata(&self) -> KeyMetadata {
+        let descriptor_public_key = self.descriptor_public_key_mutex.lock().unwrap();
+        let master_fingerprint = descriptor_public_key.master_fingerprint().to_string();
+        match descriptor_public_key.deref() {
+            BdkDescriptorPublicKey::XPub(descriptor_x_key) => {
+                let xkey = descriptor_x_key.xkey;
+                KeyMetadata {
+                    master_fingerprint,
+                    fingerprint: xkey.fingerprint().to_string(),
+                    origin_path: descriptor_x_key
+                        .origin
+                        .as_ref()
+                        .map(|(_, path)| path.to_string()),
+                    wildcard: descriptor_x_key.wildcard.into(),
+                    parent_fingerprint: Some(xkey.parent_fingerprint.to_string()),
+                    depth: Some(xkey.depth as u32),
+                    child_number: Some(u32::from(xkey.child_number)),
+                    chain_code: Some(xkey.chain_code.to_bytes().to_vec()),
+                    xpub: Some(xkey.to_string()),
+                    xprv: None,
+                    public_key: Some(xkey.public_key.serialize().to_vec()),
+                    x_only_public_key: xkey.public_key.x_only_public_key().0.serialize().to_vec(),
+                }
+            }
+            BdkDescriptorPublicKey::Single(single) => {
+                // Without an origin, the master fingerprint is the fingerprint of the key itself.
+                let fingerprint = BdkDescriptorPublicKey::Single(SinglePub {
+                    origin: None,
+                    key: single.key.clone(),
+                })
+                .master_fingerprint()
+                .to_string();
+                let (public_key, x_only_public_key) = match single.key {
+                    SinglePubKey::FullKey(key) => (
+                        Some(key.inner.serialize().to_vec()),
+                        key.inner.x_only_public_key().0.serialize().to_vec(),
+                    ),
+                    SinglePubKey::XOnly(key) => (None, key.serialize().to_vec()),
+                };
+                KeyMetadata {
+                    master_fingerprint,
+                    fingerprint,
+                    origin_path: single.origin.as_ref().map(|(_, path)| path.to_string()),
+                    wildcard: WildcardType::None,
+                    parent_fingerprint: None,
+                    depth: None,
+                    child_number: None,
+                    chain_code: None,
+                    xpub: None,
+                    xprv: None,
+                    public_key,
+                    x_only_public_key,
+                }
             }
         }
     }
@@ -231,9 +349,147 @@ impl DescriptorPublicKey {
         self.descriptor_public_key_mutex.lock().unwrap().to_string()
     }
 }
+
+/// The wildcard at the end of a descriptor key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WildcardType {
+    /// No wildcard, e.g. a single key or `xpub.../0`.
+    None,
+    /// `xpub.../*`.
+    Unhardened,
+    /// `xprv.../*'`, which can't be derived from the public key.
+    Hardened,
+}
+
+impl From<Wildcard> for WildcardType {
+    fn from(wildcard: Wildcard) -> Self {
+        match wildcard {
+            Wildcard::None => WildcardType::None,
+            Wildcard::Unhardened => WildcardType::Unhardened,
+            Wildcard::Hardened => WildcardType::Hardened,
+        }
+    }
+}
+
+/// The metadata of a descriptor key. The extended key fields are `None` for single keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMetadata {
+    /// The fingerprint from the key origin, or of the key itself without an origin.
+    pub master_fingerprint: String,
+    /// The fingerprint of the key itself.
+    pub fingerprint: String,
+    /// The derivation path from the master key, e.g. `m/84'/0'/0'`, if the key has an origin.
+    pub origin_path: Option<String>,
+    pub wildcard: WildcardType,
+    /// The fingerprint of the parent key, `00000000` for a master key.
+    pub parent_fingerprint: Option<String>,
+    pub depth: Option<u32>,
+    /// The child number, with the high bit set for hardened children.
+    pub child_number: Option<u32>,
+    pub chain_code: Option<Vec<u8>>,
+    /// The extended public key, without the origin nor the derivation steps after it.
+    pub xpub: Option<String>,
+    /// The extended private key, without the origin nor the derivation steps after it. Only for
+    /// secret keys.
+    pub xprv: Option<String>,
+    /// The 33 bytes compressed public key, before any derivation steps. `None` for x-only keys.
+    pub public_key: Option<Vec<u8>>,
+    /// The 32 bytes x-only public key, as used by taproot.
+    pub x_only_public_key: Vec<u8>,
+}
+/// The script types of extended public key versions, see SLIP-132.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slip132Version {
+    /// `xpub`, or `tpub` on the test networks: `pkh()`, or `sh()` multisig.
+    Standard,
+    /// `ypub`/`upub`: `sh(wpkh())`.
+    NestedSegwit,
+    /// `zpub`/`vpub`: `wpkh()`.
+    Segwit,
+    /// `Ypub`/`Upub`: `sh(wsh())` multisig.
+    NestedSegwitMultisig,
+    /// `Zpub`/`Vpub`: `wsh()` multisig.
+    SegwitMultisig,
+}
+
+/// The version bytes of each SLIP-132 version, for mainnet and the test networks.
+const SLIP132_VERSIONS: [(Slip132Version, [u8; 4], [u8; 4]); 5] = [
+    (
+        Slip132Version::Standard,
+        [0x04, 0x88, 0xb2, 0x1e],
+        [0x04, 0x35, 0x87, 0xcf],
+    ),
+    (
+        Slip132Version::NestedSegwit,
+        [0x04, 0x9d, 0x7c, 0xb2],
+        [0x04, 0x4a, 0x52, 0x62],
+    ),
+    (
+        Slip132Version::Segwit,
+        [0x04, 0xb2, 0x47, 0x46],
+        [0x04, 0x5f, 0x1c, 0xf6],
+    ),
+    (
+        Slip132Version::NestedSegwitMultisig,
+        [0x02, 0x95, 0xb4, 0x3f],
+        [0x02, 0x42, 0x89, 0xef],
+    ),
+    (
+        Slip132Version::SegwitMultisig,
+        [0x02, 0xaa, 0x7e, 0xd3],
+        [0x02, 0x57, 0x54, 0x83],
+    ),
+];
+
+/// Decode an extended public key with any SLIP-132 version, e.g. a `zpub`, into a standard one.
+pub(crate) fn slip132_to_standard(key: &str) -> Result<(ExtendedPubKey, Slip132Version), BdkError> {
+    let invalid_key = || BdkError::Generic(format!("Invalid extended public key {}", key));
+    let mut data = base58::from_check(key.trim()).map_err(|_| invalid_key())?;
+    if data.len() != 78 {
+        return Err(invalid_key());
+    }
+    let (version, mainnet) = SLIP132_VERSIONS
+        .iter()
+        .find_map(|(version, mainnet, testnet)| {
+            if data[0..4] == mainnet[..] {
+                Some((*version, true))
+            } else if data[0..4] == testnet[..] {
+                Some((*version, false))
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| BdkError::Generic(format!("Unsupported extended key version {}", key)))?;
+    let standard = if mainnet {
+        SLIP132_VERSIONS[0].1
+    } else {
+        SLIP132_VERSIONS[0].2
+    };
+    data[0..4].copy_from_slice(&standard);
+    let xpub = ExtendedPubKey::decode(&data).map_err(|_| invalid_key())?;
+    Ok((xpub, version))
+}
+
+/// Encode an extended public key with the SLIP-132 `version` of its network.
+pub(crate) fn standard_to_slip132(xpub: &ExtendedPubKey, version: Slip132Version) -> String {
+    let (_, mainnet, testnet) = SLIP132_VERSIONS
+        .iter()
+        .find(|(x, _, _)| *x == version)
+        .unwrap();
+    let mut data = xpub.encode();
+    data[0..4].copy_from_slice(match xpub.network {
+        Network::Bitcoin => mainnet,
+        _ => testnet,
+    });
+    base58::check_encode_slice(&data)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::key::{DerivationPath, DescriptorPublicKey, DescriptorSecretKey, Mnemonic};
+    use crate::key::{
+        slip132_to_standard, standard_to_slip132, DerivationPath, DescriptorPublicKey,
+        DescriptorSecretKey, Mnemonic, Slip132Version, WildcardType,
+    };
     use bdk::bitcoin::hashes::hex::ToHex;
     use bdk::bitcoin::Network;
     use bdk::Error as BdkError;
@@ -313,6 +569,137 @@ mod test {
         assert!(derived_dpk.is_err());
     }
 
+    #[test]
+    fn test_single_key() {
+        let secret_hex = "e93315d6ce401eb4db803a56232f0ed3e69b053774e6047df54f1bd00e5ea936";
+        let single_dsk =
+            DescriptorSecretKey::from_single(secret_hex.to_string(), Network::Testnet).unwrap();
+        assert_eq!(
+            single_dsk.as_string(),
+            "cVQ1bagBzrypkpC8vEGC2HXpJuDkid4Wdf1WaLk88XdAjRMEjanm"
+        );
+        assert_eq!(single_dsk.secret_bytes().unwrap().to_hex(), secret_hex);
+        let wif_dsk =
+            DescriptorSecretKey::from_single(single_dsk.as_string(), Network::Regtest).unwrap();
+        assert_eq!(wif_dsk.as_string(), single_dsk.as_string());
+        assert!(DescriptorSecretKey::from_single(
+            "L5328fgLZoHZbNisXpT4ey2kgfvM4AxpZcs3TvHcdQyAUgLsoncr".to_string(),
+            Network::Testnet
+        )
+        .is_err());
+        assert!(DescriptorSecretKey::from_single("e933".to_string(), Network::Testnet).is_err());
+
+        let single_dpk = single_dsk.as_public().unwrap();
+        assert_eq!(single_dpk.as_string().len(), 66);
+        assert_eq!(
+            DescriptorPublicKey::from_string(single_dpk.as_string())
+                .unwrap()
+                .as_string(),
+            single_dpk.as_string()
+        );
+
+        assert!(derive_dsk(&single_dsk, "m/0").is_err());
+        assert!(extend_dsk(&single_dsk, "m/0").is_err());
+        assert!(derive_dpk(&single_dpk, "m/0").is_err());
+        assert!(DescriptorSecretKey::from_string("not a key".to_string()).is_err());
+        assert!(DescriptorPublicKey::from_string("not a key".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_slip132_conversion() {
+        let tpub = "tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m";
+        let vpub = "vpub5VmR59DVLkizfQ4fzJdsR7X2nygGdzc8DTogjHxe95xektQ5AWD6ZBBE66kkSLCEiqPH9qi8NTJmzFbq9NX1MZPjXFDwn976QchSGairb9R";
+        let capital_vpub = "Vpub5gfWCNwvuiHN5yE3vy6rFBrqWmiXrMHiXjTMeZEBWro4P4xyvubNeJ39toiDzmR8xJTG2RiFFfhH7RDbSbfxW3WQMiLMBYa6JLyJrX8bphK";
+        let (xpub, version) = slip132_to_standard(vpub).unwrap();
+        assert_eq!(xpub.to_string(), tpub);
+        assert_eq!(version, Slip132Version::Segwit);
+        assert_eq!(
+            standard_to_slip132(&xpub, Slip132Version::SegwitMultisig),
+            capital_vpub
+        );
+        let (xpub, version) = slip132_to_standard(capital_vpub).unwrap();
+        assert_eq!(xpub.to_string(), tpub);
+        assert_eq!(version, Slip132Version::SegwitMultisig);
+        assert_eq!(standard_to_slip132(&xpub, Slip132Version::Segwit), vpub);
+        assert_eq!(
+            slip132_to_standard(tpub).unwrap().1,
+            Slip132Version::Standard
+        );
+
+        let (xpub, _) = slip132_to_standard("xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8").unwrap();
+        assert!(standard_to_slip132(&xpub, Slip132Version::Segwit).starts_with("zpub"));
+        assert!(
+            standard_to_slip132(&xpub, Slip132Version::NestedSegwitMultisig).starts_with("Ypub")
+        );
+        // A private key is not a public key version.
+        assert!(slip132_to_standard("tprv8ZgxMBicQKsPdWuqM1t1CDRvQtQuBPyfL6GbhQwtxDKgUAVPbxmj71pRA8raTqLrec5LyTs5TqCxdABcZr77bt2KyWA5bizJHnC4g4ysm4h").is_err());
+    }
+
+    #[test]
+    fn test_key_metadata() {
+        let master_dsk = get_descriptor_secret_key().unwrap();
+        let master = master_dsk.metadata().unwrap();
+        assert_eq!(master.master_fingerprint, "d1d04177");
+        assert_eq!(master.fingerprint, "d1d04177");
+        assert_eq!(master.parent_fingerprint.as_deref(), Some("00000000"));
+        assert_eq!(master.depth, Some(0));
+        assert_eq!(master.child_number, Some(0));
+        assert_eq!(master.origin_path, None);
+        assert_eq!(master.wildcard, WildcardType::Unhardened);
+        assert_eq!(master.xprv.as_deref(), Some("tprv8ZgxMBicQKsPdWuqM1t1CDRvQtQuBPyfL6GbhQwtxDKgUAVPbxmj71pRA8raTqLrec5LyTs5TqCxdABcZr77bt2KyWA5bizJHnC4g4ysm4h"));
+        assert_eq!(master.xpub.as_deref(), Some("tpubD6NzVbkrYhZ4WywdEfYbbd62yuvqLjAZuPsNyvzCNV85JekAEMbKHWSHLF9h3j45SxewXDcLv328B1SEZrxg4iwGfmdt1pDFjZiTkGiFqGa"));
+        assert_eq!(master.chain_code.as_ref().map(Vec::len), Some(32));
+        assert_eq!(master.public_key.as_ref().map(Vec::len), Some(33));
+        assert_eq!(master.x_only_public_key.len(), 32);
+        assert_eq!(
+            master.public_key.as_ref().unwrap()[1..],
+            master.x_only_public_key[..]
+        );
+        assert_eq!(master_dsk.as_public().unwrap().metadata().xprv, None);
+
+        let derived_dpk = derive_dsk(&master_dsk, "m/84'/1'/0'")
+            .unwrap()
+            .as_public()
+            .unwrap();
+        let derived = derived_dpk.metadata();
+        assert_eq!(derived.master_fingerprint, "d1d04177");
+        assert_ne!(derived.fingerprint, "d1d04177");
+        assert_eq!(derived.origin_path.as_deref(), Some("m/84'/1'/0'"));
+        assert_eq!(derived.depth, Some(3));
+        assert_eq!(derived.child_number, Some(0x8000_0000));
+        assert_eq!(derived.xpub.as_deref(), Some("tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6"));
+        // Extending with hardened steps reports the same keys as deriving them.
+        let extended = extend_dsk(&master_dsk, "m/84'/1'/0'/0")
+            .unwrap()
+            .metadata()
+            .unwrap();
+        let derived_xprv = derive_dsk(&master_dsk, "m/84'/1'/0'")
+            .unwrap()
+            .metadata()
+            .unwrap()
+            .xprv;
+        assert_eq!(extended.xprv, derived_xprv);
+        assert_eq!(extended.xpub, derived.xpub);
+        let child = derive_dpk(&derived_dpk, "m/0").unwrap().metadata();
+        assert_eq!(child.parent_fingerprint, Some(derived.fingerprint));
+        assert_eq!(child.master_fingerprint, "d1d04177");
+
+        let single_dsk = DescriptorSecretKey::from_single(
+            "cVQ1bagBzrypkpC8vEGC2HXpJuDkid4Wdf1WaLk88XdAjRMEjanm".to_string(),
+            Network::Testnet,
+        )
+        .unwrap();
+        let single = single_dsk.metadata().unwrap();
+        assert_eq!(single.public_key, master.public_key);
+        assert_eq!(single.fingerprint, "d1d04177");
+        assert_eq!(single.master_fingerprint, "d1d04177");
+        assert_eq!(single.wildcard, WildcardType::None);
+        assert_eq!(single.xpub, None);
+        assert_eq!(single.xprv, None);
+        assert_eq!(single.depth, None);
+        assert_eq!(single.chain_code, None);
+    }
+
     #[test]
     fn test_retrieve_master_secret_key() {
         let master_dpk = get_descriptor_secret_key();
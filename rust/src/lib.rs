@@ -2,9 +2,15 @@
 extern crate core;
 mod blockchain;
 mod bridge_generated;
+mod bsms;
 mod descriptor;
+mod import;
 mod key;
+mod multisig;
+mod payjoin;
 mod psbt;
+mod psbt_v2;
 mod r_api;
 mod types;
+mod ur;
 mod wallet;
@@ -0,0 +1,474 @@
+use crate::descriptor::BdkDescriptor;
+use crate::psbt::{input_utxo, FinalizedPsbt, PartiallySignedTransaction};
+use bdk::bitcoin::secp256k1::Secp256k1;
+use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+use bdk::bitcoin::{Network, PublicKey as BdkPublicKey};
+use bdk::keys::DescriptorPublicKey as BdkDescriptorPublicKey;
+use bdk::miniscript::{Descriptor, ForEachKey};
+use bdk::Error as BdkError;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// The derivation indexes searched for inputs that carry no BIP32 derivations.
+const FINALIZE_LOOKAHEAD: u32 = 100;
+
+lazy_static! {
+    static ref MULTISIG_SESSION: RwLock<HashMap<String, Arc<MultisigSession>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// The signing status of one cosigner of a multisig session.
+pub struct CosignerStatus {
+    /// The master key fingerprint of the cosigner.
+    pub fingerprint: String,
+    /// The inputs carrying a signature from this cosigner.
+    pub signed_inputs: Vec<u32>,
+    /// Whether the cosigner signed every input that is not finalized yet.
+    pub has_signed: bool,
+}
+
+/// The state of a multisig session.
+pub struct MultisigStatus {
+    /// Signatures needed for each input.
+    pub threshold: u32,
+    pub cosigners: Vec<CosignerStatus>,
+    /// The number of cosigner signatures on each input, `threshold` for finalized inputs.
+    pub signatures: Vec<u32>,
+    /// Whether every input has enough signatures to be finalized.
+    pub is_ready: bool,
+    /// The session PSBT, base64 encoded.
+    pub psbt: String,
+}
+
+/// What is saved when exporting a session.
+#[derive(Serialize, Deserialize)]
+struct SessionRecord {
+    descriptor: String,
+    change_descriptor: Option<String>,
+    network: String,
+    psbt: String,
+}
+
+/// Collects the signatures of the cosigners of a multisig wallet on one PSBT.
+pub struct MultisigSession {
+    descriptors: Vec<BdkDescriptor>,
+    network: Network,
+    threshold: u32,
+    cosigners: Vec<String>,
+    psbt_mutex: Mutex<BdkPartiallySignedTransaction>,
+}
+
+impl MultisigSession {
+    /// Start a session for a PSBT spending from a `multi` or `sortedmulti` descriptor and return
+    /// its id.
+    pub fn new(
+        descriptor: String,
+        change_descriptor: Option<String>,
+        network: Network,
+        psbt: &PartiallySignedTransaction,
+    ) -> Result<String, BdkError> {
+        let mut descriptors = vec![BdkDescriptor::new(descriptor, network)?];
+        if let Some(change_descriptor) = change_descriptor {
+            descriptors.push(BdkDescriptor::new(change_descriptor, network)?);
+        }
+        let (threshold, keys) = multisig_keys(&descriptors[0])?;
+        let mut cosigners: Vec<String> = Vec::new();
+        for key in keys {
+            let fingerprint = key.master_fingerprint().to_string();
+            if cosigners.contains(&fingerprint) {
+                return Err(BdkError::Generic(format!(
+                    "More than one cosigner key has the fingerprint {}",
+                    fingerprint
+                )));
+            }
+            cosigners.push(fingerprint);
+        }
+        if descriptors.len() > 1 {
+            let (change_threshold, change_keys) = multisig_keys(&descriptors[1])?;
+            let mut change_cosigners: Vec<String> = change_keys
+                .iter()
+                .map(|x| x.master_fingerprint().to_string())
+                .collect();
+            let mut sorted_cosigners = cosigners.clone();
+            change_cosigners.sort();
+            sorted_cosigners.sort();
+            if change_threshold != threshold || change_cosigners != sorted_cosigners {
+                return Err(BdkError::Generic(
+                    "The change descriptor has different cosigners or threshold".to_string(),
+                ));
+            }
+        }
+        let session = MultisigSession {
+            descriptors,
+            network,
+            threshold: threshold as u32,
+            cosigners,
+            psbt_mutex: Mutex::new(psbt.internal.lock().unwrap().clone()),
+        };
+        let id = format!("{:016x}", rand::random::<u64>());
+        MULTISIG_SESSION
+            .write()
+            .unwrap()
+            .insert(id.clone(), Arc::new(session));
+        Ok(id)
+    }
+
+    /// Restore a session saved with [`MultisigSession::export`] and return its new id.
+    pub fn restore(data: &str) -> Result<String, BdkError> {
+        let record: SessionRecord = serde_json::from_str(data)
+            .map_err(|e| BdkError::Generic(format!("Invalid multisig session: {}", e)))?;
+        let network = Network::from_str(&record.network)
+            .map_err(|e| BdkError::Generic(format!("Invalid multisig session: {}", e)))?;
+        let psbt = PartiallySignedTransaction::new(record.psbt)?;
+        MultisigSession::new(record.descriptor, record.change_descriptor, network, &psbt)
+    }
+
+    pub fn retrieve_session(id: String) -> Arc<MultisigSession> {
+        let session_lock = MULTISIG_SESSION.read().unwrap();
+        session_lock.get(id.as_str()).unwrap().clone()
+    }
+    pub fn remove_session(id: String) {
+        MULTISIG_SESSION.write().unwrap().remove(id.as_str());
+    }
+
+    /// Save the session so it can be restored later, e.g. after the app restarts. Private keys
+    /// in the descriptors are not saved.
+    pub(crate) fn export(&self) -> Result<String, BdkError> {
+        let record = SessionRecord {
+            descriptor: self.descriptors[0].as_string(),
+            change_descriptor: self.descriptors.get(1).map(|x| x.as_string()),
+            network: self.network.to_string(),
+            psbt: self.psbt().serialize()?,
+        };
+        Ok(serde_json::to_string(&record).unwrap())
+    }
+
+    /// Merge a copy of the session PSBT signed by a cosigner.
+    pub(crate) fn add_psbt(
+        &self,
+        signed: PartiallySignedTransaction,
+    ) -> Result<MultisigStatus, BdkError> {
+        let combined = PartiallySignedTransaction::combine_all(&[self.psbt(), signed])?;
+        let combined = PartiallySignedTransaction::new(combined.psbt)?;
+        *self.psbt_mutex.lock().unwrap() = combined.internal.into_inner().unwrap();
+        self.status()
+    }
+
+    pub(crate) fn status(&self) -> Result<MultisigStatus, BdkError> {
+        let psbt = self.psbt_mutex.lock().unwrap();
+        let mut cosigners: Vec<CosignerStatus> = self
+            .cosigners
+            .iter()
+            .map(|fingerprint| CosignerStatus {
+                fingerprint: fingerprint.clone(),
+                signed_inputs: vec![],
+                has_signed: true,
+            })
+            .collect();
+        let mut signatures = Vec::with_capacity(psbt.inputs.len());
+        for (index, input) in psbt.inputs.iter().enumerate() {
+            if input.final_script_sig.is_some() || input.final_script_witness.is_some() {
+                signatures.push(self.threshold);
+                continue;
+            }
+            // Signatures without BIP32 derivation are matched against the descriptor keys.
+            let derived_keys = if input
+                .partial_sigs
+                .keys()
+                .any(|key| !input.bip32_derivation.contains_key(&key.inner))
+            {
+                self.derived_keys(&psbt, index)
+            } else {
+                HashMap::new()
+            };
+            let mut count = 0;
+            for cosigner in cosigners.iter_mut() {
+                let signed = input.partial_sigs.keys().any(|key| {
+                    input
+                        .bip32_derivation
+                        .get(&key.inner)
+                        .map(|(fingerprint, _)| fingerprint.to_string())
+                        .or_else(|| derived_keys.get(key).cloned())
+                        .is_some_and(|fingerprint| fingerprint == cosigner.fingerprint)
+                });
+                if signed {
+                    cosigner.signed_inputs.push(index as u32);
+                    count += 1;
+                } else {
+                    cosigner.has_signed = false;
+                }
+            }
+            signatures.push(count);
+        }
+        Ok(MultisigStatus {
+            threshold: self.threshold,
+            cosigners,
+            is_ready: signatures.iter().all(|x| *x >= self.threshold),
+            signatures,
+            psbt: PartiallySignedTransaction {
+                internal: Mutex::new(psbt.clone()),
+            }
+            .serialize()?,
+        })
+    }
+
+    /// Finalize the session PSBT once every input has enough signatures.
+    pub(crate) fn finalize(&self) -> Result<FinalizedPsbt, BdkError> {
+        let status = self.status()?;
+        if !status.is_ready {
+            let missing = status
+                .signatures
+                .iter()
+                .filter(|x| **x < self.threshold)
+                .count();
+            return Err(BdkError::Generic(format!(
+                "{} input(s) have fewer than {} signatures",
+                missing, self.threshold
+            )));
+        }
+        let psbt = self.psbt();
+        let inputs = psbt.finalize_with_descriptors(&self.descriptors, FINALIZE_LOOKAHEAD)?;
+        let finalized = FinalizedPsbt::new(&psbt, inputs)?;
+        *self.psbt_mutex.lock().unwrap() = psbt.internal.into_inner().unwrap();
+        Ok(finalized)
+    }
+
+    /// The cosigner keys of the UTXO spent by an input, with their fingerprints, when it was
+    /// derived within the first `FINALIZE_LOOKAHEAD` indexes.
+    fn derived_keys(
+        &self,
+        psbt: &BdkPartiallySignedTransaction,
+        index: usize,
+    ) -> HashMap<BdkPublicKey, String> {
+        let secp = Secp256k1::verification_only();
+        let mut keys = HashMap::new();
+        let descriptor = input_utxo(psbt, index).and_then(|utxo| {
+            self.descriptors
+                .iter()
+                .find_map(|x| x.find_derivation(&utxo.script_pubkey, vec![], FINALIZE_LOOKAHEAD))
+        });
+        if let Some(descriptor) = descriptor {
+            descriptor.for_each_key(|key| {
+                if let Ok(public_key) = key.derive_public_key(&secp) {
+                    keys.insert(public_key, key.master_fingerprint().to_string());
+                }
+                true
+            });
+        }
+        keys
+    }
+
+    fn psbt(&self) -> PartiallySignedTransaction {
+        PartiallySignedTransaction {
+            internal: Mutex::new(self.psbt_mutex.lock().unwrap().clone()),
+        }
+    }
+}
+
+/// The threshold and keys of a `multi` or `sortedmulti` descriptor.
+fn multisig_keys(
+    descriptor: &BdkDescriptor,
+) -> Result<(usize, Vec<BdkDescriptorPublicKey>), BdkError> {
+    match descriptor.extended_descriptor {
+        Descriptor::Tr(_) => None,
+        _ => descriptor.multisig(),
+    }
+    .ok_or_else(|| {
+        BdkError::Generic(
+            "Only sh, wsh and sh-wsh multi or sortedmulti descriptors are supported".to_string(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::BdkDescriptor;
+    use crate::multisig::MultisigSession;
+    use crate::psbt::PartiallySignedTransaction;
+    use bdk::bitcoin::secp256k1::Secp256k1;
+    use bdk::bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey, ExtendedPubKey};
+    use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+    use bdk::bitcoin::{Network, OutPoint, PackedLockTime, Sequence, Transaction, TxIn, TxOut};
+    use bdk::database::MemoryDatabase;
+    use bdk::miniscript::psbt::PsbtExt;
+    use bdk::{SignOptions, Wallet};
+    use std::str::FromStr;
+    use std::sync::Mutex;
+
+    const DESCRIPTOR: &str = "wsh(sortedmulti(2,tpubD6NzVbkrYhZ4WywdEfYbbd62yuvqLjAZuPsNyvzCNV85JekAEMbKHWSHLF9h3j45SxewXDcLv328B1SEZrxg4iwGfmdt1pDFjZiTkGiFqGa/0/*,tpubD9oaCiP1MPmQdndm7DCD3D3QU34pWd6BbKSRedoZF1UJcNhEk3PJwkALNYkhxeTKL29oGNR7psqvT1KZydCGqUDEKXN6dVQJY2R8ooLPy8m/0/*,tpubDCoPjomfTqh1e7o1WgGpQtARWtkueXQAepTeNpWiitS3Sdv8RKJ1yvTrGHcwjDXp2SKyMrTEca4LoN7gEUiGCWboyWe2rz99Kf4jK4m2Zmx/0/*))";
+
+    const MASTER_KEY: &str = "tprv8ZgxMBicQKsPdWuqM1t1CDRvQtQuBPyfL6GbhQwtxDKgUAVPbxmj71pRA8raTqLrec5LyTs5TqCxdABcZr77bt2KyWA5bizJHnC4g4ysm4h";
+
+    fn unsigned_psbt() -> PartiallySignedTransaction {
+        psbt_for(DESCRIPTOR)
+    }
+
+    /// An unsigned PSBT spending a UTXO of `descriptor` at index 0.
+    fn psbt_for(descriptor: &str) -> PartiallySignedTransaction {
+        let descriptor = BdkDescriptor::new(descriptor.to_string(), Network::Testnet).unwrap();
+        let script_pubkey = descriptor
+            .extended_descriptor
+            .at_derivation_index(0)
+            .script_pubkey();
+        let tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime(0),
+            input: vec![TxIn {
+                previous_output: OutPoint::default(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                ..Default::default()
+            }],
+            output: vec![TxOut {
+                value: 90_000,
+                script_pubkey: script_pubkey.clone(),
+            }],
+        };
+        let mut psbt = BdkPartiallySignedTransaction::from_unsigned_tx(tx).unwrap();
+        psbt.inputs[0].witness_utxo = Some(TxOut {
+            value: 100_000,
+            script_pubkey,
+        });
+        PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        }
+    }
+
+    #[test]
+    fn test_session_status() {
+        let id = MultisigSession::new(
+            DESCRIPTOR.to_string(),
+            None,
+            Network::Testnet,
+            &unsigned_psbt(),
+        )
+        .unwrap();
+        let status = MultisigSession::retrieve_session(id.clone())
+            .status()
+            .unwrap();
+        assert_eq!(status.threshold, 2);
+        assert_eq!(status.cosigners.len(), 3);
+        assert!(status.cosigners.iter().any(|x| x.fingerprint == "d1d04177"));
+        assert!(status.cosigners.iter().all(|x| !x.has_signed));
+        assert_eq!(status.signatures, vec![0]);
+        assert!(!status.is_ready);
+        assert!(MultisigSession::retrieve_session(id.clone())
+            .finalize()
+            .is_err());
+        MultisigSession::remove_session(id);
+    }
+
+    #[test]
+    fn test_session_export_restore() {
+        let id = MultisigSession::new(
+            DESCRIPTOR.to_string(),
+            None,
+            Network::Testnet,
+            &unsigned_psbt(),
+        )
+        .unwrap();
+        let session = MultisigSession::retrieve_session(id.clone());
+        let restored_id = MultisigSession::restore(&session.export().unwrap()).unwrap();
+        let restored = MultisigSession::retrieve_session(restored_id.clone());
+        assert_ne!(restored_id, id);
+        assert_eq!(restored.export().unwrap(), session.export().unwrap());
+        assert_eq!(
+            restored.status().unwrap().psbt,
+            session.status().unwrap().psbt
+        );
+        MultisigSession::remove_session(id);
+        MultisigSession::remove_session(restored_id);
+    }
+
+    #[test]
+    fn test_session_finalize() {
+        let secp = Secp256k1::new();
+        let master = ExtendedPrivKey::from_str(MASTER_KEY).unwrap();
+        let xprvs: Vec<ExtendedPrivKey> = (0..3)
+            .map(|i| {
+                master
+                    .derive_priv(&secp, &[ChildNumber::from_hardened_idx(i).unwrap()])
+                    .unwrap()
+            })
+            .collect();
+        let xpubs: Vec<String> = xprvs
+            .iter()
+            .map(|x| ExtendedPubKey::from_priv(&secp, x).to_string())
+            .collect();
+        let descriptor = |signer: Option<usize>| {
+            let keys: Vec<String> = (0..3)
+                .map(|i| match signer {
+                    Some(signer) if signer == i => format!("{}/0/*", xprvs[i]),
+                    _ => format!("{}/0/*", xpubs[i]),
+                })
+                .collect();
+            format!("wsh(sortedmulti(2,{}))", keys.join(","))
+        };
+        let unsigned = psbt_for(&descriptor(None));
+        let id = MultisigSession::new(descriptor(None), None, Network::Testnet, &unsigned).unwrap();
+        let session = MultisigSession::retrieve_session(id.clone());
+        let bdk_descriptor = BdkDescriptor::new(descriptor(None), Network::Testnet).unwrap();
+        let sign = |signer: usize| {
+            let mut psbt = unsigned.internal.lock().unwrap().clone();
+            psbt.update_input_with_descriptor(
+                0,
+                &bdk_descriptor.extended_descriptor.at_derivation_index(0),
+            )
+            .unwrap();
+            let wallet = Wallet::new(
+                descriptor(Some(signer)).as_str(),
+                None,
+                Network::Testnet,
+                MemoryDatabase::default(),
+            )
+            .unwrap();
+            let options = SignOptions {
+                trust_witness_utxo: true,
+                try_finalize: false,
+                ..Default::default()
+            };
+            wallet.sign(&mut psbt, options).unwrap();
+            assert_eq!(psbt.inputs[0].partial_sigs.len(), 1);
+            // Cosigners may strip the BIP32 derivations from the PSBTs they return.
+            psbt.inputs[0].bip32_derivation.clear();
+            PartiallySignedTransaction {
+                internal: Mutex::new(psbt),
+            }
+        };
+
+        let status = session.add_psbt(sign(0)).unwrap();
+        assert_eq!(status.signatures, vec![1]);
+        assert!(!status.is_ready);
+        let signer = xprvs[0].fingerprint(&secp).to_string();
+        for cosigner in status.cosigners {
+            assert_eq!(cosigner.has_signed, cosigner.fingerprint == signer);
+        }
+        assert!(session.finalize().is_err());
+
+        let status = session.add_psbt(sign(2)).unwrap();
+        assert_eq!(status.signatures, vec![2]);
+        assert!(status.is_ready);
+        assert_eq!(status.cosigners.iter().filter(|x| x.has_signed).count(), 2);
+        let finalized = session.finalize().unwrap();
+        assert!(finalized.is_finalized);
+        let psbt = PartiallySignedTransaction::new(finalized.psbt).unwrap();
+        let tx = psbt.internal.lock().unwrap().clone().extract_tx();
+        // The witness holds the empty CHECKMULTISIG dummy, two signatures and the script.
+        assert_eq!(tx.input[0].witness.len(), 4);
+        MultisigSession::remove_session(id);
+    }
+
+    #[test]
+    fn test_not_multisig() {
+        let descriptor = "wpkh(tpubD6NzVbkrYhZ4WywdEfYbbd62yuvqLjAZuPsNyvzCNV85JekAEMbKHWSHLF9h3j45SxewXDcLv328B1SEZrxg4iwGfmdt1pDFjZiTkGiFqGa/0/*)";
+        assert!(MultisigSession::new(
+            descriptor.to_string(),
+            None,
+            Network::Testnet,
+            &unsigned_psbt()
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,902 @@
+use crate::psbt::{input_utxo, psbt_fee_amount};
+use crate::types::Address;
+use crate::wallet::{SignOptions, Wallet, TXIN_BASE_WEIGHT};
+use bdk::bitcoin::consensus::encode::VarInt;
+use bdk::bitcoin::util::amount::{Amount, Denomination};
+use bdk::bitcoin::util::psbt::{
+    Input as BdkInput, PartiallySignedTransaction as BdkPartiallySignedTransaction,
+};
+use bdk::bitcoin::{OutPoint as BdkOutPoint, Script as BdkScript, TxIn as BdkTxIn, Witness};
+use bdk::database::AnyDatabase;
+use bdk::{Error as BdkError, FeeRate, SignOptions as BdkSignOptions, Wallet as BdkWallet};
+use rand::Rng;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// A BIP21 payment request carrying a BIP78 PayJoin endpoint.
+#[derive(Clone, Debug)]
+pub struct PayjoinUri {
+    /// The address to pay.
+    pub address: String,
+    /// The requested amount in sats, if any.
+    pub amount: Option<u64>,
+    /// The receiver's PayJoin endpoint (`pj=`).
+    pub endpoint: String,
+    /// Whether the receiver asked the sender to refuse output substitution (`pjos=0`).
+    pub disable_output_substitution: bool,
+}
+
+impl PayjoinUri {
+    pub(crate) fn new(uri: String) -> Result<Self, BdkError> {
+        let uri = uri.trim();
+        let rest = match uri.get(..8) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("bitcoin:") => &uri[8..],
+            _ => return Err(BdkError::Generic("Not a BIP21 URI".to_string())),
+        };
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let address = Address::new(address.to_string())?;
+
+        let mut amount = None;
+        let mut endpoint = None;
+        let mut disable_output_substitution = false;
+        for (key, value) in parse_query(query)? {
+            match key.as_str() {
+                "amount" => {
+                    amount = Some(
+                        Amount::from_str_in(&value, Denomination::Bitcoin)
+                            .map_err(|e| BdkError::Generic(e.to_string()))?
+                            .to_sat(),
+                    )
+                }
+                "pj" => endpoint = Some(value),
+                "pjos" => disable_output_substitution = value == "0",
+                _ => {}
+            }
+        }
+        let endpoint = endpoint
+            .ok_or_else(|| BdkError::Generic("The URI has no PayJoin endpoint".to_string()))?;
+        Ok(PayjoinUri {
+            address: address.address.to_string(),
+            amount,
+            endpoint,
+            disable_output_substitution,
+        })
+    }
+}
+
+/// Optional parameters a PayJoin sender passes to the receiver, as described by BIP78.
+#[derive(Clone, Debug, Default)]
+pub struct PayjoinParams {
+    /// Index of the sender's output that may be reduced to pay for the receiver's inputs.
+    pub additional_fee_output_index: Option<u32>,
+    /// Maximum sats the sender accepts to pay for the receiver's inputs.
+    pub max_additional_fee_contribution: Option<u64>,
+    /// Minimum fee rate (sat/vB) the payjoin transaction must pay.
+    pub min_fee_rate: Option<f32>,
+    /// Whether the receiver may not substitute the payee output.
+    pub disable_output_substitution: bool,
+}
+
+impl PayjoinParams {
+    fn to_query(&self) -> String {
+        let mut query = "v=1".to_string();
+        if let (Some(index), Some(contribution)) = (
+            self.additional_fee_output_index,
+            self.max_additional_fee_contribution,
+        ) {
+            query.push_str(&format!(
+                "&additionalfeeoutputindex={}&maxadditionalfeecontribution={}",
+                index, contribution
+            ));
+        }
+        if let Some(min_fee_rate) = self.min_fee_rate {
+            query.push_str(&format!("&minfeerate={}", min_fee_rate));
+        }
+        if self.disable_output_substitution {
+            query.push_str("&disableoutputsubstitution=true");
+        }
+        query
+    }
+
+    fn from_query(query: &str) -> Result<Self, BdkError> {
+        let mut params = PayjoinParams::default();
+        for (key, value) in parse_query(query)? {
+            let invalid = || BdkError::Generic(format!("Invalid PayJoin parameter {}", key));
+            match key.as_str() {
+                "v" if value != "1" => {
+                    return Err(BdkError::Generic(format!(
+                        "Unsupported PayJoin version {}",
+                        value
+                    )))
+                }
+                "additionalfeeoutputindex" => {
+                    params.additional_fee_output_index = Some(value.parse().map_err(|_| invalid())?)
+                }
+                "maxadditionalfeecontribution" => {
+                    params.max_additional_fee_contribution =
+                        Some(value.parse().map_err(|_| invalid())?)
+                }
+                "minfeerate" => params.min_fee_rate = Some(value.parse().map_err(|_| invalid())?),
+                "disableoutputsubstitution" => params.disable_output_substitution = value == "true",
+                _ => {}
+            }
+        }
+        Ok(params)
+    }
+}
+
+/// The HTTP request a PayJoin sender posts to the receiver's endpoint with `Content-Type: text/plain`.
+pub struct PayjoinRequest {
+    /// The endpoint with the BIP78 parameters appended to its query string.
+    pub url: String,
+    /// The signed original PSBT, base64 encoded. It doubles as the fallback transaction to
+    /// broadcast if the PayJoin fails.
+    pub body: String,
+}
+
+/// Posts a PayJoin request and returns the receiver's response body.
+///
+/// Implement this to plug in the HTTP client of your choice, or to talk to an in-process receiver.
+pub trait PayjoinTransport {
+    fn post(&self, url: &str, body: &str) -> Result<String, BdkError>;
+}
+
+/// Posts PayJoin requests over HTTPS, or plain HTTP to an onion service, as BIP78 requires.
+pub(crate) struct HttpTransport;
+
+impl PayjoinTransport for HttpTransport {
+    fn post(&self, url: &str, body: &str) -> Result<String, BdkError> {
+        let host = url
+            .split('/')
+            .nth(2)
+            .and_then(|x| x.split(':').next())
+            .unwrap_or_default();
+        if !url.starts_with("https://") && !host.ends_with(".onion") {
+            return Err(BdkError::Generic(format!(
+                "The PayJoin endpoint {} is neither HTTPS nor an onion service",
+                url
+            )));
+        }
+        let response = match ureq::post(url)
+            .set("Content-Type", "text/plain")
+            .send_string(body)
+        {
+            Ok(e) => e,
+            Err(ureq::Error::Status(code, response)) => {
+                return Err(BdkError::Generic(format!(
+                    "The PayJoin receiver refused the request ({}): {}",
+                    code,
+                    response.into_string().unwrap_or_default()
+                )))
+            }
+            Err(e) => {
+                return Err(BdkError::Generic(format!(
+                    "The PayJoin request failed: {}",
+                    e
+                )))
+            }
+        };
+        response
+            .into_string()
+            .map_err(|e| BdkError::Generic(format!("Invalid PayJoin response: {}", e)))
+    }
+}
+
+/// The sender side of a PayJoin, holding the signed original PSBT the proposal is checked against.
+pub(crate) struct PayjoinSender {
+    uri: PayjoinUri,
+    params: PayjoinParams,
+    original: BdkPartiallySignedTransaction,
+}
+
+impl PayjoinSender {
+    /// Sign `psbt`, as returned by `tx_builder_finish`, to produce the original PSBT.
+    pub(crate) fn new(
+        wallet: &Wallet,
+        uri: PayjoinUri,
+        mut psbt: BdkPartiallySignedTransaction,
+        params: PayjoinParams,
+        sign_options: Option<SignOptions>,
+    ) -> Result<Self, BdkError> {
+        let mut options: BdkSignOptions = sign_options.map(SignOptions::into).unwrap_or_default();
+        options.try_finalize = true;
+        if !wallet.get_wallet().sign(&mut psbt, options)? {
+            return Err(BdkError::Generic(
+                "The original PSBT could not be finalized".to_string(),
+            ));
+        }
+        Self::from_original(uri, psbt, params)
+    }
+
+    /// Resume a PayJoin from an original PSBT that has already been signed and finalized.
+    pub(crate) fn from_original(
+        uri: PayjoinUri,
+        original: BdkPartiallySignedTransaction,
+        mut params: PayjoinParams,
+    ) -> Result<Self, BdkError> {
+        let payee_script = Address::new(uri.address.clone())?.script_pubkey();
+        if !original
+            .unsigned_tx
+            .output
+            .iter()
+            .any(|x| x.script_pubkey == payee_script)
+        {
+            return Err(BdkError::Generic(
+                "The original PSBT does not pay the PayJoin receiver".to_string(),
+            ));
+        }
+        params.disable_output_substitution |= uri.disable_output_substitution;
+        Ok(PayjoinSender {
+            uri,
+            params,
+            original,
+        })
+    }
+
+    pub(crate) fn request(&self) -> PayjoinRequest {
+        let separator = if self.uri.endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        PayjoinRequest {
+            url: format!(
+                "{}{}{}",
+                self.uri.endpoint,
+                separator,
+                self.params.to_query()
+            ),
+            body: self.original.to_string(),
+        }
+    }
+
+    /// Post the original PSBT through `transport` and process the receiver's proposal.
+    pub(crate) fn send(
+        &self,
+        transport: &dyn PayjoinTransport,
+        wallet: &Wallet,
+        sign_options: Option<SignOptions>,
+    ) -> Result<BdkPartiallySignedTransaction, BdkError> {
+        let request = self.request();
+        let response = transport.post(&request.url, &request.body)?;
+        self.process_response(wallet, response, sign_options)
+    }
+
+    /// Run the BIP78 sender checks on the receiver's proposal, then sign and finalize our inputs.
+    pub(crate) fn process_response(
+        &self,
+        wallet: &Wallet,
+        response: String,
+        sign_options: Option<SignOptions>,
+    ) -> Result<BdkPartiallySignedTransaction, BdkError> {
+        let mut proposal = BdkPartiallySignedTransaction::from_str(response.trim())?;
+        let bdk_wallet = wallet.get_wallet();
+        self.check_proposal(&bdk_wallet, &proposal)?;
+
+        // The receiver strips our inputs down to the outpoint; restore the UTXO data so that the
+        // wallet can find and sign them again.
+        for (txin, input) in proposal
+            .unsigned_tx
+            .input
+            .iter()
+            .zip(proposal.inputs.iter_mut())
+        {
+            if let Some(index) = self
+                .original
+                .unsigned_tx
+                .input
+                .iter()
+                .position(|x| x.previous_output == txin.previous_output)
+            {
+                let original = &self.original.inputs[index];
+                input.witness_utxo = original.witness_utxo.clone();
+                input.non_witness_utxo = original.non_witness_utxo.clone();
+            }
+        }
+
+        let mut options: BdkSignOptions = sign_options.map(SignOptions::into).unwrap_or_default();
+        options.try_finalize = true;
+        if !bdk_wallet.sign(&mut proposal, options)? {
+            return Err(BdkError::Generic(
+                "The PayJoin proposal could not be finalized".to_string(),
+            ));
+        }
+        let fee = psbt_fee_amount(&proposal).ok_or(BdkError::FeeRateUnavailable)?;
+        let fee_rate = FeeRate::from_wu(fee, proposal.clone().extract_tx().weight());
+        let original_fee_rate = self.original_fee_rate()?;
+        if fee_rate < original_fee_rate {
+            return Err(BdkError::Generic(format!(
+                "The PayJoin proposal pays {} sat/vB, below the {} sat/vB of the original",
+                fee_rate.as_sat_per_vb(),
+                original_fee_rate.as_sat_per_vb()
+            )));
+        }
+        if let Some(min_fee_rate) = self.params.min_fee_rate {
+            if fee_rate < FeeRate::from_sat_per_vb(min_fee_rate) {
+                return Err(BdkError::Generic(format!(
+                    "The PayJoin proposal pays {} sat/vB, below the minimum of {} sat/vB",
+                    fee_rate.as_sat_per_vb(),
+                    min_fee_rate
+                )));
+            }
+        }
+        Ok(proposal)
+    }
+
+    /// The fee rate of the finalized original transaction.
+    fn original_fee_rate(&self) -> Result<FeeRate, BdkError> {
+        let fee = psbt_fee_amount(&self.original).ok_or(BdkError::FeeRateUnavailable)?;
+        Ok(FeeRate::from_wu(
+            fee,
+            self.original.clone().extract_tx().weight(),
+        ))
+    }
+
+    fn check_proposal(
+        &self,
+        wallet: &BdkWallet<AnyDatabase>,
+        proposal: &BdkPartiallySignedTransaction,
+    ) -> Result<(), BdkError> {
+        let reject = |reason: &str| {
+            Err(BdkError::Generic(format!(
+                "Invalid PayJoin proposal: {}",
+                reason
+            )))
+        };
+        let original_tx = &self.original.unsigned_tx;
+        let proposal_tx = &proposal.unsigned_tx;
+        if proposal_tx.version != original_tx.version {
+            return reject("the transaction version changed");
+        }
+        if proposal_tx.lock_time != original_tx.lock_time {
+            return reject("the transaction locktime changed");
+        }
+        if proposal.inputs.len() != proposal_tx.input.len()
+            || proposal.outputs.len() != proposal_tx.output.len()
+        {
+            return reject("the PSBT maps do not match the transaction");
+        }
+
+        let original_inputs: HashMap<BdkOutPoint, &BdkTxIn> = original_tx
+            .input
+            .iter()
+            .map(|x| (x.previous_output, x))
+            .collect();
+        // When all our inputs share a script type, the receiver's must have it too, otherwise
+        // they would stand out as not ours.
+        let sender_types: Vec<Option<ScriptType>> = (0..self.original.inputs.len())
+            .map(|index| {
+                input_utxo(&self.original, index).map(|x| ScriptType::of(&x.script_pubkey))
+            })
+            .collect();
+        let sender_type = match sender_types.first() {
+            Some(Some(first)) if sender_types.iter().all(|x| *x == Some(*first)) => Some(*first),
+            _ => None,
+        };
+        let mut receiver_weight = 0;
+        let mut sender_inputs = 0;
+        for (index, (txin, input)) in proposal_tx
+            .input
+            .iter()
+            .zip(proposal.inputs.iter())
+            .enumerate()
+        {
+            if let Some(original) = original_inputs.get(&txin.previous_output) {
+                if txin.sequence != original.sequence {
+                    return reject("the sequence of one of our inputs changed");
+                }
+                if input.final_script_sig.is_some()
+                    || input.final_script_witness.is_some()
+                    || !input.partial_sigs.is_empty()
+                    || !input.bip32_derivation.is_empty()
+                {
+                    return reject("one of our inputs carries signatures or key paths");
+                }
+                sender_inputs += 1;
+            } else {
+                if input.final_script_sig.is_none() && input.final_script_witness.is_none() {
+                    return reject("a receiver input is not finalized");
+                }
+                let utxo = match input_utxo(proposal, index) {
+                    Some(e) => e,
+                    None => return reject("a receiver input has no UTXO information"),
+                };
+                if wallet.is_mine(&utxo.script_pubkey)? {
+                    return reject("a receiver input belongs to this wallet");
+                }
+                if sender_type.is_some_and(|x| x != ScriptType::of(&utxo.script_pubkey)) {
+                    return reject("a receiver input has a different script type than ours");
+                }
+                receiver_weight += finalized_input_weight(input);
+                if original_tx
+                    .input
+                    .first()
+                    .is_some_and(|x| x.sequence != txin.sequence)
+                {
+                    return reject("a receiver input uses a different sequence");
+                }
+            }
+        }
+        if sender_inputs != original_tx.input.len() {
+            return reject("some of our inputs are missing");
+        }
+
+        let payee_script = Address::new(self.uri.address.clone())?.script_pubkey();
+        let max_contribution = self.params.max_additional_fee_contribution.unwrap_or(0);
+        let fee_output_index = self.params.additional_fee_output_index.map(|x| x as usize);
+        let mut contribution = 0;
+        for (index, original) in original_tx.output.iter().enumerate() {
+            let proposed = proposal_tx
+                .output
+                .iter()
+                .find(|x| x.script_pubkey == original.script_pubkey);
+            if original.script_pubkey == payee_script {
+                if self.params.disable_output_substitution
+                    && proposed.is_none_or(|x| x.value < original.value)
+                {
+                    return reject("the payee output was substituted");
+                }
+                continue;
+            }
+            let proposed = match proposed {
+                Some(e) => e,
+                None => return reject("one of our outputs is missing"),
+            };
+            if proposed.value < original.value {
+                if Some(index) != fee_output_index {
+                    return reject("one of our outputs was decreased");
+                }
+                contribution += original.value - proposed.value;
+            }
+        }
+        if contribution > max_contribution {
+            return reject("the fee contribution exceeds maxadditionalfeecontribution");
+        }
+        if contribution > self.original_fee_rate()?.fee_wu(receiver_weight) {
+            return reject("the fee contribution exceeds the fee of the receiver's inputs");
+        }
+        Ok(())
+    }
+}
+
+/// The script types BIP78 compares between the sender's and the receiver's inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScriptType {
+    P2pkh,
+    P2sh,
+    P2wpkh,
+    P2wsh,
+    P2tr,
+    Other,
+}
+
+impl ScriptType {
+    fn of(script: &BdkScript) -> Self {
+        if script.is_p2pkh() {
+            ScriptType::P2pkh
+        } else if script.is_p2sh() {
+            ScriptType::P2sh
+        } else if script.is_v0_p2wpkh() {
+            ScriptType::P2wpkh
+        } else if script.is_v0_p2wsh() {
+            ScriptType::P2wsh
+        } else if script.is_v1_p2tr() {
+            ScriptType::P2tr
+        } else {
+            ScriptType::Other
+        }
+    }
+}
+
+/// Weight a finalized PSBT input adds to the transaction, with two extra vbytes for signatures
+/// that end up longer than the ones the receiver made.
+fn finalized_input_weight(input: &BdkInput) -> usize {
+    let script_sig_len = input.final_script_sig.as_ref().map_or(0, |x| x.len());
+    let witness_weight = input
+        .final_script_witness
+        .as_ref()
+        .map_or(0, |x| x.serialized_len());
+    (32 + 4 + VarInt(script_sig_len as u64).len() + script_sig_len + 4) * 4 + witness_weight + 8
+}
+
+/// Turn the sender's original PSBT into a PayJoin proposal by contributing one of our UTXOs.
+///
+/// The original PSBT must be finalized, must pay this wallet and must not spend any of its coins.
+/// The contributed input is signed and finalized; its fee, at the original fee rate, is taken from
+/// the sender's fee output within the limits of `query`, the rest is paid by our output.
+pub(crate) fn payjoin_receive(
+    wallet: &Wallet,
+    original_psbt: String,
+    query: String,
+) -> Result<BdkPartiallySignedTransaction, BdkError> {
+    let params = PayjoinParams::from_query(query.trim_start_matches('?'))?;
+    let mut psbt = BdkPartiallySignedTransaction::from_str(original_psbt.trim())?;
+    let bdk_wallet = wallet.get_wallet();
+
+    if psbt
+        .inputs
+        .iter()
+        .any(|x| x.final_script_sig.is_none() && x.final_script_witness.is_none())
+    {
+        return Err(BdkError::Generic(
+            "The original PSBT is not finalized".to_string(),
+        ));
+    }
+    let mut sender_types = Vec::with_capacity(psbt.inputs.len());
+    for index in 0..psbt.inputs.len() {
+        let utxo = input_utxo(&psbt, index).ok_or_else(|| {
+            BdkError::Generic("The original PSBT is missing UTXO information".to_string())
+        })?;
+        if bdk_wallet.is_mine(&utxo.script_pubkey)? {
+            return Err(BdkError::Generic(
+                "The original PSBT spends coins of this wallet".to_string(),
+            ));
+        }
+        sender_types.push(ScriptType::of(&utxo.script_pubkey));
+    }
+    // Senders reject inputs of another script type than theirs, when theirs are all the same.
+    let sender_type = match sender_types.first() {
+        Some(first) if sender_types.iter().all(|x| x == first) => Some(*first),
+        _ => None,
+    };
+    let mut receiver_index = None;
+    for (index, txout) in psbt.unsigned_tx.output.iter().enumerate() {
+        if bdk_wallet.is_mine(&txout.script_pubkey)? {
+            receiver_index = Some(index);
+            break;
+        }
+    }
+    let receiver_index = receiver_index.ok_or_else(|| {
+        BdkError::Generic("The original PSBT does not pay this wallet".to_string())
+    })?;
+    let original_fee = psbt_fee_amount(&psbt).ok_or(BdkError::FeeRateUnavailable)?;
+    let original_fee_rate = FeeRate::from_wu(original_fee, psbt.clone().extract_tx().weight());
+
+    // Contribute our smallest confirmed UTXO of the sender's script type that is worth more than
+    // the fee it adds.
+    let mut candidate = None;
+    for utxo in bdk_wallet.list_unspent()? {
+        if sender_type.is_some_and(|x| x != ScriptType::of(&utxo.txout.script_pubkey)) {
+            continue;
+        }
+        let confirmed = bdk_wallet
+            .get_tx(&utxo.outpoint.txid, false)?
+            .is_some_and(|x| x.confirmation_time.is_some());
+        if !confirmed {
+            continue;
+        }
+        let input_weight = TXIN_BASE_WEIGHT
+            + bdk_wallet
+                .get_descriptor_for_keychain(utxo.keychain)
+                .max_satisfaction_weight()?;
+        let additional_fee = original_fee_rate.fee_wu(input_weight);
+        if utxo.txout.value > additional_fee
+            && candidate
+                .as_ref()
+                .is_none_or(|(x, _): &(bdk::LocalUtxo, u64)| utxo.txout.value < x.txout.value)
+        {
+            candidate = Some((utxo, additional_fee));
+        }
+    }
+    let (utxo, additional_fee) = candidate.ok_or_else(|| {
+        BdkError::Generic(
+            "No confirmed UTXO of the sender's script type available to contribute".to_string(),
+        )
+    })?;
+    let psbt_input = bdk_wallet.get_psbt_input(utxo.clone(), None, false)?;
+
+    // Our input now invalidates the sender's signatures, and BIP78 forbids leaking their key
+    // paths back to them.
+    for input in psbt.inputs.iter_mut() {
+        input.final_script_sig = None;
+        input.final_script_witness = None;
+        input.partial_sigs.clear();
+        input.bip32_derivation.clear();
+        input.tap_key_origins.clear();
+    }
+    for output in psbt.outputs.iter_mut() {
+        output.bip32_derivation.clear();
+        output.tap_key_origins.clear();
+    }
+    psbt.xpub.clear();
+
+    let sequence = psbt.unsigned_tx.input[0].sequence;
+    let position = rand::thread_rng().gen_range(0..=psbt.inputs.len());
+    psbt.unsigned_tx.input.insert(
+        position,
+        BdkTxIn {
+            previous_output: utxo.outpoint,
+            script_sig: BdkScript::new(),
+            sequence,
+            witness: Witness::default(),
+        },
+    );
+    psbt.inputs.insert(position, psbt_input);
+
+    let sender_contribution = match params.additional_fee_output_index.map(|x| x as usize) {
+        Some(index) if index != receiver_index && index < psbt.unsigned_tx.output.len() => {
+            let contribution = additional_fee
+                .min(params.max_additional_fee_contribution.unwrap_or(0))
+                .min(psbt.unsigned_tx.output[index].value);
+            psbt.unsigned_tx.output[index].value -= contribution;
+            contribution
+        }
+        _ => 0,
+    };
+    psbt.unsigned_tx.output[receiver_index].value +=
+        utxo.txout.value - (additional_fee - sender_contribution);
+
+    let sign_options = BdkSignOptions {
+        trust_witness_utxo: true,
+        ..Default::default()
+    };
+    bdk_wallet.sign(&mut psbt, sign_options)?;
+    let input = &psbt.inputs[position];
+    if input.final_script_sig.is_none() && input.final_script_witness.is_none() {
+        return Err(BdkError::Generic(
+            "The contributed input could not be finalized".to_string(),
+        ));
+    }
+    Ok(psbt)
+}
+
+fn parse_query(query: &str) -> Result<Vec<(String, String)>, BdkError> {
+    query
+        .split('&')
+        .filter(|x| !x.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((percent_decode(key)?, percent_decode(value)?))
+        })
+        .collect()
+}
+
+fn percent_decode(value: &str) -> Result<String, BdkError> {
+    let invalid = || BdkError::Generic(format!("Invalid percent-encoding in {}", value));
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = value.get(index + 1..index + 3).ok_or_else(invalid)?;
+            decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::payjoin::{
+        payjoin_receive, PayjoinParams, PayjoinSender, PayjoinTransport, PayjoinUri,
+    };
+    use crate::wallet::test::{
+        foreign_script, funding_tx, test_script, wallet_with_txs, TEST_CHANGE_DESCRIPTOR,
+        TEST_DESCRIPTOR,
+    };
+    use crate::wallet::Wallet;
+    use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+    use bdk::bitcoin::{Address, Network, Script};
+    use bdk::psbt::PsbtUtils;
+    use bdk::{Error as BdkError, FeeRate};
+
+    const RECEIVER_DESCRIPTOR: &str = "wpkh(tprv8ZgxMBicQKsPdWuqM1t1CDRvQtQuBPyfL6GbhQwtxDKgUAVPbxmj71pRA8raTqLrec5LyTs5TqCxdABcZr77bt2KyWA5bizJHnC4g4ysm4h/84'/1'/0'/0/*)";
+    const LEGACY_RECEIVER_DESCRIPTOR: &str = "pkh(tprv8ZgxMBicQKsPdWuqM1t1CDRvQtQuBPyfL6GbhQwtxDKgUAVPbxmj71pRA8raTqLrec5LyTs5TqCxdABcZr77bt2KyWA5bizJHnC4g4ysm4h/44'/1'/0'/0/*)";
+
+    /// A receiver running in the same process, which may tamper with its proposal.
+    struct InProcessReceiver<'a> {
+        wallet: &'a Wallet,
+        tamper: &'a dyn Fn(&mut BdkPartiallySignedTransaction),
+    }
+
+    impl PayjoinTransport for InProcessReceiver<'_> {
+        fn post(&self, url: &str, body: &str) -> Result<String, BdkError> {
+            let (_, query) = url.split_once('?').unwrap_or((url, ""));
+            let mut proposal = payjoin_receive(self.wallet, body.to_string(), query.to_string())?;
+            (self.tamper)(&mut proposal);
+            Ok(proposal.to_string())
+        }
+    }
+
+    /// A wallet with a confirmed UTXO of 50 000 sats, and a smaller unconfirmed one that must not
+    /// be contributed.
+    fn receiver_wallet(descriptor: &str) -> Wallet {
+        let funding = funding_tx(test_script(descriptor, 0), 50_000);
+        let unconfirmed = funding_tx(test_script(descriptor, 2), 20_000);
+        wallet_with_txs(
+            descriptor,
+            None,
+            &[(funding, Some(100)), (unconfirmed, None)],
+        )
+    }
+
+    /// Pay 30 000 sats to `receiver` through the in-process transport.
+    fn pay(
+        receiver: &Wallet,
+        max_additional_fee_contribution: u64,
+        tamper: &dyn Fn(&mut BdkPartiallySignedTransaction),
+    ) -> Result<(BdkPartiallySignedTransaction, BdkPartiallySignedTransaction), BdkError> {
+        let funding = funding_tx(test_script(TEST_DESCRIPTOR, 0), 100_000);
+        let sender = wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &[(funding, Some(100))],
+        );
+        let payee_script = receiver
+            .get_wallet()
+            .get_address(bdk::wallet::AddressIndex::Peek(1))?
+            .script_pubkey();
+        let uri = PayjoinUri::new(format!(
+            "bitcoin:{}?amount=0.0003&pj=https://example.com/pj",
+            Address::from_script(&payee_script, Network::Regtest).unwrap()
+        ))?;
+        let psbt = {
+            let wallet = sender.get_wallet();
+            let mut tx_builder = wallet.build_tx();
+            tx_builder
+                .add_recipient(payee_script.clone(), 30_000)
+                .fee_rate(FeeRate::from_sat_per_vb(2.0));
+            tx_builder.finish()?.0
+        };
+        let change_index = psbt
+            .unsigned_tx
+            .output
+            .iter()
+            .position(|x| x.script_pubkey != payee_script)
+            .map(|x| x as u32);
+        let params = PayjoinParams {
+            additional_fee_output_index: change_index,
+            max_additional_fee_contribution: Some(max_additional_fee_contribution),
+            min_fee_rate: None,
+            disable_output_substitution: false,
+        };
+        let payjoin = PayjoinSender::new(&sender, uri, psbt, params, None)?;
+        let transport = InProcessReceiver {
+            wallet: receiver,
+            tamper,
+        };
+        let proposal = payjoin.send(&transport, &sender, None)?;
+        Ok((payjoin.original, proposal))
+    }
+
+    fn output_value(psbt: &BdkPartiallySignedTransaction, script: &Script) -> u64 {
+        psbt.unsigned_tx
+            .output
+            .iter()
+            .find(|x| &x.script_pubkey == script)
+            .map(|x| x.value)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_payjoin_round_trip() {
+        let receiver = receiver_wallet(RECEIVER_DESCRIPTOR);
+        let payee_script = test_script(RECEIVER_DESCRIPTOR, 1);
+        let (original, proposal) = pay(&receiver, 1_000, &|_| {}).unwrap();
+        assert_eq!(proposal.unsigned_tx.input.len(), 2);
+        assert!(proposal
+            .inputs
+            .iter()
+            .all(|x| x.final_script_witness.is_some()));
+        // The receiver's input goes to the payee output, minus its share of the fee.
+        let received = output_value(&proposal, &payee_script);
+        assert!(received > 30_000 + 50_000 - 1_000);
+        assert!(received <= 30_000 + 50_000);
+        let original_fee = original.fee_amount().unwrap();
+        let fee = proposal.fee_amount().unwrap();
+        assert!(fee > original_fee);
+        assert!(
+            FeeRate::from_wu(fee, proposal.clone().extract_tx().weight())
+                >= FeeRate::from_wu(original_fee, original.extract_tx().weight())
+        );
+    }
+
+    #[test]
+    fn test_payjoin_sender_checks() {
+        let receiver = receiver_wallet(RECEIVER_DESCRIPTOR);
+        let payee_script = test_script(RECEIVER_DESCRIPTOR, 1);
+
+        // The receiver takes more from our change than the fee of its input.
+        let decrease_change = |psbt: &mut BdkPartiallySignedTransaction| {
+            let change = psbt
+                .unsigned_tx
+                .output
+                .iter_mut()
+                .find(|x| x.script_pubkey != payee_script)
+                .unwrap();
+            change.value -= 5_000;
+        };
+        let err = pay(&receiver, 100_000, &decrease_change).unwrap_err();
+        assert!(format!("{:?}", err).contains("the fee of the receiver's inputs"));
+        let err = pay(&receiver, 1_000, &decrease_change).unwrap_err();
+        assert!(format!("{:?}", err).contains("maxadditionalfeecontribution"));
+
+        // The receiver pays itself from the fee, lowering the fee rate below the original's.
+        let lower_fee = |psbt: &mut BdkPartiallySignedTransaction| {
+            let payee = psbt
+                .unsigned_tx
+                .output
+                .iter_mut()
+                .find(|x| x.script_pubkey == payee_script)
+                .unwrap();
+            payee.value += 300;
+        };
+        let err = pay(&receiver, 1_000, &lower_fee).unwrap_err();
+        assert!(format!("{:?}", err).contains("below the"));
+
+        // A legacy input would single out the receiver's coins in a segwit transaction.
+        let legacy_input = |psbt: &mut BdkPartiallySignedTransaction| {
+            let input = psbt
+                .inputs
+                .iter_mut()
+                .find(|x| x.final_script_witness.is_some() && x.witness_utxo.is_some())
+                .unwrap();
+            let legacy_script = test_script(LEGACY_RECEIVER_DESCRIPTOR, 0);
+            input.witness_utxo.as_mut().unwrap().script_pubkey = legacy_script;
+        };
+        let err = pay(&receiver, 1_000, &legacy_input).unwrap_err();
+        assert!(format!("{:?}", err).contains("a different script type than ours"));
+        // So receivers only contribute inputs of the sender's script type.
+        let legacy_receiver = receiver_wallet(LEGACY_RECEIVER_DESCRIPTOR);
+        let err = pay(&legacy_receiver, 1_000, &|_| {}).unwrap_err();
+        assert!(format!("{:?}", err).contains("No confirmed UTXO of the sender's script type"));
+    }
+
+    #[test]
+    fn test_unknown_sender_output() {
+        // The original PSBT must pay the receiver named in the URI.
+        let funding = funding_tx(test_script(TEST_DESCRIPTOR, 0), 100_000);
+        let sender = wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &[(funding, Some(100))],
+        );
+        let psbt = {
+            let wallet = sender.get_wallet();
+            let mut tx_builder = wallet.build_tx();
+            tx_builder.add_recipient(foreign_script(), 30_000);
+            tx_builder.finish().unwrap().0
+        };
+        let uri = PayjoinUri::new(format!(
+            "bitcoin:{}?pj=https://example.com/pj",
+            Address::from_script(&test_script(RECEIVER_DESCRIPTOR, 1), Network::Regtest).unwrap()
+        ))
+        .unwrap();
+        assert!(PayjoinSender::new(&sender, uri, psbt, PayjoinParams::default(), None).is_err());
+    }
+
+    #[test]
+    fn test_parse_payjoin_uri() {
+        let uri = PayjoinUri::new("bitcoin:tb1q6d3a2w975yny0asuvd9a67ner4nks58ff0q8g4?amount=0.0001&pj=https%3A%2F%2Fexample.com%2Fpj&pjos=0".to_string()).unwrap();
+        assert_eq!(uri.address, "tb1q6d3a2w975yny0asuvd9a67ner4nks58ff0q8g4");
+        assert_eq!(uri.amount, Some(10_000));
+        assert_eq!(uri.endpoint, "https://example.com/pj");
+        assert!(uri.disable_output_substitution);
+
+        // a plain BIP21 URI cannot be used for a PayJoin
+        assert!(PayjoinUri::new(
+            "bitcoin:tb1q6d3a2w975yny0asuvd9a67ner4nks58ff0q8g4?amount=0.0001".to_string()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_payjoin_params_round_trip() {
+        let params = PayjoinParams {
+            additional_fee_output_index: Some(1),
+            max_additional_fee_contribution: Some(182),
+            min_fee_rate: Some(2.0),
+            disable_output_substitution: true,
+        };
+        let parsed = PayjoinParams::from_query(&params.to_query()).unwrap();
+        assert_eq!(parsed.additional_fee_output_index, Some(1));
+        assert_eq!(parsed.max_additional_fee_contribution, Some(182));
+        assert_eq!(parsed.min_fee_rate, Some(2.0));
+        assert!(parsed.disable_output_substitution);
+        assert!(PayjoinParams::from_query("v=2").is_err());
+    }
+}
@@ -1,17 +1,30 @@
-use bdk::bitcoin::consensus::Decodable;
-use bdk::bitcoin::hashes::hex::ToHex;
+use bdk::bitcoin::consensus::{encode, Decodable};
+use bdk::bitcoin::hashes::hex::{FromHex, ToHex};
 use bdk::bitcoin::psbt::serialize::Serialize;
 use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
-use bdk::bitcoin::Transaction as BdkTransaction;
-use bdk::psbt::PsbtUtils;
+use bdk::bitcoin::util::psbt::{raw, Input as BdkInput, Output as BdkOutput};
+use bdk::bitcoin::{
+    EcdsaSighashType, OutPoint as BdkOutPoint, Sequence, Transaction as BdkTransaction,
+    TxIn as BdkTxIn, TxOut as BdkTxOut, Txid,
+};
+use bdk::miniscript::psbt::PsbtExt;
 use bdk::{Error as BdkError, FeeRate};
-use std::borrow::Borrow;
+use std::fmt;
 use std::io::Cursor;
 use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
-use crate::types::{TxIn, TxOut};
+use crate::descriptor::BdkDescriptor;
+use crate::psbt_v2::{self, RawPsbt, PSBT_MAGIC};
+use crate::types::{Network, OutPoint, PsbtSigHashType, Script, TxIn, TxOut};
+use bdk::bitcoin::secp256k1::Secp256k1;
+use bdk::bitcoin::util::bip32::{ChildNumber, KeySource};
+use bdk::bitcoin::{Address as BdkAddress, Network as BdkNetwork};
+
+/// The most derivation indexes `finalize_with_descriptors` searches for inputs without BIP32
+/// derivations.
+pub(crate) const MAX_FINALIZE_LOOKAHEAD: u32 = 10_000;
 
 #[derive(Debug)]
 pub struct PartiallySignedTransaction {
@@ -19,17 +32,95 @@ pub struct PartiallySignedTransaction {
 }
 
 impl PartiallySignedTransaction {
-    pub(crate) fn new(psbt_base64: String) -> Result<Self, BdkError> {
-        let psbt: BdkPartiallySignedTransaction =
-            BdkPartiallySignedTransaction::from_str(psbt_base64.borrow())?;
+    /// Parse a PSBT of either version 0 or version 2 (BIP370), given as base64 or hex.
+    pub(crate) fn new(psbt_str: String) -> Result<Self, BdkError> {
+        let psbt_str = psbt_str.trim();
+        let is_hex = psbt_str
+            .get(..10)
+            .is_some_and(|x| x.eq_ignore_ascii_case(&PSBT_MAGIC.to_hex()));
+        if is_hex {
+            Self::from_hex(psbt_str)
+        } else {
+            Self::from_base64(psbt_str)
+        }
+    }
+
+    pub(crate) fn from_base64(psbt_base64: &str) -> Result<Self, BdkError> {
+        let bytes = base64::decode(psbt_base64.trim())
+            .map_err(|e| BdkError::Generic(format!("Invalid base64 PSBT: {}", e)))?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub(crate) fn from_hex(psbt_hex: &str) -> Result<Self, BdkError> {
+        let bytes = Vec::<u8>::from_hex(psbt_hex.trim())
+            .map_err(|e| BdkError::Generic(format!("Invalid hex PSBT: {}", e)))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a binary PSBT, as stored in `.psbt` files. The key-value maps are checked for a
+    /// valid magic, duplicate keys and trailing data before the fields are decoded.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, BdkError> {
+        let raw = RawPsbt::deserialize(bytes)?;
+        let psbt = match raw.version()? {
+            2 => psbt_v2::from_v2(raw)?,
+            _ => encode::deserialize(bytes)?,
+        };
         Ok(PartiallySignedTransaction {
             internal: Mutex::new(psbt),
         })
     }
 
-    pub(crate) fn serialize(&self) -> String {
+    /// Parse a PSBT read from a file or a QR code, which may hold binary, hex or base64 data.
+    pub(crate) fn from_any(bytes: &[u8]) -> Result<Self, BdkError> {
+        if bytes.starts_with(PSBT_MAGIC) {
+            return Self::from_bytes(bytes);
+        }
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Self::new(text.to_string()),
+            Err(_) => Err(BdkError::Generic("Unrecognised PSBT encoding".to_string())),
+        }
+    }
+
+    /// Serialize as base64, keeping the version the PSBT was created or parsed with.
+    pub(crate) fn serialize(&self) -> Result<String, BdkError> {
+        self.to_bytes().map(base64::encode)
+    }
+
+    /// Serialize as hex, keeping the version the PSBT was created or parsed with.
+    pub(crate) fn to_hex(&self) -> Result<String, BdkError> {
+        self.to_bytes().map(|x| x.to_hex())
+    }
+
+    /// Serialize as binary, keeping the version the PSBT was created or parsed with.
+    pub(crate) fn to_bytes(&self) -> Result<Vec<u8>, BdkError> {
         let psbt = self.internal.lock().unwrap().clone();
-        psbt.to_string()
+        match psbt.version {
+            2 => psbt_v2::to_v2(&psbt),
+            _ => Ok(encode::serialize(&psbt)),
+        }
+    }
+
+    /// Serialize in the given PSBT version, either 0 or 2.
+    pub(crate) fn to_bytes_version(&self, version: u32) -> Result<Vec<u8>, BdkError> {
+        let psbt = self.internal.lock().unwrap().clone();
+        match version {
+            0 => Ok(encode::serialize(&psbt_v2::to_v0(&psbt))),
+            2 => psbt_v2::to_v2(&psbt),
+            _ => Err(BdkError::Generic(format!(
+                "PSBT version {} is not supported",
+                version
+            ))),
+        }
+    }
+
+    /// Serialize as base64 in the given PSBT version, either 0 or 2.
+    pub(crate) fn serialize_version(&self, version: u32) -> Result<String, BdkError> {
+        self.to_bytes_version(version).map(base64::encode)
+    }
+
+    /// The PSBT version, 0 or 2.
+    pub(crate) fn version(&self) -> u32 {
+        self.internal.lock().unwrap().version
     }
 
     pub(crate) fn txid(&self) -> String {
@@ -60,10 +151,298 @@ impl PartiallySignedTransaction {
         }))
     }
 
+    /// Combines any number of PSBTs describing the same unsigned transaction, reporting for each
+    /// input the signatures the first PSBT gained from the others.
+    pub(crate) fn combine_all(
+        psbts: &[PartiallySignedTransaction],
+    ) -> Result<CombinedPsbt, BdkError> {
+        let mut combined = match psbts.first() {
+            Some(first) => first.internal.lock().unwrap().clone(),
+            None => return Err(BdkError::Generic("No PSBTs to combine".to_string())),
+        };
+        let mut inputs: Vec<CombinedInput> = (0..combined.inputs.len())
+            .map(|index| CombinedInput {
+                index: index as u32,
+                added_signatures: vec![],
+            })
+            .collect();
+        for (source, other) in psbts.iter().enumerate().skip(1) {
+            let other = other.internal.lock().unwrap().clone();
+            if other.unsigned_tx != combined.unsigned_tx {
+                return Err(BdkError::Generic(format!(
+                    "PSBT {} describes a different unsigned transaction ({} instead of {})",
+                    source,
+                    other.unsigned_tx.txid(),
+                    combined.unsigned_tx.txid()
+                )));
+            }
+            let before: Vec<Vec<AddedSignature>> = combined
+                .inputs
+                .iter()
+                .map(|input| input_signatures(input, source as u32))
+                .collect();
+            combined.combine(other)?;
+            for (index, input) in combined.inputs.iter().enumerate() {
+                for signature in input_signatures(input, source as u32) {
+                    if !before[index].iter().any(|x| x.same_key(&signature)) {
+                        inputs[index].added_signatures.push(signature);
+                    }
+                }
+            }
+        }
+        Ok(CombinedPsbt {
+            psbt: PartiallySignedTransaction {
+                internal: Mutex::new(combined),
+            }
+            .serialize()?,
+            inputs,
+        })
+    }
+
+    /// Add an input spending `outpoint`, described by `input` (which must carry its UTXO).
+    /// Without a `sequence` the input gets the sequence of the first input.
+    pub(crate) fn add_input(
+        &self,
+        outpoint: &OutPoint,
+        input: BdkInput,
+        sequence: Option<u32>,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        let previous_output = BdkOutPoint {
+            txid: Txid::from_str(&outpoint.txid)
+                .map_err(|e| BdkError::Generic(format!("Invalid txid: {}", e)))?,
+            vout: outpoint.vout,
+        };
+        match (&input.non_witness_utxo, &input.witness_utxo) {
+            (Some(tx), _) => {
+                if tx.txid() != previous_output.txid {
+                    return Err(BdkError::Generic(
+                        "The non-witness UTXO does not match the outpoint".to_string(),
+                    ));
+                }
+                if tx.output.len() <= previous_output.vout as usize {
+                    return Err(BdkError::Generic(
+                        "The outpoint is not in the non-witness UTXO".to_string(),
+                    ));
+                }
+            }
+            (None, Some(_)) => {}
+            (None, None) => {
+                return Err(BdkError::Generic(
+                    "The input has no UTXO information".to_string(),
+                ))
+            }
+        }
+        self.edit(PsbtEdit::AddInput, |psbt| {
+            psbt_v2::check_modifiable(psbt, true, false)?;
+            if psbt
+                .unsigned_tx
+                .input
+                .iter()
+                .any(|x| x.previous_output == previous_output)
+            {
+                return Err(BdkError::Generic(format!(
+                    "The PSBT already spends {}",
+                    previous_output
+                )));
+            }
+            let sequence = match sequence {
+                Some(e) => Sequence(e),
+                None => psbt
+                    .unsigned_tx
+                    .input
+                    .first()
+                    .map_or(Sequence::ENABLE_RBF_NO_LOCKTIME, |x| x.sequence),
+            };
+            psbt.unsigned_tx.input.push(BdkTxIn {
+                previous_output,
+                sequence,
+                ..Default::default()
+            });
+            psbt.inputs.push(input);
+            Ok(())
+        })
+    }
+
+    /// Remove an unsigned input.
+    pub(crate) fn remove_input(
+        &self,
+        index: u32,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        let index = index as usize;
+        self.edit(PsbtEdit::RemoveInput(index), |psbt| {
+            psbt_v2::check_modifiable(psbt, true, false)?;
+            if psbt.inputs.len() == 1 {
+                return Err(BdkError::Generic("Can't remove the only input".to_string()));
+            }
+            if !signature_sighash_types(&psbt.inputs[index]).is_empty() {
+                return Err(BdkError::Generic(format!(
+                    "Input {} is signed and can't be removed",
+                    index
+                )));
+            }
+            psbt.unsigned_tx.input.remove(index);
+            psbt.inputs.remove(index);
+            Ok(())
+        })
+    }
+
+    /// Add an output paying `amount` to `script`.
+    pub(crate) fn add_output(
+        &self,
+        script: Script,
+        amount: u64,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        self.edit(PsbtEdit::AddOutput, |psbt| {
+            psbt_v2::check_modifiable(psbt, false, true)?;
+            psbt.unsigned_tx.output.push(BdkTxOut {
+                value: amount,
+                script_pubkey: script.into(),
+            });
+            psbt.outputs.push(Default::default());
+            Ok(())
+        })
+    }
+
+    /// Remove an output that no signature commits to.
+    pub(crate) fn remove_output(
+        &self,
+        index: u32,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        let index = index as usize;
+        self.edit(PsbtEdit::RemoveOutput(index), |psbt| {
+            psbt_v2::check_modifiable(psbt, false, true)?;
+            psbt.unsigned_tx.output.remove(index);
+            psbt.outputs.remove(index);
+            Ok(())
+        })
+    }
+
+    pub(crate) fn set_sequence(
+        &self,
+        index: u32,
+        sequence: u32,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        let index = index as usize;
+        self.edit(PsbtEdit::Sequence(index), |psbt| {
+            psbt.unsigned_tx.input[index].sequence = Sequence(sequence);
+            Ok(())
+        })
+    }
+
+    pub(crate) fn set_lock_time(
+        &self,
+        lock_time: u32,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        self.edit(PsbtEdit::LockTime, |psbt| {
+            psbt_v2::set_lock_time(psbt, lock_time)
+        })
+    }
+
+    /// Set, or remove when `value` is `None`, an unknown field of one of the PSBT maps. Key types
+    /// defined by BIP174, BIP370 and BIP371 are refused, as is the proprietary key type.
+    pub(crate) fn set_unknown(
+        &self,
+        map: PsbtMap,
+        key_type: u8,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        let defined = match map {
+            PsbtMap::Global => key_type <= 0x06 || key_type == 0xfb,
+            PsbtMap::Input { .. } => key_type <= 0x1a,
+            PsbtMap::Output { .. } => key_type <= 0x08,
+        };
+        if defined || key_type == 0xfc {
+            return Err(BdkError::Generic(format!(
+                "Key type {:#04x} is not an unknown field",
+                key_type
+            )));
+        }
+        self.edit(PsbtEdit::Fields, |psbt| {
+            let unknown = match map {
+                PsbtMap::Global => &mut psbt.unknown,
+                PsbtMap::Input { index } => &mut input_mut(psbt, index)?.unknown,
+                PsbtMap::Output { index } => &mut output_mut(psbt, index)?.unknown,
+            };
+            let key = raw::Key {
+                type_value: key_type,
+                key,
+            };
+            match value {
+                Some(value) => unknown.insert(key, value),
+                None => unknown.remove(&key),
+            };
+            Ok(())
+        })
+    }
+
+    /// Set, or remove when `value` is `None`, a proprietary field of one of the PSBT maps.
+    pub(crate) fn set_proprietary(
+        &self,
+        map: PsbtMap,
+        prefix: Vec<u8>,
+        subtype: u8,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> Result<Arc<PartiallySignedTransaction>, BdkError> {
+        self.edit(PsbtEdit::Fields, |psbt| {
+            let proprietary = match map {
+                PsbtMap::Global => &mut psbt.proprietary,
+                PsbtMap::Input { index } => &mut input_mut(psbt, index)?.proprietary,
+                PsbtMap::Output { index } => &mut output_mut(psbt, index)?.proprietary,
+            };
+            let key = raw::ProprietaryKey {
+                prefix,
+                subtype,
+                key,
+            };
+            match value {
+                Some(value) => proprietary.insert(key, value),
+                None => proprietary.remove(&key),
+            };
+            Ok(())
+        })
+    }
+
+    /// Apply `edit` to a copy of the PSBT, after checking the indexes it refers to and that it
+    /// leaves every existing signature valid.
+    fn edit<F>(&self, edit: PsbtEdit, apply: F) -> Result<Arc<PartiallySignedTransaction>, BdkError>
+    where
+        F: FnOnce(&mut BdkPartiallySignedTransaction) -> Result<(), BdkError>,
+    {
+        let mut psbt = self.internal.lock().unwrap().clone();
+        let (inputs, outputs) = (psbt.inputs.len(), psbt.outputs.len());
+        match edit {
+            PsbtEdit::Sequence(index) | PsbtEdit::RemoveInput(index) if index >= inputs => {
+                return Err(BdkError::Generic(format!("No input at index {}", index)))
+            }
+            PsbtEdit::RemoveOutput(index) if index >= outputs => {
+                return Err(BdkError::Generic(format!("No output at index {}", index)))
+            }
+            _ => {}
+        }
+        for (index, input) in psbt.inputs.iter().enumerate() {
+            let is_taproot = is_taproot_input(input);
+            if signature_sighash_types(input)
+                .into_iter()
+                .any(|sighash| edit.invalidates(index, sighash, is_taproot, outputs))
+            {
+                return Err(BdkError::Generic(format!(
+                    "The edit would invalidate the signatures of input {}",
+                    index
+                )));
+            }
+        }
+        apply(&mut psbt)?;
+        Ok(Arc::new(PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        }))
+    }
+
     /// The total transaction fee amount, sum of input amounts minus sum of output amounts, in Sats.
     /// If the PSBT is missing a TxOut for an input returns None.
     pub(crate) fn fee_amount(&self) -> Option<u64> {
-        self.internal.lock().unwrap().fee_amount()
+        psbt_fee_amount(&self.internal.lock().unwrap())
     }
 
     /// The transaction's fee rate. This value will only be accurate if calculated AFTER the
@@ -71,7 +450,295 @@ impl PartiallySignedTransaction {
     /// transaction.
     /// If the PSBT is missing a TxOut for an input returns None.
     pub(crate) fn fee_rate(&self) -> Option<Arc<FeeRate>> {
-        self.internal.lock().unwrap().fee_rate().map(Arc::new)
+        let psbt = self.internal.lock().unwrap();
+        psbt_fee_amount(&psbt)
+            .map(|fee| Arc::new(FeeRate::from_wu(fee, psbt.clone().extract_tx().weight())))
+    }
+
+    /// Describe every input of the PSBT: what it spends, who derived and signed it, and whether it
+    /// is finalized.
+    pub(crate) fn inputs(&self) -> Vec<PsbtInputInfo> {
+        let psbt = self.internal.lock().unwrap();
+        psbt.unsigned_tx
+            .input
+            .iter()
+            .zip(psbt.inputs.iter())
+            .enumerate()
+            .map(|(index, (txin, input))| {
+                let utxo = input_utxo(&psbt, index);
+                let mut bip32_derivations: Vec<KeyOrigin> = input
+                    .bip32_derivation
+                    .iter()
+                    .map(|(key, source)| KeyOrigin::new(key.to_string(), source))
+                    .collect();
+                bip32_derivations.extend(
+                    input
+                        .tap_key_origins
+                        .iter()
+                        .map(|(key, (_, source))| KeyOrigin::new(key.to_string(), source)),
+                );
+                PsbtInputInfo {
+                    previous_output: txin.previous_output.into(),
+                    sequence: txin.sequence.0,
+                    amount: utxo.as_ref().map(|x| x.value),
+                    script_pubkey: utxo.map(|x| x.script_pubkey.into()),
+                    sighash_type: input
+                        .sighash_type
+                        .map(|x| PsbtSigHashType { inner: x.to_u32() }),
+                    bip32_derivations,
+                    partial_sigs: input.partial_sigs.keys().map(|x| x.to_string()).collect(),
+                    has_tap_key_sig: input.tap_key_sig.is_some(),
+                    tap_script_sigs: input
+                        .tap_script_sigs
+                        .keys()
+                        .map(|(key, leaf_hash)| TapScriptSig {
+                            public_key: key.to_string(),
+                            leaf_hash: leaf_hash.to_string(),
+                        })
+                        .collect(),
+                    is_finalized: input.final_script_sig.is_some()
+                        || input.final_script_witness.is_some(),
+                }
+            })
+            .collect()
+    }
+
+    /// Describe every output of the PSBT. Addresses are encoded for `network`.
+    pub(crate) fn outputs(&self, network: Network) -> Vec<PsbtOutputInfo> {
+        let psbt = self.internal.lock().unwrap();
+        let network = network.into();
+        psbt.unsigned_tx
+            .output
+            .iter()
+            .zip(psbt.outputs.iter())
+            .map(|(txout, output)| {
+                let mut bip32_derivations: Vec<KeyOrigin> = output
+                    .bip32_derivation
+                    .iter()
+                    .map(|(key, source)| KeyOrigin::new(key.to_string(), source))
+                    .collect();
+                bip32_derivations.extend(
+                    output
+                        .tap_key_origins
+                        .iter()
+                        .map(|(key, (_, source))| KeyOrigin::new(key.to_string(), source)),
+                );
+                PsbtOutputInfo {
+                    script_pubkey: txout.script_pubkey.clone().into(),
+                    address: BdkAddress::from_script(&txout.script_pubkey, network)
+                        .map(|x| x.to_string())
+                        .ok(),
+                    amount: txout.value,
+                    bip32_derivations,
+                }
+            })
+            .collect()
+    }
+
+    /// Finalize the inputs spending from `descriptors`, without a wallet.
+    ///
+    /// Each input is matched against the descriptors (trying the derivation indexes found in its
+    /// BIP32 derivations, then the first `lookahead` indexes), updated with the scripts of the
+    /// matching descriptor and finalized with miniscript, filling its `final_script_sig` and
+    /// `final_script_witness`. Inputs that are already finalized are left untouched.
+    ///
+    /// Fails if `lookahead` is above `MAX_FINALIZE_LOOKAHEAD`.
+    pub(crate) fn finalize_with_descriptors(
+        &self,
+        descriptors: &[BdkDescriptor],
+        lookahead: u32,
+    ) -> Result<Vec<FinalizeInputResult>, BdkError> {
+        if lookahead > MAX_FINALIZE_LOOKAHEAD {
+            return Err(BdkError::Generic(format!(
+                "The lookahead can be at most {}",
+                MAX_FINALIZE_LOOKAHEAD
+            )));
+        }
+        let secp = Secp256k1::verification_only();
+        let mut psbt = self.internal.lock().unwrap();
+        Ok((0..psbt.inputs.len())
+            .map(|index| {
+                let input = &psbt.inputs[index];
+                if input.final_script_sig.is_some() || input.final_script_witness.is_some() {
+                    return FinalizeInputResult::finalized(index);
+                }
+                let utxo = match input_utxo(&psbt, index) {
+                    Some(e) => e,
+                    None => return FinalizeInputResult::error(index, "Missing UTXO information"),
+                };
+                let hints: Vec<_> = input
+                    .bip32_derivation
+                    .values()
+                    .chain(input.tap_key_origins.values().map(|(_, source)| source))
+                    .filter_map(|(_, path)| path.as_ref().last().copied())
+                    .collect();
+                let descriptor = match descriptors
+                    .iter()
+                    .find_map(|x| x.find_derivation(&utxo.script_pubkey, hints.clone(), lookahead))
+                {
+                    Some(e) => e,
+                    None => {
+                        return FinalizeInputResult::error(
+                            index,
+                            "The input does not spend from the descriptor",
+                        )
+                    }
+                };
+                if let Err(e) = psbt.update_input_with_descriptor(index, &descriptor) {
+                    return FinalizeInputResult::error(index, &e.to_string());
+                }
+                match psbt.finalize_inp_mut(&secp, index) {
+                    Ok(_) => FinalizeInputResult::finalized(index),
+                    Err(e) => FinalizeInputResult::error(index, &e.to_string()),
+                }
+            })
+            .collect())
+    }
+
+    /// Explain why each input of the PSBT is not finalized yet.
+    pub(crate) fn finalize_report(&self) -> Vec<FinalizeInputResult> {
+        let secp = Secp256k1::verification_only();
+        let psbt = self.internal.lock().unwrap();
+        psbt.inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                if input.final_script_sig.is_some() || input.final_script_witness.is_some() {
+                    return FinalizeInputResult::finalized(index);
+                }
+                // Finalize a copy with miniscript to learn why the input cannot be finalized.
+                match psbt.clone().finalize_inp_mut(&secp, index) {
+                    Ok(_) => FinalizeInputResult::error(index, "The input was not finalized"),
+                    Err(e) => FinalizeInputResult::error(index, &e.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    /// Check the PSBT for anything that could make signing it costly or unsafe on `network`.
+    ///
+    /// `is_mine` tells the outputs paying back to the signing wallet, which don't count towards
+    /// the amount sent when checking `max_fee_percent`.
+    pub(crate) fn check_safety(
+        &self,
+        network: BdkNetwork,
+        policy: &PsbtSafetyPolicy,
+        is_mine: impl Fn(&bdk::bitcoin::Script) -> bool,
+    ) -> PsbtSafetyReport {
+        let psbt = self.internal.lock().unwrap();
+        let mut issues = Vec::new();
+
+        if let Some(psbt_network) = network_mismatch(&psbt, network) {
+            issues.push(PsbtIssue::NetworkMismatch {
+                network: psbt_network.into(),
+            });
+        }
+
+        let mut values = Vec::with_capacity(psbt.inputs.len());
+        for (index, (input, txin)) in psbt
+            .inputs
+            .iter()
+            .zip(psbt.unsigned_tx.input.iter())
+            .enumerate()
+        {
+            let index = index as u32;
+            if let Some(tx) = &input.non_witness_utxo {
+                if tx.txid() != txin.previous_output.txid {
+                    issues.push(PsbtIssue::NonWitnessUtxoMismatch { index });
+                }
+            }
+            let witness_value = input.witness_utxo.as_ref().map(|x| x.value);
+            let non_witness_value = input
+                .non_witness_utxo
+                .as_ref()
+                .and_then(|tx| tx.output.get(txin.previous_output.vout as usize))
+                .map(|x| x.value);
+            if let (Some(witness_value), Some(non_witness_value)) =
+                (witness_value, non_witness_value)
+            {
+                if witness_value != non_witness_value {
+                    issues.push(PsbtIssue::UtxoValueMismatch {
+                        index,
+                        witness_value,
+                        non_witness_value,
+                    });
+                }
+            }
+            // The previous transaction is bound to the input by its txid, unlike the witness UTXO.
+            values.push(non_witness_value.or(witness_value));
+            let is_taproot = input
+                .witness_utxo
+                .as_ref()
+                .is_some_and(|x| x.script_pubkey.is_v1_p2tr());
+            if non_witness_value.is_none() && witness_value.is_none() {
+                issues.push(PsbtIssue::MissingUtxo { index });
+            } else if input.non_witness_utxo.is_none()
+                && policy.require_non_witness_utxo
+                && !is_taproot
+            {
+                issues.push(PsbtIssue::MissingNonWitnessUtxo { index });
+            }
+            if let Some(sighash) = input.sighash_type {
+                // 0 is SIGHASH_DEFAULT for taproot, which commits to the same data as ALL.
+                if !matches!(sighash.to_u32(), 0 | 1) {
+                    issues.push(PsbtIssue::NonStandardSighash {
+                        index,
+                        sighash: sighash.to_u32(),
+                    });
+                }
+            }
+        }
+
+        for (index, txout) in psbt.unsigned_tx.output.iter().enumerate() {
+            let dust_limit = txout.script_pubkey.dust_value().to_sat();
+            if !txout.script_pubkey.is_provably_unspendable() && txout.value < dust_limit {
+                issues.push(PsbtIssue::DustOutput {
+                    index: index as u32,
+                    value: txout.value,
+                    dust_limit,
+                });
+            }
+        }
+
+        let total: u64 = psbt.unsigned_tx.output.iter().map(|x| x.value).sum();
+        let fee = values
+            .into_iter()
+            .sum::<Option<u64>>()
+            .and_then(|x| x.checked_sub(total));
+        if let Some(fee) = fee {
+            if let Some(max_fee) = policy.max_fee {
+                if fee > max_fee {
+                    issues.push(PsbtIssue::FeeTooHigh { fee, max_fee });
+                }
+            }
+            if let Some(max_fee_percent) = policy.max_fee_percent {
+                // A transaction paying only the wallet itself is compared to all its outputs.
+                let sent = match psbt
+                    .unsigned_tx
+                    .output
+                    .iter()
+                    .filter(|x| !is_mine(&x.script_pubkey))
+                    .map(|x| x.value)
+                    .sum()
+                {
+                    0 => total,
+                    sent => sent,
+                };
+                let fee_percent = fee as f32 * 100.0 / sent.max(1) as f32;
+                if fee_percent > max_fee_percent {
+                    issues.push(PsbtIssue::FeeTooHighRelative {
+                        fee,
+                        fee_percent,
+                        max_fee_percent,
+                    });
+                }
+            }
+        }
+        PsbtSafetyReport {
+            is_safe: issues.is_empty(),
+            fee,
+            issues,
+        }
     }
 
     /// Serialize the PSBT data structure as a String of JSON.
@@ -81,6 +748,452 @@ impl PartiallySignedTransaction {
     }
 }
 
+/// Limits applied by the PSBT safety checks.
+pub struct PsbtSafetyPolicy {
+    /// Highest acceptable fee (sats).
+    pub max_fee: Option<u64>,
+    /// Highest acceptable fee, as a percentage of the value sent to outputs the wallet doesn't own.
+    pub max_fee_percent: Option<f32>,
+    /// Whether non-taproot inputs must carry their full previous transaction, which protects
+    /// against the SegWit v0 fee bug.
+    pub require_non_witness_utxo: bool,
+    /// Whether signing is refused when any issue is found.
+    pub refuse_unsafe: bool,
+}
+impl Default for PsbtSafetyPolicy {
+    /// No fee limits, and a refusal on any issue.
+    fn default() -> Self {
+        PsbtSafetyPolicy {
+            max_fee: None,
+            max_fee_percent: None,
+            require_non_witness_utxo: false,
+            refuse_unsafe: true,
+        }
+    }
+}
+
+/// A problem found by the PSBT safety checks.
+pub enum PsbtIssue {
+    /// The fee is above the policy's absolute limit.
+    FeeTooHigh { fee: u64, max_fee: u64 },
+    /// The fee is above the policy's limit relative to the amount sent.
+    FeeTooHighRelative {
+        fee: u64,
+        fee_percent: f32,
+        max_fee_percent: f32,
+    },
+    /// The input has no UTXO data at all, so its value and the fee are unknown.
+    MissingUtxo { index: u32 },
+    /// The input only has a `witness_utxo`, whose amount can't be verified.
+    MissingNonWitnessUtxo { index: u32 },
+    /// The `non_witness_utxo` of the input is not the transaction it spends from.
+    NonWitnessUtxoMismatch { index: u32 },
+    /// The `witness_utxo` of the input doesn't have the value of the output its `non_witness_utxo`
+    /// says it spends. The value of the `non_witness_utxo` is the one used for the fee.
+    UtxoValueMismatch {
+        index: u32,
+        witness_value: u64,
+        non_witness_value: u64,
+    },
+    /// The input asks for a sighash type other than ALL.
+    NonStandardSighash { index: u32, sighash: u32 },
+    /// The output is below the dust limit of its script.
+    DustOutput {
+        index: u32,
+        value: u64,
+        dust_limit: u64,
+    },
+    /// The PSBT carries keys or derivation paths of another network.
+    NetworkMismatch { network: Network },
+}
+
+impl fmt::Display for PsbtIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PsbtIssue::FeeTooHigh { fee, max_fee } => write!(
+                f,
+                "the fee of {} sats is above the limit of {} sats",
+                fee, max_fee
+            ),
+            PsbtIssue::FeeTooHighRelative {
+                fee,
+                fee_percent,
+                max_fee_percent,
+            } => write!(
+                f,
+                "the fee of {} sats is {:.2}% of the amount sent, above the limit of {}%",
+                fee, fee_percent, max_fee_percent
+            ),
+            PsbtIssue::MissingUtxo { index } => write!(f, "input {} has no UTXO data", index),
+            PsbtIssue::MissingNonWitnessUtxo { index } => write!(
+                f,
+                "input {} has no previous transaction to verify its value",
+                index
+            ),
+            PsbtIssue::NonWitnessUtxoMismatch { index } => write!(
+                f,
+                "the previous transaction of input {} is not the one it spends from",
+                index
+            ),
+            PsbtIssue::UtxoValueMismatch {
+                index,
+                witness_value,
+                non_witness_value,
+            } => write!(
+                f,
+                "input {} spends {} sats according to its witness UTXO but {} sats according to \
+                 its previous transaction",
+                index, witness_value, non_witness_value
+            ),
+            PsbtIssue::NonStandardSighash { index, sighash } => {
+                write!(f, "input {} asks for sighash type {:#x}", index, sighash)
+            }
+            PsbtIssue::DustOutput {
+                index,
+                value,
+                dust_limit,
+            } => write!(
+                f,
+                "output {} of {} sats is below the dust limit of {} sats",
+                index, value, dust_limit
+            ),
+            PsbtIssue::NetworkMismatch { network } => {
+                write!(f, "the PSBT is for {}", BdkNetwork::from(network.clone()))
+            }
+        }
+    }
+}
+
+/// The outcome of the PSBT safety checks.
+pub struct PsbtSafetyReport {
+    pub is_safe: bool,
+    /// Fee (sats), if every input has UTXO data.
+    pub fee: Option<u64>,
+    pub issues: Vec<PsbtIssue>,
+}
+
+/// The outcome of finalizing one PSBT input.
+pub struct FinalizeInputResult {
+    pub index: u32,
+    pub is_finalized: bool,
+    /// Why the input could not be finalized yet, e.g. missing signatures.
+    pub error: Option<String>,
+}
+impl FinalizeInputResult {
+    fn finalized(index: usize) -> Self {
+        FinalizeInputResult {
+            index: index as u32,
+            is_finalized: true,
+            error: None,
+        }
+    }
+    fn error(index: usize, error: &str) -> Self {
+        FinalizeInputResult {
+            index: index as u32,
+            is_finalized: false,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// A PSBT after finalization, along with the outcome for each of its inputs.
+pub struct FinalizedPsbt {
+    pub psbt: String,
+    /// Whether every input is finalized, i.e. the transaction can be extracted.
+    pub is_finalized: bool,
+    pub inputs: Vec<FinalizeInputResult>,
+}
+impl FinalizedPsbt {
+    pub(crate) fn new(
+        psbt: &PartiallySignedTransaction,
+        inputs: Vec<FinalizeInputResult>,
+    ) -> Result<Self, BdkError> {
+        Ok(FinalizedPsbt {
+            psbt: psbt.serialize()?,
+            is_finalized: inputs.iter().all(|x| x.is_finalized),
+            inputs,
+        })
+    }
+}
+
+/// The origin of a key: the fingerprint of its master key and the path it was derived at.
+#[derive(Debug, Clone)]
+pub struct KeyOrigin {
+    /// The public key, hex encoded (x-only for taproot keys).
+    pub public_key: String,
+    pub fingerprint: String,
+    pub path: String,
+}
+impl KeyOrigin {
+    fn new(public_key: String, source: &KeySource) -> Self {
+        KeyOrigin {
+            public_key,
+            fingerprint: source.0.to_string(),
+            path: source.1.to_string(),
+        }
+    }
+}
+
+/// A taproot script-path signature present in a PSBT input.
+#[derive(Debug, Clone)]
+pub struct TapScriptSig {
+    /// The x-only public key that signed, hex encoded.
+    pub public_key: String,
+    /// The hash of the leaf the signature is for.
+    pub leaf_hash: String,
+}
+
+/// One of the key-value maps of a PSBT.
+#[derive(Debug, Clone, Copy)]
+pub enum PsbtMap {
+    Global,
+    Input { index: u32 },
+    Output { index: u32 },
+}
+
+/// A change to the transaction of a PSBT, as far as signatures are concerned.
+#[derive(Clone, Copy)]
+enum PsbtEdit {
+    LockTime,
+    Sequence(usize),
+    AddInput,
+    RemoveInput(usize),
+    AddOutput,
+    RemoveOutput(usize),
+    /// Changes to unknown or proprietary fields, which no signature commits to.
+    Fields,
+}
+impl PsbtEdit {
+    /// Whether a signature of input `index` with the `sighash` type no longer holds after the
+    /// edit, for a transaction that has `outputs` outputs before it. Taproot signatures follow
+    /// BIP341, the others BIP143 and the legacy rules, which commit to the same fields here
+    /// except for the sequences.
+    fn invalidates(&self, index: usize, sighash: u32, is_taproot: bool, outputs: usize) -> bool {
+        let anyone_can_pay = sighash & 0x80 != 0;
+        let single = sighash & 0x7f == EcdsaSighashType::Single.to_u32();
+        let all = !single && sighash & 0x7f != EcdsaSighashType::None.to_u32();
+        match *self {
+            PsbtEdit::LockTime => true,
+            // Without ANYONECANPAY taproot signatures commit to every sequence, ECDSA
+            // SIGHASH_NONE and SIGHASH_SINGLE signatures only to their own.
+            PsbtEdit::Sequence(i) => i == index || (!anyone_can_pay && (all || is_taproot)),
+            PsbtEdit::AddInput => !anyone_can_pay,
+            // Later inputs move to another index, which SIGHASH_SINGLE pairs with its output.
+            PsbtEdit::RemoveInput(i) => !anyone_can_pay || (single && index > i),
+            PsbtEdit::AddOutput => all || (single && index >= outputs),
+            PsbtEdit::RemoveOutput(i) => all || (single && index >= i),
+            PsbtEdit::Fields => false,
+        }
+    }
+}
+
+/// The network the PSBT was made for, when it isn't `network`: that of its global extended keys,
+/// or the one given by the coin type of its BIP44 style derivation paths (0 for mainnet, 1 for the
+/// test networks).
+pub(crate) fn network_mismatch(
+    psbt: &BdkPartiallySignedTransaction,
+    network: BdkNetwork,
+) -> Option<BdkNetwork> {
+    let is_mainnet = network == BdkNetwork::Bitcoin;
+    if let Some(xpub) = psbt
+        .xpub
+        .keys()
+        .find(|x| (x.network == BdkNetwork::Bitcoin) != is_mainnet)
+    {
+        return Some(xpub.network);
+    }
+    let inputs = psbt.inputs.iter().flat_map(|x| {
+        x.bip32_derivation
+            .values()
+            .chain(x.tap_key_origins.values().map(|(_, source)| source))
+    });
+    let outputs = psbt.outputs.iter().flat_map(|x| {
+        x.bip32_derivation
+            .values()
+            .chain(x.tap_key_origins.values().map(|(_, source)| source))
+    });
+    let mismatched_path = inputs.chain(outputs).any(|(_, path)| match path.as_ref() {
+        [ChildNumber::Hardened {
+            index: 44 | 48 | 49 | 84 | 86,
+        }, ChildNumber::Hardened { index: coin }, ..] => (*coin == 0) != is_mainnet && *coin <= 1,
+        _ => false,
+    });
+    match (mismatched_path, is_mainnet) {
+        (false, _) => None,
+        (true, true) => Some(BdkNetwork::Testnet),
+        (true, false) => Some(BdkNetwork::Bitcoin),
+    }
+}
+
+/// The output spent by input `index`. Unlike `PsbtUtils::get_utxo_for`, an out of range vout in
+/// the non-witness UTXO of an untrusted PSBT gives `None` instead of panicking.
+pub(crate) fn input_utxo(psbt: &BdkPartiallySignedTransaction, index: usize) -> Option<BdkTxOut> {
+    let input = psbt.inputs.get(index)?;
+    match (&input.witness_utxo, &input.non_witness_utxo) {
+        (Some(utxo), _) => Some(utxo.clone()),
+        (None, Some(tx)) => {
+            let vout = psbt.unsigned_tx.input.get(index)?.previous_output.vout;
+            tx.output.get(vout as usize).cloned()
+        }
+        (None, None) => None,
+    }
+}
+
+/// The fee of the PSBT, `None` if an input is missing its UTXO or the outputs exceed the inputs.
+pub(crate) fn psbt_fee_amount(psbt: &BdkPartiallySignedTransaction) -> Option<u64> {
+    let received: u64 = (0..psbt.unsigned_tx.input.len())
+        .map(|index| input_utxo(psbt, index).map(|x| x.value))
+        .sum::<Option<u64>>()?;
+    received.checked_sub(psbt.unsigned_tx.output.iter().map(|x| x.value).sum())
+}
+
+fn input_mut(
+    psbt: &mut BdkPartiallySignedTransaction,
+    index: u32,
+) -> Result<&mut BdkInput, BdkError> {
+    psbt.inputs
+        .get_mut(index as usize)
+        .ok_or_else(|| BdkError::Generic(format!("No input at index {}", index)))
+}
+
+fn output_mut(
+    psbt: &mut BdkPartiallySignedTransaction,
+    index: u32,
+) -> Result<&mut BdkOutput, BdkError> {
+    psbt.outputs
+        .get_mut(index as usize)
+        .ok_or_else(|| BdkError::Generic(format!("No output at index {}", index)))
+}
+
+/// The sighash types of the signatures of an input, empty if it has none.
+fn signature_sighash_types(input: &BdkInput) -> Vec<u32> {
+    if input.final_script_sig.is_some() || input.final_script_witness.is_some() {
+        // The sighash types of final signatures aren't parsed, assume they commit to everything.
+        return vec![EcdsaSighashType::All.to_u32()];
+    }
+    input
+        .partial_sigs
+        .values()
+        .map(|sig| sig.hash_ty.to_u32())
+        .chain(input.tap_key_sig.iter().map(|sig| sig.hash_ty as u32))
+        .chain(input.tap_script_sigs.values().map(|sig| sig.hash_ty as u32))
+        .collect()
+}
+
+/// Whether the input spends a taproot output, so its signatures follow BIP341.
+fn is_taproot_input(input: &BdkInput) -> bool {
+    input.tap_key_sig.is_some()
+        || !input.tap_script_sigs.is_empty()
+        || input
+            .witness_utxo
+            .as_ref()
+            .is_some_and(|x| x.script_pubkey.is_v1_p2tr())
+}
+
+/// A signature added to an input while combining PSBTs.
+#[derive(Debug, Clone)]
+pub struct AddedSignature {
+    /// The public key that signed, hex encoded (x-only for taproot keys). For a taproot key-path
+    /// signature this is the internal key, or empty if the PSBT doesn't carry it.
+    pub public_key: String,
+    /// The hash of the leaf for taproot script-path signatures.
+    pub leaf_hash: Option<String>,
+    /// Whether this is a taproot key-path signature.
+    pub is_tap_key_sig: bool,
+    /// Position in the combined list of the PSBT the signature came from.
+    pub source: u32,
+}
+impl AddedSignature {
+    fn same_key(&self, other: &AddedSignature) -> bool {
+        self.public_key == other.public_key
+            && self.leaf_hash == other.leaf_hash
+            && self.is_tap_key_sig == other.is_tap_key_sig
+    }
+}
+
+fn input_signatures(input: &BdkInput, source: u32) -> Vec<AddedSignature> {
+    let mut signatures: Vec<AddedSignature> = input
+        .partial_sigs
+        .keys()
+        .map(|key| AddedSignature {
+            public_key: key.to_string(),
+            leaf_hash: None,
+            is_tap_key_sig: false,
+            source,
+        })
+        .collect();
+    if input.tap_key_sig.is_some() {
+        signatures.push(AddedSignature {
+            public_key: input
+                .tap_internal_key
+                .map(|x| x.to_string())
+                .unwrap_or_default(),
+            leaf_hash: None,
+            is_tap_key_sig: true,
+            source,
+        });
+    }
+    signatures.extend(
+        input
+            .tap_script_sigs
+            .keys()
+            .map(|(key, leaf_hash)| AddedSignature {
+                public_key: key.to_string(),
+                leaf_hash: Some(leaf_hash.to_string()),
+                is_tap_key_sig: false,
+                source,
+            }),
+    );
+    signatures
+}
+
+/// The signatures an input gained while combining PSBTs.
+#[derive(Debug, Clone)]
+pub struct CombinedInput {
+    pub index: u32,
+    pub added_signatures: Vec<AddedSignature>,
+}
+
+/// The result of combining several PSBTs.
+#[derive(Debug, Clone)]
+pub struct CombinedPsbt {
+    /// The combined PSBT, base64 encoded.
+    pub psbt: String,
+    /// One entry per input, in order.
+    pub inputs: Vec<CombinedInput>,
+}
+
+/// A PSBT input as seen by a signer.
+pub struct PsbtInputInfo {
+    pub previous_output: OutPoint,
+    pub sequence: u32,
+    /// Value of the spent output, from the witness or non-witness UTXO. `None` if the PSBT has
+    /// neither.
+    pub amount: Option<u64>,
+    /// Script of the spent output, `None` if the PSBT has no UTXO for this input.
+    pub script_pubkey: Option<Script>,
+    pub sighash_type: Option<PsbtSigHashType>,
+    /// The BIP32 derivations of the keys involved, including taproot keys.
+    pub bip32_derivations: Vec<KeyOrigin>,
+    /// The public keys, hex encoded, that have provided a partial ECDSA signature.
+    pub partial_sigs: Vec<String>,
+    /// Whether a taproot key-path signature is present.
+    pub has_tap_key_sig: bool,
+    pub tap_script_sigs: Vec<TapScriptSig>,
+    /// Whether the input has a final script_sig or witness.
+    pub is_finalized: bool,
+}
+
+/// A PSBT output as seen by a signer.
+pub struct PsbtOutputInfo {
+    pub script_pubkey: Script,
+    /// The address of the output, `None` if the script has no address form.
+    pub address: Option<String>,
+    pub amount: u64,
+    /// The BIP32 derivations of the output's keys, present when the output is change.
+    pub bip32_derivations: Vec<KeyOrigin>,
+}
+
 #[derive(Debug)]
 pub struct Transaction {
     pub(crate) internal: BdkTransaction,
@@ -154,3 +1267,652 @@ impl Transaction {
         self.internal.output.iter().map(|x| x.into()).collect()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::BdkDescriptor;
+    use crate::psbt::{
+        is_taproot_input, PartiallySignedTransaction, PsbtEdit, PsbtIssue, PsbtSafetyPolicy,
+        PsbtSafetyReport, MAX_FINALIZE_LOOKAHEAD,
+    };
+    use crate::types::Network;
+    use crate::wallet::test::{
+        foreign_script, funded_wallet, funding_tx, test_script, TEST_DESCRIPTOR,
+    };
+    use crate::wallet::Wallet;
+    use bdk::bitcoin::util::bip32::DerivationPath;
+    use bdk::bitcoin::util::psbt::Input as BdkInput;
+    use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+    use bdk::bitcoin::util::psbt::PsbtSighashType;
+    use bdk::bitcoin::{
+        Address as BdkAddress, Network as BdkNetwork, Script as BdkScript, TxOut as BdkTxOut,
+    };
+    use bdk::{FeeRate, SignOptions};
+    use std::str::FromStr;
+    use std::sync::Mutex;
+
+    /// A PSBT spending a 100_000 sats UTXO of the test wallet: 60_000 sats to a foreign script,
+    /// an OP_RETURN output and the change.
+    fn test_psbt() -> BdkPartiallySignedTransaction {
+        let wallet = funded_wallet(&[100_000]);
+        let bdk_wallet = wallet.get_wallet();
+        let mut builder = bdk_wallet.build_tx();
+        builder
+            .add_recipient(foreign_script(), 60_000)
+            .add_data(b"bdk")
+            .fee_rate(FeeRate::from_sat_per_vb(2.0));
+        builder.finish().unwrap().0
+    }
+
+    /// A PSBT spending both UTXOs of `wallet`, signed but not finalized.
+    fn signed_psbt(wallet: &Wallet) -> BdkPartiallySignedTransaction {
+        let bdk_wallet = wallet.get_wallet();
+        let mut builder = bdk_wallet.build_tx();
+        builder
+            .drain_wallet()
+            .drain_to(foreign_script())
+            .fee_rate(FeeRate::from_sat_per_vb(2.0));
+        let mut psbt = builder.finish().unwrap().0;
+        let options = SignOptions {
+            try_finalize: false,
+            ..Default::default()
+        };
+        assert!(!bdk_wallet.sign(&mut psbt, options).unwrap());
+        psbt
+    }
+
+    fn wrap(psbt: BdkPartiallySignedTransaction) -> PartiallySignedTransaction {
+        PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        }
+    }
+
+    #[test]
+    fn test_inputs() {
+        let psbt = test_psbt();
+        let previous_output = psbt.unsigned_tx.input[0].previous_output;
+        let inputs = wrap(psbt.clone()).inputs();
+        assert_eq!(inputs.len(), 1);
+        let input = &inputs[0];
+        assert_eq!(input.previous_output.txid, previous_output.txid.to_string());
+        assert_eq!(input.previous_output.vout, previous_output.vout);
+        assert_eq!(input.amount, Some(100_000));
+        assert_eq!(
+            input.script_pubkey.as_ref().unwrap().internal,
+            test_script(TEST_DESCRIPTOR, 0).to_bytes()
+        );
+        assert_eq!(input.bip32_derivations.len(), 1);
+        assert_eq!(input.bip32_derivations[0].path, "m/0/0");
+        assert!(input.partial_sigs.is_empty());
+        assert!(!input.has_tap_key_sig);
+        assert!(!input.is_finalized);
+
+        let wallet = funded_wallet(&[100_000]);
+        let mut signed = psbt.clone();
+        let options = SignOptions {
+            try_finalize: false,
+            ..Default::default()
+        };
+        assert!(!wallet.get_wallet().sign(&mut signed, options).unwrap());
+        let inputs = wrap(signed.clone()).inputs();
+        assert_eq!(inputs[0].partial_sigs.len(), 1);
+        assert_eq!(
+            inputs[0].partial_sigs[0],
+            signed.inputs[0]
+                .partial_sigs
+                .keys()
+                .next()
+                .unwrap()
+                .to_string()
+        );
+        assert!(!inputs[0].is_finalized);
+        wallet
+            .get_wallet()
+            .finalize_psbt(&mut signed, Default::default())
+            .unwrap();
+        assert!(wrap(signed).inputs()[0].is_finalized);
+
+        // An untrusted PSBT whose non-witness UTXO lacks the spent output gives no UTXO rather
+        // than a panic.
+        let mut tampered = psbt;
+        tampered.inputs[0].witness_utxo = None;
+        tampered.unsigned_tx.input[0].previous_output.vout = 7;
+        let tampered = wrap(tampered);
+        let inputs = tampered.inputs();
+        assert_eq!(inputs[0].amount, None);
+        assert!(inputs[0].script_pubkey.is_none());
+        assert_eq!(tampered.fee_amount(), None);
+        assert!(tampered.fee_rate().is_none());
+    }
+
+    #[test]
+    fn test_outputs() {
+        let psbt = test_psbt();
+        let fee = wrap(psbt.clone()).fee_amount().unwrap();
+        let outputs = wrap(psbt).outputs(Network::Regtest);
+        assert_eq!(outputs.len(), 3);
+
+        let recipient = outputs.iter().find(|x| x.amount == 60_000).unwrap();
+        assert_eq!(
+            recipient.script_pubkey.internal,
+            foreign_script().to_bytes()
+        );
+        assert_eq!(
+            recipient.address.as_deref(),
+            Some(
+                BdkAddress::from_script(&foreign_script(), BdkNetwork::Regtest)
+                    .unwrap()
+                    .to_string()
+                    .as_str()
+            )
+        );
+        assert!(recipient.bip32_derivations.is_empty());
+
+        let data = outputs
+            .iter()
+            .find(|x| BdkScript::from(x.script_pubkey.internal.clone()).is_op_return())
+            .unwrap();
+        assert_eq!(data.amount, 0);
+        assert!(data.address.is_none());
+
+        let change = outputs
+            .iter()
+            .find(|x| !x.bip32_derivations.is_empty())
+            .unwrap();
+        assert_eq!(change.bip32_derivations[0].path, "m/1/0");
+        assert!(change.address.as_ref().unwrap().starts_with("bcrt1"));
+        assert_eq!(change.amount, 100_000 - 60_000 - fee);
+
+        // Addresses follow the requested network.
+        let outputs = wrap(test_psbt()).outputs(Network::Testnet);
+        assert!(outputs
+            .iter()
+            .filter_map(|x| x.address.as_ref())
+            .all(|x| x.starts_with("tb1")));
+    }
+
+    #[test]
+    fn test_finalize_with_descriptors() {
+        let wallet = funded_wallet(&[30_000, 40_000]);
+        let psbt = signed_psbt(&wallet);
+        // Finalized without the wallet, from the public descriptor only.
+        let public = BdkDescriptor::new(TEST_DESCRIPTOR.to_string(), BdkNetwork::Regtest)
+            .unwrap()
+            .as_string();
+        let descriptors = vec![BdkDescriptor::new(public, BdkNetwork::Regtest).unwrap()];
+
+        let foreign = wrap(psbt.clone());
+        let inputs = foreign.finalize_with_descriptors(&descriptors, 0).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert!(inputs.iter().all(|x| x.is_finalized && x.error.is_none()));
+        let finalized = foreign.internal.into_inner().unwrap();
+        assert!(finalized
+            .inputs
+            .iter()
+            .all(|x| x.final_script_witness.is_some()));
+        assert!(finalized
+            .extract_tx()
+            .input
+            .iter()
+            .all(|x| x.witness.len() == 2));
+
+        // Without BIP32 derivations the inputs are only found within the lookahead: the UTXOs are
+        // at indexes 0 and 1.
+        let mut stripped = psbt.clone();
+        for input in stripped.inputs.iter_mut() {
+            input.bip32_derivation.clear();
+        }
+        let inputs = wrap(stripped.clone())
+            .finalize_with_descriptors(&descriptors, 1)
+            .unwrap();
+        assert_eq!(inputs.iter().filter(|x| x.is_finalized).count(), 1);
+        let missed = inputs.iter().find(|x| !x.is_finalized).unwrap();
+        assert_eq!(
+            missed.error.as_deref(),
+            Some("The input does not spend from the descriptor")
+        );
+        let inputs = wrap(stripped.clone())
+            .finalize_with_descriptors(&descriptors, 2)
+            .unwrap();
+        assert!(inputs.iter().all(|x| x.is_finalized));
+        assert!(wrap(stripped)
+            .finalize_with_descriptors(&descriptors, MAX_FINALIZE_LOOKAHEAD + 1)
+            .is_err());
+        assert!(wrap(psbt.clone())
+            .finalize_with_descriptors(&descriptors, u32::MAX)
+            .is_err());
+
+        // A descriptor the inputs don't spend from finalizes nothing.
+        let other =
+            BdkDescriptor::new(TEST_DESCRIPTOR.replace("/0/*", "/2/*"), BdkNetwork::Regtest)
+                .unwrap();
+        let inputs = wrap(psbt).finalize_with_descriptors(&[other], 10).unwrap();
+        assert!(inputs.iter().all(|x| !x.is_finalized));
+    }
+
+    #[test]
+    fn test_finalize_report() {
+        let wallet = funded_wallet(&[30_000, 40_000]);
+        let mut psbt = signed_psbt(&wallet);
+        let descriptors =
+            vec![BdkDescriptor::new(TEST_DESCRIPTOR.to_string(), BdkNetwork::Regtest).unwrap()];
+        // The first input lost its signature, the second its UTXO.
+        psbt.inputs[0].partial_sigs.clear();
+        let mut missing_utxo = psbt.clone();
+        missing_utxo.inputs[1].witness_utxo = None;
+        missing_utxo.inputs[1].non_witness_utxo = None;
+
+        let inputs = wrap(missing_utxo)
+            .finalize_with_descriptors(&descriptors, 10)
+            .unwrap();
+        assert!(!inputs[0].is_finalized);
+        assert!(inputs[0].error.is_some());
+        assert_eq!(inputs[1].index, 1);
+        assert!(!inputs[1].is_finalized);
+        assert_eq!(inputs[1].error.as_deref(), Some("Missing UTXO information"));
+
+        // The wallet finalizes the signed input and explains why the other one is not.
+        let psbt = wrap(psbt);
+        let inputs = wallet.finalize_psbt(&psbt, None).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].index, 0);
+        assert!(!inputs[0].is_finalized);
+        assert!(inputs[0].error.is_some());
+        assert!(inputs[1].is_finalized);
+        assert!(inputs[1].error.is_none());
+        let report = psbt.finalize_report();
+        assert!(!report[0].is_finalized);
+        assert_eq!(report[0].error, inputs[0].error);
+        assert!(report[1].is_finalized);
+        let internal = psbt.internal.lock().unwrap();
+        assert!(internal.inputs[0].final_script_witness.is_none());
+        assert!(internal.inputs[1].final_script_witness.is_some());
+    }
+
+    #[test]
+    fn test_encodings() {
+        let psbt = wrap(test_psbt());
+        let bytes = psbt.to_bytes().unwrap();
+        let hex = psbt.to_hex().unwrap();
+        let base64 = psbt.serialize().unwrap();
+        assert!(bytes.starts_with(b"psbt\xff"));
+        assert!(hex.starts_with("70736274ff"));
+        assert!(base64.starts_with("cHNidP8"));
+
+        // Hex and base64 are told apart by `new`, upper case hex and surrounding whitespace
+        // included.
+        for text in [
+            hex.clone(),
+            hex.to_uppercase(),
+            base64.clone(),
+            format!("  {}\n", base64),
+        ] {
+            let parsed = PartiallySignedTransaction::new(text).unwrap();
+            assert_eq!(parsed.serialize().unwrap(), base64);
+        }
+        // `from_any` also takes the raw binary of `.psbt` files.
+        for data in [
+            bytes.clone(),
+            hex.clone().into_bytes(),
+            base64.clone().into_bytes(),
+        ] {
+            let parsed = PartiallySignedTransaction::from_any(&data).unwrap();
+            assert_eq!(parsed.to_bytes().unwrap(), bytes);
+        }
+
+        // Text that is neither, including multi-byte characters where the magic would be, is an
+        // error rather than a panic.
+        for text in ["", "70736274", "ééééééééééé", "70736274fé00", "not a psbt"] {
+            assert!(PartiallySignedTransaction::new(text.to_string()).is_err());
+            assert!(PartiallySignedTransaction::from_any(text.as_bytes()).is_err());
+        }
+        assert!(PartiallySignedTransaction::from_any(&[0xff, 0xfe, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_version_round_trip() {
+        let psbt = wrap(test_psbt());
+        assert_eq!(psbt.version(), 0);
+        let v0 = psbt.serialize().unwrap();
+        assert_eq!(psbt.serialize_version(0).unwrap(), v0);
+
+        // A version 2 PSBT keeps its version through parsing and every encoding.
+        let v2 = psbt.serialize_version(2).unwrap();
+        assert_ne!(v2, v0);
+        let parsed = PartiallySignedTransaction::new(v2.clone()).unwrap();
+        assert_eq!(parsed.version(), 2);
+        assert_eq!(parsed.serialize().unwrap(), v2);
+        let from_hex = PartiallySignedTransaction::new(parsed.to_hex().unwrap()).unwrap();
+        assert_eq!(from_hex.serialize().unwrap(), v2);
+        let from_bytes = PartiallySignedTransaction::from_any(&parsed.to_bytes().unwrap()).unwrap();
+        assert_eq!(from_bytes.serialize().unwrap(), v2);
+        assert_eq!(parsed.txid(), psbt.txid());
+
+        // And converts back to the same version 0 PSBT.
+        assert_eq!(parsed.serialize_version(0).unwrap(), v0);
+        assert!(parsed.serialize_version(1).is_err());
+    }
+
+    #[test]
+    fn test_check_safety() {
+        let psbt = test_psbt();
+        let check = |psbt: &BdkPartiallySignedTransaction, policy: &PsbtSafetyPolicy| {
+            wrap(psbt.clone()).check_safety(BdkNetwork::Regtest, policy, |script| {
+                script != &foreign_script() && !script.is_provably_unspendable()
+            })
+        };
+        let single_issue = |report: PsbtSafetyReport| {
+            assert!(!report.is_safe);
+            assert_eq!(
+                report.issues.len(),
+                1,
+                "{:?}",
+                report
+                    .issues
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+            );
+            report.issues.into_iter().next().unwrap()
+        };
+        let default = PsbtSafetyPolicy::default();
+        assert!(default.refuse_unsafe);
+        let report = check(&psbt, &default);
+        assert!(report.is_safe);
+        assert!(report.issues.is_empty());
+        let fee = report.fee.unwrap();
+        assert_eq!(Some(fee), wrap(psbt.clone()).fee_amount());
+
+        let policy = PsbtSafetyPolicy {
+            max_fee: Some(fee - 1),
+            ..PsbtSafetyPolicy::default()
+        };
+        let issue = single_issue(check(&psbt, &policy));
+        assert!(matches!(issue, PsbtIssue::FeeTooHigh { .. }));
+        assert_eq!(
+            issue.to_string(),
+            format!(
+                "the fee of {} sats is above the limit of {} sats",
+                fee,
+                fee - 1
+            )
+        );
+
+        let policy = PsbtSafetyPolicy {
+            max_fee_percent: Some(0.1),
+            ..PsbtSafetyPolicy::default()
+        };
+        let issue = single_issue(check(&psbt, &policy));
+        assert!(matches!(issue, PsbtIssue::FeeTooHighRelative { .. }));
+        let message = issue.to_string();
+        assert!(message.starts_with(&format!("the fee of {} sats is ", fee)));
+        assert!(message.ends_with("% of the amount sent, above the limit of 0.1%"));
+
+        // The change isn't sent: the fee is about 0.3% of all outputs, but 0.5% of the payment.
+        let policy = PsbtSafetyPolicy {
+            max_fee_percent: Some(0.4),
+            ..PsbtSafetyPolicy::default()
+        };
+        assert!(matches!(
+            single_issue(check(&psbt, &policy)),
+            PsbtIssue::FeeTooHighRelative { .. }
+        ));
+        // A transaction paying only the wallet is compared to all its outputs.
+        assert!(
+            wrap(psbt.clone())
+                .check_safety(BdkNetwork::Regtest, &policy, |_| true)
+                .is_safe
+        );
+
+        let mut missing = psbt.clone();
+        missing.inputs[0].witness_utxo = None;
+        missing.inputs[0].non_witness_utxo = None;
+        let report = check(&missing, &default);
+        assert_eq!(report.fee, None);
+        assert!(matches!(
+            single_issue(report),
+            PsbtIssue::MissingUtxo { index: 0 }
+        ));
+
+        let mut witness_only = psbt.clone();
+        witness_only.inputs[0].non_witness_utxo = None;
+        assert!(check(&witness_only, &default).is_safe);
+        let policy = PsbtSafetyPolicy {
+            require_non_witness_utxo: true,
+            ..PsbtSafetyPolicy::default()
+        };
+        assert!(matches!(
+            single_issue(check(&witness_only, &policy)),
+            PsbtIssue::MissingNonWitnessUtxo { index: 0 }
+        ));
+
+        // Another transaction with an output of the same value at the same index.
+        assert_eq!(psbt.unsigned_tx.input[0].previous_output.vout, 0);
+        let mut other_tx = psbt.clone();
+        other_tx.inputs[0].non_witness_utxo = Some(funding_tx(foreign_script(), 100_000));
+        assert!(matches!(
+            single_issue(check(&other_tx, &default)),
+            PsbtIssue::NonWitnessUtxoMismatch { index: 0 }
+        ));
+
+        // The value of the previous transaction is trusted over the witness UTXO's.
+        let mut lowered = psbt.clone();
+        lowered.inputs[0].witness_utxo.as_mut().unwrap().value = 60_000;
+        let report = check(&lowered, &default);
+        assert_eq!(report.fee, Some(fee));
+        assert!(matches!(
+            single_issue(report),
+            PsbtIssue::UtxoValueMismatch {
+                index: 0,
+                witness_value: 60_000,
+                non_witness_value: 100_000
+            }
+        ));
+
+        let mut sighash = psbt.clone();
+        sighash.inputs[0].sighash_type = Some(PsbtSighashType::from_u32(0x83));
+        assert!(matches!(
+            single_issue(check(&sighash, &default)),
+            PsbtIssue::NonStandardSighash {
+                index: 0,
+                sighash: 0x83
+            }
+        ));
+
+        let mut dust = psbt.clone();
+        let recipient = dust
+            .unsigned_tx
+            .output
+            .iter()
+            .position(|x| x.script_pubkey == foreign_script())
+            .unwrap();
+        dust.unsigned_tx.output[recipient].value = 100;
+        let issue = single_issue(check(&dust, &default));
+        assert!(matches!(issue, PsbtIssue::DustOutput { .. }));
+        assert_eq!(
+            issue.to_string(),
+            format!(
+                "output {} of 100 sats is below the dust limit of 294 sats",
+                recipient
+            )
+        );
+
+        // The wallet's paths have no coin type, a BIP84 mainnet one gives the network away.
+        let mut mainnet = psbt;
+        for source in mainnet.inputs[0].bip32_derivation.values_mut() {
+            source.1 = DerivationPath::from_str("m/84'/0'/0'/0/0").unwrap();
+        }
+        let issue = single_issue(check(&mainnet, &default));
+        assert!(matches!(
+            issue,
+            PsbtIssue::NetworkMismatch {
+                network: Network::Bitcoin
+            }
+        ));
+        assert_eq!(issue.to_string(), "the PSBT is for bitcoin");
+        assert!(
+            wrap(mainnet)
+                .check_safety(BdkNetwork::Bitcoin, &default, |_| false)
+                .is_safe
+        );
+    }
+
+    #[test]
+    fn test_combine_all() {
+        let wallet = funded_wallet(&[30_000, 40_000]);
+        let signed = signed_psbt(&wallet);
+        let mut unsigned = signed.clone();
+        for input in unsigned.inputs.iter_mut() {
+            input.partial_sigs.clear();
+        }
+        // Each cosigner returns a copy with one of the inputs signed.
+        let mut first = unsigned.clone();
+        first.inputs[0] = signed.inputs[0].clone();
+        let mut second = unsigned.clone();
+        second.inputs[1] = signed.inputs[1].clone();
+        let key = |index: usize| {
+            signed.inputs[index]
+                .partial_sigs
+                .keys()
+                .next()
+                .unwrap()
+                .to_string()
+        };
+
+        let combined = PartiallySignedTransaction::combine_all(&[
+            wrap(unsigned.clone()),
+            wrap(first.clone()),
+            wrap(second),
+            // Signatures already combined are not reported again.
+            wrap(first),
+        ])
+        .unwrap();
+        assert_eq!(combined.inputs.len(), 2);
+        for (index, input) in combined.inputs.iter().enumerate() {
+            assert_eq!(input.index, index as u32);
+            assert_eq!(input.added_signatures.len(), 1);
+            let signature = &input.added_signatures[0];
+            assert_eq!(signature.public_key, key(index));
+            assert_eq!(signature.source, index as u32 + 1);
+            assert!(signature.leaf_hash.is_none());
+            assert!(!signature.is_tap_key_sig);
+        }
+        let psbt = PartiallySignedTransaction::new(combined.psbt).unwrap();
+        assert_eq!(psbt.internal.into_inner().unwrap().inputs, signed.inputs);
+
+        // Combining into an already signed PSBT adds nothing.
+        let combined = PartiallySignedTransaction::combine_all(&[
+            wrap(signed.clone()),
+            wrap(unsigned.clone()),
+        ])
+        .unwrap();
+        assert!(combined
+            .inputs
+            .iter()
+            .all(|x| x.added_signatures.is_empty()));
+
+        let mut other_tx = unsigned.clone();
+        other_tx.unsigned_tx.lock_time.0 += 1;
+        let error = PartiallySignedTransaction::combine_all(&[
+            wrap(unsigned.clone()),
+            wrap(signed),
+            wrap(other_tx),
+        ])
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("PSBT 2 describes a different unsigned transaction"));
+        assert!(PartiallySignedTransaction::combine_all(&[]).is_err());
+        let single = PartiallySignedTransaction::combine_all(&[wrap(unsigned)]).unwrap();
+        assert!(single.inputs.iter().all(|x| x.added_signatures.is_empty()));
+    }
+
+    #[test]
+    fn test_edit_invalidates() {
+        // A signature of input 1, in a transaction with two outputs.
+        let edits = [
+            PsbtEdit::LockTime,
+            PsbtEdit::Sequence(1),
+            PsbtEdit::Sequence(0),
+            PsbtEdit::AddInput,
+            PsbtEdit::RemoveInput(0),
+            PsbtEdit::AddOutput,
+            PsbtEdit::RemoveOutput(0),
+            PsbtEdit::Fields,
+        ];
+        // The sighash type, whether each edit invalidates an ECDSA signature, and whether changing
+        // the sequence of input 0 invalidates a taproot signature.
+        let matrix: [(u32, [bool; 8], bool); 6] = [
+            (
+                0x01,
+                [true, true, true, true, true, true, true, false],
+                true,
+            ),
+            (
+                0x02,
+                [true, true, false, true, true, false, false, false],
+                true,
+            ),
+            (
+                0x03,
+                [true, true, false, true, true, false, true, false],
+                true,
+            ),
+            (
+                0x81,
+                [true, true, false, false, false, true, true, false],
+                false,
+            ),
+            (
+                0x82,
+                [true, true, false, false, false, false, false, false],
+                false,
+            ),
+            (
+                0x83,
+                [true, true, false, false, true, false, true, false],
+                false,
+            ),
+        ];
+        for (sighash, expected, taproot_sequence) in matrix {
+            for (i, edit) in edits.iter().enumerate() {
+                assert_eq!(
+                    edit.invalidates(1, sighash, false, 2),
+                    expected[i],
+                    "ECDSA sighash {:#x}, edit {}",
+                    sighash,
+                    i
+                );
+                let taproot_expected = if i == 2 {
+                    taproot_sequence
+                } else {
+                    expected[i]
+                };
+                assert_eq!(
+                    edit.invalidates(1, sighash, true, 2),
+                    taproot_expected,
+                    "taproot sighash {:#x}, edit {}",
+                    sighash,
+                    i
+                );
+            }
+        }
+        // SIGHASH_DEFAULT commits to the same fields as SIGHASH_ALL.
+        for edit in edits {
+            assert_eq!(
+                edit.invalidates(1, 0x00, true, 2),
+                edit.invalidates(1, 0x01, true, 2)
+            );
+        }
+
+        let mut input = BdkInput::default();
+        assert!(!is_taproot_input(&input));
+        let mut script = vec![0x51, 0x20];
+        script.extend([1; 32]);
+        input.witness_utxo = Some(BdkTxOut {
+            value: 10_000,
+            script_pubkey: BdkScript::from(script),
+        });
+        assert!(is_taproot_input(&input));
+    }
+}
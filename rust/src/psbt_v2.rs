@@ -0,0 +1,655 @@
+//! PSBT version 2 (BIP370) encoding.
+//!
+//! A version 2 PSBT is decoded into the same `BdkPartiallySignedTransaction` model used for
+//! version 0: the unsigned transaction is rebuilt from the per-input and per-output fields, and the
+//! fields that can't be recovered from it (fallback locktime, tx modifiable flags and the required
+//! locktimes of each input) are kept in the `unknown` maps, where `combine` and signing leave them
+//! alone. The `version` of the decoded PSBT is set to 2 so it is written back out as version 2.
+use bdk::bitcoin::consensus::encode::{deserialize, serialize};
+use bdk::bitcoin::consensus::Decodable;
+use bdk::bitcoin::hashes::hex::ToHex;
+use bdk::bitcoin::util::psbt::raw;
+use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+use bdk::bitcoin::{
+    OutPoint as BdkOutPoint, PackedLockTime, Script as BdkScript, Sequence,
+    Transaction as BdkTransaction, TxIn as BdkTxIn, TxOut as BdkTxOut, Txid, VarInt, Witness,
+};
+use bdk::Error as BdkError;
+use std::io::{Cursor, Read};
+
+pub(crate) const PSBT_MAGIC: &[u8] = b"psbt\xff";
+
+const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+const PSBT_GLOBAL_TX_VERSION: u8 = 0x02;
+const PSBT_GLOBAL_FALLBACK_LOCKTIME: u8 = 0x03;
+const PSBT_GLOBAL_INPUT_COUNT: u8 = 0x04;
+const PSBT_GLOBAL_OUTPUT_COUNT: u8 = 0x05;
+const PSBT_GLOBAL_TX_MODIFIABLE: u8 = 0x06;
+const PSBT_GLOBAL_VERSION: u8 = 0xfb;
+
+const PSBT_IN_PREVIOUS_TXID: u8 = 0x0e;
+const PSBT_IN_OUTPUT_INDEX: u8 = 0x0f;
+const PSBT_IN_SEQUENCE: u8 = 0x10;
+const PSBT_IN_REQUIRED_TIME_LOCKTIME: u8 = 0x11;
+const PSBT_IN_REQUIRED_HEIGHT_LOCKTIME: u8 = 0x12;
+
+const PSBT_OUT_AMOUNT: u8 = 0x03;
+const PSBT_OUT_SCRIPT: u8 = 0x04;
+
+const TX_MODIFIABLE_INPUTS: u8 = 0x01;
+const TX_MODIFIABLE_OUTPUTS: u8 = 0x02;
+
+/// Global fields that only exist in version 2 and can't be derived from the unsigned transaction.
+const PRESERVED_GLOBAL_TYPES: [u8; 2] = [PSBT_GLOBAL_FALLBACK_LOCKTIME, PSBT_GLOBAL_TX_MODIFIABLE];
+/// Input fields that only exist in version 2 and can't be derived from the unsigned transaction.
+const PRESERVED_INPUT_TYPES: [u8; 2] = [
+    PSBT_IN_REQUIRED_TIME_LOCKTIME,
+    PSBT_IN_REQUIRED_HEIGHT_LOCKTIME,
+];
+
+/// The key-value pairs of a single PSBT map, in the order they were read.
+pub(crate) type RawMap = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// A PSBT split into its key-value maps, without interpreting any of the values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RawPsbt {
+    pub global: RawMap,
+    pub inputs: Vec<RawMap>,
+    pub outputs: Vec<RawMap>,
+}
+
+impl RawPsbt {
+    pub(crate) fn deserialize(bytes: &[u8]) -> Result<Self, BdkError> {
+        if !bytes.starts_with(PSBT_MAGIC) {
+            return Err(BdkError::Generic("Invalid PSBT magic bytes".to_string()));
+        }
+        let mut cursor = Cursor::new(&bytes[PSBT_MAGIC.len()..]);
+        let global = read_map(&mut cursor)?;
+        let (input_count, output_count) = match map_version(&global)? {
+            0 => {
+                let tx = decode_unsigned_tx(required(
+                    &global,
+                    PSBT_GLOBAL_UNSIGNED_TX,
+                    "unsigned transaction",
+                )?)?;
+                (tx.input.len() as u64, tx.output.len() as u64)
+            }
+            2 => (
+                deserialize::<VarInt>(required(&global, PSBT_GLOBAL_INPUT_COUNT, "input count")?)?
+                    .0,
+                deserialize::<VarInt>(required(
+                    &global,
+                    PSBT_GLOBAL_OUTPUT_COUNT,
+                    "output count",
+                )?)?
+                .0,
+            ),
+            version => {
+                return Err(BdkError::Generic(format!(
+                    "PSBT version {} is not supported",
+                    version
+                )))
+            }
+        };
+        let inputs = (0..input_count)
+            .map(|_| read_map(&mut cursor))
+            .collect::<Result<Vec<_>, _>>()?;
+        let outputs = (0..output_count)
+            .map(|_| read_map(&mut cursor))
+            .collect::<Result<Vec<_>, _>>()?;
+        if (cursor.position() as usize) < cursor.get_ref().len() {
+            return Err(BdkError::Generic(
+                "Unexpected trailing data after the PSBT".to_string(),
+            ));
+        }
+        Ok(RawPsbt {
+            global,
+            inputs,
+            outputs,
+        })
+    }
+
+    pub(crate) fn serialize(&self) -> Vec<u8> {
+        let mut bytes = PSBT_MAGIC.to_vec();
+        for map in std::iter::once(&self.global)
+            .chain(self.inputs.iter())
+            .chain(self.outputs.iter())
+        {
+            for (key, value) in map {
+                bytes.extend(serialize(&VarInt(key.len() as u64)));
+                bytes.extend(key);
+                bytes.extend(serialize(&VarInt(value.len() as u64)));
+                bytes.extend(value);
+            }
+            bytes.push(0x00);
+        }
+        bytes
+    }
+
+    /// The PSBT version declared in the global map.
+    pub(crate) fn version(&self) -> Result<u32, BdkError> {
+        map_version(&self.global)
+    }
+}
+
+/// Decode a version 2 PSBT into the version 0 model, keeping the version 2 only fields.
+pub(crate) fn from_v2(raw: RawPsbt) -> Result<BdkPartiallySignedTransaction, BdkError> {
+    let RawPsbt {
+        mut global,
+        mut inputs,
+        mut outputs,
+    } = raw;
+    let version: i32 = deserialize(required(
+        &global,
+        PSBT_GLOBAL_TX_VERSION,
+        "transaction version",
+    )?)?;
+    let input = inputs
+        .iter()
+        .map(|map| -> Result<BdkTxIn, BdkError> {
+            let sequence = match get(map, PSBT_IN_SEQUENCE) {
+                Some(value) => Sequence(deserialize(value)?),
+                None => Sequence::MAX,
+            };
+            Ok(BdkTxIn {
+                previous_output: BdkOutPoint {
+                    txid: deserialize::<Txid>(required(
+                        map,
+                        PSBT_IN_PREVIOUS_TXID,
+                        "previous txid",
+                    )?)?,
+                    vout: deserialize(required(map, PSBT_IN_OUTPUT_INDEX, "output index")?)?,
+                },
+                script_sig: BdkScript::new(),
+                sequence,
+                witness: Witness::default(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let output = outputs
+        .iter()
+        .map(|map| -> Result<BdkTxOut, BdkError> {
+            let amount: i64 = deserialize(required(map, PSBT_OUT_AMOUNT, "output amount")?)?;
+            if amount < 0 {
+                return Err(BdkError::Generic(
+                    "PSBT output amount is negative".to_string(),
+                ));
+            }
+            Ok(BdkTxOut {
+                value: amount as u64,
+                script_pubkey: BdkScript::from(
+                    required(map, PSBT_OUT_SCRIPT, "output script")?.to_vec(),
+                ),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let tx = BdkTransaction {
+        version,
+        lock_time: PackedLockTime(lock_time(&global, &inputs)?),
+        input,
+        output,
+    };
+
+    global.retain(|(key, _)| {
+        !is_type(
+            key,
+            &[
+                PSBT_GLOBAL_TX_VERSION,
+                PSBT_GLOBAL_INPUT_COUNT,
+                PSBT_GLOBAL_OUTPUT_COUNT,
+                PSBT_GLOBAL_VERSION,
+            ],
+        )
+    });
+    global.push((vec![PSBT_GLOBAL_UNSIGNED_TX], encode_unsigned_tx(&tx)));
+    for map in inputs.iter_mut() {
+        map.retain(|(key, _)| {
+            !is_type(
+                key,
+                &[
+                    PSBT_IN_PREVIOUS_TXID,
+                    PSBT_IN_OUTPUT_INDEX,
+                    PSBT_IN_SEQUENCE,
+                ],
+            )
+        });
+    }
+    for map in outputs.iter_mut() {
+        map.retain(|(key, _)| !is_type(key, &[PSBT_OUT_AMOUNT, PSBT_OUT_SCRIPT]));
+    }
+    let raw = RawPsbt {
+        global,
+        inputs,
+        outputs,
+    };
+    let mut psbt: BdkPartiallySignedTransaction = deserialize(&raw.serialize())?;
+    psbt.version = 2;
+    Ok(psbt)
+}
+
+/// Encode a PSBT as version 2, deriving the per-input and per-output fields from its unsigned
+/// transaction.
+pub(crate) fn to_v2(psbt: &BdkPartiallySignedTransaction) -> Result<Vec<u8>, BdkError> {
+    let mut v0 = psbt.clone();
+    v0.version = 0;
+    let tx = v0.unsigned_tx.clone();
+    let mut raw = RawPsbt::deserialize(&serialize(&v0))?;
+
+    raw.global.retain(|(key, _)| {
+        !is_type(
+            key,
+            &[
+                PSBT_GLOBAL_UNSIGNED_TX,
+                PSBT_GLOBAL_TX_VERSION,
+                PSBT_GLOBAL_INPUT_COUNT,
+                PSBT_GLOBAL_OUTPUT_COUNT,
+                PSBT_GLOBAL_VERSION,
+            ],
+        )
+    });
+    // Without required locktimes on the inputs, the fallback is what carries the locktime.
+    let has_required_locktime = raw.inputs.iter().any(|map| {
+        map.iter()
+            .any(|(key, _)| is_type(key, &PRESERVED_INPUT_TYPES))
+    });
+    if get(&raw.global, PSBT_GLOBAL_FALLBACK_LOCKTIME).is_none()
+        && !has_required_locktime
+        && tx.lock_time.0 != 0
+    {
+        raw.global.push((
+            vec![PSBT_GLOBAL_FALLBACK_LOCKTIME],
+            serialize(&tx.lock_time.0),
+        ));
+    }
+    raw.global
+        .push((vec![PSBT_GLOBAL_TX_VERSION], serialize(&tx.version)));
+    raw.global.push((
+        vec![PSBT_GLOBAL_INPUT_COUNT],
+        serialize(&VarInt(tx.input.len() as u64)),
+    ));
+    raw.global.push((
+        vec![PSBT_GLOBAL_OUTPUT_COUNT],
+        serialize(&VarInt(tx.output.len() as u64)),
+    ));
+    raw.global
+        .push((vec![PSBT_GLOBAL_VERSION], serialize(&2u32)));
+    raw.global.sort();
+
+    for (map, txin) in raw.inputs.iter_mut().zip(tx.input.iter()) {
+        map.retain(|(key, _)| {
+            !is_type(
+                key,
+                &[
+                    PSBT_IN_PREVIOUS_TXID,
+                    PSBT_IN_OUTPUT_INDEX,
+                    PSBT_IN_SEQUENCE,
+                ],
+            )
+        });
+        map.push((
+            vec![PSBT_IN_PREVIOUS_TXID],
+            serialize(&txin.previous_output.txid),
+        ));
+        map.push((
+            vec![PSBT_IN_OUTPUT_INDEX],
+            serialize(&txin.previous_output.vout),
+        ));
+        map.push((vec![PSBT_IN_SEQUENCE], serialize(&txin.sequence.0)));
+        map.sort();
+    }
+    for (map, txout) in raw.outputs.iter_mut().zip(tx.output.iter()) {
+        map.retain(|(key, _)| !is_type(key, &[PSBT_OUT_AMOUNT, PSBT_OUT_SCRIPT]));
+        map.push((vec![PSBT_OUT_AMOUNT], serialize(&(txout.value as i64))));
+        map.push((vec![PSBT_OUT_SCRIPT], txout.script_pubkey.to_bytes()));
+        map.sort();
+    }
+    Ok(raw.serialize())
+}
+
+/// Convert a PSBT to version 0, dropping the version 2 only fields.
+pub(crate) fn to_v0(psbt: &BdkPartiallySignedTransaction) -> BdkPartiallySignedTransaction {
+    let mut psbt = psbt.clone();
+    psbt.version = 0;
+    psbt.unknown
+        .retain(|key, _| !PRESERVED_GLOBAL_TYPES.contains(&key.type_value));
+    for input in psbt.inputs.iter_mut() {
+        input
+            .unknown
+            .retain(|key, _| !PRESERVED_INPUT_TYPES.contains(&key.type_value));
+    }
+    psbt
+}
+
+/// Set the locktime of a PSBT. For version 2 the fallback locktime is updated too, and PSBTs whose
+/// locktime comes from the required locktimes of their inputs are refused.
+pub(crate) fn set_lock_time(
+    psbt: &mut BdkPartiallySignedTransaction,
+    lock_time: u32,
+) -> Result<(), BdkError> {
+    if psbt.version == 2 {
+        let has_required_locktime = psbt.inputs.iter().any(|input| {
+            input
+                .unknown
+                .keys()
+                .any(|key| PRESERVED_INPUT_TYPES.contains(&key.type_value))
+        });
+        if has_required_locktime {
+            return Err(BdkError::Generic(
+                "The locktime of this PSBT is set by the required locktimes of its inputs"
+                    .to_string(),
+            ));
+        }
+        psbt.unknown
+            .retain(|key, _| key.type_value != PSBT_GLOBAL_FALLBACK_LOCKTIME);
+        if lock_time != 0 {
+            psbt.unknown.insert(
+                raw::Key {
+                    type_value: PSBT_GLOBAL_FALLBACK_LOCKTIME,
+                    key: vec![],
+                },
+                serialize(&lock_time),
+            );
+        }
+    }
+    psbt.unsigned_tx.lock_time = PackedLockTime(lock_time);
+    Ok(())
+}
+
+/// Check the tx modifiable flags of a version 2 PSBT before adding or removing inputs or outputs.
+/// PSBTs without the flags can be modified freely.
+pub(crate) fn check_modifiable(
+    psbt: &BdkPartiallySignedTransaction,
+    inputs: bool,
+    outputs: bool,
+) -> Result<(), BdkError> {
+    if psbt.version != 2 {
+        return Ok(());
+    }
+    let key = raw::Key {
+        type_value: PSBT_GLOBAL_TX_MODIFIABLE,
+        key: vec![],
+    };
+    let flags = match psbt.unknown.get(&key).map(|x| x.as_slice()) {
+        Some([flags]) => *flags,
+        Some(_) => {
+            return Err(BdkError::Generic(
+                "Invalid PSBT tx modifiable flags".to_string(),
+            ))
+        }
+        None => return Ok(()),
+    };
+    if inputs && flags & TX_MODIFIABLE_INPUTS == 0 {
+        return Err(BdkError::Generic(
+            "The PSBT does not allow modifying its inputs".to_string(),
+        ));
+    }
+    if outputs && flags & TX_MODIFIABLE_OUTPUTS == 0 {
+        return Err(BdkError::Generic(
+            "The PSBT does not allow modifying its outputs".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Pick the transaction locktime as described in BIP370: the largest required locktime of the
+/// inputs, preferring height based locktimes when both kinds are possible, otherwise the fallback
+/// locktime.
+fn lock_time(global: &RawMap, inputs: &[RawMap]) -> Result<u32, BdkError> {
+    let mut heights = Vec::new();
+    let mut times = Vec::new();
+    let mut height_possible = true;
+    let mut time_possible = true;
+    for map in inputs {
+        let height = get(map, PSBT_IN_REQUIRED_HEIGHT_LOCKTIME)
+            .map(deserialize::<u32>)
+            .transpose()?;
+        let time = get(map, PSBT_IN_REQUIRED_TIME_LOCKTIME)
+            .map(deserialize::<u32>)
+            .transpose()?;
+        if height.is_none() && time.is_none() {
+            continue;
+        }
+        match height {
+            Some(height) => heights.push(height),
+            None => height_possible = false,
+        }
+        match time {
+            Some(time) => times.push(time),
+            None => time_possible = false,
+        }
+    }
+    if heights.is_empty() && times.is_empty() {
+        return match get(global, PSBT_GLOBAL_FALLBACK_LOCKTIME) {
+            Some(value) => Ok(deserialize(value)?),
+            None => Ok(0),
+        };
+    }
+    if height_possible {
+        Ok(heights.into_iter().max().unwrap_or(0))
+    } else if time_possible {
+        Ok(times.into_iter().max().unwrap_or(0))
+    } else {
+        Err(BdkError::Generic(
+            "PSBT inputs require incompatible locktime types".to_string(),
+        ))
+    }
+}
+
+fn map_version(global: &RawMap) -> Result<u32, BdkError> {
+    match get(global, PSBT_GLOBAL_VERSION) {
+        Some(value) => Ok(deserialize(value)?),
+        None => Ok(0),
+    }
+}
+
+fn read_map(cursor: &mut Cursor<&[u8]>) -> Result<RawMap, BdkError> {
+    let mut map = RawMap::new();
+    loop {
+        let key = read_bytes(cursor)?;
+        if key.is_empty() {
+            return Ok(map);
+        }
+        let value = read_bytes(cursor)?;
+        if map.iter().any(|(k, _)| k == &key) {
+            return Err(BdkError::Generic(format!(
+                "Duplicate PSBT key {}",
+                key.to_hex()
+            )));
+        }
+        map.push((key, value));
+    }
+}
+
+fn read_bytes(cursor: &mut Cursor<&[u8]>) -> Result<Vec<u8>, BdkError> {
+    let len = VarInt::consensus_decode(cursor)?.0;
+    let remaining = cursor.get_ref().len() as u64 - cursor.position();
+    if len > remaining {
+        return Err(BdkError::Generic("Unexpected end of PSBT data".to_string()));
+    }
+    let mut bytes = vec![0; len as usize];
+    cursor
+        .read_exact(&mut bytes)
+        .map_err(|e| BdkError::Generic(e.to_string()))?;
+    Ok(bytes)
+}
+
+/// Decode the unsigned transaction of a version 0 PSBT field by field, like
+/// `bitcoin::util::psbt` does: it has no witnesses, and the consensus decoding would read a
+/// transaction without inputs as a segwit marker.
+fn decode_unsigned_tx(bytes: &[u8]) -> Result<BdkTransaction, BdkError> {
+    let mut cursor = Cursor::new(bytes);
+    let tx = BdkTransaction {
+        version: Decodable::consensus_decode(&mut cursor)?,
+        input: Decodable::consensus_decode(&mut cursor)?,
+        output: Decodable::consensus_decode(&mut cursor)?,
+        lock_time: Decodable::consensus_decode(&mut cursor)?,
+    };
+    if (cursor.position() as usize) < bytes.len() {
+        return Err(BdkError::Generic(
+            "Unexpected trailing data after the unsigned transaction".to_string(),
+        ));
+    }
+    Ok(tx)
+}
+
+/// Encode the unsigned transaction of a version 0 PSBT field by field, the counterpart of
+/// `decode_unsigned_tx`: the consensus encoding adds a segwit marker to transactions without
+/// inputs.
+fn encode_unsigned_tx(tx: &BdkTransaction) -> Vec<u8> {
+    let mut bytes = serialize(&tx.version);
+    bytes.extend(serialize(&tx.input));
+    bytes.extend(serialize(&tx.output));
+    bytes.extend(serialize(&tx.lock_time));
+    bytes
+}
+
+/// The value of the key made of `key_type` alone.
+fn get(map: &RawMap, key_type: u8) -> Option<&[u8]> {
+    map.iter()
+        .find(|(key, _)| key.as_slice() == [key_type])
+        .map(|(_, value)| value.as_slice())
+}
+
+fn required<'a>(map: &'a RawMap, key_type: u8, name: &str) -> Result<&'a [u8], BdkError> {
+    get(map, key_type).ok_or_else(|| BdkError::Generic(format!("PSBT is missing the {}", name)))
+}
+
+fn is_type(key: &[u8], types: &[u8]) -> bool {
+    key.first().is_some_and(|key_type| types.contains(key_type))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bdk::bitcoin::hashes::hex::FromHex;
+    use std::str::FromStr;
+
+    // A version 2 PSBT with one input and two outputs.
+    const PSBT_V2: &str = "cHNidP8BAgQCAAAAAQQBAQEFAQIB+wQCAAAAAAEOIMhfgYRAlPnw7sHkH41j4Kmen3Pccl1zGYccnEEh2QoLAQ8EAAAAAAABAwgACK8vAAAAAAEEFgAUxDD2TEdW2jENvRoIVXLvKZkmJywAAQMIoFrqCwAAAAABBBYAFE3Rk6yWSlasG54cyoRU/i9HT4UTAA==";
+
+    #[test]
+    fn test_v2_round_trip() {
+        let bytes = base64::decode(PSBT_V2).unwrap();
+        let raw = RawPsbt::deserialize(&bytes).unwrap();
+        assert_eq!(raw.version().unwrap(), 2);
+        let psbt = from_v2(raw.clone()).unwrap();
+        assert_eq!(psbt.version, 2);
+        assert_eq!(psbt.unsigned_tx.version, 2);
+        assert_eq!(psbt.unsigned_tx.input.len(), 1);
+        assert_eq!(
+            psbt.unsigned_tx.input[0].previous_output,
+            BdkOutPoint::from_str(
+                "0b0ad921419c1c8719735d72dc739f9ea9e0638d1fe4c1eef0f9944084815fc8:0"
+            )
+            .unwrap()
+        );
+        assert_eq!(psbt.unsigned_tx.output.len(), 2);
+        assert_eq!(psbt.unsigned_tx.output[0].value, 800_000_000);
+
+        let v2 = RawPsbt::deserialize(&to_v2(&psbt).unwrap()).unwrap();
+        let psbt_again = from_v2(v2).unwrap();
+        assert_eq!(psbt, psbt_again);
+
+        let v0 = to_v0(&psbt);
+        assert_eq!(v0.version, 0);
+        assert_eq!(v0.unsigned_tx, psbt.unsigned_tx);
+    }
+
+    #[test]
+    fn test_v2_preserves_fields() {
+        let bytes = base64::decode(PSBT_V2).unwrap();
+        let mut raw = RawPsbt::deserialize(&bytes).unwrap();
+        raw.global
+            .push((vec![PSBT_GLOBAL_TX_MODIFIABLE], vec![0x03]));
+        raw.inputs[0].push((
+            vec![PSBT_IN_REQUIRED_HEIGHT_LOCKTIME],
+            serialize(&700_000u32),
+        ));
+        let psbt = from_v2(raw).unwrap();
+        assert_eq!(psbt.unsigned_tx.lock_time.0, 700_000);
+
+        let raw = RawPsbt::deserialize(&to_v2(&psbt).unwrap()).unwrap();
+        assert_eq!(
+            get(&raw.global, PSBT_GLOBAL_TX_MODIFIABLE),
+            Some(&[0x03][..])
+        );
+        assert_eq!(
+            get(&raw.inputs[0], PSBT_IN_REQUIRED_HEIGHT_LOCKTIME),
+            Some(&serialize(&700_000u32)[..])
+        );
+
+        let v0 = to_v0(&psbt);
+        assert!(v0.unknown.is_empty());
+        assert!(v0.inputs[0].unknown.is_empty());
+    }
+
+    #[test]
+    fn test_v2_lock_time_and_modifiable() {
+        let bytes = base64::decode(PSBT_V2).unwrap();
+        let mut raw = RawPsbt::deserialize(&bytes).unwrap();
+        raw.global
+            .push((vec![PSBT_GLOBAL_TX_MODIFIABLE], vec![TX_MODIFIABLE_OUTPUTS]));
+        let mut psbt = from_v2(raw).unwrap();
+        assert!(check_modifiable(&psbt, false, true).is_ok());
+        assert!(check_modifiable(&psbt, true, false).is_err());
+        assert!(check_modifiable(&to_v0(&psbt), true, false).is_ok());
+
+        set_lock_time(&mut psbt, 800_000).unwrap();
+        let raw = RawPsbt::deserialize(&to_v2(&psbt).unwrap()).unwrap();
+        assert_eq!(
+            get(&raw.global, PSBT_GLOBAL_FALLBACK_LOCKTIME),
+            Some(&serialize(&800_000u32)[..])
+        );
+        assert_eq!(from_v2(raw).unwrap().unsigned_tx.lock_time.0, 800_000);
+
+        psbt.inputs[0].unknown.insert(
+            raw::Key {
+                type_value: PSBT_IN_REQUIRED_HEIGHT_LOCKTIME,
+                key: vec![],
+            },
+            serialize(&700_000u32),
+        );
+        assert!(set_lock_time(&mut psbt, 0).is_err());
+    }
+
+    #[test]
+    fn test_v0_without_inputs() {
+        // A version 0 PSBT whose unsigned transaction has no inputs yet.
+        let tx = BdkTransaction {
+            version: 2,
+            lock_time: PackedLockTime(0),
+            input: vec![],
+            output: vec![BdkTxOut {
+                value: 800_000_000,
+                script_pubkey: BdkScript::from(
+                    Vec::<u8>::from_hex("0014c430f64c4756da310dbd1a085572ef299926272c").unwrap(),
+                ),
+            }],
+        };
+        let psbt = BdkPartiallySignedTransaction::from_unsigned_tx(tx).unwrap();
+        let raw = RawPsbt::deserialize(&serialize(&psbt)).unwrap();
+        assert_eq!(raw.version().unwrap(), 0);
+        assert!(raw.inputs.is_empty());
+        assert_eq!(raw.outputs.len(), 1);
+
+        let v2 = RawPsbt::deserialize(&to_v2(&psbt).unwrap()).unwrap();
+        assert_eq!(
+            get(&v2.global, PSBT_GLOBAL_INPUT_COUNT),
+            Some(&serialize(&VarInt(0))[..])
+        );
+        assert_eq!(from_v2(v2).unwrap().unsigned_tx, psbt.unsigned_tx);
+    }
+
+    #[test]
+    fn test_strict_parsing() {
+        let bytes = base64::decode(PSBT_V2).unwrap();
+        assert!(RawPsbt::deserialize(&bytes[1..]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0x00);
+        assert!(RawPsbt::deserialize(&trailing).is_err());
+        let mut raw = RawPsbt::deserialize(&bytes).unwrap();
+        let duplicate = raw.outputs[0][0].clone();
+        raw.outputs[0].push(duplicate);
+        assert!(RawPsbt::deserialize(&raw.serialize()).is_err());
+    }
+}
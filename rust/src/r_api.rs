@@ -1,15 +1,33 @@
 pub use crate::blockchain::{Blockchain, BlockchainConfig};
+use crate::bsms::{self, BsmsDescriptorRecord, BsmsEncryption, BsmsKeyRecord};
 pub use crate::descriptor::BdkDescriptor;
-use crate::key::{DerivationPath, DescriptorPublicKey, DescriptorSecretKey, Mnemonic};
-use crate::psbt::PartiallySignedTransaction;
+use crate::descriptor::{
+    self, compile_policy, describe, CompiledPolicy, DerivedAddress, DescriptorInfo, DescriptorType,
+    MultisigDescriptors, MultisigScriptType, PolicyContext, PolicyKey, Slip132Template,
+};
+use crate::import::{self, ImportedWallet};
+use crate::key::{
+    self, DerivationPath, DescriptorPublicKey, DescriptorSecretKey, KeyMetadata, Mnemonic,
+    Slip132Version,
+};
+use crate::multisig::{MultisigSession, MultisigStatus};
+use crate::payjoin::{
+    payjoin_receive, HttpTransport, PayjoinParams, PayjoinRequest, PayjoinSender, PayjoinUri,
+};
 pub use crate::psbt::Transaction;
+use crate::psbt::{
+    CombinedPsbt, FinalizedPsbt, PartiallySignedTransaction, PsbtInputInfo, PsbtMap,
+    PsbtOutputInfo, PsbtSafetyPolicy, PsbtSafetyReport,
+};
 use crate::types::{
-    to_input, Address, AddressIndex, AddressInfo, Balance, BdkTxBuilderResult, ChangeSpendPolicy,
-    DescNetwork,  KeychainKind, Network, OutPoint, Payload, PsbtSigHashType, RbfValue,
-    Script, ScriptAmount, TransactionDetails, TxIn, TxOut, WordCount,
+    to_input, Address, AddressIndex, AddressInfo, Balance, BdkBumpFeeTxBuilderResult,
+    BdkSweepResult, BdkTxBuilderResult, ChangeSpendPolicy, ConsolidationPsbt, DescNetwork,
+    KeychainKind, MaxSendable, Network, OutPoint, Payload, PsbtAnalysis, PsbtSigHashType, RbfValue,
+    Script, ScriptAmount, ShrinkOutput, TransactionDetails, TxIn, TxOut, UtxoLabel, WordCount,
 };
+use crate::ur::{self, UrDecodeProgress, UrDecoder};
 pub use crate::wallet::{DatabaseConfig, Wallet};
-use bdk::bitcoin::{Address as BdkAddress, OutPoint as BdkOutPoint, Sequence, Txid};
+use bdk::bitcoin::{OutPoint as BdkOutPoint, Sequence, Txid};
 use bdk::keys::DescriptorSecretKey as BdkDescriptorSecretKey;
 use bdk::Error;
 use lazy_static::lazy_static;
@@ -18,7 +36,7 @@ use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex, RwLock};
 
-use crate::wallet::{LocalUtxo, SignOptions};
+use crate::wallet::{estimate_satisfied_weight, LocalUtxo, SignOptions};
 
 lazy_static! {
     static ref RUNTIME: RwLock<Option<tokio::runtime::Runtime>> = RwLock::new(None);
@@ -125,10 +143,50 @@ impl Api {
     //========PartiallySignedTransaction==========
     pub fn serialize_psbt(psbt_str: String) -> anyhow::Result<String> {
         let psbt = PartiallySignedTransaction::new(psbt_str);
-        return match psbt {
-            Ok(e) => Ok(e.serialize()),
+        return match psbt.and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+    }
+    /// Parse a PSBT given as binary, hex or base64 bytes, as read from a `.psbt` file, and
+    /// return it as base64.
+    pub fn psbt_from_bytes(bytes: Vec<u8>) -> anyhow::Result<String> {
+        match PartiallySignedTransaction::from_any(&bytes).and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Serialize a PSBT as binary, the format used for `.psbt` files.
+    pub fn psbt_to_bytes(psbt_str: String) -> anyhow::Result<Vec<u8>> {
+        match PartiallySignedTransaction::new(psbt_str).and_then(|e| e.to_bytes()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Serialize a PSBT as hex.
+    pub fn psbt_to_hex(psbt_str: String) -> anyhow::Result<String> {
+        match PartiallySignedTransaction::new(psbt_str).and_then(|e| e.to_hex()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// The version of a PSBT, 0 or 2 (BIP370).
+    pub fn psbt_version(psbt_str: String) -> anyhow::Result<u32> {
+        match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => Ok(e.version()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Serialize a PSBT of either version as version 0 or version 2 (BIP370).
+    pub fn serialize_psbt_version(psbt_str: String, version: u32) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
             Err(e) => anyhow::bail!("{:?}", e),
         };
+        match psbt.serialize_version(version) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn psbt_txid(psbt_str: String) -> anyhow::Result<String> {
         let psbt = PartiallySignedTransaction::new(psbt_str);
@@ -156,13 +214,204 @@ impl Api {
         psbt.unwrap().fee_amount()
     }
     pub fn combine_psbt(psbt_str: String, other: String) -> anyhow::Result<String> {
-        let psbt = PartiallySignedTransaction::new(psbt_str).unwrap();
-        let other = PartiallySignedTransaction::new(other).unwrap();
-        return match psbt.combine(Arc::new(other)) {
-            Ok(e) => Ok(e.serialize()),
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let other = match PartiallySignedTransaction::new(other) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        return match psbt.combine(Arc::new(other)).and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+    }
+    /// Combine PSBTs describing the same unsigned transaction, e.g. the partially signed copies
+    /// returned by each cosigner, and report which inputs gained which signatures.
+    pub fn combine_psbts(psbt_strs: Vec<String>) -> anyhow::Result<CombinedPsbt> {
+        let mut psbts = Vec::with_capacity(psbt_strs.len());
+        for (index, psbt_str) in psbt_strs.into_iter().enumerate() {
+            match PartiallySignedTransaction::new(psbt_str) {
+                Ok(e) => psbts.push(e),
+                Err(e) => anyhow::bail!("Invalid PSBT {}: {:?}", index, e),
+            }
+        }
+        return match PartiallySignedTransaction::combine_all(&psbts) {
+            Ok(e) => Ok(e),
             Err(e) => anyhow::bail!("{:?}", e),
         };
     }
+    /// Add an input spending `outpoint` to a PSBT. `psbt_input` is the JSON encoded PSBT input
+    /// and must carry its UTXO. Edits that would invalidate existing signatures are refused, as
+    /// for all the PSBT editing functions below.
+    pub fn psbt_add_input(
+        psbt_str: String,
+        outpoint: OutPoint,
+        psbt_input: String,
+        sequence: Option<u32>,
+    ) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let input = match serde_json::from_str(&psbt_input) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("Invalid PSBT input: {}", e),
+        };
+        match psbt
+            .add_input(&outpoint, input, sequence)
+            .and_then(|e| e.serialize())
+        {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Remove an unsigned input from a PSBT.
+    pub fn psbt_remove_input(psbt_str: String, index: u32) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt.remove_input(index).and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn psbt_add_output(
+        psbt_str: String,
+        script: Script,
+        amount: u64,
+    ) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt.add_output(script, amount).and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Remove an output that no signature of the PSBT commits to.
+    pub fn psbt_remove_output(psbt_str: String, index: u32) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt.remove_output(index).and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn psbt_set_sequence(
+        psbt_str: String,
+        index: u32,
+        sequence: u32,
+    ) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt
+            .set_sequence(index, sequence)
+            .and_then(|e| e.serialize())
+        {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn psbt_set_lock_time(psbt_str: String, lock_time: u32) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt.set_lock_time(lock_time).and_then(|e| e.serialize()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Set an unknown field of a PSBT map, or remove it when `value` is null.
+    pub fn psbt_set_unknown(
+        psbt_str: String,
+        map: PsbtMap,
+        key_type: u8,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt
+            .set_unknown(map, key_type, key, value)
+            .and_then(|e| e.serialize())
+        {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Set a proprietary field of a PSBT map, or remove it when `value` is null.
+    pub fn psbt_set_proprietary(
+        psbt_str: String,
+        map: PsbtMap,
+        prefix: Vec<u8>,
+        subtype: u8,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match psbt
+            .set_proprietary(map, prefix, subtype, key, value)
+            .and_then(|e| e.serialize())
+        {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Finalize the inputs of a PSBT spending from `descriptor` or `change_descriptor`, without
+    /// creating a wallet. The first `lookahead` derivation indexes, at most 10,000, are searched for
+    /// inputs that carry no BIP32 derivations.
+    pub fn finalize_psbt_with_descriptor(
+        psbt_str: String,
+        descriptor: String,
+        change_descriptor: Option<String>,
+        network: Network,
+        lookahead: u32,
+    ) -> anyhow::Result<FinalizedPsbt> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let mut descriptors = Vec::new();
+        for descriptor in std::iter::once(descriptor).chain(change_descriptor) {
+            match BdkDescriptor::new(descriptor, network.clone().into()) {
+                Ok(e) => descriptors.push(e),
+                Err(e) => anyhow::bail!("{:?}", e),
+            }
+        }
+        match psbt
+            .finalize_with_descriptors(&descriptors, lookahead)
+            .and_then(|e| FinalizedPsbt::new(&psbt, e))
+        {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn psbt_inputs(psbt_str: String) -> anyhow::Result<Vec<PsbtInputInfo>> {
+        match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => Ok(e.inputs()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn psbt_outputs(psbt_str: String, network: Network) -> anyhow::Result<Vec<PsbtOutputInfo>> {
+        match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => Ok(e.outputs(network)),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
     pub fn json_serialize(psbt_str: String) -> anyhow::Result<String> {
         let psbt = PartiallySignedTransaction::new(psbt_str).unwrap();
         Ok(psbt.json_serialize())
@@ -239,59 +488,456 @@ impl Api {
             tx_builder.add_data(data.as_slice());
         }
 
-        return match tx_builder.finish() {
-            Ok(e) => Ok(BdkTxBuilderResult(
-                Arc::new(PartiallySignedTransaction {
-                    internal: Mutex::new(e.0),
-                })
-                .serialize(),
-                TransactionDetails::from(&e.1),
-            )),
+        let (psbt, details) = match tx_builder.finish() {
+            Ok(e) => e,
             Err(e) => anyhow::bail!("{:?}", e),
         };
+        match (PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        })
+        .serialize()
+        {
+            Ok(e) => Ok(BdkTxBuilderResult(e, TransactionDetails::from(&details))),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
 
     //========BumpFeeTxBuilder==========
     pub fn bump_fee_tx_builder_finish(
         txid: String,
-        fee_rate: f32,
-        allow_shrinking: Option<String>,
+        fee_rate: Option<f32>,
+        fee_absolute: Option<u64>,
+        allow_shrinking: Option<ShrinkOutput>,
+        utxos: Vec<OutPoint>,
+        unspendable: Vec<OutPoint>,
+        manually_selected_only: bool,
         wallet_id: String,
         enable_rbf: bool,
         n_sequence: Option<u32>,
-    ) -> anyhow::Result<BdkTxBuilderResult> {
-        let txid = Txid::from_str(txid.as_str()).unwrap();
+    ) -> anyhow::Result<BdkBumpFeeTxBuilderResult> {
+        let txid = match Txid::from_str(txid.as_str()) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
         let binding = Wallet::retrieve_wallet(wallet_id);
         let bdk_wallet = binding.get_wallet();
 
+        let original = match bdk_wallet.get_tx(&txid, true) {
+            Ok(Some(e)) => e,
+            Ok(None) => anyhow::bail!("{:?}", Error::TransactionNotFound),
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let (original_tx, original_fee) = match (original.transaction, original.fee) {
+            (Some(tx), Some(fee)) => (tx, fee),
+            (None, _) => anyhow::bail!("{:?}", Error::TransactionNotFound),
+            (_, None) => anyhow::bail!("{:?}", Error::FeeRateUnavailable),
+        };
+
         let mut tx_builder = match bdk_wallet.build_fee_bump(txid) {
             Ok(e) => e,
             Err(e) => anyhow::bail!("{:?}", e),
         };
-        tx_builder.fee_rate(bdk::FeeRate::from_sat_per_vb(fee_rate));
-        if let Some(allow_shrinking) = &allow_shrinking {
-            let address = BdkAddress::from_str(allow_shrinking)
-                .map_err(|e| Error::Generic(e.to_string()))
-                .unwrap();
-            let script = address.script_pubkey();
-            tx_builder.allow_shrinking(script).unwrap();
+        match (fee_rate, fee_absolute) {
+            (_, Some(fee_amount)) => {
+                tx_builder.fee_absolute(fee_amount);
+            }
+            (Some(sat_per_vb), None) => {
+                tx_builder.fee_rate(bdk::FeeRate::from_sat_per_vb(sat_per_vb));
+            }
+            (None, None) => anyhow::bail!(
+                "{:?}",
+                Error::Generic("Either a fee rate or an absolute fee is required".to_string())
+            ),
+        }
+        if let Some(allow_shrinking) = allow_shrinking {
+            let script = match allow_shrinking.script_pubkey(&original_tx) {
+                Ok(e) => e,
+                Err(e) => anyhow::bail!("{:?}", e),
+            };
+            if let Err(e) = tx_builder.allow_shrinking(script) {
+                anyhow::bail!("{:?}", e);
+            }
+        }
+        if !utxos.is_empty() {
+            let bdk_utxos: Vec<BdkOutPoint> = utxos.iter().map(BdkOutPoint::from).collect();
+            if let Err(e) = tx_builder.add_utxos(&bdk_utxos) {
+                anyhow::bail!("{:?}", e);
+            }
+        }
+        if !unspendable.is_empty() {
+            let bdk_unspendable: Vec<BdkOutPoint> =
+                unspendable.iter().map(BdkOutPoint::from).collect();
+            tx_builder.unspendable(bdk_unspendable);
+        }
+        if manually_selected_only {
+            tx_builder.manually_selected_only();
+        }
+        if let Some(n_sequence) = n_sequence {
+            tx_builder.enable_rbf_with_sequence(bdk::bitcoin::Sequence(n_sequence));
+        }
+        if enable_rbf {
+            tx_builder.enable_rbf();
+        }
+        let (psbt, details) = match tx_builder.finish() {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let fee = details.fee.unwrap_or_default();
+        let weight = match estimate_satisfied_weight(&bdk_wallet, &psbt.unsigned_tx) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let psbt = match (PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        })
+        .serialize()
+        {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        Ok(BdkBumpFeeTxBuilderResult {
+            psbt,
+            transaction_details: TransactionDetails::from(&details),
+            original_fee,
+            original_fee_rate: bdk::FeeRate::from_wu(original_fee, original_tx.weight())
+                .as_sat_per_vb(),
+            fee,
+            fee_rate: bdk::FeeRate::from_wu(fee, weight).as_sat_per_vb(),
+        })
+    }
+
+    pub fn cancel_transaction(
+        wallet_id: String,
+        txid: String,
+        fee_rate: f32,
+    ) -> anyhow::Result<BdkTxBuilderResult> {
+        let txid = match Txid::from_str(txid.as_str()) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let (psbt, details) = match Wallet::retrieve_wallet(wallet_id)
+            .cancel_transaction(txid, bdk::FeeRate::from_sat_per_vb(fee_rate))
+        {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match (PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        })
+        .serialize()
+        {
+            Ok(e) => Ok(BdkTxBuilderResult(e, TransactionDetails::from(&details))),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+
+    //================PayJoin=========
+    pub fn parse_payjoin_uri(uri: String) -> anyhow::Result<PayjoinUri> {
+        match PayjoinUri::new(uri) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Sign the PSBT returned by `tx_builder_finish` and build the BIP78 request to post to the
+    /// receiver's endpoint. The request body doubles as the fallback transaction.
+    pub fn payjoin_sender_request(
+        wallet_id: String,
+        uri: String,
+        psbt_str: String,
+        params: PayjoinParams,
+        sign_options: Option<SignOptions>,
+    ) -> anyhow::Result<PayjoinRequest> {
+        match Self::payjoin_sender(wallet_id, uri, psbt_str, params, sign_options) {
+            Ok(e) => Ok(e.request()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Sign the PSBT returned by `tx_builder_finish`, post it to the receiver's endpoint over
+    /// HTTPS (or to an onion service) and return the checked and signed PayJoin transaction.
+    pub fn payjoin_send(
+        wallet_id: String,
+        uri: String,
+        psbt_str: String,
+        params: PayjoinParams,
+        sign_options: Option<SignOptions>,
+    ) -> anyhow::Result<String> {
+        let sender = Self::payjoin_sender(
+            wallet_id.clone(),
+            uri,
+            psbt_str,
+            params,
+            sign_options.clone(),
+        )?;
+        match sender.send(
+            &HttpTransport,
+            Wallet::retrieve_wallet(wallet_id).deref(),
+            sign_options,
+        ) {
+            Ok(e) => Ok(e.to_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    fn payjoin_sender(
+        wallet_id: String,
+        uri: String,
+        psbt_str: String,
+        params: PayjoinParams,
+        sign_options: Option<SignOptions>,
+    ) -> anyhow::Result<PayjoinSender> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let psbt = match psbt.internal.into_inner() {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let sender = PayjoinUri::new(uri).and_then(|uri| {
+            PayjoinSender::new(
+                Wallet::retrieve_wallet(wallet_id).deref(),
+                uri,
+                psbt,
+                params,
+                sign_options,
+            )
+        });
+        match sender {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Check the receiver's proposal against the original PSBT and sign it.
+    pub fn payjoin_sender_process_response(
+        wallet_id: String,
+        uri: String,
+        original_psbt: String,
+        params: PayjoinParams,
+        response: String,
+        sign_options: Option<SignOptions>,
+    ) -> anyhow::Result<String> {
+        let original = match PartiallySignedTransaction::new(original_psbt) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let original = match original.internal.into_inner() {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let proposal = PayjoinUri::new(uri)
+            .and_then(|uri| PayjoinSender::from_original(uri, original, params))
+            .and_then(|sender| {
+                sender.process_response(
+                    Wallet::retrieve_wallet(wallet_id).deref(),
+                    response,
+                    sign_options,
+                )
+            });
+        match proposal {
+            Ok(e) => Ok(e.to_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Contribute one of the wallet's inputs to the sender's original PSBT and return the
+    /// PayJoin proposal. `query` is the query string the sender's request was posted with.
+    pub fn payjoin_receive(
+        wallet_id: String,
+        original_psbt: String,
+        query: String,
+    ) -> anyhow::Result<String> {
+        match payjoin_receive(
+            Wallet::retrieve_wallet(wallet_id).deref(),
+            original_psbt,
+            query,
+        ) {
+            Ok(e) => Ok(e.to_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+
+    //================Uniform Resources=========
+    /// Encode a PSBT as animated QR parts (`ur:crypto-psbt`), `count` parts (at most 10 000) from
+    /// `start_seq_num` (1 based). A PSBT fitting in one fragment is returned as a single part.
+    pub fn psbt_to_ur(
+        psbt_str: String,
+        max_fragment_length: u32,
+        start_seq_num: u32,
+        count: u32,
+    ) -> anyhow::Result<Vec<String>> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match ur::encode_psbt(&psbt, max_fragment_length as usize, start_seq_num, count) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Encode a descriptor as a `ur:crypto-output`.
+    pub fn descriptor_to_ur(descriptor: String, network: Network) -> anyhow::Result<String> {
+        let descriptor = match BdkDescriptor::new(descriptor, network.into()) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match ur::encode_output(&descriptor) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Encode the descriptors of an account as a `ur:crypto-account`.
+    pub fn descriptors_to_ur_account(
+        descriptors: Vec<String>,
+        network: Network,
+    ) -> anyhow::Result<String> {
+        let network = network.into();
+        let mut bdk_descriptors = Vec::new();
+        for descriptor in descriptors {
+            match BdkDescriptor::new(descriptor, network) {
+                Ok(e) => bdk_descriptors.push(e),
+                Err(e) => anyhow::bail!("{:?}", e),
+            }
+        }
+        match ur::encode_account(&bdk_descriptors) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Create a decoder for scanned UR parts and return its id.
+    pub fn create_ur_decoder(network: Network) -> String {
+        UrDecoder::new(network.into())
+    }
+    pub fn ur_decoder_receive(
+        decoder_id: String,
+        part: String,
+    ) -> anyhow::Result<UrDecodeProgress> {
+        match UrDecoder::retrieve_decoder(decoder_id).receive(&part) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn drop_ur_decoder(decoder_id: String) {
+        UrDecoder::remove_decoder(decoder_id)
+    }
+
+    //================Multisig=========
+    /// Start collecting cosigner signatures on a PSBT spending from a multisig `descriptor` and
+    /// return the session id.
+    pub fn create_multisig_session(
+        descriptor: String,
+        change_descriptor: Option<String>,
+        network: Network,
+        psbt_str: String,
+    ) -> anyhow::Result<String> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match MultisigSession::new(descriptor, change_descriptor, network.into(), &psbt) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Restore a session saved with `export_multisig_session` and return its id.
+    pub fn restore_multisig_session(data: String) -> anyhow::Result<String> {
+        match MultisigSession::restore(&data) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn export_multisig_session(session_id: String) -> anyhow::Result<String> {
+        match MultisigSession::retrieve_session(session_id).export() {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Merge a copy of the session PSBT signed by a cosigner.
+    pub fn multisig_session_add_psbt(
+        session_id: String,
+        psbt_str: String,
+    ) -> anyhow::Result<MultisigStatus> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match MultisigSession::retrieve_session(session_id).add_psbt(psbt) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn multisig_session_status(session_id: String) -> anyhow::Result<MultisigStatus> {
+        match MultisigSession::retrieve_session(session_id).status() {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Finalize the session PSBT, failing if an input doesn't have enough signatures yet.
+    pub fn finalize_multisig_session(session_id: String) -> anyhow::Result<FinalizedPsbt> {
+        match MultisigSession::retrieve_session(session_id).finalize() {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn drop_multisig_session(session_id: String) {
+        MultisigSession::remove_session(session_id)
+    }
+
+    //================BSMS=========
+    /// Generate the token a BSMS coordinator shares with the signers, `00` without encryption.
+    pub fn bsms_new_token(encryption: BsmsEncryption) -> String {
+        bsms::new_token(encryption)
+    }
+    /// Create the key record of a BSMS signer for its account secret key, e.g.
+    /// `[fingerprint/48'/0'/0'/2']xprv...`.
+    pub fn bsms_key_record(
+        secret_key: String,
+        token: String,
+        description: String,
+    ) -> anyhow::Result<String> {
+        let secret_key = match DescriptorSecretKey::from_string(secret_key) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match bsms::key_record(&secret_key, &token, &description) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
         }
-        if let Some(n_sequence) = n_sequence {
-            tx_builder.enable_rbf_with_sequence(bdk::bitcoin::Sequence(n_sequence));
+    }
+    /// Decrypt and check the key record of a signer as the BSMS coordinator.
+    pub fn bsms_verify_key_record(record: String, token: String) -> anyhow::Result<BsmsKeyRecord> {
+        match bsms::verify_key_record(&record, &token) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
         }
-        if enable_rbf {
-            tx_builder.enable_rbf();
+    }
+    /// Create the descriptor record of a `sortedmulti` wallet from the keys of the verified key
+    /// records.
+    pub fn bsms_descriptor_record(
+        threshold: u32,
+        keys: Vec<String>,
+        script_type: MultisigScriptType,
+        token: String,
+        network: Network,
+    ) -> anyhow::Result<String> {
+        match bsms::descriptor_record(threshold, keys, script_type, &token, network.into()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
         }
-        return match tx_builder.finish() {
-            Ok(e) => Ok(BdkTxBuilderResult(
-                Arc::new(PartiallySignedTransaction {
-                    internal: Mutex::new(e.0),
-                })
-                .serialize(),
-                TransactionDetails::from(&e.1),
-            )),
+    }
+    /// Decrypt and check the descriptor record as a BSMS signer: it must have the signer key and
+    /// the first address must match. Returns the descriptors to create the wallet with.
+    pub fn bsms_verify_descriptor_record(
+        record: String,
+        token: String,
+        secret_key: String,
+        network: Network,
+    ) -> anyhow::Result<BsmsDescriptorRecord> {
+        let secret_key = match DescriptorSecretKey::from_string(secret_key) {
+            Ok(e) => e,
             Err(e) => anyhow::bail!("{:?}", e),
         };
+        match bsms::verify_descriptor_record(&record, &token, &secret_key, network.into()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
 
     //================Descriptor=========
@@ -309,10 +955,12 @@ impl Api {
     ) -> anyhow::Result<String> {
         let key = match DescriptorSecretKey::from_string(secret_key) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
-        let descriptor = BdkDescriptor::new_bip44(key, key_chain_kind.into(), network.into());
-        Ok(descriptor.as_string_private())
+        match BdkDescriptor::new_bip44(key, key_chain_kind.into(), network.into()) {
+            Ok(e) => Ok(e.as_string_private()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn new_bip44_public(
         key_chain_kind: KeychainKind,
@@ -322,15 +970,17 @@ impl Api {
     ) -> anyhow::Result<String> {
         let key = match DescriptorPublicKey::from_string(public_key) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
-        let descriptor = BdkDescriptor::new_bip44_public(
+        match BdkDescriptor::new_bip44_public(
             key,
             fingerprint,
             key_chain_kind.into(),
             network.into(),
-        );
-        Ok(descriptor.as_string())
+        ) {
+            Ok(e) => Ok(e.as_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn new_bip49_descriptor(
         key_chain_kind: KeychainKind,
@@ -339,10 +989,12 @@ impl Api {
     ) -> anyhow::Result<String> {
         let key = match DescriptorSecretKey::from_string(secret_key) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
-        let descriptor = BdkDescriptor::new_bip49(key, key_chain_kind.into(), network.into());
-        Ok(descriptor.as_string_private())
+        match BdkDescriptor::new_bip49(key, key_chain_kind.into(), network.into()) {
+            Ok(e) => Ok(e.as_string_private()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn new_bip49_public(
         key_chain_kind: KeychainKind,
@@ -352,15 +1004,17 @@ impl Api {
     ) -> anyhow::Result<String> {
         let key = match DescriptorPublicKey::from_string(public_key) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
-        let descriptor = BdkDescriptor::new_bip49_public(
+        match BdkDescriptor::new_bip49_public(
             key,
             fingerprint,
             key_chain_kind.into(),
             network.into(),
-        );
-        Ok(descriptor.as_string())
+        ) {
+            Ok(e) => Ok(e.as_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn new_bip84_descriptor(
         key_chain_kind: KeychainKind,
@@ -369,10 +1023,12 @@ impl Api {
     ) -> anyhow::Result<String> {
         let key = match DescriptorSecretKey::from_string(secret_key) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
-        let descriptor = BdkDescriptor::new_bip84(key, key_chain_kind.into(), network.into());
-        Ok(descriptor.as_string_private())
+        match BdkDescriptor::new_bip84(key, key_chain_kind.into(), network.into()) {
+            Ok(e) => Ok(e.as_string_private()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn new_bip84_public(
         key_chain_kind: KeychainKind,
@@ -382,15 +1038,49 @@ impl Api {
     ) -> anyhow::Result<String> {
         let key = match DescriptorPublicKey::from_string(public_key) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
-        let descriptor = BdkDescriptor::new_bip84_public(
+        match BdkDescriptor::new_bip84_public(
             key,
             fingerprint,
             key_chain_kind.into(),
             network.into(),
-        );
-        Ok(descriptor.as_string())
+        ) {
+            Ok(e) => Ok(e.as_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Build a `pkh()`, `sh(wpkh())`, `wpkh()` or `tr()` descriptor for a single secret key, given
+    /// as WIF or as 32 hex encoded bytes, such as an imported paper wallet key.
+    pub fn new_single_key_descriptor(
+        script_type: DescriptorType,
+        secret_key: String,
+        network: Network,
+    ) -> anyhow::Result<String> {
+        let key = match DescriptorSecretKey::from_single(secret_key, network.clone().into()) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match BdkDescriptor::new_single_key(key, script_type, network.into()) {
+            Ok(e) => Ok(e.as_string_private()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Build a watch-only `pkh()`, `sh(wpkh())`, `wpkh()` or `tr()` descriptor for a single public
+    /// key, given as hex.
+    pub fn new_single_key_public(
+        script_type: DescriptorType,
+        public_key: String,
+        network: Network,
+    ) -> anyhow::Result<String> {
+        let key = match DescriptorPublicKey::from_string(public_key) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match BdkDescriptor::new_single_key_public(key, script_type, network.into()) {
+            Ok(e) => Ok(e.as_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
     }
     pub fn as_string_private(descriptor: String, network: Network) -> String {
         let descriptor = BdkDescriptor::new(descriptor, network.into());
@@ -415,6 +1105,97 @@ impl Api {
             Err(e) => panic!("{:?}", e),
         }
     }
+    /// Build the external and change descriptors of a `threshold`-of-n multisig wallet from the
+    /// account-level keys of the cosigners, and optionally our own secret key.
+    pub fn new_multisig_descriptor(
+        threshold: u32,
+        public_keys: Vec<String>,
+        secret_key: Option<String>,
+        script_type: MultisigScriptType,
+        sorted: bool,
+        network: Network,
+    ) -> anyhow::Result<MultisigDescriptors> {
+        let network = network.into();
+        let mut keys = Vec::new();
+        for public_key in public_keys {
+            match DescriptorPublicKey::from_string(public_key) {
+                Ok(e) => keys.push(e),
+                Err(e) => anyhow::bail!("{:?}", e),
+            }
+        }
+        let secret_key = match secret_key.map(DescriptorSecretKey::from_string) {
+            Some(Ok(e)) => Some(e),
+            Some(Err(e)) => anyhow::bail!("{:?}", e),
+            None => None,
+        };
+        let mut descriptors = Vec::new();
+        for keychain_kind in [bdk::KeychainKind::External, bdk::KeychainKind::Internal] {
+            match BdkDescriptor::new_multisig(
+                threshold,
+                keys.clone(),
+                secret_key.clone(),
+                script_type,
+                sorted,
+                keychain_kind,
+                network,
+            ) {
+                Ok(e) => descriptors.push(e.as_string_private()),
+                Err(e) => anyhow::bail!("{:?}", e),
+            }
+        }
+        Ok(MultisigDescriptors {
+            change_descriptor: descriptors.pop().unwrap(),
+            descriptor: descriptors.pop().unwrap(),
+        })
+    }
+    /// Compile a spending policy with named keys, e.g. `or(pk(A),and(pk(B),older(4320)))`, into a
+    /// `wsh` or `tr` descriptor.
+    pub fn compile_policy(
+        policy: String,
+        keys: Vec<PolicyKey>,
+        context: PolicyContext,
+        network: Network,
+    ) -> anyhow::Result<CompiledPolicy> {
+        match compile_policy(&policy, keys, context, network.into()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Derive `count` addresses of a descriptor starting at index `start`, without creating a
+    /// wallet. At most 10,000 addresses can be derived at once.
+    pub fn derive_addresses(
+        descriptor: String,
+        network: Network,
+        start: u32,
+        count: u32,
+    ) -> anyhow::Result<Vec<DerivedAddress>> {
+        let network = network.into();
+        let descriptor = match BdkDescriptor::new(descriptor, network) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match descriptor.derive_addresses(network, start, count) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Describe the type, keys and threshold of a descriptor, which may be a BIP389 multipath
+    /// descriptor. The network is inferred from the keys.
+    pub fn descriptor_info(descriptor: String) -> anyhow::Result<DescriptorInfo> {
+        match describe(&descriptor) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Read the wallets of a Coldcard, Specter, Sparrow, Electrum or BlueWallet export, with the
+    /// descriptors to pass to `create_wallet`. Coldcard generic JSON exports hold one wallet per
+    /// script type.
+    pub fn import_wallet(data: String, network: Network) -> anyhow::Result<Vec<ImportedWallet>> {
+        match import::import_wallet(&data, network.into()) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
     //====================== Descriptor Secret =================
     pub fn create_descriptor_secret(
         network: Network,
@@ -433,13 +1214,23 @@ impl Api {
             Err(e) => anyhow::bail!("{:?}", e),
         };
     }
-    pub fn extend_descriptor_secret(secret: String, path: String) -> String {
-        let res = Self::descriptor_secret_config(secret, Some(path), false);
-        res.as_string()
+    /// Parse a single secret key, given as WIF or as 32 hex encoded bytes.
+    pub fn descriptor_secret_from_single(
+        secret: String,
+        network: Network,
+    ) -> anyhow::Result<String> {
+        match DescriptorSecretKey::from_single(secret, network.into()) {
+            Ok(e) => Ok(e.as_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn extend_descriptor_secret(secret: String, path: String) -> anyhow::Result<String> {
+        let res = Self::descriptor_secret_config(secret, Some(path), false)?;
+        Ok(res.as_string())
     }
-    pub fn derive_descriptor_secret(secret: String, path: String) -> String {
-        let res = Self::descriptor_secret_config(secret, Some(path), true);
-        res.as_string()
+    pub fn derive_descriptor_secret(secret: String, path: String) -> anyhow::Result<String> {
+        let res = Self::descriptor_secret_config(secret, Some(path), true)?;
+        Ok(res.as_string())
     }
     pub fn as_secret_bytes(secret: String) -> anyhow::Result<Vec<u8>> {
         let secret = match BdkDescriptorSecretKey::from_str(secret.as_str()) {
@@ -471,30 +1262,32 @@ impl Api {
         secret: String,
         path: Option<String>,
         derive: bool,
-    ) -> Arc<DescriptorSecretKey> {
+    ) -> anyhow::Result<Arc<DescriptorSecretKey>> {
         let secret = match BdkDescriptorSecretKey::from_str(secret.as_str()) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
         };
         let descriptor_secret = DescriptorSecretKey {
             descriptor_secret_key_mutex: Mutex::new(secret),
         };
 
-        if path.is_none() {
-            return Arc::new(descriptor_secret);
-        }
-        let derivation_path = Arc::new(
-            DerivationPath::new(path.expect("Invalid derivation path").to_string()).unwrap(),
-        );
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(Arc::new(descriptor_secret)),
+        };
+        let derivation_path = match DerivationPath::new(path) {
+            Ok(e) => Arc::new(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
         return if derive {
             match descriptor_secret.derive(derivation_path) {
-                Ok(e) => e,
-                Err(e) => panic!("{:?}", e),
+                Ok(e) => Ok(e),
+                Err(e) => anyhow::bail!("{:?}", e),
             }
         } else {
             match descriptor_secret.extend(derivation_path) {
-                Ok(e) => e,
-                Err(e) => panic!("{:?}", e),
+                Ok(e) => Ok(e),
+                Err(e) => anyhow::bail!("{:?}", e),
             }
         };
     }
@@ -508,6 +1301,24 @@ impl Api {
     }
 
     //================Descriptor Public=========
+    /// Get the fingerprints, origin, extended keys and public key bytes of a descriptor secret key.
+    pub fn descriptor_secret_metadata(secret: String) -> anyhow::Result<KeyMetadata> {
+        let descriptor_secret = match DescriptorSecretKey::from_string(secret) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match descriptor_secret.metadata() {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Get the fingerprints, origin, extended key and public key bytes of a descriptor public key.
+    pub fn descriptor_public_metadata(public_key: String) -> anyhow::Result<KeyMetadata> {
+        match DescriptorPublicKey::from_string(public_key) {
+            Ok(e) => Ok(e.metadata()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
     pub fn descriptor_public_from_string(public_key: String) -> anyhow::Result<String> {
         return match DescriptorPublicKey::from_string(public_key) {
             Ok(e) => Ok(e.as_string()),
@@ -519,8 +1330,15 @@ impl Api {
         path: String,
         derive: bool,
     ) -> anyhow::Result<String> {
-        let derivation_path = Arc::new(DerivationPath::new(path.to_string()).unwrap());
-        let descriptor_public = DescriptorPublicKey::from_string(xpub.unwrap()).unwrap();
+        let derivation_path = match DerivationPath::new(path) {
+            Ok(e) => Arc::new(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let descriptor_public = match xpub.map(DescriptorPublicKey::from_string) {
+            Some(Ok(e)) => e,
+            Some(Err(e)) => anyhow::bail!("{:?}", e),
+            None => anyhow::bail!("No public key to derive from"),
+        };
         return if derive {
             match descriptor_public.clone().derive(derivation_path) {
                 Ok(e) => Ok(e.as_string()),
@@ -534,6 +1352,31 @@ impl Api {
         };
     }
 
+    /// Convert a `ypub`, `zpub`, `Ypub`, `Zpub`, `upub`, `vpub`, `Upub` or `Vpub` key to a standard
+    /// xpub or tpub.
+    pub fn slip132_to_standard(key: String) -> anyhow::Result<String> {
+        match key::slip132_to_standard(&key) {
+            Ok((xpub, _)) => Ok(xpub.to_string()),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Convert an extended public key of any SLIP-132 version to `version`, keeping its network.
+    pub fn standard_to_slip132(key: String, version: Slip132Version) -> anyhow::Result<String> {
+        match key::slip132_to_standard(&key) {
+            Ok((xpub, _)) => Ok(key::standard_to_slip132(&xpub, version)),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Convert a SLIP-132 key, optionally with an origin such as `[fingerprint/84'/0'/0']zpub...`,
+    /// and give the `sh(wpkh())` or `wpkh()` descriptor, or the `sh(wsh())` or `wsh()` multisig
+    /// script type its version stands for.
+    pub fn slip132_template(key: String) -> anyhow::Result<Slip132Template> {
+        match descriptor::slip132_template(&key) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+
     //============ Script Class===========
     pub fn create_script(raw_output_script: Vec<u8>) -> anyhow::Result<Script> {
         return match Script::new(raw_output_script) {
@@ -575,6 +1418,8 @@ impl Api {
     }
 
     //========Wallet==========
+    /// Create a wallet from its descriptor and change descriptor, or from a single BIP389
+    /// multipath descriptor such as `wpkh(xpub.../<0;1>/*)` with no change descriptor.
     pub fn create_wallet(
         descriptor: String,
         change_descriptor: Option<String>,
@@ -643,31 +1488,152 @@ impl Api {
             Err(e) => anyhow::bail!("{:?}", e),
         }
     }
+    /// Sign the inputs of a PSBT the wallet has keys for. With a `safety_policy`, the PSBT is
+    /// first checked with `check_psbt`, and refused when issues are found and the policy's
+    /// `refuse_unsafe` is set.
     pub fn sign(
         wallet_id: String,
         psbt_str: String,
         sign_options: Option<SignOptions>,
-    ) -> Option<String> {
+        safety_policy: Option<PsbtSafetyPolicy>,
+    ) -> anyhow::Result<Option<String>> {
         let psbt = match PartiallySignedTransaction::new(psbt_str) {
             Ok(e) => e,
-            Err(e) => panic!("{:?}", e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let wallet = Wallet::retrieve_wallet(wallet_id);
+        if let Some(policy) = safety_policy.filter(|x| x.refuse_unsafe) {
+            let report = {
+                let bdk_wallet = wallet.get_wallet();
+                psbt.check_safety(bdk_wallet.network(), &policy, |script| {
+                    bdk_wallet.is_mine(script).unwrap_or(false)
+                })
+            };
+            if !report.is_safe {
+                let issues: Vec<String> = report.issues.iter().map(|x| x.to_string()).collect();
+                anyhow::bail!("Refusing to sign an unsafe PSBT: {}", issues.join("; "));
+            }
+        }
+        let signed = match wallet.sign(&psbt, sign_options.clone()) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let is_multi_sig = sign_options.is_some_and(|x| x.is_multi_sig);
+        if !signed && !is_multi_sig {
+            return Ok(None);
+        }
+        match psbt.serialize() {
+            Ok(e) => Ok(Some(e)),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Check a PSBT for absurd fees, missing UTXO data, unusual sighash types, dust outputs
+    /// and network mismatches before the wallet signs it.
+    pub fn check_psbt(
+        wallet_id: String,
+        psbt_str: String,
+        policy: PsbtSafetyPolicy,
+    ) -> anyhow::Result<PsbtSafetyReport> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        let wallet = Wallet::retrieve_wallet(wallet_id);
+        let bdk_wallet = wallet.get_wallet();
+        Ok(psbt.check_safety(bdk_wallet.network(), &policy, |script| {
+            bdk_wallet.is_mine(script).unwrap_or(false)
+        }))
+    }
+    pub fn max_sendable(
+        wallet_id: String,
+        destination: Script,
+        fee_rate: f32,
+        utxos: Vec<OutPoint>,
+    ) -> anyhow::Result<MaxSendable> {
+        match Wallet::retrieve_wallet(wallet_id).sweep(
+            destination.into(),
+            bdk::FeeRate::from_sat_per_vb(fee_rate),
+            utxos,
+            false,
+        ) {
+            Ok(e) => Ok(e.2),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn sweep(
+        wallet_id: String,
+        destination: Script,
+        fee_rate: f32,
+        utxos: Vec<OutPoint>,
+        enable_rbf: bool,
+    ) -> anyhow::Result<BdkSweepResult> {
+        let (psbt, details, max_sendable) = match Wallet::retrieve_wallet(wallet_id).sweep(
+            destination.into(),
+            bdk::FeeRate::from_sat_per_vb(fee_rate),
+            utxos,
+            enable_rbf,
+        ) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match (PartiallySignedTransaction {
+            internal: Mutex::new(psbt),
+        })
+        .serialize()
+        {
+            Ok(e) => Ok(BdkSweepResult {
+                psbt: e,
+                transaction_details: TransactionDetails::from(&details),
+                max_sendable,
+            }),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    pub fn plan_consolidation(
+        wallet_id: String,
+        fee_rate: f32,
+        future_fee_rate: f32,
+        labels: Vec<UtxoLabel>,
+        max_inputs: Option<u32>,
+    ) -> anyhow::Result<Vec<ConsolidationPsbt>> {
+        match Wallet::retrieve_wallet(wallet_id).plan_consolidation(
+            bdk::FeeRate::from_sat_per_vb(fee_rate),
+            bdk::FeeRate::from_sat_per_vb(future_fee_rate),
+            labels,
+            max_inputs,
+        ) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Finalize the inputs of a PSBT the wallet can satisfy, e.g. once all cosigners' partial
+    /// signatures have been combined.
+    pub fn finalize_psbt(
+        wallet_id: String,
+        psbt_str: String,
+        sign_options: Option<SignOptions>,
+    ) -> anyhow::Result<FinalizedPsbt> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
         };
         match Wallet::retrieve_wallet(wallet_id)
-            .sign(&psbt, sign_options.clone())
-            .unwrap()
+            .finalize_psbt(&psbt, sign_options)
+            .and_then(|e| FinalizedPsbt::new(&psbt, e))
         {
-            true => Some(psbt.serialize()),
-            false => {
-                if let Some(sign_option) = sign_options {
-                    if sign_option.is_multi_sig {
-                        Some(psbt.serialize())
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            }
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
+    /// Describe what a PSBT does to the wallet before signing it.
+    pub fn analyze_psbt(wallet_id: String, psbt_str: String) -> anyhow::Result<PsbtAnalysis> {
+        let psbt = match PartiallySignedTransaction::new(psbt_str) {
+            Ok(e) => e,
+            Err(e) => anyhow::bail!("{:?}", e),
+        };
+        match Wallet::retrieve_wallet(wallet_id).analyze_psbt(&psbt) {
+            Ok(e) => Ok(e),
+            Err(e) => anyhow::bail!("{:?}", e),
         }
     }
     pub fn wallet_network(wallet_id: String) -> Network {
@@ -710,6 +1676,16 @@ impl Api {
             Err(e) => panic!("{:?}", e),
         }
     }
+    /// Get the descriptors of both keychains as a single BIP389 multipath descriptor, e.g.
+    /// `wpkh([fingerprint/84'/0'/0']xpub.../<0;1>/*)`, for exporting the wallet.
+    pub fn get_multipath_descriptor(wallet_id: String) -> anyhow::Result<DescNetwork> {
+        let wallet = Wallet::retrieve_wallet(wallet_id);
+        let network: Network = wallet.get_wallet().network().into();
+        match wallet.get_multipath_descriptor() {
+            Ok(e) => Ok(DescNetwork(e, network)),
+            Err(e) => anyhow::bail!("{:?}", e),
+        }
+    }
     //================== Mnemonic ==========
     pub fn generate_seed_from_word_count(word_count: WordCount) -> String {
         let mnemonic = Mnemonic::new(word_count.into());
@@ -730,3 +1706,174 @@ impl Api {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::psbt::{PartiallySignedTransaction, PsbtSafetyPolicy};
+    use crate::r_api::Api;
+    use crate::types::OutPoint;
+    use crate::wallet::test::{
+        foreign_script, funded_wallet, funding_tx, register_wallet, test_script, wallet_with_txs,
+        TEST_CHANGE_DESCRIPTOR, TEST_DESCRIPTOR,
+    };
+    use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+    use bdk::bitcoin::{OutPoint as BdkOutPoint, Transaction};
+    use bdk::FeeRate;
+    use std::sync::Mutex;
+
+    fn inputs(psbt: String) -> Vec<BdkOutPoint> {
+        PartiallySignedTransaction::new(psbt)
+            .unwrap()
+            .internal
+            .lock()
+            .unwrap()
+            .unsigned_tx
+            .input
+            .iter()
+            .map(|x| x.previous_output)
+            .collect()
+    }
+
+    fn sorted(mut outpoints: Vec<BdkOutPoint>) -> Vec<BdkOutPoint> {
+        outpoints.sort();
+        outpoints
+    }
+
+    #[test]
+    fn test_bump_fee_options() {
+        let funding: Vec<(Transaction, Option<u32>)> = vec![
+            (
+                funding_tx(test_script(TEST_DESCRIPTOR, 0), 100_000),
+                Some(100),
+            ),
+            (
+                funding_tx(test_script(TEST_DESCRIPTOR, 1), 50_000),
+                Some(100),
+            ),
+        ];
+        let first = BdkOutPoint::new(funding[0].0.txid(), 0);
+        let second = OutPoint::from(BdkOutPoint::new(funding[1].0.txid(), 0));
+        let original = {
+            let wallet = wallet_with_txs(TEST_DESCRIPTOR, Some(TEST_CHANGE_DESCRIPTOR), &funding);
+            let wallet = wallet.get_wallet();
+            let mut tx_builder = wallet.build_tx();
+            tx_builder
+                .add_recipient(foreign_script(), 90_000)
+                .add_utxo(first)
+                .unwrap()
+                .manually_selected_only()
+                .fee_rate(FeeRate::from_sat_per_vb(2.0))
+                .enable_rbf();
+            let (mut psbt, _) = tx_builder.finish().unwrap();
+            assert!(wallet.sign(&mut psbt, Default::default()).unwrap());
+            psbt.extract_tx()
+        };
+        let original_fee = 100_000 - original.output.iter().map(|x| x.value).sum::<u64>();
+        let mut txs = funding.clone();
+        txs.push((original.clone(), None));
+        let wallet_id = register_wallet(wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &txs,
+        ));
+        let bump = |fee_rate: Option<f32>,
+                    fee_absolute: Option<u64>,
+                    utxos: Vec<OutPoint>,
+                    unspendable: Vec<OutPoint>,
+                    manually_selected_only: bool| {
+            Api::bump_fee_tx_builder_finish(
+                original.txid().to_string(),
+                fee_rate,
+                fee_absolute,
+                None,
+                utxos,
+                unspendable,
+                manually_selected_only,
+                wallet_id.clone(),
+                true,
+                None,
+            )
+        };
+
+        // An absolute fee is taken from the change output.
+        let res = bump(None, Some(original_fee + 1_000), vec![], vec![], false).unwrap();
+        assert_eq!(res.original_fee, original_fee);
+        assert_eq!(res.fee, original_fee + 1_000);
+        assert!(res.fee_rate > res.original_fee_rate);
+        assert_eq!(inputs(res.psbt), vec![first]);
+        assert!(bump(None, None, vec![], vec![], false).is_err());
+
+        // The manually selected UTXO is added even though the change could pay the fee.
+        let res = bump(Some(5.0), None, vec![second.clone()], vec![], true).unwrap();
+        assert_eq!(
+            sorted(inputs(res.psbt)),
+            sorted(vec![first, (&second).into()])
+        );
+
+        // At 100 sat/vB the change is not enough, and the only other UTXO is unspendable.
+        assert!(bump(Some(100.0), None, vec![], vec![second.clone()], false).is_err());
+        let res = bump(Some(100.0), None, vec![], vec![], false).unwrap();
+        assert_eq!(inputs(res.psbt).len(), 2);
+    }
+
+    #[test]
+    fn test_sign_safety() {
+        let wallet = funded_wallet(&[100_000]);
+        let psbt = {
+            let bdk_wallet = wallet.get_wallet();
+            let mut builder = bdk_wallet.build_tx();
+            builder
+                .add_recipient(foreign_script(), 60_000)
+                .fee_rate(FeeRate::from_sat_per_vb(2.0));
+            builder.finish().unwrap().0
+        };
+        let id = register_wallet(wallet);
+        let encode = |psbt: &BdkPartiallySignedTransaction| {
+            PartiallySignedTransaction {
+                internal: Mutex::new(psbt.clone()),
+            }
+            .serialize()
+            .unwrap()
+        };
+        // The checks are opt-in.
+        assert!(Api::sign(id.clone(), encode(&psbt), None, None)
+            .unwrap()
+            .is_some());
+        let default = PsbtSafetyPolicy::default();
+        assert!(Api::sign(id.clone(), encode(&psbt), None, Some(default))
+            .unwrap()
+            .is_some());
+        let mut tampered = psbt.clone();
+        tampered.inputs[0].witness_utxo.as_mut().unwrap().value = 60_000;
+        let error = Api::sign(
+            id.clone(),
+            encode(&tampered),
+            None,
+            Some(PsbtSafetyPolicy::default()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Refusing to sign an unsafe PSBT: input 0 spends 60000 sats according to its witness \
+             UTXO but 100000 sats according to its previous transaction"
+        );
+        let strict = PsbtSafetyPolicy {
+            max_fee: Some(1),
+            ..PsbtSafetyPolicy::default()
+        };
+        let error = Api::sign(id.clone(), encode(&psbt), None, Some(strict)).unwrap_err();
+        assert!(error.to_string().contains("above the limit of 1 sats"));
+
+        // A policy that doesn't refuse unsafe PSBTs only reports issues through `check_psbt`.
+        let lenient = PsbtSafetyPolicy {
+            max_fee: Some(1),
+            refuse_unsafe: false,
+            ..PsbtSafetyPolicy::default()
+        };
+        assert!(Api::sign(id.clone(), encode(&psbt), None, Some(lenient))
+            .unwrap()
+            .is_some());
+        let report = Api::check_psbt(id, encode(&psbt), PsbtSafetyPolicy::default()).unwrap();
+        assert!(report.is_safe);
+    }
+}
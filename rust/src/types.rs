@@ -5,7 +5,9 @@ use bdk::bitcoin::hashes::hex::ToHex;
 use bdk::bitcoin::locktime::Error;
 use bdk::bitcoin::psbt::Input;
 use bdk::bitcoin::util::address::{Payload as BdkPayload, WitnessVersion as BdkWitnessVersion};
-use bdk::bitcoin::{Address as BdkAddress, OutPoint as BdkOutPoint, Txid};
+use bdk::bitcoin::{
+    Address as BdkAddress, OutPoint as BdkOutPoint, Transaction as BdkTransaction, Txid,
+};
 use bdk::blockchain::Progress as BdkProgress;
 use bdk::{Balance as BdkBalance, Error as BdkError};
 use serde::{Deserialize, Serialize};
@@ -241,6 +243,152 @@ pub enum RbfValue {
 /// transaction details.
 pub struct BdkTxBuilderResult(pub String, pub TransactionDetails);
 
+/// The result after finishing a fee bump. Contains the unsigned replacement PSBT, its transaction
+/// details and the fee paid before and after the bump.
+pub struct BdkBumpFeeTxBuilderResult {
+    pub psbt: String,
+    pub transaction_details: TransactionDetails,
+    /// Absolute fee (sats) paid by the original transaction.
+    pub original_fee: u64,
+    /// Fee rate (sat/vB) paid by the original transaction.
+    pub original_fee_rate: f32,
+    /// Absolute fee (sats) paid by the replacement transaction.
+    pub fee: u64,
+    /// Estimated fee rate (sat/vB) of the replacement transaction once it is signed.
+    pub fee_rate: f32,
+}
+
+/// A user-assigned label for one of the wallet's UTXOs.
+pub struct UtxoLabel {
+    pub outpoint: OutPoint,
+    pub label: String,
+}
+
+/// A PSBT planned by the UTXO consolidation planner, merging coins of a single keychain and label
+/// into one output paying a fresh internal address.
+pub struct ConsolidationPsbt {
+    pub psbt: String,
+    pub transaction_details: TransactionDetails,
+    pub keychain: KeychainKind,
+    pub label: Option<String>,
+    /// The UTXOs merged by this PSBT.
+    pub utxos: Vec<OutPoint>,
+    /// Sats saved compared to spending the same UTXOs individually at the future fee rate.
+    pub estimated_savings: i64,
+}
+
+/// A UTXO left out of a sweep because spending it would cost more than it is worth.
+pub struct ExcludedUtxo {
+    pub outpoint: OutPoint,
+    /// Value of the UTXO (sats).
+    pub value: u64,
+    /// Fee (sats) needed to spend the UTXO at the requested fee rate.
+    pub spend_cost: u64,
+}
+
+/// The maximum amount a wallet can send to a destination in a single transaction.
+pub struct MaxSendable {
+    /// Amount (sats) received by the destination after fees.
+    pub amount: u64,
+    /// Fee (sats) paid by the transaction.
+    pub fee: u64,
+    /// The UTXOs spent.
+    pub utxos: Vec<OutPoint>,
+    /// The UTXOs excluded because they are uneconomical at the requested fee rate.
+    pub excluded: Vec<ExcludedUtxo>,
+}
+
+/// The result of a sweep. Contains the unsigned PSBT, its transaction details and a breakdown of
+/// the amount sent and the coins left behind.
+pub struct BdkSweepResult {
+    pub psbt: String,
+    pub transaction_details: TransactionDetails,
+    pub max_sendable: MaxSendable,
+}
+
+/// The output of the transaction being bumped that is allowed to shrink to pay for the new fee.
+pub enum ShrinkOutput {
+    /// The output paying to this address.
+    Address { address: String },
+    /// The output locked by this script.
+    Script { script: Script },
+    /// The output at this position in the original transaction.
+    Index { index: u32 },
+}
+impl ShrinkOutput {
+    pub(crate) fn script_pubkey(
+        self,
+        tx: &BdkTransaction,
+    ) -> Result<bdk::bitcoin::Script, BdkError> {
+        match self {
+            ShrinkOutput::Address { address } => Address::new(address).map(|e| e.script_pubkey()),
+            ShrinkOutput::Script { script } => Ok(script.into()),
+            ShrinkOutput::Index { index } => tx
+                .output
+                .get(index as usize)
+                .map(|x| x.script_pubkey.clone())
+                .ok_or_else(|| {
+                    BdkError::Generic(format!("Output index {} is out of range", index))
+                }),
+        }
+    }
+}
+
+/// An input of a PSBT, as seen by the wallet analyzing it.
+pub struct PsbtInputAnalysis {
+    pub previous_output: OutPoint,
+    /// Value of the spent output (sats), if the PSBT or the wallet knows it.
+    pub value: Option<u64>,
+    pub is_mine: bool,
+    /// Keychain and derivation index of the spent output, if it belongs to the wallet.
+    pub keychain: Option<KeychainKind>,
+    pub derivation_index: Option<u32>,
+    /// Whether the wallet holds a key able to sign this input.
+    pub can_sign: bool,
+}
+
+/// An output of a PSBT, as seen by the wallet analyzing it.
+pub struct PsbtOutputAnalysis {
+    pub script_pubkey: Script,
+    pub value: u64,
+    pub is_mine: bool,
+    /// Whether the output pays to the internal (change) keychain of the wallet.
+    pub is_change: bool,
+    pub keychain: Option<KeychainKind>,
+    pub derivation_index: Option<u32>,
+}
+
+/// Something about a PSBT the user should know before signing it.
+pub enum PsbtWarning {
+    /// The PSBT carries extended keys for another network than the wallet's.
+    NetworkMismatch { network: Network },
+    /// The input at `index` has no UTXO data, so its value can't be verified.
+    MissingUtxo { index: u32 },
+    /// The output at `index` claims a derivation from the wallet's keys, but its script doesn't
+    /// match what the wallet's descriptors derive.
+    ChangeDerivationMismatch { index: u32 },
+}
+
+/// What a PSBT does to a wallet.
+pub struct PsbtAnalysis {
+    pub inputs: Vec<PsbtInputAnalysis>,
+    pub outputs: Vec<PsbtOutputAnalysis>,
+    /// Sum of the wallet's inputs (sats).
+    pub sent: u64,
+    /// Sum of the outputs paying the wallet (sats).
+    pub received: u64,
+    /// Change in the wallet's balance once the transaction confirms (sats).
+    pub net: i64,
+    /// Fee (sats), if every input has UTXO data.
+    pub fee: Option<u64>,
+    /// Estimated fee rate (sat/vB). Unfinalized inputs are counted at their largest satisfaction
+    /// for the wallet's own inputs, and at the usual size of a single signature spend for foreign
+    /// P2PKH, P2WPKH and P2TR inputs. `None` if the fee is unknown or a foreign input's
+    /// satisfaction can't be estimated.
+    pub fee_rate: Option<f32>,
+    pub warnings: Vec<PsbtWarning>,
+}
+
 ///Types of keychains
 pub enum KeychainKind {
     External,
@@ -0,0 +1,1391 @@
+//! Uniform Resources (BCR-2020-005) for exchanging PSBTs and descriptors with air-gapped signers
+//! over QR codes.
+//!
+//! Large payloads are split with the fountain code of the specification: the first `seq_len`
+//! parts carry one fragment each, the following ones mix several fragments, so a scanner can
+//! complete the message from any large enough subset of an endless animation.
+use crate::descriptor::BdkDescriptor;
+use crate::psbt::PartiallySignedTransaction;
+use bdk::bitcoin::hashes::hex::ToHex;
+use bdk::bitcoin::hashes::{sha256, Hash};
+use bdk::bitcoin::secp256k1::PublicKey;
+use bdk::bitcoin::util::bip32::{ChainCode, ChildNumber, ExtendedPubKey, Fingerprint};
+use bdk::bitcoin::Network;
+use bdk::descriptor::ExtendedDescriptor;
+use bdk::miniscript::descriptor::{DescriptorPublicKey, ShInner, SinglePubKey, Wildcard, WshInner};
+use bdk::miniscript::{Descriptor, ForEachKey, Miniscript, ScriptContext, Terminal};
+use bdk::Error as BdkError;
+use lazy_static::lazy_static;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, RwLock};
+
+const TAG_HDKEY: u64 = 303;
+const TAG_KEYPATH: u64 = 304;
+const TAG_COIN_INFO: u64 = 305;
+const TAG_ECKEY: u64 = 306;
+const TAG_OUTPUT: u64 = 308;
+const TAG_SH: u64 = 400;
+const TAG_WSH: u64 = 401;
+const TAG_PK: u64 = 402;
+const TAG_PKH: u64 = 403;
+const TAG_WPKH: u64 = 404;
+const TAG_MULTI: u64 = 406;
+const TAG_SORTED_MULTI: u64 = 407;
+const TAG_TR: u64 = 409;
+
+pub(crate) const UR_TYPE_PSBT: &str = "crypto-psbt";
+pub(crate) const UR_TYPE_OUTPUT: &str = "crypto-output";
+pub(crate) const UR_TYPE_ACCOUNT: &str = "crypto-account";
+
+/// Fragments are never made shorter than this, however small the requested QR codes.
+const MIN_FRAGMENT_LENGTH: usize = 10;
+/// Longest message a multi-part UR may announce, so that a single scanned part can't make the
+/// decoder allocate without bound.
+const MAX_MESSAGE_LENGTH: usize = 100_000;
+/// Most fragments a multi-part UR may be split into.
+const MAX_SEQ_LEN: usize = 10_000;
+
+lazy_static! {
+    static ref UR_DECODER: RwLock<HashMap<String, Arc<UrDecoder>>> = RwLock::new(HashMap::new());
+}
+
+/// The state of a UR decoder after receiving a part.
+pub struct UrDecodeProgress {
+    /// The type of the UR being decoded, known after the first valid part.
+    pub ur_type: Option<String>,
+    /// Estimated fraction of the message received, from 0 to 1.
+    pub progress: f32,
+    /// The decoded payload, once the message is complete.
+    pub result: Option<UrPayload>,
+}
+
+/// A decoded UR payload.
+pub enum UrPayload {
+    /// A `crypto-psbt`, as base64.
+    Psbt { psbt: String },
+    /// A `crypto-output` descriptor.
+    Output { descriptor: String },
+    /// A `crypto-account`: the master key fingerprint and the descriptors of the account.
+    Account {
+        fingerprint: String,
+        descriptors: Vec<String>,
+    },
+    /// A UR of any other type, as its raw CBOR message.
+    Bytes { ur_type: String, cbor: Vec<u8> },
+}
+
+/// A stateful decoder for single and multi-part URs, accepting parts in any order.
+pub struct UrDecoder {
+    pub decoder_mutex: Mutex<FountainDecoder>,
+    network: Network,
+}
+
+impl UrDecoder {
+    pub fn new(network: Network) -> String {
+        let id = format!("{:016x}", rand::random::<u64>());
+        let decoder = UrDecoder {
+            decoder_mutex: Mutex::new(FountainDecoder::default()),
+            network,
+        };
+        UR_DECODER
+            .write()
+            .unwrap()
+            .insert(id.clone(), Arc::new(decoder));
+        id
+    }
+    pub fn retrieve_decoder(id: String) -> Arc<UrDecoder> {
+        let decoder_lock = UR_DECODER.read().unwrap();
+        decoder_lock.get(id.as_str()).unwrap().clone()
+    }
+    pub fn remove_decoder(id: String) {
+        UR_DECODER.write().unwrap().remove(id.as_str());
+    }
+
+    /// Feed a scanned part to the decoder. Parts already seen and redundant parts are accepted
+    /// and ignored.
+    pub(crate) fn receive(&self, part: &str) -> Result<UrDecodeProgress, BdkError> {
+        let mut decoder = self.decoder_mutex.lock().unwrap();
+        decoder.receive(part)?;
+        let result = match decoder.message() {
+            Some(message) => Some(decode_payload(
+                decoder.ur_type.as_deref().unwrap_or_default(),
+                message,
+                self.network,
+            )?),
+            None => None,
+        };
+        Ok(UrDecodeProgress {
+            ur_type: decoder.ur_type.clone(),
+            progress: decoder.progress(),
+            result,
+        })
+    }
+}
+
+/// Encode a PSBT as `ur:crypto-psbt` parts of at most `max_fragment_length` bytes of payload
+/// each, starting at part `start_seq_num` (1 based). At most `MAX_SEQ_LEN` parts are encoded at
+/// once.
+pub(crate) fn encode_psbt(
+    psbt: &PartiallySignedTransaction,
+    max_fragment_length: usize,
+    start_seq_num: u32,
+    count: u32,
+) -> Result<Vec<String>, BdkError> {
+    if count as usize > MAX_SEQ_LEN {
+        return Err(BdkError::Generic(format!(
+            "At most {} UR parts can be encoded at once",
+            MAX_SEQ_LEN
+        )));
+    }
+    let message = Cbor::Bytes(psbt.to_bytes()?).encode();
+    let encoder = FountainEncoder::new(UR_TYPE_PSBT, message, max_fragment_length)?;
+    let start = start_seq_num.max(1);
+    Ok((start..start.saturating_add(count))
+        .map(|seq_num| encoder.part(seq_num))
+        .collect())
+}
+
+/// Encode a descriptor as a single part `ur:crypto-output`.
+pub(crate) fn encode_output(descriptor: &BdkDescriptor) -> Result<String, BdkError> {
+    let message = output_to_cbor(&descriptor.extended_descriptor)?.encode();
+    Ok(single_part(UR_TYPE_OUTPUT, &message))
+}
+
+/// Encode the descriptors of an account as a single part `ur:crypto-account`. The master key
+/// fingerprint is taken from the key origin of the first descriptor.
+pub(crate) fn encode_account(descriptors: &[BdkDescriptor]) -> Result<String, BdkError> {
+    let fingerprint = descriptors
+        .iter()
+        .find_map(|x| master_fingerprint(&x.extended_descriptor))
+        .ok_or_else(|| BdkError::Generic("The descriptors have no key origin".to_string()))?;
+    let outputs = descriptors
+        .iter()
+        .map(|x| Ok(tagged(TAG_OUTPUT, output_to_cbor(&x.extended_descriptor)?)))
+        .collect::<Result<Vec<_>, BdkError>>()?;
+    let message = Cbor::Map(vec![
+        (
+            Cbor::Unsigned(1),
+            Cbor::Unsigned(fingerprint_to_u64(fingerprint)),
+        ),
+        (Cbor::Unsigned(2), Cbor::Array(outputs)),
+    ])
+    .encode();
+    Ok(single_part(UR_TYPE_ACCOUNT, &message))
+}
+
+fn decode_payload(ur_type: &str, message: &[u8], network: Network) -> Result<UrPayload, BdkError> {
+    let cbor = Cbor::decode(message)?;
+    match ur_type {
+        UR_TYPE_PSBT => {
+            let bytes = cbor.as_bytes().ok_or_else(|| invalid("crypto-psbt"))?;
+            let psbt = PartiallySignedTransaction::from_bytes(bytes)?;
+            Ok(UrPayload::Psbt {
+                psbt: psbt.serialize()?,
+            })
+        }
+        UR_TYPE_OUTPUT => Ok(UrPayload::Output {
+            descriptor: output_from_cbor(cbor.untag(TAG_OUTPUT).unwrap_or(&cbor), network)?,
+        }),
+        UR_TYPE_ACCOUNT => {
+            let fingerprint = cbor
+                .get(1)
+                .and_then(Cbor::as_u64)
+                .ok_or_else(|| invalid("crypto-account"))?;
+            let descriptors = cbor
+                .get(2)
+                .and_then(Cbor::as_array)
+                .ok_or_else(|| invalid("crypto-account"))?
+                .iter()
+                .map(|x| output_from_cbor(x.untag(TAG_OUTPUT).unwrap_or(x), network))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(UrPayload::Account {
+                fingerprint: fingerprint_from_u32(fingerprint)?.to_string(),
+                descriptors,
+            })
+        }
+        _ => Ok(UrPayload::Bytes {
+            ur_type: ur_type.to_string(),
+            cbor: message.to_vec(),
+        }),
+    }
+}
+
+fn output_to_cbor(descriptor: &ExtendedDescriptor) -> Result<Cbor, BdkError> {
+    Ok(match descriptor {
+        Descriptor::Pkh(pkh) => tagged(TAG_PKH, key_to_cbor(pkh.as_inner())?),
+        Descriptor::Wpkh(wpkh) => tagged(TAG_WPKH, key_to_cbor(wpkh.as_inner())?),
+        Descriptor::Sh(sh) => tagged(
+            TAG_SH,
+            match sh.as_inner() {
+                ShInner::Wsh(wsh) => tagged(TAG_WSH, wsh_to_cbor(wsh.as_inner())?),
+                ShInner::Wpkh(wpkh) => tagged(TAG_WPKH, key_to_cbor(wpkh.as_inner())?),
+                ShInner::SortedMulti(multi) => {
+                    multi_to_cbor(TAG_SORTED_MULTI, multi.k, &multi.pks)?
+                }
+                ShInner::Ms(ms) => miniscript_to_cbor(ms)?,
+            },
+        ),
+        Descriptor::Wsh(wsh) => tagged(TAG_WSH, wsh_to_cbor(wsh.as_inner())?),
+        Descriptor::Tr(tr) if tr.taptree().is_none() => {
+            tagged(TAG_TR, key_to_cbor(tr.internal_key())?)
+        }
+        _ => return Err(unsupported(descriptor)),
+    })
+}
+
+fn wsh_to_cbor(wsh: &WshInner<DescriptorPublicKey>) -> Result<Cbor, BdkError> {
+    match wsh {
+        WshInner::SortedMulti(multi) => multi_to_cbor(TAG_SORTED_MULTI, multi.k, &multi.pks),
+        WshInner::Ms(ms) => miniscript_to_cbor(ms),
+    }
+}
+
+fn miniscript_to_cbor<Ctx: ScriptContext>(
+    ms: &Miniscript<DescriptorPublicKey, Ctx>,
+) -> Result<Cbor, BdkError> {
+    match &ms.node {
+        Terminal::Multi(k, keys) => multi_to_cbor(TAG_MULTI, *k, keys),
+        _ => Err(unsupported(ms)),
+    }
+}
+
+fn multi_to_cbor(tag: u64, k: usize, keys: &[DescriptorPublicKey]) -> Result<Cbor, BdkError> {
+    let keys = keys
+        .iter()
+        .map(key_to_cbor)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tagged(
+        tag,
+        Cbor::Map(vec![
+            (Cbor::Unsigned(1), Cbor::Unsigned(k as u64)),
+            (Cbor::Unsigned(2), Cbor::Array(keys)),
+        ]),
+    ))
+}
+
+fn key_to_cbor(key: &DescriptorPublicKey) -> Result<Cbor, BdkError> {
+    match key {
+        DescriptorPublicKey::Single(single) => {
+            let data = match &single.key {
+                SinglePubKey::FullKey(key) => key.to_bytes(),
+                SinglePubKey::XOnly(key) => key.serialize().to_vec(),
+            };
+            Ok(tagged(
+                TAG_ECKEY,
+                Cbor::Map(vec![(Cbor::Unsigned(3), Cbor::Bytes(data))]),
+            ))
+        }
+        DescriptorPublicKey::XPub(xpub) => {
+            let xkey = &xpub.xkey;
+            let network = match xkey.network {
+                Network::Bitcoin => 0,
+                _ => 1,
+            };
+            let mut map = vec![
+                (
+                    Cbor::Unsigned(3),
+                    Cbor::Bytes(xkey.public_key.serialize().to_vec()),
+                ),
+                (
+                    Cbor::Unsigned(4),
+                    Cbor::Bytes(xkey.chain_code.to_bytes().to_vec()),
+                ),
+                (
+                    Cbor::Unsigned(5),
+                    tagged(
+                        TAG_COIN_INFO,
+                        Cbor::Map(vec![(Cbor::Unsigned(2), Cbor::Unsigned(network))]),
+                    ),
+                ),
+            ];
+            let origin = match &xpub.origin {
+                Some((fingerprint, path)) => Some((*fingerprint, path.as_ref())),
+                None if xkey.depth == 0 => Some((xkey.fingerprint(), &[][..])),
+                None => None,
+            };
+            if let Some((fingerprint, path)) = origin {
+                map.push((
+                    Cbor::Unsigned(6),
+                    keypath_to_cbor(path, None, Some(fingerprint), Some(xkey.depth)),
+                ));
+            }
+            let wildcard = match xpub.wildcard {
+                Wildcard::None => None,
+                Wildcard::Unhardened => Some(false),
+                Wildcard::Hardened => Some(true),
+            };
+            if !xpub.derivation_path.as_ref().is_empty() || wildcard.is_some() {
+                map.push((
+                    Cbor::Unsigned(7),
+                    keypath_to_cbor(xpub.derivation_path.as_ref(), wildcard, None, None),
+                ));
+            }
+            map.push((
+                Cbor::Unsigned(8),
+                Cbor::Unsigned(fingerprint_to_u64(xkey.parent_fingerprint)),
+            ));
+            Ok(tagged(TAG_HDKEY, Cbor::Map(map)))
+        }
+    }
+}
+
+/// Encode a `crypto-keypath`. `wildcard` appends a final `*` component, hardened if `true`.
+fn keypath_to_cbor(
+    path: &[ChildNumber],
+    wildcard: Option<bool>,
+    fingerprint: Option<Fingerprint>,
+    depth: Option<u8>,
+) -> Cbor {
+    let mut components = Vec::new();
+    for child in path {
+        let (index, hardened) = match child {
+            ChildNumber::Normal { index } => (*index, false),
+            ChildNumber::Hardened { index } => (*index, true),
+        };
+        components.push(Cbor::Unsigned(index as u64));
+        components.push(Cbor::Bool(hardened));
+    }
+    if let Some(hardened) = wildcard {
+        components.push(Cbor::Array(vec![]));
+        components.push(Cbor::Bool(hardened));
+    }
+    let mut map = vec![(Cbor::Unsigned(1), Cbor::Array(components))];
+    if let Some(fingerprint) = fingerprint {
+        map.push((
+            Cbor::Unsigned(2),
+            Cbor::Unsigned(fingerprint_to_u64(fingerprint)),
+        ));
+    }
+    if let Some(depth) = depth {
+        map.push((Cbor::Unsigned(3), Cbor::Unsigned(depth as u64)));
+    }
+    tagged(TAG_KEYPATH, Cbor::Map(map))
+}
+
+/// Rebuild the descriptor string of a `crypto-output` script expression.
+fn output_from_cbor(expression: &Cbor, network: Network) -> Result<String, BdkError> {
+    let descriptor = match expression {
+        Cbor::Tag(TAG_SH, inner) => format!("sh({})", output_from_cbor(inner, network)?),
+        Cbor::Tag(TAG_WSH, inner) => format!("wsh({})", output_from_cbor(inner, network)?),
+        Cbor::Tag(TAG_PK, key) => format!("pk({})", key_from_cbor(key, network)?),
+        Cbor::Tag(TAG_PKH, key) => format!("pkh({})", key_from_cbor(key, network)?),
+        Cbor::Tag(TAG_WPKH, key) => format!("wpkh({})", key_from_cbor(key, network)?),
+        Cbor::Tag(TAG_TR, key) => format!("tr({})", key_from_cbor(key, network)?),
+        Cbor::Tag(tag @ (TAG_MULTI | TAG_SORTED_MULTI), multi) => {
+            let k = multi
+                .get(1)
+                .and_then(Cbor::as_u64)
+                .ok_or_else(|| invalid("multikey"))?;
+            let keys = multi
+                .get(2)
+                .and_then(Cbor::as_array)
+                .ok_or_else(|| invalid("multikey"))?
+                .iter()
+                .map(|key| key_from_cbor(key, network))
+                .collect::<Result<Vec<_>, _>>()?;
+            let name = match *tag {
+                TAG_MULTI => "multi",
+                _ => "sortedmulti",
+            };
+            format!("{}({},{})", name, k, keys.join(","))
+        }
+        _ => {
+            return Err(BdkError::Generic(
+                "Unsupported crypto-output script expression".to_string(),
+            ))
+        }
+    };
+    BdkDescriptor::new(descriptor, network).map(|x| x.as_string())
+}
+
+fn key_from_cbor(key: &Cbor, network: Network) -> Result<String, BdkError> {
+    if let Some(map) = key.untag(TAG_ECKEY) {
+        if map.get(2).and_then(Cbor::as_bool) == Some(true) {
+            return Err(BdkError::Generic(
+                "Private keys are not accepted over UR".to_string(),
+            ));
+        }
+        let data = map
+            .get(3)
+            .and_then(Cbor::as_bytes)
+            .ok_or_else(|| invalid("crypto-eckey"))?;
+        return Ok(data.to_hex());
+    }
+    let map = key.untag(TAG_HDKEY).ok_or_else(|| invalid("key"))?;
+    if map.get(2).and_then(Cbor::as_bool) == Some(true) {
+        return Err(BdkError::Generic(
+            "Private keys are not accepted over UR".to_string(),
+        ));
+    }
+    let key_data = map
+        .get(3)
+        .and_then(Cbor::as_bytes)
+        .filter(|x| x.len() == 33)
+        .ok_or_else(|| invalid("crypto-hdkey key data"))?;
+    let chain_code = map
+        .get(4)
+        .and_then(Cbor::as_bytes)
+        .filter(|x| x.len() == 32)
+        .ok_or_else(|| invalid("crypto-hdkey chain code"))?;
+    let network = match map
+        .get(5)
+        .and_then(|x| x.untag(TAG_COIN_INFO))
+        .and_then(|x| x.get(2))
+        .and_then(Cbor::as_u64)
+    {
+        Some(0) => Network::Bitcoin,
+        Some(_) => Network::Testnet,
+        None => network,
+    };
+    let origin = match map.get(6) {
+        Some(x) => Some(keypath_from_cbor(x)?),
+        None => None,
+    };
+    let children = match map.get(7) {
+        Some(x) => Some(keypath_from_cbor(x)?),
+        None => None,
+    };
+    let parent_fingerprint = match map.get(8).and_then(Cbor::as_u64) {
+        Some(x) => fingerprint_from_u32(x)?,
+        None => Fingerprint::default(),
+    };
+    let origin_path = origin
+        .as_ref()
+        .map(|x| x.components.clone())
+        .unwrap_or_default();
+    let xpub = ExtendedPubKey {
+        network,
+        depth: origin
+            .as_ref()
+            .and_then(|x| x.depth)
+            .unwrap_or(origin_path.len() as u8),
+        parent_fingerprint,
+        child_number: origin_path
+            .last()
+            .copied()
+            .unwrap_or(ChildNumber::Normal { index: 0 }),
+        public_key: PublicKey::from_slice(key_data)
+            .map_err(|e| BdkError::Generic(e.to_string()))?,
+        chain_code: ChainCode::from(chain_code),
+    };
+
+    let mut key = String::new();
+    if let Some(fingerprint) = origin.as_ref().and_then(|x| x.fingerprint) {
+        key.push_str(&format!("[{}", fingerprint));
+        for child in origin_path.iter() {
+            key.push_str(&format!("/{}", format_child(child)));
+        }
+        key.push(']');
+    }
+    key.push_str(&xpub.to_string());
+    if let Some(children) = children {
+        for child in children.components.iter() {
+            key.push_str(&format!("/{}", format_child(child)));
+        }
+        match children.wildcard {
+            Some(true) => key.push_str("/*h"),
+            Some(false) => key.push_str("/*"),
+            None => {}
+        }
+    }
+    Ok(key)
+}
+
+struct Keypath {
+    components: Vec<ChildNumber>,
+    /// A trailing `*` component, hardened if `true`.
+    wildcard: Option<bool>,
+    fingerprint: Option<Fingerprint>,
+    depth: Option<u8>,
+}
+
+fn keypath_from_cbor(keypath: &Cbor) -> Result<Keypath, BdkError> {
+    let map = keypath
+        .untag(TAG_KEYPATH)
+        .ok_or_else(|| invalid("crypto-keypath"))?;
+    let items = map
+        .get(1)
+        .and_then(Cbor::as_array)
+        .ok_or_else(|| invalid("crypto-keypath"))?;
+    let mut components = Vec::new();
+    let mut wildcard = None;
+    for pair in items.chunks(2) {
+        let hardened = pair
+            .get(1)
+            .and_then(Cbor::as_bool)
+            .ok_or_else(|| invalid("crypto-keypath"))?;
+        if wildcard.is_some() {
+            return Err(invalid("crypto-keypath"));
+        }
+        match &pair[0] {
+            Cbor::Unsigned(index) if *index < (1 << 31) => {
+                let index = *index as u32;
+                components.push(match hardened {
+                    true => ChildNumber::Hardened { index },
+                    false => ChildNumber::Normal { index },
+                });
+            }
+            Cbor::Array(x) if x.is_empty() => wildcard = Some(hardened),
+            _ => return Err(invalid("crypto-keypath")),
+        }
+    }
+    let fingerprint = match map.get(2).and_then(Cbor::as_u64) {
+        Some(x) => Some(fingerprint_from_u32(x)?),
+        None => None,
+    };
+    Ok(Keypath {
+        components,
+        wildcard,
+        fingerprint,
+        depth: map
+            .get(3)
+            .and_then(Cbor::as_u64)
+            .map(|x| x.min(u8::MAX as u64) as u8),
+    })
+}
+
+fn format_child(child: &ChildNumber) -> String {
+    match child {
+        ChildNumber::Normal { index } => index.to_string(),
+        ChildNumber::Hardened { index } => format!("{}h", index),
+    }
+}
+
+fn master_fingerprint(descriptor: &ExtendedDescriptor) -> Option<Fingerprint> {
+    let mut fingerprint = None;
+    descriptor.for_each_key(|key| {
+        if let DescriptorPublicKey::XPub(xpub) = key {
+            fingerprint = xpub.origin.as_ref().map(|(x, _)| *x);
+        }
+        fingerprint.is_none()
+    });
+    fingerprint
+}
+
+fn fingerprint_to_u64(fingerprint: Fingerprint) -> u64 {
+    u32::from_be_bytes(fingerprint.to_bytes()) as u64
+}
+
+fn fingerprint_from_u32(value: u64) -> Result<Fingerprint, BdkError> {
+    let value = u32::try_from(value).map_err(|_| invalid("fingerprint"))?;
+    Ok(Fingerprint::from(&value.to_be_bytes()[..]))
+}
+
+fn tagged(tag: u64, value: Cbor) -> Cbor {
+    Cbor::Tag(tag, Box::new(value))
+}
+
+fn invalid(what: &str) -> BdkError {
+    BdkError::Generic(format!("Invalid {}", what))
+}
+
+fn unsupported(descriptor: impl std::fmt::Display) -> BdkError {
+    BdkError::Generic(format!(
+        "Descriptor {} can't be encoded as a crypto-output",
+        descriptor
+    ))
+}
+
+fn single_part(ur_type: &str, message: &[u8]) -> String {
+    format!("ur:{}/{}", ur_type, bytewords::encode(message))
+}
+
+/// Splits a CBOR message into fragments and produces the parts of a multi-part UR.
+pub(crate) struct FountainEncoder {
+    ur_type: String,
+    message: Vec<u8>,
+    checksum: u32,
+    fragments: Vec<Vec<u8>>,
+}
+
+impl FountainEncoder {
+    pub(crate) fn new(
+        ur_type: &str,
+        message: Vec<u8>,
+        max_fragment_length: usize,
+    ) -> Result<Self, BdkError> {
+        if message.is_empty() || max_fragment_length == 0 {
+            return Err(BdkError::Generic(
+                "Can't split an empty UR message or into empty fragments".to_string(),
+            ));
+        }
+        let fragment_length =
+            nominal_fragment_length(message.len(), MIN_FRAGMENT_LENGTH, max_fragment_length);
+        let fragments = message
+            .chunks(fragment_length)
+            .map(|x| {
+                let mut fragment = x.to_vec();
+                fragment.resize(fragment_length, 0);
+                fragment
+            })
+            .collect();
+        Ok(FountainEncoder {
+            ur_type: ur_type.to_string(),
+            checksum: crc32(&message),
+            message,
+            fragments,
+        })
+    }
+
+    pub(crate) fn seq_len(&self) -> usize {
+        self.fragments.len()
+    }
+
+    /// The part with sequence number `seq_num`, starting at 1. A message fitting in one fragment
+    /// is always encoded as a single part UR.
+    pub(crate) fn part(&self, seq_num: u32) -> String {
+        if self.seq_len() == 1 {
+            return single_part(&self.ur_type, &self.message);
+        }
+        let mut data = vec![0; self.fragments[0].len()];
+        for index in choose_fragments(seq_num, self.seq_len(), self.checksum) {
+            xor_into(&mut data, &self.fragments[index]);
+        }
+        let part = Cbor::Array(vec![
+            Cbor::Unsigned(seq_num as u64),
+            Cbor::Unsigned(self.seq_len() as u64),
+            Cbor::Unsigned(self.message.len() as u64),
+            Cbor::Unsigned(self.checksum as u64),
+            Cbor::Bytes(data),
+        ]);
+        format!(
+            "ur:{}/{}-{}/{}",
+            self.ur_type,
+            seq_num,
+            self.seq_len(),
+            bytewords::encode(&part.encode())
+        )
+    }
+}
+
+/// Reassembles a UR from its parts.
+#[derive(Default)]
+pub struct FountainDecoder {
+    ur_type: Option<String>,
+    seq_len: usize,
+    message_length: usize,
+    checksum: u32,
+    fragment_length: usize,
+    /// Fragments recovered so far, by index.
+    fragments: BTreeMap<usize, Vec<u8>>,
+    /// Mixed parts that still combine more than one missing fragment.
+    mixed: Vec<(Vec<usize>, Vec<u8>)>,
+    message: Option<Vec<u8>>,
+}
+
+impl FountainDecoder {
+    pub(crate) fn receive(&mut self, part: &str) -> Result<(), BdkError> {
+        let part = part.trim().to_ascii_lowercase();
+        let path = part
+            .strip_prefix("ur:")
+            .ok_or_else(|| BdkError::Generic("Not a UR".to_string()))?;
+        let components: Vec<&str> = path.split('/').collect();
+        let (ur_type, body) = match components.as_slice() {
+            [ur_type, body] | [ur_type, _, body] => (*ur_type, *body),
+            _ => return Err(invalid("UR")),
+        };
+        if ur_type.is_empty()
+            || !ur_type
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(invalid("UR type"));
+        }
+        if let Some(expected) = &self.ur_type {
+            if expected != ur_type {
+                return Err(BdkError::Generic(format!(
+                    "Expected a ur:{} part, got ur:{}",
+                    expected, ur_type
+                )));
+            }
+        }
+        let data = bytewords::decode(body)?;
+        if components.len() == 2 {
+            self.ur_type = Some(ur_type.to_string());
+            self.message = Some(data);
+            return Ok(());
+        }
+
+        let part = Cbor::decode(&data)?;
+        let fields = part
+            .as_array()
+            .filter(|x| x.len() == 5)
+            .ok_or_else(|| invalid("UR part"))?;
+        let seq_num = fields[0].as_u64().ok_or_else(|| invalid("UR part"))?;
+        let seq_len = fields[1].as_u64().ok_or_else(|| invalid("UR part"))? as usize;
+        let message_length = fields[2].as_u64().ok_or_else(|| invalid("UR part"))? as usize;
+        let checksum = fields[3].as_u64().ok_or_else(|| invalid("UR part"))?;
+        let fragment = fields[4].as_bytes().ok_or_else(|| invalid("UR part"))?;
+        if message_length > MAX_MESSAGE_LENGTH || seq_len > MAX_SEQ_LEN {
+            return Err(BdkError::Generic(format!(
+                "The UR is too large: at most {} bytes in {} fragments are supported",
+                MAX_MESSAGE_LENGTH, MAX_SEQ_LEN
+            )));
+        }
+        // The fragments must be just long enough to hold the message.
+        let fits = |count: usize| {
+            count
+                .checked_mul(fragment.len())
+                .is_some_and(|x| x >= message_length)
+        };
+        if seq_num == 0
+            || seq_num > u32::MAX as u64
+            || checksum > u32::MAX as u64
+            || seq_len == 0
+            || !fits(seq_len)
+            || fits(seq_len - 1)
+            || components[1] != format!("{}-{}", seq_num, seq_len)
+        {
+            return Err(invalid("UR part"));
+        }
+        let checksum = checksum as u32;
+        if self.ur_type.is_none() {
+            self.ur_type = Some(ur_type.to_string());
+            self.seq_len = seq_len;
+            self.message_length = message_length;
+            self.checksum = checksum;
+            self.fragment_length = fragment.len();
+        } else if (
+            self.seq_len,
+            self.message_length,
+            self.checksum,
+            self.fragment_length,
+        ) != (seq_len, message_length, checksum, fragment.len())
+        {
+            return Err(BdkError::Generic(
+                "The part belongs to another UR".to_string(),
+            ));
+        }
+        if self.message.is_some() {
+            return Ok(());
+        }
+
+        let indexes = choose_fragments(seq_num as u32, seq_len, checksum);
+        let mut queue = vec![(indexes, fragment.to_vec())];
+        while let Some((mut indexes, mut data)) = queue.pop() {
+            indexes.retain(|index| match self.fragments.get(index) {
+                Some(known) => {
+                    xor_into(&mut data, known);
+                    false
+                }
+                None => true,
+            });
+            match indexes.len() {
+                0 => {}
+                1 => {
+                    let index = indexes[0];
+                    let mut pending = Vec::new();
+                    for (mixed_indexes, mixed_data) in self.mixed.drain(..) {
+                        if mixed_indexes.contains(&index) {
+                            queue.push((mixed_indexes, mixed_data));
+                        } else {
+                            pending.push((mixed_indexes, mixed_data));
+                        }
+                    }
+                    self.mixed = pending;
+                    self.fragments.insert(index, data);
+                }
+                _ => {
+                    if !self.mixed.iter().any(|(x, _)| x == &indexes) {
+                        self.mixed.push((indexes, data));
+                    }
+                }
+            }
+        }
+
+        if self.fragments.len() == self.seq_len {
+            let mut message: Vec<u8> = self.fragments.values().flatten().copied().collect();
+            message.truncate(self.message_length);
+            if crc32(&message) != self.checksum {
+                *self = FountainDecoder::default();
+                return Err(BdkError::Generic(
+                    "The UR message checksum doesn't match".to_string(),
+                ));
+            }
+            self.message = Some(message);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn progress(&self) -> f32 {
+        match (&self.message, self.seq_len) {
+            (Some(_), _) => 1.0,
+            (None, 0) => 0.0,
+            (None, seq_len) => self.fragments.len() as f32 / seq_len as f32,
+        }
+    }
+
+    pub(crate) fn message(&self) -> Option<&[u8]> {
+        self.message.as_deref()
+    }
+}
+
+fn xor_into(data: &mut [u8], other: &[u8]) {
+    for (a, b) in data.iter_mut().zip(other.iter()) {
+        *a ^= b;
+    }
+}
+
+fn nominal_fragment_length(
+    message_length: usize,
+    min_fragment_length: usize,
+    max_fragment_length: usize,
+) -> usize {
+    let max_fragment_count = (message_length / min_fragment_length).max(1);
+    let mut fragment_length = message_length;
+    for fragment_count in 1..=max_fragment_count {
+        fragment_length = message_length.div_ceil(fragment_count);
+        if fragment_length <= max_fragment_length {
+            break;
+        }
+    }
+    fragment_length
+}
+
+/// The fragments mixed into part `seq_num`: the first `seq_len` parts carry one fragment each,
+/// later ones a pseudo-random set seeded from the sequence number and message checksum.
+fn choose_fragments(seq_num: u32, seq_len: usize, checksum: u32) -> Vec<usize> {
+    if seq_num as usize <= seq_len {
+        return vec![seq_num as usize - 1];
+    }
+    let mut seed = seq_num.to_be_bytes().to_vec();
+    seed.extend(checksum.to_be_bytes());
+    let mut rng = Xoshiro256::new(&seed);
+    let degree = rng.choose_degree(seq_len);
+    let mut remaining: Vec<usize> = (0..seq_len).collect();
+    let mut indexes = Vec::with_capacity(degree);
+    while indexes.len() < degree {
+        let index = rng.next_int(0, remaining.len() as u64 - 1) as usize;
+        indexes.push(remaining.remove(index));
+    }
+    indexes.sort_unstable();
+    indexes
+}
+
+/// The xoshiro256** generator, seeded from the SHA-256 of the seed.
+struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    fn new(seed: &[u8]) -> Self {
+        let digest = sha256::Hash::hash(seed).into_inner();
+        let mut s = [0u64; 4];
+        for (i, chunk) in digest.chunks(8).enumerate() {
+            s[i] = chunk.iter().fold(0, |v, b| (v << 8) | *b as u64);
+        }
+        Xoshiro256 { s }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    fn next_double(&mut self) -> f64 {
+        self.next_u64() as f64 / (u64::MAX as f64 + 1.0)
+    }
+
+    fn next_int(&mut self, low: u64, high: u64) -> u64 {
+        (self.next_double() * (high - low + 1) as f64) as u64 + low
+    }
+
+    /// Pick how many fragments to mix, with probability proportional to 1/degree, using Vose's
+    /// alias method.
+    fn choose_degree(&mut self, seq_len: usize) -> usize {
+        let n = seq_len;
+        let weights: Vec<f64> = (1..=n).map(|i| 1.0 / i as f64).collect();
+        let sum: f64 = weights.iter().sum();
+        let mut p: Vec<f64> = weights.iter().map(|w| w * n as f64 / sum).collect();
+        let (mut small, mut large) = (Vec::new(), Vec::new());
+        for (j, x) in p.iter().enumerate().rev() {
+            if *x < 1.0 {
+                small.push(j);
+            } else {
+                large.push(j);
+            }
+        }
+        let mut probs = vec![0.0; n];
+        let mut aliases = vec![0; n];
+        while let (Some(a), Some(g)) = (small.last().copied(), large.last().copied()) {
+            small.pop();
+            large.pop();
+            probs[a] = p[a];
+            aliases[a] = g;
+            p[g] += p[a] - 1.0;
+            if p[g] < 1.0 {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+        for i in large.into_iter().chain(small) {
+            probs[i] = 1.0;
+        }
+        let r1 = self.next_double();
+        let r2 = self.next_double();
+        let i = (n as f64 * r1) as usize;
+        let degree = if r2 < probs[i] { i } else { aliases[i] };
+        degree + 1
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Minimal bytewords: each byte is written as the first and last letters of its word, followed
+/// by a CRC32 checksum.
+mod bytewords {
+    use super::crc32;
+    use bdk::Error as BdkError;
+
+    const WORDS: [&str; 256] = [
+        "able", "acid", "also", "apex", "aqua", "arch", "atom", "aunt", "away", "axis", "back",
+        "bald", "barn", "belt", "beta", "bias", "blue", "body", "brag", "brew", "bulb", "buzz",
+        "calm", "cash", "cats", "chef", "city", "claw", "code", "cola", "cook", "cost", "crux",
+        "curl", "cusp", "cyan", "dark", "data", "days", "deli", "dice", "diet", "door", "down",
+        "draw", "drop", "drum", "dull", "duty", "each", "easy", "echo", "edge", "epic", "even",
+        "exam", "exit", "eyes", "fact", "fair", "fern", "figs", "film", "fish", "fizz", "flap",
+        "flew", "flux", "foxy", "free", "frog", "fuel", "fund", "gala", "game", "gear", "gems",
+        "gift", "girl", "glow", "good", "gray", "grim", "guru", "gush", "gyro", "half", "hang",
+        "hard", "hawk", "heat", "help", "high", "hill", "holy", "hope", "horn", "huts", "iced",
+        "idea", "idle", "inch", "inky", "into", "iris", "iron", "item", "jade", "jazz", "join",
+        "jolt", "jowl", "judo", "jugs", "jump", "junk", "jury", "keep", "keno", "kept", "keys",
+        "kick", "kiln", "king", "kite", "kiwi", "knob", "lamb", "lava", "lazy", "leaf", "legs",
+        "liar", "limp", "lion", "list", "logo", "loud", "love", "luau", "luck", "lung", "main",
+        "many", "math", "maze", "memo", "menu", "meow", "mild", "mint", "miss", "monk", "nail",
+        "navy", "need", "news", "next", "noon", "note", "numb", "obey", "oboe", "omit", "onyx",
+        "open", "oval", "owls", "paid", "part", "peck", "play", "plus", "poem", "pool", "pose",
+        "puff", "puma", "purr", "quad", "quiz", "race", "ramp", "real", "redo", "rich", "road",
+        "rock", "roof", "ruby", "ruin", "runs", "rust", "safe", "saga", "scar", "sets", "silk",
+        "skew", "slot", "soap", "solo", "song", "stub", "surf", "swan", "taco", "task", "taxi",
+        "tent", "tied", "time", "tiny", "toil", "tomb", "toys", "trip", "tuna", "twin", "ugly",
+        "undo", "unit", "urge", "user", "vast", "very", "veto", "vial", "vibe", "view", "visa",
+        "void", "vows", "wall", "wand", "warm", "wasp", "wave", "waxy", "webs", "what", "when",
+        "whiz", "wolf", "work", "yank", "yawn", "yell", "yoga", "yurt", "zaps", "zero", "zest",
+        "zinc", "zone", "zoom",
+    ];
+
+    pub(super) fn encode(data: &[u8]) -> String {
+        let mut encoded = String::with_capacity((data.len() + 4) * 2);
+        for byte in data.iter().chain(crc32(data).to_be_bytes().iter()) {
+            let word = WORDS[*byte as usize].as_bytes();
+            encoded.push(word[0] as char);
+            encoded.push(word[3] as char);
+        }
+        encoded
+    }
+
+    pub(super) fn decode(encoded: &str) -> Result<Vec<u8>, BdkError> {
+        let invalid = || BdkError::Generic("Invalid bytewords".to_string());
+        let letters = encoded.as_bytes();
+        if !letters.len().is_multiple_of(2) || letters.len() < 10 {
+            return Err(invalid());
+        }
+        let mut bytes = letters
+            .chunks(2)
+            .map(|pair| {
+                WORDS
+                    .iter()
+                    .position(|word| word.as_bytes()[0] == pair[0] && word.as_bytes()[3] == pair[1])
+                    .map(|x| x as u8)
+                    .ok_or_else(invalid)
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        let checksum = bytes.split_off(bytes.len() - 4);
+        if crc32(&bytes).to_be_bytes()[..] != checksum[..] {
+            return Err(BdkError::Generic("Invalid bytewords checksum".to_string()));
+        }
+        Ok(bytes)
+    }
+}
+
+/// The subset of CBOR used by the UR types.
+#[derive(Debug, Clone, PartialEq)]
+enum Cbor {
+    Unsigned(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Cbor>),
+    Map(Vec<(Cbor, Cbor)>),
+    Tag(u64, Box<Cbor>),
+    Bool(bool),
+}
+
+impl Cbor {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write(&mut out);
+        out
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            Cbor::Unsigned(x) => write_header(out, 0, *x),
+            Cbor::Bytes(x) => {
+                write_header(out, 2, x.len() as u64);
+                out.extend(x);
+            }
+            Cbor::Text(x) => {
+                write_header(out, 3, x.len() as u64);
+                out.extend(x.as_bytes());
+            }
+            Cbor::Array(items) => {
+                write_header(out, 4, items.len() as u64);
+                items.iter().for_each(|x| x.write(out));
+            }
+            Cbor::Map(entries) => {
+                write_header(out, 5, entries.len() as u64);
+                for (key, value) in entries {
+                    key.write(out);
+                    value.write(out);
+                }
+            }
+            Cbor::Tag(tag, value) => {
+                write_header(out, 6, *tag);
+                value.write(out);
+            }
+            Cbor::Bool(x) => out.push(if *x { 0xf5 } else { 0xf4 }),
+        }
+    }
+
+    fn decode(data: &[u8]) -> Result<Cbor, BdkError> {
+        let mut position = 0;
+        let value = Cbor::read(data, &mut position, 0)?;
+        if position != data.len() {
+            return Err(BdkError::Generic(
+                "Unexpected trailing data after CBOR item".to_string(),
+            ));
+        }
+        Ok(value)
+    }
+
+    fn read(data: &[u8], position: &mut usize, depth: usize) -> Result<Cbor, BdkError> {
+        if depth > 32 {
+            return Err(invalid("CBOR"));
+        }
+        let initial = take(data, position, 1)?[0];
+        let (major, info) = (initial >> 5, initial & 0x1f);
+        let value = match info {
+            0..=23 => info as u64,
+            24 => take(data, position, 1)?[0] as u64,
+            25..=27 => take(data, position, 1 << (info - 24))?
+                .iter()
+                .fold(0, |v, b| (v << 8) | *b as u64),
+            _ => return Err(invalid("CBOR")),
+        };
+        Ok(match major {
+            0 => Cbor::Unsigned(value),
+            2 => Cbor::Bytes(take(data, position, value)?.to_vec()),
+            3 => Cbor::Text(
+                String::from_utf8(take(data, position, value)?.to_vec())
+                    .map_err(|_| invalid("CBOR"))?,
+            ),
+            4 => Cbor::Array(
+                (0..value)
+                    .map(|_| Cbor::read(data, position, depth + 1))
+                    .collect::<Result<_, _>>()?,
+            ),
+            5 => Cbor::Map(
+                (0..value)
+                    .map(|_| {
+                        Ok((
+                            Cbor::read(data, position, depth + 1)?,
+                            Cbor::read(data, position, depth + 1)?,
+                        ))
+                    })
+                    .collect::<Result<_, BdkError>>()?,
+            ),
+            6 => Cbor::Tag(value, Box::new(Cbor::read(data, position, depth + 1)?)),
+            7 if info == 20 => Cbor::Bool(false),
+            7 if info == 21 => Cbor::Bool(true),
+            _ => return Err(invalid("CBOR")),
+        })
+    }
+
+    fn get(&self, key: u64) -> Option<&Cbor> {
+        match self {
+            Cbor::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| k == &Cbor::Unsigned(key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn untag(&self, tag: u64) -> Option<&Cbor> {
+        match self {
+            Cbor::Tag(t, value) if *t == tag => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_u64(&self) -> Option<u64> {
+        match self {
+            Cbor::Unsigned(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Cbor::Bool(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Cbor::Bytes(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Cbor]> {
+        match self {
+            Cbor::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+fn take<'a>(data: &'a [u8], position: &mut usize, n: u64) -> Result<&'a [u8], BdkError> {
+    let end = usize::try_from(n)
+        .ok()
+        .and_then(|n| position.checked_add(n))
+        .filter(|end| *end <= data.len())
+        .ok_or_else(|| invalid("CBOR"))?;
+    let bytes = &data[*position..end];
+    *position = end;
+    Ok(bytes)
+}
+
+fn write_header(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..=23 => out.push(major | value as u8),
+        24..=0xff => {
+            out.push(major | 24);
+            out.push(value as u8);
+        }
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend((value as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend((value as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend(value.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"Hello, world!"), 0xebe6c6e6);
+        assert_eq!(crc32(b"Wolf"), 0x598c84dc);
+    }
+
+    #[test]
+    fn test_xoshiro256() {
+        let mut rng = Xoshiro256::new(b"Wolf");
+        let numbers: Vec<u64> = (0..12).map(|_| rng.next_u64() % 100).collect();
+        assert_eq!(numbers, vec![42, 81, 85, 8, 82, 84, 76, 73, 70, 88, 2, 74]);
+    }
+
+    /// The message of the reference vectors: `length` pseudo-random bytes from the seed, as a
+    /// CBOR byte string.
+    fn make_message_ur(length: usize, seed: &str) -> Vec<u8> {
+        let mut rng = Xoshiro256::new(seed.as_bytes());
+        let message = (0..length).map(|_| rng.next_int(0, 255) as u8).collect();
+        Cbor::Bytes(message).encode()
+    }
+
+    #[test]
+    fn test_reference_vectors() {
+        // From the reference implementation of BCR-2020-005 and BCR-2020-012.
+        assert_eq!(
+            single_part("bytes", &make_message_ur(50, "Wolf")),
+            "ur:bytes/hdeymejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtgwdpfnsboxgwlbaawzuefywkdplrsrjynbvygabwjldapfcsdwkbrkch"
+        );
+        let encoder = FountainEncoder::new("bytes", make_message_ur(256, "Wolf"), 30).unwrap();
+        assert_eq!(encoder.seq_len(), 9);
+        let expected = [
+            "ur:bytes/1-9/lpadascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtdkgslpgh",
+            "ur:bytes/2-9/lpaoascfadaxcywenbpljkhdcagwdpfnsboxgwlbaawzuefywkdplrsrjynbvygabwjldapfcsgmghhkhstlrdcxaefz",
+            "ur:bytes/3-9/lpaxascfadaxcywenbpljkhdcahelbknlkuejnbadmssfhfrdpsbiegecpasvssovlgeykssjykklronvsjksopdzmol",
+            "ur:bytes/4-9/lpaaascfadaxcywenbpljkhdcasotkhemthydawydtaxneurlkosgwcekonertkbrlwmplssjtammdplolsbrdzcrtas",
+            "ur:bytes/5-9/lpahascfadaxcywenbpljkhdcatbbdfmssrkzmcwnezelennjpfzbgmuktrhtejscktelgfpdlrkfyfwdajldejokbwf",
+            "ur:bytes/6-9/lpamascfadaxcywenbpljkhdcackjlhkhybssklbwefectpfnbbectrljectpavyrolkzczcpkmwidmwoxkilghdsowp",
+            "ur:bytes/7-9/lpatascfadaxcywenbpljkhdcavszmwnjkwtclrtvaynhpahrtoxmwvwatmedibkaegdosftvandiodagdhthtrlnnhy",
+            "ur:bytes/8-9/lpayascfadaxcywenbpljkhdcadmsponkkbbhgsoltjntegepmttmoonftnbuoiyrehfrtsabzsttorodklubbuyaetk",
+            "ur:bytes/9-9/lpasascfadaxcywenbpljkhdcajskecpmdckihdyhphfotjojtfmlnwmadspaxrkytbztpbauotbgtgtaeaevtgavtny",
+            "ur:bytes/10-9/lpbkascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtwdkiplzs",
+            "ur:bytes/11-9/lpbdascfadaxcywenbpljkhdcahelbknlkuejnbadmssfhfrdpsbiegecpasvssovlgeykssjykklronvsjkvetiiapk",
+            "ur:bytes/12-9/lpbnascfadaxcywenbpljkhdcarllaluzmdmgstospeyiefmwejlwtpedamktksrvlcygmzemovovllarodtmtbnptrs",
+            "ur:bytes/13-9/lpbtascfadaxcywenbpljkhdcamtkgtpknghchchyketwsvwgwfdhpgmgtylctotzopdrpayoschcmhplffziachrfgd",
+            "ur:bytes/14-9/lpbaascfadaxcywenbpljkhdcapazewnvonnvdnsbyleynwtnsjkjndeoldydkbkdslgjkbbkortbelomueekgvstegt",
+            "ur:bytes/15-9/lpbsascfadaxcywenbpljkhdcaynmhpddpzmversbdqdfyrehnqzlugmjzmnmtwmrouohtstgsbsahpawkditkckynwt",
+            "ur:bytes/16-9/lpbeascfadaxcywenbpljkhdcawygekobamwtlihsnpalnsghenskkiynthdzotsimtojetprsttmukirlrsbtamjtpd",
+            "ur:bytes/17-9/lpbyascfadaxcywenbpljkhdcamklgftaxykpewyrtqzhydntpnytyisincxmhtbceaykolduortotiaiaiafhiaoyce",
+            "ur:bytes/18-9/lpbgascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtntwkbkwy",
+            "ur:bytes/19-9/lpbwascfadaxcywenbpljkhdcadekicpaajootjzpsdrbalpeywllbdsnbinaerkurspbncxgslgftvtsrjtksplcpeo",
+            "ur:bytes/20-9/lpbbascfadaxcywenbpljkhdcayapmrleeleaxpasfrtrdkncffwjyjzgyetdmlewtkpktgllepfrltataztksmhkbot",
+        ];
+        for (index, part) in expected.iter().enumerate() {
+            assert_eq!(&encoder.part(index as u32 + 1), part);
+        }
+
+        // Without the first fragment, the mixed parts make up for it.
+        let mut decoder = FountainDecoder::default();
+        for part in expected[1..].iter() {
+            decoder.receive(part).unwrap();
+        }
+        assert_eq!(decoder.message(), Some(&make_message_ur(256, "Wolf")[..]));
+    }
+
+    #[test]
+    fn test_encode_psbt_count() {
+        let tx = bdk::bitcoin::Transaction {
+            version: 2,
+            lock_time: bdk::bitcoin::PackedLockTime(0),
+            input: vec![],
+            output: vec![],
+        };
+        let psbt = PartiallySignedTransaction {
+            internal: Mutex::new(
+                bdk::bitcoin::util::psbt::PartiallySignedTransaction::from_unsigned_tx(tx).unwrap(),
+            ),
+        };
+        assert_eq!(encode_psbt(&psbt, 10, 1, 3).unwrap().len(), 3);
+        assert!(encode_psbt(&psbt, 10, 1, MAX_SEQ_LEN as u32 + 1).is_err());
+    }
+
+    #[test]
+    fn test_bytewords() {
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(bytewords::decode(&bytewords::encode(&data)).unwrap(), data);
+        let mut encoded = bytewords::encode(&data);
+        encoded.replace_range(2..4, "ae");
+        assert!(bytewords::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_fountain_round_trip() {
+        let message: Vec<u8> = (0..1000u32).map(|x| (x * 7 % 256) as u8).collect();
+        let encoder = FountainEncoder::new("bytes", message.clone(), 100).unwrap();
+        assert_eq!(encoder.seq_len(), 10);
+
+        // Skip the first half of the pure fragments and rely on the mixed parts.
+        let mut decoder = FountainDecoder::default();
+        let mut seq_num = 6;
+        while decoder.message().is_none() {
+            decoder
+                .receive(&encoder.part(seq_num).to_uppercase())
+                .unwrap();
+            seq_num += 1;
+            assert!(seq_num < 100);
+        }
+        assert_eq!(decoder.message().unwrap(), &message[..]);
+        assert_eq!(decoder.progress(), 1.0);
+    }
+
+    /// A part of a multi-part UR announcing the given sizes, with a zeroed fragment.
+    fn raw_part(seq_len: u64, message_length: u64, fragment_length: usize) -> String {
+        let part = Cbor::Array(vec![
+            Cbor::Unsigned(1),
+            Cbor::Unsigned(seq_len),
+            Cbor::Unsigned(message_length),
+            Cbor::Unsigned(0),
+            Cbor::Bytes(vec![0; fragment_length]),
+        ]);
+        format!(
+            "ur:bytes/1-{}/{}",
+            seq_len,
+            bytewords::encode(&part.encode())
+        )
+    }
+
+    #[test]
+    fn test_fountain_limits() {
+        // The largest URs supported are accepted.
+        let parts = [
+            raw_part(MAX_SEQ_LEN as u64, MAX_SEQ_LEN as u64, 1),
+            raw_part(2, MAX_MESSAGE_LENGTH as u64, MAX_MESSAGE_LENGTH / 2),
+        ];
+        for part in parts.iter() {
+            assert!(FountainDecoder::default().receive(part).is_ok());
+        }
+        // Parts announcing more fragments or a longer message are refused before anything is
+        // allocated for them.
+        let parts = [
+            raw_part(MAX_SEQ_LEN as u64 + 1, MAX_SEQ_LEN as u64 + 1, 1),
+            raw_part(2, MAX_MESSAGE_LENGTH as u64 + 2, MAX_MESSAGE_LENGTH / 2 + 1),
+            raw_part(u32::MAX as u64, u32::MAX as u64, 1),
+        ];
+        for part in parts.iter() {
+            assert!(FountainDecoder::default().receive(part).is_err());
+        }
+    }
+
+    #[test]
+    fn test_cbor_round_trip() {
+        let value = Cbor::Map(vec![
+            (Cbor::Unsigned(1), Cbor::Unsigned(0xd1d04177)),
+            (
+                Cbor::Unsigned(2),
+                Cbor::Array(vec![
+                    tagged(TAG_OUTPUT, Cbor::Bytes(vec![1, 2, 3])),
+                    Cbor::Bool(true),
+                    Cbor::Text("ur".to_string()),
+                ]),
+            ),
+        ]);
+        assert_eq!(Cbor::decode(&value.encode()).unwrap(), value);
+        let mut trailing = value.encode();
+        trailing.push(0);
+        assert!(Cbor::decode(&trailing).is_err());
+    }
+
+    #[test]
+    fn test_output_round_trip() {
+        let descriptor = BdkDescriptor::new(
+            "wpkh([d1d04177/84'/1'/0']tpubDDNxbq17egjFk2edjv8oLnzxk52zny9aAYNv9CMqTzA4mQDiQq818sEkNe9Gzmd4QU8558zftqbfoVBDQorG3E4Wq26tB2JeE4KUoahLkx6/0/*)".to_string(),
+            Network::Testnet,
+        )
+        .unwrap();
+        let ur = encode_output(&descriptor).unwrap();
+        assert!(ur.starts_with("ur:crypto-output/"));
+        let decoder = UrDecoder::retrieve_decoder(UrDecoder::new(Network::Testnet));
+        match decoder.receive(&ur).unwrap().result {
+            Some(UrPayload::Output { descriptor: x }) => assert_eq!(x, descriptor.as_string()),
+            _ => panic!("expected a crypto-output"),
+        }
+    }
+}
@@ -1,26 +1,39 @@
 use crate::blockchain::Blockchain;
-use crate::descriptor::BdkDescriptor;
-use crate::psbt::PartiallySignedTransaction;
+use crate::descriptor::{merge_multipath, split_multipath, BdkDescriptor};
+use crate::psbt::{network_mismatch, FinalizeInputResult, PartiallySignedTransaction};
 use crate::types::{
-    AddressIndex, AddressInfo, Balance, KeychainKind, OutPoint, Progress, ProgressHolder,
-    PsbtSigHashType, TransactionDetails, TxOut,
+    AddressIndex, AddressInfo, Balance, ConsolidationPsbt, ExcludedUtxo, KeychainKind, MaxSendable,
+    OutPoint, Progress, ProgressHolder, PsbtAnalysis, PsbtInputAnalysis, PsbtOutputAnalysis,
+    PsbtSigHashType, PsbtWarning, TransactionDetails, TxOut, UtxoLabel,
 };
+use bdk::bitcoin::consensus::encode::VarInt;
 use bdk::bitcoin::hashes::hex::ToHex;
+use bdk::bitcoin::hashes::{hash160, Hash as BitcoinHash};
 use bdk::bitcoin::psbt::{Input, PsbtSighashType};
-use bdk::database::{AnyDatabase, AnyDatabaseConfig, ConfigurableDatabase};
+use bdk::bitcoin::util::bip32::ChildNumber;
+use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+use bdk::bitcoin::{OutPoint as BdkOutPoint, Txid};
+use bdk::database::{AnyDatabase, AnyDatabaseConfig, ConfigurableDatabase, Database};
 use bdk::descriptor::KeyMap;
-use bdk::{bitcoin, Error as BdkError, SyncOptions};
+use bdk::miniscript::ForEachKey;
+use bdk::signer::SignerId;
+use bdk::{bitcoin, Error as BdkError, FeeRate, SyncOptions};
 use bdk::{SignOptions as BdkSignOptions, Wallet as BdkWallet};
 use lazy_static::lazy_static;
 use std::borrow::Borrow;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::ops::Deref;
 use std::sync::RwLock;
 use std::sync::{Arc, Mutex, MutexGuard};
 use bdk::bitcoin::Script;
+/// Weight of a transaction input without its script_sig and witness: outpoint, script_sig
+/// length and nSequence.
+pub(crate) const TXIN_BASE_WEIGHT: usize = (32 + 4 + 4) * 4;
+/// Unspent outputs and their input weights, keyed by (is change, label).
+type UtxoGroups = BTreeMap<(bool, Option<String>), Vec<(bdk::LocalUtxo, usize)>>;
 lazy_static! {
     static ref WALLET: RwLock<HashMap<String, Arc<Wallet>>> = RwLock::new(HashMap::new());
 }
@@ -59,10 +72,23 @@ impl Wallet {
         network: bdk::bitcoin::Network,
         database_config: DatabaseConfig,
     ) -> Result<String, BdkError> {
+        // A BIP389 multipath descriptor carries both keychains.
+        let (descriptor, change_descriptor) = match split_multipath(&descriptor)? {
+            Some(_) if change_descriptor.is_some() => {
+                return Err(BdkError::Generic(
+                    "A multipath descriptor already includes the change descriptor".to_string(),
+                ))
+            }
+            Some((external, internal)) => (external, Some(internal)),
+            None => (descriptor, change_descriptor),
+        };
         let database = AnyDatabase::from_config(&database_config.into()).unwrap();
-        let wallet_mutex = Mutex::new(
-            BdkWallet::new(&descriptor, change_descriptor.as_ref(), network, database).unwrap(),
-        );
+        let wallet_mutex = Mutex::new(BdkWallet::new(
+            &descriptor,
+            change_descriptor.as_ref(),
+            network,
+            database,
+        )?);
         let wallet = Wallet { wallet_mutex };
 
         let id = default_hasher(&descriptor).to_hex();
@@ -143,6 +169,24 @@ impl Wallet {
             sign_options.map(SignOptions::into).unwrap_or_default(),
         )
     }
+    /// Finalize the inputs of `psbt` the wallet can satisfy, without signing.
+    ///
+    /// Returns the outcome for every input, explaining why the ones still missing data cannot be
+    /// finalized yet.
+    pub(crate) fn finalize_psbt(
+        &self,
+        psbt: &PartiallySignedTransaction,
+        sign_options: Option<SignOptions>,
+    ) -> Result<Vec<FinalizeInputResult>, BdkError> {
+        {
+            let mut psbt = psbt.internal.lock().unwrap();
+            self.get_wallet().finalize_psbt(
+                &mut psbt,
+                sign_options.map(SignOptions::into).unwrap_or_default(),
+            )?;
+        }
+        Ok(psbt.finalize_report())
+    }
     /// Returns the descriptor used to create addresses for a particular `keychain`.
     pub fn get_descriptor_for_keychain(
         &self,
@@ -156,6 +200,480 @@ impl Wallet {
             key_map: KeyMap::new(),
         })
     }
+    /// The descriptors of both keychains as a single BIP389 multipath descriptor, without private
+    /// keys.
+    pub fn get_multipath_descriptor(&self) -> Result<String, BdkError> {
+        let wallet = self.get_wallet();
+        merge_multipath(
+            &wallet
+                .get_descriptor_for_keychain(bdk::KeychainKind::External)
+                .to_string(),
+            &wallet
+                .get_descriptor_for_keychain(bdk::KeychainKind::Internal)
+                .to_string(),
+        )
+    }
+    /// Replace an unconfirmed transaction with one that spends the same inputs back to a fresh
+    /// internal address, effectively cancelling the original payment.
+    ///
+    /// The original transaction must still be unconfirmed and must signal RBF. As required by
+    /// BIP125 the replacement pays a strictly higher fee rate and a higher absolute fee than the
+    /// original; if `fee_rate` alone would produce a lower absolute fee, the fee is raised to the
+    /// original fee plus the minimum relay fee for the replacement.
+    pub(crate) fn cancel_transaction(
+        &self,
+        txid: Txid,
+        fee_rate: FeeRate,
+    ) -> Result<(BdkPartiallySignedTransaction, bdk::TransactionDetails), BdkError> {
+        let wallet = self.get_wallet();
+        let original = wallet
+            .get_tx(&txid, true)?
+            .ok_or(BdkError::TransactionNotFound)?;
+        if original.confirmation_time.is_some() {
+            return Err(BdkError::TransactionConfirmed);
+        }
+        let tx = original.transaction.ok_or(BdkError::TransactionNotFound)?;
+        if !tx.is_explicitly_rbf() {
+            return Err(BdkError::IrreplaceableTransaction);
+        }
+        let original_fee = original.fee.ok_or(BdkError::FeeRateUnavailable)?;
+        let original_fee_rate = FeeRate::from_wu(original_fee, tx.weight());
+        if fee_rate <= original_fee_rate {
+            return Err(BdkError::FeeRateTooLow {
+                required: original_fee_rate,
+            });
+        }
+
+        let outpoints: Vec<BdkOutPoint> = tx.input.iter().map(|x| x.previous_output).collect();
+        // Replacements that aren't broadcast, or that fail to build, leave the address unused for
+        // the next attempt instead of revealing a new one each time.
+        let drain_script = wallet
+            .get_internal_address(bdk::wallet::AddressIndex::LastUnused)?
+            .script_pubkey();
+        let build = |fee_absolute: Option<u64>| -> Result<_, BdkError> {
+            let mut tx_builder = wallet.build_tx();
+            tx_builder
+                .add_utxos(&outpoints)?
+                .manually_selected_only()
+                .drain_to(drain_script.clone())
+                .enable_rbf();
+            if let Some(fee_amount) = fee_absolute {
+                tx_builder.fee_absolute(fee_amount);
+            } else {
+                tx_builder.fee_rate(fee_rate);
+            }
+            tx_builder.finish()
+        };
+
+        let (psbt, details) = build(None)?;
+        // BIP125 rule 4: the replacement must also pay for its own bandwidth at the minimum
+        // relay fee rate (1 sat/vB) on top of the fee paid by the original transaction. The
+        // replacement spends the same inputs, so its size is taken from the signed original,
+        // unless the estimate for its own output is larger.
+        let replacement_vsize = estimate_satisfied_weight(&wallet, &psbt.unsigned_tx)?.div_ceil(4);
+        let required_fee = original_fee + tx.vsize().max(replacement_vsize) as u64;
+        if details.fee.unwrap_or_default() < required_fee {
+            return build(Some(required_fee));
+        }
+        Ok((psbt, details))
+    }
+    /// Build a transaction sending as much as possible from `utxos` (or all the confirmed UTXOs of
+    /// the wallet when empty) to `destination`.
+    ///
+    /// UTXOs worth less than the fee needed to spend them at `fee_rate` are left out and reported
+    /// in [`MaxSendable::excluded`]. The amount is computed by the wallet's coin selection for
+    /// the destination's script type, so it is exact for the returned PSBT.
+    pub(crate) fn sweep(
+        &self,
+        destination: Script,
+        fee_rate: FeeRate,
+        utxos: Vec<OutPoint>,
+        enable_rbf: bool,
+    ) -> Result<
+        (
+            BdkPartiallySignedTransaction,
+            bdk::TransactionDetails,
+            MaxSendable,
+        ),
+        BdkError,
+    > {
+        let wallet = self.get_wallet();
+        let candidates = if utxos.is_empty() {
+            let mut candidates = Vec::new();
+            for utxo in wallet.list_unspent()? {
+                let confirmed = wallet
+                    .get_tx(&utxo.outpoint.txid, false)?
+                    .is_some_and(|x| x.confirmation_time.is_some());
+                if confirmed {
+                    candidates.push(utxo);
+                }
+            }
+            candidates
+        } else {
+            let mut candidates = Vec::new();
+            for outpoint in utxos.iter() {
+                match wallet.get_utxo(outpoint.into())? {
+                    Some(utxo) if !utxo.is_spent => candidates.push(utxo),
+                    _ => return Err(BdkError::UnknownUtxo),
+                }
+            }
+            candidates
+        };
+
+        let mut included = Vec::new();
+        let mut excluded = Vec::new();
+        for utxo in candidates {
+            let input_weight = TXIN_BASE_WEIGHT
+                + wallet
+                    .get_descriptor_for_keychain(utxo.keychain)
+                    .max_satisfaction_weight()?;
+            let spend_cost = fee_rate.fee_wu(input_weight);
+            if utxo.txout.value <= spend_cost {
+                excluded.push(ExcludedUtxo {
+                    outpoint: utxo.outpoint.into(),
+                    value: utxo.txout.value,
+                    spend_cost,
+                });
+            } else {
+                included.push(utxo.outpoint);
+            }
+        }
+        if included.is_empty() {
+            return Err(BdkError::Generic(
+                "No UTXO is worth spending at this fee rate".to_string(),
+            ));
+        }
+
+        let mut tx_builder = wallet.build_tx();
+        tx_builder
+            .add_utxos(&included)?
+            .manually_selected_only()
+            .drain_to(destination.clone())
+            .fee_rate(fee_rate);
+        if enable_rbf {
+            tx_builder.enable_rbf();
+        }
+        let (psbt, details) = tx_builder.finish().map_err(|e| match e {
+            BdkError::InsufficientFunds { needed, available } => BdkError::Generic(format!(
+                "The selected UTXOs ({} sats) cannot pay the fee and a non-dust output ({} sats needed)",
+                available, needed
+            )),
+            e => e,
+        })?;
+        let amount = psbt
+            .unsigned_tx
+            .output
+            .iter()
+            .filter(|x| x.script_pubkey == destination)
+            .map(|x| x.value)
+            .sum();
+        let max_sendable = MaxSendable {
+            amount,
+            fee: details.fee.unwrap_or_default(),
+            utxos: included.into_iter().map(OutPoint::from).collect(),
+            excluded,
+        };
+        Ok((psbt, details, max_sendable))
+    }
+    /// Plan the consolidation of the wallet's UTXOs while fees are low.
+    ///
+    /// A UTXO is picked when its value exceeds the cost of spending it at `fee_rate`. Picked UTXOs
+    /// are grouped by keychain and label so that consolidating never links coins the user keeps
+    /// apart, and every group (split into chunks of at most `max_inputs`, at least 2) that saves
+    /// fees compared to spending its UTXOs at `future_fee_rate` is merged into a PSBT paying a
+    /// fresh internal address. The addresses are revealed, so that change of later transactions
+    /// is not paid to them while the consolidations are pending.
+    pub(crate) fn plan_consolidation(
+        &self,
+        fee_rate: FeeRate,
+        future_fee_rate: FeeRate,
+        labels: Vec<UtxoLabel>,
+        max_inputs: Option<u32>,
+    ) -> Result<Vec<ConsolidationPsbt>, BdkError> {
+        let chunk_size = match max_inputs {
+            Some(max_inputs) if max_inputs < 2 => {
+                return Err(BdkError::Generic(format!(
+                    "A consolidation needs at least 2 inputs, max_inputs is {}",
+                    max_inputs
+                )))
+            }
+            Some(max_inputs) => Some(max_inputs as usize),
+            None => None,
+        };
+        let wallet = self.get_wallet();
+        let labels: HashMap<BdkOutPoint, String> = labels
+            .into_iter()
+            .map(|x| (BdkOutPoint::from(&x.outpoint), x.label))
+            .collect();
+
+        let mut groups: UtxoGroups = BTreeMap::new();
+        for utxo in wallet.list_unspent()? {
+            let input_weight = TXIN_BASE_WEIGHT
+                + wallet
+                    .get_descriptor_for_keychain(utxo.keychain)
+                    .max_satisfaction_weight()?;
+            if utxo.txout.value <= fee_rate.fee_wu(input_weight) {
+                continue;
+            }
+            let key = (
+                utxo.keychain == bdk::KeychainKind::Internal,
+                labels.get(&utxo.outpoint).cloned(),
+            );
+            groups.entry(key).or_default().push((utxo, input_weight));
+        }
+
+        let internal_descriptor = wallet.get_descriptor_for_keychain(bdk::KeychainKind::Internal);
+        let output_spend_weight =
+            TXIN_BASE_WEIGHT + internal_descriptor.max_satisfaction_weight()?;
+        let drain_script_len = wallet
+            .get_internal_address(bdk::wallet::AddressIndex::Peek(0))?
+            .script_pubkey()
+            .len();
+        // version, locktime, input and output counts, segwit marker and flag, and the single
+        // consolidated output.
+        let overhead_weight = (4 + 4 + 1 + 1) * 4 + 2 + (8 + 1 + drain_script_len) * 4;
+
+        let mut plan = Vec::new();
+        for ((_, label), mut utxos) in groups {
+            utxos.sort_by_key(|(utxo, _)| utxo.txout.value);
+            let chunk_size = chunk_size.unwrap_or(utxos.len()).max(2);
+            for chunk in utxos.chunks(chunk_size) {
+                if chunk.len() < 2 {
+                    continue;
+                }
+                let input_weight = chunk.iter().map(|(_, weight)| weight).sum();
+                let estimated_savings = consolidation_savings(
+                    input_weight,
+                    overhead_weight,
+                    output_spend_weight,
+                    fee_rate,
+                    future_fee_rate,
+                );
+                if estimated_savings <= 0 {
+                    continue;
+                }
+
+                let outpoints: Vec<BdkOutPoint> =
+                    chunk.iter().map(|(utxo, _)| utxo.outpoint).collect();
+                // Each consolidation pays its own internal address, so that separate groups are
+                // not linked by a shared output.
+                let drain_script = wallet
+                    .get_internal_address(bdk::wallet::AddressIndex::New)?
+                    .script_pubkey();
+                let mut tx_builder = wallet.build_tx();
+                tx_builder
+                    .add_utxos(&outpoints)?
+                    .manually_selected_only()
+                    .drain_to(drain_script)
+                    .fee_rate(fee_rate)
+                    .enable_rbf();
+                let (psbt, details) = tx_builder.finish()?;
+                plan.push(ConsolidationPsbt {
+                    psbt: PartiallySignedTransaction {
+                        internal: Mutex::new(psbt),
+                    }
+                    .serialize()?,
+                    transaction_details: TransactionDetails::from(&details),
+                    keychain: chunk[0].0.keychain.into(),
+                    label: label.clone(),
+                    utxos: outpoints.into_iter().map(OutPoint::from).collect(),
+                    estimated_savings,
+                });
+            }
+        }
+        Ok(plan)
+    }
+    /// Describe what `psbt` does to this wallet before it is signed: which inputs and outputs
+    /// belong to the wallet, the net effect on its balance, the fee, which inputs it can sign
+    /// and anything that should be double checked.
+    pub(crate) fn analyze_psbt(
+        &self,
+        psbt: &PartiallySignedTransaction,
+    ) -> Result<PsbtAnalysis, BdkError> {
+        let psbt = psbt.internal.lock().unwrap().clone();
+        let wallet = self.get_wallet();
+        let database = wallet.database();
+        let keychains = [bdk::KeychainKind::External, bdk::KeychainKind::Internal];
+        let mut warnings = Vec::new();
+
+        if let Some(network) = network_mismatch(&psbt, wallet.network()) {
+            warnings.push(PsbtWarning::NetworkMismatch {
+                network: network.into(),
+            });
+        }
+
+        let signers: Vec<(bdk::KeychainKind, SignerId)> = keychains
+            .iter()
+            .flat_map(|k| {
+                wallet
+                    .get_signers(*k)
+                    .ids()
+                    .into_iter()
+                    .filter(|id| !matches!(id, SignerId::Dummy(_)))
+                    .map(|id| (*k, id.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let mut fingerprints = Vec::new();
+        for keychain in keychains.iter() {
+            wallet
+                .get_descriptor_for_keychain(*keychain)
+                .for_each_key(|key| {
+                    fingerprints.push(key.master_fingerprint());
+                    true
+                });
+        }
+
+        let mut inputs = Vec::new();
+        let mut satisfaction_weights = Vec::new();
+        for (index, (input, txin)) in psbt
+            .inputs
+            .iter()
+            .zip(psbt.unsigned_tx.input.iter())
+            .enumerate()
+        {
+            let outpoint = txin.previous_output;
+            let mut txout = input.witness_utxo.clone().or_else(|| {
+                input
+                    .non_witness_utxo
+                    .as_ref()
+                    .and_then(|tx| tx.output.get(outpoint.vout as usize).cloned())
+            });
+            if txout.is_none() {
+                warnings.push(PsbtWarning::MissingUtxo {
+                    index: index as u32,
+                });
+                txout = database.get_utxo(&outpoint)?.map(|utxo| utxo.txout);
+            }
+            let path = match &txout {
+                Some(txout) => database.get_path_from_script_pubkey(&txout.script_pubkey)?,
+                None => None,
+            };
+            let can_sign = signers.iter().any(|(keychain, id)| match id {
+                SignerId::Fingerprint(fingerprint) => {
+                    input
+                        .bip32_derivation
+                        .values()
+                        .any(|(f, _)| f == fingerprint)
+                        || input
+                            .tap_key_origins
+                            .values()
+                            .any(|(_, (f, _))| f == fingerprint)
+                }
+                SignerId::PkHash(hash) => {
+                    path.is_some_and(|(k, _)| k == *keychain)
+                        || input.bip32_derivation.keys().any(|key| {
+                            &<hash160::Hash as BitcoinHash>::hash(&key.serialize()) == hash
+                        })
+                        || input.tap_key_origins.keys().any(|key| {
+                            &<hash160::Hash as BitcoinHash>::hash(&key.serialize()) == hash
+                        })
+                }
+                SignerId::Dummy(_) => false,
+            }) || path.is_some_and(|(k, _)| signers.iter().any(|(x, _)| *x == k));
+            satisfaction_weights.push(
+                if input.final_script_sig.is_some() || input.final_script_witness.is_some() {
+                    Some(final_satisfaction_weight(input))
+                } else if let Some((keychain, _)) = path {
+                    Some(
+                        wallet
+                            .get_descriptor_for_keychain(keychain)
+                            .max_satisfaction_weight()?,
+                    )
+                } else {
+                    txout
+                        .as_ref()
+                        .and_then(|x| single_key_satisfaction_weight(&x.script_pubkey))
+                },
+            );
+            inputs.push(PsbtInputAnalysis {
+                previous_output: outpoint.into(),
+                value: txout.map(|x| x.value),
+                is_mine: path.is_some(),
+                keychain: path.map(|(k, _)| k.into()),
+                derivation_index: path.map(|(_, i)| i),
+                can_sign,
+            });
+        }
+
+        let mut outputs = Vec::new();
+        for (index, (output, txout)) in psbt
+            .outputs
+            .iter()
+            .zip(psbt.unsigned_tx.output.iter())
+            .enumerate()
+        {
+            let mut path = database.get_path_from_script_pubkey(&txout.script_pubkey)?;
+            // Outputs claiming one of our keys must be derivable from our descriptors, otherwise
+            // the coordinator may be passing off a foreign script as our change.
+            let claimed: Vec<u32> = output
+                .bip32_derivation
+                .values()
+                .chain(output.tap_key_origins.values().map(|(_, origin)| origin))
+                .filter(|(f, _)| fingerprints.contains(f))
+                .filter_map(|(_, path)| match path.into_iter().last() {
+                    Some(ChildNumber::Normal { index }) => Some(*index),
+                    _ => None,
+                })
+                .collect();
+            if path.is_none() && !claimed.is_empty() {
+                path = keychains.iter().find_map(|keychain| {
+                    let descriptor = wallet.get_descriptor_for_keychain(*keychain);
+                    claimed
+                        .iter()
+                        .find(|i| {
+                            descriptor.at_derivation_index(**i).script_pubkey()
+                                == txout.script_pubkey
+                        })
+                        .map(|i| (*keychain, *i))
+                });
+                if path.is_none() {
+                    warnings.push(PsbtWarning::ChangeDerivationMismatch {
+                        index: index as u32,
+                    });
+                }
+            }
+            outputs.push(PsbtOutputAnalysis {
+                script_pubkey: txout.script_pubkey.clone().into(),
+                value: txout.value,
+                is_mine: path.is_some(),
+                is_change: path.is_some_and(|(k, _)| k == bdk::KeychainKind::Internal),
+                keychain: path.map(|(k, _)| k.into()),
+                derivation_index: path.map(|(_, i)| i),
+            });
+        }
+
+        let sent: u64 = inputs
+            .iter()
+            .filter(|x| x.is_mine)
+            .filter_map(|x| x.value)
+            .sum();
+        let received: u64 = outputs.iter().filter(|x| x.is_mine).map(|x| x.value).sum();
+        let fee = inputs
+            .iter()
+            .map(|x| x.value)
+            .sum::<Option<u64>>()
+            .and_then(|total| {
+                total.checked_sub(psbt.unsigned_tx.output.iter().map(|x| x.value).sum())
+            });
+        let satisfaction_weight = satisfaction_weights.into_iter().sum::<Option<usize>>();
+        let fee_rate = match (fee, satisfaction_weight) {
+            (Some(fee), Some(weight)) => {
+                Some(FeeRate::from_wu(fee, psbt.unsigned_tx.weight() + weight).as_sat_per_vb())
+            }
+            _ => None,
+        };
+        Ok(PsbtAnalysis {
+            inputs,
+            outputs,
+            sent,
+            received,
+            net: received as i64 - sent as i64,
+            fee,
+            fee_rate,
+            warnings,
+        })
+    }
     pub fn get_psbt_input(
         &self,
         utxo: LocalUtxo,
@@ -170,6 +688,64 @@ impl Wallet {
     }
 }
 
+/// Sats saved by spending inputs of `input_weight` in one consolidation transaction at `fee_rate`,
+/// rather than spending them later at `future_fee_rate`. The consolidation pays for its own
+/// overhead now and for spending the consolidated output later.
+pub(crate) fn consolidation_savings(
+    input_weight: usize,
+    overhead_weight: usize,
+    output_spend_weight: usize,
+    fee_rate: FeeRate,
+    future_fee_rate: FeeRate,
+) -> i64 {
+    let later = future_fee_rate.fee_wu(input_weight) as i64;
+    let now = fee_rate.fee_wu(input_weight + overhead_weight) as i64
+        + future_fee_rate.fee_wu(output_spend_weight) as i64;
+    later - now
+}
+
+/// Estimate the weight of `tx` once every input owned by `wallet` has been satisfied, using the
+/// maximum satisfaction weight of the descriptor each input was derived from.
+pub(crate) fn estimate_satisfied_weight(
+    wallet: &BdkWallet<AnyDatabase>,
+    tx: &bitcoin::Transaction,
+) -> Result<usize, BdkError> {
+    let mut weight = tx.weight();
+    for txin in tx.input.iter() {
+        if let Some(utxo) = wallet.get_utxo(txin.previous_output)? {
+            let descriptor = wallet.get_descriptor_for_keychain(utxo.keychain);
+            weight += descriptor.max_satisfaction_weight()?;
+        }
+    }
+    Ok(weight)
+}
+
+/// Weight the final script_sig and witness of a finalized input add to the unsigned transaction.
+fn final_satisfaction_weight(input: &Input) -> usize {
+    let script_sig_len = input.final_script_sig.as_ref().map_or(0, |x| x.len());
+    let witness_weight = input
+        .final_script_witness
+        .as_ref()
+        .map_or(0, |x| x.serialized_len());
+    (VarInt(script_sig_len as u64).len() - 1 + script_sig_len) * 4 + witness_weight
+}
+
+/// Weight of the usual satisfaction of a script spent with a single signature: P2PKH, P2WPKH, and
+/// P2TR through the key path. `None` for other scripts, whose satisfaction can't be guessed.
+fn single_key_satisfaction_weight(script_pubkey: &Script) -> Option<usize> {
+    if script_pubkey.is_p2pkh() {
+        // A 72 bytes signature and a compressed key, with their length prefixes.
+        Some((1 + 72 + 1 + 33) * 4)
+    } else if script_pubkey.is_v0_p2wpkh() {
+        Some(1 + 1 + 72 + 1 + 33)
+    } else if script_pubkey.is_v1_p2tr() {
+        // A 64 bytes signature with an explicit sighash type.
+        Some(1 + 1 + 65)
+    } else {
+        None
+    }
+}
+
 /// Options for a software signer
 ///
 /// Adjust the behavior of our software signers and the way a transaction is finalized
@@ -334,11 +910,207 @@ impl From<DatabaseConfig> for AnyDatabaseConfig {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
 
     use crate::descriptor::BdkDescriptor;
-    use crate::wallet::{AddressIndex, DatabaseConfig, Wallet};
-    use bdk::bitcoin::Network;
+    use crate::psbt::PartiallySignedTransaction;
+    use crate::types::{OutPoint, PsbtWarning, UtxoLabel};
+    use crate::wallet::{consolidation_savings, AddressIndex, DatabaseConfig, Wallet};
+    use bdk::bitcoin::hashes::Hash;
+    use bdk::bitcoin::psbt::Input;
+    use bdk::bitcoin::secp256k1::Secp256k1;
+    use bdk::bitcoin::util::bip32::{DerivationPath, ExtendedPrivKey, ExtendedPubKey};
+    use bdk::bitcoin::util::psbt::PartiallySignedTransaction as BdkPartiallySignedTransaction;
+    use bdk::bitcoin::{
+        Network, OutPoint as BdkOutPoint, PackedLockTime, Script, Sequence, Transaction, TxIn,
+        TxOut as BdkTxOut, Txid, WPubkeyHash, WScriptHash, Witness,
+    };
+    use bdk::database::{AnyDatabase, BatchOperations, Database, MemoryDatabase, SyncTime};
+    use bdk::{
+        BlockTime, Error as BdkError, FeeRate, KeychainKind, LocalUtxo, TransactionDetails,
+        Wallet as BdkWallet,
+    };
+    use std::collections::{HashMap, HashSet};
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    pub(crate) const TEST_DESCRIPTOR: &str = "wpkh(tprv8hwWMmPE4BVNxGdVt3HhEERZhondQvodUY7Ajyseyhudr4WabJqWKWLr4Wi2r26CDaNCQhhxEftEaNzz7dPGhWuKFU4VULesmhEfZYyBXdE/0/*)";
+    pub(crate) const TEST_CHANGE_DESCRIPTOR: &str = "wpkh(tprv8hwWMmPE4BVNxGdVt3HhEERZhondQvodUY7Ajyseyhudr4WabJqWKWLr4Wi2r26CDaNCQhhxEftEaNzz7dPGhWuKFU4VULesmhEfZYyBXdE/1/*)";
+
+    /// The script of the external address `index` of `descriptor`.
+    pub(crate) fn test_script(descriptor: &str, index: u32) -> Script {
+        BdkWallet::new(descriptor, None, Network::Regtest, MemoryDatabase::new())
+            .unwrap()
+            .get_descriptor_for_keychain(KeychainKind::External)
+            .at_derivation_index(index)
+            .script_pubkey()
+    }
+
+    /// A script no test wallet owns.
+    pub(crate) fn foreign_script() -> Script {
+        Script::new_v0_p2wpkh(&WPubkeyHash::hash(&[0x02; 33]))
+    }
+
+    /// A transaction spending a foreign output and paying `value` to `script`.
+    pub(crate) fn funding_tx(script: Script, value: u64) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: BdkOutPoint::new(Txid::all_zeros(), value as u32),
+                script_sig: Script::new(),
+                sequence: Sequence::MAX,
+                witness: Witness::new(),
+            }],
+            output: vec![BdkTxOut {
+                value,
+                script_pubkey: script,
+            }],
+        }
+    }
+
+    /// A wallet whose database holds `txs` as a sync would have stored them, each confirmed at
+    /// the given height or unconfirmed.
+    pub(crate) fn wallet_with_txs(
+        descriptor: &str,
+        change_descriptor: Option<&str>,
+        txs: &[(Transaction, Option<u32>)],
+    ) -> Wallet {
+        let scanner = BdkWallet::new(
+            descriptor,
+            change_descriptor,
+            Network::Regtest,
+            MemoryDatabase::new(),
+        )
+        .unwrap();
+        let mut keychains = vec![KeychainKind::External];
+        if change_descriptor.is_some() {
+            keychains.push(KeychainKind::Internal);
+        }
+        let mut database = MemoryDatabase::new();
+        for keychain in keychains {
+            let descriptor = scanner.get_descriptor_for_keychain(keychain);
+            for index in 0..20 {
+                let script = descriptor.at_derivation_index(index).script_pubkey();
+                database
+                    .set_script_pubkey(&script, keychain, index)
+                    .unwrap();
+            }
+        }
+
+        let outputs: HashMap<BdkOutPoint, BdkTxOut> =
+            txs.iter()
+                .flat_map(|(tx, _)| {
+                    let txid = tx.txid();
+                    tx.output.iter().enumerate().map(move |(vout, txout)| {
+                        (BdkOutPoint::new(txid, vout as u32), txout.clone())
+                    })
+                })
+                .collect();
+        let spent: HashSet<BdkOutPoint> = txs
+            .iter()
+            .flat_map(|(tx, _)| tx.input.iter().map(|x| x.previous_output))
+            .collect();
+        for (tx, height) in txs {
+            let mut sent = 0;
+            let mut input_total = Some(0);
+            for txin in tx.input.iter() {
+                match outputs.get(&txin.previous_output) {
+                    Some(prev) => {
+                        input_total = input_total.map(|x| x + prev.value);
+                        if database
+                            .get_path_from_script_pubkey(&prev.script_pubkey)
+                            .unwrap()
+                            .is_some()
+                        {
+                            sent += prev.value;
+                        }
+                    }
+                    None => input_total = None,
+                }
+            }
+            let mut received = 0;
+            for (vout, txout) in tx.output.iter().enumerate() {
+                let path = database
+                    .get_path_from_script_pubkey(&txout.script_pubkey)
+                    .unwrap();
+                if let Some((keychain, _)) = path {
+                    received += txout.value;
+                    let outpoint = BdkOutPoint::new(tx.txid(), vout as u32);
+                    database
+                        .set_utxo(&LocalUtxo {
+                            outpoint,
+                            txout: txout.clone(),
+                            keychain,
+                            is_spent: spent.contains(&outpoint),
+                        })
+                        .unwrap();
+                }
+            }
+            let output_total: u64 = tx.output.iter().map(|x| x.value).sum();
+            database
+                .set_tx(&TransactionDetails {
+                    transaction: Some(tx.clone()),
+                    txid: tx.txid(),
+                    received,
+                    sent,
+                    fee: input_total.map(|x| x - output_total),
+                    confirmation_time: height.map(|height| BlockTime {
+                        height,
+                        timestamp: 1_600_000_000 + height as u64 * 600,
+                    }),
+                })
+                .unwrap();
+        }
+        // Balances are only computed once the wallet has synced.
+        let tip = txs
+            .iter()
+            .filter_map(|(_, height)| *height)
+            .max()
+            .unwrap_or(0);
+        database
+            .set_sync_time(SyncTime {
+                block_time: BlockTime {
+                    height: tip,
+                    timestamp: 1_600_000_000 + tip as u64 * 600,
+                },
+            })
+            .unwrap();
+
+        Wallet {
+            wallet_mutex: Mutex::new(
+                BdkWallet::new(
+                    descriptor,
+                    change_descriptor,
+                    Network::Regtest,
+                    AnyDatabase::Memory(database),
+                )
+                .unwrap(),
+            ),
+        }
+    }
+
+    /// Store `wallet` like [`Wallet::new`] does, so that the `Api` functions can find it.
+    pub(crate) fn register_wallet(wallet: Wallet) -> String {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = format!("test-{}", NEXT_ID.fetch_add(1, Ordering::SeqCst));
+        super::persist_wallet(id.clone(), wallet);
+        id
+    }
+
+    /// A wallet of the test descriptors holding a confirmed UTXO for each of `values`.
+    pub(crate) fn funded_wallet(values: &[u64]) -> Wallet {
+        let txs: Vec<(Transaction, Option<u32>)> = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let script = test_script(TEST_DESCRIPTOR, index as u32);
+                (funding_tx(script, *value), Some(100))
+            })
+            .collect();
+        wallet_with_txs(TEST_DESCRIPTOR, Some(TEST_CHANGE_DESCRIPTOR), &txs)
+    }
 
     #[test]
     fn test_peek_reset_address() {
@@ -477,4 +1249,444 @@ mod test {
             "bcrt1qaux734vuhykww9632v8cmdnk7z2mw5lsf74v6k"
         );
     }
+    #[test]
+    fn test_multipath_wallet() {
+        let test_wpkh = "wpkh(tprv8hwWMmPE4BVNxGdVt3HhEERZhondQvodUY7Ajyseyhudr4WabJqWKWLr4Wi2r26CDaNCQhhxEftEaNzz7dPGhWuKFU4VULesmhEfZYyBXdE/<0;1>/*)";
+        assert!(Wallet::new(
+            test_wpkh.to_string(),
+            Some(test_wpkh.replace("<0;1>", "1")),
+            Network::Regtest,
+            DatabaseConfig::Memory,
+        )
+        .is_err());
+
+        let wallet_id = Wallet::new(
+            test_wpkh.to_string(),
+            None,
+            Network::Regtest,
+            DatabaseConfig::Memory,
+        )
+        .unwrap();
+        let wallet = Wallet::retrieve_wallet(wallet_id);
+        assert_eq!(
+            wallet
+                .get_address(AddressIndex::Peek { index: 0 })
+                .unwrap()
+                .address,
+            "bcrt1qqjn9gky9mkrm3c28e5e87t5akd3twg6xezp0tv"
+        );
+        assert_eq!(
+            wallet
+                .get_internal_address(AddressIndex::Peek { index: 0 })
+                .unwrap()
+                .address,
+            "bcrt1qpmz73cyx00r4a5dea469j40ax6d6kqyd67nnpj"
+        );
+
+        let multipath = wallet.get_multipath_descriptor().unwrap();
+        assert!(multipath.starts_with("wpkh(tpub"));
+        assert!(multipath.contains("/<0;1>/*)#"));
+    }
+    #[test]
+    fn test_consolidation_savings() {
+        // ten P2WPKH inputs (272 wu each) into a single P2WPKH output
+        let input_weight = 10 * 272;
+        let overhead_weight = 10 * 4 + 2 + 31 * 4;
+        // merging now is only worth it when fees are expected to rise
+        assert!(
+            consolidation_savings(
+                input_weight,
+                overhead_weight,
+                272,
+                FeeRate::from_sat_per_vb(2.0),
+                FeeRate::from_sat_per_vb(20.0),
+            ) > 0
+        );
+        assert!(
+            consolidation_savings(
+                input_weight,
+                overhead_weight,
+                272,
+                FeeRate::from_sat_per_vb(20.0),
+                FeeRate::from_sat_per_vb(20.0),
+            ) < 0
+        );
+    }
+    #[test]
+    fn test_cancel_transaction() {
+        let funding = funding_tx(test_script(TEST_DESCRIPTOR, 0), 100_000);
+        let spend = |rbf: bool| {
+            let funded = wallet_with_txs(
+                TEST_DESCRIPTOR,
+                Some(TEST_CHANGE_DESCRIPTOR),
+                &[(funding.clone(), Some(100))],
+            );
+            let wallet = funded.get_wallet();
+            let mut tx_builder = wallet.build_tx();
+            tx_builder
+                .add_recipient(foreign_script(), 50_000)
+                .fee_rate(FeeRate::from_sat_per_vb(2.0));
+            if rbf {
+                tx_builder.enable_rbf();
+            }
+            let (mut psbt, _) = tx_builder.finish().unwrap();
+            assert!(wallet.sign(&mut psbt, Default::default()).unwrap());
+            psbt.extract_tx()
+        };
+        let original = spend(true);
+        let original_fee = 100_000 - original.output.iter().map(|x| x.value).sum::<u64>();
+        let required_fee = original_fee + original.vsize() as u64;
+
+        let wallet = wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &[(funding.clone(), Some(100)), (original.clone(), None)],
+        );
+        // Just above the original fee rate, the fee is raised to also pay for the replacement.
+        let (psbt, details) = wallet
+            .cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(2.5))
+            .unwrap();
+        assert_eq!(
+            psbt.unsigned_tx
+                .input
+                .iter()
+                .map(|x| x.previous_output)
+                .collect::<Vec<_>>(),
+            original
+                .input
+                .iter()
+                .map(|x| x.previous_output)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(psbt.unsigned_tx.output.len(), 1);
+        assert!(wallet
+            .is_mine(psbt.unsigned_tx.output[0].script_pubkey.clone())
+            .unwrap());
+        assert!(psbt.unsigned_tx.is_explicitly_rbf());
+        assert!(details.fee.unwrap() >= required_fee);
+        let (higher, details) = wallet
+            .cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(20.0))
+            .unwrap();
+        assert!(details.fee.unwrap() > required_fee);
+        assert!(matches!(
+            wallet.cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(1.5)),
+            Err(BdkError::FeeRateTooLow { .. })
+        ));
+        assert!(matches!(
+            wallet.cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(1_000.0)),
+            Err(BdkError::InsufficientFunds { .. })
+        ));
+        // Neither the attempts nor the failures reveal new change addresses.
+        assert_eq!(
+            higher.unsigned_tx.output[0].script_pubkey,
+            psbt.unsigned_tx.output[0].script_pubkey
+        );
+        let (last, _) = wallet
+            .cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(2.5))
+            .unwrap();
+        assert_eq!(
+            last.unsigned_tx.output[0].script_pubkey,
+            psbt.unsigned_tx.output[0].script_pubkey
+        );
+
+        let confirmed = wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &[(funding.clone(), Some(100)), (original.clone(), Some(101))],
+        );
+        assert!(matches!(
+            confirmed.cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(5.0)),
+            Err(BdkError::TransactionConfirmed)
+        ));
+
+        let irreplaceable = spend(false);
+        let wallet = wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &[(funding, Some(100)), (irreplaceable.clone(), None)],
+        );
+        assert!(matches!(
+            wallet.cancel_transaction(irreplaceable.txid(), FeeRate::from_sat_per_vb(5.0)),
+            Err(BdkError::IrreplaceableTransaction)
+        ));
+        assert!(matches!(
+            wallet.cancel_transaction(original.txid(), FeeRate::from_sat_per_vb(5.0)),
+            Err(BdkError::TransactionNotFound)
+        ));
+    }
+    #[test]
+    fn test_plan_consolidation() {
+        let values = [10_000, 12_000, 14_000, 16_000, 18_000, 30_000];
+        let wallet = funded_wallet(&values);
+        let outpoints: Vec<BdkOutPoint> = wallet
+            .list_unspent()
+            .unwrap()
+            .into_iter()
+            .map(|x| (&x.outpoint).into())
+            .collect();
+        let labelled = wallet
+            .list_unspent()
+            .unwrap()
+            .into_iter()
+            .find(|x| x.txout.value == 30_000)
+            .unwrap()
+            .outpoint;
+        let labels = || {
+            vec![UtxoLabel {
+                outpoint: labelled.clone(),
+                label: "exchange".to_string(),
+            }]
+        };
+        let low = FeeRate::from_sat_per_vb(2.0);
+        let high = FeeRate::from_sat_per_vb(50.0);
+
+        assert!(wallet
+            .plan_consolidation(low, high, labels(), Some(1))
+            .is_err());
+        assert!(wallet
+            .plan_consolidation(high, high, vec![], None)
+            .unwrap()
+            .is_empty());
+
+        let plan = wallet
+            .plan_consolidation(low, high, labels(), Some(2))
+            .unwrap();
+        // The five unlabelled UTXOs make two pairs, the last one and the labelled one are left.
+        assert_eq!(plan.len(), 2);
+        let mut drain_scripts = Vec::new();
+        for consolidation in plan.iter() {
+            assert_eq!(consolidation.utxos.len(), 2);
+            assert!(!consolidation.utxos.contains(&labelled));
+            assert!(consolidation.label.is_none());
+            assert!(consolidation.estimated_savings > 0);
+            assert!(consolidation
+                .utxos
+                .iter()
+                .all(|x| outpoints.contains(&x.into())));
+            let psbt = PartiallySignedTransaction::new(consolidation.psbt.clone()).unwrap();
+            let psbt = psbt.internal.lock().unwrap();
+            assert_eq!(psbt.unsigned_tx.input.len(), 2);
+            assert_eq!(psbt.unsigned_tx.output.len(), 1);
+            let script = psbt.unsigned_tx.output[0].script_pubkey.clone();
+            assert!(wallet.is_mine(script.clone()).unwrap());
+            assert!(!drain_scripts.contains(&script));
+            drain_scripts.push(script);
+        }
+        // Change of later transactions is not paid to the planned consolidations' addresses.
+        let change = wallet
+            .get_wallet()
+            .get_internal_address(bdk::wallet::AddressIndex::New)
+            .unwrap()
+            .script_pubkey();
+        assert!(!drain_scripts.contains(&change));
+
+        // Without a limit the five unlabelled UTXOs are merged together.
+        let plan = wallet
+            .plan_consolidation(low, high, labels(), None)
+            .unwrap();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].utxos.len(), 5);
+    }
+    #[test]
+    fn test_sweep() {
+        let fee_rate = FeeRate::from_sat_per_vb(10.0);
+        let txs: Vec<(Transaction, Option<u32>)> = [
+            (100_000, Some(100)),
+            (60_000, Some(100)),
+            (500, Some(100)),
+            (40_000, None),
+        ]
+        .iter()
+        .enumerate()
+        .map(|(index, (value, height))| {
+            let script = test_script(TEST_DESCRIPTOR, index as u32);
+            (funding_tx(script, *value), *height)
+        })
+        .collect();
+        let unconfirmed = BdkOutPoint::new(txs[3].0.txid(), 0);
+        let wallet = wallet_with_txs(TEST_DESCRIPTOR, Some(TEST_CHANGE_DESCRIPTOR), &txs);
+        let confirmed_balance = wallet.get_wallet().get_balance().unwrap().confirmed;
+        assert_eq!(confirmed_balance, 160_500);
+
+        let (psbt, _, max) = wallet
+            .sweep(foreign_script(), fee_rate, vec![], false)
+            .unwrap();
+        // The unconfirmed UTXO is left out, the 500 sats one costs more to spend than it is worth.
+        assert_eq!(max.utxos.len(), 2);
+        assert!(!max.utxos.contains(&unconfirmed.into()));
+        assert_eq!(max.excluded.len(), 1);
+        assert_eq!(max.excluded[0].value, 500);
+        assert!(max.excluded[0].spend_cost > 500);
+        assert_eq!(max.amount, confirmed_balance - 500 - max.fee);
+        assert_eq!(psbt.unsigned_tx.input.len(), 2);
+        assert_eq!(psbt.unsigned_tx.output.len(), 1);
+        assert_eq!(psbt.unsigned_tx.output[0].value, max.amount);
+
+        // Without uneconomical UTXOs, the whole confirmed balance is sent minus the fee.
+        let wallet = wallet_with_txs(
+            TEST_DESCRIPTOR,
+            Some(TEST_CHANGE_DESCRIPTOR),
+            &[txs[0].clone(), txs[1].clone(), txs[3].clone()],
+        );
+        let confirmed_balance = wallet.get_wallet().get_balance().unwrap().confirmed;
+        let (_, _, max) = wallet
+            .sweep(foreign_script(), fee_rate, vec![], false)
+            .unwrap();
+        assert!(max.excluded.is_empty());
+        assert_eq!(max.amount, confirmed_balance - max.fee);
+
+        // An unconfirmed UTXO is only spent when asked for explicitly.
+        let (_, _, max) = wallet
+            .sweep(foreign_script(), fee_rate, vec![unconfirmed.into()], false)
+            .unwrap();
+        assert_eq!(max.utxos, vec![OutPoint::from(unconfirmed)]);
+        assert_eq!(max.amount, 40_000 - max.fee);
+        assert!(wallet
+            .sweep(
+                foreign_script(),
+                FeeRate::from_sat_per_vb(1_000.0),
+                vec![],
+                false
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_analyze_psbt() {
+        let wallet = funded_wallet(&[100_000]);
+        let (psbt, details) = {
+            let bdk_wallet = wallet.get_wallet();
+            let mut builder = bdk_wallet.build_tx();
+            builder
+                .add_recipient(foreign_script(), 60_000)
+                .fee_rate(FeeRate::from_sat_per_vb(2.0));
+            builder.finish().unwrap()
+        };
+        let fee = details.fee.unwrap();
+        let own_weight = wallet
+            .get_wallet()
+            .get_descriptor_for_keychain(KeychainKind::External)
+            .max_satisfaction_weight()
+            .unwrap();
+        let analyze = |psbt: &BdkPartiallySignedTransaction| {
+            wallet
+                .analyze_psbt(&PartiallySignedTransaction {
+                    internal: Mutex::new(psbt.clone()),
+                })
+                .unwrap()
+        };
+
+        let analysis = analyze(&psbt);
+        assert_eq!(analysis.inputs.len(), 1);
+        assert!(analysis.inputs[0].is_mine);
+        assert!(analysis.inputs[0].can_sign);
+        assert_eq!(analysis.inputs[0].derivation_index, Some(0));
+        assert_eq!(analysis.sent, 100_000);
+        assert_eq!(analysis.received, 100_000 - 60_000 - fee);
+        assert_eq!(analysis.net, -(60_000 + fee as i64));
+        assert_eq!(analysis.fee, Some(fee));
+        let fee_rate = analysis.fee_rate.unwrap();
+        assert!((1.9..2.1).contains(&fee_rate), "{}", fee_rate);
+        assert!(analysis.warnings.is_empty());
+        let change = analysis.outputs.iter().find(|x| x.is_mine).unwrap();
+        assert!(change.is_change);
+        assert_eq!(change.derivation_index, Some(0));
+
+        // A foreign P2WPKH input is counted at the size of a single signature spend.
+        let mut with_foreign = psbt.clone();
+        with_foreign.unsigned_tx.input.push(TxIn {
+            previous_output: BdkOutPoint::new(Txid::all_zeros(), 1),
+            script_sig: Script::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        });
+        with_foreign.inputs.push(Input {
+            witness_utxo: Some(BdkTxOut {
+                value: 50_000,
+                script_pubkey: foreign_script(),
+            }),
+            ..Default::default()
+        });
+        let analysis = analyze(&with_foreign);
+        assert!(!analysis.inputs[1].is_mine);
+        assert!(!analysis.inputs[1].can_sign);
+        assert_eq!(analysis.sent, 100_000);
+        assert_eq!(analysis.fee, Some(fee + 50_000));
+        let weight = with_foreign.unsigned_tx.weight() + own_weight + 108;
+        assert_eq!(
+            analysis.fee_rate,
+            Some(FeeRate::from_wu(fee + 50_000, weight).as_sat_per_vb())
+        );
+
+        // The satisfaction of an unfinalized foreign script input can't be guessed, once
+        // finalized its actual size is used.
+        let mut with_script = with_foreign.clone();
+        with_script.inputs[1]
+            .witness_utxo
+            .as_mut()
+            .unwrap()
+            .script_pubkey = Script::new_v0_p2wsh(&WScriptHash::hash(&[0x51]));
+        let analysis = analyze(&with_script);
+        assert_eq!(analysis.fee, Some(fee + 50_000));
+        assert!(analysis.fee_rate.is_none());
+        with_script.inputs[1].final_script_witness =
+            Some(Witness::from_vec(vec![vec![], vec![0x51]]));
+        let analysis = analyze(&with_script);
+        let weight = with_script.unsigned_tx.weight() + own_weight + 4;
+        assert_eq!(
+            analysis.fee_rate,
+            Some(FeeRate::from_wu(fee + 50_000, weight).as_sat_per_vb())
+        );
+
+        // Without its UTXO, the value of the foreign input and so the fee are unknown.
+        let mut missing = with_foreign;
+        missing.inputs[1].witness_utxo = None;
+        let analysis = analyze(&missing);
+        assert!(analysis
+            .warnings
+            .iter()
+            .any(|x| matches!(x, PsbtWarning::MissingUtxo { index: 1 })));
+        assert_eq!(analysis.inputs[1].value, None);
+        assert_eq!(analysis.fee, None);
+        assert!(analysis.fee_rate.is_none());
+
+        // Mainnet global extended keys or BIP44 style paths don't belong in a regtest PSBT.
+        let mut with_xpub = psbt.clone();
+        let xprv = ExtendedPrivKey::new_master(Network::Bitcoin, &[0x01; 32]).unwrap();
+        let xpub = ExtendedPubKey::from_priv(&Secp256k1::new(), &xprv);
+        with_xpub
+            .xpub
+            .insert(xpub, (xpub.fingerprint(), DerivationPath::master()));
+        let mut with_path = psbt.clone();
+        for source in with_path.inputs[0].bip32_derivation.values_mut() {
+            source.1 = DerivationPath::from_str("m/84'/0'/0'/0/0").unwrap();
+        }
+        for psbt in [with_xpub, with_path] {
+            let warnings = analyze(&psbt).warnings;
+            assert_eq!(warnings.len(), 1);
+            assert!(matches!(
+                warnings[0],
+                PsbtWarning::NetworkMismatch {
+                    network: crate::types::Network::Bitcoin
+                }
+            ));
+        }
+
+        // An output claiming our change derivation must match our change script.
+        let mut fake_change = psbt;
+        let recipient = fake_change
+            .unsigned_tx
+            .output
+            .iter()
+            .position(|x| x.script_pubkey == foreign_script())
+            .unwrap();
+        fake_change.outputs[recipient].bip32_derivation =
+            fake_change.outputs[1 - recipient].bip32_derivation.clone();
+        let analysis = analyze(&fake_change);
+        assert!(!analysis.outputs[recipient].is_mine);
+        assert!(matches!(
+            analysis.warnings[..],
+            [PsbtWarning::ChangeDerivationMismatch { index }] if index == recipient as u32
+        ));
+    }
 }
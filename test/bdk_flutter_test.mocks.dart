@@ -72,8 +72,8 @@ class _FakeDescriptor_4 extends _i1.SmartFake implements _i3.Descriptor {
         );
 }
 
-class _FakeFeeRate_5 extends _i1.SmartFake implements _i3.FeeRate {
-  _FakeFeeRate_5(
+class _FakePsbtAnalysis_5 extends _i1.SmartFake implements _i2.PsbtAnalysis {
+  _FakePsbtAnalysis_5(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -82,9 +82,83 @@ class _FakeFeeRate_5 extends _i1.SmartFake implements _i3.FeeRate {
         );
 }
 
-class _FakeDescriptorPublicKey_6 extends _i1.SmartFake
+class _FakePsbtSafetyReport_6 extends _i1.SmartFake
+    implements _i2.PsbtSafetyReport {
+  _FakePsbtSafetyReport_6(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeFinalizedPsbt_7 extends _i1.SmartFake implements _i2.FinalizedPsbt {
+  _FakeFinalizedPsbt_7(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeTxBuilderResult_8 extends _i1.SmartFake
+    implements _i3.TxBuilderResult {
+  _FakeTxBuilderResult_8(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeMaxSendable_9 extends _i1.SmartFake implements _i2.MaxSendable {
+  _FakeMaxSendable_9(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeBdkSweepResult_10 extends _i1.SmartFake
+    implements _i2.BdkSweepResult {
+  _FakeBdkSweepResult_10(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakePayjoinRequest_11 extends _i1.SmartFake
+    implements _i2.PayjoinRequest {
+  _FakePayjoinRequest_11(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeFeeRate_12 extends _i1.SmartFake implements _i3.FeeRate {
+  _FakeFeeRate_12(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeDescriptorPublicKey_13 extends _i1.SmartFake
     implements _i3.DescriptorPublicKey {
-  _FakeDescriptorPublicKey_6(
+  _FakeDescriptorPublicKey_13(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -93,9 +167,9 @@ class _FakeDescriptorPublicKey_6 extends _i1.SmartFake
         );
 }
 
-class _FakeDescriptorSecretKey_7 extends _i1.SmartFake
+class _FakeDescriptorSecretKey_14 extends _i1.SmartFake
     implements _i3.DescriptorSecretKey {
-  _FakeDescriptorSecretKey_7(
+  _FakeDescriptorSecretKey_14(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -104,8 +178,8 @@ class _FakeDescriptorSecretKey_7 extends _i1.SmartFake
         );
 }
 
-class _FakeTransaction_8 extends _i1.SmartFake implements _i3.Transaction {
-  _FakeTransaction_8(
+class _FakeKeyMetadata_15 extends _i1.SmartFake implements _i2.KeyMetadata {
+  _FakeKeyMetadata_15(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -114,8 +188,8 @@ class _FakeTransaction_8 extends _i1.SmartFake implements _i3.Transaction {
         );
 }
 
-class _FakeTxBuilder_9 extends _i1.SmartFake implements _i3.TxBuilder {
-  _FakeTxBuilder_9(
+class _FakeTransaction_16 extends _i1.SmartFake implements _i3.Transaction {
+  _FakeTransaction_16(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -124,9 +198,8 @@ class _FakeTxBuilder_9 extends _i1.SmartFake implements _i3.TxBuilder {
         );
 }
 
-class _FakeTxBuilderResult_10 extends _i1.SmartFake
-    implements _i3.TxBuilderResult {
-  _FakeTxBuilderResult_10(
+class _FakeTxBuilder_17 extends _i1.SmartFake implements _i3.TxBuilder {
+  _FakeTxBuilder_17(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -135,9 +208,20 @@ class _FakeTxBuilderResult_10 extends _i1.SmartFake
         );
 }
 
-class _FakeBumpFeeTxBuilder_11 extends _i1.SmartFake
+class _FakeBumpFeeTxBuilder_18 extends _i1.SmartFake
     implements _i3.BumpFeeTxBuilder {
-  _FakeBumpFeeTxBuilder_11(
+  _FakeBumpFeeTxBuilder_18(
+    Object parent,
+    Invocation parentInvocation,
+  ) : super(
+          parent,
+          parentInvocation,
+        );
+}
+
+class _FakeBumpFeeTxBuilderResult_19 extends _i1.SmartFake
+    implements _i3.BumpFeeTxBuilderResult {
+  _FakeBumpFeeTxBuilderResult_19(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -146,8 +230,8 @@ class _FakeBumpFeeTxBuilder_11 extends _i1.SmartFake
         );
 }
 
-class _FakePayload_12 extends _i1.SmartFake implements _i2.Payload {
-  _FakePayload_12(
+class _FakePayload_20 extends _i1.SmartFake implements _i2.Payload {
+  _FakePayload_20(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -156,8 +240,8 @@ class _FakePayload_12 extends _i1.SmartFake implements _i2.Payload {
         );
 }
 
-class _FakeScript_13 extends _i1.SmartFake implements _i2.Script {
-  _FakeScript_13(
+class _FakeScript_21 extends _i1.SmartFake implements _i2.Script {
+  _FakeScript_21(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -166,8 +250,8 @@ class _FakeScript_13 extends _i1.SmartFake implements _i2.Script {
         );
 }
 
-class _FakeOutPoint_14 extends _i1.SmartFake implements _i2.OutPoint {
-  _FakeOutPoint_14(
+class _FakeOutPoint_22 extends _i1.SmartFake implements _i2.OutPoint {
+  _FakeOutPoint_22(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -176,8 +260,8 @@ class _FakeOutPoint_14 extends _i1.SmartFake implements _i2.OutPoint {
         );
 }
 
-class _FakeTxOut_15 extends _i1.SmartFake implements _i2.TxOut {
-  _FakeTxOut_15(
+class _FakeTxOut_23 extends _i1.SmartFake implements _i2.TxOut {
+  _FakeTxOut_23(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -186,9 +270,9 @@ class _FakeTxOut_15 extends _i1.SmartFake implements _i2.TxOut {
         );
 }
 
-class _FakeTransactionDetails_16 extends _i1.SmartFake
+class _FakeTransactionDetails_24 extends _i1.SmartFake
     implements _i2.TransactionDetails {
-  _FakeTransactionDetails_16(
+  _FakeTransactionDetails_24(
     Object parent,
     Invocation parentInvocation,
   ) : super(
@@ -331,6 +415,7 @@ class MockWallet extends _i1.Mock implements _i3.Wallet {
   _i4.Future<_i3.PartiallySignedTransaction> sign({
     required _i3.PartiallySignedTransaction? psbt,
     _i2.SignOptions? signOptions,
+    _i2.PsbtSafetyPolicy? safetyPolicy,
   }) =>
       (super.noSuchMethod(
         Invocation.method(
@@ -339,6 +424,7 @@ class MockWallet extends _i1.Mock implements _i3.Wallet {
           {
             #psbt: psbt,
             #signOptions: signOptions,
+            #safetyPolicy: safetyPolicy,
           },
         ),
         returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
@@ -350,6 +436,7 @@ class MockWallet extends _i1.Mock implements _i3.Wallet {
             {
               #psbt: psbt,
               #signOptions: signOptions,
+              #safetyPolicy: safetyPolicy,
             },
           ),
         )),
@@ -363,6 +450,7 @@ class MockWallet extends _i1.Mock implements _i3.Wallet {
             {
               #psbt: psbt,
               #signOptions: signOptions,
+              #safetyPolicy: safetyPolicy,
             },
           ),
         )),
@@ -432,106 +520,550 @@ class MockWallet extends _i1.Mock implements _i3.Wallet {
           ),
         )),
       ) as _i4.Future<_i3.Descriptor>);
-}
-
-/// A class which mocks [Transaction].
-///
-/// See the documentation for Mockito's code generation for more information.
-class MockTransaction extends _i1.Mock implements _i3.Transaction {
-  @override
-  _i4.Future<List<int>> serialize() => (super.noSuchMethod(
-        Invocation.method(
-          #serialize,
-          [],
-        ),
-        returnValue: _i4.Future<List<int>>.value(<int>[]),
-        returnValueForMissingStub: _i4.Future<List<int>>.value(<int>[]),
-      ) as _i4.Future<List<int>>);
-  @override
-  _i4.Future<String> txid() => (super.noSuchMethod(
-        Invocation.method(
-          #txid,
-          [],
-        ),
-        returnValue: _i4.Future<String>.value(''),
-        returnValueForMissingStub: _i4.Future<String>.value(''),
-      ) as _i4.Future<String>);
   @override
-  _i4.Future<int> weight() => (super.noSuchMethod(
+  _i4.Future<_i3.Descriptor> getMultipathDescriptor() => (super.noSuchMethod(
         Invocation.method(
-          #weight,
+          #getMultipathDescriptor,
           [],
         ),
-        returnValue: _i4.Future<int>.value(0),
-        returnValueForMissingStub: _i4.Future<int>.value(0),
-      ) as _i4.Future<int>);
+        returnValue: _i4.Future<_i3.Descriptor>.value(_FakeDescriptor_4(
+          this,
+          Invocation.method(
+            #getMultipathDescriptor,
+            [],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.Descriptor>.value(_FakeDescriptor_4(
+          this,
+          Invocation.method(
+            #getMultipathDescriptor,
+            [],
+          ),
+        )),
+      ) as _i4.Future<_i3.Descriptor>);
   @override
-  _i4.Future<int> size() => (super.noSuchMethod(
+  _i4.Future<_i2.PsbtAnalysis> analyzePsbt(
+          _i3.PartiallySignedTransaction? psbt) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #size,
-          [],
+          #analyzePsbt,
+          [psbt],
         ),
-        returnValue: _i4.Future<int>.value(0),
-        returnValueForMissingStub: _i4.Future<int>.value(0),
-      ) as _i4.Future<int>);
+        returnValue: _i4.Future<_i2.PsbtAnalysis>.value(_FakePsbtAnalysis_5(
+          this,
+          Invocation.method(
+            #analyzePsbt,
+            [psbt],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.PsbtAnalysis>.value(_FakePsbtAnalysis_5(
+          this,
+          Invocation.method(
+            #analyzePsbt,
+            [psbt],
+          ),
+        )),
+      ) as _i4.Future<_i2.PsbtAnalysis>);
   @override
-  _i4.Future<int> vsize() => (super.noSuchMethod(
+  _i4.Future<_i2.PsbtSafetyReport> checkPsbt(
+    _i3.PartiallySignedTransaction? psbt, {
+    required _i2.PsbtSafetyPolicy? policy,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #vsize,
-          [],
+          #checkPsbt,
+          [psbt],
+          {#policy: policy},
         ),
-        returnValue: _i4.Future<int>.value(0),
-        returnValueForMissingStub: _i4.Future<int>.value(0),
-      ) as _i4.Future<int>);
+        returnValue:
+            _i4.Future<_i2.PsbtSafetyReport>.value(_FakePsbtSafetyReport_6(
+          this,
+          Invocation.method(
+            #checkPsbt,
+            [psbt],
+            {#policy: policy},
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.PsbtSafetyReport>.value(_FakePsbtSafetyReport_6(
+          this,
+          Invocation.method(
+            #checkPsbt,
+            [psbt],
+            {#policy: policy},
+          ),
+        )),
+      ) as _i4.Future<_i2.PsbtSafetyReport>);
   @override
-  _i4.Future<bool> isCoinBase() => (super.noSuchMethod(
+  _i4.Future<_i2.FinalizedPsbt> finalizePsbt(
+    _i3.PartiallySignedTransaction? psbt, {
+    _i2.SignOptions? signOptions,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #isCoinBase,
-          [],
+          #finalizePsbt,
+          [psbt],
+          {#signOptions: signOptions},
         ),
-        returnValue: _i4.Future<bool>.value(false),
-        returnValueForMissingStub: _i4.Future<bool>.value(false),
-      ) as _i4.Future<bool>);
+        returnValue: _i4.Future<_i2.FinalizedPsbt>.value(_FakeFinalizedPsbt_7(
+          this,
+          Invocation.method(
+            #finalizePsbt,
+            [psbt],
+            {#signOptions: signOptions},
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.FinalizedPsbt>.value(_FakeFinalizedPsbt_7(
+          this,
+          Invocation.method(
+            #finalizePsbt,
+            [psbt],
+            {#signOptions: signOptions},
+          ),
+        )),
+      ) as _i4.Future<_i2.FinalizedPsbt>);
   @override
-  _i4.Future<bool> isExplicitlyRbf() => (super.noSuchMethod(
+  _i4.Future<_i3.TxBuilderResult> cancelTransaction({
+    required String? txid,
+    required double? feeRate,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #isExplicitlyRbf,
+          #cancelTransaction,
           [],
+          {
+            #txid: txid,
+            #feeRate: feeRate,
+          },
         ),
-        returnValue: _i4.Future<bool>.value(false),
-        returnValueForMissingStub: _i4.Future<bool>.value(false),
-      ) as _i4.Future<bool>);
+        returnValue:
+            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_8(
+          this,
+          Invocation.method(
+            #cancelTransaction,
+            [],
+            {
+              #txid: txid,
+              #feeRate: feeRate,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_8(
+          this,
+          Invocation.method(
+            #cancelTransaction,
+            [],
+            {
+              #txid: txid,
+              #feeRate: feeRate,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i3.TxBuilderResult>);
   @override
-  _i4.Future<bool> isLockTimeEnabled() => (super.noSuchMethod(
+  _i4.Future<_i2.MaxSendable> maxSendable({
+    required _i2.Script? destination,
+    required double? feeRate,
+    List<_i2.OutPoint>? utxos = const [],
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #isLockTimeEnabled,
+          #maxSendable,
           [],
+          {
+            #destination: destination,
+            #feeRate: feeRate,
+            #utxos: utxos,
+          },
         ),
-        returnValue: _i4.Future<bool>.value(false),
-        returnValueForMissingStub: _i4.Future<bool>.value(false),
-      ) as _i4.Future<bool>);
+        returnValue: _i4.Future<_i2.MaxSendable>.value(_FakeMaxSendable_9(
+          this,
+          Invocation.method(
+            #maxSendable,
+            [],
+            {
+              #destination: destination,
+              #feeRate: feeRate,
+              #utxos: utxos,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.MaxSendable>.value(_FakeMaxSendable_9(
+          this,
+          Invocation.method(
+            #maxSendable,
+            [],
+            {
+              #destination: destination,
+              #feeRate: feeRate,
+              #utxos: utxos,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i2.MaxSendable>);
   @override
-  _i4.Future<int> version() => (super.noSuchMethod(
+  _i4.Future<_i2.BdkSweepResult> sweep({
+    required _i2.Script? destination,
+    required double? feeRate,
+    List<_i2.OutPoint>? utxos = const [],
+    bool? enableRbf = false,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #version,
+          #sweep,
           [],
+          {
+            #destination: destination,
+            #feeRate: feeRate,
+            #utxos: utxos,
+            #enableRbf: enableRbf,
+          },
         ),
-        returnValue: _i4.Future<int>.value(0),
-        returnValueForMissingStub: _i4.Future<int>.value(0),
-      ) as _i4.Future<int>);
+        returnValue:
+            _i4.Future<_i2.BdkSweepResult>.value(_FakeBdkSweepResult_10(
+          this,
+          Invocation.method(
+            #sweep,
+            [],
+            {
+              #destination: destination,
+              #feeRate: feeRate,
+              #utxos: utxos,
+              #enableRbf: enableRbf,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.BdkSweepResult>.value(_FakeBdkSweepResult_10(
+          this,
+          Invocation.method(
+            #sweep,
+            [],
+            {
+              #destination: destination,
+              #feeRate: feeRate,
+              #utxos: utxos,
+              #enableRbf: enableRbf,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i2.BdkSweepResult>);
   @override
-  _i4.Future<int> lockTime() => (super.noSuchMethod(
+  _i4.Future<List<_i2.ConsolidationPsbt>> planConsolidation({
+    required double? feeRate,
+    required double? futureFeeRate,
+    List<_i2.UtxoLabel>? labels = const [],
+    int? maxInputs,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #lockTime,
+          #planConsolidation,
           [],
+          {
+            #feeRate: feeRate,
+            #futureFeeRate: futureFeeRate,
+            #labels: labels,
+            #maxInputs: maxInputs,
+          },
         ),
-        returnValue: _i4.Future<int>.value(0),
-        returnValueForMissingStub: _i4.Future<int>.value(0),
-      ) as _i4.Future<int>);
+        returnValue: _i4.Future<List<_i2.ConsolidationPsbt>>.value(
+            <_i2.ConsolidationPsbt>[]),
+        returnValueForMissingStub:
+            _i4.Future<List<_i2.ConsolidationPsbt>>.value(
+                <_i2.ConsolidationPsbt>[]),
+      ) as _i4.Future<List<_i2.ConsolidationPsbt>>);
   @override
-  _i4.Future<List<_i2.TxIn>> input() => (super.noSuchMethod(
+  _i4.Future<_i2.PayjoinRequest> payjoinSenderRequest({
+    required String? uri,
+    required _i3.PartiallySignedTransaction? psbt,
+    required _i2.PayjoinParams? params,
+    _i2.SignOptions? signOptions,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #input,
+          #payjoinSenderRequest,
+          [],
+          {
+            #uri: uri,
+            #psbt: psbt,
+            #params: params,
+            #signOptions: signOptions,
+          },
+        ),
+        returnValue:
+            _i4.Future<_i2.PayjoinRequest>.value(_FakePayjoinRequest_11(
+          this,
+          Invocation.method(
+            #payjoinSenderRequest,
+            [],
+            {
+              #uri: uri,
+              #psbt: psbt,
+              #params: params,
+              #signOptions: signOptions,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.PayjoinRequest>.value(_FakePayjoinRequest_11(
+          this,
+          Invocation.method(
+            #payjoinSenderRequest,
+            [],
+            {
+              #uri: uri,
+              #psbt: psbt,
+              #params: params,
+              #signOptions: signOptions,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i2.PayjoinRequest>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> payjoinSenderProcessResponse({
+    required String? uri,
+    required _i3.PartiallySignedTransaction? originalPsbt,
+    required _i2.PayjoinParams? params,
+    required String? response,
+    _i2.SignOptions? signOptions,
+  }) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #payjoinSenderProcessResponse,
+          [],
+          {
+            #uri: uri,
+            #originalPsbt: originalPsbt,
+            #params: params,
+            #response: response,
+            #signOptions: signOptions,
+          },
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #payjoinSenderProcessResponse,
+            [],
+            {
+              #uri: uri,
+              #originalPsbt: originalPsbt,
+              #params: params,
+              #response: response,
+              #signOptions: signOptions,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #payjoinSenderProcessResponse,
+            [],
+            {
+              #uri: uri,
+              #originalPsbt: originalPsbt,
+              #params: params,
+              #response: response,
+              #signOptions: signOptions,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> payjoinSend({
+    required String? uri,
+    required _i3.PartiallySignedTransaction? psbt,
+    required _i2.PayjoinParams? params,
+    _i2.SignOptions? signOptions,
+  }) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #payjoinSend,
+          [],
+          {
+            #uri: uri,
+            #psbt: psbt,
+            #params: params,
+            #signOptions: signOptions,
+          },
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #payjoinSend,
+            [],
+            {
+              #uri: uri,
+              #psbt: psbt,
+              #params: params,
+              #signOptions: signOptions,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #payjoinSend,
+            [],
+            {
+              #uri: uri,
+              #psbt: psbt,
+              #params: params,
+              #signOptions: signOptions,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> payjoinReceive({
+    required _i3.PartiallySignedTransaction? originalPsbt,
+    required String? query,
+  }) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #payjoinReceive,
+          [],
+          {
+            #originalPsbt: originalPsbt,
+            #query: query,
+          },
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #payjoinReceive,
+            [],
+            {
+              #originalPsbt: originalPsbt,
+              #query: query,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #payjoinReceive,
+            [],
+            {
+              #originalPsbt: originalPsbt,
+              #query: query,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+}
+
+/// A class which mocks [Transaction].
+///
+/// See the documentation for Mockito's code generation for more information.
+class MockTransaction extends _i1.Mock implements _i3.Transaction {
+  @override
+  _i4.Future<List<int>> serialize() => (super.noSuchMethod(
+        Invocation.method(
+          #serialize,
+          [],
+        ),
+        returnValue: _i4.Future<List<int>>.value(<int>[]),
+        returnValueForMissingStub: _i4.Future<List<int>>.value(<int>[]),
+      ) as _i4.Future<List<int>>);
+  @override
+  _i4.Future<String> txid() => (super.noSuchMethod(
+        Invocation.method(
+          #txid,
+          [],
+        ),
+        returnValue: _i4.Future<String>.value(''),
+        returnValueForMissingStub: _i4.Future<String>.value(''),
+      ) as _i4.Future<String>);
+  @override
+  _i4.Future<int> weight() => (super.noSuchMethod(
+        Invocation.method(
+          #weight,
+          [],
+        ),
+        returnValue: _i4.Future<int>.value(0),
+        returnValueForMissingStub: _i4.Future<int>.value(0),
+      ) as _i4.Future<int>);
+  @override
+  _i4.Future<int> size() => (super.noSuchMethod(
+        Invocation.method(
+          #size,
+          [],
+        ),
+        returnValue: _i4.Future<int>.value(0),
+        returnValueForMissingStub: _i4.Future<int>.value(0),
+      ) as _i4.Future<int>);
+  @override
+  _i4.Future<int> vsize() => (super.noSuchMethod(
+        Invocation.method(
+          #vsize,
+          [],
+        ),
+        returnValue: _i4.Future<int>.value(0),
+        returnValueForMissingStub: _i4.Future<int>.value(0),
+      ) as _i4.Future<int>);
+  @override
+  _i4.Future<bool> isCoinBase() => (super.noSuchMethod(
+        Invocation.method(
+          #isCoinBase,
+          [],
+        ),
+        returnValue: _i4.Future<bool>.value(false),
+        returnValueForMissingStub: _i4.Future<bool>.value(false),
+      ) as _i4.Future<bool>);
+  @override
+  _i4.Future<bool> isExplicitlyRbf() => (super.noSuchMethod(
+        Invocation.method(
+          #isExplicitlyRbf,
+          [],
+        ),
+        returnValue: _i4.Future<bool>.value(false),
+        returnValueForMissingStub: _i4.Future<bool>.value(false),
+      ) as _i4.Future<bool>);
+  @override
+  _i4.Future<bool> isLockTimeEnabled() => (super.noSuchMethod(
+        Invocation.method(
+          #isLockTimeEnabled,
+          [],
+        ),
+        returnValue: _i4.Future<bool>.value(false),
+        returnValueForMissingStub: _i4.Future<bool>.value(false),
+      ) as _i4.Future<bool>);
+  @override
+  _i4.Future<int> version() => (super.noSuchMethod(
+        Invocation.method(
+          #version,
+          [],
+        ),
+        returnValue: _i4.Future<int>.value(0),
+        returnValueForMissingStub: _i4.Future<int>.value(0),
+      ) as _i4.Future<int>);
+  @override
+  _i4.Future<int> lockTime() => (super.noSuchMethod(
+        Invocation.method(
+          #lockTime,
+          [],
+        ),
+        returnValue: _i4.Future<int>.value(0),
+        returnValueForMissingStub: _i4.Future<int>.value(0),
+      ) as _i4.Future<int>);
+  @override
+  _i4.Future<List<_i2.TxIn>> input() => (super.noSuchMethod(
+        Invocation.method(
+          #input,
           [],
         ),
         returnValue: _i4.Future<List<_i2.TxIn>>.value(<_i2.TxIn>[]),
@@ -578,14 +1110,15 @@ class MockBlockchain extends _i1.Mock implements _i3.Blockchain {
           #estimateFee,
           [target],
         ),
-        returnValue: _i4.Future<_i3.FeeRate>.value(_FakeFeeRate_5(
+        returnValue: _i4.Future<_i3.FeeRate>.value(_FakeFeeRate_12(
           this,
           Invocation.method(
             #estimateFee,
             [target],
           ),
         )),
-        returnValueForMissingStub: _i4.Future<_i3.FeeRate>.value(_FakeFeeRate_5(
+        returnValueForMissingStub:
+            _i4.Future<_i3.FeeRate>.value(_FakeFeeRate_12(
           this,
           Invocation.method(
             #estimateFee,
@@ -616,7 +1149,7 @@ class MockDescriptorSecretKey extends _i1.Mock
           [],
         ),
         returnValue: _i4.Future<_i3.DescriptorPublicKey>.value(
-            _FakeDescriptorPublicKey_6(
+            _FakeDescriptorPublicKey_13(
           this,
           Invocation.method(
             #asPublic,
@@ -624,7 +1157,7 @@ class MockDescriptorSecretKey extends _i1.Mock
           ),
         )),
         returnValueForMissingStub: _i4.Future<_i3.DescriptorPublicKey>.value(
-            _FakeDescriptorPublicKey_6(
+            _FakeDescriptorPublicKey_13(
           this,
           Invocation.method(
             #asPublic,
@@ -650,7 +1183,7 @@ class MockDescriptorSecretKey extends _i1.Mock
           [derivationPath],
         ),
         returnValue: _i4.Future<_i3.DescriptorSecretKey>.value(
-            _FakeDescriptorSecretKey_7(
+            _FakeDescriptorSecretKey_14(
           this,
           Invocation.method(
             #derive,
@@ -658,7 +1191,7 @@ class MockDescriptorSecretKey extends _i1.Mock
           ),
         )),
         returnValueForMissingStub: _i4.Future<_i3.DescriptorSecretKey>.value(
-            _FakeDescriptorSecretKey_7(
+            _FakeDescriptorSecretKey_14(
           this,
           Invocation.method(
             #derive,
@@ -675,7 +1208,7 @@ class MockDescriptorSecretKey extends _i1.Mock
           [derivationPath],
         ),
         returnValue: _i4.Future<_i3.DescriptorSecretKey>.value(
-            _FakeDescriptorSecretKey_7(
+            _FakeDescriptorSecretKey_14(
           this,
           Invocation.method(
             #extend,
@@ -683,7 +1216,7 @@ class MockDescriptorSecretKey extends _i1.Mock
           ),
         )),
         returnValueForMissingStub: _i4.Future<_i3.DescriptorSecretKey>.value(
-            _FakeDescriptorSecretKey_7(
+            _FakeDescriptorSecretKey_14(
           this,
           Invocation.method(
             #extend,
@@ -700,6 +1233,28 @@ class MockDescriptorSecretKey extends _i1.Mock
         returnValue: _i4.Future<List<int>>.value(<int>[]),
         returnValueForMissingStub: _i4.Future<List<int>>.value(<int>[]),
       ) as _i4.Future<List<int>>);
+  @override
+  _i4.Future<_i2.KeyMetadata> metadata() => (super.noSuchMethod(
+        Invocation.method(
+          #metadata,
+          [],
+        ),
+        returnValue: _i4.Future<_i2.KeyMetadata>.value(_FakeKeyMetadata_15(
+          this,
+          Invocation.method(
+            #metadata,
+            [],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.KeyMetadata>.value(_FakeKeyMetadata_15(
+          this,
+          Invocation.method(
+            #metadata,
+            [],
+          ),
+        )),
+      ) as _i4.Future<_i2.KeyMetadata>);
 }
 
 /// A class which mocks [DescriptorPublicKey].
@@ -725,7 +1280,7 @@ class MockDescriptorPublicKey extends _i1.Mock
           [derivationPath],
         ),
         returnValue: _i4.Future<_i3.DescriptorPublicKey>.value(
-            _FakeDescriptorPublicKey_6(
+            _FakeDescriptorPublicKey_13(
           this,
           Invocation.method(
             #derive,
@@ -733,7 +1288,7 @@ class MockDescriptorPublicKey extends _i1.Mock
           ),
         )),
         returnValueForMissingStub: _i4.Future<_i3.DescriptorPublicKey>.value(
-            _FakeDescriptorPublicKey_6(
+            _FakeDescriptorPublicKey_13(
           this,
           Invocation.method(
             #derive,
@@ -750,49 +1305,455 @@ class MockDescriptorPublicKey extends _i1.Mock
           [derivationPath],
         ),
         returnValue: _i4.Future<_i3.DescriptorPublicKey>.value(
-            _FakeDescriptorPublicKey_6(
+            _FakeDescriptorPublicKey_13(
+          this,
+          Invocation.method(
+            #extend,
+            [derivationPath],
+          ),
+        )),
+        returnValueForMissingStub: _i4.Future<_i3.DescriptorPublicKey>.value(
+            _FakeDescriptorPublicKey_13(
+          this,
+          Invocation.method(
+            #extend,
+            [derivationPath],
+          ),
+        )),
+      ) as _i4.Future<_i3.DescriptorPublicKey>);
+  @override
+  _i4.Future<_i2.KeyMetadata> metadata() => (super.noSuchMethod(
+        Invocation.method(
+          #metadata,
+          [],
+        ),
+        returnValue: _i4.Future<_i2.KeyMetadata>.value(_FakeKeyMetadata_15(
+          this,
+          Invocation.method(
+            #metadata,
+            [],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i2.KeyMetadata>.value(_FakeKeyMetadata_15(
+          this,
+          Invocation.method(
+            #metadata,
+            [],
+          ),
+        )),
+      ) as _i4.Future<_i2.KeyMetadata>);
+}
+
+/// A class which mocks [PartiallySignedTransaction].
+///
+/// See the documentation for Mockito's code generation for more information.
+class MockPartiallySignedTransaction extends _i1.Mock
+    implements _i3.PartiallySignedTransaction {
+  @override
+  String get psbtBase64 => (super.noSuchMethod(
+        Invocation.getter(#psbtBase64),
+        returnValue: '',
+        returnValueForMissingStub: '',
+      ) as String);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> combine(
+          _i3.PartiallySignedTransaction? other) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #combine,
+          [other],
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #combine,
+            [other],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #combine,
+            [other],
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.Transaction> extractTx() => (super.noSuchMethod(
+        Invocation.method(
+          #extractTx,
+          [],
+        ),
+        returnValue: _i4.Future<_i3.Transaction>.value(_FakeTransaction_16(
+          this,
+          Invocation.method(
+            #extractTx,
+            [],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.Transaction>.value(_FakeTransaction_16(
+          this,
+          Invocation.method(
+            #extractTx,
+            [],
+          ),
+        )),
+      ) as _i4.Future<_i3.Transaction>);
+  @override
+  _i4.Future<int?> feeAmount() => (super.noSuchMethod(
+        Invocation.method(
+          #feeAmount,
+          [],
+        ),
+        returnValue: _i4.Future<int?>.value(),
+        returnValueForMissingStub: _i4.Future<int?>.value(),
+      ) as _i4.Future<int?>);
+  @override
+  _i4.Future<_i3.FeeRate?> feeRate() => (super.noSuchMethod(
+        Invocation.method(
+          #feeRate,
+          [],
+        ),
+        returnValue: _i4.Future<_i3.FeeRate?>.value(),
+        returnValueForMissingStub: _i4.Future<_i3.FeeRate?>.value(),
+      ) as _i4.Future<_i3.FeeRate?>);
+  @override
+  _i4.Future<String> serialize() => (super.noSuchMethod(
+        Invocation.method(
+          #serialize,
+          [],
+        ),
+        returnValue: _i4.Future<String>.value(''),
+        returnValueForMissingStub: _i4.Future<String>.value(''),
+      ) as _i4.Future<String>);
+  @override
+  _i4.Future<String> jsonSerialize() => (super.noSuchMethod(
+        Invocation.method(
+          #jsonSerialize,
+          [],
+        ),
+        returnValue: _i4.Future<String>.value(''),
+        returnValueForMissingStub: _i4.Future<String>.value(''),
+      ) as _i4.Future<String>);
+  @override
+  _i4.Future<String> txId() => (super.noSuchMethod(
+        Invocation.method(
+          #txId,
+          [],
+        ),
+        returnValue: _i4.Future<String>.value(''),
+        returnValueForMissingStub: _i4.Future<String>.value(''),
+      ) as _i4.Future<String>);
+  @override
+  _i4.Future<_i5.Uint8List> toBytes() => (super.noSuchMethod(
+        Invocation.method(
+          #toBytes,
+          [],
+        ),
+        returnValue: _i4.Future<_i5.Uint8List>.value(_i5.Uint8List(0)),
+        returnValueForMissingStub:
+            _i4.Future<_i5.Uint8List>.value(_i5.Uint8List(0)),
+      ) as _i4.Future<_i5.Uint8List>);
+  @override
+  _i4.Future<String> toHex() => (super.noSuchMethod(
+        Invocation.method(
+          #toHex,
+          [],
+        ),
+        returnValue: _i4.Future<String>.value(''),
+        returnValueForMissingStub: _i4.Future<String>.value(''),
+      ) as _i4.Future<String>);
+  @override
+  _i4.Future<List<String>> toUr({
+    required int? maxFragmentLength,
+    int? startSeqNum = 1,
+    required int? count,
+  }) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #toUr,
+          [],
+          {
+            #maxFragmentLength: maxFragmentLength,
+            #startSeqNum: startSeqNum,
+            #count: count,
+          },
+        ),
+        returnValue: _i4.Future<List<String>>.value(<String>[]),
+        returnValueForMissingStub: _i4.Future<List<String>>.value(<String>[]),
+      ) as _i4.Future<List<String>>);
+  @override
+  _i4.Future<int> version() => (super.noSuchMethod(
+        Invocation.method(
+          #version,
+          [],
+        ),
+        returnValue: _i4.Future<int>.value(0),
+        returnValueForMissingStub: _i4.Future<int>.value(0),
+      ) as _i4.Future<int>);
+  @override
+  _i4.Future<String> serializeVersion(int? version) => (super.noSuchMethod(
+        Invocation.method(
+          #serializeVersion,
+          [version],
+        ),
+        returnValue: _i4.Future<String>.value(''),
+        returnValueForMissingStub: _i4.Future<String>.value(''),
+      ) as _i4.Future<String>);
+  @override
+  _i4.Future<List<_i2.PsbtInputInfo>> inputs() => (super.noSuchMethod(
+        Invocation.method(
+          #inputs,
+          [],
+        ),
+        returnValue:
+            _i4.Future<List<_i2.PsbtInputInfo>>.value(<_i2.PsbtInputInfo>[]),
+        returnValueForMissingStub:
+            _i4.Future<List<_i2.PsbtInputInfo>>.value(<_i2.PsbtInputInfo>[]),
+      ) as _i4.Future<List<_i2.PsbtInputInfo>>);
+  @override
+  _i4.Future<List<_i2.PsbtOutputInfo>> outputs(_i2.Network? network) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #outputs,
+          [network],
+        ),
+        returnValue:
+            _i4.Future<List<_i2.PsbtOutputInfo>>.value(<_i2.PsbtOutputInfo>[]),
+        returnValueForMissingStub:
+            _i4.Future<List<_i2.PsbtOutputInfo>>.value(<_i2.PsbtOutputInfo>[]),
+      ) as _i4.Future<List<_i2.PsbtOutputInfo>>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> addInput({
+    required _i2.OutPoint? outpoint,
+    required _i3.Input? input,
+    int? sequence,
+  }) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #addInput,
+          [],
+          {
+            #outpoint: outpoint,
+            #input: input,
+            #sequence: sequence,
+          },
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #addInput,
+            [],
+            {
+              #outpoint: outpoint,
+              #input: input,
+              #sequence: sequence,
+            },
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #addInput,
+            [],
+            {
+              #outpoint: outpoint,
+              #input: input,
+              #sequence: sequence,
+            },
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> addOutput(
+    _i2.Script? script,
+    int? amount,
+  ) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #addOutput,
+          [
+            script,
+            amount,
+          ],
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #addOutput,
+            [
+              script,
+              amount,
+            ],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #addOutput,
+            [
+              script,
+              amount,
+            ],
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> removeInput(int? index) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #removeInput,
+          [index],
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #removeInput,
+            [index],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #removeInput,
+            [index],
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> removeOutput(int? index) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #removeOutput,
+          [index],
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #removeOutput,
+            [index],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #removeOutput,
+            [index],
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> setLockTime(int? lockTime) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #setLockTime,
+          [lockTime],
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #setLockTime,
+            [lockTime],
+          ),
+        )),
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
+          this,
+          Invocation.method(
+            #setLockTime,
+            [lockTime],
+          ),
+        )),
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
+  @override
+  _i4.Future<_i3.PartiallySignedTransaction> setSequence(
+    int? index,
+    int? sequence,
+  ) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #setSequence,
+          [
+            index,
+            sequence,
+          ],
+        ),
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
           this,
           Invocation.method(
-            #extend,
-            [derivationPath],
+            #setSequence,
+            [
+              index,
+              sequence,
+            ],
           ),
         )),
-        returnValueForMissingStub: _i4.Future<_i3.DescriptorPublicKey>.value(
-            _FakeDescriptorPublicKey_6(
+        returnValueForMissingStub:
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
           this,
           Invocation.method(
-            #extend,
-            [derivationPath],
+            #setSequence,
+            [
+              index,
+              sequence,
+            ],
           ),
         )),
-      ) as _i4.Future<_i3.DescriptorPublicKey>);
-}
-
-/// A class which mocks [PartiallySignedTransaction].
-///
-/// See the documentation for Mockito's code generation for more information.
-class MockPartiallySignedTransaction extends _i1.Mock
-    implements _i3.PartiallySignedTransaction {
-  @override
-  String get psbtBase64 => (super.noSuchMethod(
-        Invocation.getter(#psbtBase64),
-        returnValue: '',
-        returnValueForMissingStub: '',
-      ) as String);
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
   @override
-  _i4.Future<_i3.PartiallySignedTransaction> combine(
-          _i3.PartiallySignedTransaction? other) =>
+  _i4.Future<_i3.PartiallySignedTransaction> setProprietary({
+    required _i2.PsbtMap? map,
+    required _i5.Uint8List? prefix,
+    required int? subtype,
+    required _i5.Uint8List? key,
+    _i5.Uint8List? value,
+  }) =>
       (super.noSuchMethod(
         Invocation.method(
-          #combine,
-          [other],
+          #setProprietary,
+          [],
+          {
+            #map: map,
+            #prefix: prefix,
+            #subtype: subtype,
+            #key: key,
+            #value: value,
+          },
         ),
         returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
             _FakePartiallySignedTransaction_2(
           this,
           Invocation.method(
-            #combine,
-            [other],
+            #setProprietary,
+            [],
+            {
+              #map: map,
+              #prefix: prefix,
+              #subtype: subtype,
+              #key: key,
+              #value: value,
+            },
           ),
         )),
         returnValueForMissingStub:
@@ -800,78 +1761,66 @@ class MockPartiallySignedTransaction extends _i1.Mock
                 _FakePartiallySignedTransaction_2(
           this,
           Invocation.method(
-            #combine,
-            [other],
+            #setProprietary,
+            [],
+            {
+              #map: map,
+              #prefix: prefix,
+              #subtype: subtype,
+              #key: key,
+              #value: value,
+            },
           ),
         )),
       ) as _i4.Future<_i3.PartiallySignedTransaction>);
   @override
-  _i4.Future<_i3.Transaction> extractTx() => (super.noSuchMethod(
+  _i4.Future<_i3.PartiallySignedTransaction> setUnknown({
+    required _i2.PsbtMap? map,
+    required int? keyType,
+    required _i5.Uint8List? key,
+    _i5.Uint8List? value,
+  }) =>
+      (super.noSuchMethod(
         Invocation.method(
-          #extractTx,
+          #setUnknown,
           [],
+          {
+            #map: map,
+            #keyType: keyType,
+            #key: key,
+            #value: value,
+          },
         ),
-        returnValue: _i4.Future<_i3.Transaction>.value(_FakeTransaction_8(
+        returnValue: _i4.Future<_i3.PartiallySignedTransaction>.value(
+            _FakePartiallySignedTransaction_2(
           this,
           Invocation.method(
-            #extractTx,
+            #setUnknown,
             [],
+            {
+              #map: map,
+              #keyType: keyType,
+              #key: key,
+              #value: value,
+            },
           ),
         )),
         returnValueForMissingStub:
-            _i4.Future<_i3.Transaction>.value(_FakeTransaction_8(
+            _i4.Future<_i3.PartiallySignedTransaction>.value(
+                _FakePartiallySignedTransaction_2(
           this,
           Invocation.method(
-            #extractTx,
+            #setUnknown,
             [],
+            {
+              #map: map,
+              #keyType: keyType,
+              #key: key,
+              #value: value,
+            },
           ),
         )),
-      ) as _i4.Future<_i3.Transaction>);
-  @override
-  _i4.Future<int?> feeAmount() => (super.noSuchMethod(
-        Invocation.method(
-          #feeAmount,
-          [],
-        ),
-        returnValue: _i4.Future<int?>.value(),
-        returnValueForMissingStub: _i4.Future<int?>.value(),
-      ) as _i4.Future<int?>);
-  @override
-  _i4.Future<_i3.FeeRate?> feeRate() => (super.noSuchMethod(
-        Invocation.method(
-          #feeRate,
-          [],
-        ),
-        returnValue: _i4.Future<_i3.FeeRate?>.value(),
-        returnValueForMissingStub: _i4.Future<_i3.FeeRate?>.value(),
-      ) as _i4.Future<_i3.FeeRate?>);
-  @override
-  _i4.Future<String> serialize() => (super.noSuchMethod(
-        Invocation.method(
-          #serialize,
-          [],
-        ),
-        returnValue: _i4.Future<String>.value(''),
-        returnValueForMissingStub: _i4.Future<String>.value(''),
-      ) as _i4.Future<String>);
-  @override
-  _i4.Future<String> jsonSerialize() => (super.noSuchMethod(
-        Invocation.method(
-          #jsonSerialize,
-          [],
-        ),
-        returnValue: _i4.Future<String>.value(''),
-        returnValueForMissingStub: _i4.Future<String>.value(''),
-      ) as _i4.Future<String>);
-  @override
-  _i4.Future<String> txId() => (super.noSuchMethod(
-        Invocation.method(
-          #txId,
-          [],
-        ),
-        returnValue: _i4.Future<String>.value(''),
-        returnValueForMissingStub: _i4.Future<String>.value(''),
-      ) as _i4.Future<String>);
+      ) as _i4.Future<_i3.PartiallySignedTransaction>);
 }
 
 /// A class which mocks [TxBuilder].
@@ -885,7 +1834,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           [],
           {#data: data},
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addData,
@@ -893,7 +1842,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
             {#data: data},
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addData,
@@ -915,7 +1864,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
             amount,
           ],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addRecipient,
@@ -925,7 +1874,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
             ],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addRecipient,
@@ -943,14 +1892,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #unSpendable,
           [outpoints],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #unSpendable,
             [outpoints],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #unSpendable,
@@ -964,14 +1913,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #addUtxo,
           [outpoint],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addUtxo,
             [outpoint],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addUtxo,
@@ -985,14 +1934,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #addUtxos,
           [outpoints],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addUtxos,
             [outpoints],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addUtxos,
@@ -1015,7 +1964,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
             satisfactionWeight,
           ],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addForeignUtxo,
@@ -1026,7 +1975,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
             ],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addForeignUtxo,
@@ -1044,14 +1993,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #doNotSpendChange,
           [],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #doNotSpendChange,
             [],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #doNotSpendChange,
@@ -1065,14 +2014,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #drainWallet,
           [],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #drainWallet,
             [],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #drainWallet,
@@ -1086,14 +2035,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #drainTo,
           [script],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #drainTo,
             [script],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #drainTo,
@@ -1107,14 +2056,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #enableRbfWithSequence,
           [nSequence],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #enableRbfWithSequence,
             [nSequence],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #enableRbfWithSequence,
@@ -1128,14 +2077,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #enableRbf,
           [],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #enableRbf,
             [],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #enableRbf,
@@ -1149,14 +2098,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #feeAbsolute,
           [feeAmount],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #feeAbsolute,
             [feeAmount],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #feeAbsolute,
@@ -1170,14 +2119,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #feeRate,
           [satPerVbyte],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #feeRate,
             [satPerVbyte],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #feeRate,
@@ -1192,14 +2141,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #setRecipients,
           [recipients],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #setRecipients,
             [recipients],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #setRecipients,
@@ -1213,14 +2162,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #manuallySelectedOnly,
           [],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #manuallySelectedOnly,
             [],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #manuallySelectedOnly,
@@ -1235,14 +2184,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #addUnSpendable,
           [unSpendable],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addUnSpendable,
             [unSpendable],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #addUnSpendable,
@@ -1256,14 +2205,14 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           #onlySpendChange,
           [],
         ),
-        returnValue: _FakeTxBuilder_9(
+        returnValue: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #onlySpendChange,
             [],
           ),
         ),
-        returnValueForMissingStub: _FakeTxBuilder_9(
+        returnValueForMissingStub: _FakeTxBuilder_17(
           this,
           Invocation.method(
             #onlySpendChange,
@@ -1279,7 +2228,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           [wallet],
         ),
         returnValue:
-            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_10(
+            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_8(
           this,
           Invocation.method(
             #finish,
@@ -1287,7 +2236,7 @@ class MockTxBuilder extends _i1.Mock implements _i3.TxBuilder {
           ),
         )),
         returnValueForMissingStub:
-            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_10(
+            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_8(
           this,
           Invocation.method(
             #finish,
@@ -1308,25 +2257,19 @@ class MockBumpFeeTxBuilder extends _i1.Mock implements _i3.BumpFeeTxBuilder {
         returnValueForMissingStub: '',
       ) as String);
   @override
-  double get feeRate => (super.noSuchMethod(
-        Invocation.getter(#feeRate),
-        returnValue: 0.0,
-        returnValueForMissingStub: 0.0,
-      ) as double);
-  @override
   _i3.BumpFeeTxBuilder allowShrinking(String? address) => (super.noSuchMethod(
         Invocation.method(
           #allowShrinking,
           [address],
         ),
-        returnValue: _FakeBumpFeeTxBuilder_11(
+        returnValue: _FakeBumpFeeTxBuilder_18(
           this,
           Invocation.method(
             #allowShrinking,
             [address],
           ),
         ),
-        returnValueForMissingStub: _FakeBumpFeeTxBuilder_11(
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
           this,
           Invocation.method(
             #allowShrinking,
@@ -1335,19 +2278,191 @@ class MockBumpFeeTxBuilder extends _i1.Mock implements _i3.BumpFeeTxBuilder {
         ),
       ) as _i3.BumpFeeTxBuilder);
   @override
+  _i3.BumpFeeTxBuilder allowShrinkingScript(_i2.Script? script) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #allowShrinkingScript,
+          [script],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #allowShrinkingScript,
+            [script],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #allowShrinkingScript,
+            [script],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder allowShrinkingIndex(int? index) => (super.noSuchMethod(
+        Invocation.method(
+          #allowShrinkingIndex,
+          [index],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #allowShrinkingIndex,
+            [index],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #allowShrinkingIndex,
+            [index],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder feeAbsolute(int? feeAmount) => (super.noSuchMethod(
+        Invocation.method(
+          #feeAbsolute,
+          [feeAmount],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #feeAbsolute,
+            [feeAmount],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #feeAbsolute,
+            [feeAmount],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder addUtxo(_i2.OutPoint? outpoint) => (super.noSuchMethod(
+        Invocation.method(
+          #addUtxo,
+          [outpoint],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #addUtxo,
+            [outpoint],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #addUtxo,
+            [outpoint],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder addUtxos(List<_i2.OutPoint>? outpoints) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #addUtxos,
+          [outpoints],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #addUtxos,
+            [outpoints],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #addUtxos,
+            [outpoints],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder unSpendable(List<_i2.OutPoint>? outpoints) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #unSpendable,
+          [outpoints],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #unSpendable,
+            [outpoints],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #unSpendable,
+            [outpoints],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder addUnSpendable(_i2.OutPoint? unSpendable) =>
+      (super.noSuchMethod(
+        Invocation.method(
+          #addUnSpendable,
+          [unSpendable],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #addUnSpendable,
+            [unSpendable],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #addUnSpendable,
+            [unSpendable],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
+  _i3.BumpFeeTxBuilder manuallySelectedOnly() => (super.noSuchMethod(
+        Invocation.method(
+          #manuallySelectedOnly,
+          [],
+        ),
+        returnValue: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #manuallySelectedOnly,
+            [],
+          ),
+        ),
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
+          this,
+          Invocation.method(
+            #manuallySelectedOnly,
+            [],
+          ),
+        ),
+      ) as _i3.BumpFeeTxBuilder);
+  @override
   _i3.BumpFeeTxBuilder enableRbf() => (super.noSuchMethod(
         Invocation.method(
           #enableRbf,
           [],
         ),
-        returnValue: _FakeBumpFeeTxBuilder_11(
+        returnValue: _FakeBumpFeeTxBuilder_18(
           this,
           Invocation.method(
             #enableRbf,
             [],
           ),
         ),
-        returnValueForMissingStub: _FakeBumpFeeTxBuilder_11(
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
           this,
           Invocation.method(
             #enableRbf,
@@ -1362,14 +2477,14 @@ class MockBumpFeeTxBuilder extends _i1.Mock implements _i3.BumpFeeTxBuilder {
           #enableRbfWithSequence,
           [nSequence],
         ),
-        returnValue: _FakeBumpFeeTxBuilder_11(
+        returnValue: _FakeBumpFeeTxBuilder_18(
           this,
           Invocation.method(
             #enableRbfWithSequence,
             [nSequence],
           ),
         ),
-        returnValueForMissingStub: _FakeBumpFeeTxBuilder_11(
+        returnValueForMissingStub: _FakeBumpFeeTxBuilder_18(
           this,
           Invocation.method(
             #enableRbfWithSequence,
@@ -1378,29 +2493,29 @@ class MockBumpFeeTxBuilder extends _i1.Mock implements _i3.BumpFeeTxBuilder {
         ),
       ) as _i3.BumpFeeTxBuilder);
   @override
-  _i4.Future<_i3.TxBuilderResult> finish(_i3.Wallet? wallet) =>
+  _i4.Future<_i3.BumpFeeTxBuilderResult> finish(_i3.Wallet? wallet) =>
       (super.noSuchMethod(
         Invocation.method(
           #finish,
           [wallet],
         ),
-        returnValue:
-            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_10(
+        returnValue: _i4.Future<_i3.BumpFeeTxBuilderResult>.value(
+            _FakeBumpFeeTxBuilderResult_19(
           this,
           Invocation.method(
             #finish,
             [wallet],
           ),
         )),
-        returnValueForMissingStub:
-            _i4.Future<_i3.TxBuilderResult>.value(_FakeTxBuilderResult_10(
+        returnValueForMissingStub: _i4.Future<_i3.BumpFeeTxBuilderResult>.value(
+            _FakeBumpFeeTxBuilderResult_19(
           this,
           Invocation.method(
             #finish,
             [wallet],
           ),
         )),
-      ) as _i4.Future<_i3.TxBuilderResult>);
+      ) as _i4.Future<_i3.BumpFeeTxBuilderResult>);
 }
 
 /// A class which mocks [Script].
@@ -1434,7 +2549,7 @@ class MockAddress extends _i1.Mock implements _i3.Address {
           #payload,
           [],
         ),
-        returnValue: _i4.Future<_i2.Payload>.value(_FakePayload_12(
+        returnValue: _i4.Future<_i2.Payload>.value(_FakePayload_20(
           this,
           Invocation.method(
             #payload,
@@ -1442,7 +2557,7 @@ class MockAddress extends _i1.Mock implements _i3.Address {
           ),
         )),
         returnValueForMissingStub:
-            _i4.Future<_i2.Payload>.value(_FakePayload_12(
+            _i4.Future<_i2.Payload>.value(_FakePayload_20(
           this,
           Invocation.method(
             #payload,
@@ -1466,14 +2581,14 @@ class MockAddress extends _i1.Mock implements _i3.Address {
           #scriptPubKey,
           [],
         ),
-        returnValue: _i4.Future<_i2.Script>.value(_FakeScript_13(
+        returnValue: _i4.Future<_i2.Script>.value(_FakeScript_21(
           this,
           Invocation.method(
             #scriptPubKey,
             [],
           ),
         )),
-        returnValueForMissingStub: _i4.Future<_i2.Script>.value(_FakeScript_13(
+        returnValueForMissingStub: _i4.Future<_i2.Script>.value(_FakeScript_21(
           this,
           Invocation.method(
             #scriptPubKey,
@@ -1510,11 +2625,11 @@ class MockLocalUtxo extends _i1.Mock implements _i2.LocalUtxo {
   @override
   _i2.OutPoint get outpoint => (super.noSuchMethod(
         Invocation.getter(#outpoint),
-        returnValue: _FakeOutPoint_14(
+        returnValue: _FakeOutPoint_22(
           this,
           Invocation.getter(#outpoint),
         ),
-        returnValueForMissingStub: _FakeOutPoint_14(
+        returnValueForMissingStub: _FakeOutPoint_22(
           this,
           Invocation.getter(#outpoint),
         ),
@@ -1522,11 +2637,11 @@ class MockLocalUtxo extends _i1.Mock implements _i2.LocalUtxo {
   @override
   _i2.TxOut get txout => (super.noSuchMethod(
         Invocation.getter(#txout),
-        returnValue: _FakeTxOut_15(
+        returnValue: _FakeTxOut_23(
           this,
           Invocation.getter(#txout),
         ),
-        returnValueForMissingStub: _FakeTxOut_15(
+        returnValueForMissingStub: _FakeTxOut_23(
           this,
           Invocation.getter(#txout),
         ),
@@ -1564,11 +2679,11 @@ class MockTxBuilderResult extends _i1.Mock implements _i3.TxBuilderResult {
   @override
   _i2.TransactionDetails get txDetails => (super.noSuchMethod(
         Invocation.getter(#txDetails),
-        returnValue: _FakeTransactionDetails_16(
+        returnValue: _FakeTransactionDetails_24(
           this,
           Invocation.getter(#txDetails),
         ),
-        returnValueForMissingStub: _FakeTransactionDetails_16(
+        returnValueForMissingStub: _FakeTransactionDetails_24(
           this,
           Invocation.getter(#txDetails),
         ),